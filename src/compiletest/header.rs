@@ -32,7 +32,13 @@ type test_props = {
     // Modules from aux directory that should be compiled
     aux_builds: ~[~str],
     // Environment settings to use during execution
-    exec_env: ~[(~str,~str)]
+    exec_env: ~[(~str,~str)],
+    // A C file from aux directory with a `main` that the test, compiled
+    // as a static library, should be linked into
+    c_main: Option<~str>,
+    // Whether the shared libraries of the aux crates are removed before
+    // the test runs, so that it fails if it was linked against them
+    no_aux_dylibs: bool
 };
 
 // Load any test directives embedded in the file
@@ -42,6 +48,8 @@ fn load_props(testfile: &Path) -> test_props {
     let mut exec_env = ~[];
     let mut compile_flags = None;
     let mut pp_exact = None;
+    let mut c_main = None;
+    let mut no_aux_dylibs = false;
     for iter_header(testfile) |ln| {
         match parse_error_pattern(ln) {
           Some(ep) => error_patterns.push(ep),
@@ -63,13 +71,23 @@ fn load_props(testfile: &Path) -> test_props {
         do parse_exec_env(ln).iter |ee| {
             exec_env.push(*ee);
         }
+
+        if c_main.is_none() {
+            c_main = parse_c_main(ln);
+        }
+
+        if !no_aux_dylibs {
+            no_aux_dylibs = parse_name_directive(ln, ~"no-aux-dylibs");
+        }
     };
     return {
         error_patterns: error_patterns,
         compile_flags: compile_flags,
         pp_exact: pp_exact,
         aux_builds: aux_builds,
-        exec_env: exec_env
+        exec_env: exec_env,
+        c_main: c_main,
+        no_aux_dylibs: no_aux_dylibs
     };
 }
 
@@ -116,6 +134,10 @@ fn parse_compile_flags(line: ~str) -> Option<~str> {
     parse_name_value_directive(line, ~"compile-flags")
 }
 
+fn parse_c_main(line: ~str) -> Option<~str> {
+    parse_name_value_directive(line, ~"c-main")
+}
+
 fn parse_exec_env(line: ~str) -> Option<(~str, ~str)> {
     do parse_name_value_directive(line, ~"exec-env").map |nv| {
         // nv is either FOO or FOO=BAR
@@ -104,13 +104,19 @@ fn check_correct_failure_status(procres: procres) {
 }
 
 fn run_rpass_test(config: config, props: test_props, testfile: &Path) {
-    if !config.jit {
+    if props.c_main.is_some() {
+        run_c_main_test(config, props, testfile);
+    } else if !config.jit {
         let mut procres = compile_test(config, props, testfile);
 
         if procres.status != 0 {
             fatal_procres(~"compilation failed!", procres);
         }
 
+        if props.no_aux_dylibs {
+            remove_aux_dylibs(config, testfile);
+        }
+
         procres = exec_compiled_test(config, props, testfile);
 
         if procres.status != 0 {
@@ -123,6 +129,68 @@ fn run_rpass_test(config: config, props: test_props, testfile: &Path) {
     }
 }
 
+// Compiles the test as a static library, links it into a C program and
+// runs that
+fn run_c_main_test(config: config, props: test_props, testfile: &Path) {
+    let archive = make_out_name(config, testfile, ~"a");
+    let link_args = ~[~"-L", aux_output_dir_name(config, testfile).to_str(),
+                      ~"--staticlib"];
+    let mut procres = compose_and_run_compiler(
+        config, props, testfile,
+        make_compile_args(config, props, link_args,
+                          |a, b| make_out_name(a, b, ~"a"), testfile),
+        None);
+
+    if procres.status != 0 {
+        fatal_procres(~"compilation failed!", procres);
+    }
+
+    // rustc prints the native libraries the archive needs as a note
+    let mut native_args = None;
+    for str::lines(procres.stderr).each |line| {
+        if str::contains(*line, ~"link against the following native") {
+            match str::find_str(*line, ~"`: ") {
+              Some(i) => {
+                native_args = Some(str::slice(*line, i + 3u, line.len()));
+              }
+              None => ()
+            }
+        }
+    }
+    if native_args.is_none() {
+        fatal_procres(~"no native libraries listed for archive", procres);
+    }
+
+    let c_main = config.aux_base.push_rel(&Path(props.c_main.get()));
+    // A header written with --c-header ends up next to the archive
+    let cc_args = ~[~"-I", config.build_base.to_str(),
+                    c_main.to_str(), archive.to_str(),
+                    ~"-o", make_exe_name(config, testfile).to_str()]
+        + split_maybe_args(native_args);
+    procres = compose_and_run(config, testfile,
+                              {prog: ~"cc", args: cc_args}, ~[],
+                              config.compile_lib_path, None);
+
+    if procres.status != 0 {
+        fatal_procres(~"linking C main failed!", procres);
+    }
+
+    procres = exec_compiled_test(config, props, testfile);
+
+    if procres.status != 0 {
+        fatal_procres(~"test run failed!", procres);
+    }
+}
+
+fn remove_aux_dylibs(config: config, testfile: &Path) {
+    let aux_dir = aux_output_dir_name(config, testfile);
+    for os::list_dir_path(&aux_dir).each |lib| {
+        if str::ends_with(lib.to_str(), os::DLL_SUFFIX) {
+            os::remove_file(*lib);
+        }
+    }
+}
+
 fn run_pretty_test(config: config, props: test_props, testfile: &Path) {
     if props.pp_exact.is_some() {
         logv(config, ~"testing for exact pretty-printing");
@@ -447,10 +515,18 @@ fn compose_and_run_compiler(
     let extra_link_args = ~[~"-L",
                             aux_output_dir_name(config, testfile).to_str()];
 
+    // A static library bundles the crates it uses, so they are built
+    // statically too
+    let lib_args = if props.c_main.is_some() {
+        ~[~"--static", ~"--lib"]
+    } else {
+        ~[~"--lib"]
+    };
+
     for vec::each(props.aux_builds) |rel_ab| {
         let abs_ab = config.aux_base.push_rel(&Path(*rel_ab));
         let aux_args =
-            make_compile_args(config, props, ~[~"--lib"] + extra_link_args,
+            make_compile_args(config, props, lib_args + extra_link_args,
                               |a,b| make_lib_name(a, b, testfile), &abs_ab);
         let auxres = compose_and_run(config, &abs_ab, aux_args, ~[],
                                      config.compile_lib_path, None);
@@ -1,9 +1,11 @@
 #!/usr/bin/env python
 # xfail-license
 
-# This digests UnicodeData.txt and DerivedCoreProperties.txt and emits rust
-# code covering the core properties. Since this is a pretty rare event we
-# just store this out-of-line and check the unicode.rs file into git.
+# This digests UnicodeData.txt, DerivedCoreProperties.txt and friends and
+# emits rust code covering the core properties, case mapping,
+# normalization and grapheme cluster breaks. Since this is a pretty rare
+# event we just store this out-of-line and check the unicode.rs file into
+# git.
 #
 # The emitted code is "the minimum we think is necessary for libcore", that
 # is, to support basic operations of the compiler and "most nontrivial rust
@@ -12,11 +14,16 @@
 
 import fileinput, re, os, sys
 
+# All of the tables come from this one version of the database, so that
+# the properties, mappings and break rules agree with each other.
+unicode_version = "14.0.0"
+
 
 def fetch(f):
     if not os.path.exists(f):
-        os.system("curl -O http://www.unicode.org/Public/UNIDATA/%s"
-                  % f)
+        os.system("curl --create-dirs -o %s "
+                  "http://www.unicode.org/Public/%s/ucd/%s"
+                  % (f, unicode_version, f))
 
     if not os.path.exists(f):
         sys.stderr.write("cannot load %s" % f)
@@ -28,6 +35,9 @@ def load_unicode_data(f):
     gencats = {}
     canon_decomp = {}
     compat_decomp = {}
+    combining = {}
+    lower = {}
+    upper = {}
     curr_cat = ""
     c_lo = 0
     c_hi = 0
@@ -37,10 +47,17 @@ def load_unicode_data(f):
             continue
         [code, name, gencat, combine, bidi,
          decomp, deci, digit, num, mirror,
-         old, iso, upcase, lowcsae, titlecase ] = fields
+         old, iso, upcase, lowcase, titlecase ] = fields
 
         code = int(code, 16)
 
+        if combine != "0":
+            combining[code] = int(combine)
+        if upcase != "":
+            upper[code] = int(upcase, 16)
+        if lowcase != "":
+            lower[code] = int(lowcase, 16)
+
         if decomp != "":
             if decomp.startswith('<'):
                 seq = []
@@ -69,7 +86,7 @@ def load_unicode_data(f):
             c_lo = code
             c_hi = code
 
-    return (canon_decomp, compat_decomp, gencats)
+    return (canon_decomp, compat_decomp, gencats, combining, lower, upper)
 
 
 def load_derived_core_properties(f):
@@ -113,88 +130,427 @@ def escape_char(c):
     return "'\\U%8.8x'" % c
 
 def emit_property_module(f, mod, tbl):
-    f.write("mod %s {\n" % mod)
-    keys = tbl.keys()
-    keys.sort()
-    for cat in keys:
-        f.write("    pure fn %s(c: char) -> bool {\n" % cat)
-        f.write("        ret alt c {\n")
+    f.write("pub mod %s {\n" % mod)
+    for cat in sorted(tbl.keys()):
+        f.write("    pub pure fn %s(c: char) -> bool {\n" % cat)
+        f.write("        return match c {\n")
         prefix = ' '
         for pair in tbl[cat]:
             if pair[0] == pair[1]:
                 f.write("            %c %s\n" %
                         (prefix, escape_char(pair[0])))
             else:
-                f.write("            %c %s to %s\n" %
+                f.write("            %c %s .. %s\n" %
                         (prefix,
                          escape_char(pair[0]),
                          escape_char(pair[1])))
             prefix = '|'
-        f.write("              { true }\n")
-        f.write("            _ { false }\n")
+        f.write("              => true,\n")
+        f.write("            _ => false\n")
         f.write("        };\n")
         f.write("    }\n\n")
-    f.write("}\n")
-
-def emit_decomp_module(f, canon, compat):
-    canon_keys = canon.keys()
-    canon_keys.sort()
-
-    compat_keys = compat.keys()
-    compat_keys.sort()
-    f.write("mod decompose {\n\n");
-    f.write("    export canonical, compatibility;\n\n")
-    f.write("    fn canonical(c: char, i: block(char)) "
-            + "{ d(c, i, false); }\n\n")
-    f.write("    fn compatibility(c: char, i: block(char)) "
-            +"{ d(c, i, true); }\n\n")
-    f.write("    fn d(c: char, i: block(char), k: bool) {\n")
-
-    f.write("        if c <= '\\x7f' { i(c); ret; }\n")
-
-    # First check the canonical decompositions
-    f.write("        // Canonical decomposition\n")
-    f.write("        alt c {\n")
-    for char in canon_keys:
-        f.write("          %s {\n" % escape_char(char))
-        for d in canon[char]:
-            f.write("            d(%s, i, k);\n"
-                    % escape_char(d))
-        f.write("          }\n")
-
-    f.write("          _ { }\n")
-    f.write("        }\n\n")
-
-    # Bottom out if we're not doing compat.
-    f.write("        if !k { i(c); ret; }\n\n ")
-
-    # Then check the compatibility decompositions
-    f.write("        // Compatibility decomposition\n")
-    f.write("        alt c {\n")
-    for char in compat_keys:
-        f.write("          %s {\n" % escape_char(char))
-        for d in compat[char]:
-            f.write("            d(%s, i, k);\n"
-                    % escape_char(d))
-        f.write("          }\n")
-
-    f.write("          _ { }\n")
-    f.write("        }\n\n")
-
-    # Finally bottom out.
-    f.write("        i(c);\n")
-    f.write("    }\n")
     f.write("}\n\n")
 
+def load_special_casing(f, lower, upper):
+    # Only the unconditional mappings; the language and context sensitive
+    # ones (other than final sigma, which str handles) are left to callers.
+    fetch(f)
+    special_lower = {}
+    special_upper = {}
+    for line in fileinput.input(f):
+        line = line.split('#')[0].strip()
+        if line == "":
+            continue
+        fields = [x.strip() for x in line.split(";")]
+        if len(fields) > 4 and fields[4] != "":
+            continue
+        code = int(fields[0], 16)
+        lo = [int(x, 16) for x in fields[1].split()]
+        up = [int(x, 16) for x in fields[3].split()]
+        if lo != [lower.get(code, code)]:
+            special_lower[code] = lo
+        if up != [upper.get(code, code)]:
+            special_upper[code] = up
+    return (special_lower, special_upper)
+
+def load_case_folding(f):
+    # Full case folding: the common (C) and full (F) mappings.
+    fetch(f)
+    folding = {}
+    for line in fileinput.input(f):
+        line = line.split('#')[0].strip()
+        if line == "":
+            continue
+        fields = [x.strip() for x in line.split(";")]
+        if fields[1] in ("C", "F"):
+            folding[int(fields[0], 16)] = \
+                [int(x, 16) for x in fields[2].split()]
+    return folding
+
+def load_property(f, interesting):
+    fetch(f)
+    props = {}
+    re1 = re.compile("^([0-9A-F]+)(?:\.\.([0-9A-F]+))? +; (\w+)")
+    for line in fileinput.input(f):
+        m = re1.match(line)
+        if not m or m.group(3) not in interesting:
+            continue
+        lo = int(m.group(1), 16)
+        hi = lo
+        if m.group(2):
+            hi = int(m.group(2), 16)
+        for c in range(lo, hi + 1):
+            props[c] = m.group(3)
+    return props
+
+def group_ranges(tbl):
+    # Collapse a map from code points to values into runs of consecutive
+    # code points with the same value.
+    ranges = []
+    for c in sorted(tbl.keys()):
+        if ranges and ranges[-1][1] == c - 1 and ranges[-1][2] == tbl[c]:
+            ranges[-1][1] = c
+        else:
+            ranges.append([c, c, tbl[c]])
+    return ranges
+
+def emit_pattern(lo, hi):
+    if lo == hi:
+        return escape_char(lo)
+    return "%s .. %s" % (escape_char(lo), escape_char(hi))
+
+def offset_arms(tbl):
+    # Runs of chars that are all offset by the same amount, either
+    # consecutively or (as is common for cased letters) every other char,
+    # become a single arm. Yields (pattern, expression) pairs, where the
+    # expression is given as a format taking the mapped value.
+    offsets = dict((c, tbl[c] - c) for c in tbl)
+    runs = []
+    for c in sorted(offsets.keys()):
+        if runs and runs[-1][2] == offsets[c] and \
+                (runs[-1][1] == c - 1 and runs[-1][3] in (0, 1) or
+                 runs[-1][1] == c - 2 and runs[-1][3] in (0, 2)):
+            runs[-1][3] = c - runs[-1][1]
+            runs[-1][1] = c
+        else:
+            runs.append([c, c, offsets[c], 0])
+    arms = []
+    for (lo, hi, off, step) in runs:
+        if step == 2 and (hi - lo) // 2 < 2:
+            for c in range(lo, hi + 1, 2):
+                arms.append((escape_char(c), escape_char(c + off)))
+            continue
+        pat = emit_pattern(lo, hi)
+        if step == 2:
+            pat += " if (c as u32) %% 2 == %d" % (lo % 2)
+        if lo == hi:
+            arms.append((pat, escape_char(lo + off)))
+        elif off > 0:
+            arms.append((pat, "((c as u32) + %d) as char" % off))
+        else:
+            arms.append((pat, "((c as u32) - %d) as char" % -off))
+    return arms
+
+def emit_arm(f, pat, expr):
+    line = "          %s => %s," % (pat, expr)
+    if len(line) <= 78:
+        f.write(line + "\n")
+    else:
+        f.write("          %s =>\n            %s,\n" % (pat, expr))
+
+def emit_char_mapping(f, name, doc, tbl):
+    f.write("    /// %s\n" % doc)
+    f.write("    pub pure fn %s(c: char) -> char {\n" % name)
+    f.write("        match c {\n")
+    for (pat, expr) in offset_arms(tbl):
+        emit_arm(f, pat, expr)
+    f.write("          _ => c\n")
+    f.write("        }\n")
+    f.write("    }\n\n")
+
+def emit_calls(f, pat, seq):
+    calls = ["f(%s);" % escape_char(x) for x in seq]
+    line = "          %s => { %s }" % (pat, " ".join(calls))
+    if len(line) <= 78:
+        f.write(line + "\n")
+        return
+    f.write("          %s => {\n" % pat)
+    line = "           "
+    for call in calls:
+        if len(line) + 1 + len(call) > 78:
+            f.write(line + "\n")
+            line = "           "
+        line += " " + call
+    f.write(line + "\n")
+    f.write("          }\n")
+
+def emit_char_expansion(f, name, doc, tbl, default):
+    f.write("    /**\n")
+    for line in doc:
+        f.write("     * %s\n" % line)
+    f.write("     */\n")
+    f.write("    pub pure fn %s(c: char, f: fn(char)) {\n" % name)
+    f.write("        match c {\n")
+    for c in sorted(tbl.keys()):
+        if len(tbl[c]) > 1:
+            emit_calls(f, escape_char(c), tbl[c])
+    singles = dict((c, tbl[c][0]) for c in tbl if len(tbl[c]) == 1)
+    for (pat, expr) in offset_arms(singles):
+        emit_arm(f, pat, "f(%s)" % expr)
+    f.write("          _ => f(%s)\n" % default)
+    f.write("        }\n")
+    f.write("    }\n\n")
+
+def emit_conversions_module(f, lower, upper, special_lower,
+                            special_upper, folding):
+    f.write("pub mod conversions {\n\n")
+    emit_char_mapping(f, "to_lower",
+                      "The simple lowercase mapping of a char", lower)
+    emit_char_mapping(f, "to_upper",
+                      "The simple uppercase mapping of a char", upper)
+    emit_char_expansion(f, "each_lower",
+                        ["Call `f` with each char of the full lowercase "
+                         "mapping of `c`,",
+                         "which may be more than one char"],
+                        special_lower, "to_lower(c)")
+    emit_char_expansion(f, "each_upper",
+                        ["Call `f` with each char of the full uppercase "
+                         "mapping of `c`,",
+                         "which may be more than one char"],
+                        special_upper, "to_upper(c)")
+    emit_char_expansion(f, "each_fold",
+                        ["Call `f` with each char of the full case "
+                         "folding of `c`. Strings",
+                         "whose foldings are equal are equal ignoring "
+                         "case."],
+                        folding, "c")
+    f.write("}\n\n")
+
+def expand_decomp(c, canon, compat, k):
+    if c in canon:
+        return [x for d in canon[c] for x in expand_decomp(d, canon,
+                                                          compat, k)]
+    if k and c in compat:
+        return [x for d in compat[c] for x in expand_decomp(d, canon,
+                                                           compat, k)]
+    return [c]
+
+def emit_decomp_table(f, name, tbl):
+    f.write("    pure fn %s(c: char, f: fn(char)) -> bool {\n" % name)
+    f.write("        match c {\n")
+    for c in sorted(tbl.keys()):
+        emit_calls(f, escape_char(c), tbl[c])
+    f.write("          _ => return false\n")
+    f.write("        }\n")
+    f.write("        true\n")
+    f.write("    }\n\n")
+
+def emit_normalization_module(f, canon, compat, combining, exclusions):
+    canon_full = {}
+    compat_full = {}
+    for c in canon:
+        canon_full[c] = expand_decomp(c, canon, compat, False)
+    for c in set(canon.keys()) | set(compat.keys()):
+        seq = expand_decomp(c, canon, compat, True)
+        if seq != canon_full.get(c, [c]):
+            compat_full[c] = seq
+
+    # Primary composites: canonical pairs that aren't excluded and don't
+    # start with a non-starter.
+    compositions = {}
+    for c in canon:
+        seq = canon[c]
+        if len(seq) == 2 and c not in exclusions \
+                and combining.get(seq[0], 0) == 0:
+            compositions[(seq[0], seq[1])] = c
+
+    f.write("pub mod normalization {\n")
+    f.write("    use option::{None, Option, Some};\n\n")
+    f.write("    // Hangul syllables are composed and decomposed "
+            "algorithmically\n")
+    f.write("""    const S_BASE: u32 = 0xac00;
+    const L_BASE: u32 = 0x1100;
+    const V_BASE: u32 = 0x1161;
+    const T_BASE: u32 = 0x11a7;
+    const L_COUNT: u32 = 19;
+    const V_COUNT: u32 = 21;
+    const T_COUNT: u32 = 28;
+    const N_COUNT: u32 = V_COUNT * T_COUNT;
+    const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+    /// The canonical combining class of a char
+    pub pure fn canonical_combining_class(c: char) -> u8 {
+        match c {
+""")
+    for (lo, hi, cls) in group_ranges(combining):
+        f.write("          %s => %d,\n" % (emit_pattern(lo, hi), cls))
+    f.write("""          _ => 0
+        }
+    }
+
+    /**
+     * Call `f` with each char of the full canonical decomposition of
+     * `c`. The result is not in canonical order.
+     */
+    pub pure fn decompose_canonical(c: char, f: fn(char)) {
+        d(c, f, false);
+    }
+
+    /**
+     * Call `f` with each char of the full compatibility decomposition of
+     * `c`. The result is not in canonical order.
+     */
+    pub pure fn decompose_compatible(c: char, f: fn(char)) {
+        d(c, f, true);
+    }
+
+    /**
+     * The primary composite of `a` followed by `b`, if there is one. This
+     * is the pairwise step of canonical composition.
+     */
+    pub pure fn compose(a: char, b: char) -> Option<char> {
+        let (a_, b_) = (a as u32, b as u32);
+        if a_ >= L_BASE && a_ < L_BASE + L_COUNT
+            && b_ >= V_BASE && b_ < V_BASE + V_COUNT {
+            let l = a_ - L_BASE, v = b_ - V_BASE;
+            return Some((S_BASE + (l * V_COUNT + v) * T_COUNT) as char);
+        }
+        if a_ >= S_BASE && a_ < S_BASE + S_COUNT
+            && (a_ - S_BASE) % T_COUNT == 0
+            && b_ > T_BASE && b_ < T_BASE + T_COUNT {
+            return Some((a_ + (b_ - T_BASE)) as char);
+        }
+        match (a, b) {
+""")
+    for pair in sorted(compositions.keys()):
+        f.write("          (%s, %s) => Some(%s),\n"
+                % (escape_char(pair[0]), escape_char(pair[1]),
+                   escape_char(compositions[pair])))
+    f.write("""          _ => None
+        }
+    }
+
+    pure fn d(c: char, f: fn(char), k: bool) {
+        // 7-bit ASCII never decomposes
+        if c <= '\\x7f' { f(c); return; }
+
+        let s = c as u32;
+        if s >= S_BASE && s < S_BASE + S_COUNT {
+            let s_index = s - S_BASE;
+            f((L_BASE + s_index / N_COUNT) as char);
+            f((V_BASE + (s_index % N_COUNT) / T_COUNT) as char);
+            if s_index % T_COUNT != 0 {
+                f((T_BASE + s_index % T_COUNT) as char);
+            }
+            return;
+        }
+
+        if k && compatibility(c, f) { return; }
+        if canonical(c, f) { return; }
+        f(c);
+    }
+
+""")
+    emit_decomp_table(f, "canonical", canon_full)
+    emit_decomp_table(f, "compatibility", compat_full)
+    f.write("}\n\n")
+
+grapheme_cats = [("CR", "CR"), ("LF", "LF"), ("Control", "Control"),
+                 ("Extend", "Extend"), ("ZWJ", "ZWJ"),
+                 ("Regional_Indicator", "RegionalIndicator"),
+                 ("Prepend", "Prepend"), ("SpacingMark", "SpacingMark"),
+                 ("L", "HangulL"), ("V", "HangulV"), ("T", "HangulT"),
+                 ("Extended_Pictographic", "ExtendedPictographic")]
+
+def emit_grapheme_module(f, gcb, ext_pict):
+    cats = dict(gcb)
+    for c in ext_pict:
+        cats[c] = "Extended_Pictographic"
+    # LV and LVT alternate through the Hangul syllables block, so they are
+    # told apart arithmetically rather than listed.
+    for c in list(cats.keys()):
+        if cats[c] in ("LV", "LVT"):
+            del cats[c]
+    names = dict(grapheme_cats)
+    f.write("pub mod grapheme {\n")
+    f.write("    /// Grapheme_Cluster_Break property values, as used by\n")
+    f.write("    /// the extended grapheme cluster rules of UAX #29\n")
+    f.write("    #[deriving_eq]\n")
+    f.write("    pub enum GraphemeCat {\n")
+    for (_, name) in grapheme_cats[:-1]:
+        f.write("        %s,\n" % name)
+        if name == "HangulT":
+            f.write("        HangulLV,\n")
+            f.write("        HangulLVT,\n")
+    f.write("        ExtendedPictographic,\n")
+    f.write("        Any,\n")
+    f.write("    }\n\n")
+    f.write("    /// The grapheme cluster break category of a char\n")
+    f.write("    pub pure fn grapheme_category(c: char) -> GraphemeCat {\n")
+    f.write("        match c {\n")
+    for (lo, hi, cat) in group_ranges(cats):
+        if lo < 0xac00 <= hi:
+            raise Exception("range overlaps the Hangul syllables")
+        f.write("          %s => %s,\n" % (emit_pattern(lo, hi),
+                                          names[cat]))
+    f.write("""          '\\uac00' .. '\\ud7a3' => {
+            if ((c as u32) - 0xac00) % 28 == 0 {
+                HangulLV
+            } else {
+                HangulLVT
+            }
+          }
+          _ => Any
+        }
+    }
+}
+""")
+
+preamble = """// Copyright 2012 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[doc(hidden)]; // FIXME #3538
+// NB: transitionary, de-mode-ing.
+#[forbid(deprecated_mode)];
+#[forbid(deprecated_pattern)];
+
+"""
+
 r = "unicode.rs"
 for i in [r]:
     if os.path.exists(i):
         os.remove(i);
 rf = open(r, "w")
+rf.write(preamble)
+rf.write("// Generated by src/etc/unicode.py from version %s of the Unicode\n"
+         "// Character Database.\n\n" % unicode_version)
 
-(canon_decomp, compat_decomp, gencats) = load_unicode_data("UnicodeData.txt")
-emit_decomp_module(rf, canon_decomp, compat_decomp)
+(canon_decomp, compat_decomp, gencats, combining,
+ lower, upper) = load_unicode_data("UnicodeData.txt")
 emit_property_module(rf, "general_category", gencats)
 
 derived = load_derived_core_properties("DerivedCoreProperties.txt")
 emit_property_module(rf, "derived_property", derived)
+
+(special_lower, special_upper) = load_special_casing("SpecialCasing.txt",
+                                                     lower, upper)
+folding = load_case_folding("CaseFolding.txt")
+emit_conversions_module(rf, lower, upper, special_lower, special_upper,
+                        folding)
+
+exclusions = load_property("DerivedNormalizationProps.txt",
+                           ["Full_Composition_Exclusion"])
+emit_normalization_module(rf, canon_decomp, compat_decomp, combining,
+                          exclusions)
+
+gcb = load_property("auxiliary/GraphemeBreakProperty.txt",
+                    [x for (x, _) in grapheme_cats] + ["LV", "LVT"])
+ext_pict = load_property("emoji/emoji-data.txt", ["Extended_Pictographic"])
+emit_grapheme_module(rf, gcb, ext_pict)
@@ -78,6 +78,26 @@ pub pure fn is_uppercase(c: char) -> bool {
     return unicode::general_category::Lu(c);
 }
 
+/**
+ * Convert a char to lowercase using the simple Unicode case mapping. The
+ * char is returned unchanged if it has no lowercase form. See
+ * `str::to_lower` for mappings that produce several chars.
+ */
+#[inline(always)]
+pub pure fn to_lower(c: char) -> char {
+    return unicode::conversions::to_lower(c);
+}
+
+/**
+ * Convert a char to uppercase using the simple Unicode case mapping. The
+ * char is returned unchanged if it has no uppercase form. See
+ * `str::to_upper` for mappings that produce several chars.
+ */
+#[inline(always)]
+pub pure fn to_upper(c: char) -> char {
+    return unicode::conversions::to_upper(c);
+}
+
 /**
  * Indicates whether a character is whitespace. Whitespace is defined in
  * terms of the Unicode General Categories 'Zs', 'Zl', 'Zp'
@@ -228,6 +248,26 @@ fn test_is_uppercase() {
     assert is_uppercase('T');
 }
 
+#[test]
+fn test_to_lower() {
+    assert to_lower('A') == 'a';
+    assert to_lower('a') == 'a';
+    assert to_lower('Ö') == 'ö';
+    assert to_lower('Σ') == 'σ';
+    assert to_lower('\U00010400') == '\U00010428';
+    assert to_lower('1') == '1';
+}
+
+#[test]
+fn test_to_upper() {
+    assert to_upper('z') == 'Z';
+    assert to_upper('ä') == 'Ä';
+    assert to_upper('ß') == 'ß';
+    assert to_upper('ǆ') == 'Ǆ';
+    assert to_upper('\u2c65') == '\u023a';
+    assert to_upper('!') == '!';
+}
+
 #[test]
 fn test_is_whitespace() {
     assert is_whitespace(' ');
@@ -8,6 +8,8 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use vec;
+
 /**
 Clonable types are copied with the clone method
 */
@@ -19,3 +21,131 @@ impl (): Clone {
     #[inline(always)]
     fn clone(&self) -> () { () }
 }
+
+impl<T: Clone> ~T: Clone {
+    /// Return a deep copy of the owned box.
+    #[inline(always)]
+    fn clone(&self) -> ~T { ~(**self).clone() }
+}
+
+impl<T> @T: Clone {
+    /// Return a shallow copy of the managed box.
+    #[inline(always)]
+    fn clone(&self) -> @T { *self }
+}
+
+impl<T: Clone> ~[T]: Clone {
+    /// Return a new vector holding a clone of each element.
+    #[inline(always)]
+    fn clone(&self) -> ~[T] {
+        let mut v = ~[];
+        for vec::each(*self) |elt| { v.push(elt.clone()); }
+        move v
+    }
+}
+
+impl<T> @[T]: Clone {
+    /// Return a shallow copy of the managed vector.
+    #[inline(always)]
+    fn clone(&self) -> @[T] { *self }
+}
+
+impl ~str: Clone {
+    /// Return a new string with the same contents.
+    #[inline(always)]
+    fn clone(&self) -> ~str { copy *self }
+}
+
+impl @str: Clone {
+    /// Return a shallow copy of the managed string.
+    #[inline(always)]
+    fn clone(&self) -> @str { *self }
+}
+
+impl<A: Clone, B: Clone> (A, B): Clone {
+    /// Return a tuple holding a clone of each element.
+    #[inline(always)]
+    fn clone(&self) -> (A, B) {
+        match *self {
+          (ref a, ref b) => (a.clone(), b.clone())
+        }
+    }
+}
+
+impl<A: Clone, B: Clone, C: Clone> (A, B, C): Clone {
+    /// Return a tuple holding a clone of each element.
+    #[inline(always)]
+    fn clone(&self) -> (A, B, C) {
+        match *self {
+          (ref a, ref b, ref c) => (a.clone(), b.clone(), c.clone())
+        }
+    }
+}
+
+macro_rules! clone_impl(
+    ($t:ty) => {
+        impl $t: Clone {
+            #[inline(always)]
+            fn clone(&self) -> $t { *self }
+        }
+    }
+)
+
+clone_impl!(int)
+clone_impl!(i8)
+clone_impl!(i16)
+clone_impl!(i32)
+clone_impl!(i64)
+
+clone_impl!(uint)
+clone_impl!(u8)
+clone_impl!(u16)
+clone_impl!(u32)
+clone_impl!(u64)
+
+clone_impl!(float)
+clone_impl!(f32)
+clone_impl!(f64)
+
+clone_impl!(bool)
+clone_impl!(char)
+
+#[test]
+fn test_owned_clone() {
+    let a : ~int = ~5i;
+    let b : ~int = a.clone();
+    assert a == b;
+}
+
+#[test]
+fn test_managed_clone() {
+    let a : @int = @5i;
+    let b : @int = a.clone();
+    assert a == b;
+}
+
+#[test]
+fn test_vec_clone() {
+    let a = ~[~1, ~2, ~3];
+    let b = a.clone();
+    assert a == b;
+}
+
+#[test]
+fn test_str_clone() {
+    let a = ~"hello";
+    let b = a.clone();
+    assert a == b;
+
+    let c = @"world";
+    assert c.clone() == c;
+}
+
+#[test]
+fn test_tuple_clone() {
+    let a = (~1, ~"two");
+    assert a.clone() == a;
+
+    let b = (1, @[2, 3], 'x');
+    assert b.clone() == b;
+}
@@ -12,16 +12,29 @@
 
 Simple compression
 
+`deflate_bytes` and `inflate_bytes` work on whole buffers at once.
+`DeflateWriter` and `InflateReader` wrap a `Writer` or `Reader` and
+compress or decompress incrementally as data passes through them, in raw
+deflate, zlib or gzip format.
+
 */
 
 // NB: transitionary, de-mode-ing.
 #[forbid(deprecated_mode)];
 #[forbid(deprecated_pattern)];
 
+use dvec;
+use dvec::DVec;
+use io::{Reader, SeekStyle, Writer, WriterType, WriterUtil};
+use io;
 use libc;
-use libc::{c_void, size_t, c_int};
+use libc::{c_void, size_t, c_int, c_ulong};
+use ops::Drop;
+use prelude::*;
 use ptr;
 use rand;
+use rand::RngUtil;
+use uint;
 use vec;
 
 extern mod rustrt {
@@ -34,6 +47,28 @@ extern mod rustrt {
                                            src_buf_len: size_t,
                                            pout_len: *size_t,
                                            flags: c_int) -> *c_void;
+
+    unsafe fn rust_tdefl_new(flags: c_int) -> *c_void;
+
+    unsafe fn tdefl_compress(d: *c_void,
+                             pin_buf: *const c_void,
+                             pin_buf_size: *size_t,
+                             pout_buf: *c_void,
+                             pout_buf_size: *size_t,
+                             flush: c_int) -> c_int;
+
+    unsafe fn rust_tinfl_new() -> *c_void;
+
+    unsafe fn tinfl_decompress(r: *c_void,
+                               pin_buf_next: *const u8,
+                               pin_buf_size: *size_t,
+                               pout_buf_start: *u8,
+                               pout_buf_next: *u8,
+                               pout_buf_size: *size_t,
+                               decomp_flags: u32) -> c_int;
+
+    unsafe fn mz_crc32(crc: c_ulong, ptr: *const u8,
+                       buf_len: size_t) -> c_ulong;
 }
 
 const lz_none : c_int = 0x0;   // Huffman-coding only.
@@ -41,7 +76,64 @@ const lz_fast : c_int = 0x1;   // LZ with only one probe
 const lz_norm : c_int = 0x80;  // LZ with 128 probes, "normal"
 const lz_best : c_int = 0xfff; // LZ with 4095 probes, "best"
 
-pub fn deflate_bytes(bytes: &[const u8]) -> ~[u8] {
+const tdefl_write_zlib_header : c_int = 0x01000;
+const tdefl_greedy_parsing : c_int = 0x04000;
+
+const tdefl_no_flush : c_int = 0;
+const tdefl_sync_flush : c_int = 2;
+const tdefl_finish : c_int = 4;
+
+const tdefl_status_done : c_int = 1;
+
+const tinfl_flag_parse_zlib_header : u32 = 1;
+const tinfl_flag_has_more_input : u32 = 2;
+
+const tinfl_status_adler32_mismatch : c_int = -2;
+const tinfl_status_done : c_int = 0;
+const tinfl_status_needs_more_input : c_int = 1;
+
+// The decompressor needs an output window at least this large, so that it
+// can look back at everything a match may refer to.
+const tinfl_lz_dict_size : uint = 32768u;
+
+const gzip_fhcrc : u8 = 0x02;
+const gzip_fextra : u8 = 0x04;
+const gzip_fname : u8 = 0x08;
+const gzip_fcomment : u8 = 0x10;
+const gzip_reserved : u8 = 0xe0;
+
+/// The fastest compression level that still looks for matches
+pub const fast_compression : uint = 1u;
+/// The compression level used by `deflate_bytes`
+pub const default_compression : uint = 6u;
+/// The compression level that trades the most speed for size
+pub const best_compression : uint = 9u;
+
+/// The container format around a deflate stream
+#[deriving_eq]
+pub enum Format {
+    /// Bare deflate data, as produced by `deflate_bytes`
+    Raw,
+    /// A zlib stream (RFC 1950), ending in an Adler-32 checksum
+    Zlib,
+    /// A single gzip member (RFC 1952), ending in a CRC-32 checksum
+    Gzip,
+}
+
+/**
+ * Translate a zlib-style compression level into compressor flags. Levels
+ * run from 0, which only does Huffman coding, to 10; anything higher is
+ * treated as 10. As in zlib, levels up to 3 use greedy parsing.
+ */
+fn level_flags(level: uint) -> c_int {
+    let probes = match level {
+      0u => 0, 1u => 1, 2u => 6, 3u => 32, 4u => 16, 5u => 32,
+      6u => 128, 7u => 256, 8u => 512, 9u => 768, _ => 1500
+    };
+    if level <= 3u { probes | tdefl_greedy_parsing } else { probes }
+}
+
+fn deflate_bytes_flags(bytes: &[const u8], flags: c_int) -> ~[u8] {
     do vec::as_const_buf(bytes) |b, len| {
         unsafe {
             let mut outsz : size_t = 0;
@@ -49,7 +141,7 @@ pub fn deflate_bytes(bytes: &[const u8]) -> ~[u8] {
                 rustrt::tdefl_compress_mem_to_heap(b as *c_void,
                                                    len as size_t,
                                                    ptr::addr_of(&outsz),
-                                                   lz_norm);
+                                                   flags);
             assert res as int != 0;
             let out = vec::raw::from_buf_raw(res as *u8,
                                             outsz as uint);
@@ -59,6 +151,15 @@ pub fn deflate_bytes(bytes: &[const u8]) -> ~[u8] {
     }
 }
 
+pub fn deflate_bytes(bytes: &[const u8]) -> ~[u8] {
+    deflate_bytes_flags(bytes, lz_norm)
+}
+
+/// Compress `bytes` to raw deflate data at compression level `level`
+pub fn deflate_bytes_level(bytes: &[const u8], level: uint) -> ~[u8] {
+    deflate_bytes_flags(bytes, level_flags(level))
+}
+
 pub fn inflate_bytes(bytes: &[const u8]) -> ~[u8] {
     do vec::as_const_buf(bytes) |b, len| {
         unsafe {
@@ -77,6 +178,452 @@ pub fn inflate_bytes(bytes: &[const u8]) -> ~[u8] {
     }
 }
 
+/// Update the CRC-32 checksum `crc` with `bytes`. Checksums start at 0.
+pub fn crc32(crc: u32, bytes: &[const u8]) -> u32 {
+    do vec::as_const_buf(bytes) |b, len| {
+        unsafe {
+            rustrt::mz_crc32(crc as c_ulong, b, len as size_t) as u32
+        }
+    }
+}
+
+fn le_u32(bytes: &[u8]) -> u32 {
+    let mut n = 0u32;
+    for vec::rev_each(bytes) |b| {
+        n = (n << 8) | (*b as u32);
+    }
+    n
+}
+
+/**
+ * Wraps a writer and compresses everything written to it. Compressed data
+ * is passed on to the underlying writer in chunks as the compressor
+ * produces it.
+ *
+ * The stream must be ended with `finish`, which happens automatically
+ * when the `DeflateWriter` is dropped. Flushing the writer ends the
+ * current deflate block, so that everything written so far can be
+ * decompressed, at some cost in compression.
+ */
+pub struct DeflateWriter<W> {
+    priv base: W,
+    priv state: *c_void,
+    priv format: Format,
+    priv buf: DVec<u8>,
+    priv mut crc: u32,
+    priv mut total_in: uint,
+    priv mut finished: bool,
+}
+
+pub impl<W: Writer> DeflateWriter<W> {
+    /**
+     * Wrap `base` in a writer that compresses to `format` at compression
+     * level `level`, from 0 (fastest) to 10 (smallest). For gzip, the
+     * header is written to `base` straight away.
+     */
+    static fn new(base: W, format: Format, level: uint) -> DeflateWriter<W> {
+        let mut flags = level_flags(level);
+        if format == Zlib {
+            flags |= tdefl_write_zlib_header;
+        }
+        let state = unsafe { rustrt::rust_tdefl_new(flags) };
+        assert state as int != 0;
+        if format == Gzip {
+            // Magic, deflate, no flags, no mtime, no extra flags and an
+            // unknown OS.
+            base.write([0x1f, 0x8b, 8, 0, 0, 0, 0, 0, 0, 255]);
+        }
+        DeflateWriter {
+            base: move base,
+            state: state,
+            format: format,
+            buf: DVec(),
+            mut crc: 0u32,
+            mut total_in: 0u,
+            mut finished: false
+        }
+    }
+
+    /// Returns a reference to the underlying writer.
+    fn get_ref(&self) -> &self/W { &self.base }
+
+    /**
+     * Write out the end of the compressed stream, followed by the checksum
+     * for zlib and gzip. Nothing more may be written afterwards.
+     */
+    fn finish(&self) {
+        if self.finished { return; }
+        self.compress([], tdefl_finish);
+        self.finished = true;
+        if self.format == Gzip {
+            self.base.write_le_u32(self.crc);
+            self.base.write_le_u32(self.total_in as u32);
+        }
+    }
+
+    /**
+     * Feed `input` to the compressor and pass whatever it produces on to
+     * the underlying writer, until all of `input` has been consumed and
+     * `flush` has been carried out.
+     */
+    priv fn compress(&self, input: &[const u8], flush: c_int) {
+        assert !self.finished;
+        let state = self.state;
+        let capacity = io::default_buffer_capacity;
+        do self.buf.swap |buf| {
+            let mut buf = move buf;
+            vec::reserve(&mut buf, capacity);
+            unsafe { vec::raw::set_len(&mut buf, capacity); }
+            let mut pos = 0u;
+            loop {
+                let mut in_size = (input.len() - pos) as size_t;
+                let mut out_size = capacity as size_t;
+                let status = do vec::as_const_buf(input) |ibuf, _len| {
+                    do vec::as_mut_buf(buf) |obuf, _len| {
+                        unsafe {
+                            rustrt::tdefl_compress(
+                                state,
+                                ptr::const_offset(ibuf, pos) as *const c_void,
+                                ptr::addr_of(&in_size),
+                                obuf as *c_void,
+                                ptr::addr_of(&out_size),
+                                flush)
+                        }
+                    }
+                };
+                if status < 0 {
+                    fail ~"deflate failed";
+                }
+                pos += in_size as uint;
+                let out_size = out_size as uint;
+                if out_size > 0u {
+                    self.base.write(vec::view(buf, 0u, out_size));
+                }
+                // The compressor has caught up once it has taken all of
+                // the input without filling the output buffer.
+                if status == tdefl_status_done ||
+                   (flush != tdefl_finish && pos == input.len() &&
+                    out_size < capacity) {
+                    break;
+                }
+            }
+            move buf
+        }
+    }
+}
+
+impl<W: Writer> DeflateWriter<W>: Writer {
+    fn write(&self, v: &[const u8]) {
+        self.compress(v, tdefl_no_flush);
+        if self.format == Gzip {
+            self.crc = crc32(self.crc, v);
+        }
+        self.total_in += v.len();
+    }
+    fn seek(&self, _offset: int, _whence: SeekStyle) {
+        fail ~"cannot seek in a compressed stream";
+    }
+    fn tell(&self) -> uint { self.total_in }
+    fn flush(&self) -> int {
+        if !self.finished {
+            self.compress([], tdefl_sync_flush);
+        }
+        self.base.flush()
+    }
+    fn get_type(&self) -> WriterType { self.base.get_type() }
+}
+
+impl<W: Writer> DeflateWriter<W>: Drop {
+    fn finalize(&self) {
+        self.finish();
+        unsafe { libc::free(self.state); }
+    }
+}
+
+/**
+ * Wraps a reader of compressed data and decompresses it as it is read,
+ * reading from the underlying reader in chunks as needed. Only a window of
+ * the output is kept in memory, however long the stream is.
+ *
+ * Reading fails if the compressed data is corrupt or truncated, or if its
+ * checksum does not match.
+ */
+pub struct InflateReader<R> {
+    priv base: R,
+    priv state: *c_void,
+    priv format: Format,
+    priv input: DVec<u8>,
+    priv mut in_pos: uint,
+    priv mut in_eof: bool,
+    // The decompressor writes into this circular window at `window_pos`;
+    // what it produced last and has not been read yet is between
+    // `out_pos` and `out_end`.
+    priv window: DVec<u8>,
+    priv mut window_pos: uint,
+    priv mut out_pos: uint,
+    priv mut out_end: uint,
+    priv mut done: bool,
+    priv mut crc: u32,
+    priv mut total_out: uint,
+}
+
+pub impl<R: Reader> InflateReader<R> {
+    /**
+     * Wrap `base` in a reader that decompresses `format` data. For gzip,
+     * the header is read from `base` straight away, and an error is
+     * returned if it is not a valid gzip header.
+     */
+    static fn new(base: R, format: Format)
+        -> Result<InflateReader<R>, ~str> {
+        let state = unsafe { rustrt::rust_tinfl_new() };
+        assert state as int != 0;
+        let r = InflateReader {
+            base: move base,
+            state: state,
+            format: format,
+            input: DVec(),
+            mut in_pos: 0u,
+            mut in_eof: false,
+            window: dvec::from_vec(vec::from_elem(tinfl_lz_dict_size, 0u8)),
+            mut window_pos: 0u,
+            mut out_pos: 0u,
+            mut out_end: 0u,
+            mut done: false,
+            mut crc: 0u32,
+            mut total_out: 0u
+        };
+        if format == Gzip {
+            match r.read_gzip_header() {
+              Ok(()) => (),
+              Err(move e) => return Err(move e)
+            }
+        }
+        Ok(move r)
+    }
+
+    /// Returns a reference to the underlying reader.
+    fn get_ref(&self) -> &self/R { &self.base }
+
+    /**
+     * Refill the input buffer from the underlying reader if it has been
+     * used up. Returns false if there is no input left.
+     */
+    priv fn fill_input(&self) -> bool {
+        if self.in_pos == self.input.len() && !self.in_eof {
+            let capacity = io::default_buffer_capacity;
+            do self.input.swap |buf| {
+                let mut buf = move buf;
+                vec::reserve(&mut buf, capacity);
+                unsafe { vec::raw::set_len(&mut buf, capacity); }
+                let count = self.base.read(buf, capacity);
+                unsafe { vec::raw::set_len(&mut buf, count); }
+                move buf
+            }
+            self.in_pos = 0u;
+            self.in_eof = self.input.len() == 0u;
+        }
+        self.in_pos < self.input.len()
+    }
+
+    /**
+     * Move the next `n` bytes of input onto the end of `v`. Returns false
+     * if the input runs out first.
+     */
+    priv fn take_input(&self, v: &mut ~[u8], n: uint) -> bool {
+        for n.times {
+            if !self.fill_input() { return false; }
+            v.push(self.input.get_elt(self.in_pos));
+            self.in_pos += 1u;
+        }
+        true
+    }
+
+    /**
+     * Move input up to and including the next zero byte onto the end of
+     * `v`. Returns false if the input runs out first.
+     */
+    priv fn take_c_str(&self, v: &mut ~[u8]) -> bool {
+        loop {
+            if !self.take_input(v, 1u) { return false; }
+            if vec::last(*v) == 0u8 { return true; }
+        }
+    }
+
+    /// Read and check a gzip member header, skipping any optional fields.
+    priv fn read_gzip_header(&self) -> Result<(), ~str> {
+        let mut header = ~[];
+        if !self.take_input(&mut header, 10u) {
+            return Err(~"truncated gzip header");
+        }
+        if header[0] != 0x1f || header[1] != 0x8b {
+            return Err(~"not in gzip format");
+        }
+        if header[2] != 8 {
+            return Err(~"unknown gzip compression method");
+        }
+        let flags = header[3];
+        if (flags & gzip_reserved) != 0 {
+            return Err(~"unknown gzip header flags");
+        }
+        let mut complete = true;
+        if (flags & gzip_fextra) != 0 {
+            complete = self.take_input(&mut header, 2u);
+            if complete {
+                let n = header.len();
+                let xlen = header[n - 2u] as uint |
+                    (header[n - 1u] as uint << 8);
+                complete = self.take_input(&mut header, xlen);
+            }
+        }
+        if complete && (flags & gzip_fname) != 0 {
+            complete = self.take_c_str(&mut header);
+        }
+        if complete && (flags & gzip_fcomment) != 0 {
+            complete = self.take_c_str(&mut header);
+        }
+        if complete && (flags & gzip_fhcrc) != 0 {
+            let expected = crc32(0u32, header) & 0xffff;
+            complete = self.take_input(&mut header, 2u);
+            if complete {
+                let n = header.len();
+                let found = header[n - 2u] as u32 |
+                    (header[n - 1u] as u32 << 8);
+                if found != expected {
+                    return Err(~"gzip header checksum mismatch");
+                }
+            }
+        }
+        if complete { Ok(()) } else { Err(~"truncated gzip header") }
+    }
+
+    /// Read the gzip trailer and check it against what was decompressed.
+    priv fn check_gzip_trailer(&self) {
+        let mut trailer = ~[];
+        if !self.take_input(&mut trailer, 8u) {
+            fail ~"truncated gzip trailer";
+        }
+        let crc = le_u32(vec::view(trailer, 0u, 4u));
+        let size = le_u32(vec::view(trailer, 4u, 8u));
+        if crc != self.crc {
+            fail ~"gzip checksum mismatch";
+        }
+        if size != self.total_out as u32 {
+            fail ~"gzip length mismatch";
+        }
+    }
+
+    /**
+     * Run the decompressor until it has produced some output or the end of
+     * the stream has been reached. Does nothing while earlier output is
+     * still unread.
+     */
+    priv fn inflate(&self) {
+        while !self.done && self.out_pos == self.out_end {
+            self.fill_input();
+            // The decompressor never reads past the end of the stream, so
+            // it can always be told to expect more input, and will ask for
+            // it if the stream is truncated.
+            let mut flags = tinfl_flag_has_more_input;
+            if self.format == Zlib {
+                flags |= tinfl_flag_parse_zlib_header;
+            }
+            let state = self.state;
+            let in_pos = self.in_pos;
+            let window_pos = self.window_pos;
+            let mut in_size = (self.input.len() - in_pos) as size_t;
+            let mut out_size = (tinfl_lz_dict_size - window_pos) as size_t;
+            let status = do self.input.borrow |input| {
+                do vec::as_imm_buf(input) |ibuf, _len| {
+                    do self.window.borrow_mut |window| {
+                        do vec::as_mut_buf(window) |obuf, _len| {
+                            unsafe {
+                                rustrt::tinfl_decompress(
+                                    state,
+                                    ptr::offset(ibuf, in_pos) as *const u8,
+                                    ptr::addr_of(&in_size),
+                                    obuf as *u8,
+                                    ptr::mut_offset(obuf, window_pos) as *u8,
+                                    ptr::addr_of(&out_size),
+                                    flags)
+                            }
+                        }
+                    }
+                }
+            };
+            self.in_pos += in_size as uint;
+            let produced = out_size as uint;
+            if produced > 0u {
+                if self.format == Gzip {
+                    let crc = self.crc;
+                    self.crc = do self.window.borrow |window| {
+                        crc32(crc, vec::view(window, window_pos,
+                                             window_pos + produced))
+                    };
+                }
+                self.out_pos = window_pos;
+                self.out_end = window_pos + produced;
+                self.window_pos = (window_pos + produced) &
+                    (tinfl_lz_dict_size - 1u);
+                self.total_out += produced;
+            }
+            if status == tinfl_status_done {
+                self.done = true;
+                if self.format == Gzip {
+                    self.check_gzip_trailer();
+                }
+            } else if status == tinfl_status_adler32_mismatch {
+                fail ~"zlib checksum mismatch";
+            } else if status < 0 {
+                fail ~"corrupt deflate stream";
+            } else if status == tinfl_status_needs_more_input &&
+                      self.in_eof {
+                fail ~"truncated deflate stream";
+            }
+        }
+    }
+}
+
+impl<R: Reader> InflateReader<R>: Reader {
+    fn read(&self, bytes: &[mut u8], len: uint) -> uint {
+        let mut count = 0u;
+        while count < len {
+            self.inflate();
+            if self.out_pos == self.out_end { break; }
+            let pos = self.out_pos;
+            let n = uint::min(len - count, self.out_end - pos);
+            do self.window.borrow |window| {
+                let dst = vec::mut_view(bytes, count, count + n);
+                vec::bytes::copy_memory(dst, vec::view(window, pos, pos + n),
+                                        n);
+            }
+            self.out_pos += n;
+            count += n;
+        }
+        count
+    }
+    fn read_byte(&self) -> int {
+        self.inflate();
+        if self.out_pos == self.out_end { return -1; }
+        let b = self.window.get_elt(self.out_pos);
+        self.out_pos += 1u;
+        b as int
+    }
+    fn eof(&self) -> bool {
+        self.inflate();
+        self.out_pos == self.out_end
+    }
+    fn seek(&self, _offset: int, _whence: SeekStyle) {
+        fail ~"cannot seek in a compressed stream";
+    }
+    fn tell(&self) -> uint { self.total_out - (self.out_end - self.out_pos) }
+}
+
+impl<R: Reader> InflateReader<R>: Drop {
+    fn finalize(&self) {
+        unsafe { libc::free(self.state); }
+    }
+}
+
 #[test]
 #[allow(non_implicitly_copyable_typarams)]
 fn test_flate_round_trip() {
@@ -100,3 +647,148 @@ fn test_flate_round_trip() {
         assert(in == out);
     }
 }
+
+#[test]
+fn test_deflate_levels() {
+    let r = rand::Rng();
+    let mut in = ~[];
+    for 1000.times {
+        in.push_all(r.gen_bytes(r.gen_uint_range(1, 4)));
+        in.push_all(str::to_bytes("a repeated phrase "));
+    }
+    for uint::range(0u, 12u) |level| {
+        let cmp = deflate_bytes_level(in, level);
+        assert(inflate_bytes(cmp) == in);
+    }
+    assert deflate_bytes_level(in, best_compression).len() <
+        deflate_bytes_level(in, 0u).len();
+}
+
+#[test]
+fn test_crc32() {
+    assert crc32(0u32, str::to_bytes("123456789")) == 0xcbf43926u32;
+    let first = crc32(0u32, str::to_bytes("1234"));
+    assert crc32(first, str::to_bytes("56789")) == 0xcbf43926u32;
+}
+
+#[cfg(test)]
+fn inflate_all(bytes: &[u8], format: Format) -> ~[u8] {
+    do io::with_bytes_reader(bytes) |rdr| {
+        match InflateReader::new(rdr, format) {
+          Ok(move r) => r.read_whole_stream(),
+          Err(move e) => fail e
+        }
+    }
+}
+
+#[test]
+#[allow(non_implicitly_copyable_typarams)]
+fn test_stream_round_trip() {
+    let r = rand::Rng();
+    let mut words = ~[];
+    for 20.times {
+        words.push(r.gen_bytes(r.gen_uint_range(1, 10)));
+    }
+    // Long enough to wrap around the decompressor's window a few times.
+    let mut in = ~[];
+    for 20000.times {
+        in.push_all(r.choose(words));
+    }
+    for [Raw, Zlib, Gzip].each |format| {
+        let cmp = do io::with_bytes_writer |wr| {
+            let d = DeflateWriter::new(wr, *format, default_compression);
+            // Write in uneven pieces to exercise the buffering.
+            let mut pos = 0u;
+            while pos < in.len() {
+                let end = uint::min(in.len(),
+                                    pos + r.gen_uint_range(1, 5000));
+                d.write(vec::view(in, pos, end));
+                pos = end;
+            }
+            assert d.tell() == in.len();
+            d.finish();
+        };
+        assert cmp.len() < in.len();
+        assert inflate_all(cmp, *format) == in;
+        if *format == Raw {
+            assert inflate_bytes(cmp) == in;
+        }
+    }
+}
+
+#[test]
+fn test_stream_flush() {
+    let wr = @io::BytesWriter();
+    let d = DeflateWriter::new(wr as Writer, Zlib, default_compression);
+    d.write(str::to_bytes("abc"));
+    d.flush();
+    let partial = wr.bytes.get();
+    do io::with_bytes_reader(partial) |rdr| {
+        match InflateReader::new(rdr, Zlib) {
+          Ok(move r) => assert r.read_bytes(3u) == str::to_bytes("abc"),
+          Err(move e) => fail e
+        }
+    }
+    d.write(str::to_bytes("def"));
+    d.finish();
+    assert inflate_all(wr.bytes.get(), Zlib) == str::to_bytes("abcdef");
+}
+
+#[test]
+fn test_empty_stream() {
+    for [Raw, Zlib, Gzip].each |format| {
+        let cmp = do io::with_bytes_writer |wr| {
+            DeflateWriter::new(wr, *format, default_compression).finish();
+        };
+        assert inflate_all(cmp, *format).is_empty();
+    }
+}
+
+// A gzip member with every optional header field: extra data, a file
+// name, a comment and a header checksum.
+#[cfg(test)]
+const gzip_hello: [u8 * 49] = [
+    0x1f, 0x8b, 0x08, 0x1e, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x03, 0x00,
+    0x61, 0x62, 0x63, 0x61, 0x2e, 0x74, 0x78, 0x74, 0x00, 0x68, 0x69, 0x00,
+    0x21, 0x20, 0xcb, 0x48, 0xcd, 0xc9, 0xc9, 0xd7, 0x51, 0x28, 0xcf, 0x2f,
+    0xca, 0x49, 0xe1, 0x02, 0x00, 0x53, 0x74, 0x24, 0xf4, 0x0d, 0x00, 0x00,
+    0x00
+];
+
+#[test]
+fn test_gzip_header_fields() {
+    assert inflate_all(gzip_hello, Gzip) ==
+        str::to_bytes("hello, world\n");
+}
+
+#[test]
+fn test_gzip_bad_header() {
+    let mut bytes = vec::from_slice(gzip_hello);
+    bytes[24] ^= 1;
+    do io::with_bytes_reader(bytes) |rdr| {
+        assert InflateReader::new(rdr, Gzip).is_err();
+    }
+    do io::with_bytes_reader(vec::view(gzip_hello, 0u, 18u)) |rdr| {
+        assert InflateReader::new(rdr, Gzip).is_err();
+    }
+    do io::with_bytes_reader(deflate_bytes(gzip_hello)) |rdr| {
+        assert InflateReader::new(rdr, Gzip).is_err();
+    }
+}
+
+#[test]
+#[should_fail]
+#[ignore(cfg(windows))]
+fn test_gzip_bad_checksum() {
+    let mut bytes = vec::from_slice(gzip_hello);
+    bytes[41] ^= 1;
+    inflate_all(bytes, Gzip);
+}
+
+#[test]
+#[should_fail]
+#[ignore(cfg(windows))]
+fn test_truncated_stream() {
+    let cmp = deflate_bytes(str::to_bytes("hello, hello, hello, world"));
+    inflate_all(vec::view(cmp, 0u, cmp.len() - 2u), Raw);
+}
@@ -78,6 +78,15 @@ pub trait Streaming {
     fn reset();
 }
 
+/**
+ * Creates a streaming hash function keyed with `k0` and `k1`.
+ *
+ * Hashtables can be given one of these to hash their keys with something
+ * other than SipHash. It is a bare function rather than a closure so that
+ * the tables using it remain sendable.
+ */
+pub type StreamingFactory = extern fn(k0: u64, k1: u64) -> @Streaming;
+
 impl <A: IterBytes> A: Hash {
     #[inline(always)]
     pure fn hash_keyed(k0: u64, k1: u64) -> u64 {
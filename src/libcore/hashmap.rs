@@ -25,11 +25,12 @@ pub mod linear {
     use container::{Container, Mutable, Map, Set};
     use cmp::Eq;
     use cmp;
-    use hash::Hash;
+    use hash::{Hash, StreamingFactory};
     use kinds::Copy;
     use option::{None, Option, Some};
     use option;
     use rand;
+    use rand::RngUtil;
     use to_bytes::IterBytes;
     use uint;
     use vec;
@@ -45,6 +46,7 @@ pub mod linear {
     pub struct LinearMap<K: Eq Hash, V> {
         k0: u64,
         k1: u64,
+        hasher: Option<StreamingFactory>,
         resize_at: uint,
         size: uint,
         buckets: ~[Option<Bucket<K, V>>],
@@ -63,16 +65,23 @@ pub mod linear {
 
     pub fn linear_map_with_capacity<K: Eq Hash, V>(
         initial_capacity: uint) -> LinearMap<K, V> {
-        let r = rand::Rng();
+        let r = rand::task_rng();
         linear_map_with_capacity_and_keys(r.gen_u64(), r.gen_u64(),
                                           initial_capacity)
     }
 
-    pure fn linear_map_with_capacity_and_keys<K: Eq Hash, V>(
+    /**
+     * Create a LinearMap whose hash function is keyed with `k0` and `k1`
+     * rather than random keys. Maps with known keys are open to
+     * hash-flooding by whoever can choose their contents, so this is
+     * meant for tests that need a reproducible layout.
+     */
+    pub pure fn linear_map_with_capacity_and_keys<K: Eq Hash, V>(
         k0: u64, k1: u64,
         initial_capacity: uint) -> LinearMap<K, V> {
         LinearMap {
             k0: k0, k1: k1,
+            hasher: None,
             resize_at: resize_at(initial_capacity),
             size: 0,
             buckets: vec::from_fn(initial_capacity, |_| None)
@@ -80,6 +89,20 @@ pub mod linear {
     }
 
     priv impl<K: Hash IterBytes Eq, V> LinearMap<K, V> {
+        #[inline(always)]
+        pure fn hash_key(&self, k: &K) -> uint {
+            match self.hasher {
+                None => k.hash_keyed(self.k0, self.k1) as uint,
+                Some(hasher) => unsafe {
+                    let s = hasher(self.k0, self.k1);
+                    for k.iter_bytes(true) |bytes| {
+                        s.input(bytes);
+                    }
+                    s.result_u64() as uint
+                }
+            }
+        }
+
         #[inline(always)]
         pure fn to_bucket(&self, h: uint) -> uint {
             // FIXME(#3041) borrow a more sophisticated technique here from
@@ -116,7 +139,7 @@ pub mod linear {
         #[inline(always)]
         pure fn bucket_for_key(&self, buckets: &[Option<Bucket<K, V>>],
                                k: &K) -> SearchResult {
-            let hash = k.hash_keyed(self.k0, self.k1) as uint;
+            let hash = self.hash_key(k);
             self.bucket_for_key_with_hash(buckets, hash, k)
         }
 
@@ -323,7 +346,7 @@ pub mod linear {
                 self.expand();
             }
 
-            let hash = k.hash_keyed(self.k0, self.k1) as uint;
+            let hash = self.hash_key(&k);
             self.insert_internal(hash, k, v)
         }
 
@@ -340,14 +363,34 @@ pub mod linear {
             linear_map_with_capacity(INITIAL_CAPACITY)
         }
 
+        /**
+         * Create an empty LinearMap whose hash function is keyed with `k0`
+         * and `k1`. See `linear_map_with_capacity_and_keys`.
+         */
+        static fn with_keys(k0: u64, k1: u64) -> LinearMap<K, V> {
+            linear_map_with_capacity_and_keys(k0, k1, INITIAL_CAPACITY)
+        }
+
+        /**
+         * Create an empty LinearMap that hashes its keys with the streaming
+         * hash function made by `hasher`, keyed with `k0` and `k1`
+         */
+        static fn with_hasher(k0: u64, k1: u64,
+                              hasher: StreamingFactory) -> LinearMap<K, V> {
+            let mut map = linear_map_with_capacity_and_keys(
+                k0, k1, INITIAL_CAPACITY);
+            map.hasher = Some(hasher);
+            move map
+        }
+
         fn pop(&mut self, k: &K) -> Option<V> {
-            let hash = k.hash_keyed(self.k0, self.k1) as uint;
+            let hash = self.hash_key(k);
             self.pop_internal(hash, k)
         }
 
         fn swap(&mut self, k: K, v: V) -> Option<V> {
             // this could be faster.
-            let hash = k.hash_keyed(self.k0, self.k1) as uint;
+            let hash = self.hash_key(&k);
             let old_value = self.pop_internal(hash, &k);
 
             if self.size >= self.resize_at {
@@ -474,13 +517,31 @@ pub mod linear {
     pub impl <T: Hash IterBytes Eq> LinearSet<T> {
         /// Create an empty LinearSet
         static fn new() -> LinearSet<T> { LinearSet{map: LinearMap::new()} }
+
+        /**
+         * Create an empty LinearSet whose hash function is keyed with `k0`
+         * and `k1`. See `linear_map_with_capacity_and_keys`.
+         */
+        static fn with_keys(k0: u64, k1: u64) -> LinearSet<T> {
+            LinearSet{map: LinearMap::with_keys(k0, k1)}
+        }
+
+        /**
+         * Create an empty LinearSet that hashes its values with the
+         * streaming hash function made by `hasher`, keyed with `k0` and `k1`
+         */
+        static fn with_hasher(k0: u64, k1: u64,
+                              hasher: StreamingFactory) -> LinearSet<T> {
+            LinearSet{map: LinearMap::with_hasher(k0, k1, hasher)}
+        }
     }
 }
 
 #[test]
 pub mod test {
     use option::{None, Some};
-    use hashmap::linear::LinearMap;
+    use hash::Streaming;
+    use hashmap::linear::{LinearMap, LinearSet};
     use hashmap::linear;
     use uint;
 
@@ -624,4 +685,65 @@ pub mod test {
         assert m.len() == i;
         assert !m.is_empty();
     }
+
+    #[test]
+    pub fn test_with_keys() {
+        let mut m = LinearMap::with_keys(1, 2);
+        assert m.insert(1, 2);
+        assert m.insert(2, 4);
+        assert m.k0 == 1 && m.k1 == 2;
+        assert m.find_copy(&1) == Some(2);
+        assert m.find_copy(&2) == Some(4);
+
+        let mut s = LinearSet::with_keys(1, 2);
+        assert s.insert(1);
+        assert s.contains(&1);
+    }
+
+    #[test]
+    pub fn test_random_keys() {
+        let m1: LinearMap<int, int> = LinearMap::new();
+        let m2: LinearMap<int, int> = LinearMap::new();
+        assert m1.k0 != m2.k0 || m1.k1 != m2.k1;
+    }
+
+    struct SumState {
+        k0: u64,
+        mut sum: u64
+    }
+
+    impl @SumState: Streaming {
+        fn input(buf: &[const u8]) {
+            for buf.each |b| { self.sum += *b as u64; }
+        }
+        fn result_bytes() -> ~[u8] { ~[] }
+        fn result_str() -> ~str { ~"" }
+        fn result_u64() -> u64 { self.k0 + self.sum }
+        fn reset() { self.sum = 0; }
+    }
+
+    fn sum_hasher(k0: u64, _k1: u64) -> @Streaming {
+        @SumState { k0: k0, sum: 0 } as @Streaming
+    }
+
+    #[test]
+    pub fn test_with_hasher() {
+        // "ab" and "ba" collide under this hash, so lookups fall back to
+        // probing but must still find the right entries.
+        let mut m = LinearMap::with_hasher(0, 0, sum_hasher);
+        assert m.insert(~"ab", 1);
+        assert m.insert(~"ba", 2);
+        assert m.insert(~"c", 3);
+        assert m.len() == 3;
+        assert m.find_copy(&~"ab") == Some(1);
+        assert m.find_copy(&~"ba") == Some(2);
+        assert m.pop(&~"ab") == Some(1);
+        assert m.find(&~"ab").is_none();
+        assert m.find_copy(&~"ba") == Some(2);
+
+        let mut s = LinearSet::with_hasher(7, 0, sum_hasher);
+        assert s.insert(10);
+        assert !s.insert(10);
+        assert s.contains(&10);
+    }
 }
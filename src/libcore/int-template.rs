@@ -69,6 +69,109 @@ pub pure fn div(x: T, y: T) -> T { x / y }
 #[inline(always)]
 pub pure fn rem(x: T, y: T) -> T { x % y }
 
+/// Returns `x + y`, or `None` if the sum overflows
+#[inline(always)]
+pub pure fn checked_add(x: T, y: T) -> Option<T> {
+    match inst::add_with_overflow(x, y) {
+        (r, false) => Some(r),
+        (_, true) => None
+    }
+}
+/// Returns `x - y`, or `None` if the difference overflows
+#[inline(always)]
+pub pure fn checked_sub(x: T, y: T) -> Option<T> {
+    match inst::sub_with_overflow(x, y) {
+        (r, false) => Some(r),
+        (_, true) => None
+    }
+}
+/// Returns `x * y`, or `None` if the product overflows
+#[inline(always)]
+pub pure fn checked_mul(x: T, y: T) -> Option<T> {
+    match inst::mul_with_overflow(x, y) {
+        (r, false) => Some(r),
+        (_, true) => None
+    }
+}
+/// Returns `x / y`, or `None` if `y` is zero or the quotient overflows
+#[inline(always)]
+pub pure fn checked_div(x: T, y: T) -> Option<T> {
+    if y == 0 as T || (x == min_value && y == -1 as T) {
+        None
+    } else {
+        Some(x / y)
+    }
+}
+
+/// Returns `x + y`, wrapping around at the bounds of the type
+#[inline(always)]
+pub pure fn wrapping_add(x: T, y: T) -> T {
+    let (r, _) = inst::add_with_overflow(x, y);
+    r
+}
+/// Returns `x - y`, wrapping around at the bounds of the type
+#[inline(always)]
+pub pure fn wrapping_sub(x: T, y: T) -> T {
+    let (r, _) = inst::sub_with_overflow(x, y);
+    r
+}
+/// Returns `x * y`, wrapping around at the bounds of the type
+#[inline(always)]
+pub pure fn wrapping_mul(x: T, y: T) -> T {
+    let (r, _) = inst::mul_with_overflow(x, y);
+    r
+}
+/**
+ * Returns `x / y`, wrapping around at the bounds of the type. The only
+ * quotient that overflows is `min_value / -1`, which wraps to `min_value`.
+ *
+ * # Failure
+ *
+ * Fails if `y` is zero.
+ */
+#[inline(always)]
+pub pure fn wrapping_div(x: T, y: T) -> T {
+    if x == min_value && y == -1 as T { min_value } else { x / y }
+}
+
+/// Returns `x + y`, clamped to `min_value` or `max_value` on overflow
+#[inline(always)]
+pub pure fn saturating_add(x: T, y: T) -> T {
+    match inst::add_with_overflow(x, y) {
+        (r, false) => r,
+        (_, true) => if y > 0 as T { max_value } else { min_value }
+    }
+}
+/// Returns `x - y`, clamped to `min_value` or `max_value` on overflow
+#[inline(always)]
+pub pure fn saturating_sub(x: T, y: T) -> T {
+    match inst::sub_with_overflow(x, y) {
+        (r, false) => r,
+        (_, true) => if y < 0 as T { max_value } else { min_value }
+    }
+}
+/// Returns `x * y`, clamped to `min_value` or `max_value` on overflow
+#[inline(always)]
+pub pure fn saturating_mul(x: T, y: T) -> T {
+    match inst::mul_with_overflow(x, y) {
+        (r, false) => r,
+        (_, true) => {
+            if (x < 0 as T) == (y < 0 as T) { max_value } else { min_value }
+        }
+    }
+}
+/**
+ * Returns `x / y`, clamped to `max_value` on overflow
+ *
+ * # Failure
+ *
+ * Fails if `y` is zero.
+ */
+#[inline(always)]
+pub pure fn saturating_div(x: T, y: T) -> T {
+    if x == min_value && y == -1 as T { max_value } else { x / y }
+}
+
 #[inline(always)]
 pub pure fn lt(x: T, y: T) -> bool { x < y }
 #[inline(always)]
@@ -416,3 +519,67 @@ pub fn test_ranges() {
 fn test_range_step_zero_step() {
     for range_step(0,10,0) |_i| {}
 }
+
+#[test]
+fn test_checked() {
+    assert checked_add(max_value, 0 as T) == Some(max_value);
+    assert checked_add(max_value, 1 as T) == None;
+    assert checked_add(min_value, -1 as T) == None;
+    assert checked_add(min_value, max_value) == Some(-1 as T);
+
+    assert checked_sub(min_value, 0 as T) == Some(min_value);
+    assert checked_sub(min_value, 1 as T) == None;
+    assert checked_sub(max_value, -1 as T) == None;
+    assert checked_sub(-1 as T, max_value) == Some(min_value);
+    assert checked_sub(0 as T, min_value) == None;
+
+    assert checked_mul(max_value, 1 as T) == Some(max_value);
+    assert checked_mul(max_value, 2 as T) == None;
+    assert checked_mul(max_value, -1 as T) == Some(-max_value);
+    assert checked_mul(min_value, -1 as T) == None;
+    assert checked_mul(min_value / 2 as T, 2 as T) == Some(min_value);
+    assert checked_mul(max_value / 2 as T + 1 as T, 2 as T) == None;
+
+    assert checked_div(min_value, 1 as T) == Some(min_value);
+    assert checked_div(min_value, -1 as T) == None;
+    assert checked_div(max_value, -1 as T) == Some(-max_value);
+    assert checked_div(-7 as T, 2 as T) == Some(-3 as T);
+    assert checked_div(1 as T, 0 as T) == None;
+}
+
+#[test]
+fn test_wrapping() {
+    assert wrapping_add(5 as T, -7 as T) == -2 as T;
+    assert wrapping_add(max_value, 1 as T) == min_value;
+    assert wrapping_add(min_value, -1 as T) == max_value;
+    assert wrapping_sub(min_value, 1 as T) == max_value;
+    assert wrapping_sub(max_value, -1 as T) == min_value;
+    assert wrapping_mul(max_value, 2 as T) == -2 as T;
+    assert wrapping_mul(min_value, -1 as T) == min_value;
+    assert wrapping_div(max_value, -1 as T) == -max_value;
+    assert wrapping_div(min_value, -1 as T) == min_value;
+}
+
+#[test]
+fn test_saturating() {
+    assert saturating_add(max_value, min_value) == -1 as T;
+    assert saturating_add(max_value, 1 as T) == max_value;
+    assert saturating_add(min_value, -1 as T) == min_value;
+    assert saturating_sub(min_value, 1 as T) == min_value;
+    assert saturating_sub(max_value, -1 as T) == max_value;
+    assert saturating_sub(0 as T, min_value) == max_value;
+    assert saturating_mul(-3 as T, 4 as T) == -12 as T;
+    assert saturating_mul(max_value, 2 as T) == max_value;
+    assert saturating_mul(min_value, 2 as T) == min_value;
+    assert saturating_mul(max_value, -2 as T) == min_value;
+    assert saturating_mul(min_value, -1 as T) == max_value;
+    assert saturating_div(max_value, -1 as T) == -max_value;
+    assert saturating_div(min_value, -1 as T) == max_value;
+}
+
+#[test]
+#[should_fail]
+#[ignore(cfg(windows))]
+fn test_saturating_div_zero() {
+    saturating_div(1 as T, 0 as T);
+}
@@ -10,7 +10,72 @@
 
 //! Operations and constants for `i16`
 
+pub use self::inst::{
+    add_with_overflow, sub_with_overflow, mul_with_overflow
+};
+
 mod inst {
     pub type T = i16;
     pub const bits: uint = ::u16::bits;
+
+    #[cfg(stage1)]
+    #[cfg(stage2)]
+    #[cfg(stage3)]
+    #[abi = "rust-intrinsic"]
+    extern mod rusti {
+        fn i16_add_with_overflow(x: i16, y: i16) -> (i16, bool);
+        fn i16_sub_with_overflow(x: i16, y: i16) -> (i16, bool);
+        fn i16_mul_with_overflow(x: i16, y: i16) -> (i16, bool);
+    }
+
+    /// Returns `x + y` wrapped around, and whether it overflowed
+    #[cfg(stage0)]
+    #[inline(always)]
+    pub pure fn add_with_overflow(x: T, y: T) -> (T, bool) {
+        let wide = x as i64 + y as i64;
+        (wide as T, wide != (wide as T) as i64)
+    }
+
+    /// Returns `x + y` wrapped around, and whether it overflowed
+    #[cfg(stage1)]
+    #[cfg(stage2)]
+    #[cfg(stage3)]
+    #[inline(always)]
+    pub pure fn add_with_overflow(x: T, y: T) -> (T, bool) {
+        unsafe { rusti::i16_add_with_overflow(x, y) }
+    }
+
+    /// Returns `x - y` wrapped around, and whether it overflowed
+    #[cfg(stage0)]
+    #[inline(always)]
+    pub pure fn sub_with_overflow(x: T, y: T) -> (T, bool) {
+        let wide = x as i64 - y as i64;
+        (wide as T, wide != (wide as T) as i64)
+    }
+
+    /// Returns `x - y` wrapped around, and whether it overflowed
+    #[cfg(stage1)]
+    #[cfg(stage2)]
+    #[cfg(stage3)]
+    #[inline(always)]
+    pub pure fn sub_with_overflow(x: T, y: T) -> (T, bool) {
+        unsafe { rusti::i16_sub_with_overflow(x, y) }
+    }
+
+    /// Returns `x * y` wrapped around, and whether it overflowed
+    #[cfg(stage0)]
+    #[inline(always)]
+    pub pure fn mul_with_overflow(x: T, y: T) -> (T, bool) {
+        let wide = x as i64 * y as i64;
+        (wide as T, wide != (wide as T) as i64)
+    }
+
+    /// Returns `x * y` wrapped around, and whether it overflowed
+    #[cfg(stage1)]
+    #[cfg(stage2)]
+    #[cfg(stage3)]
+    #[inline(always)]
+    pub pure fn mul_with_overflow(x: T, y: T) -> (T, bool) {
+        unsafe { rusti::i16_mul_with_overflow(x, y) }
+    }
 }
@@ -10,7 +10,72 @@
 
 //! Operations and constants for `i32`
 
+pub use self::inst::{
+    add_with_overflow, sub_with_overflow, mul_with_overflow
+};
+
 mod inst {
     pub type T = i32;
     pub const bits: uint = ::u32::bits;
+
+    #[cfg(stage1)]
+    #[cfg(stage2)]
+    #[cfg(stage3)]
+    #[abi = "rust-intrinsic"]
+    extern mod rusti {
+        fn i32_add_with_overflow(x: i32, y: i32) -> (i32, bool);
+        fn i32_sub_with_overflow(x: i32, y: i32) -> (i32, bool);
+        fn i32_mul_with_overflow(x: i32, y: i32) -> (i32, bool);
+    }
+
+    /// Returns `x + y` wrapped around, and whether it overflowed
+    #[cfg(stage0)]
+    #[inline(always)]
+    pub pure fn add_with_overflow(x: T, y: T) -> (T, bool) {
+        let wide = x as i64 + y as i64;
+        (wide as T, wide != (wide as T) as i64)
+    }
+
+    /// Returns `x + y` wrapped around, and whether it overflowed
+    #[cfg(stage1)]
+    #[cfg(stage2)]
+    #[cfg(stage3)]
+    #[inline(always)]
+    pub pure fn add_with_overflow(x: T, y: T) -> (T, bool) {
+        unsafe { rusti::i32_add_with_overflow(x, y) }
+    }
+
+    /// Returns `x - y` wrapped around, and whether it overflowed
+    #[cfg(stage0)]
+    #[inline(always)]
+    pub pure fn sub_with_overflow(x: T, y: T) -> (T, bool) {
+        let wide = x as i64 - y as i64;
+        (wide as T, wide != (wide as T) as i64)
+    }
+
+    /// Returns `x - y` wrapped around, and whether it overflowed
+    #[cfg(stage1)]
+    #[cfg(stage2)]
+    #[cfg(stage3)]
+    #[inline(always)]
+    pub pure fn sub_with_overflow(x: T, y: T) -> (T, bool) {
+        unsafe { rusti::i32_sub_with_overflow(x, y) }
+    }
+
+    /// Returns `x * y` wrapped around, and whether it overflowed
+    #[cfg(stage0)]
+    #[inline(always)]
+    pub pure fn mul_with_overflow(x: T, y: T) -> (T, bool) {
+        let wide = x as i64 * y as i64;
+        (wide as T, wide != (wide as T) as i64)
+    }
+
+    /// Returns `x * y` wrapped around, and whether it overflowed
+    #[cfg(stage1)]
+    #[cfg(stage2)]
+    #[cfg(stage3)]
+    #[inline(always)]
+    pub pure fn mul_with_overflow(x: T, y: T) -> (T, bool) {
+        unsafe { rusti::i32_mul_with_overflow(x, y) }
+    }
 }
@@ -10,7 +10,79 @@
 
 //! Operations and constants for `i64`
 
+pub use self::inst::{
+    add_with_overflow, sub_with_overflow, mul_with_overflow
+};
+
 mod inst {
     pub type T = i64;
     pub const bits: uint = ::u64::bits;
+
+    #[cfg(stage1)]
+    #[cfg(stage2)]
+    #[cfg(stage3)]
+    #[abi = "rust-intrinsic"]
+    extern mod rusti {
+        fn i64_add_with_overflow(x: i64, y: i64) -> (i64, bool);
+        fn i64_sub_with_overflow(x: i64, y: i64) -> (i64, bool);
+        fn i64_mul_with_overflow(x: i64, y: i64) -> (i64, bool);
+    }
+
+    /// Returns `x + y` wrapped around, and whether it overflowed
+    #[cfg(stage0)]
+    #[inline(always)]
+    pub pure fn add_with_overflow(x: T, y: T) -> (T, bool) {
+        let r = x + y;
+        (r, ((x ^ r) & (y ^ r)) < 0)
+    }
+
+    /// Returns `x + y` wrapped around, and whether it overflowed
+    #[cfg(stage1)]
+    #[cfg(stage2)]
+    #[cfg(stage3)]
+    #[inline(always)]
+    pub pure fn add_with_overflow(x: T, y: T) -> (T, bool) {
+        unsafe { rusti::i64_add_with_overflow(x, y) }
+    }
+
+    /// Returns `x - y` wrapped around, and whether it overflowed
+    #[cfg(stage0)]
+    #[inline(always)]
+    pub pure fn sub_with_overflow(x: T, y: T) -> (T, bool) {
+        let r = x - y;
+        (r, ((x ^ y) & (x ^ r)) < 0)
+    }
+
+    /// Returns `x - y` wrapped around, and whether it overflowed
+    #[cfg(stage1)]
+    #[cfg(stage2)]
+    #[cfg(stage3)]
+    #[inline(always)]
+    pub pure fn sub_with_overflow(x: T, y: T) -> (T, bool) {
+        unsafe { rusti::i64_sub_with_overflow(x, y) }
+    }
+
+    /// Returns `x * y` wrapped around, and whether it overflowed
+    #[cfg(stage0)]
+    #[inline(always)]
+    pub pure fn mul_with_overflow(x: T, y: T) -> (T, bool) {
+        let r = x * y;
+        if x == 0 {
+            (r, false)
+        } else if x == -1 {
+            // `r / x` would itself overflow for `min_value / -1`
+            (r, y == ::i64::min_value)
+        } else {
+            (r, r / x != y)
+        }
+    }
+
+    /// Returns `x * y` wrapped around, and whether it overflowed
+    #[cfg(stage1)]
+    #[cfg(stage2)]
+    #[cfg(stage3)]
+    #[inline(always)]
+    pub pure fn mul_with_overflow(x: T, y: T) -> (T, bool) {
+        unsafe { rusti::i64_mul_with_overflow(x, y) }
+    }
 }
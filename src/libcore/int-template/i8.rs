@@ -10,7 +10,88 @@
 
 //! Operations and constants for `i8`
 
+pub use self::inst::{
+    add_with_overflow, sub_with_overflow, mul_with_overflow
+};
+
 mod inst {
     pub type T = i8;
     pub const bits: uint = ::u8::bits;
+
+    #[cfg(stage1)]
+    #[cfg(stage2)]
+    #[cfg(stage3)]
+    #[abi = "rust-intrinsic"]
+    extern mod rusti {
+        fn i8_add_with_overflow(x: i8, y: i8) -> (i8, bool);
+        fn i8_sub_with_overflow(x: i8, y: i8) -> (i8, bool);
+        fn i8_mul_with_overflow(x: i8, y: i8) -> (i8, bool);
+    }
+
+    /// Returns `x + y` wrapped around, and whether it overflowed
+    #[cfg(stage0)]
+    #[inline(always)]
+    pub pure fn add_with_overflow(x: T, y: T) -> (T, bool) {
+        let wide = x as i64 + y as i64;
+        (wide as T, wide != (wide as T) as i64)
+    }
+
+    /// Returns `x + y` wrapped around, and whether it overflowed
+    #[cfg(stage1)]
+    #[cfg(stage2)]
+    #[cfg(stage3)]
+    #[inline(always)]
+    pub pure fn add_with_overflow(x: T, y: T) -> (T, bool) {
+        unsafe { rusti::i8_add_with_overflow(x, y) }
+    }
+
+    /// Returns `x - y` wrapped around, and whether it overflowed
+    #[cfg(stage0)]
+    #[inline(always)]
+    pub pure fn sub_with_overflow(x: T, y: T) -> (T, bool) {
+        let wide = x as i64 - y as i64;
+        (wide as T, wide != (wide as T) as i64)
+    }
+
+    /// Returns `x - y` wrapped around, and whether it overflowed
+    #[cfg(stage1)]
+    #[cfg(stage2)]
+    #[cfg(stage3)]
+    #[inline(always)]
+    pub pure fn sub_with_overflow(x: T, y: T) -> (T, bool) {
+        unsafe { rusti::i8_sub_with_overflow(x, y) }
+    }
+
+    /// Returns `x * y` wrapped around, and whether it overflowed
+    #[cfg(stage0)]
+    #[inline(always)]
+    pub pure fn mul_with_overflow(x: T, y: T) -> (T, bool) {
+        let wide = x as i64 * y as i64;
+        (wide as T, wide != (wide as T) as i64)
+    }
+
+    /// Returns `x * y` wrapped around, and whether it overflowed
+    #[cfg(stage1)]
+    #[cfg(stage2)]
+    #[cfg(stage3)]
+    #[inline(always)]
+    pub pure fn mul_with_overflow(x: T, y: T) -> (T, bool) {
+        unsafe { rusti::i8_mul_with_overflow(x, y) }
+    }
+
+    #[test]
+    fn test_with_overflow_exhaustive() {
+        fn check(got: (T, bool), wide: int) {
+            let (r, o) = got;
+            assert r == wide as T;
+            assert o == (wide < -128 || wide >= 128);
+        }
+        for ::int::range(-128, 128) |x| {
+            for ::int::range(-128, 128) |y| {
+                check(add_with_overflow(x as T, y as T), x + y);
+                check(sub_with_overflow(x as T, y as T), x - y);
+                check(mul_with_overflow(x as T, y as T), x * y);
+            }
+        }
+    }
 }
@@ -10,7 +10,9 @@
 
 //! Operations and constants for `int`
 
-pub use self::inst::pow;
+pub use self::inst::{
+    pow, add_with_overflow, sub_with_overflow, mul_with_overflow
+};
 
 mod inst {
     pub type T = int;
@@ -54,4 +56,49 @@ mod inst {
         assert (::int::min_value <= 0);
         assert (::int::min_value + ::int::max_value + 1 == 0);
     }
+
+    /// Returns `x + y` wrapped around, and whether it overflowed
+    #[cfg(target_word_size = "32")]
+    #[inline(always)]
+    pub pure fn add_with_overflow(x: T, y: T) -> (T, bool) {
+        let (r, o) = ::i32::add_with_overflow(x as i32, y as i32);
+        (r as T, o)
+    }
+
+    #[cfg(target_word_size = "64")]
+    #[inline(always)]
+    pub pure fn add_with_overflow(x: T, y: T) -> (T, bool) {
+        let (r, o) = ::i64::add_with_overflow(x as i64, y as i64);
+        (r as T, o)
+    }
+
+    /// Returns `x - y` wrapped around, and whether it overflowed
+    #[cfg(target_word_size = "32")]
+    #[inline(always)]
+    pub pure fn sub_with_overflow(x: T, y: T) -> (T, bool) {
+        let (r, o) = ::i32::sub_with_overflow(x as i32, y as i32);
+        (r as T, o)
+    }
+
+    #[cfg(target_word_size = "64")]
+    #[inline(always)]
+    pub pure fn sub_with_overflow(x: T, y: T) -> (T, bool) {
+        let (r, o) = ::i64::sub_with_overflow(x as i64, y as i64);
+        (r as T, o)
+    }
+
+    /// Returns `x * y` wrapped around, and whether it overflowed
+    #[cfg(target_word_size = "32")]
+    #[inline(always)]
+    pub pure fn mul_with_overflow(x: T, y: T) -> (T, bool) {
+        let (r, o) = ::i32::mul_with_overflow(x as i32, y as i32);
+        (r as T, o)
+    }
+
+    #[cfg(target_word_size = "64")]
+    #[inline(always)]
+    pub pure fn mul_with_overflow(x: T, y: T) -> (T, bool) {
+        let (r, o) = ::i64::mul_with_overflow(x as i64, y as i64);
+        (r as T, o)
+    }
 }
@@ -1054,6 +1054,210 @@ pub pure fn with_str_writer(f: fn(Writer)) -> ~str {
     unsafe { move ::cast::transmute(move v) }
 }
 
+// Buffered adaptors
+
+/// The buffer size used by `BufferedReader::new` and `BufferedWriter::new`.
+pub const default_buffer_capacity: uint = 8192u;
+
+/**
+ * Wraps a reader and reads from it in chunks of `capacity` bytes, so that
+ * many small reads (such as `read_byte` or `read_line`) do not each turn
+ * into a call on the underlying reader.
+ */
+pub struct BufferedReader<R> {
+    priv base: R,
+    priv buf: DVec<u8>,
+    priv mut pos: uint,
+    priv capacity: uint,
+}
+
+pub impl<R: Reader> BufferedReader<R> {
+    /// Wrap `base` in a reader with the default buffer capacity.
+    static fn new(base: R) -> BufferedReader<R> {
+        BufferedReader::with_capacity(default_buffer_capacity, move base)
+    }
+
+    /// Wrap `base` in a reader that buffers `capacity` bytes at a time.
+    static fn with_capacity(capacity: uint, base: R) -> BufferedReader<R> {
+        assert capacity > 0;
+        BufferedReader {
+            base: move base,
+            buf: DVec(),
+            mut pos: 0u,
+            capacity: capacity
+        }
+    }
+
+    /// Returns a reference to the underlying reader.
+    fn get_ref(&self) -> &self/R { &self.base }
+
+    /**
+     * Read up to and including the first occurrence of `delim`, or until
+     * EOF. The delimiter is included in the result if it was found.
+     */
+    fn read_until(&self, delim: u8) -> ~[u8] {
+        let mut result = ~[];
+        while self.fill_buf() {
+            let pos = self.pos;
+            let (n, found) = do self.buf.borrow |buf| {
+                let rest = vec::view(buf, pos, buf.len());
+                match vec::position_elem(rest, &delim) {
+                    Some(i) => {
+                        result.push_all(vec::view(rest, 0u, i + 1u));
+                        (i + 1u, true)
+                    }
+                    None => {
+                        result.push_all(rest);
+                        (rest.len(), false)
+                    }
+                }
+            };
+            self.pos += n;
+            if found { break; }
+        }
+        move result
+    }
+
+    /// The number of bytes that have been buffered but not yet consumed.
+    priv fn unread(&self) -> uint { self.buf.len() - self.pos }
+
+    /**
+     * Refill the buffer from the underlying reader if it has been
+     * exhausted. Returns false if there is nothing left to read.
+     */
+    priv fn fill_buf(&self) -> bool {
+        if self.pos == self.buf.len() {
+            let capacity = self.capacity;
+            do self.buf.swap |buf| {
+                let mut buf = move buf;
+                vec::reserve(&mut buf, capacity);
+                unsafe { vec::raw::set_len(&mut buf, capacity); }
+                let count = self.base.read(buf, capacity);
+                unsafe { vec::raw::set_len(&mut buf, count); }
+                move buf
+            }
+            self.pos = 0u;
+        }
+        self.pos < self.buf.len()
+    }
+
+    /// Throw away any buffered data.
+    priv fn discard(&self) {
+        do self.buf.swap |buf| {
+            let mut buf = move buf;
+            vec::truncate(&mut buf, 0u);
+            move buf
+        }
+        self.pos = 0u;
+    }
+}
+
+impl<R: Reader> BufferedReader<R>: Reader {
+    fn read(&self, bytes: &[mut u8], len: uint) -> uint {
+        let mut count = 0u;
+        while count < len && self.fill_buf() {
+            let pos = self.pos;
+            let n = do self.buf.borrow |buf| {
+                let n = uint::min(len - count, buf.len() - pos);
+                let dst = vec::mut_view(bytes, count, count + n);
+                vec::bytes::copy_memory(dst, vec::view(buf, pos, pos + n), n);
+                n
+            };
+            self.pos += n;
+            count += n;
+        }
+        count
+    }
+    fn read_byte(&self) -> int {
+        if !self.fill_buf() { return -1; }
+        let b = self.buf.get_elt(self.pos);
+        self.pos += 1u;
+        b as int
+    }
+    fn eof(&self) -> bool { !self.fill_buf() }
+    fn seek(&self, offset: int, whence: SeekStyle) {
+        // The underlying reader is ahead of us by however much we have
+        // buffered, so relative seeks have to account for that.
+        let offset = match whence {
+          SeekCur => offset - self.unread() as int,
+          SeekSet | SeekEnd => offset
+        };
+        self.discard();
+        self.base.seek(offset, whence);
+    }
+    fn tell(&self) -> uint { self.base.tell() - self.unread() }
+}
+
+/**
+ * Wraps a writer and collects small writes into a buffer of `capacity`
+ * bytes before passing them on. Anything still buffered is written out
+ * when the `BufferedWriter` is flushed, seeked or dropped.
+ */
+pub struct BufferedWriter<W> {
+    priv base: W,
+    priv buf: DVec<u8>,
+    priv capacity: uint,
+}
+
+pub impl<W: Writer> BufferedWriter<W> {
+    /// Wrap `base` in a writer with the default buffer capacity.
+    static fn new(base: W) -> BufferedWriter<W> {
+        BufferedWriter::with_capacity(default_buffer_capacity, move base)
+    }
+
+    /// Wrap `base` in a writer that buffers up to `capacity` bytes.
+    static fn with_capacity(capacity: uint, base: W) -> BufferedWriter<W> {
+        assert capacity > 0;
+        BufferedWriter { base: move base, buf: DVec(), capacity: capacity }
+    }
+
+    /// Returns a reference to the underlying writer.
+    fn get_ref(&self) -> &self/W { &self.base }
+
+    /// Write out the contents of the buffer without flushing `base`.
+    priv fn flush_buf(&self) {
+        if self.buf.len() > 0u {
+            do self.buf.swap |buf| {
+                let mut buf = move buf;
+                self.base.write(buf);
+                vec::truncate(&mut buf, 0u);
+                move buf
+            }
+        }
+    }
+}
+
+impl<W: Writer> BufferedWriter<W>: Writer {
+    fn write(&self, v: &[const u8]) {
+        if self.buf.len() + v.len() > self.capacity {
+            self.flush_buf();
+        }
+        // Writes that would not fit in the buffer anyway go straight
+        // through rather than being copied.
+        if v.len() >= self.capacity {
+            self.base.write(v);
+        } else {
+            self.buf.push_all(v);
+        }
+    }
+    fn seek(&self, offset: int, whence: SeekStyle) {
+        self.flush_buf();
+        self.base.seek(offset, whence);
+    }
+    fn tell(&self) -> uint { self.base.tell() + self.buf.len() }
+    fn flush(&self) -> int {
+        self.flush_buf();
+        self.base.flush()
+    }
+    fn get_type(&self) -> WriterType { self.base.get_type() }
+}
+
+impl<W: Writer> BufferedWriter<W>: Drop {
+    fn finalize(&self) {
+        self.flush();
+    }
+}
+
 // Utility functions
 pub fn seek_in_buf(offset: int, pos: uint, len: uint, whence: SeekStyle) ->
    uint {
@@ -1187,6 +1391,7 @@ mod tests {
     use debug;
     use i32;
     use io::{BytesWriter, SeekCur, SeekEnd, SeekSet};
+    use io::{ReaderUtil, Writer, WriterUtil, SeekStyle};
     use io;
     use path::Path;
     use result;
@@ -1411,6 +1616,107 @@ mod tests {
         }
     }
 
+    #[test]
+    fn buffered_reader_read() {
+        let data = ~[0u8, 1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8];
+        do io::with_bytes_reader(data) |inp| {
+            let rd = io::BufferedReader::with_capacity(3, inp);
+            assert rd.read_byte() == 0;
+            assert rd.tell() == 1;
+            let mut buf = vec::from_elem(5, 0u8);
+            assert rd.read(buf, 5) == 5;
+            assert buf == ~[1u8, 2u8, 3u8, 4u8, 5u8];
+            assert rd.tell() == 6;
+            assert rd.read_bytes(10) == ~[6u8, 7u8, 8u8, 9u8];
+            assert rd.eof();
+            assert rd.read_byte() == -1;
+        }
+    }
+
+    #[test]
+    fn buffered_reader_seek() {
+        let data = ~[0u8, 1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8];
+        do io::with_bytes_reader(data) |inp| {
+            let rd = io::BufferedReader::with_capacity(4, inp);
+            assert rd.read_byte() == 0;
+            rd.seek(2, SeekCur);
+            assert rd.tell() == 3;
+            assert rd.read_byte() == 3;
+            rd.seek(-1, SeekEnd);
+            assert rd.read_byte() == 7;
+            assert rd.eof();
+            rd.seek(1, SeekSet);
+            assert rd.read_byte() == 1;
+        }
+    }
+
+    #[test]
+    fn buffered_reader_read_until() {
+        do io::with_str_reader(~"a,bcdef,,ghi") |inp| {
+            let rd = io::BufferedReader::with_capacity(2, inp);
+            assert rd.read_until(',' as u8) == str::to_bytes("a,");
+            assert rd.read_until(',' as u8) == str::to_bytes("bcdef,");
+            assert rd.read_until(',' as u8) == str::to_bytes(",");
+            assert rd.read_until(',' as u8) == str::to_bytes("ghi");
+            assert rd.read_until(',' as u8) == ~[];
+        }
+    }
+
+    #[test]
+    fn buffered_reader_each_line() {
+        do io::with_str_reader(~"first line\nsecond\n\nlast") |inp| {
+            let rd = io::BufferedReader::with_capacity(4, inp);
+            let mut lines = ~[];
+            for rd.each_line |line| {
+                lines.push(str::from_slice(line));
+            }
+            assert lines == ~[~"first line", ~"second", ~"", ~"last"];
+        }
+    }
+
+    #[test]
+    fn buffered_writer_write() {
+        let wr = io::BufferedWriter::with_capacity(4, BytesWriter());
+        wr.write(~[0u8, 1u8]);
+        assert wr.get_ref().bytes.len() == 0;
+        assert wr.tell() == 2;
+        wr.write(~[2u8, 3u8]);
+        assert wr.get_ref().bytes.len() == 0;
+        // Doesn't fit, so the buffer is written out first
+        wr.write(~[4u8]);
+        assert wr.get_ref().bytes.borrow(|bytes| bytes ==
+            ~[0u8, 1u8, 2u8, 3u8]);
+        // Larger than the buffer, so goes straight through
+        wr.write(~[5u8, 6u8, 7u8, 8u8, 9u8]);
+        assert wr.get_ref().bytes.borrow(|bytes| bytes ==
+            ~[0u8, 1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8]);
+        wr.write(~[10u8]);
+        assert wr.get_ref().bytes.len() == 10;
+        wr.flush();
+        assert wr.get_ref().bytes.len() == 11;
+    }
+
+    #[test]
+    fn buffered_writer_flush_on_drop() {
+        struct SharedWriter { out: @BytesWriter }
+        impl SharedWriter: Writer {
+            fn write(&self, v: &[const u8]) { self.out.write(v) }
+            fn seek(&self, off: int, style: SeekStyle) {
+                self.out.seek(off, style)
+            }
+            fn tell(&self) -> uint { self.out.tell() }
+            fn flush(&self) -> int { self.out.flush() }
+            fn get_type(&self) -> io::WriterType { self.out.get_type() }
+        }
+
+        let out = @BytesWriter();
+        {
+            let wr = io::BufferedWriter::new(SharedWriter { out: out });
+            wr.write_str("hello");
+            assert out.bytes.len() == 0;
+        }
+        assert out.bytes.borrow(|bytes| bytes == str::to_bytes("hello"));
+    }
 }
 
 //
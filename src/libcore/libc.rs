@@ -92,6 +92,7 @@ pub use libc::funcs::posix88::stdio::*;
 pub use libc::funcs::posix88::fcntl::*;
 pub use libc::funcs::posix88::dirent::*;
 pub use libc::funcs::posix88::unistd::*;
+pub use libc::funcs::posix88::signal::*;
 
 pub use libc::funcs::posix01::stat_::*;
 pub use libc::funcs::posix01::unistd::*;
@@ -834,6 +835,8 @@ pub mod consts {
             pub const S_IFDIR : int = 16384;
             pub const S_IFREG : int = 32768;
             pub const S_IFMT : int = 61440;
+            pub const S_IFLNK : int = 40960;
+            pub const S_IFSOCK : int = 49152;
             pub const S_IEXEC : int = 64;
             pub const S_IWRITE : int = 128;
             pub const S_IREAD : int = 256;
@@ -852,8 +855,14 @@ pub mod consts {
             pub const F_TEST : int = 3;
             pub const F_TLOCK : int = 2;
             pub const F_ULOCK : int = 0;
+            pub const SIGHUP : int = 1;
+            pub const SIGINT : int = 2;
+            pub const SIGQUIT : int = 3;
+            pub const SIGKILL : int = 9;
+            pub const SIGTERM : int = 15;
         }
         pub mod posix01 {
+            pub const WNOHANG : int = 1;
         }
         pub mod posix08 {
         }
@@ -901,6 +910,8 @@ pub mod consts {
             pub const S_IFDIR : int = 16384;
             pub const S_IFREG : int = 32768;
             pub const S_IFMT : int = 61440;
+            pub const S_IFLNK : int = 40960;
+            pub const S_IFSOCK : int = 49152;
             pub const S_IEXEC : int = 64;
             pub const S_IWRITE : int = 128;
             pub const S_IREAD : int = 256;
@@ -919,8 +930,14 @@ pub mod consts {
             pub const F_TEST : int = 3;
             pub const F_TLOCK : int = 2;
             pub const F_ULOCK : int = 0;
+            pub const SIGHUP : int = 1;
+            pub const SIGINT : int = 2;
+            pub const SIGQUIT : int = 3;
+            pub const SIGKILL : int = 9;
+            pub const SIGTERM : int = 15;
         }
         pub mod posix01 {
+            pub const WNOHANG : int = 1;
         }
         pub mod posix08 {
         }
@@ -969,6 +986,8 @@ pub mod consts {
             pub const S_IFDIR : int = 16384;
             pub const S_IFREG : int = 32768;
             pub const S_IFMT : int = 61440;
+            pub const S_IFLNK : int = 40960;
+            pub const S_IFSOCK : int = 49152;
             pub const S_IEXEC : int = 64;
             pub const S_IWRITE : int = 128;
             pub const S_IREAD : int = 256;
@@ -987,8 +1006,14 @@ pub mod consts {
             pub const F_TEST : int = 3;
             pub const F_TLOCK : int = 2;
             pub const F_ULOCK : int = 0;
+            pub const SIGHUP : int = 1;
+            pub const SIGINT : int = 2;
+            pub const SIGQUIT : int = 3;
+            pub const SIGKILL : int = 9;
+            pub const SIGTERM : int = 15;
         }
         pub mod posix01 {
+            pub const WNOHANG : int = 1;
         }
         pub mod posix08 {
         }
@@ -1263,6 +1288,12 @@ pub mod funcs {
             unsafe fn write(fd: c_int, buf: *c_void, count: c_uint) -> c_int;
 
         }
+
+        #[nolink]
+        #[abi = "cdecl"]
+        pub extern mod signal {
+            // Not supplied at all.
+        }
     }
 
 
@@ -1385,6 +1416,12 @@ pub mod funcs {
             unsafe fn write(fd: c_int, buf: *c_void, count: size_t)
                          -> ssize_t;
         }
+
+        #[nolink]
+        #[abi = "cdecl"]
+        pub extern mod signal {
+            unsafe fn kill(pid: pid_t, sig: c_int) -> c_int;
+        }
     }
 
     #[cfg(target_os = "linux")]
@@ -1510,9 +1547,10 @@ pub mod funcs {
 
     #[cfg(target_os = "win32")]
     pub mod extra {
-        use libc::types::os::arch::c95::c_int;
+        use libc::types::os::arch::c95::{c_int, c_uint};
         use libc::types::os::arch::extra::{DWORD, HMODULE, LPCWSTR, LPWSTR};
-        use libc::types::os::arch::extra::{BOOL, LPSECURITY_ATTRIBUTES};
+        use libc::types::os::arch::extra::{BOOL, HANDLE};
+        use libc::types::os::arch::extra::{LPSECURITY_ATTRIBUTES};
 
         #[abi = "stdcall"]
         pub extern mod kernel32 {
@@ -1535,6 +1573,11 @@ pub mod funcs {
             unsafe fn SetCurrentDirectoryW(lpPathName: LPCWSTR) -> BOOL;
 
             unsafe fn GetLastError() -> DWORD;
+
+            unsafe fn GetExitCodeProcess(hProcess: HANDLE,
+                                         lpExitCode: *mut DWORD) -> BOOL;
+            unsafe fn TerminateProcess(hProcess: HANDLE,
+                                       uExitCode: c_uint) -> BOOL;
         }
 
         #[abi = "cdecl"]
@@ -647,6 +647,74 @@ pub fn make_absolute(p: &Path) -> Path {
     }
 }
 
+/**
+ * Convert a path to an absolute, normalized path with every symbolic link
+ * resolved
+ *
+ * Every component of the path must exist.
+ */
+pub fn canonicalize(p: &Path) -> Result<Path, ~str> {
+    return canonicalize_os(p);
+
+    #[cfg(windows)]
+    fn canonicalize_os(p: &Path) -> Result<Path, ~str> {
+        let abs = make_absolute(p).normalize();
+        if path_exists(&abs) {
+            Ok(move abs)
+        } else {
+            Err(fmt!("error canonicalizing %s: path does not exist",
+                     p.to_str()))
+        }
+    }
+
+    #[cfg(unix)]
+    fn canonicalize_os(p: &Path) -> Result<Path, ~str> {
+        // The same limit as Linux's MAXSYMLINKS
+        let max_links = 40;
+
+        // Walk the path one component at a time, splicing in the target
+        // of each link we meet. `todo` is a stack, so it's kept reversed.
+        let mut todo = vec::reversed(make_absolute(p).components);
+        let mut result = Path("/");
+        let mut links = 0;
+        while !todo.is_empty() {
+            let c = todo.pop();
+            if c == ~"." {
+                loop;
+            }
+            if c == ~".." {
+                result = result.pop();
+                loop;
+            }
+            let next = result.push(c);
+            let st = match lstat(&next) {
+                Ok(st) => st,
+                Err(move e) => return Err(move e)
+            };
+            if !st.is_symlink() {
+                result = move next;
+                loop;
+            }
+
+            links += 1;
+            if links > max_links {
+                return Err(fmt!("error canonicalizing %s: too many \
+                                 levels of symbolic links", p.to_str()));
+            }
+            let target = match readlink(&next) {
+                Ok(move target) => move target,
+                Err(move e) => return Err(move e)
+            };
+            if target.is_absolute {
+                result = Path("/");
+            }
+            for vec::rev_each(target.components) |tc| {
+                todo.push(copy *tc);
+            }
+        }
+        Ok(move result)
+    }
+}
 
 /// Creates a directory at the specified path
 pub fn make_dir(p: &Path, mode: c_int) -> bool {
@@ -836,6 +904,263 @@ pub fn remove_file(p: &Path) -> bool {
     }
 }
 
+/// The kind of object a path refers to
+#[deriving_eq]
+pub enum FileType {
+    RegularFile,
+    Directory,
+    Symlink,
+    NamedPipe,
+    BlockDevice,
+    CharDevice,
+    Socket,
+    UnknownFileType,
+}
+
+/// Metadata about a filesystem object, as returned by `stat` and `lstat`
+pub struct FileStat {
+    file_type: FileType,
+    /// Size in bytes
+    size: u64,
+    /// Permission bits (the low 12 bits of `st_mode`)
+    perm: uint,
+    /// Last modification time, in seconds since the epoch
+    mtime: i64,
+}
+
+pub impl FileStat {
+    pure fn is_file(&self) -> bool { self.file_type == RegularFile }
+    pure fn is_dir(&self) -> bool { self.file_type == Directory }
+    pure fn is_symlink(&self) -> bool { self.file_type == Symlink }
+}
+
+fn file_stat(st: &libc::stat) -> FileStat {
+    let mode = st.st_mode as uint;
+    FileStat {
+        file_type: file_type(mode),
+        size: st.st_size as u64,
+        perm: mode & 0xfff,
+        mtime: st.st_mtime as i64,
+    }
+}
+
+#[cfg(unix)]
+fn file_type(mode: uint) -> FileType {
+    use libc::consts::os::posix88::{S_IFLNK, S_IFSOCK};
+
+    match (mode as int) & libc::S_IFMT {
+      t if t == libc::S_IFREG => RegularFile,
+      t if t == libc::S_IFDIR => Directory,
+      t if t == S_IFLNK => Symlink,
+      t if t == libc::S_IFIFO => NamedPipe,
+      t if t == libc::S_IFBLK => BlockDevice,
+      t if t == libc::S_IFCHR => CharDevice,
+      t if t == S_IFSOCK => Socket,
+      _ => UnknownFileType
+    }
+}
+
+#[cfg(windows)]
+fn file_type(mode: uint) -> FileType {
+    match (mode as int) & libc::S_IFMT {
+      t if t == libc::S_IFREG => RegularFile,
+      t if t == libc::S_IFDIR => Directory,
+      t if t == libc::S_IFIFO => NamedPipe,
+      t if t == libc::S_IFCHR => CharDevice,
+      _ => UnknownFileType
+    }
+}
+
+/// Format an error message for a failed operation on `p` using the
+/// description of the last OS error
+fn path_error(what: &str, p: &Path) -> ~str {
+    fmt!("error %s %s: %s", what, p.to_str(), last_os_error())
+}
+
+/// Returns the metadata for the object at `p`, following symlinks
+pub fn stat(p: &Path) -> Result<FileStat, ~str> {
+    match p.stat() {
+        Some(ref st) => Ok(file_stat(st)),
+        None => Err(path_error("reading metadata of", p))
+    }
+}
+
+/// Returns the metadata for the object at `p`, without following symlinks
+#[cfg(unix)]
+pub fn lstat(p: &Path) -> Result<FileStat, ~str> {
+    match p.lstat() {
+        Some(ref st) => Ok(file_stat(st)),
+        None => Err(path_error("reading metadata of", p))
+    }
+}
+
+/**
+ * Renames a file or directory
+ *
+ * On Windows this fails if `to` already exists; elsewhere `to` is
+ * atomically replaced.
+ */
+pub fn rename(from: &Path, to: &Path) -> Result<(), ~str> {
+    let ok = unsafe {
+        do as_c_charp(from.to_str()) |fromp| {
+            do as_c_charp(to.to_str()) |top| {
+                libc::rename(fromp, top) == (0 as c_int)
+            }
+        }
+    };
+    if ok {
+        Ok(())
+    } else {
+        Err(fmt!("error renaming %s to %s: %s",
+                 from.to_str(), to.to_str(), last_os_error()))
+    }
+}
+
+/// Creates a symbolic link at `link` pointing to `target`
+#[cfg(unix)]
+pub fn symlink(target: &Path, link: &Path) -> Result<(), ~str> {
+    let ok = unsafe {
+        do as_c_charp(target.to_str()) |targetp| {
+            do as_c_charp(link.to_str()) |linkp| {
+                libc::symlink(targetp, linkp) == (0 as c_int)
+            }
+        }
+    };
+    if ok { Ok(()) } else { Err(path_error("creating symlink", link)) }
+}
+
+/// Returns the path that the symbolic link at `p` points to
+#[cfg(unix)]
+pub fn readlink(p: &Path) -> Result<Path, ~str> {
+    let mut size = tmpbuf_sz;
+    loop {
+        let mut buf = vec::with_capacity::<u8>(size);
+        let n = unsafe {
+            do as_c_charp(p.to_str()) |pathp| {
+                do vec::as_mut_buf(buf) |b, _len| {
+                    libc::readlink(pathp, b as *mut c_char, size as size_t)
+                }
+            }
+        };
+        if n < (0 as ssize_t) {
+            return Err(path_error("reading symlink", p));
+        }
+        // A result that fills the buffer may have been truncated
+        if (n as uint) < size {
+            unsafe { vec::raw::set_len(&mut buf, n as uint); }
+            return Ok(Path(str::from_bytes(buf)));
+        }
+        size *= 2;
+    }
+}
+
+/// Changes the permission bits of the object at `p`
+pub fn chmod(p: &Path, mode: c_int) -> Result<(), ~str> {
+    return if do_chmod(p, mode) {
+        Ok(())
+    } else {
+        Err(path_error("changing permissions of", p))
+    };
+
+    #[cfg(windows)]
+    fn do_chmod(p: &Path, mode: c_int) -> bool {
+        unsafe {
+            do as_c_charp(p.to_str()) |buf| {
+                libc::chmod(buf, mode) == (0 as c_int)
+            }
+        }
+    }
+
+    #[cfg(unix)]
+    fn do_chmod(p: &Path, mode: c_int) -> bool {
+        unsafe {
+            do as_c_charp(p.to_str()) |buf| {
+                libc::chmod(buf, mode as mode_t) == (0 as c_int)
+            }
+        }
+    }
+}
+
+/**
+ * Changes the owner and group of the object at `p`
+ *
+ * Passing -1 for either `uid` or `gid` leaves that id unchanged.
+ */
+#[cfg(unix)]
+pub fn chown(p: &Path, uid: int, gid: int) -> Result<(), ~str> {
+    use libc::types::os::arch::posix88::{gid_t, uid_t};
+
+    let ok = unsafe {
+        do as_c_charp(p.to_str()) |buf| {
+            libc::chown(buf, uid as uid_t, gid as gid_t) == (0 as c_int)
+        }
+    };
+    if ok { Ok(()) } else { Err(path_error("changing owner of", p)) }
+}
+
+/**
+ * Creates a directory at the specified path, along with any missing
+ * parent directories
+ *
+ * Succeeds if the directory already exists.
+ */
+pub fn make_dir_recursive(p: &Path, mode: c_int) -> Result<(), ~str> {
+    if path_is_dir(p) {
+        return Ok(());
+    }
+    if p.components.len() > 1 {
+        match make_dir_recursive(&p.pop(), mode) {
+            Ok(()) => (),
+            Err(move e) => return Err(move e)
+        }
+    }
+    if make_dir(p, mode) {
+        Ok(())
+    } else {
+        let err = path_error("creating directory", p);
+        // Somebody else may have created it in the meantime
+        if path_is_dir(p) { Ok(()) } else { Err(move err) }
+    }
+}
+
+/**
+ * Removes a directory and everything inside it
+ *
+ * Symbolic links are removed rather than followed.
+ */
+pub fn remove_dir_recursive(p: &Path) -> Result<(), ~str> {
+    for list_dir_path(p).each |child| {
+        let child: &Path = &**child;
+        let res = if is_real_dir(child) {
+            remove_dir_recursive(child)
+        } else if remove_file(child) {
+            Ok(())
+        } else {
+            Err(path_error("removing", child))
+        };
+        match res {
+            Ok(()) => (),
+            Err(move e) => return Err(move e)
+        }
+    }
+    return if remove_dir(p) {
+        Ok(())
+    } else {
+        Err(path_error("removing directory", p))
+    };
+
+    #[cfg(unix)]
+    fn is_real_dir(p: &Path) -> bool {
+        match lstat(p) {
+            Ok(ref st) => st.is_dir(),
+            Err(_) => false
+        }
+    }
+
+    #[cfg(windows)]
+    fn is_real_dir(p: &Path) -> bool { path_is_dir(p) }
+}
+
 /// Get a string representing the platform-dependent last error
 pub fn last_os_error() -> ~str {
     unsafe {
@@ -1058,6 +1383,8 @@ mod tests {
     use debug;
     use libc::{c_int, c_void, size_t};
     use libc;
+    use io;
+    use io::WriterUtil;
     use option::{None, Option, Some};
     use option;
     use os::{as_c_charp, env, getcwd, getenv, make_absolute, real_args};
@@ -1065,6 +1392,7 @@ mod tests {
     use os;
     use path::Path;
     use rand;
+    use rand::RngUtil;
     use run;
     use str;
     use vec;
@@ -1247,6 +1575,122 @@ mod tests {
         assert (!os::path_exists(&Path("test/nonexistent-bogus-path")));
     }
 
+    fn make_temp_path() -> Path {
+        let rng: rand::Rng = rand::Rng();
+        os::tmpdir().push(~"rust-os-test-" + rng.gen_str(10u))
+    }
+
+    #[test]
+    fn make_and_remove_dir_recursive() {
+        let root = make_temp_path();
+        let deep = root.push_many([~"a", ~"b", ~"c"]);
+        assert os::make_dir_recursive(&deep, 0x1ed).is_ok(); // 755
+        assert os::path_is_dir(&deep);
+        // Making it again is fine
+        assert os::make_dir_recursive(&deep, 0x1ed).is_ok();
+
+        let file = root.push_many([~"a", ~"file.txt"]);
+        io::file_writer(&file, [io::Create]).get().write_str("hello");
+        assert os::stat(&file).get().is_file();
+        assert os::stat(&deep).get().is_dir();
+
+        assert os::remove_dir_recursive(&root).is_ok();
+        assert !os::path_exists(&root);
+    }
+
+    #[test]
+    fn stat_does_not_exist() {
+        let p = Path("test/nonexistent-bogus-path");
+        match os::stat(&p) {
+          Err(ref e) => {
+            assert str::starts_with(*e, "error reading metadata of");
+          }
+          Ok(_) => fail
+        }
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn canonicalize() {
+        let dir = make_temp_path();
+        let real = dir.push_many([~"real", ~"sub"]);
+        assert os::make_dir_recursive(&real, 0x1ed).is_ok(); // 755
+        // An absolute link, and a relative one that goes back up
+        assert os::symlink(&dir.push("real"), &dir.push("abs")).is_ok();
+        assert os::symlink(&Path("real/sub/.."), &dir.push("rel")).is_ok();
+
+        let dir = os::canonicalize(&dir).get();
+        let expected = dir.push_many([~"real", ~"sub"]);
+        assert os::canonicalize(&dir.push_many([~"abs", ~"sub"])).get() ==
+            expected;
+        assert os::canonicalize(&dir.push_many([~"rel", ~"sub"])).get() ==
+            expected;
+        assert os::canonicalize(&dir.push_many([~"abs", ~"..", ~"rel",
+                                                ~".", ~"sub"])).get() ==
+            expected;
+        assert os::canonicalize(&dir.push("missing")).is_err();
+
+        // A loop
+        assert os::symlink(&Path("loop"), &dir.push("loop")).is_ok();
+        assert os::canonicalize(&dir.push("loop")).is_err();
+
+        assert os::remove_dir_recursive(&dir).is_ok();
+    }
+
+    #[test]
+    fn rename_file() {
+        let dir = make_temp_path();
+        assert os::make_dir(&dir, 0x1ed);
+        let from = dir.push("from.txt");
+        let to = dir.push("to.txt");
+        io::file_writer(&from, [io::Create]).get().write_str("hello");
+
+        assert os::rename(&from, &to).is_ok();
+        assert !os::path_exists(&from);
+        assert os::stat(&to).get().size == 5;
+        assert os::rename(&from, &to).is_err();
+
+        assert os::remove_dir_recursive(&dir).is_ok();
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn symlink_and_readlink() {
+        let dir = make_temp_path();
+        assert os::make_dir(&dir, 0x1ed);
+        let target = dir.push("target.txt");
+        let link = dir.push("link");
+        io::file_writer(&target, [io::Create]).get().write_str("hi");
+
+        assert os::symlink(&target, &link).is_ok();
+        assert os::readlink(&link).get() == target;
+        assert os::lstat(&link).get().is_symlink();
+        assert os::stat(&link).get().is_file();
+        assert os::readlink(&target).is_err();
+
+        // Removing the directory must not follow the link
+        assert os::remove_dir_recursive(&dir).is_ok();
+        assert !os::path_exists(&dir);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn chmod_file() {
+        let dir = make_temp_path();
+        assert os::make_dir(&dir, 0x1ed);
+        let file = dir.push("file.txt");
+        io::file_writer(&file, [io::Create]).get();
+
+        assert os::chmod(&file, 0x180).is_ok(); // 600
+        assert os::stat(&file).get().perm == 0x180;
+        assert os::chmod(&file, 0x1a4).is_ok(); // 644
+        assert os::stat(&file).get().perm == 0x1a4;
+        // Leaving both ids unchanged is always allowed
+        assert os::chown(&file, -1, -1).is_ok();
+
+        assert os::remove_dir_recursive(&dir).is_ok();
+    }
+
     #[test]
     fn copy_file_does_not_exist() {
       assert !os::copy_file(&Path("test/nonexistent-bogus-path"),
@@ -24,6 +24,7 @@ use option::{None, Option, Some};
 use ptr;
 use str;
 use to_str::ToStr;
+use vec;
 
 #[deriving_eq]
 pub struct WindowsPath {
@@ -70,6 +71,20 @@ pub trait GenericPath {
     pure fn pop() -> self;
 
     pure fn normalize() -> self;
+
+    /**
+     * True if the components of `other` are a prefix of the components
+     * of this path. No normalization is done.
+     */
+    pure fn starts_with((&self)) -> bool;
+
+    /**
+     * The relative path that leads from the directory `base` to this
+     * path, after normalizing both. None if both paths aren't absolute
+     * or both relative (and on the same drive), or if there is a `..`
+     * left in `base` that can't be undone.
+     */
+    pure fn relative_to((&self)) -> Option<self>;
 }
 
 #[cfg(windows)]
@@ -528,6 +543,24 @@ impl PosixPath : GenericPath {
           //  ..self
         }
     }
+
+    pure fn starts_with(other: &PosixPath) -> bool {
+        self.is_absolute == other.is_absolute &&
+            components_start_with(self.components, other.components)
+    }
+
+    pure fn relative_to(base: &PosixPath) -> Option<PosixPath> {
+        if self.is_absolute != base.is_absolute {
+            return None;
+        }
+        let path = self.normalize();
+        let base = base.normalize();
+        match relative_components(path.components, base.components) {
+          Some(move cs) => Some(PosixPath { is_absolute: false,
+                                            components: move cs }),
+          None => None
+        }
+    }
 }
 
 
@@ -735,6 +768,26 @@ impl WindowsPath : GenericPath {
             components: normalize(self.components)
         }
     }
+
+    pure fn starts_with(other: &WindowsPath) -> bool {
+        windows::same_prefix(&self, other) &&
+            components_start_with(self.components, other.components)
+    }
+
+    pure fn relative_to(base: &WindowsPath) -> Option<WindowsPath> {
+        if !windows::same_prefix(&self, base) {
+            return None;
+        }
+        let path = self.normalize();
+        let base = base.normalize();
+        match relative_components(path.components, base.components) {
+          Some(move cs) => Some(WindowsPath { host: None,
+                                              device: None,
+                                              is_absolute: false,
+                                              components: move cs }),
+          None => None
+        }
+    }
 }
 
 
@@ -756,10 +809,35 @@ pub pure fn normalize(components: &[~str]) -> ~[~str] {
     move cs
 }
 
+pure fn components_start_with(cs: &[~str], prefix: &[~str]) -> bool {
+    prefix.len() <= cs.len() && vec::view(cs, 0, prefix.len()) == prefix
+}
+
+// The components that lead from `base` to `cs`, both normalized
+pure fn relative_components(cs: &[~str], base: &[~str]) -> Option<~[~str]> {
+    let mut i = 0;
+    while i < cs.len() && i < base.len() && cs[i] == base[i] {
+        i += 1;
+    }
+    let mut rel = ~[];
+    unsafe {
+        for vec::view(base, i, base.len()).each |c| {
+            // We don't know the name of the directory we'd be going
+            // back into
+            if *c == ~".." { return None; }
+            rel.push(~"..");
+        }
+        rel.push_all(vec::view(cs, i, cs.len()));
+    }
+    Some(move rel)
+}
+
 // Various windows helpers, and tests for the impl.
 pub mod windows {
     use libc;
     use option::{None, Option, Some};
+    use path::WindowsPath;
+    use str;
     use to_str::ToStr;
 
     #[inline(always)]
@@ -767,6 +845,16 @@ pub mod windows {
         u == '/' as u8 || u == '\\' as u8
     }
 
+    /// Whether two paths have the same host, drive and absoluteness
+    pub pure fn same_prefix(a: &WindowsPath, b: &WindowsPath) -> bool {
+        // Drive letters are case-insensitive
+        pure fn lower(d: &Option<~str>) -> Option<~str> {
+            d.map(|d| str::to_lower(*d))
+        }
+        a.is_absolute == b.is_absolute && a.host == b.host &&
+            lower(&a.device) == lower(&b.device)
+    }
+
     pub pure fn extract_unc_prefix(s: &str) -> Option<(~str,~str)> {
         if (s.len() > 1 &&
             s[0] == '\\' as u8 &&
@@ -918,6 +1006,81 @@ mod tests {
             .push("..").normalize()), "a/b");
     }
 
+    #[test]
+    fn test_posix_starts_with() {
+        let p = PosixPath("/usr/lib/rustc");
+        assert p.starts_with(&PosixPath("/"));
+        assert p.starts_with(&PosixPath("/usr"));
+        assert p.starts_with(&PosixPath("/usr/lib/"));
+        assert p.starts_with(&PosixPath("/usr/lib/rustc"));
+        assert !p.starts_with(&PosixPath("/usr/li"));
+        assert !p.starts_with(&PosixPath("/usr/lib/rustc/bin"));
+        assert !p.starts_with(&PosixPath("usr"));
+        assert PosixPath("a/b").starts_with(&PosixPath("a"));
+        assert PosixPath("a/b").starts_with(&PosixPath(""));
+    }
+
+    #[test]
+    fn test_posix_relative_to() {
+        fn t(path: &str, base: &str, expected: Option<&str>) {
+            let res = PosixPath(path).relative_to(&PosixPath(base));
+            let expected = expected.map(|s| PosixPath(*s));
+            if res != expected {
+                debug!("got %?", res);
+                debug!("expected %?", expected);
+                assert res == expected;
+            }
+        }
+
+        t("/usr/lib/mylib", "/usr/bin", Some("../lib/mylib"));
+        t("/usr/lib", "/usr/lib", Some(""));
+        t("/usr/lib/a/b", "/usr/lib", Some("a/b"));
+        t("/usr", "/usr/lib/rustc", Some("../.."));
+        t("/", "/usr", Some(".."));
+        t("/usr/bin/../lib", "/usr/./bin", Some("../lib"));
+        t("a/b/c", "a/d", Some("../b/c"));
+        t("a", "../b", None);
+        t("/usr/lib", "usr", None);
+        t("usr/lib", "/usr", None);
+    }
+
+    #[test]
+    fn test_windows_starts_with() {
+        let p = WindowsPath("c:\\program files\\rust");
+        assert p.starts_with(&WindowsPath("c:\\"));
+        assert p.starts_with(&WindowsPath("C:\\program files"));
+        assert !p.starts_with(&WindowsPath("d:\\program files"));
+        assert !p.starts_with(&WindowsPath("\\program files"));
+        assert !p.starts_with(&WindowsPath("c:program files"));
+        assert WindowsPath("\\\\host\\share\\a")
+            .starts_with(&WindowsPath("\\\\host\\share"));
+        assert !WindowsPath("\\\\host\\share\\a")
+            .starts_with(&WindowsPath("\\\\other\\share"));
+    }
+
+    #[test]
+    fn test_windows_relative_to() {
+        fn t(path: &str, base: &str, expected: Option<&str>) {
+            let res = WindowsPath(path).relative_to(&WindowsPath(base));
+            let expected = expected.map(|s| WindowsPath(*s));
+            if res != expected {
+                debug!("got %?", res);
+                debug!("expected %?", expected);
+                assert res == expected;
+            }
+        }
+
+        t("c:\\rust\\lib\\std.dll", "c:\\rust\\bin",
+          Some("..\\lib\\std.dll"));
+        t("C:\\rust\\lib", "c:\\rust", Some("lib"));
+        t("c:\\rust", "c:\\rust\\bin\\..\\lib", Some(".."));
+        t("a\\b", "a/c", Some("..\\b"));
+        t("c:\\rust", "d:\\rust", None);
+        t("\\\\host\\share\\a", "\\\\host\\share\\b", Some("..\\a"));
+        t("\\\\host\\share\\a", "c:\\share", None);
+        t("c:\\rust", "c:rust", None);
+    }
+
     #[test]
     fn test_extract_unc_prefixes() {
         assert windows::extract_unc_prefix("\\\\").is_none();
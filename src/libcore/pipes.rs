@@ -769,7 +769,7 @@ pub fn select2<A: Owned, Ab: Owned, B: Owned, Bb: Owned>(
 }
 
 #[doc(hidden)]
-trait Selectable {
+pub trait Selectable {
     pure fn header() -> *PacketHeader;
 }
 
@@ -792,6 +792,35 @@ pub fn select2i<A: Selectable, B: Selectable>(a: &A, b: &B) ->
     }
 }
 
+/**
+ * Receives from whichever of `ports` is ready first. Returns the index of
+ * that port and the message, or `None` if the port has closed.
+ *
+ * If several ports are ready at once, any one of them may be chosen.
+ */
+pub fn select_recv<T: Owned, P: Selectable GenericPort<T>>(ports: &[P])
+    -> (uint, Option<T>) {
+    let ready = selecti(ports);
+    (ready, ports[ready].try_recv())
+}
+
+/**
+ * Like `select_recv`, but gives up and returns `None` if `until` receives
+ * a message or closes before any of `ports` is ready. This is how
+ * `std::timer::select_recv_timeout` implements its timeout.
+ */
+pub fn select_recv_until<T: Owned, P: Selectable GenericPort<T>>(
+    ports: &[P], until: &Port<()>) -> Option<(uint, Option<T>)> {
+    let mut headers = ports.map(|p| p.header());
+    headers.push(until.header());
+    let ready = wait_many(headers);
+    if ready == ports.len() {
+        None
+    } else {
+        Some((ready, ports[ready].try_recv()))
+    }
+}
+
 /** Waits on a set of endpoints. Returns a message, its index, and a
  list of the remaining endpoints.
 
@@ -1207,6 +1236,119 @@ impl<T: Owned> PortSet<T> : Peekable<T> {
     }
 }
 
+/**
+ * The sending end of a bounded stream. Sending blocks while `capacity`
+ * messages are waiting to be received.
+ */
+pub struct BoundedChan<T: Owned> {
+    priv chan: Chan<T>,
+    // Each message received is acknowledged on this port, handing back
+    // one unit of credit.
+    priv acks: Port<()>,
+    priv mut credits: uint,
+    priv mut closed: bool,
+}
+
+/// The receiving end of a bounded stream.
+pub struct BoundedPort<T: Owned> {
+    priv port: Port<T>,
+    priv acks: Chan<()>,
+}
+
+/**
+ * Creates a `(port, chan)` pair through which at most `capacity` messages
+ * can be in flight at a time.
+ */
+pub fn bounded_stream<T: Owned>(capacity: uint)
+    -> (BoundedPort<T>, BoundedChan<T>) {
+    assert capacity > 0;
+    let (port, chan) = stream();
+    let (ack_port, ack_chan) = stream();
+    (BoundedPort { port: move port, acks: move ack_chan },
+     BoundedChan { chan: move chan, acks: move ack_port,
+                   credits: capacity, closed: false })
+}
+
+pub impl<T: Owned> BoundedChan<T> {
+    /**
+     * Returns true if sending would block, because `capacity` messages
+     * are waiting to be received.
+     */
+    fn is_full(&self) -> bool {
+        self.collect_acks();
+        self.credits == 0 && !self.closed
+    }
+
+    /// Take back credit for every message received so far, without
+    /// blocking.
+    priv fn collect_acks(&self) {
+        while !self.closed && self.acks.peek() {
+            self.take_ack();
+        }
+    }
+
+    /// Wait for credit for one received message.
+    priv fn take_ack(&self) {
+        match self.acks.try_recv() {
+          Some(()) => self.credits += 1,
+          None => self.closed = true
+        }
+    }
+}
+
+impl<T: Owned> BoundedChan<T>: GenericChan<T> {
+    /// Sends a message, first waiting for room if the stream is full.
+    fn send(x: T) {
+        if self.is_full() {
+            self.take_ack();
+        }
+        if self.credits > 0 {
+            self.credits -= 1;
+        }
+        self.chan.send(move x)
+    }
+}
+
+impl<T: Owned> BoundedChan<T>: GenericSmartChan<T> {
+    /**
+     * Sends a message without blocking. Returns false, dropping the
+     * message, if the stream is full or the receiver has closed it.
+     */
+    fn try_send(x: T) -> bool {
+        if self.is_full() || self.closed {
+            return false;
+        }
+        self.credits -= 1;
+        self.chan.try_send(move x)
+    }
+}
+
+impl<T: Owned> BoundedPort<T>: GenericPort<T> {
+    fn recv() -> T {
+        let x = self.port.recv();
+        self.acks.try_send(());
+        move x
+    }
+
+    fn try_recv() -> Option<T> {
+        match move self.port.try_recv() {
+          Some(move x) => {
+            self.acks.try_send(());
+            Some(move x)
+          }
+          None => None
+        }
+    }
+}
+
+impl<T: Owned> BoundedPort<T>: Peekable<T> {
+    pure fn peek() -> bool { self.port.peek() }
+}
+
+impl<T: Owned> BoundedPort<T>: Selectable {
+    pure fn header() -> *PacketHeader { self.port.header() }
+}
+
 /// A channel that can be shared between many senders.
 pub type SharedChan<T: Owned> = private::Exclusive<Chan<T>>;
 
@@ -1332,8 +1474,13 @@ pub mod rt {
 #[cfg(test)]
 pub mod test {
     use either::{Either, Left, Right};
+    use option::{None, Some};
     use pipes::{Chan, Port, oneshot, recv_one, stream};
+    use pipes::{bounded_stream, select_recv, select_recv_until};
     use pipes;
+    use task;
+    use uint;
+    use vec;
 
     #[test]
     pub fn test_select2() {
@@ -1370,4 +1517,95 @@ pub mod test {
 
         assert !port.peek();
     }
+
+    #[test]
+    fn test_bounded_try_send() {
+        let (port, chan) = bounded_stream(2);
+        assert !chan.is_full();
+        assert chan.try_send(1);
+        assert chan.try_send(2);
+        assert chan.is_full();
+        assert !chan.try_send(3);
+        assert port.recv() == 1;
+        assert !chan.is_full();
+        assert chan.try_send(4);
+        assert port.recv() == 2;
+        assert port.recv() == 4;
+        assert !port.peek();
+    }
+
+    #[test]
+    fn test_bounded_backpressure() {
+        let (port, chan) = bounded_stream(1);
+        let (done_port, done_chan) = stream();
+        do task::spawn |move chan, move done_chan| {
+            for uint::range(0, 100) |i| {
+                chan.send(i);
+            }
+            done_chan.send(());
+        }
+        for uint::range(0, 100) |i| {
+            assert port.recv() == i;
+        }
+        done_port.recv();
+    }
+
+    #[test]
+    fn test_bounded_closed() {
+        let (port, chan) = bounded_stream(4);
+        assert chan.try_send(1);
+        {
+            let _port = move port;
+        }
+        // The receiver going away releases the sender without credit.
+        assert !chan.try_send(2);
+        assert !chan.is_full();
+    }
+
+    #[test]
+    fn test_select_recv() {
+        let mut ports = ~[];
+        let mut chans = ~[];
+        for 3.times {
+            let (port, chan) = stream();
+            ports.push(move port);
+            chans.push(move chan);
+        }
+
+        chans[1].send(~"one");
+        assert select_recv(ports) == (1, Some(~"one"));
+
+        {
+            let _chan = vec::pop(&mut chans);
+        }
+        assert select_recv(ports) == (2, None);
+    }
+
+    #[test]
+    fn test_select_recv_bounded() {
+        let (p1, c1) = bounded_stream(1);
+        let (p2, c2) = bounded_stream(1);
+        assert c2.try_send(5);
+        assert c2.is_full();
+        let ports = [move p1, move p2];
+        assert select_recv(ports) == (1, Some(5));
+        // Receiving through select still hands back credit.
+        assert !c2.is_full();
+        assert c1.try_send(6);
+        assert select_recv(ports) == (0, Some(6));
+    }
+
+    #[test]
+    fn test_select_recv_until() {
+        let (port, chan) = stream::<int>();
+        let (until_port, until_chan) = stream();
+
+        let ports = [move port];
+        until_chan.send(());
+        assert select_recv_until(ports, &until_port).is_none();
+
+        // Ready ports take precedence over `until`.
+        chan.send(7);
+        assert select_recv_until(ports, &until_port) == Some((0, Some(7)));
+    }
 }
@@ -14,7 +14,10 @@
 #[forbid(deprecated_mode)];
 #[forbid(deprecated_pattern)];
 
+use f64;
 use int;
+use iter::BaseIter;
+use libc::c_int;
 use prelude::*;
 use str;
 use task;
@@ -48,87 +51,203 @@ pub struct Weighted<T> {
 }
 
 /// Extension methods for random number generators
-impl Rng {
-
+pub trait RngUtil {
     /// Return a random int
-    fn gen_int() -> int {
-        self.gen_i64() as int
-    }
+    fn gen_int() -> int;
 
     /**
      * Return an int randomly chosen from the range [start, end),
      * failing if start >= end
      */
+    fn gen_int_range(start: int, end: int) -> int;
+
+    /// Return a random i8
+    fn gen_i8() -> i8;
+
+    /// Return a random i16
+    fn gen_i16() -> i16;
+
+    /// Return a random i32
+    fn gen_i32() -> i32;
+
+    /// Return a random i64
+    fn gen_i64() -> i64;
+
+    /// Return a random uint
+    fn gen_uint() -> uint;
+
+    /**
+     * Return a uint randomly chosen from the range [start, end),
+     * failing if start >= end
+     */
+    fn gen_uint_range(start: uint, end: uint) -> uint;
+
+    /// Return a random u8
+    fn gen_u8() -> u8;
+
+    /// Return a random u16
+    fn gen_u16() -> u16;
+
+    /// Return a random u32
+    fn gen_u32() -> u32;
+
+    /// Return a random u64
+    fn gen_u64() -> u64;
+
+    /// Return a random float in the interval [0,1]
+    fn gen_float() -> float;
+
+    /// Return a random f32 in the interval [0,1]
+    fn gen_f32() -> f32;
+
+    /// Return a random f64 in the interval [0,1]
+    fn gen_f64() -> f64;
+
+    /// Return a random char
+    fn gen_char() -> char;
+
+    /**
+     * Return a char randomly chosen from chars, failing if chars is empty
+     */
+    fn gen_char_from(chars: &str) -> char;
+
+    /// Return a random bool
+    fn gen_bool() -> bool;
+
+    /// Return a bool with a 1 in n chance of true
+    fn gen_weighted_bool(n: uint) -> bool;
+
+    /**
+     * Return a random string of the specified length composed of A-Z,a-z,0-9
+     */
+    fn gen_str(len: uint) -> ~str;
+
+    /// Return a random byte string of the specified length
+    fn gen_bytes(len: uint) -> ~[u8];
+
+    /// Choose an item randomly, failing if values is empty
+    fn choose<T:Copy>(values: &[T]) -> T;
+
+    /// Choose Some(item) randomly, returning None if values is empty
+    fn choose_option<T:Copy>(values: &[T]) -> Option<T>;
+
+    /**
+     * Choose an item respecting the relative weights, failing if the sum of
+     * the weights is 0
+     */
+    fn choose_weighted<T: Copy>(v : &[Weighted<T>]) -> T;
+
+    /**
+     * Choose Some(item) respecting the relative weights, returning none if
+     * the sum of the weights is 0
+     */
+    fn choose_weighted_option<T:Copy>(v: &[Weighted<T>]) -> Option<T>;
+
+    /**
+     * Return a vec containing copies of the items, in order, where
+     * the weight of the item determines how many copies there are
+     */
+    fn weighted_vec<T:Copy>(v: &[Weighted<T>]) -> ~[T];
+
+    /// Shuffle a vec
+    fn shuffle<T:Copy>(values: &[T]) -> ~[T];
+
+    /// Shuffle a mutable vec in place
+    fn shuffle_mut<T>(values: &[mut T]);
+
+    /**
+     * Return a value drawn from the normal distribution with the given
+     * mean and standard deviation
+     */
+    fn gen_normal(mean: f64, std_dev: f64) -> f64;
+
+    /**
+     * Return a value drawn from the exponential distribution with rate
+     * `lambda`, failing if lambda <= 0
+     */
+    fn gen_exp(lambda: f64) -> f64;
+
+    /**
+     * Return a value drawn from the Poisson distribution with mean
+     * `lambda`, failing if lambda <= 0
+     */
+    fn gen_poisson(lambda: f64) -> uint;
+
+    /**
+     * Return a value in [1, n] drawn from the Zipf distribution with
+     * exponent `s`, so that k is chosen with probability proportional to
+     * 1/k^s. Fails if n is 0 or s <= 0.
+     */
+    fn gen_zipf(n: uint, s: f64) -> uint;
+
+    /**
+     * Choose n items from an iterable without replacement, each subset
+     * being equally likely, using reservoir sampling. Returns all of the
+     * items if there are fewer than n of them. The order of the chosen
+     * items is unspecified.
+     */
+    fn sample<A: Copy, I: BaseIter<A>>(iter: &I, n: uint) -> ~[A];
+}
+
+impl<R: Rng> R: RngUtil {
+    fn gen_int() -> int {
+        self.gen_i64() as int
+    }
+
     fn gen_int_range(start: int, end: int) -> int {
         assert start < end;
         start + int::abs(self.gen_int() % (end - start))
     }
 
-    /// Return a random i8
     fn gen_i8() -> i8 {
         self.next() as i8
     }
 
-    /// Return a random i16
     fn gen_i16() -> i16 {
         self.next() as i16
     }
 
-    /// Return a random i32
     fn gen_i32() -> i32 {
         self.next() as i32
     }
 
-    /// Return a random i64
     fn gen_i64() -> i64 {
         (self.next() as i64 << 32) | self.next() as i64
     }
 
-    /// Return a random uint
     fn gen_uint() -> uint {
         self.gen_u64() as uint
     }
 
-    /**
-     * Return a uint randomly chosen from the range [start, end),
-     * failing if start >= end
-     */
     fn gen_uint_range(start: uint, end: uint) -> uint {
         assert start < end;
         start + (self.gen_uint() % (end - start))
     }
 
-    /// Return a random u8
     fn gen_u8() -> u8 {
         self.next() as u8
     }
 
-    /// Return a random u16
     fn gen_u16() -> u16 {
         self.next() as u16
     }
 
-    /// Return a random u32
     fn gen_u32() -> u32 {
         self.next()
     }
 
-    /// Return a random u64
     fn gen_u64() -> u64 {
         (self.next() as u64 << 32) | self.next() as u64
     }
 
-    /// Return a random float in the interval [0,1]
     fn gen_float() -> float {
         self.gen_f64() as float
     }
 
-    /// Return a random f32 in the interval [0,1]
     fn gen_f32() -> f32 {
         self.gen_f64() as f32
     }
 
-    /// Return a random f64 in the interval [0,1]
     fn gen_f64() -> f64 {
         let u1 = self.next() as f64;
         let u2 = self.next() as f64;
@@ -137,25 +256,19 @@ impl Rng {
         return ((u1 / scale + u2) / scale + u3) / scale;
     }
 
-    /// Return a random char
     fn gen_char() -> char {
         self.next() as char
     }
 
-    /**
-     * Return a char randomly chosen from chars, failing if chars is empty
-     */
     fn gen_char_from(chars: &str) -> char {
         assert !chars.is_empty();
         self.choose(str::chars(chars))
     }
 
-    /// Return a random bool
     fn gen_bool() -> bool {
         self.next() & 1u32 == 1u32
     }
 
-    /// Return a bool with a 1 in n chance of true
     fn gen_weighted_bool(n: uint) -> bool {
         if n == 0u {
             true
@@ -164,9 +277,6 @@ impl Rng {
         }
     }
 
-    /**
-     * Return a random string of the specified length composed of A-Z,a-z,0-9
-     */
     fn gen_str(len: uint) -> ~str {
         let charset = ~"ABCDEFGHIJKLMNOPQRSTUVWXYZ\
                        abcdefghijklmnopqrstuvwxyz\
@@ -180,19 +290,16 @@ impl Rng {
         move s
     }
 
-    /// Return a random byte string of the specified length
     fn gen_bytes(len: uint) -> ~[u8] {
         do vec::from_fn(len) |_i| {
             self.gen_u8()
         }
     }
 
-    /// Choose an item randomly, failing if values is empty
     fn choose<T:Copy>(values: &[T]) -> T {
         self.choose_option(values).get()
     }
 
-    /// Choose Some(item) randomly, returning None if values is empty
     fn choose_option<T:Copy>(values: &[T]) -> Option<T> {
         if values.is_empty() {
             None
@@ -201,18 +308,10 @@ impl Rng {
         }
     }
 
-    /**
-     * Choose an item respecting the relative weights, failing if the sum of
-     * the weights is 0
-     */
     fn choose_weighted<T: Copy>(v : &[Weighted<T>]) -> T {
         self.choose_weighted_option(v).get()
     }
 
-    /**
-     * Choose Some(item) respecting the relative weights, returning none if
-     * the sum of the weights is 0
-     */
     fn choose_weighted_option<T:Copy>(v: &[Weighted<T>]) -> Option<T> {
         let mut total = 0u;
         for v.each |item| {
@@ -232,10 +331,6 @@ impl Rng {
         util::unreachable();
     }
 
-    /**
-     * Return a vec containing copies of the items, in order, where
-     * the weight of the item determines how many copies there are
-     */
     fn weighted_vec<T:Copy>(v: &[Weighted<T>]) -> ~[T] {
         let mut r = ~[];
         for v.each |item| {
@@ -246,14 +341,12 @@ impl Rng {
         move r
     }
 
-    /// Shuffle a vec
     fn shuffle<T:Copy>(values: &[T]) -> ~[T] {
         let mut m = vec::from_slice(values);
         self.shuffle_mut(m);
         move m
     }
 
-    /// Shuffle a mutable vec in place
     fn shuffle_mut<T>(values: &[mut T]) {
         let mut i = values.len();
         while i >= 2u {
@@ -264,6 +357,154 @@ impl Rng {
         }
     }
 
+    fn gen_normal(mean: f64, std_dev: f64) -> f64 {
+        // Marsaglia's polar method
+        loop {
+            let u = 2.0 * self.gen_f64() - 1.0;
+            let v = 2.0 * self.gen_f64() - 1.0;
+            let s = u * u + v * v;
+            if s > 0.0 && s < 1.0 {
+                return mean + std_dev * u * f64::sqrt(-2.0 * f64::ln(s) / s);
+            }
+        }
+    }
+
+    fn gen_exp(lambda: f64) -> f64 {
+        assert lambda > 0.0;
+        -f64::ln(gen_open01(&self)) / lambda
+    }
+
+    fn gen_poisson(lambda: f64) -> uint {
+        assert lambda > 0.0;
+        if lambda < 12.0 {
+            // Knuth: count uniforms until their product drops below
+            // e^-lambda
+            let limit = f64::exp(-lambda);
+            let mut k = 0u;
+            let mut p = self.gen_f64();
+            while p > limit {
+                k += 1;
+                p *= self.gen_f64();
+            }
+            k
+        } else {
+            // rejection from a Lorentzian envelope, as in Numerical Recipes
+            let sq = f64::sqrt(2.0 * lambda);
+            let log_lambda = f64::ln(lambda);
+            let g = lambda * log_lambda - ln_gamma(lambda + 1.0);
+            loop {
+                let mut y = 0.0;
+                let mut em = -1.0;
+                while em < 0.0 {
+                    y = f64::tan(f64::consts::pi * self.gen_f64());
+                    em = sq * y + lambda;
+                }
+                em = f64::floor(em);
+                let t = 0.9 * (1.0 + y * y) *
+                    f64::exp(em * log_lambda - ln_gamma(em + 1.0) - g);
+                if self.gen_f64() <= t {
+                    return em as uint;
+                }
+            }
+        }
+    }
+
+    fn gen_zipf(n: uint, s: f64) -> uint {
+        assert n > 0 && s > 0.0;
+        // Rejection-inversion, after Hormann and Derflinger, "Rejection-
+        // inversion to generate variates from monotone discrete
+        // distributions". Constant time and space for any n.
+        let h_x1 = zipf_h_integral(1.5, s) - 1.0;
+        let h_n = zipf_h_integral(n as f64 + 0.5, s);
+        let cutoff = 2.0 - zipf_h_integral_inv(
+            zipf_h_integral(2.5, s) - zipf_h(2.0, s), s);
+        loop {
+            let u = h_n + self.gen_f64() * (h_x1 - h_n);
+            let x = zipf_h_integral_inv(u, s);
+            let mut k = (x + 0.5) as uint;
+            if k < 1 {
+                k = 1;
+            } else if k > n {
+                k = n;
+            }
+            let kf = k as f64;
+            if kf - x <= cutoff ||
+                u >= zipf_h_integral(kf + 0.5, s) - zipf_h(kf, s) {
+                return k;
+            }
+        }
+    }
+
+    fn sample<A: Copy, I: BaseIter<A>>(iter: &I, n: uint) -> ~[A] {
+        let mut reservoir = vec::with_capacity(n);
+        let mut seen = 0u;
+        for iter.each |item| {
+            if reservoir.len() < n {
+                reservoir.push(*item);
+            } else {
+                let k = self.gen_uint_range(0u, seen + 1u);
+                if k < n {
+                    reservoir[k] = *item;
+                }
+            }
+            seen += 1u;
+        }
+        move reservoir
+    }
+}
+
+/// Return a random f64 in the open interval (0,1)
+fn gen_open01<R: Rng>(rng: &R) -> f64 {
+    loop {
+        let u = rng.gen_f64();
+        if u > 0.0 && u < 1.0 {
+            return u;
+        }
+    }
+}
+
+fn ln_gamma(x: f64) -> f64 {
+    let mut sign: c_int = 0;
+    f64::lgamma(x, &mut sign)
+}
+
+// Helpers for gen_zipf. h is the (unnormalized) density 1/x^s, H its
+// integral and H^-1 the inverse of the integral. The helpers keep the
+// s ~= 1 cases numerically stable.
+
+pure fn zipf_h(x: f64, s: f64) -> f64 {
+    f64::exp(-s * f64::ln(x))
+}
+
+pure fn zipf_h_integral(x: f64, s: f64) -> f64 {
+    let log_x = f64::ln(x);
+    expm1_over_x((1.0 - s) * log_x) * log_x
+}
+
+pure fn zipf_h_integral_inv(x: f64, s: f64) -> f64 {
+    let mut t = x * (1.0 - s);
+    if t < -1.0 {
+        t = -1.0;
+    }
+    f64::exp(ln1p_over_x(t) * x)
+}
+
+/// ln(1 + x) / x, continuously extended to 1 at x = 0
+pure fn ln1p_over_x(x: f64) -> f64 {
+    if f64::abs(x) > 1e-8 {
+        f64::ln1p(x) / x
+    } else {
+        1.0 - x * (0.5 - x * (1.0 / 3.0 - 0.25 * x))
+    }
+}
+
+/// (e^x - 1) / x, continuously extended to 1 at x = 0
+pure fn expm1_over_x(x: f64) -> f64 {
+    if f64::abs(x) > 1e-8 {
+        f64::expm1(x) / x
+    } else {
+        1.0 + x * 0.5 * (1.0 + x / 3.0 * (1.0 + 0.25 * x))
+    }
 }
 
 struct RandRes {
@@ -344,6 +585,259 @@ pub pure fn seeded_xorshift(x: u32, y: u32, z: u32, w: u32) -> Rng {
     XorShiftState { x: x, y: y, z: z, w: w } as Rng
 }
 
+const RAND_SIZE_LEN: uint = 8;
+const RAND_SIZE: uint = 1 << RAND_SIZE_LEN;
+
+/**
+ * A random number generator implementing Bob Jenkins' ISAAC algorithm
+ * entirely in Rust.
+ *
+ * Unlike the generators returned by `Rng()` this is a plain struct that
+ * can live on the stack or inside other structs. Its output for a given
+ * seed is the same on every platform.
+ */
+pub struct IsaacRng {
+    priv mut cnt: uint,
+    priv rsl: [mut u32 * 256],
+    priv mem: [mut u32 * 256],
+    priv mut a: u32,
+    priv mut b: u32,
+    priv mut c: u32,
+}
+
+pub impl IsaacRng {
+    /// Create an ISAAC random number generator with a random seed
+    static fn new() -> IsaacRng {
+        let s = seed();
+        IsaacRng::new_seeded(s)
+    }
+
+    /**
+     * Create an ISAAC random number generator using the specified seed.
+     * The seed is read as little-endian 32-bit words and only its first
+     * 1024 bytes are used; shorter seeds are padded with zeroes.
+     */
+    static fn new_seeded(seed: &[u8]) -> IsaacRng {
+        let rng = IsaacRng {
+            mut cnt: 0,
+            rsl: [mut 0, ..256],
+            mem: [mut 0, ..256],
+            mut a: 0,
+            mut b: 0,
+            mut c: 0,
+        };
+        let len = uint::min(seed.len(), RAND_SIZE * 4);
+        for uint::range(0, len) |i| {
+            rng.rsl[i / 4] |= (seed[i] as u32) << (8 * (i % 4)) as u32;
+        }
+        rng.init();
+        move rng
+    }
+
+    /**
+     * Create an ISAAC random number generator with the all-zero seed, as
+     * used by the reference implementation's test vectors
+     */
+    static fn new_unseeded() -> IsaacRng {
+        IsaacRng::new_seeded([])
+    }
+
+    /// Mix the seed in `rsl` into `mem` and produce the first results
+    priv fn init(&self) {
+        let v = [mut 0x9e3779b9u32, ..8]; // the golden ratio
+        for 4.times {
+            isaac_mix(&v);
+        }
+        // two passes, so that all of the seed affects all of mem
+        for uint::range(0, 2) |pass| {
+            for uint::range(0, RAND_SIZE / 8) |j| {
+                for uint::range(0, 8) |k| {
+                    let i = j * 8 + k;
+                    v[k] += if pass == 0 { self.rsl[i] } else { self.mem[i] };
+                }
+                isaac_mix(&v);
+                for uint::range(0, 8) |k| {
+                    self.mem[j * 8 + k] = v[k];
+                }
+            }
+        }
+        self.isaac();
+    }
+
+    /// Refill `rsl` with the next RAND_SIZE results
+    priv fn isaac(&self) {
+        self.c += 1;
+        let mut a = self.a;
+        let mut b = self.b + self.c;
+        for uint::range(0, RAND_SIZE) |i| {
+            let mix = match i % 4 {
+                0 => a << 13,
+                1 => a >> 6,
+                2 => a << 2,
+                _ => a >> 16
+            };
+            let x = self.mem[i];
+            a = (a ^ mix) + self.mem[(i + RAND_SIZE / 2) % RAND_SIZE];
+            let y = self.ind(x) + a + b;
+            self.mem[i] = y;
+            b = self.ind(y >> RAND_SIZE_LEN) + x;
+            self.rsl[i] = b;
+        }
+        self.a = a;
+        self.b = b;
+        self.cnt = RAND_SIZE;
+    }
+
+    #[inline(always)]
+    priv fn ind(&self, x: u32) -> u32 {
+        self.mem[(x >> 2) as uint & (RAND_SIZE - 1)]
+    }
+}
+
+impl IsaacRng: Rng {
+    fn next() -> u32 {
+        if self.cnt == 0 {
+            self.isaac();
+        }
+        self.cnt -= 1;
+        self.rsl[self.cnt]
+    }
+}
+
+fn isaac_mix(v: &[mut u32 * 8]) {
+    v[0] ^= v[1] << 11; v[3] += v[0]; v[1] += v[2];
+    v[1] ^= v[2] >> 2;  v[4] += v[1]; v[2] += v[3];
+    v[2] ^= v[3] << 8;  v[5] += v[2]; v[3] += v[4];
+    v[3] ^= v[4] >> 16; v[6] += v[3]; v[4] += v[5];
+    v[4] ^= v[5] << 10; v[7] += v[4]; v[5] += v[6];
+    v[5] ^= v[6] >> 4;  v[0] += v[5]; v[6] += v[7];
+    v[6] ^= v[7] << 8;  v[1] += v[6]; v[7] += v[0];
+    v[7] ^= v[0] >> 9;  v[2] += v[7]; v[0] += v[1];
+}
+
+/**
+ * The 64-bit variant of ISAAC. It is faster than `IsaacRng` when 64-bit
+ * values are wanted, and like it gives the same output for a given seed
+ * on every platform.
+ */
+pub struct Isaac64Rng {
+    priv mut cnt: uint,
+    priv rsl: [mut u64 * 256],
+    priv mem: [mut u64 * 256],
+    priv mut a: u64,
+    priv mut b: u64,
+    priv mut c: u64,
+}
+
+pub impl Isaac64Rng {
+    /// Create an ISAAC-64 random number generator with a random seed
+    static fn new() -> Isaac64Rng {
+        let s = seed();
+        Isaac64Rng::new_seeded(s)
+    }
+
+    /**
+     * Create an ISAAC-64 random number generator using the specified seed.
+     * The seed is read as little-endian 64-bit words and only its first
+     * 2048 bytes are used; shorter seeds are padded with zeroes.
+     */
+    static fn new_seeded(seed: &[u8]) -> Isaac64Rng {
+        let rng = Isaac64Rng {
+            mut cnt: 0,
+            rsl: [mut 0, ..256],
+            mem: [mut 0, ..256],
+            mut a: 0,
+            mut b: 0,
+            mut c: 0,
+        };
+        let len = uint::min(seed.len(), RAND_SIZE * 8);
+        for uint::range(0, len) |i| {
+            rng.rsl[i / 8] |= (seed[i] as u64) << (8 * (i % 8)) as u64;
+        }
+        rng.init();
+        move rng
+    }
+
+    /// Create an ISAAC-64 random number generator with the all-zero seed
+    static fn new_unseeded() -> Isaac64Rng {
+        Isaac64Rng::new_seeded([])
+    }
+
+    /// Return the next random u64
+    fn next_u64(&self) -> u64 {
+        if self.cnt == 0 {
+            self.isaac();
+        }
+        self.cnt -= 1;
+        self.rsl[self.cnt]
+    }
+
+    priv fn init(&self) {
+        let v = [mut 0x9e3779b97f4a7c13u64, ..8]; // the golden ratio
+        for 4.times {
+            isaac64_mix(&v);
+        }
+        for uint::range(0, 2) |pass| {
+            for uint::range(0, RAND_SIZE / 8) |j| {
+                for uint::range(0, 8) |k| {
+                    let i = j * 8 + k;
+                    v[k] += if pass == 0 { self.rsl[i] } else { self.mem[i] };
+                }
+                isaac64_mix(&v);
+                for uint::range(0, 8) |k| {
+                    self.mem[j * 8 + k] = v[k];
+                }
+            }
+        }
+        self.isaac();
+    }
+
+    priv fn isaac(&self) {
+        self.c += 1;
+        let mut a = self.a;
+        let mut b = self.b + self.c;
+        for uint::range(0, RAND_SIZE) |i| {
+            let mix = match i % 4 {
+                0 => !(a ^ (a << 21)),
+                1 => a ^ (a >> 5),
+                2 => a ^ (a << 12),
+                _ => a ^ (a >> 33)
+            };
+            let x = self.mem[i];
+            a = mix + self.mem[(i + RAND_SIZE / 2) % RAND_SIZE];
+            let y = self.ind(x) + a + b;
+            self.mem[i] = y;
+            b = self.ind(y >> RAND_SIZE_LEN) + x;
+            self.rsl[i] = b;
+        }
+        self.a = a;
+        self.b = b;
+        self.cnt = RAND_SIZE;
+    }
+
+    #[inline(always)]
+    priv fn ind(&self, x: u64) -> u64 {
+        self.mem[(x >> 3) as uint & (RAND_SIZE - 1)]
+    }
+}
+
+impl Isaac64Rng: Rng {
+    fn next() -> u32 {
+        self.next_u64() as u32
+    }
+}
+
+fn isaac64_mix(v: &[mut u64 * 8]) {
+    v[0] -= v[4]; v[5] ^= v[7] >> 9;  v[7] += v[0];
+    v[1] -= v[5]; v[6] ^= v[0] << 9;  v[0] += v[1];
+    v[2] -= v[6]; v[7] ^= v[1] >> 23; v[1] += v[2];
+    v[3] -= v[7]; v[0] ^= v[2] << 15; v[2] += v[3];
+    v[4] -= v[0]; v[1] ^= v[3] >> 14; v[3] += v[4];
+    v[5] -= v[1]; v[2] ^= v[4] << 20; v[4] += v[5];
+    v[6] -= v[2]; v[3] ^= v[5] >> 17; v[5] += v[6];
+    v[7] -= v[3]; v[4] ^= v[6] << 14; v[6] += v[7];
+}
+
 
 // used to make space in TLS for a random number generator
 fn tls_rng_state(_v: @RandRes) {}
@@ -381,8 +875,12 @@ pub fn random() -> uint {
 #[cfg(test)]
 pub mod tests {
     use debug;
+    use f64;
     use option::{None, Option, Some};
     use rand;
+    use rand::{Isaac64Rng, IsaacRng, Rng, RngUtil};
+    use uint;
+    use vec;
 
     #[test]
     pub fn rng_seeded() {
@@ -538,6 +1036,158 @@ pub mod tests {
         assert r.shuffle(~[1, 1, 1]) == ~[1, 1, 1];
     }
 
+    #[test]
+    pub fn isaac_unseeded() {
+        // the published test vector for the all-zero seed is the second
+        // block of results, which is handed out in reverse
+        let r = IsaacRng::new_unseeded();
+        let first = vec::from_fn(5, |_i| r.next());
+        assert first == ~[405143795u32, 806046349, 807101986, 2961886497,
+                          695195257];
+        for 505.times { r.next(); }
+        assert r.next() == 0xe448e96d;
+        assert r.next() == 0xf650e4c8;
+    }
+
+    #[test]
+    pub fn isaac_seeded() {
+        let seed = &[1u8, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        let ra = IsaacRng::new_seeded(seed);
+        let rb = IsaacRng::new_seeded(seed);
+        let v = vec::from_fn(5, |_i| ra.next());
+        assert v == ~[3007656455u32, 3541738352, 2691563687, 3179798646,
+                      3522047307];
+        for 1000.times { ra.next(); }
+        let v = vec::from_fn(3, |_i| ra.next());
+        assert v == ~[888508273u32, 1976003909, 331954785];
+        assert rb.gen_str(100u) == IsaacRng::new_seeded(seed).gen_str(100u);
+    }
+
+    #[test]
+    pub fn isaac64_unseeded() {
+        let r = Isaac64Rng::new_unseeded();
+        let first = vec::from_fn(4, |_i| r.next_u64());
+        assert first == ~[11329126462075137345u64, 3096006490854172103,
+                          4961560858198160711, 11247167491742853858];
+        for 507.times { r.next_u64(); }
+        assert r.next_u64() == 0x12a8f216af9418c2;
+    }
+
+    #[test]
+    pub fn isaac64_seeded() {
+        let seed = &[1u8, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        let r = Isaac64Rng::new_seeded(seed);
+        let v = vec::from_fn(4, |_i| r.next_u64());
+        assert v == ~[17914753153097193239u64, 14562280375662257946,
+                      12170731883179097813, 6020588572800289518];
+        for 1000.times { r.next_u64(); }
+        let v = vec::from_fn(3, |_i| r.next_u64());
+        assert v == ~[3826011113366947144u64, 16288852796092137581,
+                      7416706984983738964];
+    }
+
+    #[test]
+    pub fn isaac_new() {
+        // system seeded generators should not agree
+        let ra = IsaacRng::new();
+        let rb = IsaacRng::new();
+        assert ra.gen_bytes(32u) != rb.gen_bytes(32u);
+    }
+
+    // The distribution tests draw from fixed seeds, so they are
+    // deterministic; the tolerances are still several standard errors
+    // wide so that they don't pin down the exact stream.
+
+    fn mean_and_variance(v: &[f64]) -> (f64, f64) {
+        let n = v.len() as f64;
+        let mut sum = 0.0;
+        for v.each |x| { sum += *x; }
+        let mean = sum / n;
+        let mut sq = 0.0;
+        for v.each |x| { sq += (*x - mean) * (*x - mean); }
+        (mean, sq / n)
+    }
+
+    fn assert_close(a: f64, b: f64, tolerance: f64) {
+        if f64::abs(a - b) > tolerance {
+            fail fmt!("%? is not within %? of %?", a, tolerance, b);
+        }
+    }
+
+    #[test]
+    pub fn gen_normal() {
+        let r = IsaacRng::new_seeded([1u8]);
+        let v = vec::from_fn(10000, |_i| r.gen_normal(5.0, 2.0));
+        let (mean, var) = mean_and_variance(v);
+        assert_close(mean, 5.0, 0.1);
+        assert_close(var, 4.0, 0.3);
+    }
+
+    #[test]
+    pub fn gen_exp() {
+        let r = IsaacRng::new_seeded([2u8]);
+        let v = vec::from_fn(10000, |_i| r.gen_exp(2.0));
+        assert v.all(|x| *x >= 0.0);
+        let (mean, var) = mean_and_variance(v);
+        assert_close(mean, 0.5, 0.03);
+        assert_close(var, 0.25, 0.04);
+    }
+
+    #[test]
+    pub fn gen_poisson() {
+        let r = IsaacRng::new_seeded([3u8]);
+        // both the small and large lambda methods
+        for [3.0, 50.0].each |lambda| {
+            let v = vec::from_fn(10000, |_i| r.gen_poisson(*lambda) as f64);
+            let (mean, var) = mean_and_variance(v);
+            assert_close(mean, *lambda, 0.05 * *lambda);
+            assert_close(var, *lambda, 0.15 * *lambda);
+        }
+    }
+
+    #[test]
+    pub fn gen_zipf() {
+        let r = IsaacRng::new_seeded([4u8]);
+        let n = 10u;
+        let mut counts = vec::from_elem(n + 1, 0u);
+        for 20000.times {
+            let k = r.gen_zipf(n, 1.0);
+            assert k >= 1 && k <= n;
+            counts[k] += 1;
+        }
+        let mut norm = 0.0;
+        for uint::range(1, n + 1) |k| { norm += 1.0 / (k as f64); }
+        for uint::range(1, 4) |k| {
+            let expected = 1.0 / (k as f64) / norm;
+            assert_close(counts[k] as f64 / 20000.0, expected, 0.02);
+        }
+        assert r.gen_zipf(1u, 2.0) == 1u;
+    }
+
+    #[test]
+    pub fn sample() {
+        let r = IsaacRng::new_seeded([5u8]);
+        let empty: ~[int] = ~[];
+        assert r.sample(&empty, 3u).is_empty();
+        assert r.sample(&~[1, 2, 3], 0u).is_empty();
+
+        let all = r.sample(&~[1, 2, 3], 5u);
+        assert all.len() == 3u;
+        assert all.contains(&1) && all.contains(&2) && all.contains(&3);
+
+        // every element should be picked about equally often
+        let v = vec::from_fn(10u, |i| i);
+        let mut counts = vec::from_elem(10u, 0u);
+        for 5000.times {
+            let s = r.sample(&v, 3u);
+            assert s.len() == 3u;
+            for s.each |i| { counts[*i] += 1; }
+        }
+        for counts.each |c| {
+            assert_close(*c as f64, 1500.0, 150.0);
+        }
+    }
+
     #[test]
     pub fn task_rng() {
         let r = rand::task_rng();
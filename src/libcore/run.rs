@@ -13,6 +13,7 @@
 #[forbid(deprecated_pattern)];
 
 //! Process spawning
+use cast;
 use io;
 use io::ReaderUtil;
 use libc;
@@ -20,6 +21,8 @@ use libc::{pid_t, c_void, c_int};
 use oldcomm;
 use option::{Some, None};
 use os;
+use pipes;
+use pipes::{GenericChan, GenericPort};
 use prelude::*;
 use ptr;
 use run;
@@ -85,9 +88,21 @@ pub fn spawn_process(prog: &str, args: &[~str],
                  dir: &Option<~str>,
                  in_fd: c_int, out_fd: c_int, err_fd: c_int)
               -> pid_t {
+    spawn_process_internal(prog, args, env, false, dir,
+                           in_fd, out_fd, err_fd)
+}
+
+// Like spawn_process, but when `exact_env` is true an empty `env` gives
+// the child an empty environment rather than the parent's.
+fn spawn_process_internal(prog: &str, args: &[~str],
+                          env: &Option<~[(~str,~str)]>,
+                          exact_env: bool,
+                          dir: &Option<~str>,
+                          in_fd: c_int, out_fd: c_int, err_fd: c_int)
+                       -> pid_t {
     unsafe {
         do with_argv(prog, args) |argv| {
-            do with_envp(env) |envp| {
+            do with_envp(env, exact_env) |envp| {
                 do with_dirp(dir) |dirp| {
                     rustrt::rust_run_program(argv, envp, dirp,
                                              in_fd, out_fd, err_fd)
@@ -111,12 +126,12 @@ fn with_argv<T>(prog: &str, args: &[~str],
 }
 
 #[cfg(unix)]
-fn with_envp<T>(env: &Option<~[(~str,~str)]>,
+fn with_envp<T>(env: &Option<~[(~str,~str)]>, exact: bool,
                 cb: fn(*c_void) -> T) -> T {
     // On posixy systems we can pass a char** for envp, which is
     // a null-terminated array of "k=v\n" strings.
     match *env {
-      Some(ref es) if !vec::is_empty(*es) => {
+      Some(ref es) if exact || !vec::is_empty(*es) => {
         let mut tmps = ~[];
         let mut ptrs = ~[];
 
@@ -136,14 +151,14 @@ fn with_envp<T>(env: &Option<~[(~str,~str)]>,
 }
 
 #[cfg(windows)]
-fn with_envp<T>(env: &Option<~[(~str,~str)]>,
+fn with_envp<T>(env: &Option<~[(~str,~str)]>, exact: bool,
                 cb: fn(*c_void) -> T) -> T {
     // On win32 we pass an "environment block" which is not a char**, but
     // rather a concatenation of null-terminated k=v\0 sequences, with a final
     // \0 to terminate.
     unsafe {
         match *env {
-          Some(ref es) if !vec::is_empty(*es) => {
+          Some(ref es) if exact || !vec::is_empty(*es) => {
             let mut blk : ~[u8] = ~[];
             for vec::each(*es) |e| {
                 let (k,v) = copy *e;
@@ -152,6 +167,8 @@ fn with_envp<T>(env: &Option<~[(~str,~str)]>,
                 blk += v;
                 ::cast::forget(v);
             }
+            // An empty block still needs two terminators
+            if es.is_empty() { blk += ~[0_u8]; }
             blk += ~[0_u8];
             vec::as_imm_buf(blk, |p, _len| cb(::cast::reinterpret_cast(&p)))
           }
@@ -398,56 +415,560 @@ pub fn readclose(fd: c_int) -> ~str {
 
 /// Waits for a process to exit and returns the exit code
 pub fn waitpid(pid: pid_t) -> int {
-    return waitpid_os(pid);
+    match wait_status(pid) {
+        Exited(code) => code,
+        Signaled(_) => 1
+    }
+}
+
+/// Waits for a process to exit and returns how it terminated
+pub fn wait_status(pid: pid_t) -> ExitStatus {
+    return wait_os(pid);
 
     #[cfg(windows)]
-    fn waitpid_os(pid: pid_t) -> int {
-        os::waitpid(pid) as int
+    fn wait_os(pid: pid_t) -> ExitStatus {
+        Exited(os::waitpid(pid) as int)
     }
 
     #[cfg(unix)]
-    fn waitpid_os(pid: pid_t) -> int {
-        #[cfg(target_os = "linux")]
-        #[cfg(target_os = "android")]
-        fn WIFEXITED(status: i32) -> bool {
-            (status & 0xffi32) == 0i32
+    fn wait_os(pid: pid_t) -> ExitStatus {
+        decode_status(os::waitpid(pid))
+    }
+}
+
+/// Decodes a status returned by waitpid(2)
+#[cfg(unix)]
+fn decode_status(status: c_int) -> ExitStatus {
+    // These are WIFEXITED, WEXITSTATUS and WTERMSIG, which agree on all
+    // the platforms we support. We never ask about stopped children.
+    let signal = status & 0x7f;
+    if signal == 0 {
+        Exited(((status >> 8) & 0xff) as int)
+    } else {
+        Signaled(signal as int)
+    }
+}
+
+/// How one of a child process's standard streams is set up
+pub enum Stdio {
+    /// Share the parent's stream
+    Inherit,
+    /// Connect the stream to a pipe that the parent can use
+    Piped,
+    /// Connect the stream to the null device
+    Null,
+    /// Read the stream from a file, or truncate a file and write to it
+    Redirect(Path),
+}
+
+/// How a child process terminated
+#[deriving_eq]
+pub enum ExitStatus {
+    /// The process exited with the given code
+    Exited(int),
+    /// The process was killed by the given signal
+    Signaled(int),
+}
+
+pub impl ExitStatus {
+    /// Returns true if the process exited with code 0
+    pure fn success(&self) -> bool {
+        match *self {
+            Exited(0) => true,
+            _ => false
+        }
+    }
+}
+
+impl ExitStatus: ToStr {
+    pure fn to_str() -> ~str {
+        match self {
+            Exited(code) => fmt!("exit code: %d", code),
+            Signaled(signal) => fmt!("signal: %d", signal)
+        }
+    }
+}
+
+/// The collected result of running a process to completion
+pub struct ProcessOutput {
+    status: ExitStatus,
+    output: ~[u8],
+    error: ~[u8],
+}
+
+/**
+ * A builder for spawning child processes
+ *
+ * By default the child inherits the parent's environment, working
+ * directory and standard streams.
+ *
+ * # Example
+ *
+ * ~~~
+ * let out = Command::new("ls").arg("-l").dir(&Path("/tmp")).output();
+ * ~~~
+ */
+pub struct Command {
+    priv program: ~str,
+    priv args: ~[~str],
+    // None means the parent's environment, unchanged
+    priv env: Option<~[(~str, ~str)]>,
+    priv dir: Option<~str>,
+    priv stdin: Stdio,
+    priv stdout: Stdio,
+    priv stderr: Stdio,
+}
+
+pub impl Command {
+    /// Creates a command that will run `program`, searching the path
+    static fn new(program: &str) -> Command {
+        Command {
+            program: str::from_slice(program),
+            args: ~[],
+            env: None,
+            dir: None,
+            stdin: Inherit,
+            stdout: Inherit,
+            stderr: Inherit,
+        }
+    }
+
+    /// Adds an argument
+    fn arg(self, arg: &str) -> Command {
+        let mut cmd = move self;
+        cmd.args.push(str::from_slice(arg));
+        move cmd
+    }
+
+    /// Adds several arguments
+    fn args(self, args: &[~str]) -> Command {
+        let mut cmd = move self;
+        cmd.args.push_all(args);
+        move cmd
+    }
+
+    /// Sets an environment variable for the child
+    fn env(self, key: &str, val: &str) -> Command {
+        let mut cmd = move self;
+        let mut vars = env_without(&cmd.env, key);
+        vars.push((str::from_slice(key), str::from_slice(val)));
+        cmd.env = Some(move vars);
+        move cmd
+    }
+
+    /// Removes an environment variable from the child's environment
+    fn env_remove(self, key: &str) -> Command {
+        let mut cmd = move self;
+        cmd.env = Some(env_without(&cmd.env, key));
+        move cmd
+    }
+
+    /// Starts the child with an empty environment
+    fn env_clear(self) -> Command {
+        let mut cmd = move self;
+        cmd.env = Some(~[]);
+        move cmd
+    }
+
+    /// Sets the working directory of the child
+    fn dir(self, dir: &Path) -> Command {
+        let mut cmd = move self;
+        cmd.dir = Some(dir.to_str());
+        move cmd
+    }
+
+    /// Sets up the child's standard input
+    fn stdin(self, cfg: Stdio) -> Command {
+        let mut cmd = move self;
+        cmd.stdin = move cfg;
+        move cmd
+    }
+
+    /// Sets up the child's standard output
+    fn stdout(self, cfg: Stdio) -> Command {
+        let mut cmd = move self;
+        cmd.stdout = move cfg;
+        move cmd
+    }
+
+    /// Sets up the child's standard error
+    fn stderr(self, cfg: Stdio) -> Command {
+        let mut cmd = move self;
+        cmd.stderr = move cfg;
+        move cmd
+    }
+
+    /// Starts the child process
+    fn spawn(&self) -> Result<Process, ~str> {
+        let (in_child, in_parent) = match open_stdio(&self.stdin, true) {
+            Ok(fds) => fds,
+            Err(move e) => return Err(move e)
+        };
+        let (out_child, out_parent) = match open_stdio(&self.stdout, false) {
+            Ok(fds) => fds,
+            Err(move e) => {
+                close_fds([in_child, in_parent]);
+                return Err(move e);
+            }
+        };
+        let (err_child, err_parent) = match open_stdio(&self.stderr, false) {
+            Ok(fds) => fds,
+            Err(move e) => {
+                close_fds([in_child, in_parent, out_child, out_parent]);
+                return Err(move e);
+            }
+        };
+
+        let pid = spawn_process_internal(self.program, self.args,
+                                         &self.env, true, &self.dir,
+                                         in_child, out_child, err_child);
+        close_fds([in_child, out_child, err_child]);
+
+        if pid == -1 as pid_t {
+            close_fds([in_parent, out_parent, err_parent]);
+            return Err(fmt!("error spawning %s: %s",
+                            self.program, os::last_os_error()));
+        }
+
+        Ok(Process {
+            pid: pid,
+            mut input: if in_parent == -1 { None } else { Some(in_parent) },
+            mut output: fdopen_parent(out_parent),
+            mut error: fdopen_parent(err_parent),
+            mut status: None,
+        })
+    }
+
+    /**
+     * Runs the child to completion, collecting everything it writes to
+     * stdout and stderr
+     *
+     * Both streams are read at the same time, so a child that fills up
+     * one pipe while we are waiting on the other cannot deadlock.
+     */
+    fn output(&self) -> Result<ProcessOutput, ~str> {
+        let cmd = Command {
+            stdout: Piped,
+            stderr: Piped,
+            .. copy *self
+        };
+        match cmd.spawn() {
+            Ok(ref p) => Ok(p.wait_with_output()),
+            Err(move e) => Err(move e)
+        }
+    }
+
+    /// Runs the child to completion and returns how it terminated
+    fn status(&self) -> Result<ExitStatus, ~str> {
+        match self.spawn() {
+            Ok(ref p) => Ok(p.wait()),
+            Err(move e) => Err(move e)
+        }
+    }
+}
+
+// The environment a command will pass to its child, minus `key`
+fn env_without(env: &Option<~[(~str, ~str)]>,
+               key: &str) -> ~[(~str, ~str)] {
+    let vars = match *env {
+        Some(ref vars) => copy *vars,
+        None => os::env()
+    };
+    do vars.filtered |var| {
+        match *var {
+            (ref k, _) => !str::eq_slice(*k, key)
+        }
+    }
+}
+
+#[cfg(unix)]
+fn null_device() -> Path { Path("/dev/null") }
+
+#[cfg(windows)]
+fn null_device() -> Path { Path("NUL") }
+
+/**
+ * Opens the descriptors for one of a child's standard streams, returning
+ * the end the child uses (0 to inherit) and the end the parent keeps (-1
+ * for none)
+ */
+fn open_stdio(cfg: &Stdio, input: bool) -> Result<(c_int, c_int), ~str> {
+    match *cfg {
+        Inherit => Ok((0 as c_int, -1 as c_int)),
+        Piped => {
+            let pipe = os::pipe();
+            if input {
+                Ok((pipe.in, pipe.out))
+            } else {
+                Ok((pipe.out, pipe.in))
+            }
+        }
+        Null => open_fd(&null_device(), input),
+        Redirect(ref path) => open_fd(path, input)
+    }
+}
+
+fn open_fd(path: &Path, input: bool) -> Result<(c_int, c_int), ~str> {
+    let flags = if input {
+        libc::O_RDONLY
+    } else {
+        libc::O_WRONLY | libc::O_CREAT | libc::O_TRUNC
+    };
+    let fd = unsafe {
+        do str::as_c_str(path.to_str()) |pathbuf| {
+            libc::open(pathbuf, flags as c_int,
+                       (libc::S_IRUSR | libc::S_IWUSR) as c_int)
+        }
+    };
+    if fd < (0 as c_int) {
+        Err(fmt!("error opening %s: %s",
+                 path.to_str(), os::last_os_error()))
+    } else {
+        Ok((fd, -1 as c_int))
+    }
+}
+
+// Closes descriptors returned by open_stdio, skipping the placeholders
+fn close_fds(fds: &[c_int]) {
+    for fds.each |fd| {
+        if *fd > (0 as c_int) {
+            os::close(*fd);
+        }
+    }
+}
+
+fn fdopen_parent(fd: c_int) -> Option<*libc::FILE> {
+    if fd == -1 as c_int { None } else { Some(os::fdopen(fd)) }
+}
+
+fn read_and_close(file: *libc::FILE) -> ~[u8] {
+    let reader = io::FILE_reader(file, false);
+    let buf = reader.read_whole_stream();
+    unsafe { libc::fclose(file); }
+    move buf
+}
+
+/**
+ * A running child process, as started by `Command::spawn`
+ *
+ * Dropping a `Process` closes its pipes and waits for it to exit.
+ */
+pub struct Process {
+    priv pid: pid_t,
+    priv mut input: Option<c_int>,
+    priv mut output: Option<*libc::FILE>,
+    priv mut error: Option<*libc::FILE>,
+    priv mut status: Option<ExitStatus>,
+}
+
+pub impl Process {
+    /// Returns the process id of the child
+    fn get_id(&self) -> pid_t { self.pid }
+
+    /// Returns a writer for the child's stdin, which must be piped
+    fn input(&self) -> io::Writer {
+        match self.input {
+            Some(fd) => io::fd_writer(fd, false),
+            None => fail ~"stdin of the child process is not piped"
         }
+    }
 
-        #[cfg(target_os = "macos")]
-        #[cfg(target_os = "freebsd")]
-        fn WIFEXITED(status: i32) -> bool {
-            (status & 0x7fi32) == 0i32
+    /// Returns a reader for the child's stdout, which must be piped
+    fn output(&self) -> io::Reader {
+        match self.output {
+            Some(file) => io::FILE_reader(file, false),
+            None => fail ~"stdout of the child process is not piped"
         }
+    }
 
-        #[cfg(target_os = "linux")]
-        #[cfg(target_os = "android")]
-        fn WEXITSTATUS(status: i32) -> i32 {
-            (status >> 8i32) & 0xffi32
+    /// Returns a reader for the child's stderr, which must be piped
+    fn error(&self) -> io::Reader {
+        match self.error {
+            Some(file) => io::FILE_reader(file, false),
+            None => fail ~"stderr of the child process is not piped"
         }
+    }
 
-        #[cfg(target_os = "macos")]
-        #[cfg(target_os = "freebsd")]
-        fn WEXITSTATUS(status: i32) -> i32 {
-            status >> 8i32
+    /// Closes the child's stdin, if it is piped
+    fn close_input(&self) {
+        match self.input {
+            Some(fd) => {
+                os::close(fd);
+                self.input = None;
+            }
+            None => ()
         }
+    }
 
-        let status = os::waitpid(pid);
-        return if WIFEXITED(status) {
-            WEXITSTATUS(status) as int
+    /**
+     * Sends a signal to the child
+     *
+     * On Windows any signal terminates the process.
+     */
+    fn kill(&self, signal: int) -> Result<(), ~str> {
+        if self.status.is_some() {
+            return Err(~"the process has already exited");
+        }
+        return if kill_os(self.pid, signal) {
+            Ok(())
         } else {
-            1
+            Err(fmt!("error signalling process %d: %s",
+                     self.pid as int, os::last_os_error()))
+        };
+
+        #[cfg(windows)]
+        fn kill_os(pid: pid_t, _signal: int) -> bool {
+            unsafe {
+                let handle: libc::HANDLE = cast::reinterpret_cast(&pid);
+                libc::TerminateProcess(handle, 1 as libc::c_uint) !=
+                    (0 as libc::BOOL)
+            }
+        }
+
+        #[cfg(unix)]
+        fn kill_os(pid: pid_t, signal: int) -> bool {
+            unsafe {
+                libc::kill(pid, signal as c_int) == (0 as c_int)
+            }
+        }
+    }
+
+    /// Returns the exit status if the child has exited, without blocking
+    fn try_wait(&self) -> Option<ExitStatus> {
+        if self.status.is_none() {
+            self.status = try_wait_os(self.pid);
+        }
+        return self.status;
+
+        #[cfg(windows)]
+        fn try_wait_os(pid: pid_t) -> Option<ExitStatus> {
+            const STILL_ACTIVE: libc::DWORD = 259;
+            unsafe {
+                let handle: libc::HANDLE = cast::reinterpret_cast(&pid);
+                let code = 0 as libc::DWORD;
+                let ok = libc::GetExitCodeProcess(handle,
+                                                  ptr::mut_addr_of(&code));
+                if ok == (0 as libc::BOOL) {
+                    fail fmt!("GetExitCodeProcess failed: %s",
+                              os::last_os_error());
+                }
+                if code == STILL_ACTIVE { None } else {
+                    Some(Exited(code as int))
+                }
+            }
+        }
+
+        #[cfg(unix)]
+        fn try_wait_os(pid: pid_t) -> Option<ExitStatus> {
+            use libc::consts::os::posix01::WNOHANG;
+            use libc::funcs::posix01::wait::*;
+            unsafe {
+                let status = 0 as c_int;
+                let r = waitpid(pid, ptr::mut_addr_of(&status),
+                                WNOHANG as c_int);
+                if r == (-1 as pid_t) {
+                    fail fmt!("waitpid failed: %s", os::last_os_error());
+                }
+                if r == (0 as pid_t) { None } else {
+                    Some(decode_status(status))
+                }
+            }
+        }
+    }
+
+    /// Closes the child's stdin and waits for it to exit
+    fn wait(&self) -> ExitStatus {
+        self.close_input();
+        match self.status {
+            Some(status) => status,
+            None => {
+                let status = wait_status(self.pid);
+                self.status = Some(status);
+                status
+            }
+        }
+    }
+
+    /**
+     * Closes the child's stdin, reads all of its piped stdout and stderr
+     * and waits for it to exit
+     */
+    fn wait_with_output(&self) -> ProcessOutput {
+        self.close_input();
+
+        // Read stderr on a separate thread so that the child can't block
+        // writing to one pipe while we're blocked reading the other.
+        let error_port = match self.error {
+            Some(file) => {
+                self.error = None;
+                // Unsafe pointers can't be sent, so pass it as an integer
+                let file: uint = unsafe { cast::reinterpret_cast(&file) };
+                let (port, chan) = pipes::stream();
+                do task::spawn_sched(task::SingleThreaded) |move chan| {
+                    let file: *libc::FILE = unsafe {
+                        cast::reinterpret_cast(&file)
+                    };
+                    chan.send(read_and_close(file));
+                }
+                Some(move port)
+            }
+            None => None
+        };
+        let output = match self.output {
+            Some(file) => {
+                self.output = None;
+                read_and_close(file)
+            }
+            None => ~[]
         };
+        let error = match move error_port {
+            Some(move port) => port.recv(),
+            None => ~[]
+        };
+
+        ProcessOutput {
+            status: self.wait(),
+            output: move output,
+            error: move error
+        }
+    }
+
+    priv fn close_outputs(&self) {
+        match self.output {
+            Some(file) => unsafe { libc::fclose(file); },
+            None => ()
+        }
+        match self.error {
+            Some(file) => unsafe { libc::fclose(file); },
+            None => ()
+        }
+        self.output = None;
+        self.error = None;
+    }
+}
+
+impl Process: Drop {
+    fn finalize(&self) {
+        // Close our ends of the pipes first, so that a child blocked on
+        // one of them sees EOF or EPIPE rather than waiting forever.
+        self.close_input();
+        self.close_outputs();
+        self.wait();
     }
 }
 
 #[cfg(test)]
 mod tests {
     use debug;
-    use io::WriterUtil;
+    use io;
+    use io::{ReaderUtil, WriterUtil};
+    use libc;
     use option::{None, Some};
     use os;
+    use run::{Command, Exited, Null, Piped, Redirect, Signaled};
     use run::{readclose, writeclose};
     use run;
+    use str;
 
     // Regression test for memory leaks
     #[ignore(cfg(windows))] // FIXME (#2626)
@@ -493,6 +1014,95 @@ mod tests {
         assert status == 1;
     }
 
+    #[test]
+    #[ignore(cfg(windows))]
+    fn command_output() {
+        let out = Command::new("sh").arg("-c")
+            .arg("echo out; echo err >&2; exit 3")
+            .output().get();
+        assert out.status == Exited(3);
+        assert !out.status.success();
+        assert out.output == str::to_bytes("out\n");
+        assert out.error == str::to_bytes("err\n");
+    }
+
+    #[test]
+    #[ignore(cfg(windows))]
+    fn command_output_large() {
+        // Enough output on both streams to fill up the pipe buffers
+        let out = Command::new("sh").arg("-c")
+            .arg("i=0; while [ $i -lt 2000 ]; do \
+                  echo 0123456789012345678901234567890123456789; \
+                  echo 0123456789012345678901234567890123456789 >&2; \
+                  i=$((i+1)); done")
+            .output().get();
+        assert out.status.success();
+        assert out.output.len() == 2000 * 41;
+        assert out.error.len() == 2000 * 41;
+    }
+
+    #[test]
+    #[ignore(cfg(windows))]
+    fn command_pipes() {
+        let p = Command::new("cat").stdin(Piped).stdout(Piped).spawn().get();
+        p.input().write_str("test");
+        p.close_input();
+        assert p.output().read_whole_stream() == str::to_bytes("test");
+        assert p.wait() == Exited(0);
+    }
+
+    #[test]
+    #[ignore(cfg(windows))]
+    fn command_env_and_dir() {
+        let out = Command::new("sh").arg("-c").arg("echo $FOO$BAR; pwd")
+            .env_clear().env("FOO", "foo").env("BAR", "bar")
+            .env_remove("BAR").env("PATH", "/bin:/usr/bin")
+            .dir(&Path("/"))
+            .output().get();
+        assert out.output == str::to_bytes("foo\n/\n");
+    }
+
+    #[test]
+    #[ignore(cfg(windows))]
+    fn command_redirect() {
+        let path = Path("tmp/lib-run-test-redirect.tmp");
+        let status = Command::new("echo").arg("hello")
+            .stdout(Redirect(copy path)).stderr(Null)
+            .status().get();
+        assert status.success();
+        assert io::read_whole_file_str(&path).get() == ~"hello\n";
+
+        let out = Command::new("cat").stdin(Redirect(copy path))
+            .output().get();
+        assert out.output == str::to_bytes("hello\n");
+
+        assert Command::new("cat")
+            .stdin(Redirect(Path("tmp/nonexistent-bogus-path")))
+            .spawn().is_err();
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn command_kill_and_try_wait() {
+        let p = Command::new("sleep").arg("1000").spawn().get();
+        assert p.try_wait().is_none();
+        assert p.kill(libc::SIGKILL as int).is_ok();
+        assert p.wait() == Signaled(libc::SIGKILL as int);
+        assert p.try_wait() == Some(Signaled(libc::SIGKILL as int));
+        assert p.kill(libc::SIGKILL as int).is_err();
+    }
+
+    #[test]
+    #[ignore(cfg(windows))]
+    fn command_try_wait_exited() {
+        let p = Command::new("true").spawn().get();
+        let mut status = None;
+        while status.is_none() {
+            status = p.try_wait();
+        }
+        assert status == Some(Exited(0));
+    }
+
 }
 
 // Local Variables:
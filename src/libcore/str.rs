@@ -33,6 +33,13 @@ use str;
 use to_str::ToStr;
 use u8;
 use uint;
+use unicode;
+use unicode::conversions;
+use unicode::grapheme::{GraphemeCat, grapheme_category};
+use unicode::grapheme::{CR, LF, Control, Extend, ZWJ, RegionalIndicator};
+use unicode::grapheme::{Prepend, SpacingMark, ExtendedPictographic};
+use unicode::grapheme::{HangulL, HangulV, HangulT, HangulLV, HangulLVT};
+use unicode::normalization;
 use vec;
 
 /*
@@ -642,18 +649,274 @@ pub fn split_within(ss: &str, lim: uint) -> ~[~str] {
 
 
 
-/// Convert a string to lowercase. ASCII only
+/**
+ * Convert a string to lowercase using the full Unicode case mapping, so
+ * the result may be longer than the input. The mapping is not language
+ * sensitive, but a capital sigma at the end of a word does become a
+ * final sigma.
+ */
 pub pure fn to_lower(s: &str) -> ~str {
-    map(s,
-        |c| unsafe{(libc::tolower(c as libc::c_char)) as char}
-    )
+    let mut result = with_capacity(len(s));
+    let cs = chars(s);
+    for cs.eachi |i, c| {
+        if *c == '\u03a3' && is_final_sigma(cs, i) {
+            unsafe { push_char(&mut result, '\u03c2'); }
+        } else {
+            conversions::each_lower(*c, |l| unsafe {
+                push_char(&mut result, l)
+            });
+        }
+    }
+    result
 }
 
-/// Convert a string to uppercase. ASCII only
+/**
+ * Convert a string to uppercase using the full Unicode case mapping, so
+ * the result may be longer than the input, as when 'ß' becomes "SS"
+ */
 pub pure fn to_upper(s: &str) -> ~str {
-    map(s,
-        |c| unsafe{(libc::toupper(c as libc::c_char)) as char}
-    )
+    let mut result = with_capacity(len(s));
+    for each_char(s) |c| {
+        conversions::each_upper(c, |u| unsafe {
+            push_char(&mut result, u)
+        });
+    }
+    result
+}
+
+/**
+ * Convert a string to its full Unicode case folding. Two strings that
+ * differ only in case have the same folding.
+ */
+pub pure fn to_casefold(s: &str) -> ~str {
+    let mut result = with_capacity(len(s));
+    for each_char(s) |c| {
+        conversions::each_fold(c, |f| unsafe {
+            push_char(&mut result, f)
+        });
+    }
+    result
+}
+
+/**
+ * Returns true if two strings are equal ignoring case, by comparing their
+ * full case foldings. The strings are not normalized first, so for
+ * caseless matching of arbitrary text compare `nfd` forms.
+ */
+pub pure fn eq_ignore_case(a: &str, b: &str) -> bool {
+    eq_slice(to_casefold(a), to_casefold(b))
+}
+
+// Whether the capital sigma at `cs[i]` ends a word, which is the
+// Final_Sigma condition of SpecialCasing.txt
+pure fn is_final_sigma(cs: &[char], i: uint) -> bool {
+    let mut j = i;
+    let mut after_cased = false;
+    while j > 0 {
+        j -= 1;
+        if !is_case_ignorable(cs[j]) {
+            after_cased = is_cased(cs[j]);
+            break;
+        }
+    }
+    if !after_cased { return false; }
+    j = i + 1;
+    while j < cs.len() {
+        if !is_case_ignorable(cs[j]) { return !is_cased(cs[j]); }
+        j += 1;
+    }
+    true
+}
+
+pure fn is_cased(c: char) -> bool {
+    char::is_lowercase(c) || char::is_uppercase(c) ||
+        unicode::general_category::Lt(c)
+}
+
+pure fn is_case_ignorable(c: char) -> bool {
+    match c {
+      '\'' | '.' | ':' | '\xb7' | '\u2019' => true,
+      _ => unicode::general_category::Mn(c) ||
+           unicode::general_category::Me(c) ||
+           unicode::general_category::Cf(c) ||
+           unicode::general_category::Lm(c) ||
+           unicode::general_category::Sk(c)
+    }
+}
+
+/*
+Section: Normalization
+*/
+
+/// Return the canonical decomposition (Normalization Form D) of a string
+pub pure fn nfd(s: &str) -> ~str {
+    from_chars(decompose(s, false))
+}
+
+/// Return the compatibility decomposition (Normalization Form KD) of a string
+pub pure fn nfkd(s: &str) -> ~str {
+    from_chars(decompose(s, true))
+}
+
+/**
+ * Return the canonical decomposition followed by canonical composition
+ * (Normalization Form C) of a string
+ */
+pub pure fn nfc(s: &str) -> ~str {
+    from_chars(recompose(decompose(s, false)))
+}
+
+/**
+ * Return the compatibility decomposition followed by canonical
+ * composition (Normalization Form KC) of a string
+ */
+pub pure fn nfkc(s: &str) -> ~str {
+    from_chars(recompose(decompose(s, true)))
+}
+
+// Fully decompose a string and put it in canonical order
+pure fn decompose(s: &str, compat: bool) -> ~[char] {
+    let mut buf = ~[];
+    for each_char(s) |c| {
+        if compat {
+            normalization::decompose_compatible(c, |d| unsafe {
+                buf.push(d)
+            });
+        } else {
+            normalization::decompose_canonical(c, |d| unsafe {
+                buf.push(d)
+            });
+        }
+    }
+
+    // Canonical ordering is a stable sort of each run of non-starters by
+    // combining class. The runs are short, so an insertion sort will do.
+    let mut i = 1;
+    while i < buf.len() {
+        let class = normalization::canonical_combining_class(buf[i]);
+        if class != 0 {
+            let mut j = i;
+            while j > 0 {
+                let prev = normalization::canonical_combining_class(
+                    buf[j - 1]);
+                if prev <= class { break; }
+                unsafe {
+                    let t = buf[j];
+                    buf[j] = buf[j - 1];
+                    buf[j - 1] = t;
+                }
+                j -= 1;
+            }
+        }
+        i += 1;
+    }
+    buf
+}
+
+// Canonically compose a decomposed, canonically ordered sequence
+pure fn recompose(cs: &[char]) -> ~[char] {
+    let mut out = vec::with_capacity(cs.len());
+    // the index in `out` of the last starter and the combining class of
+    // the last char since then that didn't combine with it
+    let mut starter = None;
+    let mut last_class = 0;
+    for cs.each |c| {
+        let c = *c;
+        let class = normalization::canonical_combining_class(c);
+        let mut combined = false;
+        match starter {
+          Some(si) => {
+            // c is blocked from the starter by anything in between with
+            // a combining class of zero or at least its own
+            let blocked = out.len() > si + 1 &&
+                (last_class == 0 || last_class >= class);
+            if !blocked {
+                match normalization::compose(out[si], c) {
+                  Some(composed) => {
+                    unsafe { out[si] = composed; }
+                    combined = true;
+                  }
+                  None => ()
+                }
+            }
+          }
+          None => ()
+        }
+        if !combined {
+            if class == 0 { starter = Some(out.len()); }
+            last_class = class;
+            unsafe { out.push(c); }
+        }
+    }
+    out
+}
+
+/*
+Section: Grapheme clusters
+*/
+
+/**
+ * Iterate over the extended grapheme clusters of a string, as defined by
+ * Unicode Standard Annex #29. A grapheme cluster is roughly what a reader
+ * sees as a single character: a letter and its combining marks, a Hangul
+ * syllable written with conjoining jamo, a flag, a CR LF pair and so on.
+ */
+pub pure fn each_grapheme(s: &str, it: fn(&str) -> bool) {
+    let len = len(s);
+    if len == 0 { return; }
+    let CharRange {ch, next} = char_range_at(s, 0);
+    let mut prev = grapheme_category(ch);
+    // the number of regional indicators immediately before the current
+    // char, and whether an emoji and any extenders precede it
+    let mut ri_run = if prev == RegionalIndicator { 1 } else { 0 };
+    let mut emoji = prev == ExtendedPictographic;
+    let mut emoji_zwj = false;
+    let mut start = 0u;
+    let mut i = next;
+    while i < len {
+        let CharRange {ch, next} = char_range_at(s, i);
+        let cat = grapheme_category(ch);
+        if is_grapheme_break(prev, cat, ri_run, emoji_zwj) {
+            if !it(view(s, start, i)) { return; }
+            start = i;
+        }
+        emoji_zwj = emoji && cat == ZWJ;
+        emoji = cat == ExtendedPictographic || (emoji && cat == Extend);
+        ri_run = if cat == RegionalIndicator { ri_run + 1 } else { 0 };
+        prev = cat;
+        i = next;
+    }
+    it(view(s, start, len));
+}
+
+/// Split a string into its extended grapheme clusters
+pub pure fn graphemes(s: &str) -> ~[~str] {
+    let mut result = ~[];
+    for each_grapheme(s) |g| {
+        unsafe { result.push(from_slice(g)); }
+    }
+    result
+}
+
+// The rules of UAX #29 for whether there is a grapheme cluster boundary
+// between chars of categories `prev` and `cat`
+pure fn is_grapheme_break(prev: GraphemeCat, cat: GraphemeCat,
+                          ri_run: uint, emoji_zwj: bool) -> bool {
+    match (prev, cat) {
+      (CR, LF) => false,                                        // GB3
+      (CR, _) | (LF, _) | (Control, _) => true,                 // GB4
+      (_, CR) | (_, LF) | (_, Control) => true,                 // GB5
+      (HangulL, HangulL) | (HangulL, HangulV) |
+      (HangulL, HangulLV) | (HangulL, HangulLVT) => false,      // GB6
+      (HangulLV, HangulV) | (HangulLV, HangulT) |
+      (HangulV, HangulV) | (HangulV, HangulT) => false,         // GB7
+      (HangulLVT, HangulT) | (HangulT, HangulT) => false,       // GB8
+      (_, Extend) | (_, ZWJ) | (_, SpacingMark) => false,       // GB9, GB9a
+      (Prepend, _) => false,                                    // GB9b
+      (ZWJ, ExtendedPictographic) => !emoji_zwj,                // GB11
+      (RegionalIndicator, RegionalIndicator) => ri_run % 2 == 0, // GB12-13
+      _ => true                                                 // GB999
+    }
 }
 
 /**
@@ -2666,14 +2929,112 @@ mod tests {
 
     #[test]
     fn test_to_upper() {
-        // libc::toupper, and hence str::to_upper
-        // are culturally insensitive: they only work for ASCII
-        // (see Issue #1347)
-        let unicode = ~""; //"\u65e5\u672c"; // uncomment once non-ASCII works
+        let unicode = ~"\u65e5\u672c";
         let input = ~"abcDEF" + unicode + ~"xyz:.;";
         let expected = ~"ABCDEF" + unicode + ~"XYZ:.;";
         let actual = to_upper(input);
         assert expected == actual;
+        assert to_upper(~"ἀπὸ") == ~"ἈΠῸ";
+        assert to_upper(~"straße") == ~"STRASSE";
+        assert to_upper(~"ﬁ") == ~"FI";
+    }
+
+    #[test]
+    fn test_to_lower_unicode() {
+        assert to_lower(~"") == ~"";
+        assert to_lower(~"YMCA") == ~"ymca";
+        assert to_lower(~"ÀÉÎÕÜ") == ~"àéîõü";
+        assert to_lower(~"İ") == ~"i\u0307";
+        // final sigma
+        assert to_lower(~"ΟΔΟΣ") == ~"οδος";
+        assert to_lower(~"ΟΔΟΣ ΣΟΦΟΣ.") == ~"οδος σοφος.";
+        assert to_lower(~"Σ") == ~"σ";
+        assert to_lower(~"ΑΣ'Α") == ~"ασ'α";
+    }
+
+    #[test]
+    fn test_eq_ignore_case() {
+        assert eq_ignore_case(~"", ~"");
+        assert eq_ignore_case(~"Hello", ~"hELLO");
+        assert eq_ignore_case(~"Straße", ~"STRASSE");
+        assert eq_ignore_case(~"ΜΆΪΟΣ", ~"μάϊος");
+        assert !eq_ignore_case(~"abc", ~"abd");
+        assert !eq_ignore_case(~"ab", ~"abc");
+        assert to_casefold(~"ẞ") == ~"ss";
+    }
+
+    #[test]
+    fn test_nfd() {
+        assert nfd(~"abc") == ~"abc";
+        assert nfd(~"\u00e9") == ~"e\u0301";
+        assert nfd(~"\u1e0b\u0323") == ~"d\u0323\u0307";
+        // canonical ordering of combining marks
+        assert nfd(~"a\u0301\u0316") == ~"a\u0316\u0301";
+        assert nfd(~"\uac00") == ~"\u1100\u1161";
+        assert nfd(~"\uac01") == ~"\u1100\u1161\u11a8";
+        // compatibility mappings are left alone
+        assert nfd(~"\ufb01") == ~"\ufb01";
+    }
+
+    #[test]
+    fn test_nfkd() {
+        assert nfkd(~"\ufb01") == ~"fi";
+        assert nfkd(~"\u2460") == ~"1";
+        assert nfkd(~"\u1e9b\u0323") == ~"s\u0323\u0307";
+        assert nfkd(~"\u00bd") == ~"1\u20442";
+    }
+
+    #[test]
+    fn test_nfc() {
+        assert nfc(~"abc") == ~"abc";
+        assert nfc(~"e\u0301") == ~"\u00e9";
+        assert nfc(~"d\u0307\u0323") == ~"\u1e0d\u0307";
+        assert nfc(~"\u1100\u1161\u11a8") == ~"\uac01";
+        // composition exclusions and singletons
+        assert nfc(~"\u0958") == ~"\u0915\u093c";
+        assert nfc(~"\u212b") == ~"\u00c5";
+        // marks of a lower class don't block, but those of the same do
+        assert nfc(~"e\u0316\u0301") == ~"\u00e9\u0316";
+        assert nfc(~"a\u0305\u0301") == ~"a\u0305\u0301";
+        assert nfc(~"\ufb01") == ~"\ufb01";
+    }
+
+    #[test]
+    fn test_nfkc() {
+        assert nfkc(~"\ufb01") == ~"fi";
+        assert nfkc(~"\u1e9b\u0323") == ~"\u1e69";
+        assert nfkc(~"\uff76\uff9e") == ~"\u30ac";
+    }
+
+    #[test]
+    fn test_graphemes() {
+        let empty: ~[~str] = ~[];
+        assert graphemes(~"") == empty;
+        assert graphemes(~"abc") == ~[~"a", ~"b", ~"c"];
+        assert graphemes(~"e\u0301x") == ~[~"e\u0301", ~"x"];
+        assert graphemes(~"a\r\nb\n\r") ==
+            ~[~"a", ~"\r\n", ~"b", ~"\n", ~"\r"];
+        // conjoining jamo
+        assert graphemes(~"\u1100\u1161\u11a8\u1100") ==
+            ~[~"\u1100\u1161\u11a8", ~"\u1100"];
+        // flags pair up regional indicators
+        assert graphemes(~"\U0001f1fa\U0001f1f8\U0001f1eb") ==
+            ~[~"\U0001f1fa\U0001f1f8", ~"\U0001f1eb"];
+        // emoji zwj sequences
+        assert graphemes(~"\U0001f468\u200d\U0001f469!") ==
+            ~[~"\U0001f468\u200d\U0001f469", ~"!"];
+        assert graphemes(~"a\u200d\U0001f469") ==
+            ~[~"a\u200d", ~"\U0001f469"];
+        // spacing marks and prepend
+        assert graphemes(~"\u0915\u093f\u0600a") ==
+            ~[~"\u0915\u093f", ~"\u0600a"];
+
+        let mut n = 0;
+        for each_grapheme(~"xe\u0301y") |g| {
+            n += 1;
+            if g == "e\u0301" { break; }
+        }
+        assert n == 2;
     }
 
     #[test]
@@ -47,6 +47,99 @@ pub pure fn div(x: T, y: T) -> T { x / y }
 #[inline(always)]
 pub pure fn rem(x: T, y: T) -> T { x % y }
 
+/// Returns `x + y`, or `None` if the sum overflows
+#[inline(always)]
+pub pure fn checked_add(x: T, y: T) -> Option<T> {
+    match inst::add_with_overflow(x, y) {
+        (r, false) => Some(r),
+        (_, true) => None
+    }
+}
+/// Returns `x - y`, or `None` if `y` is greater than `x`
+#[inline(always)]
+pub pure fn checked_sub(x: T, y: T) -> Option<T> {
+    match inst::sub_with_overflow(x, y) {
+        (r, false) => Some(r),
+        (_, true) => None
+    }
+}
+/// Returns `x * y`, or `None` if the product overflows
+#[inline(always)]
+pub pure fn checked_mul(x: T, y: T) -> Option<T> {
+    match inst::mul_with_overflow(x, y) {
+        (r, false) => Some(r),
+        (_, true) => None
+    }
+}
+/// Returns `x / y`, or `None` if `y` is zero
+#[inline(always)]
+pub pure fn checked_div(x: T, y: T) -> Option<T> {
+    if y == 0 as T { None } else { Some(x / y) }
+}
+
+/// Returns `x + y`, wrapping around at the bounds of the type
+#[inline(always)]
+pub pure fn wrapping_add(x: T, y: T) -> T {
+    let (r, _) = inst::add_with_overflow(x, y);
+    r
+}
+/// Returns `x - y`, wrapping around at the bounds of the type
+#[inline(always)]
+pub pure fn wrapping_sub(x: T, y: T) -> T {
+    let (r, _) = inst::sub_with_overflow(x, y);
+    r
+}
+/// Returns `x * y`, wrapping around at the bounds of the type
+#[inline(always)]
+pub pure fn wrapping_mul(x: T, y: T) -> T {
+    let (r, _) = inst::mul_with_overflow(x, y);
+    r
+}
+/**
+ * Returns `x / y`. Unsigned division never overflows, so this is the same
+ * as `div`; it exists so that every integer module has the same interface.
+ *
+ * # Failure
+ *
+ * Fails if `y` is zero.
+ */
+#[inline(always)]
+pub pure fn wrapping_div(x: T, y: T) -> T { x / y }
+
+/// Returns `x + y`, clamped to `max_value` on overflow
+#[inline(always)]
+pub pure fn saturating_add(x: T, y: T) -> T {
+    match inst::add_with_overflow(x, y) {
+        (r, false) => r,
+        (_, true) => max_value
+    }
+}
+/// Returns `x - y`, clamped to `min_value` if `y` is greater than `x`
+#[inline(always)]
+pub pure fn saturating_sub(x: T, y: T) -> T {
+    match inst::sub_with_overflow(x, y) {
+        (r, false) => r,
+        (_, true) => min_value
+    }
+}
+/// Returns `x * y`, clamped to `max_value` on overflow
+#[inline(always)]
+pub pure fn saturating_mul(x: T, y: T) -> T {
+    match inst::mul_with_overflow(x, y) {
+        (r, false) => r,
+        (_, true) => max_value
+    }
+}
+/**
+ * Returns `x / y`, which is the same as `div` for unsigned types
+ *
+ * # Failure
+ *
+ * Fails if `y` is zero.
+ */
+#[inline(always)]
+pub pure fn saturating_div(x: T, y: T) -> T { x / y }
+
 #[inline(always)]
 pub pure fn lt(x: T, y: T) -> bool { x < y }
 #[inline(always)]
@@ -407,3 +500,56 @@ fn test_range_step_zero_step_up() {
 fn test_range_step_zero_step_down() {
     for range_step(0,-10,0) |_i| {}
 }
+
+#[test]
+pub fn test_checked() {
+    assert checked_add(max_value, 0 as T) == Some(max_value);
+    assert checked_add(max_value, 1 as T) == None;
+    assert checked_add(max_value - 1 as T, 1 as T) == Some(max_value);
+
+    assert checked_sub(0 as T, 0 as T) == Some(0 as T);
+    assert checked_sub(0 as T, 1 as T) == None;
+    assert checked_sub(max_value, max_value) == Some(0 as T);
+    assert checked_sub(1 as T, max_value) == None;
+
+    assert checked_mul(max_value, 1 as T) == Some(max_value);
+    assert checked_mul(max_value, 2 as T) == None;
+    assert checked_mul(max_value / 2 as T, 2 as T) ==
+        Some(max_value - 1 as T);
+    assert checked_mul(max_value / 2 as T + 1 as T, 2 as T) == None;
+
+    assert checked_div(max_value, 1 as T) == Some(max_value);
+    assert checked_div(7 as T, 2 as T) == Some(3 as T);
+    assert checked_div(1 as T, 0 as T) == None;
+}
+
+#[test]
+pub fn test_wrapping() {
+    assert wrapping_add(max_value, 1 as T) == 0 as T;
+    assert wrapping_add(max_value, max_value) == max_value - 1 as T;
+    assert wrapping_sub(0 as T, 1 as T) == max_value;
+    assert wrapping_sub(1 as T, max_value) == 2 as T;
+    assert wrapping_mul(max_value, 2 as T) == max_value - 1 as T;
+    assert wrapping_mul(max_value, max_value) == 1 as T;
+    assert wrapping_div(max_value, max_value) == 1 as T;
+}
+
+#[test]
+pub fn test_saturating() {
+    assert saturating_add(max_value - 1 as T, 1 as T) == max_value;
+    assert saturating_add(max_value, 1 as T) == max_value;
+    assert saturating_sub(1 as T, 1 as T) == 0 as T;
+    assert saturating_sub(0 as T, 1 as T) == 0 as T;
+    assert saturating_sub(1 as T, max_value) == 0 as T;
+    assert saturating_mul(max_value, 1 as T) == max_value;
+    assert saturating_mul(max_value, 2 as T) == max_value;
+    assert saturating_mul(max_value, max_value) == max_value;
+    assert saturating_div(max_value, 2 as T) == max_value / 2 as T;
+}
+
+#[test]
+#[should_fail]
+#[ignore(cfg(windows))]
+fn test_saturating_div_zero() {
+    saturating_div(1 as T, 0 as T);
+}
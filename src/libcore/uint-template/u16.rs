@@ -10,9 +10,73 @@
 
 //! Operations and constants for `u16`
 
+pub use self::inst::{
+    add_with_overflow, sub_with_overflow, mul_with_overflow
+};
+
 mod inst {
     pub type T = u16;
     #[allow(non_camel_case_types)]
     pub type T_SIGNED = i16;
     pub const bits: uint = 16;
+
+    #[cfg(stage1)]
+    #[cfg(stage2)]
+    #[cfg(stage3)]
+    #[abi = "rust-intrinsic"]
+    extern mod rusti {
+        fn u16_add_with_overflow(x: u16, y: u16) -> (u16, bool);
+        fn u16_sub_with_overflow(x: u16, y: u16) -> (u16, bool);
+        fn u16_mul_with_overflow(x: u16, y: u16) -> (u16, bool);
+    }
+
+    /// Returns `x + y` wrapped around, and whether it overflowed
+    #[cfg(stage0)]
+    #[inline(always)]
+    pub pure fn add_with_overflow(x: T, y: T) -> (T, bool) {
+        let wide = x as u64 + y as u64;
+        (wide as T, wide != (wide as T) as u64)
+    }
+
+    /// Returns `x + y` wrapped around, and whether it overflowed
+    #[cfg(stage1)]
+    #[cfg(stage2)]
+    #[cfg(stage3)]
+    #[inline(always)]
+    pub pure fn add_with_overflow(x: T, y: T) -> (T, bool) {
+        unsafe { rusti::u16_add_with_overflow(x, y) }
+    }
+
+    /// Returns `x - y` wrapped around, and whether it overflowed
+    #[cfg(stage0)]
+    #[inline(always)]
+    pub pure fn sub_with_overflow(x: T, y: T) -> (T, bool) {
+        (x - y, y > x)
+    }
+
+    /// Returns `x - y` wrapped around, and whether it overflowed
+    #[cfg(stage1)]
+    #[cfg(stage2)]
+    #[cfg(stage3)]
+    #[inline(always)]
+    pub pure fn sub_with_overflow(x: T, y: T) -> (T, bool) {
+        unsafe { rusti::u16_sub_with_overflow(x, y) }
+    }
+
+    /// Returns `x * y` wrapped around, and whether it overflowed
+    #[cfg(stage0)]
+    #[inline(always)]
+    pub pure fn mul_with_overflow(x: T, y: T) -> (T, bool) {
+        let wide = x as u64 * y as u64;
+        (wide as T, wide != (wide as T) as u64)
+    }
+
+    /// Returns `x * y` wrapped around, and whether it overflowed
+    #[cfg(stage1)]
+    #[cfg(stage2)]
+    #[cfg(stage3)]
+    #[inline(always)]
+    pub pure fn mul_with_overflow(x: T, y: T) -> (T, bool) {
+        unsafe { rusti::u16_mul_with_overflow(x, y) }
+    }
 }
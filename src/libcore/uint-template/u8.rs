@@ -10,7 +10,9 @@
 
 //! Operations and constants for `u8`
 
-pub use self::inst::is_ascii;
+pub use self::inst::{
+    is_ascii, add_with_overflow, sub_with_overflow, mul_with_overflow
+};
 
 mod inst {
     pub type T = u8;
@@ -22,4 +24,80 @@ mod inst {
     // parent module so that they appear in core::u8 and not core::u8::u8;
 
     pub pure fn is_ascii(x: T) -> bool { return 0 as T == x & 128 as T; }
+
+    #[cfg(stage1)]
+    #[cfg(stage2)]
+    #[cfg(stage3)]
+    #[abi = "rust-intrinsic"]
+    extern mod rusti {
+        fn u8_add_with_overflow(x: u8, y: u8) -> (u8, bool);
+        fn u8_sub_with_overflow(x: u8, y: u8) -> (u8, bool);
+        fn u8_mul_with_overflow(x: u8, y: u8) -> (u8, bool);
+    }
+
+    /// Returns `x + y` wrapped around, and whether it overflowed
+    #[cfg(stage0)]
+    #[inline(always)]
+    pub pure fn add_with_overflow(x: T, y: T) -> (T, bool) {
+        let wide = x as u64 + y as u64;
+        (wide as T, wide != (wide as T) as u64)
+    }
+
+    /// Returns `x + y` wrapped around, and whether it overflowed
+    #[cfg(stage1)]
+    #[cfg(stage2)]
+    #[cfg(stage3)]
+    #[inline(always)]
+    pub pure fn add_with_overflow(x: T, y: T) -> (T, bool) {
+        unsafe { rusti::u8_add_with_overflow(x, y) }
+    }
+
+    /// Returns `x - y` wrapped around, and whether it overflowed
+    #[cfg(stage0)]
+    #[inline(always)]
+    pub pure fn sub_with_overflow(x: T, y: T) -> (T, bool) {
+        (x - y, y > x)
+    }
+
+    /// Returns `x - y` wrapped around, and whether it overflowed
+    #[cfg(stage1)]
+    #[cfg(stage2)]
+    #[cfg(stage3)]
+    #[inline(always)]
+    pub pure fn sub_with_overflow(x: T, y: T) -> (T, bool) {
+        unsafe { rusti::u8_sub_with_overflow(x, y) }
+    }
+
+    /// Returns `x * y` wrapped around, and whether it overflowed
+    #[cfg(stage0)]
+    #[inline(always)]
+    pub pure fn mul_with_overflow(x: T, y: T) -> (T, bool) {
+        let wide = x as u64 * y as u64;
+        (wide as T, wide != (wide as T) as u64)
+    }
+
+    /// Returns `x * y` wrapped around, and whether it overflowed
+    #[cfg(stage1)]
+    #[cfg(stage2)]
+    #[cfg(stage3)]
+    #[inline(always)]
+    pub pure fn mul_with_overflow(x: T, y: T) -> (T, bool) {
+        unsafe { rusti::u8_mul_with_overflow(x, y) }
+    }
+
+    #[test]
+    fn test_with_overflow_exhaustive() {
+        fn check(got: (T, bool), wide: int) {
+            let (r, o) = got;
+            assert r == wide as T;
+            assert o == (wide < 0 || wide >= 256);
+        }
+        for ::int::range(0, 256) |x| {
+            for ::int::range(0, 256) |y| {
+                check(add_with_overflow(x as T, y as T), x + y);
+                check(sub_with_overflow(x as T, y as T), x - y);
+                check(mul_with_overflow(x as T, y as T), x * y);
+            }
+        }
+    }
 }
@@ -12,7 +12,8 @@
 
 pub use self::inst::{
     div_ceil, div_round, div_floor, iterate,
-    next_power_of_two
+    next_power_of_two,
+    add_with_overflow, sub_with_overflow, mul_with_overflow
 };
 
 mod inst {
@@ -174,4 +175,49 @@ mod inst {
         assert(uint::div_ceil(3u, 4u)  == 1u);
         assert(uint::div_round(3u, 4u) == 1u);
     }
+
+    /// Returns `x + y` wrapped around, and whether it overflowed
+    #[cfg(target_word_size = "32")]
+    #[inline(always)]
+    pub pure fn add_with_overflow(x: T, y: T) -> (T, bool) {
+        let (r, o) = ::u32::add_with_overflow(x as u32, y as u32);
+        (r as T, o)
+    }
+
+    #[cfg(target_word_size = "64")]
+    #[inline(always)]
+    pub pure fn add_with_overflow(x: T, y: T) -> (T, bool) {
+        let (r, o) = ::u64::add_with_overflow(x as u64, y as u64);
+        (r as T, o)
+    }
+
+    /// Returns `x - y` wrapped around, and whether it overflowed
+    #[cfg(target_word_size = "32")]
+    #[inline(always)]
+    pub pure fn sub_with_overflow(x: T, y: T) -> (T, bool) {
+        let (r, o) = ::u32::sub_with_overflow(x as u32, y as u32);
+        (r as T, o)
+    }
+
+    #[cfg(target_word_size = "64")]
+    #[inline(always)]
+    pub pure fn sub_with_overflow(x: T, y: T) -> (T, bool) {
+        let (r, o) = ::u64::sub_with_overflow(x as u64, y as u64);
+        (r as T, o)
+    }
+
+    /// Returns `x * y` wrapped around, and whether it overflowed
+    #[cfg(target_word_size = "32")]
+    #[inline(always)]
+    pub pure fn mul_with_overflow(x: T, y: T) -> (T, bool) {
+        let (r, o) = ::u32::mul_with_overflow(x as u32, y as u32);
+        (r as T, o)
+    }
+
+    #[cfg(target_word_size = "64")]
+    #[inline(always)]
+    pub pure fn mul_with_overflow(x: T, y: T) -> (T, bool) {
+        let (r, o) = ::u64::mul_with_overflow(x as u64, y as u64);
+        (r as T, o)
+    }
 }
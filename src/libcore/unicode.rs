@@ -13,11 +13,15 @@
 #[forbid(deprecated_mode)];
 #[forbid(deprecated_pattern)];
 
+// Generated by src/etc/unicode.py from version 14.0.0 of the Unicode
+// Character Database.
+
 pub mod general_category {
     pub pure fn Cc(c: char) -> bool {
         return match c {
               '\x00' .. '\x1f'
-            | '\x7f' .. '\x9f' => true,
+            | '\x7f' .. '\x9f'
+              => true,
             _ => false
         };
     }
@@ -25,32 +29,41 @@ pub mod general_category {
     pub pure fn Cf(c: char) -> bool {
         return match c {
               '\xad'
-            | '\u0600' .. '\u0603'
+            | '\u0600' .. '\u0605'
+            | '\u061c'
             | '\u06dd'
             | '\u070f'
-            | '\u17b4' .. '\u17b5'
+            | '\u0890' .. '\u0891'
+            | '\u08e2'
+            | '\u180e'
             | '\u200b' .. '\u200f'
             | '\u202a' .. '\u202e'
             | '\u2060' .. '\u206f'
             | '\ufeff'
             | '\ufff9' .. '\ufffb'
             | '\U000110bd'
+            | '\U000110cd'
+            | '\U00013430' .. '\U00013438'
+            | '\U0001bca0' .. '\U0001bca3'
             | '\U0001d173' .. '\U0001d17a'
-            | '\U000e0001' .. '\U000e007f' => true,
+            | '\U000e0001' .. '\U000e007f'
+              => true,
             _ => false
         };
     }
 
     pub pure fn Co(c: char) -> bool {
         return match c {
-          '\ue000' .. '\uf8ff' => true,
-          _ => false
+              '\ue000' .. '\uf8ff'
+              => true,
+            _ => false
         };
     }
 
     pub pure fn Cs(c: char) -> bool {
         return match c {
-              '\ud800' .. '\udfff' => true,
+              '\ud800' .. '\udfff'
+              => true,
             _ => false
         };
     }
@@ -58,9 +71,7 @@ pub mod general_category {
     pub pure fn Ll(c: char) -> bool {
         return match c {
               '\x61' .. '\x7a'
-            | '\xaa'
             | '\xb5'
-            | '\xba'
             | '\xdf' .. '\xf6'
             | '\xf8' .. '\xff'
             | '\u0101'
@@ -324,9 +335,17 @@ pub mod general_category {
             | '\u0523'
             | '\u0525'
             | '\u0527'
-            | '\u0561' .. '\u0587'
+            | '\u0529'
+            | '\u052b'
+            | '\u052d'
+            | '\u052f'
+            | '\u0560' .. '\u0588'
+            | '\u10d0' .. '\u10fa'
+            | '\u10fd' .. '\u10ff'
+            | '\u13f8' .. '\u13fd'
+            | '\u1c80' .. '\u1c88'
             | '\u1d00' .. '\u1d2b'
-            | '\u1d62' .. '\u1d77'
+            | '\u1d6b' .. '\u1d77'
             | '\u1d79' .. '\u1d9a'
             | '\u1e01'
             | '\u1e03'
@@ -477,7 +496,7 @@ pub mod general_category {
             | '\u2146' .. '\u2149'
             | '\u214e'
             | '\u2184'
-            | '\u2c30' .. '\u2c5e'
+            | '\u2c30' .. '\u2c5f'
             | '\u2c61'
             | '\u2c65' .. '\u2c66'
             | '\u2c68'
@@ -485,7 +504,7 @@ pub mod general_category {
             | '\u2c6c'
             | '\u2c71'
             | '\u2c73' .. '\u2c74'
-            | '\u2c76' .. '\u2c7c'
+            | '\u2c76' .. '\u2c7b'
             | '\u2c81'
             | '\u2c83'
             | '\u2c85'
@@ -538,7 +557,8 @@ pub mod general_category {
             | '\u2ce3' .. '\u2ce4'
             | '\u2cec'
             | '\u2cee'
-            | '\u2d00' .. '\u2d25'
+            | '\u2cf3'
+            | '\u2d00' .. '\u2d2d'
             | '\ua641'
             | '\ua643'
             | '\ua645'
@@ -574,6 +594,8 @@ pub mod general_category {
             | '\ua693'
             | '\ua695'
             | '\ua697'
+            | '\ua699'
+            | '\ua69b'
             | '\ua723'
             | '\ua725'
             | '\ua727'
@@ -623,14 +645,44 @@ pub mod general_category {
             | '\ua78c'
             | '\ua78e'
             | '\ua791'
+            | '\ua793' .. '\ua795'
+            | '\ua797'
+            | '\ua799'
+            | '\ua79b'
+            | '\ua79d'
+            | '\ua79f'
             | '\ua7a1'
             | '\ua7a3'
             | '\ua7a5'
             | '\ua7a7'
-            | '\ua7a9' .. '\ua7fa'
+            | '\ua7a9'
+            | '\ua7af'
+            | '\ua7b5'
+            | '\ua7b7'
+            | '\ua7b9'
+            | '\ua7bb'
+            | '\ua7bd'
+            | '\ua7bf'
+            | '\ua7c1'
+            | '\ua7c3'
+            | '\ua7c8'
+            | '\ua7ca'
+            | '\ua7d1' .. '\ua7d5'
+            | '\ua7d7'
+            | '\ua7d9'
+            | '\ua7f6'
+            | '\ua7fa'
+            | '\uab30' .. '\uab5a'
+            | '\uab60' .. '\uab68'
+            | '\uab70' .. '\uabbf'
             | '\ufb00' .. '\ufb17'
             | '\uff41' .. '\uff5a'
             | '\U00010428' .. '\U0001044f'
+            | '\U000104d8' .. '\U000104fb'
+            | '\U00010597' .. '\U000105bc'
+            | '\U00010cc0' .. '\U00010cf2'
+            | '\U000118c0' .. '\U000118df'
+            | '\U00016e60' .. '\U00016e7f'
             | '\U0001d41a' .. '\U0001d433'
             | '\U0001d44e' .. '\U0001d467'
             | '\U0001d482' .. '\U0001d49b'
@@ -655,8 +707,11 @@ pub mod general_category {
             | '\U0001d7aa' .. '\U0001d7c2'
             | '\U0001d7c4' .. '\U0001d7c9'
             | '\U0001d7cb'
-          => true,
-          _ => false
+            | '\U0001df00' .. '\U0001df09'
+            | '\U0001df0b' .. '\U0001df1e'
+            | '\U0001e922' .. '\U0001e943'
+              => true,
+            _ => false
         };
     }
 
@@ -677,6 +732,7 @@ pub mod general_category {
             | '\u081a'
             | '\u0824'
             | '\u0828'
+            | '\u08c9'
             | '\u0971'
             | '\u0e46'
             | '\u0ec6'
@@ -685,13 +741,13 @@ pub mod general_category {
             | '\u1843'
             | '\u1aa7'
             | '\u1c78' .. '\u1c7d'
-            | '\u1d2c' .. '\u1d61'
+            | '\u1d2c' .. '\u1d6a'
             | '\u1d78'
             | '\u1d9b' .. '\u1dbf'
             | '\u2071'
             | '\u207f'
             | '\u2090' .. '\u209c'
-            | '\u2c7d'
+            | '\u2c7c' .. '\u2c7d'
             | '\u2d6f'
             | '\u2e2f'
             | '\u3005'
@@ -703,22 +759,38 @@ pub mod general_category {
             | '\ua4f8' .. '\ua4fd'
             | '\ua60c'
             | '\ua67f'
+            | '\ua69c' .. '\ua69d'
             | '\ua717' .. '\ua71f'
             | '\ua770'
             | '\ua788'
+            | '\ua7f2' .. '\ua7f4'
+            | '\ua7f8' .. '\ua7f9'
             | '\ua9cf'
+            | '\ua9e6'
             | '\uaa70'
             | '\uaadd'
+            | '\uaaf3' .. '\uaaf4'
+            | '\uab5c' .. '\uab5f'
+            | '\uab69'
             | '\uff70'
             | '\uff9e' .. '\uff9f'
-          => true,
-          _ => false
+            | '\U00010780' .. '\U000107ba'
+            | '\U00016b40' .. '\U00016b43'
+            | '\U00016f93' .. '\U00016fe1'
+            | '\U00016fe3'
+            | '\U0001aff0' .. '\U0001affe'
+            | '\U0001e137' .. '\U0001e13d'
+            | '\U0001e94b'
+              => true,
+            _ => false
         };
     }
 
     pub pure fn Lo(c: char) -> bool {
         return match c {
-              '\u01bb'
+              '\xaa'
+            | '\xba'
+            | '\u01bb'
             | '\u01c0' .. '\u01c3'
             | '\u0294'
             | '\u05d0' .. '\u05f2'
@@ -737,37 +809,46 @@ pub mod general_category {
             | '\u07ca' .. '\u07ea'
             | '\u0800' .. '\u0815'
             | '\u0840' .. '\u0858'
+            | '\u0860' .. '\u0887'
+            | '\u0889' .. '\u088e'
+            | '\u08a0' .. '\u08c8'
             | '\u0904' .. '\u0939'
             | '\u093d'
             | '\u0950'
             | '\u0958' .. '\u0961'
-            | '\u0972' .. '\u097f'
+            | '\u0972' .. '\u0980'
             | '\u0985' .. '\u09b9'
             | '\u09bd'
             | '\u09ce'
             | '\u09dc' .. '\u09e1'
             | '\u09f0' .. '\u09f1'
+            | '\u09fc'
             | '\u0a05' .. '\u0a39'
             | '\u0a59' .. '\u0a5e'
             | '\u0a72' .. '\u0a74'
             | '\u0a85' .. '\u0ab9'
             | '\u0abd'
             | '\u0ad0' .. '\u0ae1'
+            | '\u0af9'
             | '\u0b05' .. '\u0b39'
             | '\u0b3d'
             | '\u0b5c' .. '\u0b61'
             | '\u0b71'
             | '\u0b83' .. '\u0bb9'
             | '\u0bd0'
-            | '\u0c05' .. '\u0c3d'
+            | '\u0c05' .. '\u0c39'
+            | '\u0c3d'
             | '\u0c58' .. '\u0c61'
+            | '\u0c80'
             | '\u0c85' .. '\u0cb9'
             | '\u0cbd'
-            | '\u0cde' .. '\u0ce1'
+            | '\u0cdd' .. '\u0ce1'
             | '\u0cf1' .. '\u0cf2'
-            | '\u0d05' .. '\u0d3d'
+            | '\u0d04' .. '\u0d3a'
+            | '\u0d3d'
             | '\u0d4e'
-            | '\u0d60' .. '\u0d61'
+            | '\u0d54' .. '\u0d56'
+            | '\u0d5f' .. '\u0d61'
             | '\u0d7a' .. '\u0d7f'
             | '\u0d85' .. '\u0dc6'
             | '\u0e01' .. '\u0e30'
@@ -788,39 +869,39 @@ pub mod general_category {
             | '\u106e' .. '\u1070'
             | '\u1075' .. '\u1081'
             | '\u108e'
-            | '\u10d0' .. '\u10fa'
             | '\u1100' .. '\u135a'
             | '\u1380' .. '\u138f'
-            | '\u13a0' .. '\u13f4'
             | '\u1401' .. '\u166c'
             | '\u166f' .. '\u167f'
             | '\u1681' .. '\u169a'
             | '\u16a0' .. '\u16ea'
-            | '\u1700' .. '\u1711'
-            | '\u1720' .. '\u1731'
+            | '\u16f1' .. '\u1711'
+            | '\u171f' .. '\u1731'
             | '\u1740' .. '\u1751'
             | '\u1760' .. '\u1770'
             | '\u1780' .. '\u17b3'
             | '\u17dc'
             | '\u1820' .. '\u1842'
-            | '\u1844' .. '\u18a8'
-            | '\u18aa' .. '\u191c'
-            | '\u1950' .. '\u19ab'
-            | '\u19c1' .. '\u19c7'
+            | '\u1844' .. '\u1884'
+            | '\u1887' .. '\u18a8'
+            | '\u18aa' .. '\u191e'
+            | '\u1950' .. '\u19c9'
             | '\u1a00' .. '\u1a16'
             | '\u1a20' .. '\u1a54'
             | '\u1b05' .. '\u1b33'
-            | '\u1b45' .. '\u1b4b'
+            | '\u1b45' .. '\u1b4c'
             | '\u1b83' .. '\u1ba0'
             | '\u1bae' .. '\u1baf'
-            | '\u1bc0' .. '\u1be5'
+            | '\u1bba' .. '\u1be5'
             | '\u1c00' .. '\u1c23'
             | '\u1c4d' .. '\u1c4f'
             | '\u1c5a' .. '\u1c77'
             | '\u1ce9' .. '\u1cec'
-            | '\u1cee' .. '\u1cf1'
+            | '\u1cee' .. '\u1cf3'
+            | '\u1cf5' .. '\u1cf6'
+            | '\u1cfa'
             | '\u2135' .. '\u2138'
-            | '\u2d30' .. '\u2d65'
+            | '\u2d30' .. '\u2d67'
             | '\u2d80' .. '\u2dde'
             | '\u3006'
             | '\u303c'
@@ -828,9 +909,9 @@ pub mod general_category {
             | '\u309f'
             | '\u30a1' .. '\u30fa'
             | '\u30ff' .. '\u318e'
-            | '\u31a0' .. '\u31ba'
+            | '\u31a0' .. '\u31bf'
             | '\u31f0' .. '\u31ff'
-            | '\u3400' .. '\u4db5'
+            | '\u3400' .. '\u4dbf'
             | '\u4e00' .. '\ua014'
             | '\ua016' .. '\ua48c'
             | '\ua4d0' .. '\ua4f7'
@@ -839,6 +920,8 @@ pub mod general_category {
             | '\ua62a' .. '\ua62b'
             | '\ua66e'
             | '\ua6a0' .. '\ua6e5'
+            | '\ua78f'
+            | '\ua7f7'
             | '\ua7fb' .. '\ua801'
             | '\ua803' .. '\ua805'
             | '\ua807' .. '\ua80a'
@@ -847,58 +930,154 @@ pub mod general_category {
             | '\ua882' .. '\ua8b3'
             | '\ua8f2' .. '\ua8f7'
             | '\ua8fb'
+            | '\ua8fd' .. '\ua8fe'
             | '\ua90a' .. '\ua925'
             | '\ua930' .. '\ua946'
             | '\ua960' .. '\ua97c'
             | '\ua984' .. '\ua9b2'
-            | '\uaa00' .. '\uaa28'
+            | '\ua9e0' .. '\ua9e4'
+            | '\ua9e7' .. '\ua9ef'
+            | '\ua9fa' .. '\uaa28'
             | '\uaa40' .. '\uaa42'
             | '\uaa44' .. '\uaa4b'
             | '\uaa60' .. '\uaa6f'
             | '\uaa71' .. '\uaa76'
             | '\uaa7a'
-            | '\uaa80' .. '\uaaaf'
+            | '\uaa7e' .. '\uaaaf'
             | '\uaab1'
             | '\uaab5' .. '\uaab6'
             | '\uaab9' .. '\uaabd'
             | '\uaac0'
             | '\uaac2' .. '\uaadc'
-            | '\uab01' .. '\uabe2'
+            | '\uaae0' .. '\uaaea'
+            | '\uaaf2'
+            | '\uab01' .. '\uab2e'
+            | '\uabc0' .. '\uabe2'
             | '\uac00' .. '\ud7fb'
             | '\uf900' .. '\ufad9'
             | '\ufb1d'
             | '\ufb1f' .. '\ufb28'
             | '\ufb2a' .. '\ufbb1'
             | '\ufbd3' .. '\ufd3d'
-            | '\ufd50' .. '\ufdfb'
+            | '\ufd50' .. '\ufdc7'
+            | '\ufdf0' .. '\ufdfb'
             | '\ufe70' .. '\ufefc'
             | '\uff66' .. '\uff6f'
             | '\uff71' .. '\uff9d'
             | '\uffa0' .. '\uffdc'
             | '\U00010000' .. '\U000100fa'
-            | '\U00010280' .. '\U0001031e'
-            | '\U00010330' .. '\U00010340'
+            | '\U00010280' .. '\U000102d0'
+            | '\U00010300' .. '\U0001031f'
+            | '\U0001032d' .. '\U00010340'
             | '\U00010342' .. '\U00010349'
+            | '\U00010350' .. '\U00010375'
             | '\U00010380' .. '\U0001039d'
             | '\U000103a0' .. '\U000103cf'
             | '\U00010450' .. '\U0001049d'
+            | '\U00010500' .. '\U00010563'
+            | '\U00010600' .. '\U00010767'
             | '\U00010800' .. '\U00010855'
+            | '\U00010860' .. '\U00010876'
+            | '\U00010880' .. '\U0001089e'
+            | '\U000108e0' .. '\U000108f5'
             | '\U00010900' .. '\U00010915'
             | '\U00010920' .. '\U00010939'
+            | '\U00010980' .. '\U000109b7'
+            | '\U000109be' .. '\U000109bf'
             | '\U00010a00'
-            | '\U00010a10' .. '\U00010a33'
+            | '\U00010a10' .. '\U00010a35'
             | '\U00010a60' .. '\U00010a7c'
+            | '\U00010a80' .. '\U00010a9c'
+            | '\U00010ac0' .. '\U00010ac7'
+            | '\U00010ac9' .. '\U00010ae4'
             | '\U00010b00' .. '\U00010b35'
             | '\U00010b40' .. '\U00010b55'
             | '\U00010b60' .. '\U00010b72'
+            | '\U00010b80' .. '\U00010b91'
             | '\U00010c00' .. '\U00010c48'
+            | '\U00010d00' .. '\U00010d23'
+            | '\U00010e80' .. '\U00010ea9'
+            | '\U00010eb0' .. '\U00010f1c'
+            | '\U00010f27' .. '\U00010f45'
+            | '\U00010f70' .. '\U00010f81'
+            | '\U00010fb0' .. '\U00010fc4'
+            | '\U00010fe0' .. '\U00010ff6'
             | '\U00011003' .. '\U00011037'
+            | '\U00011071' .. '\U00011072'
+            | '\U00011075'
             | '\U00011083' .. '\U000110af'
-            | '\U00012000' .. '\U0001236e'
-            | '\U00013000' .. '\U0001b001'
-            | '\U00020000' .. '\U0002fa1d'
-          => true,
-          _ => false
+            | '\U000110d0' .. '\U000110e8'
+            | '\U00011103' .. '\U00011126'
+            | '\U00011144'
+            | '\U00011147' .. '\U00011172'
+            | '\U00011176'
+            | '\U00011183' .. '\U000111b2'
+            | '\U000111c1' .. '\U000111c4'
+            | '\U000111da'
+            | '\U000111dc'
+            | '\U00011200' .. '\U0001122b'
+            | '\U00011280' .. '\U000112a8'
+            | '\U000112b0' .. '\U000112de'
+            | '\U00011305' .. '\U00011339'
+            | '\U0001133d'
+            | '\U00011350'
+            | '\U0001135d' .. '\U00011361'
+            | '\U00011400' .. '\U00011434'
+            | '\U00011447' .. '\U0001144a'
+            | '\U0001145f' .. '\U000114af'
+            | '\U000114c4' .. '\U000114c5'
+            | '\U000114c7'
+            | '\U00011580' .. '\U000115ae'
+            | '\U000115d8' .. '\U000115db'
+            | '\U00011600' .. '\U0001162f'
+            | '\U00011644'
+            | '\U00011680' .. '\U000116aa'
+            | '\U000116b8'
+            | '\U00011700' .. '\U0001171a'
+            | '\U00011740' .. '\U0001182b'
+            | '\U000118ff' .. '\U0001192f'
+            | '\U0001193f'
+            | '\U00011941'
+            | '\U000119a0' .. '\U000119d0'
+            | '\U000119e1'
+            | '\U000119e3'
+            | '\U00011a00'
+            | '\U00011a0b' .. '\U00011a32'
+            | '\U00011a3a'
+            | '\U00011a50'
+            | '\U00011a5c' .. '\U00011a89'
+            | '\U00011a9d'
+            | '\U00011ab0' .. '\U00011c2e'
+            | '\U00011c40'
+            | '\U00011c72' .. '\U00011c8f'
+            | '\U00011d00' .. '\U00011d30'
+            | '\U00011d46'
+            | '\U00011d60' .. '\U00011d89'
+            | '\U00011d98'
+            | '\U00011ee0' .. '\U00011ef2'
+            | '\U00011fb0'
+            | '\U00012000' .. '\U00012399'
+            | '\U00012480' .. '\U00012ff0'
+            | '\U00013000' .. '\U0001342e'
+            | '\U00014400' .. '\U00016a5e'
+            | '\U00016a70' .. '\U00016abe'
+            | '\U00016ad0' .. '\U00016aed'
+            | '\U00016b00' .. '\U00016b2f'
+            | '\U00016b63' .. '\U00016b8f'
+            | '\U00016f00' .. '\U00016f4a'
+            | '\U00016f50'
+            | '\U00017000' .. '\U00018d08'
+            | '\U0001b000' .. '\U0001bc99'
+            | '\U0001df0a'
+            | '\U0001e100' .. '\U0001e12c'
+            | '\U0001e14e'
+            | '\U0001e290' .. '\U0001e2ad'
+            | '\U0001e2c0' .. '\U0001e2eb'
+            | '\U0001e7e0' .. '\U0001e8c4'
+            | '\U0001ee00' .. '\U0001eebb'
+            | '\U00020000' .. '\U0003134a'
+              => true,
+            _ => false
         };
     }
 
@@ -914,8 +1093,8 @@ pub mod general_category {
             | '\u1fbc'
             | '\u1fcc'
             | '\u1ffc'
-          => true,
-          _ => false
+              => true,
+            _ => false
         };
     }
 
@@ -1068,6 +1247,7 @@ pub mod general_category {
             | '\u0370'
             | '\u0372'
             | '\u0376'
+            | '\u037f'
             | '\u0386'
             | '\u0388' .. '\u038f'
             | '\u0391' .. '\u03ab'
@@ -1184,8 +1364,14 @@ pub mod general_category {
             | '\u0522'
             | '\u0524'
             | '\u0526'
+            | '\u0528'
+            | '\u052a'
+            | '\u052c'
+            | '\u052e'
             | '\u0531' .. '\u0556'
-            | '\u10a0' .. '\u10c5'
+            | '\u10a0' .. '\u10cd'
+            | '\u13a0' .. '\u13f5'
+            | '\u1c90' .. '\u1cbf'
             | '\u1e00'
             | '\u1e02'
             | '\u1e04'
@@ -1336,7 +1522,7 @@ pub mod general_category {
             | '\u213e' .. '\u213f'
             | '\u2145'
             | '\u2183'
-            | '\u2c00' .. '\u2c2e'
+            | '\u2c00' .. '\u2c2f'
             | '\u2c60'
             | '\u2c62' .. '\u2c64'
             | '\u2c67'
@@ -1397,6 +1583,7 @@ pub mod general_category {
             | '\u2ce2'
             | '\u2ceb'
             | '\u2ced'
+            | '\u2cf2'
             | '\ua640'
             | '\ua642'
             | '\ua644'
@@ -1432,6 +1619,8 @@ pub mod general_category {
             | '\ua692'
             | '\ua694'
             | '\ua696'
+            | '\ua698'
+            | '\ua69a'
             | '\ua722'
             | '\ua724'
             | '\ua726'
@@ -1480,13 +1669,39 @@ pub mod general_category {
             | '\ua78b'
             | '\ua78d'
             | '\ua790'
+            | '\ua792'
+            | '\ua796'
+            | '\ua798'
+            | '\ua79a'
+            | '\ua79c'
+            | '\ua79e'
             | '\ua7a0'
             | '\ua7a2'
             | '\ua7a4'
             | '\ua7a6'
             | '\ua7a8'
+            | '\ua7aa' .. '\ua7ae'
+            | '\ua7b0' .. '\ua7b4'
+            | '\ua7b6'
+            | '\ua7b8'
+            | '\ua7ba'
+            | '\ua7bc'
+            | '\ua7be'
+            | '\ua7c0'
+            | '\ua7c2'
+            | '\ua7c4' .. '\ua7c7'
+            | '\ua7c9'
+            | '\ua7d0'
+            | '\ua7d6'
+            | '\ua7d8'
+            | '\ua7f5'
             | '\uff21' .. '\uff3a'
             | '\U00010400' .. '\U00010427'
+            | '\U000104b0' .. '\U000104d3'
+            | '\U00010570' .. '\U00010595'
+            | '\U00010c80' .. '\U00010cb2'
+            | '\U000118a0' .. '\U000118bf'
+            | '\U00016e40' .. '\U00016e5f'
             | '\U0001d400' .. '\U0001d419'
             | '\U0001d434' .. '\U0001d44d'
             | '\U0001d468' .. '\U0001d481'
@@ -1506,8 +1721,9 @@ pub mod general_category {
             | '\U0001d756' .. '\U0001d76e'
             | '\U0001d790' .. '\U0001d7a8'
             | '\U0001d7ca'
-          => true,
-          _ => false
+            | '\U0001e900' .. '\U0001e921'
+              => true,
+            _ => false
         };
     }
 
@@ -1548,7 +1764,8 @@ pub mod general_category {
             | '\u0d57'
             | '\u0d82' .. '\u0d83'
             | '\u0dcf' .. '\u0dd1'
-            | '\u0dd8' .. '\u0df3'
+            | '\u0dd8' .. '\u0ddf'
+            | '\u0df2' .. '\u0df3'
             | '\u0f3e' .. '\u0f3f'
             | '\u0f7f'
             | '\u102b' .. '\u102c'
@@ -1562,15 +1779,15 @@ pub mod general_category {
             | '\u1087' .. '\u108c'
             | '\u108f'
             | '\u109a' .. '\u109c'
+            | '\u1715'
+            | '\u1734'
             | '\u17b6'
             | '\u17be' .. '\u17c5'
             | '\u17c7' .. '\u17c8'
             | '\u1923' .. '\u1926'
             | '\u1929' .. '\u1931'
             | '\u1933' .. '\u1938'
-            | '\u19b0' .. '\u19c0'
-            | '\u19c8' .. '\u19c9'
-            | '\u1a19' .. '\u1a1b'
+            | '\u1a19' .. '\u1a1a'
             | '\u1a55'
             | '\u1a57'
             | '\u1a61'
@@ -1592,7 +1809,8 @@ pub mod general_category {
             | '\u1c24' .. '\u1c2b'
             | '\u1c34' .. '\u1c35'
             | '\u1ce1'
-            | '\u1cf2'
+            | '\u1cf7'
+            | '\u302e' .. '\u302f'
             | '\ua823' .. '\ua824'
             | '\ua827'
             | '\ua880' .. '\ua881'
@@ -1601,11 +1819,15 @@ pub mod general_category {
             | '\ua983'
             | '\ua9b4' .. '\ua9b5'
             | '\ua9ba' .. '\ua9bb'
-            | '\ua9bd' .. '\ua9c0'
+            | '\ua9be' .. '\ua9c0'
             | '\uaa2f' .. '\uaa30'
             | '\uaa33' .. '\uaa34'
             | '\uaa4d'
             | '\uaa7b'
+            | '\uaa7d'
+            | '\uaaeb'
+            | '\uaaee' .. '\uaaef'
+            | '\uaaf5'
             | '\uabe3' .. '\uabe4'
             | '\uabe6' .. '\uabe7'
             | '\uabe9' .. '\uabea'
@@ -1615,21 +1837,78 @@ pub mod general_category {
             | '\U00011082'
             | '\U000110b0' .. '\U000110b2'
             | '\U000110b7' .. '\U000110b8'
+            | '\U0001112c'
+            | '\U00011145' .. '\U00011146'
+            | '\U00011182'
+            | '\U000111b3' .. '\U000111b5'
+            | '\U000111bf' .. '\U000111c0'
+            | '\U000111ce'
+            | '\U0001122c' .. '\U0001122e'
+            | '\U00011232' .. '\U00011233'
+            | '\U00011235'
+            | '\U000112e0' .. '\U000112e2'
+            | '\U00011302' .. '\U00011303'
+            | '\U0001133e' .. '\U0001133f'
+            | '\U00011341' .. '\U0001134d'
+            | '\U00011357'
+            | '\U00011362' .. '\U00011363'
+            | '\U00011435' .. '\U00011437'
+            | '\U00011440' .. '\U00011441'
+            | '\U00011445'
+            | '\U000114b0' .. '\U000114b2'
+            | '\U000114b9'
+            | '\U000114bb' .. '\U000114be'
+            | '\U000114c1'
+            | '\U000115af' .. '\U000115b1'
+            | '\U000115b8' .. '\U000115bb'
+            | '\U000115be'
+            | '\U00011630' .. '\U00011632'
+            | '\U0001163b' .. '\U0001163c'
+            | '\U0001163e'
+            | '\U000116ac'
+            | '\U000116ae' .. '\U000116af'
+            | '\U000116b6'
+            | '\U00011720' .. '\U00011721'
+            | '\U00011726'
+            | '\U0001182c' .. '\U0001182e'
+            | '\U00011838'
+            | '\U00011930' .. '\U00011938'
+            | '\U0001193d'
+            | '\U00011940'
+            | '\U00011942'
+            | '\U000119d1' .. '\U000119d3'
+            | '\U000119dc' .. '\U000119df'
+            | '\U000119e4'
+            | '\U00011a39'
+            | '\U00011a57' .. '\U00011a58'
+            | '\U00011a97'
+            | '\U00011c2f'
+            | '\U00011c3e'
+            | '\U00011ca9'
+            | '\U00011cb1'
+            | '\U00011cb4'
+            | '\U00011d8a' .. '\U00011d8e'
+            | '\U00011d93' .. '\U00011d94'
+            | '\U00011d96'
+            | '\U00011ef5' .. '\U00011ef6'
+            | '\U00016f51' .. '\U00016f87'
+            | '\U00016ff0' .. '\U00016ff1'
             | '\U0001d165' .. '\U0001d166'
             | '\U0001d16d' .. '\U0001d172'
-          => true,
-          _ => false
+              => true,
+            _ => false
         };
     }
 
     pub pure fn Me(c: char) -> bool {
         return match c {
               '\u0488' .. '\u0489'
+            | '\u1abe'
             | '\u20dd' .. '\u20e0'
             | '\u20e2' .. '\u20e4'
             | '\ua670' .. '\ua672'
-          => true,
-          _ => false
+              => true,
+            _ => false
         };
     }
 
@@ -1653,12 +1932,15 @@ pub mod general_category {
             | '\u0730' .. '\u074a'
             | '\u07a6' .. '\u07b0'
             | '\u07eb' .. '\u07f3'
+            | '\u07fd'
             | '\u0816' .. '\u0819'
             | '\u081b' .. '\u0823'
             | '\u0825' .. '\u0827'
             | '\u0829' .. '\u082d'
             | '\u0859' .. '\u085b'
-            | '\u0900' .. '\u0902'
+            | '\u0898' .. '\u089f'
+            | '\u08ca' .. '\u08e1'
+            | '\u08e3' .. '\u0902'
             | '\u093a'
             | '\u093c'
             | '\u0941' .. '\u0948'
@@ -1670,16 +1952,17 @@ pub mod general_category {
             | '\u09c1' .. '\u09c4'
             | '\u09cd'
             | '\u09e2' .. '\u09e3'
-            | '\u0a01' .. '\u0a02'
+            | '\u09fe' .. '\u0a02'
             | '\u0a3c'
             | '\u0a41' .. '\u0a51'
             | '\u0a70' .. '\u0a71'
-            | '\u0a75' .. '\u0a82'
+            | '\u0a75'
+            | '\u0a81' .. '\u0a82'
             | '\u0abc'
             | '\u0ac1' .. '\u0ac8'
             | '\u0acd'
             | '\u0ae2' .. '\u0ae3'
-            | '\u0b01'
+            | '\u0afa' .. '\u0b01'
             | '\u0b3c'
             | '\u0b3f'
             | '\u0b41' .. '\u0b44'
@@ -1688,17 +1971,24 @@ pub mod general_category {
             | '\u0b82'
             | '\u0bc0'
             | '\u0bcd'
+            | '\u0c00'
+            | '\u0c04'
+            | '\u0c3c'
             | '\u0c3e' .. '\u0c40'
             | '\u0c46' .. '\u0c56'
             | '\u0c62' .. '\u0c63'
+            | '\u0c81'
             | '\u0cbc'
             | '\u0cbf'
             | '\u0cc6'
             | '\u0ccc' .. '\u0ccd'
             | '\u0ce2' .. '\u0ce3'
+            | '\u0d00' .. '\u0d01'
+            | '\u0d3b' .. '\u0d3c'
             | '\u0d41' .. '\u0d44'
             | '\u0d4d'
             | '\u0d62' .. '\u0d63'
+            | '\u0d81'
             | '\u0dca'
             | '\u0dd2' .. '\u0dd6'
             | '\u0e31'
@@ -1729,26 +2019,31 @@ pub mod general_category {
             | '\u109d'
             | '\u135d' .. '\u135f'
             | '\u1712' .. '\u1714'
-            | '\u1732' .. '\u1734'
+            | '\u1732' .. '\u1733'
             | '\u1752' .. '\u1753'
             | '\u1772' .. '\u1773'
+            | '\u17b4' .. '\u17b5'
             | '\u17b7' .. '\u17bd'
             | '\u17c6'
             | '\u17c9' .. '\u17d3'
             | '\u17dd'
             | '\u180b' .. '\u180d'
+            | '\u180f'
+            | '\u1885' .. '\u1886'
             | '\u18a9'
             | '\u1920' .. '\u1922'
             | '\u1927' .. '\u1928'
             | '\u1932'
             | '\u1939' .. '\u193b'
             | '\u1a17' .. '\u1a18'
+            | '\u1a1b'
             | '\u1a56'
             | '\u1a58' .. '\u1a60'
             | '\u1a62'
             | '\u1a65' .. '\u1a6c'
             | '\u1a73' .. '\u1a7f'
-            | '\u1b00' .. '\u1b03'
+            | '\u1ab0' .. '\u1abd'
+            | '\u1abf' .. '\u1b03'
             | '\u1b34'
             | '\u1b36' .. '\u1b3a'
             | '\u1b3c'
@@ -1757,6 +2052,7 @@ pub mod general_category {
             | '\u1b80' .. '\u1b81'
             | '\u1ba2' .. '\u1ba5'
             | '\u1ba8' .. '\u1ba9'
+            | '\u1bab' .. '\u1bad'
             | '\u1be6'
             | '\u1be8' .. '\u1be9'
             | '\u1bed'
@@ -1767,6 +2063,8 @@ pub mod general_category {
             | '\u1cd4' .. '\u1ce0'
             | '\u1ce2' .. '\u1ce8'
             | '\u1ced'
+            | '\u1cf4'
+            | '\u1cf8' .. '\u1cf9'
             | '\u1dc0' .. '\u1dff'
             | '\u20d0' .. '\u20dc'
             | '\u20e1'
@@ -1774,55 +2072,157 @@ pub mod general_category {
             | '\u2cef' .. '\u2cf1'
             | '\u2d7f'
             | '\u2de0' .. '\u2dff'
-            | '\u302a' .. '\u302f'
+            | '\u302a' .. '\u302d'
             | '\u3099' .. '\u309a'
             | '\ua66f'
-            | '\ua67c' .. '\ua67d'
+            | '\ua674' .. '\ua67d'
+            | '\ua69e' .. '\ua69f'
             | '\ua6f0' .. '\ua6f1'
             | '\ua802'
             | '\ua806'
             | '\ua80b'
             | '\ua825' .. '\ua826'
-            | '\ua8c4'
+            | '\ua82c'
+            | '\ua8c4' .. '\ua8c5'
             | '\ua8e0' .. '\ua8f1'
+            | '\ua8ff'
             | '\ua926' .. '\ua92d'
             | '\ua947' .. '\ua951'
             | '\ua980' .. '\ua982'
             | '\ua9b3'
             | '\ua9b6' .. '\ua9b9'
-            | '\ua9bc'
+            | '\ua9bc' .. '\ua9bd'
+            | '\ua9e5'
             | '\uaa29' .. '\uaa2e'
             | '\uaa31' .. '\uaa32'
             | '\uaa35' .. '\uaa36'
             | '\uaa43'
             | '\uaa4c'
+            | '\uaa7c'
             | '\uaab0'
             | '\uaab2' .. '\uaab4'
             | '\uaab7' .. '\uaab8'
             | '\uaabe' .. '\uaabf'
             | '\uaac1'
+            | '\uaaec' .. '\uaaed'
+            | '\uaaf6'
             | '\uabe5'
             | '\uabe8'
             | '\uabed'
             | '\ufb1e'
             | '\ufe00' .. '\ufe0f'
-            | '\ufe20' .. '\ufe26'
+            | '\ufe20' .. '\ufe2f'
             | '\U000101fd'
+            | '\U000102e0'
+            | '\U00010376' .. '\U0001037a'
             | '\U00010a01' .. '\U00010a0f'
             | '\U00010a38' .. '\U00010a3f'
+            | '\U00010ae5' .. '\U00010ae6'
+            | '\U00010d24' .. '\U00010d27'
+            | '\U00010eab' .. '\U00010eac'
+            | '\U00010f46' .. '\U00010f50'
+            | '\U00010f82' .. '\U00010f85'
             | '\U00011001'
             | '\U00011038' .. '\U00011046'
-            | '\U00011080' .. '\U00011081'
+            | '\U00011070'
+            | '\U00011073' .. '\U00011074'
+            | '\U0001107f' .. '\U00011081'
             | '\U000110b3' .. '\U000110b6'
             | '\U000110b9' .. '\U000110ba'
+            | '\U000110c2'
+            | '\U00011100' .. '\U00011102'
+            | '\U00011127' .. '\U0001112b'
+            | '\U0001112d' .. '\U00011134'
+            | '\U00011173'
+            | '\U00011180' .. '\U00011181'
+            | '\U000111b6' .. '\U000111be'
+            | '\U000111c9' .. '\U000111cc'
+            | '\U000111cf'
+            | '\U0001122f' .. '\U00011231'
+            | '\U00011234'
+            | '\U00011236' .. '\U00011237'
+            | '\U0001123e'
+            | '\U000112df'
+            | '\U000112e3' .. '\U000112ea'
+            | '\U00011300' .. '\U00011301'
+            | '\U0001133b' .. '\U0001133c'
+            | '\U00011340'
+            | '\U00011366' .. '\U00011374'
+            | '\U00011438' .. '\U0001143f'
+            | '\U00011442' .. '\U00011444'
+            | '\U00011446'
+            | '\U0001145e'
+            | '\U000114b3' .. '\U000114b8'
+            | '\U000114ba'
+            | '\U000114bf' .. '\U000114c0'
+            | '\U000114c2' .. '\U000114c3'
+            | '\U000115b2' .. '\U000115b5'
+            | '\U000115bc' .. '\U000115bd'
+            | '\U000115bf' .. '\U000115c0'
+            | '\U000115dc' .. '\U000115dd'
+            | '\U00011633' .. '\U0001163a'
+            | '\U0001163d'
+            | '\U0001163f' .. '\U00011640'
+            | '\U000116ab'
+            | '\U000116ad'
+            | '\U000116b0' .. '\U000116b5'
+            | '\U000116b7'
+            | '\U0001171d' .. '\U0001171f'
+            | '\U00011722' .. '\U00011725'
+            | '\U00011727' .. '\U0001172b'
+            | '\U0001182f' .. '\U00011837'
+            | '\U00011839' .. '\U0001183a'
+            | '\U0001193b' .. '\U0001193c'
+            | '\U0001193e'
+            | '\U00011943'
+            | '\U000119d4' .. '\U000119db'
+            | '\U000119e0'
+            | '\U00011a01' .. '\U00011a0a'
+            | '\U00011a33' .. '\U00011a38'
+            | '\U00011a3b' .. '\U00011a3e'
+            | '\U00011a47'
+            | '\U00011a51' .. '\U00011a56'
+            | '\U00011a59' .. '\U00011a5b'
+            | '\U00011a8a' .. '\U00011a96'
+            | '\U00011a98' .. '\U00011a99'
+            | '\U00011c30' .. '\U00011c3d'
+            | '\U00011c3f'
+            | '\U00011c92' .. '\U00011ca7'
+            | '\U00011caa' .. '\U00011cb0'
+            | '\U00011cb2' .. '\U00011cb3'
+            | '\U00011cb5' .. '\U00011cb6'
+            | '\U00011d31' .. '\U00011d45'
+            | '\U00011d47'
+            | '\U00011d90' .. '\U00011d91'
+            | '\U00011d95'
+            | '\U00011d97'
+            | '\U00011ef3' .. '\U00011ef4'
+            | '\U00016af0' .. '\U00016af4'
+            | '\U00016b30' .. '\U00016b36'
+            | '\U00016f4f'
+            | '\U00016f8f' .. '\U00016f92'
+            | '\U00016fe4'
+            | '\U0001bc9d' .. '\U0001bc9e'
+            | '\U0001cf00' .. '\U0001cf46'
             | '\U0001d167' .. '\U0001d169'
             | '\U0001d17b' .. '\U0001d182'
             | '\U0001d185' .. '\U0001d18b'
             | '\U0001d1aa' .. '\U0001d1ad'
             | '\U0001d242' .. '\U0001d244'
+            | '\U0001da00' .. '\U0001da36'
+            | '\U0001da3b' .. '\U0001da6c'
+            | '\U0001da75'
+            | '\U0001da84'
+            | '\U0001da9b' .. '\U0001daaf'
+            | '\U0001e000' .. '\U0001e02a'
+            | '\U0001e130' .. '\U0001e136'
+            | '\U0001e2ae'
+            | '\U0001e2ec' .. '\U0001e2ef'
+            | '\U0001e8d0' .. '\U0001e8d6'
+            | '\U0001e944' .. '\U0001e94a'
             | '\U000e0100' .. '\U000e01ef'
-          => true,
-          _ => false
+              => true,
+            _ => false
         };
     }
 
@@ -1841,6 +2241,7 @@ pub mod general_category {
             | '\u0c66' .. '\u0c6f'
             | '\u0ce6' .. '\u0cef'
             | '\u0d66' .. '\u0d6f'
+            | '\u0de6' .. '\u0def'
             | '\u0e50' .. '\u0e59'
             | '\u0ed0' .. '\u0ed9'
             | '\u0f20' .. '\u0f29'
@@ -1859,14 +2260,37 @@ pub mod general_category {
             | '\ua8d0' .. '\ua8d9'
             | '\ua900' .. '\ua909'
             | '\ua9d0' .. '\ua9d9'
+            | '\ua9f0' .. '\ua9f9'
             | '\uaa50' .. '\uaa59'
             | '\uabf0' .. '\uabf9'
             | '\uff10' .. '\uff19'
             | '\U000104a0' .. '\U000104a9'
+            | '\U00010d30' .. '\U00010d39'
             | '\U00011066' .. '\U0001106f'
+            | '\U000110f0' .. '\U000110f9'
+            | '\U00011136' .. '\U0001113f'
+            | '\U000111d0' .. '\U000111d9'
+            | '\U000112f0' .. '\U000112f9'
+            | '\U00011450' .. '\U00011459'
+            | '\U000114d0' .. '\U000114d9'
+            | '\U00011650' .. '\U00011659'
+            | '\U000116c0' .. '\U000116c9'
+            | '\U00011730' .. '\U00011739'
+            | '\U000118e0' .. '\U000118e9'
+            | '\U00011950' .. '\U00011959'
+            | '\U00011c50' .. '\U00011c59'
+            | '\U00011d50' .. '\U00011d59'
+            | '\U00011da0' .. '\U00011da9'
+            | '\U00016a60' .. '\U00016a69'
+            | '\U00016ac0' .. '\U00016ac9'
+            | '\U00016b50' .. '\U00016b59'
             | '\U0001d7ce' .. '\U0001d7ff'
-          => true,
-          _ => false
+            | '\U0001e140' .. '\U0001e149'
+            | '\U0001e2f0' .. '\U0001e2f9'
+            | '\U0001e950' .. '\U0001e959'
+            | '\U0001fbf0' .. '\U0001fbf9'
+              => true,
+            _ => false
         };
     }
 
@@ -1883,9 +2307,9 @@ pub mod general_category {
             | '\U00010341'
             | '\U0001034a'
             | '\U000103d1' .. '\U000103d5'
-            | '\U00012400' .. '\U00012462'
-          => true,
-          _ => false
+            | '\U00012400' .. '\U0001246e'
+              => true,
+            _ => false
         };
     }
 
@@ -1898,7 +2322,8 @@ pub mod general_category {
             | '\u0b72' .. '\u0b77'
             | '\u0bf0' .. '\u0bf2'
             | '\u0c78' .. '\u0c7e'
-            | '\u0d70' .. '\u0d75'
+            | '\u0d58' .. '\u0d5e'
+            | '\u0d70' .. '\u0d78'
             | '\u0f2a' .. '\u0f33'
             | '\u1369' .. '\u137c'
             | '\u17f0' .. '\u17f9'
@@ -1914,26 +2339,53 @@ pub mod general_category {
             | '\u2cfd'
             | '\u3192' .. '\u3195'
             | '\u3220' .. '\u3229'
+            | '\u3248' .. '\u324f'
             | '\u3251' .. '\u325f'
             | '\u3280' .. '\u3289'
             | '\u32b1' .. '\u32bf'
             | '\ua830' .. '\ua835'
             | '\U00010107' .. '\U00010133'
             | '\U00010175' .. '\U00010178'
-            | '\U0001018a'
+            | '\U0001018a' .. '\U0001018b'
+            | '\U000102e1' .. '\U000102fb'
             | '\U00010320' .. '\U00010323'
             | '\U00010858' .. '\U0001085f'
+            | '\U00010879' .. '\U0001087f'
+            | '\U000108a7' .. '\U000108af'
+            | '\U000108fb' .. '\U000108ff'
             | '\U00010916' .. '\U0001091b'
-            | '\U00010a40' .. '\U00010a47'
+            | '\U000109bc' .. '\U000109bd'
+            | '\U000109c0' .. '\U000109ff'
+            | '\U00010a40' .. '\U00010a48'
             | '\U00010a7d' .. '\U00010a7e'
+            | '\U00010a9d' .. '\U00010a9f'
+            | '\U00010aeb' .. '\U00010aef'
             | '\U00010b58' .. '\U00010b5f'
             | '\U00010b78' .. '\U00010b7f'
+            | '\U00010ba9' .. '\U00010baf'
+            | '\U00010cfa' .. '\U00010cff'
             | '\U00010e60' .. '\U00010e7e'
+            | '\U00010f1d' .. '\U00010f26'
+            | '\U00010f51' .. '\U00010f54'
+            | '\U00010fc5' .. '\U00010fcb'
             | '\U00011052' .. '\U00011065'
-            | '\U0001d360' .. '\U0001d371'
-            | '\U0001f100' .. '\U0001f10a'
-          => true,
-          _ => false
+            | '\U000111e1' .. '\U000111f4'
+            | '\U0001173a' .. '\U0001173b'
+            | '\U000118ea' .. '\U000118f2'
+            | '\U00011c5a' .. '\U00011c6c'
+            | '\U00011fc0' .. '\U00011fd4'
+            | '\U00016b5b' .. '\U00016b61'
+            | '\U00016e80' .. '\U00016e96'
+            | '\U0001d2e0' .. '\U0001d2f3'
+            | '\U0001d360' .. '\U0001d378'
+            | '\U0001e8c7' .. '\U0001e8cf'
+            | '\U0001ec71' .. '\U0001ecab'
+            | '\U0001ecad' .. '\U0001ecaf'
+            | '\U0001ecb1' .. '\U0001ed2d'
+            | '\U0001ed2f' .. '\U0001ed3d'
+            | '\U0001f100' .. '\U0001f10c'
+              => true,
+            _ => false
         };
     }
 
@@ -1945,8 +2397,8 @@ pub mod general_category {
             | '\ufe33' .. '\ufe34'
             | '\ufe4d' .. '\ufe4f'
             | '\uff3f'
-          => true,
-          _ => false
+              => true,
+            _ => false
         };
     }
 
@@ -1960,6 +2412,9 @@ pub mod general_category {
             | '\u2010' .. '\u2015'
             | '\u2e17'
             | '\u2e1a'
+            | '\u2e3a' .. '\u2e3b'
+            | '\u2e40'
+            | '\u2e5d'
             | '\u301c'
             | '\u3030'
             | '\u30a0'
@@ -1967,8 +2422,9 @@ pub mod general_category {
             | '\ufe58'
             | '\ufe63'
             | '\uff0d'
-          => true,
-          _ => false
+            | '\U00010ead'
+              => true,
+            _ => false
         };
     }
 
@@ -1983,6 +2439,8 @@ pub mod general_category {
             | '\u2046'
             | '\u207e'
             | '\u208e'
+            | '\u2309'
+            | '\u230b'
             | '\u232a'
             | '\u2769'
             | '\u276b'
@@ -2015,6 +2473,10 @@ pub mod general_category {
             | '\u2e25'
             | '\u2e27'
             | '\u2e29'
+            | '\u2e56'
+            | '\u2e58'
+            | '\u2e5a'
+            | '\u2e5c'
             | '\u3009'
             | '\u300b'
             | '\u300d'
@@ -2025,7 +2487,7 @@ pub mod general_category {
             | '\u3019'
             | '\u301b'
             | '\u301e' .. '\u301f'
-            | '\ufd3f'
+            | '\ufd3e'
             | '\ufe18'
             | '\ufe36'
             | '\ufe38'
@@ -2044,8 +2506,8 @@ pub mod general_category {
             | '\uff5d'
             | '\uff60'
             | '\uff63'
-          => true,
-          _ => false
+              => true,
+            _ => false
         };
     }
 
@@ -2061,8 +2523,8 @@ pub mod general_category {
             | '\u2e0d'
             | '\u2e1d'
             | '\u2e21'
-          => true,
-          _ => false
+              => true,
+            _ => false
         };
     }
 
@@ -2079,8 +2541,8 @@ pub mod general_category {
             | '\u2e0c'
             | '\u2e1c'
             | '\u2e20'
-          => true,
-          _ => false
+              => true,
+            _ => false
         };
     }
 
@@ -2095,7 +2557,8 @@ pub mod general_category {
             | '\x3f' .. '\x40'
             | '\x5c'
             | '\xa1'
-            | '\xb7'
+            | '\xa7'
+            | '\xb6' .. '\xb7'
             | '\xbf'
             | '\u037e'
             | '\u0387'
@@ -2107,7 +2570,8 @@ pub mod general_category {
             | '\u05f3' .. '\u05f4'
             | '\u0609' .. '\u060a'
             | '\u060c' .. '\u060d'
-            | '\u061b' .. '\u061f'
+            | '\u061b'
+            | '\u061d' .. '\u061f'
             | '\u066a' .. '\u066d'
             | '\u06d4'
             | '\u0700' .. '\u070d'
@@ -2116,17 +2580,23 @@ pub mod general_category {
             | '\u085e'
             | '\u0964' .. '\u0965'
             | '\u0970'
+            | '\u09fd'
+            | '\u0a76'
+            | '\u0af0'
+            | '\u0c77'
+            | '\u0c84'
             | '\u0df4'
             | '\u0e4f'
             | '\u0e5a' .. '\u0e5b'
             | '\u0f04' .. '\u0f12'
+            | '\u0f14'
             | '\u0f85'
             | '\u0fd0' .. '\u0fd4'
             | '\u0fd9' .. '\u0fda'
             | '\u104a' .. '\u104f'
             | '\u10fb'
-            | '\u1361' .. '\u1368'
-            | '\u166d' .. '\u166e'
+            | '\u1360' .. '\u1368'
+            | '\u166e'
             | '\u16eb' .. '\u16ed'
             | '\u1735' .. '\u1736'
             | '\u17d4' .. '\u17d6'
@@ -2138,9 +2608,11 @@ pub mod general_category {
             | '\u1aa0' .. '\u1aa6'
             | '\u1aa8' .. '\u1aad'
             | '\u1b5a' .. '\u1b60'
+            | '\u1b7d' .. '\u1b7e'
             | '\u1bfc' .. '\u1bff'
             | '\u1c3b' .. '\u1c3f'
             | '\u1c7e' .. '\u1c7f'
+            | '\u1cc0' .. '\u1cc7'
             | '\u1cd3'
             | '\u2016' .. '\u2017'
             | '\u2020' .. '\u2027'
@@ -2161,7 +2633,11 @@ pub mod general_category {
             | '\u2e1b'
             | '\u2e1e' .. '\u2e1f'
             | '\u2e2a' .. '\u2e2e'
-            | '\u2e30' .. '\u2e31'
+            | '\u2e30' .. '\u2e39'
+            | '\u2e3c' .. '\u2e3f'
+            | '\u2e41'
+            | '\u2e43' .. '\u2e4f'
+            | '\u2e52' .. '\u2e54'
             | '\u3001' .. '\u3003'
             | '\u303d'
             | '\u30fb'
@@ -2173,12 +2649,14 @@ pub mod general_category {
             | '\ua874' .. '\ua877'
             | '\ua8ce' .. '\ua8cf'
             | '\ua8f8' .. '\ua8fa'
+            | '\ua8fc'
             | '\ua92e' .. '\ua92f'
             | '\ua95f'
             | '\ua9c1' .. '\ua9cd'
             | '\ua9de' .. '\ua9df'
             | '\uaa5c' .. '\uaa5f'
             | '\uaade' .. '\uaadf'
+            | '\uaaf0' .. '\uaaf1'
             | '\uabeb'
             | '\ufe10' .. '\ufe16'
             | '\ufe19'
@@ -2199,21 +2677,62 @@ pub mod general_category {
             | '\uff3c'
             | '\uff61'
             | '\uff64' .. '\uff65'
-            | '\U00010100' .. '\U00010101'
+            | '\U00010100' .. '\U00010102'
             | '\U0001039f'
             | '\U000103d0'
+            | '\U0001056f'
             | '\U00010857'
             | '\U0001091f'
             | '\U0001093f'
             | '\U00010a50' .. '\U00010a58'
             | '\U00010a7f'
+            | '\U00010af0' .. '\U00010af6'
             | '\U00010b39' .. '\U00010b3f'
+            | '\U00010b99' .. '\U00010b9c'
+            | '\U00010f55' .. '\U00010f59'
+            | '\U00010f86' .. '\U00010f89'
             | '\U00011047' .. '\U0001104d'
             | '\U000110bb' .. '\U000110bc'
             | '\U000110be' .. '\U000110c1'
-            | '\U00012470' .. '\U00012473'
-          => true,
-          _ => false
+            | '\U00011140' .. '\U00011143'
+            | '\U00011174' .. '\U00011175'
+            | '\U000111c5' .. '\U000111c8'
+            | '\U000111cd'
+            | '\U000111db'
+            | '\U000111dd' .. '\U000111df'
+            | '\U00011238' .. '\U0001123d'
+            | '\U000112a9'
+            | '\U0001144b' .. '\U0001144f'
+            | '\U0001145a' .. '\U0001145d'
+            | '\U000114c6'
+            | '\U000115c1' .. '\U000115d7'
+            | '\U00011641' .. '\U00011643'
+            | '\U00011660' .. '\U0001166c'
+            | '\U000116b9'
+            | '\U0001173c' .. '\U0001173e'
+            | '\U0001183b'
+            | '\U00011944' .. '\U00011946'
+            | '\U000119e2'
+            | '\U00011a3f' .. '\U00011a46'
+            | '\U00011a9a' .. '\U00011a9c'
+            | '\U00011a9e' .. '\U00011aa2'
+            | '\U00011c41' .. '\U00011c45'
+            | '\U00011c70' .. '\U00011c71'
+            | '\U00011ef7' .. '\U00011ef8'
+            | '\U00011fff'
+            | '\U00012470' .. '\U00012474'
+            | '\U00012ff1' .. '\U00012ff2'
+            | '\U00016a6e' .. '\U00016a6f'
+            | '\U00016af5'
+            | '\U00016b37' .. '\U00016b3b'
+            | '\U00016b44'
+            | '\U00016e97' .. '\U00016e9a'
+            | '\U00016fe2'
+            | '\U0001bc9f'
+            | '\U0001da87' .. '\U0001da8b'
+            | '\U0001e95e' .. '\U0001e95f'
+              => true,
+            _ => false
         };
     }
 
@@ -2230,6 +2749,8 @@ pub mod general_category {
             | '\u2045'
             | '\u207d'
             | '\u208d'
+            | '\u2308'
+            | '\u230a'
             | '\u2329'
             | '\u2768'
             | '\u276a'
@@ -2262,6 +2783,11 @@ pub mod general_category {
             | '\u2e24'
             | '\u2e26'
             | '\u2e28'
+            | '\u2e42'
+            | '\u2e55'
+            | '\u2e57'
+            | '\u2e59'
+            | '\u2e5b'
             | '\u3008'
             | '\u300a'
             | '\u300c'
@@ -2272,7 +2798,7 @@ pub mod general_category {
             | '\u3018'
             | '\u301a'
             | '\u301d'
-            | '\ufd3e'
+            | '\ufd3f'
             | '\ufe17'
             | '\ufe35'
             | '\ufe37'
@@ -2291,8 +2817,8 @@ pub mod general_category {
             | '\uff5b'
             | '\uff5f'
             | '\uff62'
-          => true,
-          _ => false
+              => true,
+            _ => false
         };
     }
 
@@ -2300,22 +2826,27 @@ pub mod general_category {
         return match c {
               '\x24'
             | '\xa2' .. '\xa5'
+            | '\u058f'
             | '\u060b'
+            | '\u07fe' .. '\u07ff'
             | '\u09f2' .. '\u09f3'
             | '\u09fb'
             | '\u0af1'
             | '\u0bf9'
             | '\u0e3f'
             | '\u17db'
-            | '\u20a0' .. '\u20b9'
+            | '\u20a0' .. '\u20c0'
             | '\ua838'
             | '\ufdfc'
             | '\ufe69'
             | '\uff04'
             | '\uffe0' .. '\uffe1'
             | '\uffe5' .. '\uffe6'
-          => true,
-          _ => false
+            | '\U00011fdd' .. '\U00011fe0'
+            | '\U0001e2ff'
+            | '\U0001ecb0'
+              => true,
+            _ => false
         };
     }
 
@@ -2334,6 +2865,7 @@ pub mod general_category {
             | '\u02ef' .. '\u02ff'
             | '\u0375'
             | '\u0384' .. '\u0385'
+            | '\u0888'
             | '\u1fbd'
             | '\u1fbf' .. '\u1fc1'
             | '\u1fcd' .. '\u1fcf'
@@ -2344,12 +2876,15 @@ pub mod general_category {
             | '\ua700' .. '\ua716'
             | '\ua720' .. '\ua721'
             | '\ua789' .. '\ua78a'
-            | '\ufbb2' .. '\ufbc1'
+            | '\uab5b'
+            | '\uab6a' .. '\uab6b'
+            | '\ufbb2' .. '\ufbc2'
             | '\uff3e'
             | '\uff40'
             | '\uffe3'
-          => true,
-          _ => false
+            | '\U0001f3fb' .. '\U0001f3ff'
+              => true,
+            _ => false
         };
     }
 
@@ -2382,7 +2917,6 @@ pub mod general_category {
             | '\u21d2'
             | '\u21d4'
             | '\u21f4' .. '\u22ff'
-            | '\u2308' .. '\u230b'
             | '\u2320' .. '\u2321'
             | '\u237c'
             | '\u239b' .. '\u23b3'
@@ -2419,19 +2953,20 @@ pub mod general_category {
             | '\U0001d789'
             | '\U0001d7a9'
             | '\U0001d7c3'
-          => true,
-          _ => false
+            | '\U0001eef0' .. '\U0001eef1'
+              => true,
+            _ => false
         };
     }
 
     pub pure fn So(c: char) -> bool {
         return match c {
-              '\xa6' .. '\xa7'
+              '\xa6'
             | '\xa9'
             | '\xae'
             | '\xb0'
-            | '\xb6'
             | '\u0482'
+            | '\u058d' .. '\u058e'
             | '\u060e' .. '\u060f'
             | '\u06de'
             | '\u06e9'
@@ -2442,9 +2977,11 @@ pub mod general_category {
             | '\u0bf3' .. '\u0bf8'
             | '\u0bfa'
             | '\u0c7f'
+            | '\u0d4f'
             | '\u0d79'
             | '\u0f01' .. '\u0f03'
-            | '\u0f13' .. '\u0f17'
+            | '\u0f13'
+            | '\u0f15' .. '\u0f17'
             | '\u0f1a' .. '\u0f1f'
             | '\u0f34'
             | '\u0f36'
@@ -2453,8 +2990,8 @@ pub mod general_category {
             | '\u0fc7' .. '\u0fcf'
             | '\u0fd5' .. '\u0fd8'
             | '\u109e' .. '\u109f'
-            | '\u1360'
             | '\u1390' .. '\u1399'
+            | '\u166d'
             | '\u1940'
             | '\u19de' .. '\u19ff'
             | '\u1b61' .. '\u1b6a'
@@ -2473,6 +3010,7 @@ pub mod general_category {
             | '\u214a'
             | '\u214c' .. '\u214d'
             | '\u214f'
+            | '\u218a' .. '\u218b'
             | '\u2195' .. '\u2199'
             | '\u219c' .. '\u219f'
             | '\u21a1' .. '\u21a2'
@@ -2499,8 +3037,9 @@ pub mod general_category {
             | '\u2800' .. '\u28ff'
             | '\u2b00' .. '\u2b2f'
             | '\u2b45' .. '\u2b46'
-            | '\u2b50' .. '\u2b59'
+            | '\u2b4d' .. '\u2bff'
             | '\u2ce5' .. '\u2cea'
+            | '\u2e50' .. '\u2e51'
             | '\u2e80' .. '\u2ffb'
             | '\u3004'
             | '\u3012' .. '\u3013'
@@ -2511,7 +3050,8 @@ pub mod general_category {
             | '\u3196' .. '\u319f'
             | '\u31c0' .. '\u31e3'
             | '\u3200' .. '\u321e'
-            | '\u322a' .. '\u3250'
+            | '\u322a' .. '\u3247'
+            | '\u3250'
             | '\u3260' .. '\u327f'
             | '\u328a' .. '\u32b0'
             | '\u32c0' .. '\u33ff'
@@ -2521,39 +3061,60 @@ pub mod general_category {
             | '\ua836' .. '\ua837'
             | '\ua839'
             | '\uaa77' .. '\uaa79'
-            | '\ufdfd'
+            | '\ufd40' .. '\ufd4f'
+            | '\ufdcf'
+            | '\ufdfd' .. '\ufdff'
             | '\uffe4'
             | '\uffe8'
             | '\uffed' .. '\uffee'
             | '\ufffc' .. '\ufffd'
-            | '\U00010102'
             | '\U00010137' .. '\U0001013f'
             | '\U00010179' .. '\U00010189'
-            | '\U00010190' .. '\U000101fc'
-            | '\U0001d000' .. '\U0001d164'
+            | '\U0001018c' .. '\U000101fc'
+            | '\U00010877' .. '\U00010878'
+            | '\U00010ac8'
+            | '\U0001173f'
+            | '\U00011fd5' .. '\U00011fdc'
+            | '\U00011fe1' .. '\U00011ff1'
+            | '\U00016b3c' .. '\U00016b3f'
+            | '\U00016b45'
+            | '\U0001bc9c'
+            | '\U0001cf50' .. '\U0001d164'
             | '\U0001d16a' .. '\U0001d16c'
             | '\U0001d183' .. '\U0001d184'
             | '\U0001d18c' .. '\U0001d1a9'
             | '\U0001d1ae' .. '\U0001d241'
-            | '\U0001d245' .. '\U0001d356'
-            | '\U0001f000' .. '\U0001f0df'
-            | '\U0001f110' .. '\U0001f773'
-          => true,
-          _ => false
+            | '\U0001d245'
+            | '\U0001d300' .. '\U0001d356'
+            | '\U0001d800' .. '\U0001d9ff'
+            | '\U0001da37' .. '\U0001da3a'
+            | '\U0001da6d' .. '\U0001da74'
+            | '\U0001da76' .. '\U0001da83'
+            | '\U0001da85' .. '\U0001da86'
+            | '\U0001e14f'
+            | '\U0001ecac'
+            | '\U0001ed2e'
+            | '\U0001f000' .. '\U0001f0f5'
+            | '\U0001f10d' .. '\U0001f3fa'
+            | '\U0001f400' .. '\U0001fbca'
+              => true,
+            _ => false
         };
     }
 
     pub pure fn Zl(c: char) -> bool {
         return match c {
-          '\u2028' => true,
-          _ => false
+              '\u2028'
+              => true,
+            _ => false
         };
     }
 
     pub pure fn Zp(c: char) -> bool {
         return match c {
-          '\u2029' => true,
-          _ => false
+              '\u2029'
+              => true,
+            _ => false
         };
     }
 
@@ -2562,20 +3123,18 @@ pub mod general_category {
               '\x20'
             | '\xa0'
             | '\u1680'
-            | '\u180e'
             | '\u2000' .. '\u200a'
             | '\u202f'
             | '\u205f'
             | '\u3000'
-          => true,
-          _ => false
+              => true,
+            _ => false
         };
     }
 
 }
-mod derived_property {
-    #[legacy_exports];
-    /// Check if a character has the alphabetic unicode property
+
+pub mod derived_property {
     pub pure fn Alphabetic(c: char) -> bool {
         return match c {
               '\x41' .. '\x5a'
@@ -2585,116 +3144,77 @@ mod derived_property {
             | '\xba'
             | '\xc0' .. '\xd6'
             | '\xd8' .. '\xf6'
-            | '\xf8' .. '\u01ba'
-            | '\u01bb'
-            | '\u01bc' .. '\u01bf'
-            | '\u01c0' .. '\u01c3'
-            | '\u01c4' .. '\u0293'
-            | '\u0294'
-            | '\u0295' .. '\u02af'
-            | '\u02b0' .. '\u02c1'
+            | '\xf8' .. '\u02c1'
             | '\u02c6' .. '\u02d1'
             | '\u02e0' .. '\u02e4'
             | '\u02ec'
             | '\u02ee'
             | '\u0345'
-            | '\u0370' .. '\u0373'
-            | '\u0374'
+            | '\u0370' .. '\u0374'
             | '\u0376' .. '\u0377'
-            | '\u037a'
-            | '\u037b' .. '\u037d'
+            | '\u037a' .. '\u037d'
+            | '\u037f'
             | '\u0386'
             | '\u0388' .. '\u038a'
             | '\u038c'
             | '\u038e' .. '\u03a1'
             | '\u03a3' .. '\u03f5'
             | '\u03f7' .. '\u0481'
-            | '\u048a' .. '\u0527'
+            | '\u048a' .. '\u052f'
             | '\u0531' .. '\u0556'
             | '\u0559'
-            | '\u0561' .. '\u0587'
+            | '\u0560' .. '\u0588'
             | '\u05b0' .. '\u05bd'
             | '\u05bf'
             | '\u05c1' .. '\u05c2'
             | '\u05c4' .. '\u05c5'
             | '\u05c7'
             | '\u05d0' .. '\u05ea'
-            | '\u05f0' .. '\u05f2'
+            | '\u05ef' .. '\u05f2'
             | '\u0610' .. '\u061a'
-            | '\u0620' .. '\u063f'
-            | '\u0640'
-            | '\u0641' .. '\u064a'
-            | '\u064b' .. '\u0657'
+            | '\u0620' .. '\u0657'
             | '\u0659' .. '\u065f'
-            | '\u066e' .. '\u066f'
-            | '\u0670'
-            | '\u0671' .. '\u06d3'
-            | '\u06d5'
-            | '\u06d6' .. '\u06dc'
-            | '\u06e1' .. '\u06e4'
-            | '\u06e5' .. '\u06e6'
-            | '\u06e7' .. '\u06e8'
-            | '\u06ed'
-            | '\u06ee' .. '\u06ef'
+            | '\u066e' .. '\u06d3'
+            | '\u06d5' .. '\u06dc'
+            | '\u06e1' .. '\u06e8'
+            | '\u06ed' .. '\u06ef'
             | '\u06fa' .. '\u06fc'
             | '\u06ff'
-            | '\u0710'
-            | '\u0711'
-            | '\u0712' .. '\u072f'
-            | '\u0730' .. '\u073f'
-            | '\u074d' .. '\u07a5'
-            | '\u07a6' .. '\u07b0'
-            | '\u07b1'
+            | '\u0710' .. '\u073f'
+            | '\u074d' .. '\u07b1'
             | '\u07ca' .. '\u07ea'
             | '\u07f4' .. '\u07f5'
             | '\u07fa'
-            | '\u0800' .. '\u0815'
-            | '\u0816' .. '\u0817'
-            | '\u081a'
-            | '\u081b' .. '\u0823'
-            | '\u0824'
-            | '\u0825' .. '\u0827'
-            | '\u0828'
-            | '\u0829' .. '\u082c'
+            | '\u0800' .. '\u0817'
+            | '\u081a' .. '\u082c'
             | '\u0840' .. '\u0858'
-            | '\u0900' .. '\u0902'
-            | '\u0903'
-            | '\u0904' .. '\u0939'
-            | '\u093a'
-            | '\u093b'
-            | '\u093d'
-            | '\u093e' .. '\u0940'
-            | '\u0941' .. '\u0948'
-            | '\u0949' .. '\u094c'
-            | '\u094e' .. '\u094f'
-            | '\u0950'
-            | '\u0955' .. '\u0957'
-            | '\u0958' .. '\u0961'
-            | '\u0962' .. '\u0963'
-            | '\u0971'
-            | '\u0972' .. '\u0977'
-            | '\u0979' .. '\u097f'
-            | '\u0981'
-            | '\u0982' .. '\u0983'
+            | '\u0860' .. '\u086a'
+            | '\u0870' .. '\u0887'
+            | '\u0889' .. '\u088e'
+            | '\u08a0' .. '\u08c9'
+            | '\u08d4' .. '\u08df'
+            | '\u08e3' .. '\u08e9'
+            | '\u08f0' .. '\u093b'
+            | '\u093d' .. '\u094c'
+            | '\u094e' .. '\u0950'
+            | '\u0955' .. '\u0963'
+            | '\u0971' .. '\u0983'
             | '\u0985' .. '\u098c'
             | '\u098f' .. '\u0990'
             | '\u0993' .. '\u09a8'
             | '\u09aa' .. '\u09b0'
             | '\u09b2'
             | '\u09b6' .. '\u09b9'
-            | '\u09bd'
-            | '\u09be' .. '\u09c0'
-            | '\u09c1' .. '\u09c4'
+            | '\u09bd' .. '\u09c4'
             | '\u09c7' .. '\u09c8'
             | '\u09cb' .. '\u09cc'
             | '\u09ce'
             | '\u09d7'
             | '\u09dc' .. '\u09dd'
-            | '\u09df' .. '\u09e1'
-            | '\u09e2' .. '\u09e3'
+            | '\u09df' .. '\u09e3'
             | '\u09f0' .. '\u09f1'
-            | '\u0a01' .. '\u0a02'
-            | '\u0a03'
+            | '\u09fc'
+            | '\u0a01' .. '\u0a03'
             | '\u0a05' .. '\u0a0a'
             | '\u0a0f' .. '\u0a10'
             | '\u0a13' .. '\u0a28'
@@ -2702,56 +3222,41 @@ mod derived_property {
             | '\u0a32' .. '\u0a33'
             | '\u0a35' .. '\u0a36'
             | '\u0a38' .. '\u0a39'
-            | '\u0a3e' .. '\u0a40'
-            | '\u0a41' .. '\u0a42'
+            | '\u0a3e' .. '\u0a42'
             | '\u0a47' .. '\u0a48'
             | '\u0a4b' .. '\u0a4c'
             | '\u0a51'
             | '\u0a59' .. '\u0a5c'
             | '\u0a5e'
-            | '\u0a70' .. '\u0a71'
-            | '\u0a72' .. '\u0a74'
-            | '\u0a75'
-            | '\u0a81' .. '\u0a82'
-            | '\u0a83'
+            | '\u0a70' .. '\u0a75'
+            | '\u0a81' .. '\u0a83'
             | '\u0a85' .. '\u0a8d'
             | '\u0a8f' .. '\u0a91'
             | '\u0a93' .. '\u0aa8'
             | '\u0aaa' .. '\u0ab0'
             | '\u0ab2' .. '\u0ab3'
             | '\u0ab5' .. '\u0ab9'
-            | '\u0abd'
-            | '\u0abe' .. '\u0ac0'
-            | '\u0ac1' .. '\u0ac5'
-            | '\u0ac7' .. '\u0ac8'
-            | '\u0ac9'
+            | '\u0abd' .. '\u0ac5'
+            | '\u0ac7' .. '\u0ac9'
             | '\u0acb' .. '\u0acc'
             | '\u0ad0'
-            | '\u0ae0' .. '\u0ae1'
-            | '\u0ae2' .. '\u0ae3'
-            | '\u0b01'
-            | '\u0b02' .. '\u0b03'
+            | '\u0ae0' .. '\u0ae3'
+            | '\u0af9' .. '\u0afc'
+            | '\u0b01' .. '\u0b03'
             | '\u0b05' .. '\u0b0c'
             | '\u0b0f' .. '\u0b10'
             | '\u0b13' .. '\u0b28'
             | '\u0b2a' .. '\u0b30'
             | '\u0b32' .. '\u0b33'
             | '\u0b35' .. '\u0b39'
-            | '\u0b3d'
-            | '\u0b3e'
-            | '\u0b3f'
-            | '\u0b40'
-            | '\u0b41' .. '\u0b44'
+            | '\u0b3d' .. '\u0b44'
             | '\u0b47' .. '\u0b48'
             | '\u0b4b' .. '\u0b4c'
-            | '\u0b56'
-            | '\u0b57'
+            | '\u0b56' .. '\u0b57'
             | '\u0b5c' .. '\u0b5d'
-            | '\u0b5f' .. '\u0b61'
-            | '\u0b62' .. '\u0b63'
+            | '\u0b5f' .. '\u0b63'
             | '\u0b71'
-            | '\u0b82'
-            | '\u0b83'
+            | '\u0b82' .. '\u0b83'
             | '\u0b85' .. '\u0b8a'
             | '\u0b8e' .. '\u0b90'
             | '\u0b92' .. '\u0b95'
@@ -2761,140 +3266,86 @@ mod derived_property {
             | '\u0ba3' .. '\u0ba4'
             | '\u0ba8' .. '\u0baa'
             | '\u0bae' .. '\u0bb9'
-            | '\u0bbe' .. '\u0bbf'
-            | '\u0bc0'
-            | '\u0bc1' .. '\u0bc2'
+            | '\u0bbe' .. '\u0bc2'
             | '\u0bc6' .. '\u0bc8'
             | '\u0bca' .. '\u0bcc'
             | '\u0bd0'
             | '\u0bd7'
-            | '\u0c01' .. '\u0c03'
+            | '\u0c00' .. '\u0c03'
             | '\u0c05' .. '\u0c0c'
             | '\u0c0e' .. '\u0c10'
             | '\u0c12' .. '\u0c28'
-            | '\u0c2a' .. '\u0c33'
-            | '\u0c35' .. '\u0c39'
-            | '\u0c3d'
-            | '\u0c3e' .. '\u0c40'
-            | '\u0c41' .. '\u0c44'
+            | '\u0c2a' .. '\u0c39'
+            | '\u0c3d' .. '\u0c44'
             | '\u0c46' .. '\u0c48'
             | '\u0c4a' .. '\u0c4c'
             | '\u0c55' .. '\u0c56'
-            | '\u0c58' .. '\u0c59'
-            | '\u0c60' .. '\u0c61'
-            | '\u0c62' .. '\u0c63'
-            | '\u0c82' .. '\u0c83'
+            | '\u0c58' .. '\u0c5a'
+            | '\u0c5d'
+            | '\u0c60' .. '\u0c63'
+            | '\u0c80' .. '\u0c83'
             | '\u0c85' .. '\u0c8c'
             | '\u0c8e' .. '\u0c90'
             | '\u0c92' .. '\u0ca8'
             | '\u0caa' .. '\u0cb3'
             | '\u0cb5' .. '\u0cb9'
-            | '\u0cbd'
-            | '\u0cbe'
-            | '\u0cbf'
-            | '\u0cc0' .. '\u0cc4'
-            | '\u0cc6'
-            | '\u0cc7' .. '\u0cc8'
-            | '\u0cca' .. '\u0ccb'
-            | '\u0ccc'
+            | '\u0cbd' .. '\u0cc4'
+            | '\u0cc6' .. '\u0cc8'
+            | '\u0cca' .. '\u0ccc'
             | '\u0cd5' .. '\u0cd6'
-            | '\u0cde'
-            | '\u0ce0' .. '\u0ce1'
-            | '\u0ce2' .. '\u0ce3'
+            | '\u0cdd' .. '\u0cde'
+            | '\u0ce0' .. '\u0ce3'
             | '\u0cf1' .. '\u0cf2'
-            | '\u0d02' .. '\u0d03'
-            | '\u0d05' .. '\u0d0c'
+            | '\u0d00' .. '\u0d0c'
             | '\u0d0e' .. '\u0d10'
             | '\u0d12' .. '\u0d3a'
-            | '\u0d3d'
-            | '\u0d3e' .. '\u0d40'
-            | '\u0d41' .. '\u0d44'
+            | '\u0d3d' .. '\u0d44'
             | '\u0d46' .. '\u0d48'
             | '\u0d4a' .. '\u0d4c'
             | '\u0d4e'
-            | '\u0d57'
-            | '\u0d60' .. '\u0d61'
-            | '\u0d62' .. '\u0d63'
+            | '\u0d54' .. '\u0d57'
+            | '\u0d5f' .. '\u0d63'
             | '\u0d7a' .. '\u0d7f'
-            | '\u0d82' .. '\u0d83'
+            | '\u0d81' .. '\u0d83'
             | '\u0d85' .. '\u0d96'
             | '\u0d9a' .. '\u0db1'
             | '\u0db3' .. '\u0dbb'
             | '\u0dbd'
             | '\u0dc0' .. '\u0dc6'
-            | '\u0dcf' .. '\u0dd1'
-            | '\u0dd2' .. '\u0dd4'
+            | '\u0dcf' .. '\u0dd4'
             | '\u0dd6'
             | '\u0dd8' .. '\u0ddf'
             | '\u0df2' .. '\u0df3'
-            | '\u0e01' .. '\u0e30'
-            | '\u0e31'
-            | '\u0e32' .. '\u0e33'
-            | '\u0e34' .. '\u0e3a'
-            | '\u0e40' .. '\u0e45'
-            | '\u0e46'
+            | '\u0e01' .. '\u0e3a'
+            | '\u0e40' .. '\u0e46'
             | '\u0e4d'
             | '\u0e81' .. '\u0e82'
             | '\u0e84'
-            | '\u0e87' .. '\u0e88'
-            | '\u0e8a'
-            | '\u0e8d'
-            | '\u0e94' .. '\u0e97'
-            | '\u0e99' .. '\u0e9f'
-            | '\u0ea1' .. '\u0ea3'
+            | '\u0e86' .. '\u0e8a'
+            | '\u0e8c' .. '\u0ea3'
             | '\u0ea5'
-            | '\u0ea7'
-            | '\u0eaa' .. '\u0eab'
-            | '\u0ead' .. '\u0eb0'
-            | '\u0eb1'
-            | '\u0eb2' .. '\u0eb3'
-            | '\u0eb4' .. '\u0eb9'
-            | '\u0ebb' .. '\u0ebc'
-            | '\u0ebd'
+            | '\u0ea7' .. '\u0eb9'
+            | '\u0ebb' .. '\u0ebd'
             | '\u0ec0' .. '\u0ec4'
             | '\u0ec6'
             | '\u0ecd'
-            | '\u0edc' .. '\u0edd'
+            | '\u0edc' .. '\u0edf'
             | '\u0f00'
             | '\u0f40' .. '\u0f47'
             | '\u0f49' .. '\u0f6c'
-            | '\u0f71' .. '\u0f7e'
-            | '\u0f7f'
-            | '\u0f80' .. '\u0f81'
-            | '\u0f88' .. '\u0f8c'
-            | '\u0f8d' .. '\u0f97'
+            | '\u0f71' .. '\u0f81'
+            | '\u0f88' .. '\u0f97'
             | '\u0f99' .. '\u0fbc'
-            | '\u1000' .. '\u102a'
-            | '\u102b' .. '\u102c'
-            | '\u102d' .. '\u1030'
-            | '\u1031'
-            | '\u1032' .. '\u1036'
+            | '\u1000' .. '\u1036'
             | '\u1038'
-            | '\u103b' .. '\u103c'
-            | '\u103d' .. '\u103e'
-            | '\u103f'
-            | '\u1050' .. '\u1055'
-            | '\u1056' .. '\u1057'
-            | '\u1058' .. '\u1059'
-            | '\u105a' .. '\u105d'
-            | '\u105e' .. '\u1060'
-            | '\u1061'
-            | '\u1062'
-            | '\u1065' .. '\u1066'
-            | '\u1067' .. '\u1068'
-            | '\u106e' .. '\u1070'
-            | '\u1071' .. '\u1074'
-            | '\u1075' .. '\u1081'
-            | '\u1082'
-            | '\u1083' .. '\u1084'
-            | '\u1085' .. '\u1086'
-            | '\u108e'
-            | '\u109c'
-            | '\u109d'
+            | '\u103b' .. '\u103f'
+            | '\u1050' .. '\u108f'
+            | '\u109a' .. '\u109d'
             | '\u10a0' .. '\u10c5'
+            | '\u10c7'
+            | '\u10cd'
             | '\u10d0' .. '\u10fa'
-            | '\u10fc'
-            | '\u1100' .. '\u1248'
+            | '\u10fc' .. '\u1248'
             | '\u124a' .. '\u124d'
             | '\u1250' .. '\u1256'
             | '\u1258'
@@ -2910,110 +3361,59 @@ mod derived_property {
             | '\u12d8' .. '\u1310'
             | '\u1312' .. '\u1315'
             | '\u1318' .. '\u135a'
-            | '\u135f'
             | '\u1380' .. '\u138f'
-            | '\u13a0' .. '\u13f4'
+            | '\u13a0' .. '\u13f5'
+            | '\u13f8' .. '\u13fd'
             | '\u1401' .. '\u166c'
             | '\u166f' .. '\u167f'
             | '\u1681' .. '\u169a'
             | '\u16a0' .. '\u16ea'
-            | '\u16ee' .. '\u16f0'
-            | '\u1700' .. '\u170c'
-            | '\u170e' .. '\u1711'
-            | '\u1712' .. '\u1713'
-            | '\u1720' .. '\u1731'
-            | '\u1732' .. '\u1733'
-            | '\u1740' .. '\u1751'
-            | '\u1752' .. '\u1753'
+            | '\u16ee' .. '\u16f8'
+            | '\u1700' .. '\u1713'
+            | '\u171f' .. '\u1733'
+            | '\u1740' .. '\u1753'
             | '\u1760' .. '\u176c'
             | '\u176e' .. '\u1770'
             | '\u1772' .. '\u1773'
             | '\u1780' .. '\u17b3'
-            | '\u17b6'
-            | '\u17b7' .. '\u17bd'
-            | '\u17be' .. '\u17c5'
-            | '\u17c6'
-            | '\u17c7' .. '\u17c8'
+            | '\u17b6' .. '\u17c8'
             | '\u17d7'
             | '\u17dc'
-            | '\u1820' .. '\u1842'
-            | '\u1843'
-            | '\u1844' .. '\u1877'
-            | '\u1880' .. '\u18a8'
-            | '\u18a9'
-            | '\u18aa'
+            | '\u1820' .. '\u1878'
+            | '\u1880' .. '\u18aa'
             | '\u18b0' .. '\u18f5'
-            | '\u1900' .. '\u191c'
-            | '\u1920' .. '\u1922'
-            | '\u1923' .. '\u1926'
-            | '\u1927' .. '\u1928'
-            | '\u1929' .. '\u192b'
-            | '\u1930' .. '\u1931'
-            | '\u1932'
-            | '\u1933' .. '\u1938'
+            | '\u1900' .. '\u191e'
+            | '\u1920' .. '\u192b'
+            | '\u1930' .. '\u1938'
             | '\u1950' .. '\u196d'
             | '\u1970' .. '\u1974'
             | '\u1980' .. '\u19ab'
-            | '\u19b0' .. '\u19c0'
-            | '\u19c1' .. '\u19c7'
-            | '\u19c8' .. '\u19c9'
-            | '\u1a00' .. '\u1a16'
-            | '\u1a17' .. '\u1a18'
-            | '\u1a19' .. '\u1a1b'
-            | '\u1a20' .. '\u1a54'
-            | '\u1a55'
-            | '\u1a56'
-            | '\u1a57'
-            | '\u1a58' .. '\u1a5e'
-            | '\u1a61'
-            | '\u1a62'
-            | '\u1a63' .. '\u1a64'
-            | '\u1a65' .. '\u1a6c'
-            | '\u1a6d' .. '\u1a72'
-            | '\u1a73' .. '\u1a74'
+            | '\u19b0' .. '\u19c9'
+            | '\u1a00' .. '\u1a1b'
+            | '\u1a20' .. '\u1a5e'
+            | '\u1a61' .. '\u1a74'
             | '\u1aa7'
-            | '\u1b00' .. '\u1b03'
-            | '\u1b04'
-            | '\u1b05' .. '\u1b33'
-            | '\u1b35'
-            | '\u1b36' .. '\u1b3a'
-            | '\u1b3b'
-            | '\u1b3c'
-            | '\u1b3d' .. '\u1b41'
-            | '\u1b42'
-            | '\u1b43'
-            | '\u1b45' .. '\u1b4b'
-            | '\u1b80' .. '\u1b81'
-            | '\u1b82'
-            | '\u1b83' .. '\u1ba0'
-            | '\u1ba1'
-            | '\u1ba2' .. '\u1ba5'
-            | '\u1ba6' .. '\u1ba7'
-            | '\u1ba8' .. '\u1ba9'
-            | '\u1bae' .. '\u1baf'
-            | '\u1bc0' .. '\u1be5'
-            | '\u1be7'
-            | '\u1be8' .. '\u1be9'
-            | '\u1bea' .. '\u1bec'
-            | '\u1bed'
-            | '\u1bee'
-            | '\u1bef' .. '\u1bf1'
-            | '\u1c00' .. '\u1c23'
-            | '\u1c24' .. '\u1c2b'
-            | '\u1c2c' .. '\u1c33'
-            | '\u1c34' .. '\u1c35'
+            | '\u1abf' .. '\u1ac0'
+            | '\u1acc' .. '\u1ace'
+            | '\u1b00' .. '\u1b33'
+            | '\u1b35' .. '\u1b43'
+            | '\u1b45' .. '\u1b4c'
+            | '\u1b80' .. '\u1ba9'
+            | '\u1bac' .. '\u1baf'
+            | '\u1bba' .. '\u1be5'
+            | '\u1be7' .. '\u1bf1'
+            | '\u1c00' .. '\u1c36'
             | '\u1c4d' .. '\u1c4f'
-            | '\u1c5a' .. '\u1c77'
-            | '\u1c78' .. '\u1c7d'
+            | '\u1c5a' .. '\u1c7d'
+            | '\u1c80' .. '\u1c88'
+            | '\u1c90' .. '\u1cba'
+            | '\u1cbd' .. '\u1cbf'
             | '\u1ce9' .. '\u1cec'
-            | '\u1cee' .. '\u1cf1'
-            | '\u1cf2'
-            | '\u1d00' .. '\u1d2b'
-            | '\u1d2c' .. '\u1d61'
-            | '\u1d62' .. '\u1d77'
-            | '\u1d78'
-            | '\u1d79' .. '\u1d9a'
-            | '\u1d9b' .. '\u1dbf'
+            | '\u1cee' .. '\u1cf3'
+            | '\u1cf5' .. '\u1cf6'
+            | '\u1cfa'
+            | '\u1d00' .. '\u1dbf'
+            | '\u1de7' .. '\u1df4'
             | '\u1e00' .. '\u1f15'
             | '\u1f18' .. '\u1f1d'
             | '\u1f20' .. '\u1f45'
@@ -3045,24 +3445,19 @@ mod derived_property {
             | '\u2126'
             | '\u2128'
             | '\u212a' .. '\u212d'
-            | '\u212f' .. '\u2134'
-            | '\u2135' .. '\u2138'
-            | '\u2139'
+            | '\u212f' .. '\u2139'
             | '\u213c' .. '\u213f'
             | '\u2145' .. '\u2149'
             | '\u214e'
-            | '\u2160' .. '\u2182'
-            | '\u2183' .. '\u2184'
-            | '\u2185' .. '\u2188'
+            | '\u2160' .. '\u2188'
             | '\u24b6' .. '\u24e9'
-            | '\u2c00' .. '\u2c2e'
-            | '\u2c30' .. '\u2c5e'
-            | '\u2c60' .. '\u2c7c'
-            | '\u2c7d'
-            | '\u2c7e' .. '\u2ce4'
+            | '\u2c00' .. '\u2ce4'
             | '\u2ceb' .. '\u2cee'
+            | '\u2cf2' .. '\u2cf3'
             | '\u2d00' .. '\u2d25'
-            | '\u2d30' .. '\u2d65'
+            | '\u2d27'
+            | '\u2d2d'
+            | '\u2d30' .. '\u2d67'
             | '\u2d6f'
             | '\u2d80' .. '\u2d96'
             | '\u2da0' .. '\u2da6'
@@ -3075,127 +3470,74 @@ mod derived_property {
             | '\u2dd8' .. '\u2dde'
             | '\u2de0' .. '\u2dff'
             | '\u2e2f'
-            | '\u3005'
-            | '\u3006'
-            | '\u3007'
+            | '\u3005' .. '\u3007'
             | '\u3021' .. '\u3029'
             | '\u3031' .. '\u3035'
-            | '\u3038' .. '\u303a'
-            | '\u303b'
-            | '\u303c'
+            | '\u3038' .. '\u303c'
             | '\u3041' .. '\u3096'
-            | '\u309d' .. '\u309e'
-            | '\u309f'
+            | '\u309d' .. '\u309f'
             | '\u30a1' .. '\u30fa'
-            | '\u30fc' .. '\u30fe'
-            | '\u30ff'
-            | '\u3105' .. '\u312d'
+            | '\u30fc' .. '\u30ff'
+            | '\u3105' .. '\u312f'
             | '\u3131' .. '\u318e'
-            | '\u31a0' .. '\u31ba'
+            | '\u31a0' .. '\u31bf'
             | '\u31f0' .. '\u31ff'
-            | '\u3400' .. '\u4db5'
-            | '\u4e00' .. '\u9fcb'
-            | '\ua000' .. '\ua014'
-            | '\ua015'
-            | '\ua016' .. '\ua48c'
-            | '\ua4d0' .. '\ua4f7'
-            | '\ua4f8' .. '\ua4fd'
-            | '\ua500' .. '\ua60b'
-            | '\ua60c'
+            | '\u3400' .. '\u4dbf'
+            | '\u4e00' .. '\ua48c'
+            | '\ua4d0' .. '\ua4fd'
+            | '\ua500' .. '\ua60c'
             | '\ua610' .. '\ua61f'
             | '\ua62a' .. '\ua62b'
-            | '\ua640' .. '\ua66d'
-            | '\ua66e'
-            | '\ua67f'
-            | '\ua680' .. '\ua697'
-            | '\ua6a0' .. '\ua6e5'
-            | '\ua6e6' .. '\ua6ef'
+            | '\ua640' .. '\ua66e'
+            | '\ua674' .. '\ua67b'
+            | '\ua67f' .. '\ua6ef'
             | '\ua717' .. '\ua71f'
-            | '\ua722' .. '\ua76f'
-            | '\ua770'
-            | '\ua771' .. '\ua787'
-            | '\ua788'
-            | '\ua78b' .. '\ua78e'
-            | '\ua790' .. '\ua791'
-            | '\ua7a0' .. '\ua7a9'
-            | '\ua7fa'
-            | '\ua7fb' .. '\ua801'
-            | '\ua803' .. '\ua805'
-            | '\ua807' .. '\ua80a'
-            | '\ua80c' .. '\ua822'
-            | '\ua823' .. '\ua824'
-            | '\ua825' .. '\ua826'
-            | '\ua827'
+            | '\ua722' .. '\ua788'
+            | '\ua78b' .. '\ua7ca'
+            | '\ua7d0' .. '\ua7d1'
+            | '\ua7d3'
+            | '\ua7d5' .. '\ua7d9'
+            | '\ua7f2' .. '\ua805'
+            | '\ua807' .. '\ua827'
             | '\ua840' .. '\ua873'
-            | '\ua880' .. '\ua881'
-            | '\ua882' .. '\ua8b3'
-            | '\ua8b4' .. '\ua8c3'
+            | '\ua880' .. '\ua8c3'
+            | '\ua8c5'
             | '\ua8f2' .. '\ua8f7'
             | '\ua8fb'
-            | '\ua90a' .. '\ua925'
-            | '\ua926' .. '\ua92a'
-            | '\ua930' .. '\ua946'
-            | '\ua947' .. '\ua951'
-            | '\ua952'
+            | '\ua8fd' .. '\ua8ff'
+            | '\ua90a' .. '\ua92a'
+            | '\ua930' .. '\ua952'
             | '\ua960' .. '\ua97c'
-            | '\ua980' .. '\ua982'
-            | '\ua983'
-            | '\ua984' .. '\ua9b2'
-            | '\ua9b4' .. '\ua9b5'
-            | '\ua9b6' .. '\ua9b9'
-            | '\ua9ba' .. '\ua9bb'
-            | '\ua9bc'
-            | '\ua9bd' .. '\ua9bf'
+            | '\ua980' .. '\ua9b2'
+            | '\ua9b4' .. '\ua9bf'
             | '\ua9cf'
-            | '\uaa00' .. '\uaa28'
-            | '\uaa29' .. '\uaa2e'
-            | '\uaa2f' .. '\uaa30'
-            | '\uaa31' .. '\uaa32'
-            | '\uaa33' .. '\uaa34'
-            | '\uaa35' .. '\uaa36'
-            | '\uaa40' .. '\uaa42'
-            | '\uaa43'
-            | '\uaa44' .. '\uaa4b'
-            | '\uaa4c'
-            | '\uaa4d'
-            | '\uaa60' .. '\uaa6f'
-            | '\uaa70'
-            | '\uaa71' .. '\uaa76'
-            | '\uaa7a'
-            | '\uaa80' .. '\uaaaf'
-            | '\uaab0'
-            | '\uaab1'
-            | '\uaab2' .. '\uaab4'
-            | '\uaab5' .. '\uaab6'
-            | '\uaab7' .. '\uaab8'
-            | '\uaab9' .. '\uaabd'
-            | '\uaabe'
+            | '\ua9e0' .. '\ua9ef'
+            | '\ua9fa' .. '\ua9fe'
+            | '\uaa00' .. '\uaa36'
+            | '\uaa40' .. '\uaa4d'
+            | '\uaa60' .. '\uaa76'
+            | '\uaa7a' .. '\uaabe'
             | '\uaac0'
             | '\uaac2'
-            | '\uaadb' .. '\uaadc'
-            | '\uaadd'
+            | '\uaadb' .. '\uaadd'
+            | '\uaae0' .. '\uaaef'
+            | '\uaaf2' .. '\uaaf5'
             | '\uab01' .. '\uab06'
             | '\uab09' .. '\uab0e'
             | '\uab11' .. '\uab16'
             | '\uab20' .. '\uab26'
             | '\uab28' .. '\uab2e'
-            | '\uabc0' .. '\uabe2'
-            | '\uabe3' .. '\uabe4'
-            | '\uabe5'
-            | '\uabe6' .. '\uabe7'
-            | '\uabe8'
-            | '\uabe9' .. '\uabea'
+            | '\uab30' .. '\uab5a'
+            | '\uab5c' .. '\uab69'
+            | '\uab70' .. '\uabea'
             | '\uac00' .. '\ud7a3'
             | '\ud7b0' .. '\ud7c6'
             | '\ud7cb' .. '\ud7fb'
-            | '\uf900' .. '\ufa2d'
-            | '\ufa30' .. '\ufa6d'
+            | '\uf900' .. '\ufa6d'
             | '\ufa70' .. '\ufad9'
             | '\ufb00' .. '\ufb06'
             | '\ufb13' .. '\ufb17'
-            | '\ufb1d'
-            | '\ufb1e'
-            | '\ufb1f' .. '\ufb28'
+            | '\ufb1d' .. '\ufb28'
             | '\ufb2a' .. '\ufb36'
             | '\ufb38' .. '\ufb3c'
             | '\ufb3e'
@@ -3210,11 +3552,7 @@ mod derived_property {
             | '\ufe76' .. '\ufefc'
             | '\uff21' .. '\uff3a'
             | '\uff41' .. '\uff5a'
-            | '\uff66' .. '\uff6f'
-            | '\uff70'
-            | '\uff71' .. '\uff9d'
-            | '\uff9e' .. '\uff9f'
-            | '\uffa0' .. '\uffbe'
+            | '\uff66' .. '\uffbe'
             | '\uffc2' .. '\uffc7'
             | '\uffca' .. '\uffcf'
             | '\uffd2' .. '\uffd7'
@@ -3229,52 +3567,206 @@ mod derived_property {
             | '\U00010140' .. '\U00010174'
             | '\U00010280' .. '\U0001029c'
             | '\U000102a0' .. '\U000102d0'
-            | '\U00010300' .. '\U0001031e'
-            | '\U00010330' .. '\U00010340'
-            | '\U00010341'
-            | '\U00010342' .. '\U00010349'
-            | '\U0001034a'
+            | '\U00010300' .. '\U0001031f'
+            | '\U0001032d' .. '\U0001034a'
+            | '\U00010350' .. '\U0001037a'
             | '\U00010380' .. '\U0001039d'
             | '\U000103a0' .. '\U000103c3'
             | '\U000103c8' .. '\U000103cf'
             | '\U000103d1' .. '\U000103d5'
-            | '\U00010400' .. '\U0001044f'
-            | '\U00010450' .. '\U0001049d'
+            | '\U00010400' .. '\U0001049d'
+            | '\U000104b0' .. '\U000104d3'
+            | '\U000104d8' .. '\U000104fb'
+            | '\U00010500' .. '\U00010527'
+            | '\U00010530' .. '\U00010563'
+            | '\U00010570' .. '\U0001057a'
+            | '\U0001057c' .. '\U0001058a'
+            | '\U0001058c' .. '\U00010592'
+            | '\U00010594' .. '\U00010595'
+            | '\U00010597' .. '\U000105a1'
+            | '\U000105a3' .. '\U000105b1'
+            | '\U000105b3' .. '\U000105b9'
+            | '\U000105bb' .. '\U000105bc'
+            | '\U00010600' .. '\U00010736'
+            | '\U00010740' .. '\U00010755'
+            | '\U00010760' .. '\U00010767'
+            | '\U00010780' .. '\U00010785'
+            | '\U00010787' .. '\U000107b0'
+            | '\U000107b2' .. '\U000107ba'
             | '\U00010800' .. '\U00010805'
             | '\U00010808'
             | '\U0001080a' .. '\U00010835'
             | '\U00010837' .. '\U00010838'
             | '\U0001083c'
             | '\U0001083f' .. '\U00010855'
+            | '\U00010860' .. '\U00010876'
+            | '\U00010880' .. '\U0001089e'
+            | '\U000108e0' .. '\U000108f2'
+            | '\U000108f4' .. '\U000108f5'
             | '\U00010900' .. '\U00010915'
             | '\U00010920' .. '\U00010939'
-            | '\U00010a00'
-            | '\U00010a01' .. '\U00010a03'
+            | '\U00010980' .. '\U000109b7'
+            | '\U000109be' .. '\U000109bf'
+            | '\U00010a00' .. '\U00010a03'
             | '\U00010a05' .. '\U00010a06'
-            | '\U00010a0c' .. '\U00010a0f'
-            | '\U00010a10' .. '\U00010a13'
+            | '\U00010a0c' .. '\U00010a13'
             | '\U00010a15' .. '\U00010a17'
-            | '\U00010a19' .. '\U00010a33'
+            | '\U00010a19' .. '\U00010a35'
             | '\U00010a60' .. '\U00010a7c'
+            | '\U00010a80' .. '\U00010a9c'
+            | '\U00010ac0' .. '\U00010ac7'
+            | '\U00010ac9' .. '\U00010ae4'
             | '\U00010b00' .. '\U00010b35'
             | '\U00010b40' .. '\U00010b55'
             | '\U00010b60' .. '\U00010b72'
+            | '\U00010b80' .. '\U00010b91'
             | '\U00010c00' .. '\U00010c48'
-            | '\U00011000'
-            | '\U00011001'
-            | '\U00011002'
-            | '\U00011003' .. '\U00011037'
-            | '\U00011038' .. '\U00011045'
-            | '\U00011082'
-            | '\U00011083' .. '\U000110af'
-            | '\U000110b0' .. '\U000110b2'
-            | '\U000110b3' .. '\U000110b6'
-            | '\U000110b7' .. '\U000110b8'
-            | '\U00012000' .. '\U0001236e'
-            | '\U00012400' .. '\U00012462'
+            | '\U00010c80' .. '\U00010cb2'
+            | '\U00010cc0' .. '\U00010cf2'
+            | '\U00010d00' .. '\U00010d27'
+            | '\U00010e80' .. '\U00010ea9'
+            | '\U00010eab' .. '\U00010eac'
+            | '\U00010eb0' .. '\U00010eb1'
+            | '\U00010f00' .. '\U00010f1c'
+            | '\U00010f27'
+            | '\U00010f30' .. '\U00010f45'
+            | '\U00010f70' .. '\U00010f81'
+            | '\U00010fb0' .. '\U00010fc4'
+            | '\U00010fe0' .. '\U00010ff6'
+            | '\U00011000' .. '\U00011045'
+            | '\U00011071' .. '\U00011075'
+            | '\U00011082' .. '\U000110b8'
+            | '\U000110c2'
+            | '\U000110d0' .. '\U000110e8'
+            | '\U00011100' .. '\U00011132'
+            | '\U00011144' .. '\U00011147'
+            | '\U00011150' .. '\U00011172'
+            | '\U00011176'
+            | '\U00011180' .. '\U000111bf'
+            | '\U000111c1' .. '\U000111c4'
+            | '\U000111ce' .. '\U000111cf'
+            | '\U000111da'
+            | '\U000111dc'
+            | '\U00011200' .. '\U00011211'
+            | '\U00011213' .. '\U00011234'
+            | '\U00011237'
+            | '\U0001123e'
+            | '\U00011280' .. '\U00011286'
+            | '\U00011288'
+            | '\U0001128a' .. '\U0001128d'
+            | '\U0001128f' .. '\U0001129d'
+            | '\U0001129f' .. '\U000112a8'
+            | '\U000112b0' .. '\U000112e8'
+            | '\U00011300' .. '\U00011303'
+            | '\U00011305' .. '\U0001130c'
+            | '\U0001130f' .. '\U00011310'
+            | '\U00011313' .. '\U00011328'
+            | '\U0001132a' .. '\U00011330'
+            | '\U00011332' .. '\U00011333'
+            | '\U00011335' .. '\U00011339'
+            | '\U0001133d' .. '\U00011344'
+            | '\U00011347' .. '\U00011348'
+            | '\U0001134b' .. '\U0001134c'
+            | '\U00011350'
+            | '\U00011357'
+            | '\U0001135d' .. '\U00011363'
+            | '\U00011400' .. '\U00011441'
+            | '\U00011443' .. '\U00011445'
+            | '\U00011447' .. '\U0001144a'
+            | '\U0001145f' .. '\U00011461'
+            | '\U00011480' .. '\U000114c1'
+            | '\U000114c4' .. '\U000114c5'
+            | '\U000114c7'
+            | '\U00011580' .. '\U000115b5'
+            | '\U000115b8' .. '\U000115be'
+            | '\U000115d8' .. '\U000115dd'
+            | '\U00011600' .. '\U0001163e'
+            | '\U00011640'
+            | '\U00011644'
+            | '\U00011680' .. '\U000116b5'
+            | '\U000116b8'
+            | '\U00011700' .. '\U0001171a'
+            | '\U0001171d' .. '\U0001172a'
+            | '\U00011740' .. '\U00011746'
+            | '\U00011800' .. '\U00011838'
+            | '\U000118a0' .. '\U000118df'
+            | '\U000118ff' .. '\U00011906'
+            | '\U00011909'
+            | '\U0001190c' .. '\U00011913'
+            | '\U00011915' .. '\U00011916'
+            | '\U00011918' .. '\U00011935'
+            | '\U00011937' .. '\U00011938'
+            | '\U0001193b' .. '\U0001193c'
+            | '\U0001193f' .. '\U00011942'
+            | '\U000119a0' .. '\U000119a7'
+            | '\U000119aa' .. '\U000119d7'
+            | '\U000119da' .. '\U000119df'
+            | '\U000119e1'
+            | '\U000119e3' .. '\U000119e4'
+            | '\U00011a00' .. '\U00011a32'
+            | '\U00011a35' .. '\U00011a3e'
+            | '\U00011a50' .. '\U00011a97'
+            | '\U00011a9d'
+            | '\U00011ab0' .. '\U00011af8'
+            | '\U00011c00' .. '\U00011c08'
+            | '\U00011c0a' .. '\U00011c36'
+            | '\U00011c38' .. '\U00011c3e'
+            | '\U00011c40'
+            | '\U00011c72' .. '\U00011c8f'
+            | '\U00011c92' .. '\U00011ca7'
+            | '\U00011ca9' .. '\U00011cb6'
+            | '\U00011d00' .. '\U00011d06'
+            | '\U00011d08' .. '\U00011d09'
+            | '\U00011d0b' .. '\U00011d36'
+            | '\U00011d3a'
+            | '\U00011d3c' .. '\U00011d3d'
+            | '\U00011d3f' .. '\U00011d41'
+            | '\U00011d43'
+            | '\U00011d46' .. '\U00011d47'
+            | '\U00011d60' .. '\U00011d65'
+            | '\U00011d67' .. '\U00011d68'
+            | '\U00011d6a' .. '\U00011d8e'
+            | '\U00011d90' .. '\U00011d91'
+            | '\U00011d93' .. '\U00011d96'
+            | '\U00011d98'
+            | '\U00011ee0' .. '\U00011ef6'
+            | '\U00011fb0'
+            | '\U00012000' .. '\U00012399'
+            | '\U00012400' .. '\U0001246e'
+            | '\U00012480' .. '\U00012543'
+            | '\U00012f90' .. '\U00012ff0'
             | '\U00013000' .. '\U0001342e'
+            | '\U00014400' .. '\U00014646'
             | '\U00016800' .. '\U00016a38'
-            | '\U0001b000' .. '\U0001b001'
+            | '\U00016a40' .. '\U00016a5e'
+            | '\U00016a70' .. '\U00016abe'
+            | '\U00016ad0' .. '\U00016aed'
+            | '\U00016b00' .. '\U00016b2f'
+            | '\U00016b40' .. '\U00016b43'
+            | '\U00016b63' .. '\U00016b77'
+            | '\U00016b7d' .. '\U00016b8f'
+            | '\U00016e40' .. '\U00016e7f'
+            | '\U00016f00' .. '\U00016f4a'
+            | '\U00016f4f' .. '\U00016f87'
+            | '\U00016f8f' .. '\U00016f9f'
+            | '\U00016fe0' .. '\U00016fe1'
+            | '\U00016fe3'
+            | '\U00016ff0' .. '\U00016ff1'
+            | '\U00017000' .. '\U000187f7'
+            | '\U00018800' .. '\U00018cd5'
+            | '\U00018d00' .. '\U00018d08'
+            | '\U0001aff0' .. '\U0001aff3'
+            | '\U0001aff5' .. '\U0001affb'
+            | '\U0001affd' .. '\U0001affe'
+            | '\U0001b000' .. '\U0001b122'
+            | '\U0001b150' .. '\U0001b152'
+            | '\U0001b164' .. '\U0001b167'
+            | '\U0001b170' .. '\U0001b2fb'
+            | '\U0001bc00' .. '\U0001bc6a'
+            | '\U0001bc70' .. '\U0001bc7c'
+            | '\U0001bc80' .. '\U0001bc88'
+            | '\U0001bc90' .. '\U0001bc99'
+            | '\U0001bc9e'
             | '\U0001d400' .. '\U0001d454'
             | '\U0001d456' .. '\U0001d49c'
             | '\U0001d49e' .. '\U0001d49f'
@@ -3305,12 +3797,70 @@ mod derived_property {
             | '\U0001d78a' .. '\U0001d7a8'
             | '\U0001d7aa' .. '\U0001d7c2'
             | '\U0001d7c4' .. '\U0001d7cb'
-            | '\U00020000' .. '\U0002a6d6'
-            | '\U0002a700' .. '\U0002b734'
+            | '\U0001df00' .. '\U0001df1e'
+            | '\U0001e000' .. '\U0001e006'
+            | '\U0001e008' .. '\U0001e018'
+            | '\U0001e01b' .. '\U0001e021'
+            | '\U0001e023' .. '\U0001e024'
+            | '\U0001e026' .. '\U0001e02a'
+            | '\U0001e100' .. '\U0001e12c'
+            | '\U0001e137' .. '\U0001e13d'
+            | '\U0001e14e'
+            | '\U0001e290' .. '\U0001e2ad'
+            | '\U0001e2c0' .. '\U0001e2eb'
+            | '\U0001e7e0' .. '\U0001e7e6'
+            | '\U0001e7e8' .. '\U0001e7eb'
+            | '\U0001e7ed' .. '\U0001e7ee'
+            | '\U0001e7f0' .. '\U0001e7fe'
+            | '\U0001e800' .. '\U0001e8c4'
+            | '\U0001e900' .. '\U0001e943'
+            | '\U0001e947'
+            | '\U0001e94b'
+            | '\U0001ee00' .. '\U0001ee03'
+            | '\U0001ee05' .. '\U0001ee1f'
+            | '\U0001ee21' .. '\U0001ee22'
+            | '\U0001ee24'
+            | '\U0001ee27'
+            | '\U0001ee29' .. '\U0001ee32'
+            | '\U0001ee34' .. '\U0001ee37'
+            | '\U0001ee39'
+            | '\U0001ee3b'
+            | '\U0001ee42'
+            | '\U0001ee47'
+            | '\U0001ee49'
+            | '\U0001ee4b'
+            | '\U0001ee4d' .. '\U0001ee4f'
+            | '\U0001ee51' .. '\U0001ee52'
+            | '\U0001ee54'
+            | '\U0001ee57'
+            | '\U0001ee59'
+            | '\U0001ee5b'
+            | '\U0001ee5d'
+            | '\U0001ee5f'
+            | '\U0001ee61' .. '\U0001ee62'
+            | '\U0001ee64'
+            | '\U0001ee67' .. '\U0001ee6a'
+            | '\U0001ee6c' .. '\U0001ee72'
+            | '\U0001ee74' .. '\U0001ee77'
+            | '\U0001ee79' .. '\U0001ee7c'
+            | '\U0001ee7e'
+            | '\U0001ee80' .. '\U0001ee89'
+            | '\U0001ee8b' .. '\U0001ee9b'
+            | '\U0001eea1' .. '\U0001eea3'
+            | '\U0001eea5' .. '\U0001eea9'
+            | '\U0001eeab' .. '\U0001eebb'
+            | '\U0001f130' .. '\U0001f149'
+            | '\U0001f150' .. '\U0001f169'
+            | '\U0001f170' .. '\U0001f189'
+            | '\U00020000' .. '\U0002a6df'
+            | '\U0002a700' .. '\U0002b738'
             | '\U0002b740' .. '\U0002b81d'
+            | '\U0002b820' .. '\U0002cea1'
+            | '\U0002ceb0' .. '\U0002ebe0'
             | '\U0002f800' .. '\U0002fa1d'
-          => true,
-          _ => false
+            | '\U00030000' .. '\U0003134a'
+              => true,
+            _ => false
         };
     }
 
@@ -3326,127 +3876,69 @@ mod derived_property {
             | '\xba'
             | '\xc0' .. '\xd6'
             | '\xd8' .. '\xf6'
-            | '\xf8' .. '\u01ba'
-            | '\u01bb'
-            | '\u01bc' .. '\u01bf'
-            | '\u01c0' .. '\u01c3'
-            | '\u01c4' .. '\u0293'
-            | '\u0294'
-            | '\u0295' .. '\u02af'
-            | '\u02b0' .. '\u02c1'
+            | '\xf8' .. '\u02c1'
             | '\u02c6' .. '\u02d1'
             | '\u02e0' .. '\u02e4'
             | '\u02ec'
             | '\u02ee'
-            | '\u0300' .. '\u036f'
-            | '\u0370' .. '\u0373'
-            | '\u0374'
+            | '\u0300' .. '\u0374'
             | '\u0376' .. '\u0377'
             | '\u037b' .. '\u037d'
-            | '\u0386'
-            | '\u0387'
-            | '\u0388' .. '\u038a'
+            | '\u037f'
+            | '\u0386' .. '\u038a'
             | '\u038c'
             | '\u038e' .. '\u03a1'
             | '\u03a3' .. '\u03f5'
             | '\u03f7' .. '\u0481'
             | '\u0483' .. '\u0487'
-            | '\u048a' .. '\u0527'
+            | '\u048a' .. '\u052f'
             | '\u0531' .. '\u0556'
             | '\u0559'
-            | '\u0561' .. '\u0587'
+            | '\u0560' .. '\u0588'
             | '\u0591' .. '\u05bd'
             | '\u05bf'
             | '\u05c1' .. '\u05c2'
             | '\u05c4' .. '\u05c5'
             | '\u05c7'
             | '\u05d0' .. '\u05ea'
-            | '\u05f0' .. '\u05f2'
+            | '\u05ef' .. '\u05f2'
             | '\u0610' .. '\u061a'
-            | '\u0620' .. '\u063f'
-            | '\u0640'
-            | '\u0641' .. '\u064a'
-            | '\u064b' .. '\u065f'
-            | '\u0660' .. '\u0669'
-            | '\u066e' .. '\u066f'
-            | '\u0670'
-            | '\u0671' .. '\u06d3'
-            | '\u06d5'
-            | '\u06d6' .. '\u06dc'
-            | '\u06df' .. '\u06e4'
-            | '\u06e5' .. '\u06e6'
-            | '\u06e7' .. '\u06e8'
-            | '\u06ea' .. '\u06ed'
-            | '\u06ee' .. '\u06ef'
-            | '\u06f0' .. '\u06f9'
-            | '\u06fa' .. '\u06fc'
+            | '\u0620' .. '\u0669'
+            | '\u066e' .. '\u06d3'
+            | '\u06d5' .. '\u06dc'
+            | '\u06df' .. '\u06e8'
+            | '\u06ea' .. '\u06fc'
             | '\u06ff'
-            | '\u0710'
-            | '\u0711'
-            | '\u0712' .. '\u072f'
-            | '\u0730' .. '\u074a'
-            | '\u074d' .. '\u07a5'
-            | '\u07a6' .. '\u07b0'
-            | '\u07b1'
-            | '\u07c0' .. '\u07c9'
-            | '\u07ca' .. '\u07ea'
-            | '\u07eb' .. '\u07f3'
-            | '\u07f4' .. '\u07f5'
+            | '\u0710' .. '\u074a'
+            | '\u074d' .. '\u07b1'
+            | '\u07c0' .. '\u07f5'
             | '\u07fa'
-            | '\u0800' .. '\u0815'
-            | '\u0816' .. '\u0819'
-            | '\u081a'
-            | '\u081b' .. '\u0823'
-            | '\u0824'
-            | '\u0825' .. '\u0827'
-            | '\u0828'
-            | '\u0829' .. '\u082d'
-            | '\u0840' .. '\u0858'
-            | '\u0859' .. '\u085b'
-            | '\u0900' .. '\u0902'
-            | '\u0903'
-            | '\u0904' .. '\u0939'
-            | '\u093a'
-            | '\u093b'
-            | '\u093c'
-            | '\u093d'
-            | '\u093e' .. '\u0940'
-            | '\u0941' .. '\u0948'
-            | '\u0949' .. '\u094c'
-            | '\u094d'
-            | '\u094e' .. '\u094f'
-            | '\u0950'
-            | '\u0951' .. '\u0957'
-            | '\u0958' .. '\u0961'
-            | '\u0962' .. '\u0963'
+            | '\u07fd'
+            | '\u0800' .. '\u082d'
+            | '\u0840' .. '\u085b'
+            | '\u0860' .. '\u086a'
+            | '\u0870' .. '\u0887'
+            | '\u0889' .. '\u088e'
+            | '\u0898' .. '\u08e1'
+            | '\u08e3' .. '\u0963'
             | '\u0966' .. '\u096f'
-            | '\u0971'
-            | '\u0972' .. '\u0977'
-            | '\u0979' .. '\u097f'
-            | '\u0981'
-            | '\u0982' .. '\u0983'
+            | '\u0971' .. '\u0983'
             | '\u0985' .. '\u098c'
             | '\u098f' .. '\u0990'
             | '\u0993' .. '\u09a8'
             | '\u09aa' .. '\u09b0'
             | '\u09b2'
             | '\u09b6' .. '\u09b9'
-            | '\u09bc'
-            | '\u09bd'
-            | '\u09be' .. '\u09c0'
-            | '\u09c1' .. '\u09c4'
+            | '\u09bc' .. '\u09c4'
             | '\u09c7' .. '\u09c8'
-            | '\u09cb' .. '\u09cc'
-            | '\u09cd'
-            | '\u09ce'
+            | '\u09cb' .. '\u09ce'
             | '\u09d7'
             | '\u09dc' .. '\u09dd'
-            | '\u09df' .. '\u09e1'
-            | '\u09e2' .. '\u09e3'
-            | '\u09e6' .. '\u09ef'
-            | '\u09f0' .. '\u09f1'
-            | '\u0a01' .. '\u0a02'
-            | '\u0a03'
+            | '\u09df' .. '\u09e3'
+            | '\u09e6' .. '\u09f1'
+            | '\u09fc'
+            | '\u09fe'
+            | '\u0a01' .. '\u0a03'
             | '\u0a05' .. '\u0a0a'
             | '\u0a0f' .. '\u0a10'
             | '\u0a13' .. '\u0a28'
@@ -3455,63 +3947,43 @@ mod derived_property {
             | '\u0a35' .. '\u0a36'
             | '\u0a38' .. '\u0a39'
             | '\u0a3c'
-            | '\u0a3e' .. '\u0a40'
-            | '\u0a41' .. '\u0a42'
+            | '\u0a3e' .. '\u0a42'
             | '\u0a47' .. '\u0a48'
             | '\u0a4b' .. '\u0a4d'
             | '\u0a51'
             | '\u0a59' .. '\u0a5c'
             | '\u0a5e'
-            | '\u0a66' .. '\u0a6f'
-            | '\u0a70' .. '\u0a71'
-            | '\u0a72' .. '\u0a74'
-            | '\u0a75'
-            | '\u0a81' .. '\u0a82'
-            | '\u0a83'
+            | '\u0a66' .. '\u0a75'
+            | '\u0a81' .. '\u0a83'
             | '\u0a85' .. '\u0a8d'
             | '\u0a8f' .. '\u0a91'
             | '\u0a93' .. '\u0aa8'
             | '\u0aaa' .. '\u0ab0'
             | '\u0ab2' .. '\u0ab3'
             | '\u0ab5' .. '\u0ab9'
-            | '\u0abc'
-            | '\u0abd'
-            | '\u0abe' .. '\u0ac0'
-            | '\u0ac1' .. '\u0ac5'
-            | '\u0ac7' .. '\u0ac8'
-            | '\u0ac9'
-            | '\u0acb' .. '\u0acc'
-            | '\u0acd'
+            | '\u0abc' .. '\u0ac5'
+            | '\u0ac7' .. '\u0ac9'
+            | '\u0acb' .. '\u0acd'
             | '\u0ad0'
-            | '\u0ae0' .. '\u0ae1'
-            | '\u0ae2' .. '\u0ae3'
+            | '\u0ae0' .. '\u0ae3'
             | '\u0ae6' .. '\u0aef'
-            | '\u0b01'
-            | '\u0b02' .. '\u0b03'
+            | '\u0af9' .. '\u0aff'
+            | '\u0b01' .. '\u0b03'
             | '\u0b05' .. '\u0b0c'
             | '\u0b0f' .. '\u0b10'
             | '\u0b13' .. '\u0b28'
             | '\u0b2a' .. '\u0b30'
             | '\u0b32' .. '\u0b33'
             | '\u0b35' .. '\u0b39'
-            | '\u0b3c'
-            | '\u0b3d'
-            | '\u0b3e'
-            | '\u0b3f'
-            | '\u0b40'
-            | '\u0b41' .. '\u0b44'
+            | '\u0b3c' .. '\u0b44'
             | '\u0b47' .. '\u0b48'
-            | '\u0b4b' .. '\u0b4c'
-            | '\u0b4d'
-            | '\u0b56'
-            | '\u0b57'
+            | '\u0b4b' .. '\u0b4d'
+            | '\u0b55' .. '\u0b57'
             | '\u0b5c' .. '\u0b5d'
-            | '\u0b5f' .. '\u0b61'
-            | '\u0b62' .. '\u0b63'
+            | '\u0b5f' .. '\u0b63'
             | '\u0b66' .. '\u0b6f'
             | '\u0b71'
-            | '\u0b82'
-            | '\u0b83'
+            | '\u0b82' .. '\u0b83'
             | '\u0b85' .. '\u0b8a'
             | '\u0b8e' .. '\u0b90'
             | '\u0b92' .. '\u0b95'
@@ -3521,164 +3993,92 @@ mod derived_property {
             | '\u0ba3' .. '\u0ba4'
             | '\u0ba8' .. '\u0baa'
             | '\u0bae' .. '\u0bb9'
-            | '\u0bbe' .. '\u0bbf'
-            | '\u0bc0'
-            | '\u0bc1' .. '\u0bc2'
+            | '\u0bbe' .. '\u0bc2'
             | '\u0bc6' .. '\u0bc8'
-            | '\u0bca' .. '\u0bcc'
-            | '\u0bcd'
+            | '\u0bca' .. '\u0bcd'
             | '\u0bd0'
             | '\u0bd7'
             | '\u0be6' .. '\u0bef'
-            | '\u0c01' .. '\u0c03'
-            | '\u0c05' .. '\u0c0c'
+            | '\u0c00' .. '\u0c0c'
             | '\u0c0e' .. '\u0c10'
             | '\u0c12' .. '\u0c28'
-            | '\u0c2a' .. '\u0c33'
-            | '\u0c35' .. '\u0c39'
-            | '\u0c3d'
-            | '\u0c3e' .. '\u0c40'
-            | '\u0c41' .. '\u0c44'
+            | '\u0c2a' .. '\u0c39'
+            | '\u0c3c' .. '\u0c44'
             | '\u0c46' .. '\u0c48'
             | '\u0c4a' .. '\u0c4d'
             | '\u0c55' .. '\u0c56'
-            | '\u0c58' .. '\u0c59'
-            | '\u0c60' .. '\u0c61'
-            | '\u0c62' .. '\u0c63'
+            | '\u0c58' .. '\u0c5a'
+            | '\u0c5d'
+            | '\u0c60' .. '\u0c63'
             | '\u0c66' .. '\u0c6f'
-            | '\u0c82' .. '\u0c83'
+            | '\u0c80' .. '\u0c83'
             | '\u0c85' .. '\u0c8c'
             | '\u0c8e' .. '\u0c90'
             | '\u0c92' .. '\u0ca8'
             | '\u0caa' .. '\u0cb3'
             | '\u0cb5' .. '\u0cb9'
-            | '\u0cbc'
-            | '\u0cbd'
-            | '\u0cbe'
-            | '\u0cbf'
-            | '\u0cc0' .. '\u0cc4'
-            | '\u0cc6'
-            | '\u0cc7' .. '\u0cc8'
-            | '\u0cca' .. '\u0ccb'
-            | '\u0ccc' .. '\u0ccd'
+            | '\u0cbc' .. '\u0cc4'
+            | '\u0cc6' .. '\u0cc8'
+            | '\u0cca' .. '\u0ccd'
             | '\u0cd5' .. '\u0cd6'
-            | '\u0cde'
-            | '\u0ce0' .. '\u0ce1'
-            | '\u0ce2' .. '\u0ce3'
+            | '\u0cdd' .. '\u0cde'
+            | '\u0ce0' .. '\u0ce3'
             | '\u0ce6' .. '\u0cef'
             | '\u0cf1' .. '\u0cf2'
-            | '\u0d02' .. '\u0d03'
-            | '\u0d05' .. '\u0d0c'
+            | '\u0d00' .. '\u0d0c'
             | '\u0d0e' .. '\u0d10'
-            | '\u0d12' .. '\u0d3a'
-            | '\u0d3d'
-            | '\u0d3e' .. '\u0d40'
-            | '\u0d41' .. '\u0d44'
+            | '\u0d12' .. '\u0d44'
             | '\u0d46' .. '\u0d48'
-            | '\u0d4a' .. '\u0d4c'
-            | '\u0d4d'
-            | '\u0d4e'
-            | '\u0d57'
-            | '\u0d60' .. '\u0d61'
-            | '\u0d62' .. '\u0d63'
+            | '\u0d4a' .. '\u0d4e'
+            | '\u0d54' .. '\u0d57'
+            | '\u0d5f' .. '\u0d63'
             | '\u0d66' .. '\u0d6f'
             | '\u0d7a' .. '\u0d7f'
-            | '\u0d82' .. '\u0d83'
+            | '\u0d81' .. '\u0d83'
             | '\u0d85' .. '\u0d96'
             | '\u0d9a' .. '\u0db1'
             | '\u0db3' .. '\u0dbb'
             | '\u0dbd'
             | '\u0dc0' .. '\u0dc6'
             | '\u0dca'
-            | '\u0dcf' .. '\u0dd1'
-            | '\u0dd2' .. '\u0dd4'
+            | '\u0dcf' .. '\u0dd4'
             | '\u0dd6'
             | '\u0dd8' .. '\u0ddf'
+            | '\u0de6' .. '\u0def'
             | '\u0df2' .. '\u0df3'
-            | '\u0e01' .. '\u0e30'
-            | '\u0e31'
-            | '\u0e32' .. '\u0e33'
-            | '\u0e34' .. '\u0e3a'
-            | '\u0e40' .. '\u0e45'
-            | '\u0e46'
-            | '\u0e47' .. '\u0e4e'
+            | '\u0e01' .. '\u0e3a'
+            | '\u0e40' .. '\u0e4e'
             | '\u0e50' .. '\u0e59'
             | '\u0e81' .. '\u0e82'
             | '\u0e84'
-            | '\u0e87' .. '\u0e88'
-            | '\u0e8a'
-            | '\u0e8d'
-            | '\u0e94' .. '\u0e97'
-            | '\u0e99' .. '\u0e9f'
-            | '\u0ea1' .. '\u0ea3'
+            | '\u0e86' .. '\u0e8a'
+            | '\u0e8c' .. '\u0ea3'
             | '\u0ea5'
-            | '\u0ea7'
-            | '\u0eaa' .. '\u0eab'
-            | '\u0ead' .. '\u0eb0'
-            | '\u0eb1'
-            | '\u0eb2' .. '\u0eb3'
-            | '\u0eb4' .. '\u0eb9'
-            | '\u0ebb' .. '\u0ebc'
-            | '\u0ebd'
+            | '\u0ea7' .. '\u0ebd'
             | '\u0ec0' .. '\u0ec4'
             | '\u0ec6'
             | '\u0ec8' .. '\u0ecd'
             | '\u0ed0' .. '\u0ed9'
-            | '\u0edc' .. '\u0edd'
+            | '\u0edc' .. '\u0edf'
             | '\u0f00'
             | '\u0f18' .. '\u0f19'
             | '\u0f20' .. '\u0f29'
             | '\u0f35'
             | '\u0f37'
             | '\u0f39'
-            | '\u0f3e' .. '\u0f3f'
-            | '\u0f40' .. '\u0f47'
+            | '\u0f3e' .. '\u0f47'
             | '\u0f49' .. '\u0f6c'
-            | '\u0f71' .. '\u0f7e'
-            | '\u0f7f'
-            | '\u0f80' .. '\u0f84'
-            | '\u0f86' .. '\u0f87'
-            | '\u0f88' .. '\u0f8c'
-            | '\u0f8d' .. '\u0f97'
+            | '\u0f71' .. '\u0f84'
+            | '\u0f86' .. '\u0f97'
             | '\u0f99' .. '\u0fbc'
             | '\u0fc6'
-            | '\u1000' .. '\u102a'
-            | '\u102b' .. '\u102c'
-            | '\u102d' .. '\u1030'
-            | '\u1031'
-            | '\u1032' .. '\u1037'
-            | '\u1038'
-            | '\u1039' .. '\u103a'
-            | '\u103b' .. '\u103c'
-            | '\u103d' .. '\u103e'
-            | '\u103f'
-            | '\u1040' .. '\u1049'
-            | '\u1050' .. '\u1055'
-            | '\u1056' .. '\u1057'
-            | '\u1058' .. '\u1059'
-            | '\u105a' .. '\u105d'
-            | '\u105e' .. '\u1060'
-            | '\u1061'
-            | '\u1062' .. '\u1064'
-            | '\u1065' .. '\u1066'
-            | '\u1067' .. '\u106d'
-            | '\u106e' .. '\u1070'
-            | '\u1071' .. '\u1074'
-            | '\u1075' .. '\u1081'
-            | '\u1082'
-            | '\u1083' .. '\u1084'
-            | '\u1085' .. '\u1086'
-            | '\u1087' .. '\u108c'
-            | '\u108d'
-            | '\u108e'
-            | '\u108f'
-            | '\u1090' .. '\u1099'
-            | '\u109a' .. '\u109c'
-            | '\u109d'
+            | '\u1000' .. '\u1049'
+            | '\u1050' .. '\u109d'
             | '\u10a0' .. '\u10c5'
+            | '\u10c7'
+            | '\u10cd'
             | '\u10d0' .. '\u10fa'
-            | '\u10fc'
-            | '\u1100' .. '\u1248'
+            | '\u10fc' .. '\u1248'
             | '\u124a' .. '\u124d'
             | '\u1250' .. '\u1256'
             | '\u1258'
@@ -3697,139 +4097,57 @@ mod derived_property {
             | '\u135d' .. '\u135f'
             | '\u1369' .. '\u1371'
             | '\u1380' .. '\u138f'
-            | '\u13a0' .. '\u13f4'
+            | '\u13a0' .. '\u13f5'
+            | '\u13f8' .. '\u13fd'
             | '\u1401' .. '\u166c'
             | '\u166f' .. '\u167f'
             | '\u1681' .. '\u169a'
             | '\u16a0' .. '\u16ea'
-            | '\u16ee' .. '\u16f0'
-            | '\u1700' .. '\u170c'
-            | '\u170e' .. '\u1711'
-            | '\u1712' .. '\u1714'
-            | '\u1720' .. '\u1731'
-            | '\u1732' .. '\u1734'
-            | '\u1740' .. '\u1751'
-            | '\u1752' .. '\u1753'
+            | '\u16ee' .. '\u16f8'
+            | '\u1700' .. '\u1715'
+            | '\u171f' .. '\u1734'
+            | '\u1740' .. '\u1753'
             | '\u1760' .. '\u176c'
             | '\u176e' .. '\u1770'
             | '\u1772' .. '\u1773'
-            | '\u1780' .. '\u17b3'
-            | '\u17b6'
-            | '\u17b7' .. '\u17bd'
-            | '\u17be' .. '\u17c5'
-            | '\u17c6'
-            | '\u17c7' .. '\u17c8'
-            | '\u17c9' .. '\u17d3'
+            | '\u1780' .. '\u17d3'
             | '\u17d7'
-            | '\u17dc'
-            | '\u17dd'
+            | '\u17dc' .. '\u17dd'
             | '\u17e0' .. '\u17e9'
             | '\u180b' .. '\u180d'
-            | '\u1810' .. '\u1819'
-            | '\u1820' .. '\u1842'
-            | '\u1843'
-            | '\u1844' .. '\u1877'
-            | '\u1880' .. '\u18a8'
-            | '\u18a9'
-            | '\u18aa'
+            | '\u180f' .. '\u1819'
+            | '\u1820' .. '\u1878'
+            | '\u1880' .. '\u18aa'
             | '\u18b0' .. '\u18f5'
-            | '\u1900' .. '\u191c'
-            | '\u1920' .. '\u1922'
-            | '\u1923' .. '\u1926'
-            | '\u1927' .. '\u1928'
-            | '\u1929' .. '\u192b'
-            | '\u1930' .. '\u1931'
-            | '\u1932'
-            | '\u1933' .. '\u1938'
-            | '\u1939' .. '\u193b'
-            | '\u1946' .. '\u194f'
-            | '\u1950' .. '\u196d'
+            | '\u1900' .. '\u191e'
+            | '\u1920' .. '\u192b'
+            | '\u1930' .. '\u193b'
+            | '\u1946' .. '\u196d'
             | '\u1970' .. '\u1974'
-            | '\u1980' .. '\u19ab'
-            | '\u19b0' .. '\u19c0'
-            | '\u19c1' .. '\u19c7'
-            | '\u19c8' .. '\u19c9'
-            | '\u19d0' .. '\u19d9'
-            | '\u19da'
-            | '\u1a00' .. '\u1a16'
-            | '\u1a17' .. '\u1a18'
-            | '\u1a19' .. '\u1a1b'
-            | '\u1a20' .. '\u1a54'
-            | '\u1a55'
-            | '\u1a56'
-            | '\u1a57'
-            | '\u1a58' .. '\u1a5e'
-            | '\u1a60'
-            | '\u1a61'
-            | '\u1a62'
-            | '\u1a63' .. '\u1a64'
-            | '\u1a65' .. '\u1a6c'
-            | '\u1a6d' .. '\u1a72'
-            | '\u1a73' .. '\u1a7c'
-            | '\u1a7f'
-            | '\u1a80' .. '\u1a89'
-            | '\u1a90' .. '\u1a99'
-            | '\u1aa7'
-            | '\u1b00' .. '\u1b03'
-            | '\u1b04'
-            | '\u1b05' .. '\u1b33'
-            | '\u1b34'
-            | '\u1b35'
-            | '\u1b36' .. '\u1b3a'
-            | '\u1b3b'
-            | '\u1b3c'
-            | '\u1b3d' .. '\u1b41'
-            | '\u1b42'
-            | '\u1b43' .. '\u1b44'
-            | '\u1b45' .. '\u1b4b'
-            | '\u1b50' .. '\u1b59'
-            | '\u1b6b' .. '\u1b73'
-            | '\u1b80' .. '\u1b81'
-            | '\u1b82'
-            | '\u1b83' .. '\u1ba0'
-            | '\u1ba1'
-            | '\u1ba2' .. '\u1ba5'
-            | '\u1ba6' .. '\u1ba7'
-            | '\u1ba8' .. '\u1ba9'
-            | '\u1baa'
-            | '\u1bae' .. '\u1baf'
-            | '\u1bb0' .. '\u1bb9'
-            | '\u1bc0' .. '\u1be5'
-            | '\u1be6'
-            | '\u1be7'
-            | '\u1be8' .. '\u1be9'
-            | '\u1bea' .. '\u1bec'
-            | '\u1bed'
-            | '\u1bee'
-            | '\u1bef' .. '\u1bf1'
-            | '\u1bf2' .. '\u1bf3'
-            | '\u1c00' .. '\u1c23'
-            | '\u1c24' .. '\u1c2b'
-            | '\u1c2c' .. '\u1c33'
-            | '\u1c34' .. '\u1c35'
-            | '\u1c36' .. '\u1c37'
+            | '\u1980' .. '\u19ab'
+            | '\u19b0' .. '\u19c9'
+            | '\u19d0' .. '\u19da'
+            | '\u1a00' .. '\u1a1b'
+            | '\u1a20' .. '\u1a5e'
+            | '\u1a60' .. '\u1a7c'
+            | '\u1a7f' .. '\u1a89'
+            | '\u1a90' .. '\u1a99'
+            | '\u1aa7'
+            | '\u1ab0' .. '\u1abd'
+            | '\u1abf' .. '\u1ace'
+            | '\u1b00' .. '\u1b4c'
+            | '\u1b50' .. '\u1b59'
+            | '\u1b6b' .. '\u1b73'
+            | '\u1b80' .. '\u1bf3'
+            | '\u1c00' .. '\u1c37'
             | '\u1c40' .. '\u1c49'
-            | '\u1c4d' .. '\u1c4f'
-            | '\u1c50' .. '\u1c59'
-            | '\u1c5a' .. '\u1c77'
-            | '\u1c78' .. '\u1c7d'
+            | '\u1c4d' .. '\u1c7d'
+            | '\u1c80' .. '\u1c88'
+            | '\u1c90' .. '\u1cba'
+            | '\u1cbd' .. '\u1cbf'
             | '\u1cd0' .. '\u1cd2'
-            | '\u1cd4' .. '\u1ce0'
-            | '\u1ce1'
-            | '\u1ce2' .. '\u1ce8'
-            | '\u1ce9' .. '\u1cec'
-            | '\u1ced'
-            | '\u1cee' .. '\u1cf1'
-            | '\u1cf2'
-            | '\u1d00' .. '\u1d2b'
-            | '\u1d2c' .. '\u1d61'
-            | '\u1d62' .. '\u1d77'
-            | '\u1d78'
-            | '\u1d79' .. '\u1d9a'
-            | '\u1d9b' .. '\u1dbf'
-            | '\u1dc0' .. '\u1de6'
-            | '\u1dfc' .. '\u1dff'
-            | '\u1e00' .. '\u1f15'
+            | '\u1cd4' .. '\u1cfa'
+            | '\u1d00' .. '\u1f15'
             | '\u1f18' .. '\u1f1d'
             | '\u1f20' .. '\u1f45'
             | '\u1f48' .. '\u1f4d'
@@ -3860,34 +4178,23 @@ mod derived_property {
             | '\u2107'
             | '\u210a' .. '\u2113'
             | '\u2115'
-            | '\u2118'
-            | '\u2119' .. '\u211d'
+            | '\u2118' .. '\u211d'
             | '\u2124'
             | '\u2126'
             | '\u2128'
-            | '\u212a' .. '\u212d'
-            | '\u212e'
-            | '\u212f' .. '\u2134'
-            | '\u2135' .. '\u2138'
-            | '\u2139'
+            | '\u212a' .. '\u2139'
             | '\u213c' .. '\u213f'
             | '\u2145' .. '\u2149'
             | '\u214e'
-            | '\u2160' .. '\u2182'
-            | '\u2183' .. '\u2184'
-            | '\u2185' .. '\u2188'
-            | '\u2c00' .. '\u2c2e'
-            | '\u2c30' .. '\u2c5e'
-            | '\u2c60' .. '\u2c7c'
-            | '\u2c7d'
-            | '\u2c7e' .. '\u2ce4'
-            | '\u2ceb' .. '\u2cee'
-            | '\u2cef' .. '\u2cf1'
+            | '\u2160' .. '\u2188'
+            | '\u2c00' .. '\u2ce4'
+            | '\u2ceb' .. '\u2cf3'
             | '\u2d00' .. '\u2d25'
-            | '\u2d30' .. '\u2d65'
+            | '\u2d27'
+            | '\u2d2d'
+            | '\u2d30' .. '\u2d67'
             | '\u2d6f'
-            | '\u2d7f'
-            | '\u2d80' .. '\u2d96'
+            | '\u2d7f' .. '\u2d96'
             | '\u2da0' .. '\u2da6'
             | '\u2da8' .. '\u2dae'
             | '\u2db0' .. '\u2db6'
@@ -3897,148 +4204,72 @@ mod derived_property {
             | '\u2dd0' .. '\u2dd6'
             | '\u2dd8' .. '\u2dde'
             | '\u2de0' .. '\u2dff'
-            | '\u3005'
-            | '\u3006'
-            | '\u3007'
-            | '\u3021' .. '\u3029'
-            | '\u302a' .. '\u302f'
+            | '\u3005' .. '\u3007'
+            | '\u3021' .. '\u302f'
             | '\u3031' .. '\u3035'
-            | '\u3038' .. '\u303a'
-            | '\u303b'
-            | '\u303c'
+            | '\u3038' .. '\u303c'
             | '\u3041' .. '\u3096'
             | '\u3099' .. '\u309a'
-            | '\u309d' .. '\u309e'
-            | '\u309f'
+            | '\u309d' .. '\u309f'
             | '\u30a1' .. '\u30fa'
-            | '\u30fc' .. '\u30fe'
-            | '\u30ff'
-            | '\u3105' .. '\u312d'
+            | '\u30fc' .. '\u30ff'
+            | '\u3105' .. '\u312f'
             | '\u3131' .. '\u318e'
-            | '\u31a0' .. '\u31ba'
+            | '\u31a0' .. '\u31bf'
             | '\u31f0' .. '\u31ff'
-            | '\u3400' .. '\u4db5'
-            | '\u4e00' .. '\u9fcb'
-            | '\ua000' .. '\ua014'
-            | '\ua015'
-            | '\ua016' .. '\ua48c'
-            | '\ua4d0' .. '\ua4f7'
-            | '\ua4f8' .. '\ua4fd'
-            | '\ua500' .. '\ua60b'
-            | '\ua60c'
-            | '\ua610' .. '\ua61f'
-            | '\ua620' .. '\ua629'
-            | '\ua62a' .. '\ua62b'
-            | '\ua640' .. '\ua66d'
-            | '\ua66e'
-            | '\ua66f'
-            | '\ua67c' .. '\ua67d'
-            | '\ua67f'
-            | '\ua680' .. '\ua697'
-            | '\ua6a0' .. '\ua6e5'
-            | '\ua6e6' .. '\ua6ef'
-            | '\ua6f0' .. '\ua6f1'
+            | '\u3400' .. '\u4dbf'
+            | '\u4e00' .. '\ua48c'
+            | '\ua4d0' .. '\ua4fd'
+            | '\ua500' .. '\ua60c'
+            | '\ua610' .. '\ua62b'
+            | '\ua640' .. '\ua66f'
+            | '\ua674' .. '\ua67d'
+            | '\ua67f' .. '\ua6f1'
             | '\ua717' .. '\ua71f'
-            | '\ua722' .. '\ua76f'
-            | '\ua770'
-            | '\ua771' .. '\ua787'
-            | '\ua788'
-            | '\ua78b' .. '\ua78e'
-            | '\ua790' .. '\ua791'
-            | '\ua7a0' .. '\ua7a9'
-            | '\ua7fa'
-            | '\ua7fb' .. '\ua801'
-            | '\ua802'
-            | '\ua803' .. '\ua805'
-            | '\ua806'
-            | '\ua807' .. '\ua80a'
-            | '\ua80b'
-            | '\ua80c' .. '\ua822'
-            | '\ua823' .. '\ua824'
-            | '\ua825' .. '\ua826'
-            | '\ua827'
+            | '\ua722' .. '\ua788'
+            | '\ua78b' .. '\ua7ca'
+            | '\ua7d0' .. '\ua7d1'
+            | '\ua7d3'
+            | '\ua7d5' .. '\ua7d9'
+            | '\ua7f2' .. '\ua827'
+            | '\ua82c'
             | '\ua840' .. '\ua873'
-            | '\ua880' .. '\ua881'
-            | '\ua882' .. '\ua8b3'
-            | '\ua8b4' .. '\ua8c3'
-            | '\ua8c4'
+            | '\ua880' .. '\ua8c5'
             | '\ua8d0' .. '\ua8d9'
-            | '\ua8e0' .. '\ua8f1'
-            | '\ua8f2' .. '\ua8f7'
+            | '\ua8e0' .. '\ua8f7'
             | '\ua8fb'
-            | '\ua900' .. '\ua909'
-            | '\ua90a' .. '\ua925'
-            | '\ua926' .. '\ua92d'
-            | '\ua930' .. '\ua946'
-            | '\ua947' .. '\ua951'
-            | '\ua952' .. '\ua953'
+            | '\ua8fd' .. '\ua92d'
+            | '\ua930' .. '\ua953'
             | '\ua960' .. '\ua97c'
-            | '\ua980' .. '\ua982'
-            | '\ua983'
-            | '\ua984' .. '\ua9b2'
-            | '\ua9b3'
-            | '\ua9b4' .. '\ua9b5'
-            | '\ua9b6' .. '\ua9b9'
-            | '\ua9ba' .. '\ua9bb'
-            | '\ua9bc'
-            | '\ua9bd' .. '\ua9c0'
-            | '\ua9cf'
-            | '\ua9d0' .. '\ua9d9'
-            | '\uaa00' .. '\uaa28'
-            | '\uaa29' .. '\uaa2e'
-            | '\uaa2f' .. '\uaa30'
-            | '\uaa31' .. '\uaa32'
-            | '\uaa33' .. '\uaa34'
-            | '\uaa35' .. '\uaa36'
-            | '\uaa40' .. '\uaa42'
-            | '\uaa43'
-            | '\uaa44' .. '\uaa4b'
-            | '\uaa4c'
-            | '\uaa4d'
+            | '\ua980' .. '\ua9c0'
+            | '\ua9cf' .. '\ua9d9'
+            | '\ua9e0' .. '\ua9fe'
+            | '\uaa00' .. '\uaa36'
+            | '\uaa40' .. '\uaa4d'
             | '\uaa50' .. '\uaa59'
-            | '\uaa60' .. '\uaa6f'
-            | '\uaa70'
-            | '\uaa71' .. '\uaa76'
-            | '\uaa7a'
-            | '\uaa7b'
-            | '\uaa80' .. '\uaaaf'
-            | '\uaab0'
-            | '\uaab1'
-            | '\uaab2' .. '\uaab4'
-            | '\uaab5' .. '\uaab6'
-            | '\uaab7' .. '\uaab8'
-            | '\uaab9' .. '\uaabd'
-            | '\uaabe' .. '\uaabf'
-            | '\uaac0'
-            | '\uaac1'
-            | '\uaac2'
-            | '\uaadb' .. '\uaadc'
-            | '\uaadd'
+            | '\uaa60' .. '\uaa76'
+            | '\uaa7a' .. '\uaac2'
+            | '\uaadb' .. '\uaadd'
+            | '\uaae0' .. '\uaaef'
+            | '\uaaf2' .. '\uaaf6'
             | '\uab01' .. '\uab06'
             | '\uab09' .. '\uab0e'
             | '\uab11' .. '\uab16'
             | '\uab20' .. '\uab26'
             | '\uab28' .. '\uab2e'
-            | '\uabc0' .. '\uabe2'
-            | '\uabe3' .. '\uabe4'
-            | '\uabe5'
-            | '\uabe6' .. '\uabe7'
-            | '\uabe8'
-            | '\uabe9' .. '\uabea'
-            | '\uabec'
-            | '\uabed'
+            | '\uab30' .. '\uab5a'
+            | '\uab5c' .. '\uab69'
+            | '\uab70' .. '\uabea'
+            | '\uabec' .. '\uabed'
             | '\uabf0' .. '\uabf9'
             | '\uac00' .. '\ud7a3'
             | '\ud7b0' .. '\ud7c6'
             | '\ud7cb' .. '\ud7fb'
-            | '\uf900' .. '\ufa2d'
-            | '\ufa30' .. '\ufa6d'
+            | '\uf900' .. '\ufa6d'
             | '\ufa70' .. '\ufad9'
             | '\ufb00' .. '\ufb06'
             | '\ufb13' .. '\ufb17'
-            | '\ufb1d'
-            | '\ufb1e'
-            | '\ufb1f' .. '\ufb28'
+            | '\ufb1d' .. '\ufb28'
             | '\ufb2a' .. '\ufb36'
             | '\ufb38' .. '\ufb3c'
             | '\ufb3e'
@@ -4051,7 +4282,7 @@ mod derived_property {
             | '\ufd92' .. '\ufdc7'
             | '\ufdf0' .. '\ufdf9'
             | '\ufe00' .. '\ufe0f'
-            | '\ufe20' .. '\ufe26'
+            | '\ufe20' .. '\ufe2f'
             | '\ufe33' .. '\ufe34'
             | '\ufe4d' .. '\ufe4f'
             | '\ufe71'
@@ -4065,11 +4296,7 @@ mod derived_property {
             | '\uff21' .. '\uff3a'
             | '\uff3f'
             | '\uff41' .. '\uff5a'
-            | '\uff66' .. '\uff6f'
-            | '\uff70'
-            | '\uff71' .. '\uff9d'
-            | '\uff9e' .. '\uff9f'
-            | '\uffa0' .. '\uffbe'
+            | '\uff66' .. '\uffbe'
             | '\uffc2' .. '\uffc7'
             | '\uffca' .. '\uffcf'
             | '\uffd2' .. '\uffd7'
@@ -4085,60 +4312,219 @@ mod derived_property {
             | '\U000101fd'
             | '\U00010280' .. '\U0001029c'
             | '\U000102a0' .. '\U000102d0'
-            | '\U00010300' .. '\U0001031e'
-            | '\U00010330' .. '\U00010340'
-            | '\U00010341'
-            | '\U00010342' .. '\U00010349'
-            | '\U0001034a'
+            | '\U000102e0'
+            | '\U00010300' .. '\U0001031f'
+            | '\U0001032d' .. '\U0001034a'
+            | '\U00010350' .. '\U0001037a'
             | '\U00010380' .. '\U0001039d'
             | '\U000103a0' .. '\U000103c3'
             | '\U000103c8' .. '\U000103cf'
             | '\U000103d1' .. '\U000103d5'
-            | '\U00010400' .. '\U0001044f'
-            | '\U00010450' .. '\U0001049d'
+            | '\U00010400' .. '\U0001049d'
             | '\U000104a0' .. '\U000104a9'
+            | '\U000104b0' .. '\U000104d3'
+            | '\U000104d8' .. '\U000104fb'
+            | '\U00010500' .. '\U00010527'
+            | '\U00010530' .. '\U00010563'
+            | '\U00010570' .. '\U0001057a'
+            | '\U0001057c' .. '\U0001058a'
+            | '\U0001058c' .. '\U00010592'
+            | '\U00010594' .. '\U00010595'
+            | '\U00010597' .. '\U000105a1'
+            | '\U000105a3' .. '\U000105b1'
+            | '\U000105b3' .. '\U000105b9'
+            | '\U000105bb' .. '\U000105bc'
+            | '\U00010600' .. '\U00010736'
+            | '\U00010740' .. '\U00010755'
+            | '\U00010760' .. '\U00010767'
+            | '\U00010780' .. '\U00010785'
+            | '\U00010787' .. '\U000107b0'
+            | '\U000107b2' .. '\U000107ba'
             | '\U00010800' .. '\U00010805'
             | '\U00010808'
             | '\U0001080a' .. '\U00010835'
             | '\U00010837' .. '\U00010838'
             | '\U0001083c'
             | '\U0001083f' .. '\U00010855'
+            | '\U00010860' .. '\U00010876'
+            | '\U00010880' .. '\U0001089e'
+            | '\U000108e0' .. '\U000108f2'
+            | '\U000108f4' .. '\U000108f5'
             | '\U00010900' .. '\U00010915'
             | '\U00010920' .. '\U00010939'
-            | '\U00010a00'
-            | '\U00010a01' .. '\U00010a03'
+            | '\U00010980' .. '\U000109b7'
+            | '\U000109be' .. '\U000109bf'
+            | '\U00010a00' .. '\U00010a03'
             | '\U00010a05' .. '\U00010a06'
-            | '\U00010a0c' .. '\U00010a0f'
-            | '\U00010a10' .. '\U00010a13'
+            | '\U00010a0c' .. '\U00010a13'
             | '\U00010a15' .. '\U00010a17'
-            | '\U00010a19' .. '\U00010a33'
+            | '\U00010a19' .. '\U00010a35'
             | '\U00010a38' .. '\U00010a3a'
             | '\U00010a3f'
             | '\U00010a60' .. '\U00010a7c'
+            | '\U00010a80' .. '\U00010a9c'
+            | '\U00010ac0' .. '\U00010ac7'
+            | '\U00010ac9' .. '\U00010ae6'
             | '\U00010b00' .. '\U00010b35'
             | '\U00010b40' .. '\U00010b55'
             | '\U00010b60' .. '\U00010b72'
+            | '\U00010b80' .. '\U00010b91'
             | '\U00010c00' .. '\U00010c48'
-            | '\U00011000'
-            | '\U00011001'
-            | '\U00011002'
-            | '\U00011003' .. '\U00011037'
-            | '\U00011038' .. '\U00011046'
-            | '\U00011066' .. '\U0001106f'
-            | '\U00011080' .. '\U00011081'
-            | '\U00011082'
-            | '\U00011083' .. '\U000110af'
-            | '\U000110b0' .. '\U000110b2'
-            | '\U000110b3' .. '\U000110b6'
-            | '\U000110b7' .. '\U000110b8'
-            | '\U000110b9' .. '\U000110ba'
-            | '\U00012000' .. '\U0001236e'
-            | '\U00012400' .. '\U00012462'
+            | '\U00010c80' .. '\U00010cb2'
+            | '\U00010cc0' .. '\U00010cf2'
+            | '\U00010d00' .. '\U00010d27'
+            | '\U00010d30' .. '\U00010d39'
+            | '\U00010e80' .. '\U00010ea9'
+            | '\U00010eab' .. '\U00010eac'
+            | '\U00010eb0' .. '\U00010eb1'
+            | '\U00010f00' .. '\U00010f1c'
+            | '\U00010f27'
+            | '\U00010f30' .. '\U00010f50'
+            | '\U00010f70' .. '\U00010f85'
+            | '\U00010fb0' .. '\U00010fc4'
+            | '\U00010fe0' .. '\U00010ff6'
+            | '\U00011000' .. '\U00011046'
+            | '\U00011066' .. '\U00011075'
+            | '\U0001107f' .. '\U000110ba'
+            | '\U000110c2'
+            | '\U000110d0' .. '\U000110e8'
+            | '\U000110f0' .. '\U000110f9'
+            | '\U00011100' .. '\U00011134'
+            | '\U00011136' .. '\U0001113f'
+            | '\U00011144' .. '\U00011147'
+            | '\U00011150' .. '\U00011173'
+            | '\U00011176'
+            | '\U00011180' .. '\U000111c4'
+            | '\U000111c9' .. '\U000111cc'
+            | '\U000111ce' .. '\U000111da'
+            | '\U000111dc'
+            | '\U00011200' .. '\U00011211'
+            | '\U00011213' .. '\U00011237'
+            | '\U0001123e'
+            | '\U00011280' .. '\U00011286'
+            | '\U00011288'
+            | '\U0001128a' .. '\U0001128d'
+            | '\U0001128f' .. '\U0001129d'
+            | '\U0001129f' .. '\U000112a8'
+            | '\U000112b0' .. '\U000112ea'
+            | '\U000112f0' .. '\U000112f9'
+            | '\U00011300' .. '\U00011303'
+            | '\U00011305' .. '\U0001130c'
+            | '\U0001130f' .. '\U00011310'
+            | '\U00011313' .. '\U00011328'
+            | '\U0001132a' .. '\U00011330'
+            | '\U00011332' .. '\U00011333'
+            | '\U00011335' .. '\U00011339'
+            | '\U0001133b' .. '\U00011344'
+            | '\U00011347' .. '\U00011348'
+            | '\U0001134b' .. '\U0001134d'
+            | '\U00011350'
+            | '\U00011357'
+            | '\U0001135d' .. '\U00011363'
+            | '\U00011366' .. '\U0001136c'
+            | '\U00011370' .. '\U00011374'
+            | '\U00011400' .. '\U0001144a'
+            | '\U00011450' .. '\U00011459'
+            | '\U0001145e' .. '\U00011461'
+            | '\U00011480' .. '\U000114c5'
+            | '\U000114c7'
+            | '\U000114d0' .. '\U000114d9'
+            | '\U00011580' .. '\U000115b5'
+            | '\U000115b8' .. '\U000115c0'
+            | '\U000115d8' .. '\U000115dd'
+            | '\U00011600' .. '\U00011640'
+            | '\U00011644'
+            | '\U00011650' .. '\U00011659'
+            | '\U00011680' .. '\U000116b8'
+            | '\U000116c0' .. '\U000116c9'
+            | '\U00011700' .. '\U0001171a'
+            | '\U0001171d' .. '\U0001172b'
+            | '\U00011730' .. '\U00011739'
+            | '\U00011740' .. '\U00011746'
+            | '\U00011800' .. '\U0001183a'
+            | '\U000118a0' .. '\U000118e9'
+            | '\U000118ff' .. '\U00011906'
+            | '\U00011909'
+            | '\U0001190c' .. '\U00011913'
+            | '\U00011915' .. '\U00011916'
+            | '\U00011918' .. '\U00011935'
+            | '\U00011937' .. '\U00011938'
+            | '\U0001193b' .. '\U00011943'
+            | '\U00011950' .. '\U00011959'
+            | '\U000119a0' .. '\U000119a7'
+            | '\U000119aa' .. '\U000119d7'
+            | '\U000119da' .. '\U000119e1'
+            | '\U000119e3' .. '\U000119e4'
+            | '\U00011a00' .. '\U00011a3e'
+            | '\U00011a47'
+            | '\U00011a50' .. '\U00011a99'
+            | '\U00011a9d'
+            | '\U00011ab0' .. '\U00011af8'
+            | '\U00011c00' .. '\U00011c08'
+            | '\U00011c0a' .. '\U00011c36'
+            | '\U00011c38' .. '\U00011c40'
+            | '\U00011c50' .. '\U00011c59'
+            | '\U00011c72' .. '\U00011c8f'
+            | '\U00011c92' .. '\U00011ca7'
+            | '\U00011ca9' .. '\U00011cb6'
+            | '\U00011d00' .. '\U00011d06'
+            | '\U00011d08' .. '\U00011d09'
+            | '\U00011d0b' .. '\U00011d36'
+            | '\U00011d3a'
+            | '\U00011d3c' .. '\U00011d3d'
+            | '\U00011d3f' .. '\U00011d47'
+            | '\U00011d50' .. '\U00011d59'
+            | '\U00011d60' .. '\U00011d65'
+            | '\U00011d67' .. '\U00011d68'
+            | '\U00011d6a' .. '\U00011d8e'
+            | '\U00011d90' .. '\U00011d91'
+            | '\U00011d93' .. '\U00011d98'
+            | '\U00011da0' .. '\U00011da9'
+            | '\U00011ee0' .. '\U00011ef6'
+            | '\U00011fb0'
+            | '\U00012000' .. '\U00012399'
+            | '\U00012400' .. '\U0001246e'
+            | '\U00012480' .. '\U00012543'
+            | '\U00012f90' .. '\U00012ff0'
             | '\U00013000' .. '\U0001342e'
+            | '\U00014400' .. '\U00014646'
             | '\U00016800' .. '\U00016a38'
-            | '\U0001b000' .. '\U0001b001'
-            | '\U0001d165' .. '\U0001d166'
-            | '\U0001d167' .. '\U0001d169'
+            | '\U00016a40' .. '\U00016a5e'
+            | '\U00016a60' .. '\U00016a69'
+            | '\U00016a70' .. '\U00016abe'
+            | '\U00016ac0' .. '\U00016ac9'
+            | '\U00016ad0' .. '\U00016aed'
+            | '\U00016af0' .. '\U00016af4'
+            | '\U00016b00' .. '\U00016b36'
+            | '\U00016b40' .. '\U00016b43'
+            | '\U00016b50' .. '\U00016b59'
+            | '\U00016b63' .. '\U00016b77'
+            | '\U00016b7d' .. '\U00016b8f'
+            | '\U00016e40' .. '\U00016e7f'
+            | '\U00016f00' .. '\U00016f4a'
+            | '\U00016f4f' .. '\U00016f87'
+            | '\U00016f8f' .. '\U00016f9f'
+            | '\U00016fe0' .. '\U00016fe1'
+            | '\U00016fe3' .. '\U00016fe4'
+            | '\U00016ff0' .. '\U00016ff1'
+            | '\U00017000' .. '\U000187f7'
+            | '\U00018800' .. '\U00018cd5'
+            | '\U00018d00' .. '\U00018d08'
+            | '\U0001aff0' .. '\U0001aff3'
+            | '\U0001aff5' .. '\U0001affb'
+            | '\U0001affd' .. '\U0001affe'
+            | '\U0001b000' .. '\U0001b122'
+            | '\U0001b150' .. '\U0001b152'
+            | '\U0001b164' .. '\U0001b167'
+            | '\U0001b170' .. '\U0001b2fb'
+            | '\U0001bc00' .. '\U0001bc6a'
+            | '\U0001bc70' .. '\U0001bc7c'
+            | '\U0001bc80' .. '\U0001bc88'
+            | '\U0001bc90' .. '\U0001bc99'
+            | '\U0001bc9d' .. '\U0001bc9e'
+            | '\U0001cf00' .. '\U0001cf2d'
+            | '\U0001cf30' .. '\U0001cf46'
+            | '\U0001d165' .. '\U0001d169'
             | '\U0001d16d' .. '\U0001d172'
             | '\U0001d17b' .. '\U0001d182'
             | '\U0001d185' .. '\U0001d18b'
@@ -4175,13 +4561,76 @@ mod derived_property {
             | '\U0001d7aa' .. '\U0001d7c2'
             | '\U0001d7c4' .. '\U0001d7cb'
             | '\U0001d7ce' .. '\U0001d7ff'
-            | '\U00020000' .. '\U0002a6d6'
-            | '\U0002a700' .. '\U0002b734'
+            | '\U0001da00' .. '\U0001da36'
+            | '\U0001da3b' .. '\U0001da6c'
+            | '\U0001da75'
+            | '\U0001da84'
+            | '\U0001da9b' .. '\U0001da9f'
+            | '\U0001daa1' .. '\U0001daaf'
+            | '\U0001df00' .. '\U0001df1e'
+            | '\U0001e000' .. '\U0001e006'
+            | '\U0001e008' .. '\U0001e018'
+            | '\U0001e01b' .. '\U0001e021'
+            | '\U0001e023' .. '\U0001e024'
+            | '\U0001e026' .. '\U0001e02a'
+            | '\U0001e100' .. '\U0001e12c'
+            | '\U0001e130' .. '\U0001e13d'
+            | '\U0001e140' .. '\U0001e149'
+            | '\U0001e14e'
+            | '\U0001e290' .. '\U0001e2ae'
+            | '\U0001e2c0' .. '\U0001e2f9'
+            | '\U0001e7e0' .. '\U0001e7e6'
+            | '\U0001e7e8' .. '\U0001e7eb'
+            | '\U0001e7ed' .. '\U0001e7ee'
+            | '\U0001e7f0' .. '\U0001e7fe'
+            | '\U0001e800' .. '\U0001e8c4'
+            | '\U0001e8d0' .. '\U0001e8d6'
+            | '\U0001e900' .. '\U0001e94b'
+            | '\U0001e950' .. '\U0001e959'
+            | '\U0001ee00' .. '\U0001ee03'
+            | '\U0001ee05' .. '\U0001ee1f'
+            | '\U0001ee21' .. '\U0001ee22'
+            | '\U0001ee24'
+            | '\U0001ee27'
+            | '\U0001ee29' .. '\U0001ee32'
+            | '\U0001ee34' .. '\U0001ee37'
+            | '\U0001ee39'
+            | '\U0001ee3b'
+            | '\U0001ee42'
+            | '\U0001ee47'
+            | '\U0001ee49'
+            | '\U0001ee4b'
+            | '\U0001ee4d' .. '\U0001ee4f'
+            | '\U0001ee51' .. '\U0001ee52'
+            | '\U0001ee54'
+            | '\U0001ee57'
+            | '\U0001ee59'
+            | '\U0001ee5b'
+            | '\U0001ee5d'
+            | '\U0001ee5f'
+            | '\U0001ee61' .. '\U0001ee62'
+            | '\U0001ee64'
+            | '\U0001ee67' .. '\U0001ee6a'
+            | '\U0001ee6c' .. '\U0001ee72'
+            | '\U0001ee74' .. '\U0001ee77'
+            | '\U0001ee79' .. '\U0001ee7c'
+            | '\U0001ee7e'
+            | '\U0001ee80' .. '\U0001ee89'
+            | '\U0001ee8b' .. '\U0001ee9b'
+            | '\U0001eea1' .. '\U0001eea3'
+            | '\U0001eea5' .. '\U0001eea9'
+            | '\U0001eeab' .. '\U0001eebb'
+            | '\U0001fbf0' .. '\U0001fbf9'
+            | '\U00020000' .. '\U0002a6df'
+            | '\U0002a700' .. '\U0002b738'
             | '\U0002b740' .. '\U0002b81d'
+            | '\U0002b820' .. '\U0002cea1'
+            | '\U0002ceb0' .. '\U0002ebe0'
             | '\U0002f800' .. '\U0002fa1d'
+            | '\U00030000' .. '\U0003134a'
             | '\U000e0100' .. '\U000e01ef'
-          => true,
-          _ => false
+              => true,
+            _ => false
         };
     }
 
@@ -4194,37 +4643,28 @@ mod derived_property {
             | '\xba'
             | '\xc0' .. '\xd6'
             | '\xd8' .. '\xf6'
-            | '\xf8' .. '\u01ba'
-            | '\u01bb'
-            | '\u01bc' .. '\u01bf'
-            | '\u01c0' .. '\u01c3'
-            | '\u01c4' .. '\u0293'
-            | '\u0294'
-            | '\u0295' .. '\u02af'
-            | '\u02b0' .. '\u02c1'
+            | '\xf8' .. '\u02c1'
             | '\u02c6' .. '\u02d1'
             | '\u02e0' .. '\u02e4'
             | '\u02ec'
             | '\u02ee'
-            | '\u0370' .. '\u0373'
-            | '\u0374'
+            | '\u0370' .. '\u0374'
             | '\u0376' .. '\u0377'
             | '\u037b' .. '\u037d'
+            | '\u037f'
             | '\u0386'
             | '\u0388' .. '\u038a'
             | '\u038c'
             | '\u038e' .. '\u03a1'
             | '\u03a3' .. '\u03f5'
             | '\u03f7' .. '\u0481'
-            | '\u048a' .. '\u0527'
+            | '\u048a' .. '\u052f'
             | '\u0531' .. '\u0556'
             | '\u0559'
-            | '\u0561' .. '\u0587'
+            | '\u0560' .. '\u0588'
             | '\u05d0' .. '\u05ea'
-            | '\u05f0' .. '\u05f2'
-            | '\u0620' .. '\u063f'
-            | '\u0640'
-            | '\u0641' .. '\u064a'
+            | '\u05ef' .. '\u05f2'
+            | '\u0620' .. '\u064a'
             | '\u066e' .. '\u066f'
             | '\u0671' .. '\u06d3'
             | '\u06d5'
@@ -4244,13 +4684,15 @@ mod derived_property {
             | '\u0824'
             | '\u0828'
             | '\u0840' .. '\u0858'
+            | '\u0860' .. '\u086a'
+            | '\u0870' .. '\u0887'
+            | '\u0889' .. '\u088e'
+            | '\u08a0' .. '\u08c9'
             | '\u0904' .. '\u0939'
             | '\u093d'
             | '\u0950'
             | '\u0958' .. '\u0961'
-            | '\u0971'
-            | '\u0972' .. '\u0977'
-            | '\u0979' .. '\u097f'
+            | '\u0971' .. '\u0980'
             | '\u0985' .. '\u098c'
             | '\u098f' .. '\u0990'
             | '\u0993' .. '\u09a8'
@@ -4262,6 +4704,7 @@ mod derived_property {
             | '\u09dc' .. '\u09dd'
             | '\u09df' .. '\u09e1'
             | '\u09f0' .. '\u09f1'
+            | '\u09fc'
             | '\u0a05' .. '\u0a0a'
             | '\u0a0f' .. '\u0a10'
             | '\u0a13' .. '\u0a28'
@@ -4281,6 +4724,7 @@ mod derived_property {
             | '\u0abd'
             | '\u0ad0'
             | '\u0ae0' .. '\u0ae1'
+            | '\u0af9'
             | '\u0b05' .. '\u0b0c'
             | '\u0b0f' .. '\u0b10'
             | '\u0b13' .. '\u0b28'
@@ -4305,26 +4749,28 @@ mod derived_property {
             | '\u0c05' .. '\u0c0c'
             | '\u0c0e' .. '\u0c10'
             | '\u0c12' .. '\u0c28'
-            | '\u0c2a' .. '\u0c33'
-            | '\u0c35' .. '\u0c39'
+            | '\u0c2a' .. '\u0c39'
             | '\u0c3d'
-            | '\u0c58' .. '\u0c59'
+            | '\u0c58' .. '\u0c5a'
+            | '\u0c5d'
             | '\u0c60' .. '\u0c61'
+            | '\u0c80'
             | '\u0c85' .. '\u0c8c'
             | '\u0c8e' .. '\u0c90'
             | '\u0c92' .. '\u0ca8'
             | '\u0caa' .. '\u0cb3'
             | '\u0cb5' .. '\u0cb9'
             | '\u0cbd'
-            | '\u0cde'
+            | '\u0cdd' .. '\u0cde'
             | '\u0ce0' .. '\u0ce1'
             | '\u0cf1' .. '\u0cf2'
-            | '\u0d05' .. '\u0d0c'
+            | '\u0d04' .. '\u0d0c'
             | '\u0d0e' .. '\u0d10'
             | '\u0d12' .. '\u0d3a'
             | '\u0d3d'
             | '\u0d4e'
-            | '\u0d60' .. '\u0d61'
+            | '\u0d54' .. '\u0d56'
+            | '\u0d5f' .. '\u0d61'
             | '\u0d7a' .. '\u0d7f'
             | '\u0d85' .. '\u0d96'
             | '\u0d9a' .. '\u0db1'
@@ -4333,25 +4779,18 @@ mod derived_property {
             | '\u0dc0' .. '\u0dc6'
             | '\u0e01' .. '\u0e30'
             | '\u0e32'
-            | '\u0e40' .. '\u0e45'
-            | '\u0e46'
+            | '\u0e40' .. '\u0e46'
             | '\u0e81' .. '\u0e82'
             | '\u0e84'
-            | '\u0e87' .. '\u0e88'
-            | '\u0e8a'
-            | '\u0e8d'
-            | '\u0e94' .. '\u0e97'
-            | '\u0e99' .. '\u0e9f'
-            | '\u0ea1' .. '\u0ea3'
+            | '\u0e86' .. '\u0e8a'
+            | '\u0e8c' .. '\u0ea3'
             | '\u0ea5'
-            | '\u0ea7'
-            | '\u0eaa' .. '\u0eab'
-            | '\u0ead' .. '\u0eb0'
+            | '\u0ea7' .. '\u0eb0'
             | '\u0eb2'
             | '\u0ebd'
             | '\u0ec0' .. '\u0ec4'
             | '\u0ec6'
-            | '\u0edc' .. '\u0edd'
+            | '\u0edc' .. '\u0edf'
             | '\u0f00'
             | '\u0f40' .. '\u0f47'
             | '\u0f49' .. '\u0f6c'
@@ -4366,9 +4805,10 @@ mod derived_property {
             | '\u1075' .. '\u1081'
             | '\u108e'
             | '\u10a0' .. '\u10c5'
+            | '\u10c7'
+            | '\u10cd'
             | '\u10d0' .. '\u10fa'
-            | '\u10fc'
-            | '\u1100' .. '\u1248'
+            | '\u10fc' .. '\u1248'
             | '\u124a' .. '\u124d'
             | '\u1250' .. '\u1256'
             | '\u1258'
@@ -4385,52 +4825,49 @@ mod derived_property {
             | '\u1312' .. '\u1315'
             | '\u1318' .. '\u135a'
             | '\u1380' .. '\u138f'
-            | '\u13a0' .. '\u13f4'
+            | '\u13a0' .. '\u13f5'
+            | '\u13f8' .. '\u13fd'
             | '\u1401' .. '\u166c'
             | '\u166f' .. '\u167f'
             | '\u1681' .. '\u169a'
             | '\u16a0' .. '\u16ea'
-            | '\u16ee' .. '\u16f0'
-            | '\u1700' .. '\u170c'
-            | '\u170e' .. '\u1711'
-            | '\u1720' .. '\u1731'
+            | '\u16ee' .. '\u16f8'
+            | '\u1700' .. '\u1711'
+            | '\u171f' .. '\u1731'
             | '\u1740' .. '\u1751'
             | '\u1760' .. '\u176c'
             | '\u176e' .. '\u1770'
             | '\u1780' .. '\u17b3'
             | '\u17d7'
             | '\u17dc'
-            | '\u1820' .. '\u1842'
-            | '\u1843'
-            | '\u1844' .. '\u1877'
+            | '\u1820' .. '\u1878'
             | '\u1880' .. '\u18a8'
             | '\u18aa'
             | '\u18b0' .. '\u18f5'
-            | '\u1900' .. '\u191c'
+            | '\u1900' .. '\u191e'
             | '\u1950' .. '\u196d'
             | '\u1970' .. '\u1974'
             | '\u1980' .. '\u19ab'
-            | '\u19c1' .. '\u19c7'
+            | '\u19b0' .. '\u19c9'
             | '\u1a00' .. '\u1a16'
             | '\u1a20' .. '\u1a54'
             | '\u1aa7'
             | '\u1b05' .. '\u1b33'
-            | '\u1b45' .. '\u1b4b'
+            | '\u1b45' .. '\u1b4c'
             | '\u1b83' .. '\u1ba0'
             | '\u1bae' .. '\u1baf'
-            | '\u1bc0' .. '\u1be5'
+            | '\u1bba' .. '\u1be5'
             | '\u1c00' .. '\u1c23'
             | '\u1c4d' .. '\u1c4f'
-            | '\u1c5a' .. '\u1c77'
-            | '\u1c78' .. '\u1c7d'
+            | '\u1c5a' .. '\u1c7d'
+            | '\u1c80' .. '\u1c88'
+            | '\u1c90' .. '\u1cba'
+            | '\u1cbd' .. '\u1cbf'
             | '\u1ce9' .. '\u1cec'
-            | '\u1cee' .. '\u1cf1'
-            | '\u1d00' .. '\u1d2b'
-            | '\u1d2c' .. '\u1d61'
-            | '\u1d62' .. '\u1d77'
-            | '\u1d78'
-            | '\u1d79' .. '\u1d9a'
-            | '\u1d9b' .. '\u1dbf'
+            | '\u1cee' .. '\u1cf3'
+            | '\u1cf5' .. '\u1cf6'
+            | '\u1cfa'
+            | '\u1d00' .. '\u1dbf'
             | '\u1e00' .. '\u1f15'
             | '\u1f18' .. '\u1f1d'
             | '\u1f20' .. '\u1f45'
@@ -4457,30 +4894,22 @@ mod derived_property {
             | '\u2107'
             | '\u210a' .. '\u2113'
             | '\u2115'
-            | '\u2118'
-            | '\u2119' .. '\u211d'
+            | '\u2118' .. '\u211d'
             | '\u2124'
             | '\u2126'
             | '\u2128'
-            | '\u212a' .. '\u212d'
-            | '\u212e'
-            | '\u212f' .. '\u2134'
-            | '\u2135' .. '\u2138'
-            | '\u2139'
+            | '\u212a' .. '\u2139'
             | '\u213c' .. '\u213f'
             | '\u2145' .. '\u2149'
             | '\u214e'
-            | '\u2160' .. '\u2182'
-            | '\u2183' .. '\u2184'
-            | '\u2185' .. '\u2188'
-            | '\u2c00' .. '\u2c2e'
-            | '\u2c30' .. '\u2c5e'
-            | '\u2c60' .. '\u2c7c'
-            | '\u2c7d'
-            | '\u2c7e' .. '\u2ce4'
+            | '\u2160' .. '\u2188'
+            | '\u2c00' .. '\u2ce4'
             | '\u2ceb' .. '\u2cee'
+            | '\u2cf2' .. '\u2cf3'
             | '\u2d00' .. '\u2d25'
-            | '\u2d30' .. '\u2d65'
+            | '\u2d27'
+            | '\u2d2d'
+            | '\u2d30' .. '\u2d67'
             | '\u2d6f'
             | '\u2d80' .. '\u2d96'
             | '\u2da0' .. '\u2da6'
@@ -4491,51 +4920,34 @@ mod derived_property {
             | '\u2dc8' .. '\u2dce'
             | '\u2dd0' .. '\u2dd6'
             | '\u2dd8' .. '\u2dde'
-            | '\u3005'
-            | '\u3006'
-            | '\u3007'
+            | '\u3005' .. '\u3007'
             | '\u3021' .. '\u3029'
             | '\u3031' .. '\u3035'
-            | '\u3038' .. '\u303a'
-            | '\u303b'
-            | '\u303c'
+            | '\u3038' .. '\u303c'
             | '\u3041' .. '\u3096'
-            | '\u309d' .. '\u309e'
-            | '\u309f'
+            | '\u309d' .. '\u309f'
             | '\u30a1' .. '\u30fa'
-            | '\u30fc' .. '\u30fe'
-            | '\u30ff'
-            | '\u3105' .. '\u312d'
+            | '\u30fc' .. '\u30ff'
+            | '\u3105' .. '\u312f'
             | '\u3131' .. '\u318e'
-            | '\u31a0' .. '\u31ba'
+            | '\u31a0' .. '\u31bf'
             | '\u31f0' .. '\u31ff'
-            | '\u3400' .. '\u4db5'
-            | '\u4e00' .. '\u9fcb'
-            | '\ua000' .. '\ua014'
-            | '\ua015'
-            | '\ua016' .. '\ua48c'
-            | '\ua4d0' .. '\ua4f7'
-            | '\ua4f8' .. '\ua4fd'
-            | '\ua500' .. '\ua60b'
-            | '\ua60c'
+            | '\u3400' .. '\u4dbf'
+            | '\u4e00' .. '\ua48c'
+            | '\ua4d0' .. '\ua4fd'
+            | '\ua500' .. '\ua60c'
             | '\ua610' .. '\ua61f'
             | '\ua62a' .. '\ua62b'
-            | '\ua640' .. '\ua66d'
-            | '\ua66e'
-            | '\ua67f'
-            | '\ua680' .. '\ua697'
-            | '\ua6a0' .. '\ua6e5'
-            | '\ua6e6' .. '\ua6ef'
+            | '\ua640' .. '\ua66e'
+            | '\ua67f' .. '\ua69d'
+            | '\ua6a0' .. '\ua6ef'
             | '\ua717' .. '\ua71f'
-            | '\ua722' .. '\ua76f'
-            | '\ua770'
-            | '\ua771' .. '\ua787'
-            | '\ua788'
-            | '\ua78b' .. '\ua78e'
-            | '\ua790' .. '\ua791'
-            | '\ua7a0' .. '\ua7a9'
-            | '\ua7fa'
-            | '\ua7fb' .. '\ua801'
+            | '\ua722' .. '\ua788'
+            | '\ua78b' .. '\ua7ca'
+            | '\ua7d0' .. '\ua7d1'
+            | '\ua7d3'
+            | '\ua7d5' .. '\ua7d9'
+            | '\ua7f2' .. '\ua801'
             | '\ua803' .. '\ua805'
             | '\ua807' .. '\ua80a'
             | '\ua80c' .. '\ua822'
@@ -4543,37 +4955,41 @@ mod derived_property {
             | '\ua882' .. '\ua8b3'
             | '\ua8f2' .. '\ua8f7'
             | '\ua8fb'
+            | '\ua8fd' .. '\ua8fe'
             | '\ua90a' .. '\ua925'
             | '\ua930' .. '\ua946'
             | '\ua960' .. '\ua97c'
             | '\ua984' .. '\ua9b2'
             | '\ua9cf'
+            | '\ua9e0' .. '\ua9e4'
+            | '\ua9e6' .. '\ua9ef'
+            | '\ua9fa' .. '\ua9fe'
             | '\uaa00' .. '\uaa28'
             | '\uaa40' .. '\uaa42'
             | '\uaa44' .. '\uaa4b'
-            | '\uaa60' .. '\uaa6f'
-            | '\uaa70'
-            | '\uaa71' .. '\uaa76'
+            | '\uaa60' .. '\uaa76'
             | '\uaa7a'
-            | '\uaa80' .. '\uaaaf'
+            | '\uaa7e' .. '\uaaaf'
             | '\uaab1'
             | '\uaab5' .. '\uaab6'
             | '\uaab9' .. '\uaabd'
             | '\uaac0'
             | '\uaac2'
-            | '\uaadb' .. '\uaadc'
-            | '\uaadd'
+            | '\uaadb' .. '\uaadd'
+            | '\uaae0' .. '\uaaea'
+            | '\uaaf2' .. '\uaaf4'
             | '\uab01' .. '\uab06'
             | '\uab09' .. '\uab0e'
             | '\uab11' .. '\uab16'
             | '\uab20' .. '\uab26'
             | '\uab28' .. '\uab2e'
-            | '\uabc0' .. '\uabe2'
+            | '\uab30' .. '\uab5a'
+            | '\uab5c' .. '\uab69'
+            | '\uab70' .. '\uabe2'
             | '\uac00' .. '\ud7a3'
             | '\ud7b0' .. '\ud7c6'
             | '\ud7cb' .. '\ud7fb'
-            | '\uf900' .. '\ufa2d'
-            | '\ufa30' .. '\ufa6d'
+            | '\uf900' .. '\ufa6d'
             | '\ufa70' .. '\ufad9'
             | '\ufb00' .. '\ufb06'
             | '\ufb13' .. '\ufb17'
@@ -4599,9 +5015,7 @@ mod derived_property {
             | '\ufe7f' .. '\ufefc'
             | '\uff21' .. '\uff3a'
             | '\uff41' .. '\uff5a'
-            | '\uff66' .. '\uff6f'
-            | '\uff70'
-            | '\uff71' .. '\uff9d'
+            | '\uff66' .. '\uff9d'
             | '\uffa0' .. '\uffbe'
             | '\uffc2' .. '\uffc7'
             | '\uffca' .. '\uffcf'
@@ -4617,41 +5031,183 @@ mod derived_property {
             | '\U00010140' .. '\U00010174'
             | '\U00010280' .. '\U0001029c'
             | '\U000102a0' .. '\U000102d0'
-            | '\U00010300' .. '\U0001031e'
-            | '\U00010330' .. '\U00010340'
-            | '\U00010341'
-            | '\U00010342' .. '\U00010349'
-            | '\U0001034a'
+            | '\U00010300' .. '\U0001031f'
+            | '\U0001032d' .. '\U0001034a'
+            | '\U00010350' .. '\U00010375'
             | '\U00010380' .. '\U0001039d'
             | '\U000103a0' .. '\U000103c3'
             | '\U000103c8' .. '\U000103cf'
             | '\U000103d1' .. '\U000103d5'
-            | '\U00010400' .. '\U0001044f'
-            | '\U00010450' .. '\U0001049d'
+            | '\U00010400' .. '\U0001049d'
+            | '\U000104b0' .. '\U000104d3'
+            | '\U000104d8' .. '\U000104fb'
+            | '\U00010500' .. '\U00010527'
+            | '\U00010530' .. '\U00010563'
+            | '\U00010570' .. '\U0001057a'
+            | '\U0001057c' .. '\U0001058a'
+            | '\U0001058c' .. '\U00010592'
+            | '\U00010594' .. '\U00010595'
+            | '\U00010597' .. '\U000105a1'
+            | '\U000105a3' .. '\U000105b1'
+            | '\U000105b3' .. '\U000105b9'
+            | '\U000105bb' .. '\U000105bc'
+            | '\U00010600' .. '\U00010736'
+            | '\U00010740' .. '\U00010755'
+            | '\U00010760' .. '\U00010767'
+            | '\U00010780' .. '\U00010785'
+            | '\U00010787' .. '\U000107b0'
+            | '\U000107b2' .. '\U000107ba'
             | '\U00010800' .. '\U00010805'
             | '\U00010808'
             | '\U0001080a' .. '\U00010835'
             | '\U00010837' .. '\U00010838'
             | '\U0001083c'
             | '\U0001083f' .. '\U00010855'
+            | '\U00010860' .. '\U00010876'
+            | '\U00010880' .. '\U0001089e'
+            | '\U000108e0' .. '\U000108f2'
+            | '\U000108f4' .. '\U000108f5'
             | '\U00010900' .. '\U00010915'
             | '\U00010920' .. '\U00010939'
+            | '\U00010980' .. '\U000109b7'
+            | '\U000109be' .. '\U000109bf'
             | '\U00010a00'
             | '\U00010a10' .. '\U00010a13'
             | '\U00010a15' .. '\U00010a17'
-            | '\U00010a19' .. '\U00010a33'
+            | '\U00010a19' .. '\U00010a35'
             | '\U00010a60' .. '\U00010a7c'
+            | '\U00010a80' .. '\U00010a9c'
+            | '\U00010ac0' .. '\U00010ac7'
+            | '\U00010ac9' .. '\U00010ae4'
             | '\U00010b00' .. '\U00010b35'
             | '\U00010b40' .. '\U00010b55'
             | '\U00010b60' .. '\U00010b72'
+            | '\U00010b80' .. '\U00010b91'
             | '\U00010c00' .. '\U00010c48'
+            | '\U00010c80' .. '\U00010cb2'
+            | '\U00010cc0' .. '\U00010cf2'
+            | '\U00010d00' .. '\U00010d23'
+            | '\U00010e80' .. '\U00010ea9'
+            | '\U00010eb0' .. '\U00010eb1'
+            | '\U00010f00' .. '\U00010f1c'
+            | '\U00010f27'
+            | '\U00010f30' .. '\U00010f45'
+            | '\U00010f70' .. '\U00010f81'
+            | '\U00010fb0' .. '\U00010fc4'
+            | '\U00010fe0' .. '\U00010ff6'
             | '\U00011003' .. '\U00011037'
+            | '\U00011071' .. '\U00011072'
+            | '\U00011075'
             | '\U00011083' .. '\U000110af'
-            | '\U00012000' .. '\U0001236e'
-            | '\U00012400' .. '\U00012462'
+            | '\U000110d0' .. '\U000110e8'
+            | '\U00011103' .. '\U00011126'
+            | '\U00011144'
+            | '\U00011147'
+            | '\U00011150' .. '\U00011172'
+            | '\U00011176'
+            | '\U00011183' .. '\U000111b2'
+            | '\U000111c1' .. '\U000111c4'
+            | '\U000111da'
+            | '\U000111dc'
+            | '\U00011200' .. '\U00011211'
+            | '\U00011213' .. '\U0001122b'
+            | '\U00011280' .. '\U00011286'
+            | '\U00011288'
+            | '\U0001128a' .. '\U0001128d'
+            | '\U0001128f' .. '\U0001129d'
+            | '\U0001129f' .. '\U000112a8'
+            | '\U000112b0' .. '\U000112de'
+            | '\U00011305' .. '\U0001130c'
+            | '\U0001130f' .. '\U00011310'
+            | '\U00011313' .. '\U00011328'
+            | '\U0001132a' .. '\U00011330'
+            | '\U00011332' .. '\U00011333'
+            | '\U00011335' .. '\U00011339'
+            | '\U0001133d'
+            | '\U00011350'
+            | '\U0001135d' .. '\U00011361'
+            | '\U00011400' .. '\U00011434'
+            | '\U00011447' .. '\U0001144a'
+            | '\U0001145f' .. '\U00011461'
+            | '\U00011480' .. '\U000114af'
+            | '\U000114c4' .. '\U000114c5'
+            | '\U000114c7'
+            | '\U00011580' .. '\U000115ae'
+            | '\U000115d8' .. '\U000115db'
+            | '\U00011600' .. '\U0001162f'
+            | '\U00011644'
+            | '\U00011680' .. '\U000116aa'
+            | '\U000116b8'
+            | '\U00011700' .. '\U0001171a'
+            | '\U00011740' .. '\U00011746'
+            | '\U00011800' .. '\U0001182b'
+            | '\U000118a0' .. '\U000118df'
+            | '\U000118ff' .. '\U00011906'
+            | '\U00011909'
+            | '\U0001190c' .. '\U00011913'
+            | '\U00011915' .. '\U00011916'
+            | '\U00011918' .. '\U0001192f'
+            | '\U0001193f'
+            | '\U00011941'
+            | '\U000119a0' .. '\U000119a7'
+            | '\U000119aa' .. '\U000119d0'
+            | '\U000119e1'
+            | '\U000119e3'
+            | '\U00011a00'
+            | '\U00011a0b' .. '\U00011a32'
+            | '\U00011a3a'
+            | '\U00011a50'
+            | '\U00011a5c' .. '\U00011a89'
+            | '\U00011a9d'
+            | '\U00011ab0' .. '\U00011af8'
+            | '\U00011c00' .. '\U00011c08'
+            | '\U00011c0a' .. '\U00011c2e'
+            | '\U00011c40'
+            | '\U00011c72' .. '\U00011c8f'
+            | '\U00011d00' .. '\U00011d06'
+            | '\U00011d08' .. '\U00011d09'
+            | '\U00011d0b' .. '\U00011d30'
+            | '\U00011d46'
+            | '\U00011d60' .. '\U00011d65'
+            | '\U00011d67' .. '\U00011d68'
+            | '\U00011d6a' .. '\U00011d89'
+            | '\U00011d98'
+            | '\U00011ee0' .. '\U00011ef2'
+            | '\U00011fb0'
+            | '\U00012000' .. '\U00012399'
+            | '\U00012400' .. '\U0001246e'
+            | '\U00012480' .. '\U00012543'
+            | '\U00012f90' .. '\U00012ff0'
             | '\U00013000' .. '\U0001342e'
+            | '\U00014400' .. '\U00014646'
             | '\U00016800' .. '\U00016a38'
-            | '\U0001b000' .. '\U0001b001'
+            | '\U00016a40' .. '\U00016a5e'
+            | '\U00016a70' .. '\U00016abe'
+            | '\U00016ad0' .. '\U00016aed'
+            | '\U00016b00' .. '\U00016b2f'
+            | '\U00016b40' .. '\U00016b43'
+            | '\U00016b63' .. '\U00016b77'
+            | '\U00016b7d' .. '\U00016b8f'
+            | '\U00016e40' .. '\U00016e7f'
+            | '\U00016f00' .. '\U00016f4a'
+            | '\U00016f50'
+            | '\U00016f93' .. '\U00016f9f'
+            | '\U00016fe0' .. '\U00016fe1'
+            | '\U00016fe3'
+            | '\U00017000' .. '\U000187f7'
+            | '\U00018800' .. '\U00018cd5'
+            | '\U00018d00' .. '\U00018d08'
+            | '\U0001aff0' .. '\U0001aff3'
+            | '\U0001aff5' .. '\U0001affb'
+            | '\U0001affd' .. '\U0001affe'
+            | '\U0001b000' .. '\U0001b122'
+            | '\U0001b150' .. '\U0001b152'
+            | '\U0001b164' .. '\U0001b167'
+            | '\U0001b170' .. '\U0001b2fb'
+            | '\U0001bc00' .. '\U0001bc6a'
+            | '\U0001bc70' .. '\U0001bc7c'
+            | '\U0001bc80' .. '\U0001bc88'
+            | '\U0001bc90' .. '\U0001bc99'
             | '\U0001d400' .. '\U0001d454'
             | '\U0001d456' .. '\U0001d49c'
             | '\U0001d49e' .. '\U0001d49f'
@@ -4682,13 +5238,9007 @@ mod derived_property {
             | '\U0001d78a' .. '\U0001d7a8'
             | '\U0001d7aa' .. '\U0001d7c2'
             | '\U0001d7c4' .. '\U0001d7cb'
-            | '\U00020000' .. '\U0002a6d6'
-            | '\U0002a700' .. '\U0002b734'
+            | '\U0001df00' .. '\U0001df1e'
+            | '\U0001e100' .. '\U0001e12c'
+            | '\U0001e137' .. '\U0001e13d'
+            | '\U0001e14e'
+            | '\U0001e290' .. '\U0001e2ad'
+            | '\U0001e2c0' .. '\U0001e2eb'
+            | '\U0001e7e0' .. '\U0001e7e6'
+            | '\U0001e7e8' .. '\U0001e7eb'
+            | '\U0001e7ed' .. '\U0001e7ee'
+            | '\U0001e7f0' .. '\U0001e7fe'
+            | '\U0001e800' .. '\U0001e8c4'
+            | '\U0001e900' .. '\U0001e943'
+            | '\U0001e94b'
+            | '\U0001ee00' .. '\U0001ee03'
+            | '\U0001ee05' .. '\U0001ee1f'
+            | '\U0001ee21' .. '\U0001ee22'
+            | '\U0001ee24'
+            | '\U0001ee27'
+            | '\U0001ee29' .. '\U0001ee32'
+            | '\U0001ee34' .. '\U0001ee37'
+            | '\U0001ee39'
+            | '\U0001ee3b'
+            | '\U0001ee42'
+            | '\U0001ee47'
+            | '\U0001ee49'
+            | '\U0001ee4b'
+            | '\U0001ee4d' .. '\U0001ee4f'
+            | '\U0001ee51' .. '\U0001ee52'
+            | '\U0001ee54'
+            | '\U0001ee57'
+            | '\U0001ee59'
+            | '\U0001ee5b'
+            | '\U0001ee5d'
+            | '\U0001ee5f'
+            | '\U0001ee61' .. '\U0001ee62'
+            | '\U0001ee64'
+            | '\U0001ee67' .. '\U0001ee6a'
+            | '\U0001ee6c' .. '\U0001ee72'
+            | '\U0001ee74' .. '\U0001ee77'
+            | '\U0001ee79' .. '\U0001ee7c'
+            | '\U0001ee7e'
+            | '\U0001ee80' .. '\U0001ee89'
+            | '\U0001ee8b' .. '\U0001ee9b'
+            | '\U0001eea1' .. '\U0001eea3'
+            | '\U0001eea5' .. '\U0001eea9'
+            | '\U0001eeab' .. '\U0001eebb'
+            | '\U00020000' .. '\U0002a6df'
+            | '\U0002a700' .. '\U0002b738'
             | '\U0002b740' .. '\U0002b81d'
+            | '\U0002b820' .. '\U0002cea1'
+            | '\U0002ceb0' .. '\U0002ebe0'
             | '\U0002f800' .. '\U0002fa1d'
-          => true,
-          _ => false
+            | '\U00030000' .. '\U0003134a'
+              => true,
+            _ => false
         };
     }
 
 }
+
+pub mod conversions {
+
+    /// The simple lowercase mapping of a char
+    pub pure fn to_lower(c: char) -> char {
+        match c {
+          '\x41' .. '\x5a' => ((c as u32) + 32) as char,
+          '\xc0' .. '\xd6' => ((c as u32) + 32) as char,
+          '\xd8' .. '\xde' => ((c as u32) + 32) as char,
+          '\u0100' .. '\u012e' if (c as u32) % 2 == 0 =>
+            ((c as u32) + 1) as char,
+          '\u0130' => '\x69',
+          '\u0132' .. '\u0136' if (c as u32) % 2 == 0 =>
+            ((c as u32) + 1) as char,
+          '\u0139' .. '\u0147' if (c as u32) % 2 == 1 =>
+            ((c as u32) + 1) as char,
+          '\u014a' .. '\u0176' if (c as u32) % 2 == 0 =>
+            ((c as u32) + 1) as char,
+          '\u0178' => '\xff',
+          '\u0179' .. '\u017d' if (c as u32) % 2 == 1 =>
+            ((c as u32) + 1) as char,
+          '\u0181' => '\u0253',
+          '\u0182' => '\u0183',
+          '\u0184' => '\u0185',
+          '\u0186' => '\u0254',
+          '\u0187' => '\u0188',
+          '\u0189' .. '\u018a' => ((c as u32) + 205) as char,
+          '\u018b' => '\u018c',
+          '\u018e' => '\u01dd',
+          '\u018f' => '\u0259',
+          '\u0190' => '\u025b',
+          '\u0191' => '\u0192',
+          '\u0193' => '\u0260',
+          '\u0194' => '\u0263',
+          '\u0196' => '\u0269',
+          '\u0197' => '\u0268',
+          '\u0198' => '\u0199',
+          '\u019c' => '\u026f',
+          '\u019d' => '\u0272',
+          '\u019f' => '\u0275',
+          '\u01a0' .. '\u01a4' if (c as u32) % 2 == 0 =>
+            ((c as u32) + 1) as char,
+          '\u01a6' => '\u0280',
+          '\u01a7' => '\u01a8',
+          '\u01a9' => '\u0283',
+          '\u01ac' => '\u01ad',
+          '\u01ae' => '\u0288',
+          '\u01af' => '\u01b0',
+          '\u01b1' .. '\u01b2' => ((c as u32) + 217) as char,
+          '\u01b3' => '\u01b4',
+          '\u01b5' => '\u01b6',
+          '\u01b7' => '\u0292',
+          '\u01b8' => '\u01b9',
+          '\u01bc' => '\u01bd',
+          '\u01c4' => '\u01c6',
+          '\u01c5' => '\u01c6',
+          '\u01c7' => '\u01c9',
+          '\u01c8' => '\u01c9',
+          '\u01ca' => '\u01cc',
+          '\u01cb' .. '\u01db' if (c as u32) % 2 == 1 =>
+            ((c as u32) + 1) as char,
+          '\u01de' .. '\u01ee' if (c as u32) % 2 == 0 =>
+            ((c as u32) + 1) as char,
+          '\u01f1' => '\u01f3',
+          '\u01f2' => '\u01f3',
+          '\u01f4' => '\u01f5',
+          '\u01f6' => '\u0195',
+          '\u01f7' => '\u01bf',
+          '\u01f8' .. '\u021e' if (c as u32) % 2 == 0 =>
+            ((c as u32) + 1) as char,
+          '\u0220' => '\u019e',
+          '\u0222' .. '\u0232' if (c as u32) % 2 == 0 =>
+            ((c as u32) + 1) as char,
+          '\u023a' => '\u2c65',
+          '\u023b' => '\u023c',
+          '\u023d' => '\u019a',
+          '\u023e' => '\u2c66',
+          '\u0241' => '\u0242',
+          '\u0243' => '\u0180',
+          '\u0244' => '\u0289',
+          '\u0245' => '\u028c',
+          '\u0246' .. '\u024e' if (c as u32) % 2 == 0 =>
+            ((c as u32) + 1) as char,
+          '\u0370' => '\u0371',
+          '\u0372' => '\u0373',
+          '\u0376' => '\u0377',
+          '\u037f' => '\u03f3',
+          '\u0386' => '\u03ac',
+          '\u0388' .. '\u038a' => ((c as u32) + 37) as char,
+          '\u038c' => '\u03cc',
+          '\u038e' .. '\u038f' => ((c as u32) + 63) as char,
+          '\u0391' .. '\u03a1' => ((c as u32) + 32) as char,
+          '\u03a3' .. '\u03ab' => ((c as u32) + 32) as char,
+          '\u03cf' => '\u03d7',
+          '\u03d8' .. '\u03ee' if (c as u32) % 2 == 0 =>
+            ((c as u32) + 1) as char,
+          '\u03f4' => '\u03b8',
+          '\u03f7' => '\u03f8',
+          '\u03f9' => '\u03f2',
+          '\u03fa' => '\u03fb',
+          '\u03fd' .. '\u03ff' => ((c as u32) - 130) as char,
+          '\u0400' .. '\u040f' => ((c as u32) + 80) as char,
+          '\u0410' .. '\u042f' => ((c as u32) + 32) as char,
+          '\u0460' .. '\u0480' if (c as u32) % 2 == 0 =>
+            ((c as u32) + 1) as char,
+          '\u048a' .. '\u04be' if (c as u32) % 2 == 0 =>
+            ((c as u32) + 1) as char,
+          '\u04c0' => '\u04cf',
+          '\u04c1' .. '\u04cd' if (c as u32) % 2 == 1 =>
+            ((c as u32) + 1) as char,
+          '\u04d0' .. '\u052e' if (c as u32) % 2 == 0 =>
+            ((c as u32) + 1) as char,
+          '\u0531' .. '\u0556' => ((c as u32) + 48) as char,
+          '\u10a0' .. '\u10c5' => ((c as u32) + 7264) as char,
+          '\u10c7' => '\u2d27',
+          '\u10cd' => '\u2d2d',
+          '\u13a0' .. '\u13ef' => ((c as u32) + 38864) as char,
+          '\u13f0' .. '\u13f5' => ((c as u32) + 8) as char,
+          '\u1c90' .. '\u1cba' => ((c as u32) - 3008) as char,
+          '\u1cbd' .. '\u1cbf' => ((c as u32) - 3008) as char,
+          '\u1e00' .. '\u1e94' if (c as u32) % 2 == 0 =>
+            ((c as u32) + 1) as char,
+          '\u1e9e' => '\xdf',
+          '\u1ea0' .. '\u1efe' if (c as u32) % 2 == 0 =>
+            ((c as u32) + 1) as char,
+          '\u1f08' .. '\u1f0f' => ((c as u32) - 8) as char,
+          '\u1f18' .. '\u1f1d' => ((c as u32) - 8) as char,
+          '\u1f28' .. '\u1f2f' => ((c as u32) - 8) as char,
+          '\u1f38' .. '\u1f3f' => ((c as u32) - 8) as char,
+          '\u1f48' .. '\u1f4d' => ((c as u32) - 8) as char,
+          '\u1f59' .. '\u1f5f' if (c as u32) % 2 == 1 =>
+            ((c as u32) - 8) as char,
+          '\u1f68' .. '\u1f6f' => ((c as u32) - 8) as char,
+          '\u1f88' .. '\u1f8f' => ((c as u32) - 8) as char,
+          '\u1f98' .. '\u1f9f' => ((c as u32) - 8) as char,
+          '\u1fa8' .. '\u1faf' => ((c as u32) - 8) as char,
+          '\u1fb8' .. '\u1fb9' => ((c as u32) - 8) as char,
+          '\u1fba' .. '\u1fbb' => ((c as u32) - 74) as char,
+          '\u1fbc' => '\u1fb3',
+          '\u1fc8' .. '\u1fcb' => ((c as u32) - 86) as char,
+          '\u1fcc' => '\u1fc3',
+          '\u1fd8' .. '\u1fd9' => ((c as u32) - 8) as char,
+          '\u1fda' .. '\u1fdb' => ((c as u32) - 100) as char,
+          '\u1fe8' .. '\u1fe9' => ((c as u32) - 8) as char,
+          '\u1fea' .. '\u1feb' => ((c as u32) - 112) as char,
+          '\u1fec' => '\u1fe5',
+          '\u1ff8' .. '\u1ff9' => ((c as u32) - 128) as char,
+          '\u1ffa' .. '\u1ffb' => ((c as u32) - 126) as char,
+          '\u1ffc' => '\u1ff3',
+          '\u2126' => '\u03c9',
+          '\u212a' => '\x6b',
+          '\u212b' => '\xe5',
+          '\u2132' => '\u214e',
+          '\u2160' .. '\u216f' => ((c as u32) + 16) as char,
+          '\u2183' => '\u2184',
+          '\u24b6' .. '\u24cf' => ((c as u32) + 26) as char,
+          '\u2c00' .. '\u2c2f' => ((c as u32) + 48) as char,
+          '\u2c60' => '\u2c61',
+          '\u2c62' => '\u026b',
+          '\u2c63' => '\u1d7d',
+          '\u2c64' => '\u027d',
+          '\u2c67' .. '\u2c6b' if (c as u32) % 2 == 1 =>
+            ((c as u32) + 1) as char,
+          '\u2c6d' => '\u0251',
+          '\u2c6e' => '\u0271',
+          '\u2c6f' => '\u0250',
+          '\u2c70' => '\u0252',
+          '\u2c72' => '\u2c73',
+          '\u2c75' => '\u2c76',
+          '\u2c7e' .. '\u2c7f' => ((c as u32) - 10815) as char,
+          '\u2c80' .. '\u2ce2' if (c as u32) % 2 == 0 =>
+            ((c as u32) + 1) as char,
+          '\u2ceb' => '\u2cec',
+          '\u2ced' => '\u2cee',
+          '\u2cf2' => '\u2cf3',
+          '\ua640' .. '\ua66c' if (c as u32) % 2 == 0 =>
+            ((c as u32) + 1) as char,
+          '\ua680' .. '\ua69a' if (c as u32) % 2 == 0 =>
+            ((c as u32) + 1) as char,
+          '\ua722' .. '\ua72e' if (c as u32) % 2 == 0 =>
+            ((c as u32) + 1) as char,
+          '\ua732' .. '\ua76e' if (c as u32) % 2 == 0 =>
+            ((c as u32) + 1) as char,
+          '\ua779' => '\ua77a',
+          '\ua77b' => '\ua77c',
+          '\ua77d' => '\u1d79',
+          '\ua77e' .. '\ua786' if (c as u32) % 2 == 0 =>
+            ((c as u32) + 1) as char,
+          '\ua78b' => '\ua78c',
+          '\ua78d' => '\u0265',
+          '\ua790' => '\ua791',
+          '\ua792' => '\ua793',
+          '\ua796' .. '\ua7a8' if (c as u32) % 2 == 0 =>
+            ((c as u32) + 1) as char,
+          '\ua7aa' => '\u0266',
+          '\ua7ab' => '\u025c',
+          '\ua7ac' => '\u0261',
+          '\ua7ad' => '\u026c',
+          '\ua7ae' => '\u026a',
+          '\ua7b0' => '\u029e',
+          '\ua7b1' => '\u0287',
+          '\ua7b2' => '\u029d',
+          '\ua7b3' => '\uab53',
+          '\ua7b4' .. '\ua7c2' if (c as u32) % 2 == 0 =>
+            ((c as u32) + 1) as char,
+          '\ua7c4' => '\ua794',
+          '\ua7c5' => '\u0282',
+          '\ua7c6' => '\u1d8e',
+          '\ua7c7' => '\ua7c8',
+          '\ua7c9' => '\ua7ca',
+          '\ua7d0' => '\ua7d1',
+          '\ua7d6' => '\ua7d7',
+          '\ua7d8' => '\ua7d9',
+          '\ua7f5' => '\ua7f6',
+          '\uff21' .. '\uff3a' => ((c as u32) + 32) as char,
+          '\U00010400' .. '\U00010427' => ((c as u32) + 40) as char,
+          '\U000104b0' .. '\U000104d3' => ((c as u32) + 40) as char,
+          '\U00010570' .. '\U0001057a' => ((c as u32) + 39) as char,
+          '\U0001057c' .. '\U0001058a' => ((c as u32) + 39) as char,
+          '\U0001058c' .. '\U00010592' => ((c as u32) + 39) as char,
+          '\U00010594' .. '\U00010595' => ((c as u32) + 39) as char,
+          '\U00010c80' .. '\U00010cb2' => ((c as u32) + 64) as char,
+          '\U000118a0' .. '\U000118bf' => ((c as u32) + 32) as char,
+          '\U00016e40' .. '\U00016e5f' => ((c as u32) + 32) as char,
+          '\U0001e900' .. '\U0001e921' => ((c as u32) + 34) as char,
+          _ => c
+        }
+    }
+
+    /// The simple uppercase mapping of a char
+    pub pure fn to_upper(c: char) -> char {
+        match c {
+          '\x61' .. '\x7a' => ((c as u32) - 32) as char,
+          '\xb5' => '\u039c',
+          '\xe0' .. '\xf6' => ((c as u32) - 32) as char,
+          '\xf8' .. '\xfe' => ((c as u32) - 32) as char,
+          '\xff' => '\u0178',
+          '\u0101' .. '\u012f' if (c as u32) % 2 == 1 =>
+            ((c as u32) - 1) as char,
+          '\u0131' => '\x49',
+          '\u0133' .. '\u0137' if (c as u32) % 2 == 1 =>
+            ((c as u32) - 1) as char,
+          '\u013a' .. '\u0148' if (c as u32) % 2 == 0 =>
+            ((c as u32) - 1) as char,
+          '\u014b' .. '\u0177' if (c as u32) % 2 == 1 =>
+            ((c as u32) - 1) as char,
+          '\u017a' .. '\u017e' if (c as u32) % 2 == 0 =>
+            ((c as u32) - 1) as char,
+          '\u017f' => '\x53',
+          '\u0180' => '\u0243',
+          '\u0183' => '\u0182',
+          '\u0185' => '\u0184',
+          '\u0188' => '\u0187',
+          '\u018c' => '\u018b',
+          '\u0192' => '\u0191',
+          '\u0195' => '\u01f6',
+          '\u0199' => '\u0198',
+          '\u019a' => '\u023d',
+          '\u019e' => '\u0220',
+          '\u01a1' .. '\u01a5' if (c as u32) % 2 == 1 =>
+            ((c as u32) - 1) as char,
+          '\u01a8' => '\u01a7',
+          '\u01ad' => '\u01ac',
+          '\u01b0' => '\u01af',
+          '\u01b4' => '\u01b3',
+          '\u01b6' => '\u01b5',
+          '\u01b9' => '\u01b8',
+          '\u01bd' => '\u01bc',
+          '\u01bf' => '\u01f7',
+          '\u01c5' => '\u01c4',
+          '\u01c6' => '\u01c4',
+          '\u01c8' => '\u01c7',
+          '\u01c9' => '\u01c7',
+          '\u01cb' => '\u01ca',
+          '\u01cc' => '\u01ca',
+          '\u01ce' .. '\u01dc' if (c as u32) % 2 == 0 =>
+            ((c as u32) - 1) as char,
+          '\u01dd' => '\u018e',
+          '\u01df' .. '\u01ef' if (c as u32) % 2 == 1 =>
+            ((c as u32) - 1) as char,
+          '\u01f2' => '\u01f1',
+          '\u01f3' => '\u01f1',
+          '\u01f5' => '\u01f4',
+          '\u01f9' .. '\u021f' if (c as u32) % 2 == 1 =>
+            ((c as u32) - 1) as char,
+          '\u0223' .. '\u0233' if (c as u32) % 2 == 1 =>
+            ((c as u32) - 1) as char,
+          '\u023c' => '\u023b',
+          '\u023f' .. '\u0240' => ((c as u32) + 10815) as char,
+          '\u0242' => '\u0241',
+          '\u0247' .. '\u024f' if (c as u32) % 2 == 1 =>
+            ((c as u32) - 1) as char,
+          '\u0250' => '\u2c6f',
+          '\u0251' => '\u2c6d',
+          '\u0252' => '\u2c70',
+          '\u0253' => '\u0181',
+          '\u0254' => '\u0186',
+          '\u0256' .. '\u0257' => ((c as u32) - 205) as char,
+          '\u0259' => '\u018f',
+          '\u025b' => '\u0190',
+          '\u025c' => '\ua7ab',
+          '\u0260' => '\u0193',
+          '\u0261' => '\ua7ac',
+          '\u0263' => '\u0194',
+          '\u0265' => '\ua78d',
+          '\u0266' => '\ua7aa',
+          '\u0268' => '\u0197',
+          '\u0269' => '\u0196',
+          '\u026a' => '\ua7ae',
+          '\u026b' => '\u2c62',
+          '\u026c' => '\ua7ad',
+          '\u026f' => '\u019c',
+          '\u0271' => '\u2c6e',
+          '\u0272' => '\u019d',
+          '\u0275' => '\u019f',
+          '\u027d' => '\u2c64',
+          '\u0280' => '\u01a6',
+          '\u0282' => '\ua7c5',
+          '\u0283' => '\u01a9',
+          '\u0287' => '\ua7b1',
+          '\u0288' => '\u01ae',
+          '\u0289' => '\u0244',
+          '\u028a' .. '\u028b' => ((c as u32) - 217) as char,
+          '\u028c' => '\u0245',
+          '\u0292' => '\u01b7',
+          '\u029d' => '\ua7b2',
+          '\u029e' => '\ua7b0',
+          '\u0345' => '\u0399',
+          '\u0371' => '\u0370',
+          '\u0373' => '\u0372',
+          '\u0377' => '\u0376',
+          '\u037b' .. '\u037d' => ((c as u32) + 130) as char,
+          '\u03ac' => '\u0386',
+          '\u03ad' .. '\u03af' => ((c as u32) - 37) as char,
+          '\u03b1' .. '\u03c1' => ((c as u32) - 32) as char,
+          '\u03c2' => '\u03a3',
+          '\u03c3' .. '\u03cb' => ((c as u32) - 32) as char,
+          '\u03cc' => '\u038c',
+          '\u03cd' .. '\u03ce' => ((c as u32) - 63) as char,
+          '\u03d0' => '\u0392',
+          '\u03d1' => '\u0398',
+          '\u03d5' => '\u03a6',
+          '\u03d6' => '\u03a0',
+          '\u03d7' => '\u03cf',
+          '\u03d9' .. '\u03ef' if (c as u32) % 2 == 1 =>
+            ((c as u32) - 1) as char,
+          '\u03f0' => '\u039a',
+          '\u03f1' => '\u03a1',
+          '\u03f2' => '\u03f9',
+          '\u03f3' => '\u037f',
+          '\u03f5' => '\u0395',
+          '\u03f8' => '\u03f7',
+          '\u03fb' => '\u03fa',
+          '\u0430' .. '\u044f' => ((c as u32) - 32) as char,
+          '\u0450' .. '\u045f' => ((c as u32) - 80) as char,
+          '\u0461' .. '\u0481' if (c as u32) % 2 == 1 =>
+            ((c as u32) - 1) as char,
+          '\u048b' .. '\u04bf' if (c as u32) % 2 == 1 =>
+            ((c as u32) - 1) as char,
+          '\u04c2' .. '\u04ce' if (c as u32) % 2 == 0 =>
+            ((c as u32) - 1) as char,
+          '\u04cf' => '\u04c0',
+          '\u04d1' .. '\u052f' if (c as u32) % 2 == 1 =>
+            ((c as u32) - 1) as char,
+          '\u0561' .. '\u0586' => ((c as u32) - 48) as char,
+          '\u10d0' .. '\u10fa' => ((c as u32) + 3008) as char,
+          '\u10fd' .. '\u10ff' => ((c as u32) + 3008) as char,
+          '\u13f8' .. '\u13fd' => ((c as u32) - 8) as char,
+          '\u1c80' => '\u0412',
+          '\u1c81' => '\u0414',
+          '\u1c82' => '\u041e',
+          '\u1c83' .. '\u1c84' => ((c as u32) - 6242) as char,
+          '\u1c85' => '\u0422',
+          '\u1c86' => '\u042a',
+          '\u1c87' => '\u0462',
+          '\u1c88' => '\ua64a',
+          '\u1d79' => '\ua77d',
+          '\u1d7d' => '\u2c63',
+          '\u1d8e' => '\ua7c6',
+          '\u1e01' .. '\u1e95' if (c as u32) % 2 == 1 =>
+            ((c as u32) - 1) as char,
+          '\u1e9b' => '\u1e60',
+          '\u1ea1' .. '\u1eff' if (c as u32) % 2 == 1 =>
+            ((c as u32) - 1) as char,
+          '\u1f00' .. '\u1f07' => ((c as u32) + 8) as char,
+          '\u1f10' .. '\u1f15' => ((c as u32) + 8) as char,
+          '\u1f20' .. '\u1f27' => ((c as u32) + 8) as char,
+          '\u1f30' .. '\u1f37' => ((c as u32) + 8) as char,
+          '\u1f40' .. '\u1f45' => ((c as u32) + 8) as char,
+          '\u1f51' .. '\u1f57' if (c as u32) % 2 == 1 =>
+            ((c as u32) + 8) as char,
+          '\u1f60' .. '\u1f67' => ((c as u32) + 8) as char,
+          '\u1f70' .. '\u1f71' => ((c as u32) + 74) as char,
+          '\u1f72' .. '\u1f75' => ((c as u32) + 86) as char,
+          '\u1f76' .. '\u1f77' => ((c as u32) + 100) as char,
+          '\u1f78' .. '\u1f79' => ((c as u32) + 128) as char,
+          '\u1f7a' .. '\u1f7b' => ((c as u32) + 112) as char,
+          '\u1f7c' .. '\u1f7d' => ((c as u32) + 126) as char,
+          '\u1f80' .. '\u1f87' => ((c as u32) + 8) as char,
+          '\u1f90' .. '\u1f97' => ((c as u32) + 8) as char,
+          '\u1fa0' .. '\u1fa7' => ((c as u32) + 8) as char,
+          '\u1fb0' .. '\u1fb1' => ((c as u32) + 8) as char,
+          '\u1fb3' => '\u1fbc',
+          '\u1fbe' => '\u0399',
+          '\u1fc3' => '\u1fcc',
+          '\u1fd0' .. '\u1fd1' => ((c as u32) + 8) as char,
+          '\u1fe0' .. '\u1fe1' => ((c as u32) + 8) as char,
+          '\u1fe5' => '\u1fec',
+          '\u1ff3' => '\u1ffc',
+          '\u214e' => '\u2132',
+          '\u2170' .. '\u217f' => ((c as u32) - 16) as char,
+          '\u2184' => '\u2183',
+          '\u24d0' .. '\u24e9' => ((c as u32) - 26) as char,
+          '\u2c30' .. '\u2c5f' => ((c as u32) - 48) as char,
+          '\u2c61' => '\u2c60',
+          '\u2c65' => '\u023a',
+          '\u2c66' => '\u023e',
+          '\u2c68' .. '\u2c6c' if (c as u32) % 2 == 0 =>
+            ((c as u32) - 1) as char,
+          '\u2c73' => '\u2c72',
+          '\u2c76' => '\u2c75',
+          '\u2c81' .. '\u2ce3' if (c as u32) % 2 == 1 =>
+            ((c as u32) - 1) as char,
+          '\u2cec' => '\u2ceb',
+          '\u2cee' => '\u2ced',
+          '\u2cf3' => '\u2cf2',
+          '\u2d00' .. '\u2d25' => ((c as u32) - 7264) as char,
+          '\u2d27' => '\u10c7',
+          '\u2d2d' => '\u10cd',
+          '\ua641' .. '\ua66d' if (c as u32) % 2 == 1 =>
+            ((c as u32) - 1) as char,
+          '\ua681' .. '\ua69b' if (c as u32) % 2 == 1 =>
+            ((c as u32) - 1) as char,
+          '\ua723' .. '\ua72f' if (c as u32) % 2 == 1 =>
+            ((c as u32) - 1) as char,
+          '\ua733' .. '\ua76f' if (c as u32) % 2 == 1 =>
+            ((c as u32) - 1) as char,
+          '\ua77a' => '\ua779',
+          '\ua77c' => '\ua77b',
+          '\ua77f' .. '\ua787' if (c as u32) % 2 == 1 =>
+            ((c as u32) - 1) as char,
+          '\ua78c' => '\ua78b',
+          '\ua791' => '\ua790',
+          '\ua793' => '\ua792',
+          '\ua794' => '\ua7c4',
+          '\ua797' .. '\ua7a9' if (c as u32) % 2 == 1 =>
+            ((c as u32) - 1) as char,
+          '\ua7b5' .. '\ua7c3' if (c as u32) % 2 == 1 =>
+            ((c as u32) - 1) as char,
+          '\ua7c8' => '\ua7c7',
+          '\ua7ca' => '\ua7c9',
+          '\ua7d1' => '\ua7d0',
+          '\ua7d7' => '\ua7d6',
+          '\ua7d9' => '\ua7d8',
+          '\ua7f6' => '\ua7f5',
+          '\uab53' => '\ua7b3',
+          '\uab70' .. '\uabbf' => ((c as u32) - 38864) as char,
+          '\uff41' .. '\uff5a' => ((c as u32) - 32) as char,
+          '\U00010428' .. '\U0001044f' => ((c as u32) - 40) as char,
+          '\U000104d8' .. '\U000104fb' => ((c as u32) - 40) as char,
+          '\U00010597' .. '\U000105a1' => ((c as u32) - 39) as char,
+          '\U000105a3' .. '\U000105b1' => ((c as u32) - 39) as char,
+          '\U000105b3' .. '\U000105b9' => ((c as u32) - 39) as char,
+          '\U000105bb' .. '\U000105bc' => ((c as u32) - 39) as char,
+          '\U00010cc0' .. '\U00010cf2' => ((c as u32) - 64) as char,
+          '\U000118c0' .. '\U000118df' => ((c as u32) - 32) as char,
+          '\U00016e60' .. '\U00016e7f' => ((c as u32) - 32) as char,
+          '\U0001e922' .. '\U0001e943' => ((c as u32) - 34) as char,
+          _ => c
+        }
+    }
+
+    /**
+     * Call `f` with each char of the full lowercase mapping of `c`,
+     * which may be more than one char
+     */
+    pub pure fn each_lower(c: char, f: fn(char)) {
+        match c {
+          '\u0130' => { f('\x69'); f('\u0307'); }
+          _ => f(to_lower(c))
+        }
+    }
+
+    /**
+     * Call `f` with each char of the full uppercase mapping of `c`,
+     * which may be more than one char
+     */
+    pub pure fn each_upper(c: char, f: fn(char)) {
+        match c {
+          '\xdf' => { f('\x53'); f('\x53'); }
+          '\u0149' => { f('\u02bc'); f('\x4e'); }
+          '\u01f0' => { f('\x4a'); f('\u030c'); }
+          '\u0390' => { f('\u0399'); f('\u0308'); f('\u0301'); }
+          '\u03b0' => { f('\u03a5'); f('\u0308'); f('\u0301'); }
+          '\u0587' => { f('\u0535'); f('\u0552'); }
+          '\u1e96' => { f('\x48'); f('\u0331'); }
+          '\u1e97' => { f('\x54'); f('\u0308'); }
+          '\u1e98' => { f('\x57'); f('\u030a'); }
+          '\u1e99' => { f('\x59'); f('\u030a'); }
+          '\u1e9a' => { f('\x41'); f('\u02be'); }
+          '\u1f50' => { f('\u03a5'); f('\u0313'); }
+          '\u1f52' => { f('\u03a5'); f('\u0313'); f('\u0300'); }
+          '\u1f54' => { f('\u03a5'); f('\u0313'); f('\u0301'); }
+          '\u1f56' => { f('\u03a5'); f('\u0313'); f('\u0342'); }
+          '\u1f80' => { f('\u1f08'); f('\u0399'); }
+          '\u1f81' => { f('\u1f09'); f('\u0399'); }
+          '\u1f82' => { f('\u1f0a'); f('\u0399'); }
+          '\u1f83' => { f('\u1f0b'); f('\u0399'); }
+          '\u1f84' => { f('\u1f0c'); f('\u0399'); }
+          '\u1f85' => { f('\u1f0d'); f('\u0399'); }
+          '\u1f86' => { f('\u1f0e'); f('\u0399'); }
+          '\u1f87' => { f('\u1f0f'); f('\u0399'); }
+          '\u1f88' => { f('\u1f08'); f('\u0399'); }
+          '\u1f89' => { f('\u1f09'); f('\u0399'); }
+          '\u1f8a' => { f('\u1f0a'); f('\u0399'); }
+          '\u1f8b' => { f('\u1f0b'); f('\u0399'); }
+          '\u1f8c' => { f('\u1f0c'); f('\u0399'); }
+          '\u1f8d' => { f('\u1f0d'); f('\u0399'); }
+          '\u1f8e' => { f('\u1f0e'); f('\u0399'); }
+          '\u1f8f' => { f('\u1f0f'); f('\u0399'); }
+          '\u1f90' => { f('\u1f28'); f('\u0399'); }
+          '\u1f91' => { f('\u1f29'); f('\u0399'); }
+          '\u1f92' => { f('\u1f2a'); f('\u0399'); }
+          '\u1f93' => { f('\u1f2b'); f('\u0399'); }
+          '\u1f94' => { f('\u1f2c'); f('\u0399'); }
+          '\u1f95' => { f('\u1f2d'); f('\u0399'); }
+          '\u1f96' => { f('\u1f2e'); f('\u0399'); }
+          '\u1f97' => { f('\u1f2f'); f('\u0399'); }
+          '\u1f98' => { f('\u1f28'); f('\u0399'); }
+          '\u1f99' => { f('\u1f29'); f('\u0399'); }
+          '\u1f9a' => { f('\u1f2a'); f('\u0399'); }
+          '\u1f9b' => { f('\u1f2b'); f('\u0399'); }
+          '\u1f9c' => { f('\u1f2c'); f('\u0399'); }
+          '\u1f9d' => { f('\u1f2d'); f('\u0399'); }
+          '\u1f9e' => { f('\u1f2e'); f('\u0399'); }
+          '\u1f9f' => { f('\u1f2f'); f('\u0399'); }
+          '\u1fa0' => { f('\u1f68'); f('\u0399'); }
+          '\u1fa1' => { f('\u1f69'); f('\u0399'); }
+          '\u1fa2' => { f('\u1f6a'); f('\u0399'); }
+          '\u1fa3' => { f('\u1f6b'); f('\u0399'); }
+          '\u1fa4' => { f('\u1f6c'); f('\u0399'); }
+          '\u1fa5' => { f('\u1f6d'); f('\u0399'); }
+          '\u1fa6' => { f('\u1f6e'); f('\u0399'); }
+          '\u1fa7' => { f('\u1f6f'); f('\u0399'); }
+          '\u1fa8' => { f('\u1f68'); f('\u0399'); }
+          '\u1fa9' => { f('\u1f69'); f('\u0399'); }
+          '\u1faa' => { f('\u1f6a'); f('\u0399'); }
+          '\u1fab' => { f('\u1f6b'); f('\u0399'); }
+          '\u1fac' => { f('\u1f6c'); f('\u0399'); }
+          '\u1fad' => { f('\u1f6d'); f('\u0399'); }
+          '\u1fae' => { f('\u1f6e'); f('\u0399'); }
+          '\u1faf' => { f('\u1f6f'); f('\u0399'); }
+          '\u1fb2' => { f('\u1fba'); f('\u0399'); }
+          '\u1fb3' => { f('\u0391'); f('\u0399'); }
+          '\u1fb4' => { f('\u0386'); f('\u0399'); }
+          '\u1fb6' => { f('\u0391'); f('\u0342'); }
+          '\u1fb7' => { f('\u0391'); f('\u0342'); f('\u0399'); }
+          '\u1fbc' => { f('\u0391'); f('\u0399'); }
+          '\u1fc2' => { f('\u1fca'); f('\u0399'); }
+          '\u1fc3' => { f('\u0397'); f('\u0399'); }
+          '\u1fc4' => { f('\u0389'); f('\u0399'); }
+          '\u1fc6' => { f('\u0397'); f('\u0342'); }
+          '\u1fc7' => { f('\u0397'); f('\u0342'); f('\u0399'); }
+          '\u1fcc' => { f('\u0397'); f('\u0399'); }
+          '\u1fd2' => { f('\u0399'); f('\u0308'); f('\u0300'); }
+          '\u1fd3' => { f('\u0399'); f('\u0308'); f('\u0301'); }
+          '\u1fd6' => { f('\u0399'); f('\u0342'); }
+          '\u1fd7' => { f('\u0399'); f('\u0308'); f('\u0342'); }
+          '\u1fe2' => { f('\u03a5'); f('\u0308'); f('\u0300'); }
+          '\u1fe3' => { f('\u03a5'); f('\u0308'); f('\u0301'); }
+          '\u1fe4' => { f('\u03a1'); f('\u0313'); }
+          '\u1fe6' => { f('\u03a5'); f('\u0342'); }
+          '\u1fe7' => { f('\u03a5'); f('\u0308'); f('\u0342'); }
+          '\u1ff2' => { f('\u1ffa'); f('\u0399'); }
+          '\u1ff3' => { f('\u03a9'); f('\u0399'); }
+          '\u1ff4' => { f('\u038f'); f('\u0399'); }
+          '\u1ff6' => { f('\u03a9'); f('\u0342'); }
+          '\u1ff7' => { f('\u03a9'); f('\u0342'); f('\u0399'); }
+          '\u1ffc' => { f('\u03a9'); f('\u0399'); }
+          '\ufb00' => { f('\x46'); f('\x46'); }
+          '\ufb01' => { f('\x46'); f('\x49'); }
+          '\ufb02' => { f('\x46'); f('\x4c'); }
+          '\ufb03' => { f('\x46'); f('\x46'); f('\x49'); }
+          '\ufb04' => { f('\x46'); f('\x46'); f('\x4c'); }
+          '\ufb05' => { f('\x53'); f('\x54'); }
+          '\ufb06' => { f('\x53'); f('\x54'); }
+          '\ufb13' => { f('\u0544'); f('\u0546'); }
+          '\ufb14' => { f('\u0544'); f('\u0535'); }
+          '\ufb15' => { f('\u0544'); f('\u053b'); }
+          '\ufb16' => { f('\u054e'); f('\u0546'); }
+          '\ufb17' => { f('\u0544'); f('\u053d'); }
+          _ => f(to_upper(c))
+        }
+    }
+
+    /**
+     * Call `f` with each char of the full case folding of `c`. Strings
+     * whose foldings are equal are equal ignoring case.
+     */
+    pub pure fn each_fold(c: char, f: fn(char)) {
+        match c {
+          '\xdf' => { f('\x73'); f('\x73'); }
+          '\u0130' => { f('\x69'); f('\u0307'); }
+          '\u0149' => { f('\u02bc'); f('\x6e'); }
+          '\u01f0' => { f('\x6a'); f('\u030c'); }
+          '\u0390' => { f('\u03b9'); f('\u0308'); f('\u0301'); }
+          '\u03b0' => { f('\u03c5'); f('\u0308'); f('\u0301'); }
+          '\u0587' => { f('\u0565'); f('\u0582'); }
+          '\u1e96' => { f('\x68'); f('\u0331'); }
+          '\u1e97' => { f('\x74'); f('\u0308'); }
+          '\u1e98' => { f('\x77'); f('\u030a'); }
+          '\u1e99' => { f('\x79'); f('\u030a'); }
+          '\u1e9a' => { f('\x61'); f('\u02be'); }
+          '\u1e9e' => { f('\x73'); f('\x73'); }
+          '\u1f50' => { f('\u03c5'); f('\u0313'); }
+          '\u1f52' => { f('\u03c5'); f('\u0313'); f('\u0300'); }
+          '\u1f54' => { f('\u03c5'); f('\u0313'); f('\u0301'); }
+          '\u1f56' => { f('\u03c5'); f('\u0313'); f('\u0342'); }
+          '\u1f80' => { f('\u1f00'); f('\u03b9'); }
+          '\u1f81' => { f('\u1f01'); f('\u03b9'); }
+          '\u1f82' => { f('\u1f02'); f('\u03b9'); }
+          '\u1f83' => { f('\u1f03'); f('\u03b9'); }
+          '\u1f84' => { f('\u1f04'); f('\u03b9'); }
+          '\u1f85' => { f('\u1f05'); f('\u03b9'); }
+          '\u1f86' => { f('\u1f06'); f('\u03b9'); }
+          '\u1f87' => { f('\u1f07'); f('\u03b9'); }
+          '\u1f88' => { f('\u1f00'); f('\u03b9'); }
+          '\u1f89' => { f('\u1f01'); f('\u03b9'); }
+          '\u1f8a' => { f('\u1f02'); f('\u03b9'); }
+          '\u1f8b' => { f('\u1f03'); f('\u03b9'); }
+          '\u1f8c' => { f('\u1f04'); f('\u03b9'); }
+          '\u1f8d' => { f('\u1f05'); f('\u03b9'); }
+          '\u1f8e' => { f('\u1f06'); f('\u03b9'); }
+          '\u1f8f' => { f('\u1f07'); f('\u03b9'); }
+          '\u1f90' => { f('\u1f20'); f('\u03b9'); }
+          '\u1f91' => { f('\u1f21'); f('\u03b9'); }
+          '\u1f92' => { f('\u1f22'); f('\u03b9'); }
+          '\u1f93' => { f('\u1f23'); f('\u03b9'); }
+          '\u1f94' => { f('\u1f24'); f('\u03b9'); }
+          '\u1f95' => { f('\u1f25'); f('\u03b9'); }
+          '\u1f96' => { f('\u1f26'); f('\u03b9'); }
+          '\u1f97' => { f('\u1f27'); f('\u03b9'); }
+          '\u1f98' => { f('\u1f20'); f('\u03b9'); }
+          '\u1f99' => { f('\u1f21'); f('\u03b9'); }
+          '\u1f9a' => { f('\u1f22'); f('\u03b9'); }
+          '\u1f9b' => { f('\u1f23'); f('\u03b9'); }
+          '\u1f9c' => { f('\u1f24'); f('\u03b9'); }
+          '\u1f9d' => { f('\u1f25'); f('\u03b9'); }
+          '\u1f9e' => { f('\u1f26'); f('\u03b9'); }
+          '\u1f9f' => { f('\u1f27'); f('\u03b9'); }
+          '\u1fa0' => { f('\u1f60'); f('\u03b9'); }
+          '\u1fa1' => { f('\u1f61'); f('\u03b9'); }
+          '\u1fa2' => { f('\u1f62'); f('\u03b9'); }
+          '\u1fa3' => { f('\u1f63'); f('\u03b9'); }
+          '\u1fa4' => { f('\u1f64'); f('\u03b9'); }
+          '\u1fa5' => { f('\u1f65'); f('\u03b9'); }
+          '\u1fa6' => { f('\u1f66'); f('\u03b9'); }
+          '\u1fa7' => { f('\u1f67'); f('\u03b9'); }
+          '\u1fa8' => { f('\u1f60'); f('\u03b9'); }
+          '\u1fa9' => { f('\u1f61'); f('\u03b9'); }
+          '\u1faa' => { f('\u1f62'); f('\u03b9'); }
+          '\u1fab' => { f('\u1f63'); f('\u03b9'); }
+          '\u1fac' => { f('\u1f64'); f('\u03b9'); }
+          '\u1fad' => { f('\u1f65'); f('\u03b9'); }
+          '\u1fae' => { f('\u1f66'); f('\u03b9'); }
+          '\u1faf' => { f('\u1f67'); f('\u03b9'); }
+          '\u1fb2' => { f('\u1f70'); f('\u03b9'); }
+          '\u1fb3' => { f('\u03b1'); f('\u03b9'); }
+          '\u1fb4' => { f('\u03ac'); f('\u03b9'); }
+          '\u1fb6' => { f('\u03b1'); f('\u0342'); }
+          '\u1fb7' => { f('\u03b1'); f('\u0342'); f('\u03b9'); }
+          '\u1fbc' => { f('\u03b1'); f('\u03b9'); }
+          '\u1fc2' => { f('\u1f74'); f('\u03b9'); }
+          '\u1fc3' => { f('\u03b7'); f('\u03b9'); }
+          '\u1fc4' => { f('\u03ae'); f('\u03b9'); }
+          '\u1fc6' => { f('\u03b7'); f('\u0342'); }
+          '\u1fc7' => { f('\u03b7'); f('\u0342'); f('\u03b9'); }
+          '\u1fcc' => { f('\u03b7'); f('\u03b9'); }
+          '\u1fd2' => { f('\u03b9'); f('\u0308'); f('\u0300'); }
+          '\u1fd3' => { f('\u03b9'); f('\u0308'); f('\u0301'); }
+          '\u1fd6' => { f('\u03b9'); f('\u0342'); }
+          '\u1fd7' => { f('\u03b9'); f('\u0308'); f('\u0342'); }
+          '\u1fe2' => { f('\u03c5'); f('\u0308'); f('\u0300'); }
+          '\u1fe3' => { f('\u03c5'); f('\u0308'); f('\u0301'); }
+          '\u1fe4' => { f('\u03c1'); f('\u0313'); }
+          '\u1fe6' => { f('\u03c5'); f('\u0342'); }
+          '\u1fe7' => { f('\u03c5'); f('\u0308'); f('\u0342'); }
+          '\u1ff2' => { f('\u1f7c'); f('\u03b9'); }
+          '\u1ff3' => { f('\u03c9'); f('\u03b9'); }
+          '\u1ff4' => { f('\u03ce'); f('\u03b9'); }
+          '\u1ff6' => { f('\u03c9'); f('\u0342'); }
+          '\u1ff7' => { f('\u03c9'); f('\u0342'); f('\u03b9'); }
+          '\u1ffc' => { f('\u03c9'); f('\u03b9'); }
+          '\ufb00' => { f('\x66'); f('\x66'); }
+          '\ufb01' => { f('\x66'); f('\x69'); }
+          '\ufb02' => { f('\x66'); f('\x6c'); }
+          '\ufb03' => { f('\x66'); f('\x66'); f('\x69'); }
+          '\ufb04' => { f('\x66'); f('\x66'); f('\x6c'); }
+          '\ufb05' => { f('\x73'); f('\x74'); }
+          '\ufb06' => { f('\x73'); f('\x74'); }
+          '\ufb13' => { f('\u0574'); f('\u0576'); }
+          '\ufb14' => { f('\u0574'); f('\u0565'); }
+          '\ufb15' => { f('\u0574'); f('\u056b'); }
+          '\ufb16' => { f('\u057e'); f('\u0576'); }
+          '\ufb17' => { f('\u0574'); f('\u056d'); }
+          '\x41' .. '\x5a' => f(((c as u32) + 32) as char),
+          '\xb5' => f('\u03bc'),
+          '\xc0' .. '\xd6' => f(((c as u32) + 32) as char),
+          '\xd8' .. '\xde' => f(((c as u32) + 32) as char),
+          '\u0100' .. '\u012e' if (c as u32) % 2 == 0 =>
+            f(((c as u32) + 1) as char),
+          '\u0132' .. '\u0136' if (c as u32) % 2 == 0 =>
+            f(((c as u32) + 1) as char),
+          '\u0139' .. '\u0147' if (c as u32) % 2 == 1 =>
+            f(((c as u32) + 1) as char),
+          '\u014a' .. '\u0176' if (c as u32) % 2 == 0 =>
+            f(((c as u32) + 1) as char),
+          '\u0178' => f('\xff'),
+          '\u0179' .. '\u017d' if (c as u32) % 2 == 1 =>
+            f(((c as u32) + 1) as char),
+          '\u017f' => f('\x73'),
+          '\u0181' => f('\u0253'),
+          '\u0182' => f('\u0183'),
+          '\u0184' => f('\u0185'),
+          '\u0186' => f('\u0254'),
+          '\u0187' => f('\u0188'),
+          '\u0189' .. '\u018a' => f(((c as u32) + 205) as char),
+          '\u018b' => f('\u018c'),
+          '\u018e' => f('\u01dd'),
+          '\u018f' => f('\u0259'),
+          '\u0190' => f('\u025b'),
+          '\u0191' => f('\u0192'),
+          '\u0193' => f('\u0260'),
+          '\u0194' => f('\u0263'),
+          '\u0196' => f('\u0269'),
+          '\u0197' => f('\u0268'),
+          '\u0198' => f('\u0199'),
+          '\u019c' => f('\u026f'),
+          '\u019d' => f('\u0272'),
+          '\u019f' => f('\u0275'),
+          '\u01a0' .. '\u01a4' if (c as u32) % 2 == 0 =>
+            f(((c as u32) + 1) as char),
+          '\u01a6' => f('\u0280'),
+          '\u01a7' => f('\u01a8'),
+          '\u01a9' => f('\u0283'),
+          '\u01ac' => f('\u01ad'),
+          '\u01ae' => f('\u0288'),
+          '\u01af' => f('\u01b0'),
+          '\u01b1' .. '\u01b2' => f(((c as u32) + 217) as char),
+          '\u01b3' => f('\u01b4'),
+          '\u01b5' => f('\u01b6'),
+          '\u01b7' => f('\u0292'),
+          '\u01b8' => f('\u01b9'),
+          '\u01bc' => f('\u01bd'),
+          '\u01c4' => f('\u01c6'),
+          '\u01c5' => f('\u01c6'),
+          '\u01c7' => f('\u01c9'),
+          '\u01c8' => f('\u01c9'),
+          '\u01ca' => f('\u01cc'),
+          '\u01cb' .. '\u01db' if (c as u32) % 2 == 1 =>
+            f(((c as u32) + 1) as char),
+          '\u01de' .. '\u01ee' if (c as u32) % 2 == 0 =>
+            f(((c as u32) + 1) as char),
+          '\u01f1' => f('\u01f3'),
+          '\u01f2' => f('\u01f3'),
+          '\u01f4' => f('\u01f5'),
+          '\u01f6' => f('\u0195'),
+          '\u01f7' => f('\u01bf'),
+          '\u01f8' .. '\u021e' if (c as u32) % 2 == 0 =>
+            f(((c as u32) + 1) as char),
+          '\u0220' => f('\u019e'),
+          '\u0222' .. '\u0232' if (c as u32) % 2 == 0 =>
+            f(((c as u32) + 1) as char),
+          '\u023a' => f('\u2c65'),
+          '\u023b' => f('\u023c'),
+          '\u023d' => f('\u019a'),
+          '\u023e' => f('\u2c66'),
+          '\u0241' => f('\u0242'),
+          '\u0243' => f('\u0180'),
+          '\u0244' => f('\u0289'),
+          '\u0245' => f('\u028c'),
+          '\u0246' .. '\u024e' if (c as u32) % 2 == 0 =>
+            f(((c as u32) + 1) as char),
+          '\u0345' => f('\u03b9'),
+          '\u0370' => f('\u0371'),
+          '\u0372' => f('\u0373'),
+          '\u0376' => f('\u0377'),
+          '\u037f' => f('\u03f3'),
+          '\u0386' => f('\u03ac'),
+          '\u0388' .. '\u038a' => f(((c as u32) + 37) as char),
+          '\u038c' => f('\u03cc'),
+          '\u038e' .. '\u038f' => f(((c as u32) + 63) as char),
+          '\u0391' .. '\u03a1' => f(((c as u32) + 32) as char),
+          '\u03a3' .. '\u03ab' => f(((c as u32) + 32) as char),
+          '\u03c2' => f('\u03c3'),
+          '\u03cf' => f('\u03d7'),
+          '\u03d0' => f('\u03b2'),
+          '\u03d1' => f('\u03b8'),
+          '\u03d5' => f('\u03c6'),
+          '\u03d6' => f('\u03c0'),
+          '\u03d8' .. '\u03ee' if (c as u32) % 2 == 0 =>
+            f(((c as u32) + 1) as char),
+          '\u03f0' => f('\u03ba'),
+          '\u03f1' => f('\u03c1'),
+          '\u03f4' => f('\u03b8'),
+          '\u03f5' => f('\u03b5'),
+          '\u03f7' => f('\u03f8'),
+          '\u03f9' => f('\u03f2'),
+          '\u03fa' => f('\u03fb'),
+          '\u03fd' .. '\u03ff' => f(((c as u32) - 130) as char),
+          '\u0400' .. '\u040f' => f(((c as u32) + 80) as char),
+          '\u0410' .. '\u042f' => f(((c as u32) + 32) as char),
+          '\u0460' .. '\u0480' if (c as u32) % 2 == 0 =>
+            f(((c as u32) + 1) as char),
+          '\u048a' .. '\u04be' if (c as u32) % 2 == 0 =>
+            f(((c as u32) + 1) as char),
+          '\u04c0' => f('\u04cf'),
+          '\u04c1' .. '\u04cd' if (c as u32) % 2 == 1 =>
+            f(((c as u32) + 1) as char),
+          '\u04d0' .. '\u052e' if (c as u32) % 2 == 0 =>
+            f(((c as u32) + 1) as char),
+          '\u0531' .. '\u0556' => f(((c as u32) + 48) as char),
+          '\u10a0' .. '\u10c5' => f(((c as u32) + 7264) as char),
+          '\u10c7' => f('\u2d27'),
+          '\u10cd' => f('\u2d2d'),
+          '\u13f8' .. '\u13fd' => f(((c as u32) - 8) as char),
+          '\u1c80' => f('\u0432'),
+          '\u1c81' => f('\u0434'),
+          '\u1c82' => f('\u043e'),
+          '\u1c83' .. '\u1c84' => f(((c as u32) - 6210) as char),
+          '\u1c85' => f('\u0442'),
+          '\u1c86' => f('\u044a'),
+          '\u1c87' => f('\u0463'),
+          '\u1c88' => f('\ua64b'),
+          '\u1c90' .. '\u1cba' => f(((c as u32) - 3008) as char),
+          '\u1cbd' .. '\u1cbf' => f(((c as u32) - 3008) as char),
+          '\u1e00' .. '\u1e94' if (c as u32) % 2 == 0 =>
+            f(((c as u32) + 1) as char),
+          '\u1e9b' => f('\u1e61'),
+          '\u1ea0' .. '\u1efe' if (c as u32) % 2 == 0 =>
+            f(((c as u32) + 1) as char),
+          '\u1f08' .. '\u1f0f' => f(((c as u32) - 8) as char),
+          '\u1f18' .. '\u1f1d' => f(((c as u32) - 8) as char),
+          '\u1f28' .. '\u1f2f' => f(((c as u32) - 8) as char),
+          '\u1f38' .. '\u1f3f' => f(((c as u32) - 8) as char),
+          '\u1f48' .. '\u1f4d' => f(((c as u32) - 8) as char),
+          '\u1f59' .. '\u1f5f' if (c as u32) % 2 == 1 =>
+            f(((c as u32) - 8) as char),
+          '\u1f68' .. '\u1f6f' => f(((c as u32) - 8) as char),
+          '\u1fb8' .. '\u1fb9' => f(((c as u32) - 8) as char),
+          '\u1fba' .. '\u1fbb' => f(((c as u32) - 74) as char),
+          '\u1fbe' => f('\u03b9'),
+          '\u1fc8' .. '\u1fcb' => f(((c as u32) - 86) as char),
+          '\u1fd8' .. '\u1fd9' => f(((c as u32) - 8) as char),
+          '\u1fda' .. '\u1fdb' => f(((c as u32) - 100) as char),
+          '\u1fe8' .. '\u1fe9' => f(((c as u32) - 8) as char),
+          '\u1fea' .. '\u1feb' => f(((c as u32) - 112) as char),
+          '\u1fec' => f('\u1fe5'),
+          '\u1ff8' .. '\u1ff9' => f(((c as u32) - 128) as char),
+          '\u1ffa' .. '\u1ffb' => f(((c as u32) - 126) as char),
+          '\u2126' => f('\u03c9'),
+          '\u212a' => f('\x6b'),
+          '\u212b' => f('\xe5'),
+          '\u2132' => f('\u214e'),
+          '\u2160' .. '\u216f' => f(((c as u32) + 16) as char),
+          '\u2183' => f('\u2184'),
+          '\u24b6' .. '\u24cf' => f(((c as u32) + 26) as char),
+          '\u2c00' .. '\u2c2f' => f(((c as u32) + 48) as char),
+          '\u2c60' => f('\u2c61'),
+          '\u2c62' => f('\u026b'),
+          '\u2c63' => f('\u1d7d'),
+          '\u2c64' => f('\u027d'),
+          '\u2c67' .. '\u2c6b' if (c as u32) % 2 == 1 =>
+            f(((c as u32) + 1) as char),
+          '\u2c6d' => f('\u0251'),
+          '\u2c6e' => f('\u0271'),
+          '\u2c6f' => f('\u0250'),
+          '\u2c70' => f('\u0252'),
+          '\u2c72' => f('\u2c73'),
+          '\u2c75' => f('\u2c76'),
+          '\u2c7e' .. '\u2c7f' => f(((c as u32) - 10815) as char),
+          '\u2c80' .. '\u2ce2' if (c as u32) % 2 == 0 =>
+            f(((c as u32) + 1) as char),
+          '\u2ceb' => f('\u2cec'),
+          '\u2ced' => f('\u2cee'),
+          '\u2cf2' => f('\u2cf3'),
+          '\ua640' .. '\ua66c' if (c as u32) % 2 == 0 =>
+            f(((c as u32) + 1) as char),
+          '\ua680' .. '\ua69a' if (c as u32) % 2 == 0 =>
+            f(((c as u32) + 1) as char),
+          '\ua722' .. '\ua72e' if (c as u32) % 2 == 0 =>
+            f(((c as u32) + 1) as char),
+          '\ua732' .. '\ua76e' if (c as u32) % 2 == 0 =>
+            f(((c as u32) + 1) as char),
+          '\ua779' => f('\ua77a'),
+          '\ua77b' => f('\ua77c'),
+          '\ua77d' => f('\u1d79'),
+          '\ua77e' .. '\ua786' if (c as u32) % 2 == 0 =>
+            f(((c as u32) + 1) as char),
+          '\ua78b' => f('\ua78c'),
+          '\ua78d' => f('\u0265'),
+          '\ua790' => f('\ua791'),
+          '\ua792' => f('\ua793'),
+          '\ua796' .. '\ua7a8' if (c as u32) % 2 == 0 =>
+            f(((c as u32) + 1) as char),
+          '\ua7aa' => f('\u0266'),
+          '\ua7ab' => f('\u025c'),
+          '\ua7ac' => f('\u0261'),
+          '\ua7ad' => f('\u026c'),
+          '\ua7ae' => f('\u026a'),
+          '\ua7b0' => f('\u029e'),
+          '\ua7b1' => f('\u0287'),
+          '\ua7b2' => f('\u029d'),
+          '\ua7b3' => f('\uab53'),
+          '\ua7b4' .. '\ua7c2' if (c as u32) % 2 == 0 =>
+            f(((c as u32) + 1) as char),
+          '\ua7c4' => f('\ua794'),
+          '\ua7c5' => f('\u0282'),
+          '\ua7c6' => f('\u1d8e'),
+          '\ua7c7' => f('\ua7c8'),
+          '\ua7c9' => f('\ua7ca'),
+          '\ua7d0' => f('\ua7d1'),
+          '\ua7d6' => f('\ua7d7'),
+          '\ua7d8' => f('\ua7d9'),
+          '\ua7f5' => f('\ua7f6'),
+          '\uab70' .. '\uabbf' => f(((c as u32) - 38864) as char),
+          '\uff21' .. '\uff3a' => f(((c as u32) + 32) as char),
+          '\U00010400' .. '\U00010427' => f(((c as u32) + 40) as char),
+          '\U000104b0' .. '\U000104d3' => f(((c as u32) + 40) as char),
+          '\U00010570' .. '\U0001057a' => f(((c as u32) + 39) as char),
+          '\U0001057c' .. '\U0001058a' => f(((c as u32) + 39) as char),
+          '\U0001058c' .. '\U00010592' => f(((c as u32) + 39) as char),
+          '\U00010594' .. '\U00010595' => f(((c as u32) + 39) as char),
+          '\U00010c80' .. '\U00010cb2' => f(((c as u32) + 64) as char),
+          '\U000118a0' .. '\U000118bf' => f(((c as u32) + 32) as char),
+          '\U00016e40' .. '\U00016e5f' => f(((c as u32) + 32) as char),
+          '\U0001e900' .. '\U0001e921' => f(((c as u32) + 34) as char),
+          _ => f(c)
+        }
+    }
+
+}
+
+pub mod normalization {
+    use option::{None, Option, Some};
+
+    // Hangul syllables are composed and decomposed algorithmically
+    const S_BASE: u32 = 0xac00;
+    const L_BASE: u32 = 0x1100;
+    const V_BASE: u32 = 0x1161;
+    const T_BASE: u32 = 0x11a7;
+    const L_COUNT: u32 = 19;
+    const V_COUNT: u32 = 21;
+    const T_COUNT: u32 = 28;
+    const N_COUNT: u32 = V_COUNT * T_COUNT;
+    const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+    /// The canonical combining class of a char
+    pub pure fn canonical_combining_class(c: char) -> u8 {
+        match c {
+          '\u0300' .. '\u0314' => 230,
+          '\u0315' => 232,
+          '\u0316' .. '\u0319' => 220,
+          '\u031a' => 232,
+          '\u031b' => 216,
+          '\u031c' .. '\u0320' => 220,
+          '\u0321' .. '\u0322' => 202,
+          '\u0323' .. '\u0326' => 220,
+          '\u0327' .. '\u0328' => 202,
+          '\u0329' .. '\u0333' => 220,
+          '\u0334' .. '\u0338' => 1,
+          '\u0339' .. '\u033c' => 220,
+          '\u033d' .. '\u0344' => 230,
+          '\u0345' => 240,
+          '\u0346' => 230,
+          '\u0347' .. '\u0349' => 220,
+          '\u034a' .. '\u034c' => 230,
+          '\u034d' .. '\u034e' => 220,
+          '\u0350' .. '\u0352' => 230,
+          '\u0353' .. '\u0356' => 220,
+          '\u0357' => 230,
+          '\u0358' => 232,
+          '\u0359' .. '\u035a' => 220,
+          '\u035b' => 230,
+          '\u035c' => 233,
+          '\u035d' .. '\u035e' => 234,
+          '\u035f' => 233,
+          '\u0360' .. '\u0361' => 234,
+          '\u0362' => 233,
+          '\u0363' .. '\u036f' => 230,
+          '\u0483' .. '\u0487' => 230,
+          '\u0591' => 220,
+          '\u0592' .. '\u0595' => 230,
+          '\u0596' => 220,
+          '\u0597' .. '\u0599' => 230,
+          '\u059a' => 222,
+          '\u059b' => 220,
+          '\u059c' .. '\u05a1' => 230,
+          '\u05a2' .. '\u05a7' => 220,
+          '\u05a8' .. '\u05a9' => 230,
+          '\u05aa' => 220,
+          '\u05ab' .. '\u05ac' => 230,
+          '\u05ad' => 222,
+          '\u05ae' => 228,
+          '\u05af' => 230,
+          '\u05b0' => 10,
+          '\u05b1' => 11,
+          '\u05b2' => 12,
+          '\u05b3' => 13,
+          '\u05b4' => 14,
+          '\u05b5' => 15,
+          '\u05b6' => 16,
+          '\u05b7' => 17,
+          '\u05b8' => 18,
+          '\u05b9' .. '\u05ba' => 19,
+          '\u05bb' => 20,
+          '\u05bc' => 21,
+          '\u05bd' => 22,
+          '\u05bf' => 23,
+          '\u05c1' => 24,
+          '\u05c2' => 25,
+          '\u05c4' => 230,
+          '\u05c5' => 220,
+          '\u05c7' => 18,
+          '\u0610' .. '\u0617' => 230,
+          '\u0618' => 30,
+          '\u0619' => 31,
+          '\u061a' => 32,
+          '\u064b' => 27,
+          '\u064c' => 28,
+          '\u064d' => 29,
+          '\u064e' => 30,
+          '\u064f' => 31,
+          '\u0650' => 32,
+          '\u0651' => 33,
+          '\u0652' => 34,
+          '\u0653' .. '\u0654' => 230,
+          '\u0655' .. '\u0656' => 220,
+          '\u0657' .. '\u065b' => 230,
+          '\u065c' => 220,
+          '\u065d' .. '\u065e' => 230,
+          '\u065f' => 220,
+          '\u0670' => 35,
+          '\u06d6' .. '\u06dc' => 230,
+          '\u06df' .. '\u06e2' => 230,
+          '\u06e3' => 220,
+          '\u06e4' => 230,
+          '\u06e7' .. '\u06e8' => 230,
+          '\u06ea' => 220,
+          '\u06eb' .. '\u06ec' => 230,
+          '\u06ed' => 220,
+          '\u0711' => 36,
+          '\u0730' => 230,
+          '\u0731' => 220,
+          '\u0732' .. '\u0733' => 230,
+          '\u0734' => 220,
+          '\u0735' .. '\u0736' => 230,
+          '\u0737' .. '\u0739' => 220,
+          '\u073a' => 230,
+          '\u073b' .. '\u073c' => 220,
+          '\u073d' => 230,
+          '\u073e' => 220,
+          '\u073f' .. '\u0741' => 230,
+          '\u0742' => 220,
+          '\u0743' => 230,
+          '\u0744' => 220,
+          '\u0745' => 230,
+          '\u0746' => 220,
+          '\u0747' => 230,
+          '\u0748' => 220,
+          '\u0749' .. '\u074a' => 230,
+          '\u07eb' .. '\u07f1' => 230,
+          '\u07f2' => 220,
+          '\u07f3' => 230,
+          '\u07fd' => 220,
+          '\u0816' .. '\u0819' => 230,
+          '\u081b' .. '\u0823' => 230,
+          '\u0825' .. '\u0827' => 230,
+          '\u0829' .. '\u082d' => 230,
+          '\u0859' .. '\u085b' => 220,
+          '\u0898' => 230,
+          '\u0899' .. '\u089b' => 220,
+          '\u089c' .. '\u089f' => 230,
+          '\u08ca' .. '\u08ce' => 230,
+          '\u08cf' .. '\u08d3' => 220,
+          '\u08d4' .. '\u08e1' => 230,
+          '\u08e3' => 220,
+          '\u08e4' .. '\u08e5' => 230,
+          '\u08e6' => 220,
+          '\u08e7' .. '\u08e8' => 230,
+          '\u08e9' => 220,
+          '\u08ea' .. '\u08ec' => 230,
+          '\u08ed' .. '\u08ef' => 220,
+          '\u08f0' => 27,
+          '\u08f1' => 28,
+          '\u08f2' => 29,
+          '\u08f3' .. '\u08f5' => 230,
+          '\u08f6' => 220,
+          '\u08f7' .. '\u08f8' => 230,
+          '\u08f9' .. '\u08fa' => 220,
+          '\u08fb' .. '\u08ff' => 230,
+          '\u093c' => 7,
+          '\u094d' => 9,
+          '\u0951' => 230,
+          '\u0952' => 220,
+          '\u0953' .. '\u0954' => 230,
+          '\u09bc' => 7,
+          '\u09cd' => 9,
+          '\u09fe' => 230,
+          '\u0a3c' => 7,
+          '\u0a4d' => 9,
+          '\u0abc' => 7,
+          '\u0acd' => 9,
+          '\u0b3c' => 7,
+          '\u0b4d' => 9,
+          '\u0bcd' => 9,
+          '\u0c3c' => 7,
+          '\u0c4d' => 9,
+          '\u0c55' => 84,
+          '\u0c56' => 91,
+          '\u0cbc' => 7,
+          '\u0ccd' => 9,
+          '\u0d3b' .. '\u0d3c' => 9,
+          '\u0d4d' => 9,
+          '\u0dca' => 9,
+          '\u0e38' .. '\u0e39' => 103,
+          '\u0e3a' => 9,
+          '\u0e48' .. '\u0e4b' => 107,
+          '\u0eb8' .. '\u0eb9' => 118,
+          '\u0eba' => 9,
+          '\u0ec8' .. '\u0ecb' => 122,
+          '\u0f18' .. '\u0f19' => 220,
+          '\u0f35' => 220,
+          '\u0f37' => 220,
+          '\u0f39' => 216,
+          '\u0f71' => 129,
+          '\u0f72' => 130,
+          '\u0f74' => 132,
+          '\u0f7a' .. '\u0f7d' => 130,
+          '\u0f80' => 130,
+          '\u0f82' .. '\u0f83' => 230,
+          '\u0f84' => 9,
+          '\u0f86' .. '\u0f87' => 230,
+          '\u0fc6' => 220,
+          '\u1037' => 7,
+          '\u1039' .. '\u103a' => 9,
+          '\u108d' => 220,
+          '\u135d' .. '\u135f' => 230,
+          '\u1714' .. '\u1715' => 9,
+          '\u1734' => 9,
+          '\u17d2' => 9,
+          '\u17dd' => 230,
+          '\u18a9' => 228,
+          '\u1939' => 222,
+          '\u193a' => 230,
+          '\u193b' => 220,
+          '\u1a17' => 230,
+          '\u1a18' => 220,
+          '\u1a60' => 9,
+          '\u1a75' .. '\u1a7c' => 230,
+          '\u1a7f' => 220,
+          '\u1ab0' .. '\u1ab4' => 230,
+          '\u1ab5' .. '\u1aba' => 220,
+          '\u1abb' .. '\u1abc' => 230,
+          '\u1abd' => 220,
+          '\u1abf' .. '\u1ac0' => 220,
+          '\u1ac1' .. '\u1ac2' => 230,
+          '\u1ac3' .. '\u1ac4' => 220,
+          '\u1ac5' .. '\u1ac9' => 230,
+          '\u1aca' => 220,
+          '\u1acb' .. '\u1ace' => 230,
+          '\u1b34' => 7,
+          '\u1b44' => 9,
+          '\u1b6b' => 230,
+          '\u1b6c' => 220,
+          '\u1b6d' .. '\u1b73' => 230,
+          '\u1baa' .. '\u1bab' => 9,
+          '\u1be6' => 7,
+          '\u1bf2' .. '\u1bf3' => 9,
+          '\u1c37' => 7,
+          '\u1cd0' .. '\u1cd2' => 230,
+          '\u1cd4' => 1,
+          '\u1cd5' .. '\u1cd9' => 220,
+          '\u1cda' .. '\u1cdb' => 230,
+          '\u1cdc' .. '\u1cdf' => 220,
+          '\u1ce0' => 230,
+          '\u1ce2' .. '\u1ce8' => 1,
+          '\u1ced' => 220,
+          '\u1cf4' => 230,
+          '\u1cf8' .. '\u1cf9' => 230,
+          '\u1dc0' .. '\u1dc1' => 230,
+          '\u1dc2' => 220,
+          '\u1dc3' .. '\u1dc9' => 230,
+          '\u1dca' => 220,
+          '\u1dcb' .. '\u1dcc' => 230,
+          '\u1dcd' => 234,
+          '\u1dce' => 214,
+          '\u1dcf' => 220,
+          '\u1dd0' => 202,
+          '\u1dd1' .. '\u1df5' => 230,
+          '\u1df6' => 232,
+          '\u1df7' .. '\u1df8' => 228,
+          '\u1df9' => 220,
+          '\u1dfa' => 218,
+          '\u1dfb' => 230,
+          '\u1dfc' => 233,
+          '\u1dfd' => 220,
+          '\u1dfe' => 230,
+          '\u1dff' => 220,
+          '\u20d0' .. '\u20d1' => 230,
+          '\u20d2' .. '\u20d3' => 1,
+          '\u20d4' .. '\u20d7' => 230,
+          '\u20d8' .. '\u20da' => 1,
+          '\u20db' .. '\u20dc' => 230,
+          '\u20e1' => 230,
+          '\u20e5' .. '\u20e6' => 1,
+          '\u20e7' => 230,
+          '\u20e8' => 220,
+          '\u20e9' => 230,
+          '\u20ea' .. '\u20eb' => 1,
+          '\u20ec' .. '\u20ef' => 220,
+          '\u20f0' => 230,
+          '\u2cef' .. '\u2cf1' => 230,
+          '\u2d7f' => 9,
+          '\u2de0' .. '\u2dff' => 230,
+          '\u302a' => 218,
+          '\u302b' => 228,
+          '\u302c' => 232,
+          '\u302d' => 222,
+          '\u302e' .. '\u302f' => 224,
+          '\u3099' .. '\u309a' => 8,
+          '\ua66f' => 230,
+          '\ua674' .. '\ua67d' => 230,
+          '\ua69e' .. '\ua69f' => 230,
+          '\ua6f0' .. '\ua6f1' => 230,
+          '\ua806' => 9,
+          '\ua82c' => 9,
+          '\ua8c4' => 9,
+          '\ua8e0' .. '\ua8f1' => 230,
+          '\ua92b' .. '\ua92d' => 220,
+          '\ua953' => 9,
+          '\ua9b3' => 7,
+          '\ua9c0' => 9,
+          '\uaab0' => 230,
+          '\uaab2' .. '\uaab3' => 230,
+          '\uaab4' => 220,
+          '\uaab7' .. '\uaab8' => 230,
+          '\uaabe' .. '\uaabf' => 230,
+          '\uaac1' => 230,
+          '\uaaf6' => 9,
+          '\uabed' => 9,
+          '\ufb1e' => 26,
+          '\ufe20' .. '\ufe26' => 230,
+          '\ufe27' .. '\ufe2d' => 220,
+          '\ufe2e' .. '\ufe2f' => 230,
+          '\U000101fd' => 220,
+          '\U000102e0' => 220,
+          '\U00010376' .. '\U0001037a' => 230,
+          '\U00010a0d' => 220,
+          '\U00010a0f' => 230,
+          '\U00010a38' => 230,
+          '\U00010a39' => 1,
+          '\U00010a3a' => 220,
+          '\U00010a3f' => 9,
+          '\U00010ae5' => 230,
+          '\U00010ae6' => 220,
+          '\U00010d24' .. '\U00010d27' => 230,
+          '\U00010eab' .. '\U00010eac' => 230,
+          '\U00010f46' .. '\U00010f47' => 220,
+          '\U00010f48' .. '\U00010f4a' => 230,
+          '\U00010f4b' => 220,
+          '\U00010f4c' => 230,
+          '\U00010f4d' .. '\U00010f50' => 220,
+          '\U00010f82' => 230,
+          '\U00010f83' => 220,
+          '\U00010f84' => 230,
+          '\U00010f85' => 220,
+          '\U00011046' => 9,
+          '\U00011070' => 9,
+          '\U0001107f' => 9,
+          '\U000110b9' => 9,
+          '\U000110ba' => 7,
+          '\U00011100' .. '\U00011102' => 230,
+          '\U00011133' .. '\U00011134' => 9,
+          '\U00011173' => 7,
+          '\U000111c0' => 9,
+          '\U000111ca' => 7,
+          '\U00011235' => 9,
+          '\U00011236' => 7,
+          '\U000112e9' => 7,
+          '\U000112ea' => 9,
+          '\U0001133b' .. '\U0001133c' => 7,
+          '\U0001134d' => 9,
+          '\U00011366' .. '\U0001136c' => 230,
+          '\U00011370' .. '\U00011374' => 230,
+          '\U00011442' => 9,
+          '\U00011446' => 7,
+          '\U0001145e' => 230,
+          '\U000114c2' => 9,
+          '\U000114c3' => 7,
+          '\U000115bf' => 9,
+          '\U000115c0' => 7,
+          '\U0001163f' => 9,
+          '\U000116b6' => 9,
+          '\U000116b7' => 7,
+          '\U0001172b' => 9,
+          '\U00011839' => 9,
+          '\U0001183a' => 7,
+          '\U0001193d' .. '\U0001193e' => 9,
+          '\U00011943' => 7,
+          '\U000119e0' => 9,
+          '\U00011a34' => 9,
+          '\U00011a47' => 9,
+          '\U00011a99' => 9,
+          '\U00011c3f' => 9,
+          '\U00011d42' => 7,
+          '\U00011d44' .. '\U00011d45' => 9,
+          '\U00011d97' => 9,
+          '\U00016af0' .. '\U00016af4' => 1,
+          '\U00016b30' .. '\U00016b36' => 230,
+          '\U00016ff0' .. '\U00016ff1' => 6,
+          '\U0001bc9e' => 1,
+          '\U0001d165' .. '\U0001d166' => 216,
+          '\U0001d167' .. '\U0001d169' => 1,
+          '\U0001d16d' => 226,
+          '\U0001d16e' .. '\U0001d172' => 216,
+          '\U0001d17b' .. '\U0001d182' => 220,
+          '\U0001d185' .. '\U0001d189' => 230,
+          '\U0001d18a' .. '\U0001d18b' => 220,
+          '\U0001d1aa' .. '\U0001d1ad' => 230,
+          '\U0001d242' .. '\U0001d244' => 230,
+          '\U0001e000' .. '\U0001e006' => 230,
+          '\U0001e008' .. '\U0001e018' => 230,
+          '\U0001e01b' .. '\U0001e021' => 230,
+          '\U0001e023' .. '\U0001e024' => 230,
+          '\U0001e026' .. '\U0001e02a' => 230,
+          '\U0001e130' .. '\U0001e136' => 230,
+          '\U0001e2ae' => 230,
+          '\U0001e2ec' .. '\U0001e2ef' => 230,
+          '\U0001e8d0' .. '\U0001e8d6' => 220,
+          '\U0001e944' .. '\U0001e949' => 230,
+          '\U0001e94a' => 7,
+          _ => 0
+        }
+    }
+
+    /**
+     * Call `f` with each char of the full canonical decomposition of
+     * `c`. The result is not in canonical order.
+     */
+    pub pure fn decompose_canonical(c: char, f: fn(char)) {
+        d(c, f, false);
+    }
+
+    /**
+     * Call `f` with each char of the full compatibility decomposition of
+     * `c`. The result is not in canonical order.
+     */
+    pub pure fn decompose_compatible(c: char, f: fn(char)) {
+        d(c, f, true);
+    }
+
+    /**
+     * The primary composite of `a` followed by `b`, if there is one. This
+     * is the pairwise step of canonical composition.
+     */
+    pub pure fn compose(a: char, b: char) -> Option<char> {
+        let (a_, b_) = (a as u32, b as u32);
+        if a_ >= L_BASE && a_ < L_BASE + L_COUNT
+            && b_ >= V_BASE && b_ < V_BASE + V_COUNT {
+            let l = a_ - L_BASE, v = b_ - V_BASE;
+            return Some((S_BASE + (l * V_COUNT + v) * T_COUNT) as char);
+        }
+        if a_ >= S_BASE && a_ < S_BASE + S_COUNT
+            && (a_ - S_BASE) % T_COUNT == 0
+            && b_ > T_BASE && b_ < T_BASE + T_COUNT {
+            return Some((a_ + (b_ - T_BASE)) as char);
+        }
+        match (a, b) {
+          ('\x3c', '\u0338') => Some('\u226e'),
+          ('\x3d', '\u0338') => Some('\u2260'),
+          ('\x3e', '\u0338') => Some('\u226f'),
+          ('\x41', '\u0300') => Some('\xc0'),
+          ('\x41', '\u0301') => Some('\xc1'),
+          ('\x41', '\u0302') => Some('\xc2'),
+          ('\x41', '\u0303') => Some('\xc3'),
+          ('\x41', '\u0304') => Some('\u0100'),
+          ('\x41', '\u0306') => Some('\u0102'),
+          ('\x41', '\u0307') => Some('\u0226'),
+          ('\x41', '\u0308') => Some('\xc4'),
+          ('\x41', '\u0309') => Some('\u1ea2'),
+          ('\x41', '\u030a') => Some('\xc5'),
+          ('\x41', '\u030c') => Some('\u01cd'),
+          ('\x41', '\u030f') => Some('\u0200'),
+          ('\x41', '\u0311') => Some('\u0202'),
+          ('\x41', '\u0323') => Some('\u1ea0'),
+          ('\x41', '\u0325') => Some('\u1e00'),
+          ('\x41', '\u0328') => Some('\u0104'),
+          ('\x42', '\u0307') => Some('\u1e02'),
+          ('\x42', '\u0323') => Some('\u1e04'),
+          ('\x42', '\u0331') => Some('\u1e06'),
+          ('\x43', '\u0301') => Some('\u0106'),
+          ('\x43', '\u0302') => Some('\u0108'),
+          ('\x43', '\u0307') => Some('\u010a'),
+          ('\x43', '\u030c') => Some('\u010c'),
+          ('\x43', '\u0327') => Some('\xc7'),
+          ('\x44', '\u0307') => Some('\u1e0a'),
+          ('\x44', '\u030c') => Some('\u010e'),
+          ('\x44', '\u0323') => Some('\u1e0c'),
+          ('\x44', '\u0327') => Some('\u1e10'),
+          ('\x44', '\u032d') => Some('\u1e12'),
+          ('\x44', '\u0331') => Some('\u1e0e'),
+          ('\x45', '\u0300') => Some('\xc8'),
+          ('\x45', '\u0301') => Some('\xc9'),
+          ('\x45', '\u0302') => Some('\xca'),
+          ('\x45', '\u0303') => Some('\u1ebc'),
+          ('\x45', '\u0304') => Some('\u0112'),
+          ('\x45', '\u0306') => Some('\u0114'),
+          ('\x45', '\u0307') => Some('\u0116'),
+          ('\x45', '\u0308') => Some('\xcb'),
+          ('\x45', '\u0309') => Some('\u1eba'),
+          ('\x45', '\u030c') => Some('\u011a'),
+          ('\x45', '\u030f') => Some('\u0204'),
+          ('\x45', '\u0311') => Some('\u0206'),
+          ('\x45', '\u0323') => Some('\u1eb8'),
+          ('\x45', '\u0327') => Some('\u0228'),
+          ('\x45', '\u0328') => Some('\u0118'),
+          ('\x45', '\u032d') => Some('\u1e18'),
+          ('\x45', '\u0330') => Some('\u1e1a'),
+          ('\x46', '\u0307') => Some('\u1e1e'),
+          ('\x47', '\u0301') => Some('\u01f4'),
+          ('\x47', '\u0302') => Some('\u011c'),
+          ('\x47', '\u0304') => Some('\u1e20'),
+          ('\x47', '\u0306') => Some('\u011e'),
+          ('\x47', '\u0307') => Some('\u0120'),
+          ('\x47', '\u030c') => Some('\u01e6'),
+          ('\x47', '\u0327') => Some('\u0122'),
+          ('\x48', '\u0302') => Some('\u0124'),
+          ('\x48', '\u0307') => Some('\u1e22'),
+          ('\x48', '\u0308') => Some('\u1e26'),
+          ('\x48', '\u030c') => Some('\u021e'),
+          ('\x48', '\u0323') => Some('\u1e24'),
+          ('\x48', '\u0327') => Some('\u1e28'),
+          ('\x48', '\u032e') => Some('\u1e2a'),
+          ('\x49', '\u0300') => Some('\xcc'),
+          ('\x49', '\u0301') => Some('\xcd'),
+          ('\x49', '\u0302') => Some('\xce'),
+          ('\x49', '\u0303') => Some('\u0128'),
+          ('\x49', '\u0304') => Some('\u012a'),
+          ('\x49', '\u0306') => Some('\u012c'),
+          ('\x49', '\u0307') => Some('\u0130'),
+          ('\x49', '\u0308') => Some('\xcf'),
+          ('\x49', '\u0309') => Some('\u1ec8'),
+          ('\x49', '\u030c') => Some('\u01cf'),
+          ('\x49', '\u030f') => Some('\u0208'),
+          ('\x49', '\u0311') => Some('\u020a'),
+          ('\x49', '\u0323') => Some('\u1eca'),
+          ('\x49', '\u0328') => Some('\u012e'),
+          ('\x49', '\u0330') => Some('\u1e2c'),
+          ('\x4a', '\u0302') => Some('\u0134'),
+          ('\x4b', '\u0301') => Some('\u1e30'),
+          ('\x4b', '\u030c') => Some('\u01e8'),
+          ('\x4b', '\u0323') => Some('\u1e32'),
+          ('\x4b', '\u0327') => Some('\u0136'),
+          ('\x4b', '\u0331') => Some('\u1e34'),
+          ('\x4c', '\u0301') => Some('\u0139'),
+          ('\x4c', '\u030c') => Some('\u013d'),
+          ('\x4c', '\u0323') => Some('\u1e36'),
+          ('\x4c', '\u0327') => Some('\u013b'),
+          ('\x4c', '\u032d') => Some('\u1e3c'),
+          ('\x4c', '\u0331') => Some('\u1e3a'),
+          ('\x4d', '\u0301') => Some('\u1e3e'),
+          ('\x4d', '\u0307') => Some('\u1e40'),
+          ('\x4d', '\u0323') => Some('\u1e42'),
+          ('\x4e', '\u0300') => Some('\u01f8'),
+          ('\x4e', '\u0301') => Some('\u0143'),
+          ('\x4e', '\u0303') => Some('\xd1'),
+          ('\x4e', '\u0307') => Some('\u1e44'),
+          ('\x4e', '\u030c') => Some('\u0147'),
+          ('\x4e', '\u0323') => Some('\u1e46'),
+          ('\x4e', '\u0327') => Some('\u0145'),
+          ('\x4e', '\u032d') => Some('\u1e4a'),
+          ('\x4e', '\u0331') => Some('\u1e48'),
+          ('\x4f', '\u0300') => Some('\xd2'),
+          ('\x4f', '\u0301') => Some('\xd3'),
+          ('\x4f', '\u0302') => Some('\xd4'),
+          ('\x4f', '\u0303') => Some('\xd5'),
+          ('\x4f', '\u0304') => Some('\u014c'),
+          ('\x4f', '\u0306') => Some('\u014e'),
+          ('\x4f', '\u0307') => Some('\u022e'),
+          ('\x4f', '\u0308') => Some('\xd6'),
+          ('\x4f', '\u0309') => Some('\u1ece'),
+          ('\x4f', '\u030b') => Some('\u0150'),
+          ('\x4f', '\u030c') => Some('\u01d1'),
+          ('\x4f', '\u030f') => Some('\u020c'),
+          ('\x4f', '\u0311') => Some('\u020e'),
+          ('\x4f', '\u031b') => Some('\u01a0'),
+          ('\x4f', '\u0323') => Some('\u1ecc'),
+          ('\x4f', '\u0328') => Some('\u01ea'),
+          ('\x50', '\u0301') => Some('\u1e54'),
+          ('\x50', '\u0307') => Some('\u1e56'),
+          ('\x52', '\u0301') => Some('\u0154'),
+          ('\x52', '\u0307') => Some('\u1e58'),
+          ('\x52', '\u030c') => Some('\u0158'),
+          ('\x52', '\u030f') => Some('\u0210'),
+          ('\x52', '\u0311') => Some('\u0212'),
+          ('\x52', '\u0323') => Some('\u1e5a'),
+          ('\x52', '\u0327') => Some('\u0156'),
+          ('\x52', '\u0331') => Some('\u1e5e'),
+          ('\x53', '\u0301') => Some('\u015a'),
+          ('\x53', '\u0302') => Some('\u015c'),
+          ('\x53', '\u0307') => Some('\u1e60'),
+          ('\x53', '\u030c') => Some('\u0160'),
+          ('\x53', '\u0323') => Some('\u1e62'),
+          ('\x53', '\u0326') => Some('\u0218'),
+          ('\x53', '\u0327') => Some('\u015e'),
+          ('\x54', '\u0307') => Some('\u1e6a'),
+          ('\x54', '\u030c') => Some('\u0164'),
+          ('\x54', '\u0323') => Some('\u1e6c'),
+          ('\x54', '\u0326') => Some('\u021a'),
+          ('\x54', '\u0327') => Some('\u0162'),
+          ('\x54', '\u032d') => Some('\u1e70'),
+          ('\x54', '\u0331') => Some('\u1e6e'),
+          ('\x55', '\u0300') => Some('\xd9'),
+          ('\x55', '\u0301') => Some('\xda'),
+          ('\x55', '\u0302') => Some('\xdb'),
+          ('\x55', '\u0303') => Some('\u0168'),
+          ('\x55', '\u0304') => Some('\u016a'),
+          ('\x55', '\u0306') => Some('\u016c'),
+          ('\x55', '\u0308') => Some('\xdc'),
+          ('\x55', '\u0309') => Some('\u1ee6'),
+          ('\x55', '\u030a') => Some('\u016e'),
+          ('\x55', '\u030b') => Some('\u0170'),
+          ('\x55', '\u030c') => Some('\u01d3'),
+          ('\x55', '\u030f') => Some('\u0214'),
+          ('\x55', '\u0311') => Some('\u0216'),
+          ('\x55', '\u031b') => Some('\u01af'),
+          ('\x55', '\u0323') => Some('\u1ee4'),
+          ('\x55', '\u0324') => Some('\u1e72'),
+          ('\x55', '\u0328') => Some('\u0172'),
+          ('\x55', '\u032d') => Some('\u1e76'),
+          ('\x55', '\u0330') => Some('\u1e74'),
+          ('\x56', '\u0303') => Some('\u1e7c'),
+          ('\x56', '\u0323') => Some('\u1e7e'),
+          ('\x57', '\u0300') => Some('\u1e80'),
+          ('\x57', '\u0301') => Some('\u1e82'),
+          ('\x57', '\u0302') => Some('\u0174'),
+          ('\x57', '\u0307') => Some('\u1e86'),
+          ('\x57', '\u0308') => Some('\u1e84'),
+          ('\x57', '\u0323') => Some('\u1e88'),
+          ('\x58', '\u0307') => Some('\u1e8a'),
+          ('\x58', '\u0308') => Some('\u1e8c'),
+          ('\x59', '\u0300') => Some('\u1ef2'),
+          ('\x59', '\u0301') => Some('\xdd'),
+          ('\x59', '\u0302') => Some('\u0176'),
+          ('\x59', '\u0303') => Some('\u1ef8'),
+          ('\x59', '\u0304') => Some('\u0232'),
+          ('\x59', '\u0307') => Some('\u1e8e'),
+          ('\x59', '\u0308') => Some('\u0178'),
+          ('\x59', '\u0309') => Some('\u1ef6'),
+          ('\x59', '\u0323') => Some('\u1ef4'),
+          ('\x5a', '\u0301') => Some('\u0179'),
+          ('\x5a', '\u0302') => Some('\u1e90'),
+          ('\x5a', '\u0307') => Some('\u017b'),
+          ('\x5a', '\u030c') => Some('\u017d'),
+          ('\x5a', '\u0323') => Some('\u1e92'),
+          ('\x5a', '\u0331') => Some('\u1e94'),
+          ('\x61', '\u0300') => Some('\xe0'),
+          ('\x61', '\u0301') => Some('\xe1'),
+          ('\x61', '\u0302') => Some('\xe2'),
+          ('\x61', '\u0303') => Some('\xe3'),
+          ('\x61', '\u0304') => Some('\u0101'),
+          ('\x61', '\u0306') => Some('\u0103'),
+          ('\x61', '\u0307') => Some('\u0227'),
+          ('\x61', '\u0308') => Some('\xe4'),
+          ('\x61', '\u0309') => Some('\u1ea3'),
+          ('\x61', '\u030a') => Some('\xe5'),
+          ('\x61', '\u030c') => Some('\u01ce'),
+          ('\x61', '\u030f') => Some('\u0201'),
+          ('\x61', '\u0311') => Some('\u0203'),
+          ('\x61', '\u0323') => Some('\u1ea1'),
+          ('\x61', '\u0325') => Some('\u1e01'),
+          ('\x61', '\u0328') => Some('\u0105'),
+          ('\x62', '\u0307') => Some('\u1e03'),
+          ('\x62', '\u0323') => Some('\u1e05'),
+          ('\x62', '\u0331') => Some('\u1e07'),
+          ('\x63', '\u0301') => Some('\u0107'),
+          ('\x63', '\u0302') => Some('\u0109'),
+          ('\x63', '\u0307') => Some('\u010b'),
+          ('\x63', '\u030c') => Some('\u010d'),
+          ('\x63', '\u0327') => Some('\xe7'),
+          ('\x64', '\u0307') => Some('\u1e0b'),
+          ('\x64', '\u030c') => Some('\u010f'),
+          ('\x64', '\u0323') => Some('\u1e0d'),
+          ('\x64', '\u0327') => Some('\u1e11'),
+          ('\x64', '\u032d') => Some('\u1e13'),
+          ('\x64', '\u0331') => Some('\u1e0f'),
+          ('\x65', '\u0300') => Some('\xe8'),
+          ('\x65', '\u0301') => Some('\xe9'),
+          ('\x65', '\u0302') => Some('\xea'),
+          ('\x65', '\u0303') => Some('\u1ebd'),
+          ('\x65', '\u0304') => Some('\u0113'),
+          ('\x65', '\u0306') => Some('\u0115'),
+          ('\x65', '\u0307') => Some('\u0117'),
+          ('\x65', '\u0308') => Some('\xeb'),
+          ('\x65', '\u0309') => Some('\u1ebb'),
+          ('\x65', '\u030c') => Some('\u011b'),
+          ('\x65', '\u030f') => Some('\u0205'),
+          ('\x65', '\u0311') => Some('\u0207'),
+          ('\x65', '\u0323') => Some('\u1eb9'),
+          ('\x65', '\u0327') => Some('\u0229'),
+          ('\x65', '\u0328') => Some('\u0119'),
+          ('\x65', '\u032d') => Some('\u1e19'),
+          ('\x65', '\u0330') => Some('\u1e1b'),
+          ('\x66', '\u0307') => Some('\u1e1f'),
+          ('\x67', '\u0301') => Some('\u01f5'),
+          ('\x67', '\u0302') => Some('\u011d'),
+          ('\x67', '\u0304') => Some('\u1e21'),
+          ('\x67', '\u0306') => Some('\u011f'),
+          ('\x67', '\u0307') => Some('\u0121'),
+          ('\x67', '\u030c') => Some('\u01e7'),
+          ('\x67', '\u0327') => Some('\u0123'),
+          ('\x68', '\u0302') => Some('\u0125'),
+          ('\x68', '\u0307') => Some('\u1e23'),
+          ('\x68', '\u0308') => Some('\u1e27'),
+          ('\x68', '\u030c') => Some('\u021f'),
+          ('\x68', '\u0323') => Some('\u1e25'),
+          ('\x68', '\u0327') => Some('\u1e29'),
+          ('\x68', '\u032e') => Some('\u1e2b'),
+          ('\x68', '\u0331') => Some('\u1e96'),
+          ('\x69', '\u0300') => Some('\xec'),
+          ('\x69', '\u0301') => Some('\xed'),
+          ('\x69', '\u0302') => Some('\xee'),
+          ('\x69', '\u0303') => Some('\u0129'),
+          ('\x69', '\u0304') => Some('\u012b'),
+          ('\x69', '\u0306') => Some('\u012d'),
+          ('\x69', '\u0308') => Some('\xef'),
+          ('\x69', '\u0309') => Some('\u1ec9'),
+          ('\x69', '\u030c') => Some('\u01d0'),
+          ('\x69', '\u030f') => Some('\u0209'),
+          ('\x69', '\u0311') => Some('\u020b'),
+          ('\x69', '\u0323') => Some('\u1ecb'),
+          ('\x69', '\u0328') => Some('\u012f'),
+          ('\x69', '\u0330') => Some('\u1e2d'),
+          ('\x6a', '\u0302') => Some('\u0135'),
+          ('\x6a', '\u030c') => Some('\u01f0'),
+          ('\x6b', '\u0301') => Some('\u1e31'),
+          ('\x6b', '\u030c') => Some('\u01e9'),
+          ('\x6b', '\u0323') => Some('\u1e33'),
+          ('\x6b', '\u0327') => Some('\u0137'),
+          ('\x6b', '\u0331') => Some('\u1e35'),
+          ('\x6c', '\u0301') => Some('\u013a'),
+          ('\x6c', '\u030c') => Some('\u013e'),
+          ('\x6c', '\u0323') => Some('\u1e37'),
+          ('\x6c', '\u0327') => Some('\u013c'),
+          ('\x6c', '\u032d') => Some('\u1e3d'),
+          ('\x6c', '\u0331') => Some('\u1e3b'),
+          ('\x6d', '\u0301') => Some('\u1e3f'),
+          ('\x6d', '\u0307') => Some('\u1e41'),
+          ('\x6d', '\u0323') => Some('\u1e43'),
+          ('\x6e', '\u0300') => Some('\u01f9'),
+          ('\x6e', '\u0301') => Some('\u0144'),
+          ('\x6e', '\u0303') => Some('\xf1'),
+          ('\x6e', '\u0307') => Some('\u1e45'),
+          ('\x6e', '\u030c') => Some('\u0148'),
+          ('\x6e', '\u0323') => Some('\u1e47'),
+          ('\x6e', '\u0327') => Some('\u0146'),
+          ('\x6e', '\u032d') => Some('\u1e4b'),
+          ('\x6e', '\u0331') => Some('\u1e49'),
+          ('\x6f', '\u0300') => Some('\xf2'),
+          ('\x6f', '\u0301') => Some('\xf3'),
+          ('\x6f', '\u0302') => Some('\xf4'),
+          ('\x6f', '\u0303') => Some('\xf5'),
+          ('\x6f', '\u0304') => Some('\u014d'),
+          ('\x6f', '\u0306') => Some('\u014f'),
+          ('\x6f', '\u0307') => Some('\u022f'),
+          ('\x6f', '\u0308') => Some('\xf6'),
+          ('\x6f', '\u0309') => Some('\u1ecf'),
+          ('\x6f', '\u030b') => Some('\u0151'),
+          ('\x6f', '\u030c') => Some('\u01d2'),
+          ('\x6f', '\u030f') => Some('\u020d'),
+          ('\x6f', '\u0311') => Some('\u020f'),
+          ('\x6f', '\u031b') => Some('\u01a1'),
+          ('\x6f', '\u0323') => Some('\u1ecd'),
+          ('\x6f', '\u0328') => Some('\u01eb'),
+          ('\x70', '\u0301') => Some('\u1e55'),
+          ('\x70', '\u0307') => Some('\u1e57'),
+          ('\x72', '\u0301') => Some('\u0155'),
+          ('\x72', '\u0307') => Some('\u1e59'),
+          ('\x72', '\u030c') => Some('\u0159'),
+          ('\x72', '\u030f') => Some('\u0211'),
+          ('\x72', '\u0311') => Some('\u0213'),
+          ('\x72', '\u0323') => Some('\u1e5b'),
+          ('\x72', '\u0327') => Some('\u0157'),
+          ('\x72', '\u0331') => Some('\u1e5f'),
+          ('\x73', '\u0301') => Some('\u015b'),
+          ('\x73', '\u0302') => Some('\u015d'),
+          ('\x73', '\u0307') => Some('\u1e61'),
+          ('\x73', '\u030c') => Some('\u0161'),
+          ('\x73', '\u0323') => Some('\u1e63'),
+          ('\x73', '\u0326') => Some('\u0219'),
+          ('\x73', '\u0327') => Some('\u015f'),
+          ('\x74', '\u0307') => Some('\u1e6b'),
+          ('\x74', '\u0308') => Some('\u1e97'),
+          ('\x74', '\u030c') => Some('\u0165'),
+          ('\x74', '\u0323') => Some('\u1e6d'),
+          ('\x74', '\u0326') => Some('\u021b'),
+          ('\x74', '\u0327') => Some('\u0163'),
+          ('\x74', '\u032d') => Some('\u1e71'),
+          ('\x74', '\u0331') => Some('\u1e6f'),
+          ('\x75', '\u0300') => Some('\xf9'),
+          ('\x75', '\u0301') => Some('\xfa'),
+          ('\x75', '\u0302') => Some('\xfb'),
+          ('\x75', '\u0303') => Some('\u0169'),
+          ('\x75', '\u0304') => Some('\u016b'),
+          ('\x75', '\u0306') => Some('\u016d'),
+          ('\x75', '\u0308') => Some('\xfc'),
+          ('\x75', '\u0309') => Some('\u1ee7'),
+          ('\x75', '\u030a') => Some('\u016f'),
+          ('\x75', '\u030b') => Some('\u0171'),
+          ('\x75', '\u030c') => Some('\u01d4'),
+          ('\x75', '\u030f') => Some('\u0215'),
+          ('\x75', '\u0311') => Some('\u0217'),
+          ('\x75', '\u031b') => Some('\u01b0'),
+          ('\x75', '\u0323') => Some('\u1ee5'),
+          ('\x75', '\u0324') => Some('\u1e73'),
+          ('\x75', '\u0328') => Some('\u0173'),
+          ('\x75', '\u032d') => Some('\u1e77'),
+          ('\x75', '\u0330') => Some('\u1e75'),
+          ('\x76', '\u0303') => Some('\u1e7d'),
+          ('\x76', '\u0323') => Some('\u1e7f'),
+          ('\x77', '\u0300') => Some('\u1e81'),
+          ('\x77', '\u0301') => Some('\u1e83'),
+          ('\x77', '\u0302') => Some('\u0175'),
+          ('\x77', '\u0307') => Some('\u1e87'),
+          ('\x77', '\u0308') => Some('\u1e85'),
+          ('\x77', '\u030a') => Some('\u1e98'),
+          ('\x77', '\u0323') => Some('\u1e89'),
+          ('\x78', '\u0307') => Some('\u1e8b'),
+          ('\x78', '\u0308') => Some('\u1e8d'),
+          ('\x79', '\u0300') => Some('\u1ef3'),
+          ('\x79', '\u0301') => Some('\xfd'),
+          ('\x79', '\u0302') => Some('\u0177'),
+          ('\x79', '\u0303') => Some('\u1ef9'),
+          ('\x79', '\u0304') => Some('\u0233'),
+          ('\x79', '\u0307') => Some('\u1e8f'),
+          ('\x79', '\u0308') => Some('\xff'),
+          ('\x79', '\u0309') => Some('\u1ef7'),
+          ('\x79', '\u030a') => Some('\u1e99'),
+          ('\x79', '\u0323') => Some('\u1ef5'),
+          ('\x7a', '\u0301') => Some('\u017a'),
+          ('\x7a', '\u0302') => Some('\u1e91'),
+          ('\x7a', '\u0307') => Some('\u017c'),
+          ('\x7a', '\u030c') => Some('\u017e'),
+          ('\x7a', '\u0323') => Some('\u1e93'),
+          ('\x7a', '\u0331') => Some('\u1e95'),
+          ('\xa8', '\u0300') => Some('\u1fed'),
+          ('\xa8', '\u0301') => Some('\u0385'),
+          ('\xa8', '\u0342') => Some('\u1fc1'),
+          ('\xc2', '\u0300') => Some('\u1ea6'),
+          ('\xc2', '\u0301') => Some('\u1ea4'),
+          ('\xc2', '\u0303') => Some('\u1eaa'),
+          ('\xc2', '\u0309') => Some('\u1ea8'),
+          ('\xc4', '\u0304') => Some('\u01de'),
+          ('\xc5', '\u0301') => Some('\u01fa'),
+          ('\xc6', '\u0301') => Some('\u01fc'),
+          ('\xc6', '\u0304') => Some('\u01e2'),
+          ('\xc7', '\u0301') => Some('\u1e08'),
+          ('\xca', '\u0300') => Some('\u1ec0'),
+          ('\xca', '\u0301') => Some('\u1ebe'),
+          ('\xca', '\u0303') => Some('\u1ec4'),
+          ('\xca', '\u0309') => Some('\u1ec2'),
+          ('\xcf', '\u0301') => Some('\u1e2e'),
+          ('\xd4', '\u0300') => Some('\u1ed2'),
+          ('\xd4', '\u0301') => Some('\u1ed0'),
+          ('\xd4', '\u0303') => Some('\u1ed6'),
+          ('\xd4', '\u0309') => Some('\u1ed4'),
+          ('\xd5', '\u0301') => Some('\u1e4c'),
+          ('\xd5', '\u0304') => Some('\u022c'),
+          ('\xd5', '\u0308') => Some('\u1e4e'),
+          ('\xd6', '\u0304') => Some('\u022a'),
+          ('\xd8', '\u0301') => Some('\u01fe'),
+          ('\xdc', '\u0300') => Some('\u01db'),
+          ('\xdc', '\u0301') => Some('\u01d7'),
+          ('\xdc', '\u0304') => Some('\u01d5'),
+          ('\xdc', '\u030c') => Some('\u01d9'),
+          ('\xe2', '\u0300') => Some('\u1ea7'),
+          ('\xe2', '\u0301') => Some('\u1ea5'),
+          ('\xe2', '\u0303') => Some('\u1eab'),
+          ('\xe2', '\u0309') => Some('\u1ea9'),
+          ('\xe4', '\u0304') => Some('\u01df'),
+          ('\xe5', '\u0301') => Some('\u01fb'),
+          ('\xe6', '\u0301') => Some('\u01fd'),
+          ('\xe6', '\u0304') => Some('\u01e3'),
+          ('\xe7', '\u0301') => Some('\u1e09'),
+          ('\xea', '\u0300') => Some('\u1ec1'),
+          ('\xea', '\u0301') => Some('\u1ebf'),
+          ('\xea', '\u0303') => Some('\u1ec5'),
+          ('\xea', '\u0309') => Some('\u1ec3'),
+          ('\xef', '\u0301') => Some('\u1e2f'),
+          ('\xf4', '\u0300') => Some('\u1ed3'),
+          ('\xf4', '\u0301') => Some('\u1ed1'),
+          ('\xf4', '\u0303') => Some('\u1ed7'),
+          ('\xf4', '\u0309') => Some('\u1ed5'),
+          ('\xf5', '\u0301') => Some('\u1e4d'),
+          ('\xf5', '\u0304') => Some('\u022d'),
+          ('\xf5', '\u0308') => Some('\u1e4f'),
+          ('\xf6', '\u0304') => Some('\u022b'),
+          ('\xf8', '\u0301') => Some('\u01ff'),
+          ('\xfc', '\u0300') => Some('\u01dc'),
+          ('\xfc', '\u0301') => Some('\u01d8'),
+          ('\xfc', '\u0304') => Some('\u01d6'),
+          ('\xfc', '\u030c') => Some('\u01da'),
+          ('\u0102', '\u0300') => Some('\u1eb0'),
+          ('\u0102', '\u0301') => Some('\u1eae'),
+          ('\u0102', '\u0303') => Some('\u1eb4'),
+          ('\u0102', '\u0309') => Some('\u1eb2'),
+          ('\u0103', '\u0300') => Some('\u1eb1'),
+          ('\u0103', '\u0301') => Some('\u1eaf'),
+          ('\u0103', '\u0303') => Some('\u1eb5'),
+          ('\u0103', '\u0309') => Some('\u1eb3'),
+          ('\u0112', '\u0300') => Some('\u1e14'),
+          ('\u0112', '\u0301') => Some('\u1e16'),
+          ('\u0113', '\u0300') => Some('\u1e15'),
+          ('\u0113', '\u0301') => Some('\u1e17'),
+          ('\u014c', '\u0300') => Some('\u1e50'),
+          ('\u014c', '\u0301') => Some('\u1e52'),
+          ('\u014d', '\u0300') => Some('\u1e51'),
+          ('\u014d', '\u0301') => Some('\u1e53'),
+          ('\u015a', '\u0307') => Some('\u1e64'),
+          ('\u015b', '\u0307') => Some('\u1e65'),
+          ('\u0160', '\u0307') => Some('\u1e66'),
+          ('\u0161', '\u0307') => Some('\u1e67'),
+          ('\u0168', '\u0301') => Some('\u1e78'),
+          ('\u0169', '\u0301') => Some('\u1e79'),
+          ('\u016a', '\u0308') => Some('\u1e7a'),
+          ('\u016b', '\u0308') => Some('\u1e7b'),
+          ('\u017f', '\u0307') => Some('\u1e9b'),
+          ('\u01a0', '\u0300') => Some('\u1edc'),
+          ('\u01a0', '\u0301') => Some('\u1eda'),
+          ('\u01a0', '\u0303') => Some('\u1ee0'),
+          ('\u01a0', '\u0309') => Some('\u1ede'),
+          ('\u01a0', '\u0323') => Some('\u1ee2'),
+          ('\u01a1', '\u0300') => Some('\u1edd'),
+          ('\u01a1', '\u0301') => Some('\u1edb'),
+          ('\u01a1', '\u0303') => Some('\u1ee1'),
+          ('\u01a1', '\u0309') => Some('\u1edf'),
+          ('\u01a1', '\u0323') => Some('\u1ee3'),
+          ('\u01af', '\u0300') => Some('\u1eea'),
+          ('\u01af', '\u0301') => Some('\u1ee8'),
+          ('\u01af', '\u0303') => Some('\u1eee'),
+          ('\u01af', '\u0309') => Some('\u1eec'),
+          ('\u01af', '\u0323') => Some('\u1ef0'),
+          ('\u01b0', '\u0300') => Some('\u1eeb'),
+          ('\u01b0', '\u0301') => Some('\u1ee9'),
+          ('\u01b0', '\u0303') => Some('\u1eef'),
+          ('\u01b0', '\u0309') => Some('\u1eed'),
+          ('\u01b0', '\u0323') => Some('\u1ef1'),
+          ('\u01b7', '\u030c') => Some('\u01ee'),
+          ('\u01ea', '\u0304') => Some('\u01ec'),
+          ('\u01eb', '\u0304') => Some('\u01ed'),
+          ('\u0226', '\u0304') => Some('\u01e0'),
+          ('\u0227', '\u0304') => Some('\u01e1'),
+          ('\u0228', '\u0306') => Some('\u1e1c'),
+          ('\u0229', '\u0306') => Some('\u1e1d'),
+          ('\u022e', '\u0304') => Some('\u0230'),
+          ('\u022f', '\u0304') => Some('\u0231'),
+          ('\u0292', '\u030c') => Some('\u01ef'),
+          ('\u0391', '\u0300') => Some('\u1fba'),
+          ('\u0391', '\u0301') => Some('\u0386'),
+          ('\u0391', '\u0304') => Some('\u1fb9'),
+          ('\u0391', '\u0306') => Some('\u1fb8'),
+          ('\u0391', '\u0313') => Some('\u1f08'),
+          ('\u0391', '\u0314') => Some('\u1f09'),
+          ('\u0391', '\u0345') => Some('\u1fbc'),
+          ('\u0395', '\u0300') => Some('\u1fc8'),
+          ('\u0395', '\u0301') => Some('\u0388'),
+          ('\u0395', '\u0313') => Some('\u1f18'),
+          ('\u0395', '\u0314') => Some('\u1f19'),
+          ('\u0397', '\u0300') => Some('\u1fca'),
+          ('\u0397', '\u0301') => Some('\u0389'),
+          ('\u0397', '\u0313') => Some('\u1f28'),
+          ('\u0397', '\u0314') => Some('\u1f29'),
+          ('\u0397', '\u0345') => Some('\u1fcc'),
+          ('\u0399', '\u0300') => Some('\u1fda'),
+          ('\u0399', '\u0301') => Some('\u038a'),
+          ('\u0399', '\u0304') => Some('\u1fd9'),
+          ('\u0399', '\u0306') => Some('\u1fd8'),
+          ('\u0399', '\u0308') => Some('\u03aa'),
+          ('\u0399', '\u0313') => Some('\u1f38'),
+          ('\u0399', '\u0314') => Some('\u1f39'),
+          ('\u039f', '\u0300') => Some('\u1ff8'),
+          ('\u039f', '\u0301') => Some('\u038c'),
+          ('\u039f', '\u0313') => Some('\u1f48'),
+          ('\u039f', '\u0314') => Some('\u1f49'),
+          ('\u03a1', '\u0314') => Some('\u1fec'),
+          ('\u03a5', '\u0300') => Some('\u1fea'),
+          ('\u03a5', '\u0301') => Some('\u038e'),
+          ('\u03a5', '\u0304') => Some('\u1fe9'),
+          ('\u03a5', '\u0306') => Some('\u1fe8'),
+          ('\u03a5', '\u0308') => Some('\u03ab'),
+          ('\u03a5', '\u0314') => Some('\u1f59'),
+          ('\u03a9', '\u0300') => Some('\u1ffa'),
+          ('\u03a9', '\u0301') => Some('\u038f'),
+          ('\u03a9', '\u0313') => Some('\u1f68'),
+          ('\u03a9', '\u0314') => Some('\u1f69'),
+          ('\u03a9', '\u0345') => Some('\u1ffc'),
+          ('\u03ac', '\u0345') => Some('\u1fb4'),
+          ('\u03ae', '\u0345') => Some('\u1fc4'),
+          ('\u03b1', '\u0300') => Some('\u1f70'),
+          ('\u03b1', '\u0301') => Some('\u03ac'),
+          ('\u03b1', '\u0304') => Some('\u1fb1'),
+          ('\u03b1', '\u0306') => Some('\u1fb0'),
+          ('\u03b1', '\u0313') => Some('\u1f00'),
+          ('\u03b1', '\u0314') => Some('\u1f01'),
+          ('\u03b1', '\u0342') => Some('\u1fb6'),
+          ('\u03b1', '\u0345') => Some('\u1fb3'),
+          ('\u03b5', '\u0300') => Some('\u1f72'),
+          ('\u03b5', '\u0301') => Some('\u03ad'),
+          ('\u03b5', '\u0313') => Some('\u1f10'),
+          ('\u03b5', '\u0314') => Some('\u1f11'),
+          ('\u03b7', '\u0300') => Some('\u1f74'),
+          ('\u03b7', '\u0301') => Some('\u03ae'),
+          ('\u03b7', '\u0313') => Some('\u1f20'),
+          ('\u03b7', '\u0314') => Some('\u1f21'),
+          ('\u03b7', '\u0342') => Some('\u1fc6'),
+          ('\u03b7', '\u0345') => Some('\u1fc3'),
+          ('\u03b9', '\u0300') => Some('\u1f76'),
+          ('\u03b9', '\u0301') => Some('\u03af'),
+          ('\u03b9', '\u0304') => Some('\u1fd1'),
+          ('\u03b9', '\u0306') => Some('\u1fd0'),
+          ('\u03b9', '\u0308') => Some('\u03ca'),
+          ('\u03b9', '\u0313') => Some('\u1f30'),
+          ('\u03b9', '\u0314') => Some('\u1f31'),
+          ('\u03b9', '\u0342') => Some('\u1fd6'),
+          ('\u03bf', '\u0300') => Some('\u1f78'),
+          ('\u03bf', '\u0301') => Some('\u03cc'),
+          ('\u03bf', '\u0313') => Some('\u1f40'),
+          ('\u03bf', '\u0314') => Some('\u1f41'),
+          ('\u03c1', '\u0313') => Some('\u1fe4'),
+          ('\u03c1', '\u0314') => Some('\u1fe5'),
+          ('\u03c5', '\u0300') => Some('\u1f7a'),
+          ('\u03c5', '\u0301') => Some('\u03cd'),
+          ('\u03c5', '\u0304') => Some('\u1fe1'),
+          ('\u03c5', '\u0306') => Some('\u1fe0'),
+          ('\u03c5', '\u0308') => Some('\u03cb'),
+          ('\u03c5', '\u0313') => Some('\u1f50'),
+          ('\u03c5', '\u0314') => Some('\u1f51'),
+          ('\u03c5', '\u0342') => Some('\u1fe6'),
+          ('\u03c9', '\u0300') => Some('\u1f7c'),
+          ('\u03c9', '\u0301') => Some('\u03ce'),
+          ('\u03c9', '\u0313') => Some('\u1f60'),
+          ('\u03c9', '\u0314') => Some('\u1f61'),
+          ('\u03c9', '\u0342') => Some('\u1ff6'),
+          ('\u03c9', '\u0345') => Some('\u1ff3'),
+          ('\u03ca', '\u0300') => Some('\u1fd2'),
+          ('\u03ca', '\u0301') => Some('\u0390'),
+          ('\u03ca', '\u0342') => Some('\u1fd7'),
+          ('\u03cb', '\u0300') => Some('\u1fe2'),
+          ('\u03cb', '\u0301') => Some('\u03b0'),
+          ('\u03cb', '\u0342') => Some('\u1fe7'),
+          ('\u03ce', '\u0345') => Some('\u1ff4'),
+          ('\u03d2', '\u0301') => Some('\u03d3'),
+          ('\u03d2', '\u0308') => Some('\u03d4'),
+          ('\u0406', '\u0308') => Some('\u0407'),
+          ('\u0410', '\u0306') => Some('\u04d0'),
+          ('\u0410', '\u0308') => Some('\u04d2'),
+          ('\u0413', '\u0301') => Some('\u0403'),
+          ('\u0415', '\u0300') => Some('\u0400'),
+          ('\u0415', '\u0306') => Some('\u04d6'),
+          ('\u0415', '\u0308') => Some('\u0401'),
+          ('\u0416', '\u0306') => Some('\u04c1'),
+          ('\u0416', '\u0308') => Some('\u04dc'),
+          ('\u0417', '\u0308') => Some('\u04de'),
+          ('\u0418', '\u0300') => Some('\u040d'),
+          ('\u0418', '\u0304') => Some('\u04e2'),
+          ('\u0418', '\u0306') => Some('\u0419'),
+          ('\u0418', '\u0308') => Some('\u04e4'),
+          ('\u041a', '\u0301') => Some('\u040c'),
+          ('\u041e', '\u0308') => Some('\u04e6'),
+          ('\u0423', '\u0304') => Some('\u04ee'),
+          ('\u0423', '\u0306') => Some('\u040e'),
+          ('\u0423', '\u0308') => Some('\u04f0'),
+          ('\u0423', '\u030b') => Some('\u04f2'),
+          ('\u0427', '\u0308') => Some('\u04f4'),
+          ('\u042b', '\u0308') => Some('\u04f8'),
+          ('\u042d', '\u0308') => Some('\u04ec'),
+          ('\u0430', '\u0306') => Some('\u04d1'),
+          ('\u0430', '\u0308') => Some('\u04d3'),
+          ('\u0433', '\u0301') => Some('\u0453'),
+          ('\u0435', '\u0300') => Some('\u0450'),
+          ('\u0435', '\u0306') => Some('\u04d7'),
+          ('\u0435', '\u0308') => Some('\u0451'),
+          ('\u0436', '\u0306') => Some('\u04c2'),
+          ('\u0436', '\u0308') => Some('\u04dd'),
+          ('\u0437', '\u0308') => Some('\u04df'),
+          ('\u0438', '\u0300') => Some('\u045d'),
+          ('\u0438', '\u0304') => Some('\u04e3'),
+          ('\u0438', '\u0306') => Some('\u0439'),
+          ('\u0438', '\u0308') => Some('\u04e5'),
+          ('\u043a', '\u0301') => Some('\u045c'),
+          ('\u043e', '\u0308') => Some('\u04e7'),
+          ('\u0443', '\u0304') => Some('\u04ef'),
+          ('\u0443', '\u0306') => Some('\u045e'),
+          ('\u0443', '\u0308') => Some('\u04f1'),
+          ('\u0443', '\u030b') => Some('\u04f3'),
+          ('\u0447', '\u0308') => Some('\u04f5'),
+          ('\u044b', '\u0308') => Some('\u04f9'),
+          ('\u044d', '\u0308') => Some('\u04ed'),
+          ('\u0456', '\u0308') => Some('\u0457'),
+          ('\u0474', '\u030f') => Some('\u0476'),
+          ('\u0475', '\u030f') => Some('\u0477'),
+          ('\u04d8', '\u0308') => Some('\u04da'),
+          ('\u04d9', '\u0308') => Some('\u04db'),
+          ('\u04e8', '\u0308') => Some('\u04ea'),
+          ('\u04e9', '\u0308') => Some('\u04eb'),
+          ('\u0627', '\u0653') => Some('\u0622'),
+          ('\u0627', '\u0654') => Some('\u0623'),
+          ('\u0627', '\u0655') => Some('\u0625'),
+          ('\u0648', '\u0654') => Some('\u0624'),
+          ('\u064a', '\u0654') => Some('\u0626'),
+          ('\u06c1', '\u0654') => Some('\u06c2'),
+          ('\u06d2', '\u0654') => Some('\u06d3'),
+          ('\u06d5', '\u0654') => Some('\u06c0'),
+          ('\u0928', '\u093c') => Some('\u0929'),
+          ('\u0930', '\u093c') => Some('\u0931'),
+          ('\u0933', '\u093c') => Some('\u0934'),
+          ('\u09c7', '\u09be') => Some('\u09cb'),
+          ('\u09c7', '\u09d7') => Some('\u09cc'),
+          ('\u0b47', '\u0b3e') => Some('\u0b4b'),
+          ('\u0b47', '\u0b56') => Some('\u0b48'),
+          ('\u0b47', '\u0b57') => Some('\u0b4c'),
+          ('\u0b92', '\u0bd7') => Some('\u0b94'),
+          ('\u0bc6', '\u0bbe') => Some('\u0bca'),
+          ('\u0bc6', '\u0bd7') => Some('\u0bcc'),
+          ('\u0bc7', '\u0bbe') => Some('\u0bcb'),
+          ('\u0c46', '\u0c56') => Some('\u0c48'),
+          ('\u0cbf', '\u0cd5') => Some('\u0cc0'),
+          ('\u0cc6', '\u0cc2') => Some('\u0cca'),
+          ('\u0cc6', '\u0cd5') => Some('\u0cc7'),
+          ('\u0cc6', '\u0cd6') => Some('\u0cc8'),
+          ('\u0cca', '\u0cd5') => Some('\u0ccb'),
+          ('\u0d46', '\u0d3e') => Some('\u0d4a'),
+          ('\u0d46', '\u0d57') => Some('\u0d4c'),
+          ('\u0d47', '\u0d3e') => Some('\u0d4b'),
+          ('\u0dd9', '\u0dca') => Some('\u0dda'),
+          ('\u0dd9', '\u0dcf') => Some('\u0ddc'),
+          ('\u0dd9', '\u0ddf') => Some('\u0dde'),
+          ('\u0ddc', '\u0dca') => Some('\u0ddd'),
+          ('\u1025', '\u102e') => Some('\u1026'),
+          ('\u1b05', '\u1b35') => Some('\u1b06'),
+          ('\u1b07', '\u1b35') => Some('\u1b08'),
+          ('\u1b09', '\u1b35') => Some('\u1b0a'),
+          ('\u1b0b', '\u1b35') => Some('\u1b0c'),
+          ('\u1b0d', '\u1b35') => Some('\u1b0e'),
+          ('\u1b11', '\u1b35') => Some('\u1b12'),
+          ('\u1b3a', '\u1b35') => Some('\u1b3b'),
+          ('\u1b3c', '\u1b35') => Some('\u1b3d'),
+          ('\u1b3e', '\u1b35') => Some('\u1b40'),
+          ('\u1b3f', '\u1b35') => Some('\u1b41'),
+          ('\u1b42', '\u1b35') => Some('\u1b43'),
+          ('\u1e36', '\u0304') => Some('\u1e38'),
+          ('\u1e37', '\u0304') => Some('\u1e39'),
+          ('\u1e5a', '\u0304') => Some('\u1e5c'),
+          ('\u1e5b', '\u0304') => Some('\u1e5d'),
+          ('\u1e62', '\u0307') => Some('\u1e68'),
+          ('\u1e63', '\u0307') => Some('\u1e69'),
+          ('\u1ea0', '\u0302') => Some('\u1eac'),
+          ('\u1ea0', '\u0306') => Some('\u1eb6'),
+          ('\u1ea1', '\u0302') => Some('\u1ead'),
+          ('\u1ea1', '\u0306') => Some('\u1eb7'),
+          ('\u1eb8', '\u0302') => Some('\u1ec6'),
+          ('\u1eb9', '\u0302') => Some('\u1ec7'),
+          ('\u1ecc', '\u0302') => Some('\u1ed8'),
+          ('\u1ecd', '\u0302') => Some('\u1ed9'),
+          ('\u1f00', '\u0300') => Some('\u1f02'),
+          ('\u1f00', '\u0301') => Some('\u1f04'),
+          ('\u1f00', '\u0342') => Some('\u1f06'),
+          ('\u1f00', '\u0345') => Some('\u1f80'),
+          ('\u1f01', '\u0300') => Some('\u1f03'),
+          ('\u1f01', '\u0301') => Some('\u1f05'),
+          ('\u1f01', '\u0342') => Some('\u1f07'),
+          ('\u1f01', '\u0345') => Some('\u1f81'),
+          ('\u1f02', '\u0345') => Some('\u1f82'),
+          ('\u1f03', '\u0345') => Some('\u1f83'),
+          ('\u1f04', '\u0345') => Some('\u1f84'),
+          ('\u1f05', '\u0345') => Some('\u1f85'),
+          ('\u1f06', '\u0345') => Some('\u1f86'),
+          ('\u1f07', '\u0345') => Some('\u1f87'),
+          ('\u1f08', '\u0300') => Some('\u1f0a'),
+          ('\u1f08', '\u0301') => Some('\u1f0c'),
+          ('\u1f08', '\u0342') => Some('\u1f0e'),
+          ('\u1f08', '\u0345') => Some('\u1f88'),
+          ('\u1f09', '\u0300') => Some('\u1f0b'),
+          ('\u1f09', '\u0301') => Some('\u1f0d'),
+          ('\u1f09', '\u0342') => Some('\u1f0f'),
+          ('\u1f09', '\u0345') => Some('\u1f89'),
+          ('\u1f0a', '\u0345') => Some('\u1f8a'),
+          ('\u1f0b', '\u0345') => Some('\u1f8b'),
+          ('\u1f0c', '\u0345') => Some('\u1f8c'),
+          ('\u1f0d', '\u0345') => Some('\u1f8d'),
+          ('\u1f0e', '\u0345') => Some('\u1f8e'),
+          ('\u1f0f', '\u0345') => Some('\u1f8f'),
+          ('\u1f10', '\u0300') => Some('\u1f12'),
+          ('\u1f10', '\u0301') => Some('\u1f14'),
+          ('\u1f11', '\u0300') => Some('\u1f13'),
+          ('\u1f11', '\u0301') => Some('\u1f15'),
+          ('\u1f18', '\u0300') => Some('\u1f1a'),
+          ('\u1f18', '\u0301') => Some('\u1f1c'),
+          ('\u1f19', '\u0300') => Some('\u1f1b'),
+          ('\u1f19', '\u0301') => Some('\u1f1d'),
+          ('\u1f20', '\u0300') => Some('\u1f22'),
+          ('\u1f20', '\u0301') => Some('\u1f24'),
+          ('\u1f20', '\u0342') => Some('\u1f26'),
+          ('\u1f20', '\u0345') => Some('\u1f90'),
+          ('\u1f21', '\u0300') => Some('\u1f23'),
+          ('\u1f21', '\u0301') => Some('\u1f25'),
+          ('\u1f21', '\u0342') => Some('\u1f27'),
+          ('\u1f21', '\u0345') => Some('\u1f91'),
+          ('\u1f22', '\u0345') => Some('\u1f92'),
+          ('\u1f23', '\u0345') => Some('\u1f93'),
+          ('\u1f24', '\u0345') => Some('\u1f94'),
+          ('\u1f25', '\u0345') => Some('\u1f95'),
+          ('\u1f26', '\u0345') => Some('\u1f96'),
+          ('\u1f27', '\u0345') => Some('\u1f97'),
+          ('\u1f28', '\u0300') => Some('\u1f2a'),
+          ('\u1f28', '\u0301') => Some('\u1f2c'),
+          ('\u1f28', '\u0342') => Some('\u1f2e'),
+          ('\u1f28', '\u0345') => Some('\u1f98'),
+          ('\u1f29', '\u0300') => Some('\u1f2b'),
+          ('\u1f29', '\u0301') => Some('\u1f2d'),
+          ('\u1f29', '\u0342') => Some('\u1f2f'),
+          ('\u1f29', '\u0345') => Some('\u1f99'),
+          ('\u1f2a', '\u0345') => Some('\u1f9a'),
+          ('\u1f2b', '\u0345') => Some('\u1f9b'),
+          ('\u1f2c', '\u0345') => Some('\u1f9c'),
+          ('\u1f2d', '\u0345') => Some('\u1f9d'),
+          ('\u1f2e', '\u0345') => Some('\u1f9e'),
+          ('\u1f2f', '\u0345') => Some('\u1f9f'),
+          ('\u1f30', '\u0300') => Some('\u1f32'),
+          ('\u1f30', '\u0301') => Some('\u1f34'),
+          ('\u1f30', '\u0342') => Some('\u1f36'),
+          ('\u1f31', '\u0300') => Some('\u1f33'),
+          ('\u1f31', '\u0301') => Some('\u1f35'),
+          ('\u1f31', '\u0342') => Some('\u1f37'),
+          ('\u1f38', '\u0300') => Some('\u1f3a'),
+          ('\u1f38', '\u0301') => Some('\u1f3c'),
+          ('\u1f38', '\u0342') => Some('\u1f3e'),
+          ('\u1f39', '\u0300') => Some('\u1f3b'),
+          ('\u1f39', '\u0301') => Some('\u1f3d'),
+          ('\u1f39', '\u0342') => Some('\u1f3f'),
+          ('\u1f40', '\u0300') => Some('\u1f42'),
+          ('\u1f40', '\u0301') => Some('\u1f44'),
+          ('\u1f41', '\u0300') => Some('\u1f43'),
+          ('\u1f41', '\u0301') => Some('\u1f45'),
+          ('\u1f48', '\u0300') => Some('\u1f4a'),
+          ('\u1f48', '\u0301') => Some('\u1f4c'),
+          ('\u1f49', '\u0300') => Some('\u1f4b'),
+          ('\u1f49', '\u0301') => Some('\u1f4d'),
+          ('\u1f50', '\u0300') => Some('\u1f52'),
+          ('\u1f50', '\u0301') => Some('\u1f54'),
+          ('\u1f50', '\u0342') => Some('\u1f56'),
+          ('\u1f51', '\u0300') => Some('\u1f53'),
+          ('\u1f51', '\u0301') => Some('\u1f55'),
+          ('\u1f51', '\u0342') => Some('\u1f57'),
+          ('\u1f59', '\u0300') => Some('\u1f5b'),
+          ('\u1f59', '\u0301') => Some('\u1f5d'),
+          ('\u1f59', '\u0342') => Some('\u1f5f'),
+          ('\u1f60', '\u0300') => Some('\u1f62'),
+          ('\u1f60', '\u0301') => Some('\u1f64'),
+          ('\u1f60', '\u0342') => Some('\u1f66'),
+          ('\u1f60', '\u0345') => Some('\u1fa0'),
+          ('\u1f61', '\u0300') => Some('\u1f63'),
+          ('\u1f61', '\u0301') => Some('\u1f65'),
+          ('\u1f61', '\u0342') => Some('\u1f67'),
+          ('\u1f61', '\u0345') => Some('\u1fa1'),
+          ('\u1f62', '\u0345') => Some('\u1fa2'),
+          ('\u1f63', '\u0345') => Some('\u1fa3'),
+          ('\u1f64', '\u0345') => Some('\u1fa4'),
+          ('\u1f65', '\u0345') => Some('\u1fa5'),
+          ('\u1f66', '\u0345') => Some('\u1fa6'),
+          ('\u1f67', '\u0345') => Some('\u1fa7'),
+          ('\u1f68', '\u0300') => Some('\u1f6a'),
+          ('\u1f68', '\u0301') => Some('\u1f6c'),
+          ('\u1f68', '\u0342') => Some('\u1f6e'),
+          ('\u1f68', '\u0345') => Some('\u1fa8'),
+          ('\u1f69', '\u0300') => Some('\u1f6b'),
+          ('\u1f69', '\u0301') => Some('\u1f6d'),
+          ('\u1f69', '\u0342') => Some('\u1f6f'),
+          ('\u1f69', '\u0345') => Some('\u1fa9'),
+          ('\u1f6a', '\u0345') => Some('\u1faa'),
+          ('\u1f6b', '\u0345') => Some('\u1fab'),
+          ('\u1f6c', '\u0345') => Some('\u1fac'),
+          ('\u1f6d', '\u0345') => Some('\u1fad'),
+          ('\u1f6e', '\u0345') => Some('\u1fae'),
+          ('\u1f6f', '\u0345') => Some('\u1faf'),
+          ('\u1f70', '\u0345') => Some('\u1fb2'),
+          ('\u1f74', '\u0345') => Some('\u1fc2'),
+          ('\u1f7c', '\u0345') => Some('\u1ff2'),
+          ('\u1fb6', '\u0345') => Some('\u1fb7'),
+          ('\u1fbf', '\u0300') => Some('\u1fcd'),
+          ('\u1fbf', '\u0301') => Some('\u1fce'),
+          ('\u1fbf', '\u0342') => Some('\u1fcf'),
+          ('\u1fc6', '\u0345') => Some('\u1fc7'),
+          ('\u1ff6', '\u0345') => Some('\u1ff7'),
+          ('\u1ffe', '\u0300') => Some('\u1fdd'),
+          ('\u1ffe', '\u0301') => Some('\u1fde'),
+          ('\u1ffe', '\u0342') => Some('\u1fdf'),
+          ('\u2190', '\u0338') => Some('\u219a'),
+          ('\u2192', '\u0338') => Some('\u219b'),
+          ('\u2194', '\u0338') => Some('\u21ae'),
+          ('\u21d0', '\u0338') => Some('\u21cd'),
+          ('\u21d2', '\u0338') => Some('\u21cf'),
+          ('\u21d4', '\u0338') => Some('\u21ce'),
+          ('\u2203', '\u0338') => Some('\u2204'),
+          ('\u2208', '\u0338') => Some('\u2209'),
+          ('\u220b', '\u0338') => Some('\u220c'),
+          ('\u2223', '\u0338') => Some('\u2224'),
+          ('\u2225', '\u0338') => Some('\u2226'),
+          ('\u223c', '\u0338') => Some('\u2241'),
+          ('\u2243', '\u0338') => Some('\u2244'),
+          ('\u2245', '\u0338') => Some('\u2247'),
+          ('\u2248', '\u0338') => Some('\u2249'),
+          ('\u224d', '\u0338') => Some('\u226d'),
+          ('\u2261', '\u0338') => Some('\u2262'),
+          ('\u2264', '\u0338') => Some('\u2270'),
+          ('\u2265', '\u0338') => Some('\u2271'),
+          ('\u2272', '\u0338') => Some('\u2274'),
+          ('\u2273', '\u0338') => Some('\u2275'),
+          ('\u2276', '\u0338') => Some('\u2278'),
+          ('\u2277', '\u0338') => Some('\u2279'),
+          ('\u227a', '\u0338') => Some('\u2280'),
+          ('\u227b', '\u0338') => Some('\u2281'),
+          ('\u227c', '\u0338') => Some('\u22e0'),
+          ('\u227d', '\u0338') => Some('\u22e1'),
+          ('\u2282', '\u0338') => Some('\u2284'),
+          ('\u2283', '\u0338') => Some('\u2285'),
+          ('\u2286', '\u0338') => Some('\u2288'),
+          ('\u2287', '\u0338') => Some('\u2289'),
+          ('\u2291', '\u0338') => Some('\u22e2'),
+          ('\u2292', '\u0338') => Some('\u22e3'),
+          ('\u22a2', '\u0338') => Some('\u22ac'),
+          ('\u22a8', '\u0338') => Some('\u22ad'),
+          ('\u22a9', '\u0338') => Some('\u22ae'),
+          ('\u22ab', '\u0338') => Some('\u22af'),
+          ('\u22b2', '\u0338') => Some('\u22ea'),
+          ('\u22b3', '\u0338') => Some('\u22eb'),
+          ('\u22b4', '\u0338') => Some('\u22ec'),
+          ('\u22b5', '\u0338') => Some('\u22ed'),
+          ('\u3046', '\u3099') => Some('\u3094'),
+          ('\u304b', '\u3099') => Some('\u304c'),
+          ('\u304d', '\u3099') => Some('\u304e'),
+          ('\u304f', '\u3099') => Some('\u3050'),
+          ('\u3051', '\u3099') => Some('\u3052'),
+          ('\u3053', '\u3099') => Some('\u3054'),
+          ('\u3055', '\u3099') => Some('\u3056'),
+          ('\u3057', '\u3099') => Some('\u3058'),
+          ('\u3059', '\u3099') => Some('\u305a'),
+          ('\u305b', '\u3099') => Some('\u305c'),
+          ('\u305d', '\u3099') => Some('\u305e'),
+          ('\u305f', '\u3099') => Some('\u3060'),
+          ('\u3061', '\u3099') => Some('\u3062'),
+          ('\u3064', '\u3099') => Some('\u3065'),
+          ('\u3066', '\u3099') => Some('\u3067'),
+          ('\u3068', '\u3099') => Some('\u3069'),
+          ('\u306f', '\u3099') => Some('\u3070'),
+          ('\u306f', '\u309a') => Some('\u3071'),
+          ('\u3072', '\u3099') => Some('\u3073'),
+          ('\u3072', '\u309a') => Some('\u3074'),
+          ('\u3075', '\u3099') => Some('\u3076'),
+          ('\u3075', '\u309a') => Some('\u3077'),
+          ('\u3078', '\u3099') => Some('\u3079'),
+          ('\u3078', '\u309a') => Some('\u307a'),
+          ('\u307b', '\u3099') => Some('\u307c'),
+          ('\u307b', '\u309a') => Some('\u307d'),
+          ('\u309d', '\u3099') => Some('\u309e'),
+          ('\u30a6', '\u3099') => Some('\u30f4'),
+          ('\u30ab', '\u3099') => Some('\u30ac'),
+          ('\u30ad', '\u3099') => Some('\u30ae'),
+          ('\u30af', '\u3099') => Some('\u30b0'),
+          ('\u30b1', '\u3099') => Some('\u30b2'),
+          ('\u30b3', '\u3099') => Some('\u30b4'),
+          ('\u30b5', '\u3099') => Some('\u30b6'),
+          ('\u30b7', '\u3099') => Some('\u30b8'),
+          ('\u30b9', '\u3099') => Some('\u30ba'),
+          ('\u30bb', '\u3099') => Some('\u30bc'),
+          ('\u30bd', '\u3099') => Some('\u30be'),
+          ('\u30bf', '\u3099') => Some('\u30c0'),
+          ('\u30c1', '\u3099') => Some('\u30c2'),
+          ('\u30c4', '\u3099') => Some('\u30c5'),
+          ('\u30c6', '\u3099') => Some('\u30c7'),
+          ('\u30c8', '\u3099') => Some('\u30c9'),
+          ('\u30cf', '\u3099') => Some('\u30d0'),
+          ('\u30cf', '\u309a') => Some('\u30d1'),
+          ('\u30d2', '\u3099') => Some('\u30d3'),
+          ('\u30d2', '\u309a') => Some('\u30d4'),
+          ('\u30d5', '\u3099') => Some('\u30d6'),
+          ('\u30d5', '\u309a') => Some('\u30d7'),
+          ('\u30d8', '\u3099') => Some('\u30d9'),
+          ('\u30d8', '\u309a') => Some('\u30da'),
+          ('\u30db', '\u3099') => Some('\u30dc'),
+          ('\u30db', '\u309a') => Some('\u30dd'),
+          ('\u30ef', '\u3099') => Some('\u30f7'),
+          ('\u30f0', '\u3099') => Some('\u30f8'),
+          ('\u30f1', '\u3099') => Some('\u30f9'),
+          ('\u30f2', '\u3099') => Some('\u30fa'),
+          ('\u30fd', '\u3099') => Some('\u30fe'),
+          ('\U00011099', '\U000110ba') => Some('\U0001109a'),
+          ('\U0001109b', '\U000110ba') => Some('\U0001109c'),
+          ('\U000110a5', '\U000110ba') => Some('\U000110ab'),
+          ('\U00011131', '\U00011127') => Some('\U0001112e'),
+          ('\U00011132', '\U00011127') => Some('\U0001112f'),
+          ('\U00011347', '\U0001133e') => Some('\U0001134b'),
+          ('\U00011347', '\U00011357') => Some('\U0001134c'),
+          ('\U000114b9', '\U000114b0') => Some('\U000114bc'),
+          ('\U000114b9', '\U000114ba') => Some('\U000114bb'),
+          ('\U000114b9', '\U000114bd') => Some('\U000114be'),
+          ('\U000115b8', '\U000115af') => Some('\U000115ba'),
+          ('\U000115b9', '\U000115af') => Some('\U000115bb'),
+          ('\U00011935', '\U00011930') => Some('\U00011938'),
+          _ => None
+        }
+    }
+
+    pure fn d(c: char, f: fn(char), k: bool) {
+        // 7-bit ASCII never decomposes
+        if c <= '\x7f' { f(c); return; }
+
+        let s = c as u32;
+        if s >= S_BASE && s < S_BASE + S_COUNT {
+            let s_index = s - S_BASE;
+            f((L_BASE + s_index / N_COUNT) as char);
+            f((V_BASE + (s_index % N_COUNT) / T_COUNT) as char);
+            if s_index % T_COUNT != 0 {
+                f((T_BASE + s_index % T_COUNT) as char);
+            }
+            return;
+        }
+
+        if k && compatibility(c, f) { return; }
+        if canonical(c, f) { return; }
+        f(c);
+    }
+
+    pure fn canonical(c: char, f: fn(char)) -> bool {
+        match c {
+          '\xc0' => { f('\x41'); f('\u0300'); }
+          '\xc1' => { f('\x41'); f('\u0301'); }
+          '\xc2' => { f('\x41'); f('\u0302'); }
+          '\xc3' => { f('\x41'); f('\u0303'); }
+          '\xc4' => { f('\x41'); f('\u0308'); }
+          '\xc5' => { f('\x41'); f('\u030a'); }
+          '\xc7' => { f('\x43'); f('\u0327'); }
+          '\xc8' => { f('\x45'); f('\u0300'); }
+          '\xc9' => { f('\x45'); f('\u0301'); }
+          '\xca' => { f('\x45'); f('\u0302'); }
+          '\xcb' => { f('\x45'); f('\u0308'); }
+          '\xcc' => { f('\x49'); f('\u0300'); }
+          '\xcd' => { f('\x49'); f('\u0301'); }
+          '\xce' => { f('\x49'); f('\u0302'); }
+          '\xcf' => { f('\x49'); f('\u0308'); }
+          '\xd1' => { f('\x4e'); f('\u0303'); }
+          '\xd2' => { f('\x4f'); f('\u0300'); }
+          '\xd3' => { f('\x4f'); f('\u0301'); }
+          '\xd4' => { f('\x4f'); f('\u0302'); }
+          '\xd5' => { f('\x4f'); f('\u0303'); }
+          '\xd6' => { f('\x4f'); f('\u0308'); }
+          '\xd9' => { f('\x55'); f('\u0300'); }
+          '\xda' => { f('\x55'); f('\u0301'); }
+          '\xdb' => { f('\x55'); f('\u0302'); }
+          '\xdc' => { f('\x55'); f('\u0308'); }
+          '\xdd' => { f('\x59'); f('\u0301'); }
+          '\xe0' => { f('\x61'); f('\u0300'); }
+          '\xe1' => { f('\x61'); f('\u0301'); }
+          '\xe2' => { f('\x61'); f('\u0302'); }
+          '\xe3' => { f('\x61'); f('\u0303'); }
+          '\xe4' => { f('\x61'); f('\u0308'); }
+          '\xe5' => { f('\x61'); f('\u030a'); }
+          '\xe7' => { f('\x63'); f('\u0327'); }
+          '\xe8' => { f('\x65'); f('\u0300'); }
+          '\xe9' => { f('\x65'); f('\u0301'); }
+          '\xea' => { f('\x65'); f('\u0302'); }
+          '\xeb' => { f('\x65'); f('\u0308'); }
+          '\xec' => { f('\x69'); f('\u0300'); }
+          '\xed' => { f('\x69'); f('\u0301'); }
+          '\xee' => { f('\x69'); f('\u0302'); }
+          '\xef' => { f('\x69'); f('\u0308'); }
+          '\xf1' => { f('\x6e'); f('\u0303'); }
+          '\xf2' => { f('\x6f'); f('\u0300'); }
+          '\xf3' => { f('\x6f'); f('\u0301'); }
+          '\xf4' => { f('\x6f'); f('\u0302'); }
+          '\xf5' => { f('\x6f'); f('\u0303'); }
+          '\xf6' => { f('\x6f'); f('\u0308'); }
+          '\xf9' => { f('\x75'); f('\u0300'); }
+          '\xfa' => { f('\x75'); f('\u0301'); }
+          '\xfb' => { f('\x75'); f('\u0302'); }
+          '\xfc' => { f('\x75'); f('\u0308'); }
+          '\xfd' => { f('\x79'); f('\u0301'); }
+          '\xff' => { f('\x79'); f('\u0308'); }
+          '\u0100' => { f('\x41'); f('\u0304'); }
+          '\u0101' => { f('\x61'); f('\u0304'); }
+          '\u0102' => { f('\x41'); f('\u0306'); }
+          '\u0103' => { f('\x61'); f('\u0306'); }
+          '\u0104' => { f('\x41'); f('\u0328'); }
+          '\u0105' => { f('\x61'); f('\u0328'); }
+          '\u0106' => { f('\x43'); f('\u0301'); }
+          '\u0107' => { f('\x63'); f('\u0301'); }
+          '\u0108' => { f('\x43'); f('\u0302'); }
+          '\u0109' => { f('\x63'); f('\u0302'); }
+          '\u010a' => { f('\x43'); f('\u0307'); }
+          '\u010b' => { f('\x63'); f('\u0307'); }
+          '\u010c' => { f('\x43'); f('\u030c'); }
+          '\u010d' => { f('\x63'); f('\u030c'); }
+          '\u010e' => { f('\x44'); f('\u030c'); }
+          '\u010f' => { f('\x64'); f('\u030c'); }
+          '\u0112' => { f('\x45'); f('\u0304'); }
+          '\u0113' => { f('\x65'); f('\u0304'); }
+          '\u0114' => { f('\x45'); f('\u0306'); }
+          '\u0115' => { f('\x65'); f('\u0306'); }
+          '\u0116' => { f('\x45'); f('\u0307'); }
+          '\u0117' => { f('\x65'); f('\u0307'); }
+          '\u0118' => { f('\x45'); f('\u0328'); }
+          '\u0119' => { f('\x65'); f('\u0328'); }
+          '\u011a' => { f('\x45'); f('\u030c'); }
+          '\u011b' => { f('\x65'); f('\u030c'); }
+          '\u011c' => { f('\x47'); f('\u0302'); }
+          '\u011d' => { f('\x67'); f('\u0302'); }
+          '\u011e' => { f('\x47'); f('\u0306'); }
+          '\u011f' => { f('\x67'); f('\u0306'); }
+          '\u0120' => { f('\x47'); f('\u0307'); }
+          '\u0121' => { f('\x67'); f('\u0307'); }
+          '\u0122' => { f('\x47'); f('\u0327'); }
+          '\u0123' => { f('\x67'); f('\u0327'); }
+          '\u0124' => { f('\x48'); f('\u0302'); }
+          '\u0125' => { f('\x68'); f('\u0302'); }
+          '\u0128' => { f('\x49'); f('\u0303'); }
+          '\u0129' => { f('\x69'); f('\u0303'); }
+          '\u012a' => { f('\x49'); f('\u0304'); }
+          '\u012b' => { f('\x69'); f('\u0304'); }
+          '\u012c' => { f('\x49'); f('\u0306'); }
+          '\u012d' => { f('\x69'); f('\u0306'); }
+          '\u012e' => { f('\x49'); f('\u0328'); }
+          '\u012f' => { f('\x69'); f('\u0328'); }
+          '\u0130' => { f('\x49'); f('\u0307'); }
+          '\u0134' => { f('\x4a'); f('\u0302'); }
+          '\u0135' => { f('\x6a'); f('\u0302'); }
+          '\u0136' => { f('\x4b'); f('\u0327'); }
+          '\u0137' => { f('\x6b'); f('\u0327'); }
+          '\u0139' => { f('\x4c'); f('\u0301'); }
+          '\u013a' => { f('\x6c'); f('\u0301'); }
+          '\u013b' => { f('\x4c'); f('\u0327'); }
+          '\u013c' => { f('\x6c'); f('\u0327'); }
+          '\u013d' => { f('\x4c'); f('\u030c'); }
+          '\u013e' => { f('\x6c'); f('\u030c'); }
+          '\u0143' => { f('\x4e'); f('\u0301'); }
+          '\u0144' => { f('\x6e'); f('\u0301'); }
+          '\u0145' => { f('\x4e'); f('\u0327'); }
+          '\u0146' => { f('\x6e'); f('\u0327'); }
+          '\u0147' => { f('\x4e'); f('\u030c'); }
+          '\u0148' => { f('\x6e'); f('\u030c'); }
+          '\u014c' => { f('\x4f'); f('\u0304'); }
+          '\u014d' => { f('\x6f'); f('\u0304'); }
+          '\u014e' => { f('\x4f'); f('\u0306'); }
+          '\u014f' => { f('\x6f'); f('\u0306'); }
+          '\u0150' => { f('\x4f'); f('\u030b'); }
+          '\u0151' => { f('\x6f'); f('\u030b'); }
+          '\u0154' => { f('\x52'); f('\u0301'); }
+          '\u0155' => { f('\x72'); f('\u0301'); }
+          '\u0156' => { f('\x52'); f('\u0327'); }
+          '\u0157' => { f('\x72'); f('\u0327'); }
+          '\u0158' => { f('\x52'); f('\u030c'); }
+          '\u0159' => { f('\x72'); f('\u030c'); }
+          '\u015a' => { f('\x53'); f('\u0301'); }
+          '\u015b' => { f('\x73'); f('\u0301'); }
+          '\u015c' => { f('\x53'); f('\u0302'); }
+          '\u015d' => { f('\x73'); f('\u0302'); }
+          '\u015e' => { f('\x53'); f('\u0327'); }
+          '\u015f' => { f('\x73'); f('\u0327'); }
+          '\u0160' => { f('\x53'); f('\u030c'); }
+          '\u0161' => { f('\x73'); f('\u030c'); }
+          '\u0162' => { f('\x54'); f('\u0327'); }
+          '\u0163' => { f('\x74'); f('\u0327'); }
+          '\u0164' => { f('\x54'); f('\u030c'); }
+          '\u0165' => { f('\x74'); f('\u030c'); }
+          '\u0168' => { f('\x55'); f('\u0303'); }
+          '\u0169' => { f('\x75'); f('\u0303'); }
+          '\u016a' => { f('\x55'); f('\u0304'); }
+          '\u016b' => { f('\x75'); f('\u0304'); }
+          '\u016c' => { f('\x55'); f('\u0306'); }
+          '\u016d' => { f('\x75'); f('\u0306'); }
+          '\u016e' => { f('\x55'); f('\u030a'); }
+          '\u016f' => { f('\x75'); f('\u030a'); }
+          '\u0170' => { f('\x55'); f('\u030b'); }
+          '\u0171' => { f('\x75'); f('\u030b'); }
+          '\u0172' => { f('\x55'); f('\u0328'); }
+          '\u0173' => { f('\x75'); f('\u0328'); }
+          '\u0174' => { f('\x57'); f('\u0302'); }
+          '\u0175' => { f('\x77'); f('\u0302'); }
+          '\u0176' => { f('\x59'); f('\u0302'); }
+          '\u0177' => { f('\x79'); f('\u0302'); }
+          '\u0178' => { f('\x59'); f('\u0308'); }
+          '\u0179' => { f('\x5a'); f('\u0301'); }
+          '\u017a' => { f('\x7a'); f('\u0301'); }
+          '\u017b' => { f('\x5a'); f('\u0307'); }
+          '\u017c' => { f('\x7a'); f('\u0307'); }
+          '\u017d' => { f('\x5a'); f('\u030c'); }
+          '\u017e' => { f('\x7a'); f('\u030c'); }
+          '\u01a0' => { f('\x4f'); f('\u031b'); }
+          '\u01a1' => { f('\x6f'); f('\u031b'); }
+          '\u01af' => { f('\x55'); f('\u031b'); }
+          '\u01b0' => { f('\x75'); f('\u031b'); }
+          '\u01cd' => { f('\x41'); f('\u030c'); }
+          '\u01ce' => { f('\x61'); f('\u030c'); }
+          '\u01cf' => { f('\x49'); f('\u030c'); }
+          '\u01d0' => { f('\x69'); f('\u030c'); }
+          '\u01d1' => { f('\x4f'); f('\u030c'); }
+          '\u01d2' => { f('\x6f'); f('\u030c'); }
+          '\u01d3' => { f('\x55'); f('\u030c'); }
+          '\u01d4' => { f('\x75'); f('\u030c'); }
+          '\u01d5' => { f('\x55'); f('\u0308'); f('\u0304'); }
+          '\u01d6' => { f('\x75'); f('\u0308'); f('\u0304'); }
+          '\u01d7' => { f('\x55'); f('\u0308'); f('\u0301'); }
+          '\u01d8' => { f('\x75'); f('\u0308'); f('\u0301'); }
+          '\u01d9' => { f('\x55'); f('\u0308'); f('\u030c'); }
+          '\u01da' => { f('\x75'); f('\u0308'); f('\u030c'); }
+          '\u01db' => { f('\x55'); f('\u0308'); f('\u0300'); }
+          '\u01dc' => { f('\x75'); f('\u0308'); f('\u0300'); }
+          '\u01de' => { f('\x41'); f('\u0308'); f('\u0304'); }
+          '\u01df' => { f('\x61'); f('\u0308'); f('\u0304'); }
+          '\u01e0' => { f('\x41'); f('\u0307'); f('\u0304'); }
+          '\u01e1' => { f('\x61'); f('\u0307'); f('\u0304'); }
+          '\u01e2' => { f('\xc6'); f('\u0304'); }
+          '\u01e3' => { f('\xe6'); f('\u0304'); }
+          '\u01e6' => { f('\x47'); f('\u030c'); }
+          '\u01e7' => { f('\x67'); f('\u030c'); }
+          '\u01e8' => { f('\x4b'); f('\u030c'); }
+          '\u01e9' => { f('\x6b'); f('\u030c'); }
+          '\u01ea' => { f('\x4f'); f('\u0328'); }
+          '\u01eb' => { f('\x6f'); f('\u0328'); }
+          '\u01ec' => { f('\x4f'); f('\u0328'); f('\u0304'); }
+          '\u01ed' => { f('\x6f'); f('\u0328'); f('\u0304'); }
+          '\u01ee' => { f('\u01b7'); f('\u030c'); }
+          '\u01ef' => { f('\u0292'); f('\u030c'); }
+          '\u01f0' => { f('\x6a'); f('\u030c'); }
+          '\u01f4' => { f('\x47'); f('\u0301'); }
+          '\u01f5' => { f('\x67'); f('\u0301'); }
+          '\u01f8' => { f('\x4e'); f('\u0300'); }
+          '\u01f9' => { f('\x6e'); f('\u0300'); }
+          '\u01fa' => { f('\x41'); f('\u030a'); f('\u0301'); }
+          '\u01fb' => { f('\x61'); f('\u030a'); f('\u0301'); }
+          '\u01fc' => { f('\xc6'); f('\u0301'); }
+          '\u01fd' => { f('\xe6'); f('\u0301'); }
+          '\u01fe' => { f('\xd8'); f('\u0301'); }
+          '\u01ff' => { f('\xf8'); f('\u0301'); }
+          '\u0200' => { f('\x41'); f('\u030f'); }
+          '\u0201' => { f('\x61'); f('\u030f'); }
+          '\u0202' => { f('\x41'); f('\u0311'); }
+          '\u0203' => { f('\x61'); f('\u0311'); }
+          '\u0204' => { f('\x45'); f('\u030f'); }
+          '\u0205' => { f('\x65'); f('\u030f'); }
+          '\u0206' => { f('\x45'); f('\u0311'); }
+          '\u0207' => { f('\x65'); f('\u0311'); }
+          '\u0208' => { f('\x49'); f('\u030f'); }
+          '\u0209' => { f('\x69'); f('\u030f'); }
+          '\u020a' => { f('\x49'); f('\u0311'); }
+          '\u020b' => { f('\x69'); f('\u0311'); }
+          '\u020c' => { f('\x4f'); f('\u030f'); }
+          '\u020d' => { f('\x6f'); f('\u030f'); }
+          '\u020e' => { f('\x4f'); f('\u0311'); }
+          '\u020f' => { f('\x6f'); f('\u0311'); }
+          '\u0210' => { f('\x52'); f('\u030f'); }
+          '\u0211' => { f('\x72'); f('\u030f'); }
+          '\u0212' => { f('\x52'); f('\u0311'); }
+          '\u0213' => { f('\x72'); f('\u0311'); }
+          '\u0214' => { f('\x55'); f('\u030f'); }
+          '\u0215' => { f('\x75'); f('\u030f'); }
+          '\u0216' => { f('\x55'); f('\u0311'); }
+          '\u0217' => { f('\x75'); f('\u0311'); }
+          '\u0218' => { f('\x53'); f('\u0326'); }
+          '\u0219' => { f('\x73'); f('\u0326'); }
+          '\u021a' => { f('\x54'); f('\u0326'); }
+          '\u021b' => { f('\x74'); f('\u0326'); }
+          '\u021e' => { f('\x48'); f('\u030c'); }
+          '\u021f' => { f('\x68'); f('\u030c'); }
+          '\u0226' => { f('\x41'); f('\u0307'); }
+          '\u0227' => { f('\x61'); f('\u0307'); }
+          '\u0228' => { f('\x45'); f('\u0327'); }
+          '\u0229' => { f('\x65'); f('\u0327'); }
+          '\u022a' => { f('\x4f'); f('\u0308'); f('\u0304'); }
+          '\u022b' => { f('\x6f'); f('\u0308'); f('\u0304'); }
+          '\u022c' => { f('\x4f'); f('\u0303'); f('\u0304'); }
+          '\u022d' => { f('\x6f'); f('\u0303'); f('\u0304'); }
+          '\u022e' => { f('\x4f'); f('\u0307'); }
+          '\u022f' => { f('\x6f'); f('\u0307'); }
+          '\u0230' => { f('\x4f'); f('\u0307'); f('\u0304'); }
+          '\u0231' => { f('\x6f'); f('\u0307'); f('\u0304'); }
+          '\u0232' => { f('\x59'); f('\u0304'); }
+          '\u0233' => { f('\x79'); f('\u0304'); }
+          '\u0340' => { f('\u0300'); }
+          '\u0341' => { f('\u0301'); }
+          '\u0343' => { f('\u0313'); }
+          '\u0344' => { f('\u0308'); f('\u0301'); }
+          '\u0374' => { f('\u02b9'); }
+          '\u037e' => { f('\x3b'); }
+          '\u0385' => { f('\xa8'); f('\u0301'); }
+          '\u0386' => { f('\u0391'); f('\u0301'); }
+          '\u0387' => { f('\xb7'); }
+          '\u0388' => { f('\u0395'); f('\u0301'); }
+          '\u0389' => { f('\u0397'); f('\u0301'); }
+          '\u038a' => { f('\u0399'); f('\u0301'); }
+          '\u038c' => { f('\u039f'); f('\u0301'); }
+          '\u038e' => { f('\u03a5'); f('\u0301'); }
+          '\u038f' => { f('\u03a9'); f('\u0301'); }
+          '\u0390' => { f('\u03b9'); f('\u0308'); f('\u0301'); }
+          '\u03aa' => { f('\u0399'); f('\u0308'); }
+          '\u03ab' => { f('\u03a5'); f('\u0308'); }
+          '\u03ac' => { f('\u03b1'); f('\u0301'); }
+          '\u03ad' => { f('\u03b5'); f('\u0301'); }
+          '\u03ae' => { f('\u03b7'); f('\u0301'); }
+          '\u03af' => { f('\u03b9'); f('\u0301'); }
+          '\u03b0' => { f('\u03c5'); f('\u0308'); f('\u0301'); }
+          '\u03ca' => { f('\u03b9'); f('\u0308'); }
+          '\u03cb' => { f('\u03c5'); f('\u0308'); }
+          '\u03cc' => { f('\u03bf'); f('\u0301'); }
+          '\u03cd' => { f('\u03c5'); f('\u0301'); }
+          '\u03ce' => { f('\u03c9'); f('\u0301'); }
+          '\u03d3' => { f('\u03d2'); f('\u0301'); }
+          '\u03d4' => { f('\u03d2'); f('\u0308'); }
+          '\u0400' => { f('\u0415'); f('\u0300'); }
+          '\u0401' => { f('\u0415'); f('\u0308'); }
+          '\u0403' => { f('\u0413'); f('\u0301'); }
+          '\u0407' => { f('\u0406'); f('\u0308'); }
+          '\u040c' => { f('\u041a'); f('\u0301'); }
+          '\u040d' => { f('\u0418'); f('\u0300'); }
+          '\u040e' => { f('\u0423'); f('\u0306'); }
+          '\u0419' => { f('\u0418'); f('\u0306'); }
+          '\u0439' => { f('\u0438'); f('\u0306'); }
+          '\u0450' => { f('\u0435'); f('\u0300'); }
+          '\u0451' => { f('\u0435'); f('\u0308'); }
+          '\u0453' => { f('\u0433'); f('\u0301'); }
+          '\u0457' => { f('\u0456'); f('\u0308'); }
+          '\u045c' => { f('\u043a'); f('\u0301'); }
+          '\u045d' => { f('\u0438'); f('\u0300'); }
+          '\u045e' => { f('\u0443'); f('\u0306'); }
+          '\u0476' => { f('\u0474'); f('\u030f'); }
+          '\u0477' => { f('\u0475'); f('\u030f'); }
+          '\u04c1' => { f('\u0416'); f('\u0306'); }
+          '\u04c2' => { f('\u0436'); f('\u0306'); }
+          '\u04d0' => { f('\u0410'); f('\u0306'); }
+          '\u04d1' => { f('\u0430'); f('\u0306'); }
+          '\u04d2' => { f('\u0410'); f('\u0308'); }
+          '\u04d3' => { f('\u0430'); f('\u0308'); }
+          '\u04d6' => { f('\u0415'); f('\u0306'); }
+          '\u04d7' => { f('\u0435'); f('\u0306'); }
+          '\u04da' => { f('\u04d8'); f('\u0308'); }
+          '\u04db' => { f('\u04d9'); f('\u0308'); }
+          '\u04dc' => { f('\u0416'); f('\u0308'); }
+          '\u04dd' => { f('\u0436'); f('\u0308'); }
+          '\u04de' => { f('\u0417'); f('\u0308'); }
+          '\u04df' => { f('\u0437'); f('\u0308'); }
+          '\u04e2' => { f('\u0418'); f('\u0304'); }
+          '\u04e3' => { f('\u0438'); f('\u0304'); }
+          '\u04e4' => { f('\u0418'); f('\u0308'); }
+          '\u04e5' => { f('\u0438'); f('\u0308'); }
+          '\u04e6' => { f('\u041e'); f('\u0308'); }
+          '\u04e7' => { f('\u043e'); f('\u0308'); }
+          '\u04ea' => { f('\u04e8'); f('\u0308'); }
+          '\u04eb' => { f('\u04e9'); f('\u0308'); }
+          '\u04ec' => { f('\u042d'); f('\u0308'); }
+          '\u04ed' => { f('\u044d'); f('\u0308'); }
+          '\u04ee' => { f('\u0423'); f('\u0304'); }
+          '\u04ef' => { f('\u0443'); f('\u0304'); }
+          '\u04f0' => { f('\u0423'); f('\u0308'); }
+          '\u04f1' => { f('\u0443'); f('\u0308'); }
+          '\u04f2' => { f('\u0423'); f('\u030b'); }
+          '\u04f3' => { f('\u0443'); f('\u030b'); }
+          '\u04f4' => { f('\u0427'); f('\u0308'); }
+          '\u04f5' => { f('\u0447'); f('\u0308'); }
+          '\u04f8' => { f('\u042b'); f('\u0308'); }
+          '\u04f9' => { f('\u044b'); f('\u0308'); }
+          '\u0622' => { f('\u0627'); f('\u0653'); }
+          '\u0623' => { f('\u0627'); f('\u0654'); }
+          '\u0624' => { f('\u0648'); f('\u0654'); }
+          '\u0625' => { f('\u0627'); f('\u0655'); }
+          '\u0626' => { f('\u064a'); f('\u0654'); }
+          '\u06c0' => { f('\u06d5'); f('\u0654'); }
+          '\u06c2' => { f('\u06c1'); f('\u0654'); }
+          '\u06d3' => { f('\u06d2'); f('\u0654'); }
+          '\u0929' => { f('\u0928'); f('\u093c'); }
+          '\u0931' => { f('\u0930'); f('\u093c'); }
+          '\u0934' => { f('\u0933'); f('\u093c'); }
+          '\u0958' => { f('\u0915'); f('\u093c'); }
+          '\u0959' => { f('\u0916'); f('\u093c'); }
+          '\u095a' => { f('\u0917'); f('\u093c'); }
+          '\u095b' => { f('\u091c'); f('\u093c'); }
+          '\u095c' => { f('\u0921'); f('\u093c'); }
+          '\u095d' => { f('\u0922'); f('\u093c'); }
+          '\u095e' => { f('\u092b'); f('\u093c'); }
+          '\u095f' => { f('\u092f'); f('\u093c'); }
+          '\u09cb' => { f('\u09c7'); f('\u09be'); }
+          '\u09cc' => { f('\u09c7'); f('\u09d7'); }
+          '\u09dc' => { f('\u09a1'); f('\u09bc'); }
+          '\u09dd' => { f('\u09a2'); f('\u09bc'); }
+          '\u09df' => { f('\u09af'); f('\u09bc'); }
+          '\u0a33' => { f('\u0a32'); f('\u0a3c'); }
+          '\u0a36' => { f('\u0a38'); f('\u0a3c'); }
+          '\u0a59' => { f('\u0a16'); f('\u0a3c'); }
+          '\u0a5a' => { f('\u0a17'); f('\u0a3c'); }
+          '\u0a5b' => { f('\u0a1c'); f('\u0a3c'); }
+          '\u0a5e' => { f('\u0a2b'); f('\u0a3c'); }
+          '\u0b48' => { f('\u0b47'); f('\u0b56'); }
+          '\u0b4b' => { f('\u0b47'); f('\u0b3e'); }
+          '\u0b4c' => { f('\u0b47'); f('\u0b57'); }
+          '\u0b5c' => { f('\u0b21'); f('\u0b3c'); }
+          '\u0b5d' => { f('\u0b22'); f('\u0b3c'); }
+          '\u0b94' => { f('\u0b92'); f('\u0bd7'); }
+          '\u0bca' => { f('\u0bc6'); f('\u0bbe'); }
+          '\u0bcb' => { f('\u0bc7'); f('\u0bbe'); }
+          '\u0bcc' => { f('\u0bc6'); f('\u0bd7'); }
+          '\u0c48' => { f('\u0c46'); f('\u0c56'); }
+          '\u0cc0' => { f('\u0cbf'); f('\u0cd5'); }
+          '\u0cc7' => { f('\u0cc6'); f('\u0cd5'); }
+          '\u0cc8' => { f('\u0cc6'); f('\u0cd6'); }
+          '\u0cca' => { f('\u0cc6'); f('\u0cc2'); }
+          '\u0ccb' => { f('\u0cc6'); f('\u0cc2'); f('\u0cd5'); }
+          '\u0d4a' => { f('\u0d46'); f('\u0d3e'); }
+          '\u0d4b' => { f('\u0d47'); f('\u0d3e'); }
+          '\u0d4c' => { f('\u0d46'); f('\u0d57'); }
+          '\u0dda' => { f('\u0dd9'); f('\u0dca'); }
+          '\u0ddc' => { f('\u0dd9'); f('\u0dcf'); }
+          '\u0ddd' => { f('\u0dd9'); f('\u0dcf'); f('\u0dca'); }
+          '\u0dde' => { f('\u0dd9'); f('\u0ddf'); }
+          '\u0f43' => { f('\u0f42'); f('\u0fb7'); }
+          '\u0f4d' => { f('\u0f4c'); f('\u0fb7'); }
+          '\u0f52' => { f('\u0f51'); f('\u0fb7'); }
+          '\u0f57' => { f('\u0f56'); f('\u0fb7'); }
+          '\u0f5c' => { f('\u0f5b'); f('\u0fb7'); }
+          '\u0f69' => { f('\u0f40'); f('\u0fb5'); }
+          '\u0f73' => { f('\u0f71'); f('\u0f72'); }
+          '\u0f75' => { f('\u0f71'); f('\u0f74'); }
+          '\u0f76' => { f('\u0fb2'); f('\u0f80'); }
+          '\u0f78' => { f('\u0fb3'); f('\u0f80'); }
+          '\u0f81' => { f('\u0f71'); f('\u0f80'); }
+          '\u0f93' => { f('\u0f92'); f('\u0fb7'); }
+          '\u0f9d' => { f('\u0f9c'); f('\u0fb7'); }
+          '\u0fa2' => { f('\u0fa1'); f('\u0fb7'); }
+          '\u0fa7' => { f('\u0fa6'); f('\u0fb7'); }
+          '\u0fac' => { f('\u0fab'); f('\u0fb7'); }
+          '\u0fb9' => { f('\u0f90'); f('\u0fb5'); }
+          '\u1026' => { f('\u1025'); f('\u102e'); }
+          '\u1b06' => { f('\u1b05'); f('\u1b35'); }
+          '\u1b08' => { f('\u1b07'); f('\u1b35'); }
+          '\u1b0a' => { f('\u1b09'); f('\u1b35'); }
+          '\u1b0c' => { f('\u1b0b'); f('\u1b35'); }
+          '\u1b0e' => { f('\u1b0d'); f('\u1b35'); }
+          '\u1b12' => { f('\u1b11'); f('\u1b35'); }
+          '\u1b3b' => { f('\u1b3a'); f('\u1b35'); }
+          '\u1b3d' => { f('\u1b3c'); f('\u1b35'); }
+          '\u1b40' => { f('\u1b3e'); f('\u1b35'); }
+          '\u1b41' => { f('\u1b3f'); f('\u1b35'); }
+          '\u1b43' => { f('\u1b42'); f('\u1b35'); }
+          '\u1e00' => { f('\x41'); f('\u0325'); }
+          '\u1e01' => { f('\x61'); f('\u0325'); }
+          '\u1e02' => { f('\x42'); f('\u0307'); }
+          '\u1e03' => { f('\x62'); f('\u0307'); }
+          '\u1e04' => { f('\x42'); f('\u0323'); }
+          '\u1e05' => { f('\x62'); f('\u0323'); }
+          '\u1e06' => { f('\x42'); f('\u0331'); }
+          '\u1e07' => { f('\x62'); f('\u0331'); }
+          '\u1e08' => { f('\x43'); f('\u0327'); f('\u0301'); }
+          '\u1e09' => { f('\x63'); f('\u0327'); f('\u0301'); }
+          '\u1e0a' => { f('\x44'); f('\u0307'); }
+          '\u1e0b' => { f('\x64'); f('\u0307'); }
+          '\u1e0c' => { f('\x44'); f('\u0323'); }
+          '\u1e0d' => { f('\x64'); f('\u0323'); }
+          '\u1e0e' => { f('\x44'); f('\u0331'); }
+          '\u1e0f' => { f('\x64'); f('\u0331'); }
+          '\u1e10' => { f('\x44'); f('\u0327'); }
+          '\u1e11' => { f('\x64'); f('\u0327'); }
+          '\u1e12' => { f('\x44'); f('\u032d'); }
+          '\u1e13' => { f('\x64'); f('\u032d'); }
+          '\u1e14' => { f('\x45'); f('\u0304'); f('\u0300'); }
+          '\u1e15' => { f('\x65'); f('\u0304'); f('\u0300'); }
+          '\u1e16' => { f('\x45'); f('\u0304'); f('\u0301'); }
+          '\u1e17' => { f('\x65'); f('\u0304'); f('\u0301'); }
+          '\u1e18' => { f('\x45'); f('\u032d'); }
+          '\u1e19' => { f('\x65'); f('\u032d'); }
+          '\u1e1a' => { f('\x45'); f('\u0330'); }
+          '\u1e1b' => { f('\x65'); f('\u0330'); }
+          '\u1e1c' => { f('\x45'); f('\u0327'); f('\u0306'); }
+          '\u1e1d' => { f('\x65'); f('\u0327'); f('\u0306'); }
+          '\u1e1e' => { f('\x46'); f('\u0307'); }
+          '\u1e1f' => { f('\x66'); f('\u0307'); }
+          '\u1e20' => { f('\x47'); f('\u0304'); }
+          '\u1e21' => { f('\x67'); f('\u0304'); }
+          '\u1e22' => { f('\x48'); f('\u0307'); }
+          '\u1e23' => { f('\x68'); f('\u0307'); }
+          '\u1e24' => { f('\x48'); f('\u0323'); }
+          '\u1e25' => { f('\x68'); f('\u0323'); }
+          '\u1e26' => { f('\x48'); f('\u0308'); }
+          '\u1e27' => { f('\x68'); f('\u0308'); }
+          '\u1e28' => { f('\x48'); f('\u0327'); }
+          '\u1e29' => { f('\x68'); f('\u0327'); }
+          '\u1e2a' => { f('\x48'); f('\u032e'); }
+          '\u1e2b' => { f('\x68'); f('\u032e'); }
+          '\u1e2c' => { f('\x49'); f('\u0330'); }
+          '\u1e2d' => { f('\x69'); f('\u0330'); }
+          '\u1e2e' => { f('\x49'); f('\u0308'); f('\u0301'); }
+          '\u1e2f' => { f('\x69'); f('\u0308'); f('\u0301'); }
+          '\u1e30' => { f('\x4b'); f('\u0301'); }
+          '\u1e31' => { f('\x6b'); f('\u0301'); }
+          '\u1e32' => { f('\x4b'); f('\u0323'); }
+          '\u1e33' => { f('\x6b'); f('\u0323'); }
+          '\u1e34' => { f('\x4b'); f('\u0331'); }
+          '\u1e35' => { f('\x6b'); f('\u0331'); }
+          '\u1e36' => { f('\x4c'); f('\u0323'); }
+          '\u1e37' => { f('\x6c'); f('\u0323'); }
+          '\u1e38' => { f('\x4c'); f('\u0323'); f('\u0304'); }
+          '\u1e39' => { f('\x6c'); f('\u0323'); f('\u0304'); }
+          '\u1e3a' => { f('\x4c'); f('\u0331'); }
+          '\u1e3b' => { f('\x6c'); f('\u0331'); }
+          '\u1e3c' => { f('\x4c'); f('\u032d'); }
+          '\u1e3d' => { f('\x6c'); f('\u032d'); }
+          '\u1e3e' => { f('\x4d'); f('\u0301'); }
+          '\u1e3f' => { f('\x6d'); f('\u0301'); }
+          '\u1e40' => { f('\x4d'); f('\u0307'); }
+          '\u1e41' => { f('\x6d'); f('\u0307'); }
+          '\u1e42' => { f('\x4d'); f('\u0323'); }
+          '\u1e43' => { f('\x6d'); f('\u0323'); }
+          '\u1e44' => { f('\x4e'); f('\u0307'); }
+          '\u1e45' => { f('\x6e'); f('\u0307'); }
+          '\u1e46' => { f('\x4e'); f('\u0323'); }
+          '\u1e47' => { f('\x6e'); f('\u0323'); }
+          '\u1e48' => { f('\x4e'); f('\u0331'); }
+          '\u1e49' => { f('\x6e'); f('\u0331'); }
+          '\u1e4a' => { f('\x4e'); f('\u032d'); }
+          '\u1e4b' => { f('\x6e'); f('\u032d'); }
+          '\u1e4c' => { f('\x4f'); f('\u0303'); f('\u0301'); }
+          '\u1e4d' => { f('\x6f'); f('\u0303'); f('\u0301'); }
+          '\u1e4e' => { f('\x4f'); f('\u0303'); f('\u0308'); }
+          '\u1e4f' => { f('\x6f'); f('\u0303'); f('\u0308'); }
+          '\u1e50' => { f('\x4f'); f('\u0304'); f('\u0300'); }
+          '\u1e51' => { f('\x6f'); f('\u0304'); f('\u0300'); }
+          '\u1e52' => { f('\x4f'); f('\u0304'); f('\u0301'); }
+          '\u1e53' => { f('\x6f'); f('\u0304'); f('\u0301'); }
+          '\u1e54' => { f('\x50'); f('\u0301'); }
+          '\u1e55' => { f('\x70'); f('\u0301'); }
+          '\u1e56' => { f('\x50'); f('\u0307'); }
+          '\u1e57' => { f('\x70'); f('\u0307'); }
+          '\u1e58' => { f('\x52'); f('\u0307'); }
+          '\u1e59' => { f('\x72'); f('\u0307'); }
+          '\u1e5a' => { f('\x52'); f('\u0323'); }
+          '\u1e5b' => { f('\x72'); f('\u0323'); }
+          '\u1e5c' => { f('\x52'); f('\u0323'); f('\u0304'); }
+          '\u1e5d' => { f('\x72'); f('\u0323'); f('\u0304'); }
+          '\u1e5e' => { f('\x52'); f('\u0331'); }
+          '\u1e5f' => { f('\x72'); f('\u0331'); }
+          '\u1e60' => { f('\x53'); f('\u0307'); }
+          '\u1e61' => { f('\x73'); f('\u0307'); }
+          '\u1e62' => { f('\x53'); f('\u0323'); }
+          '\u1e63' => { f('\x73'); f('\u0323'); }
+          '\u1e64' => { f('\x53'); f('\u0301'); f('\u0307'); }
+          '\u1e65' => { f('\x73'); f('\u0301'); f('\u0307'); }
+          '\u1e66' => { f('\x53'); f('\u030c'); f('\u0307'); }
+          '\u1e67' => { f('\x73'); f('\u030c'); f('\u0307'); }
+          '\u1e68' => { f('\x53'); f('\u0323'); f('\u0307'); }
+          '\u1e69' => { f('\x73'); f('\u0323'); f('\u0307'); }
+          '\u1e6a' => { f('\x54'); f('\u0307'); }
+          '\u1e6b' => { f('\x74'); f('\u0307'); }
+          '\u1e6c' => { f('\x54'); f('\u0323'); }
+          '\u1e6d' => { f('\x74'); f('\u0323'); }
+          '\u1e6e' => { f('\x54'); f('\u0331'); }
+          '\u1e6f' => { f('\x74'); f('\u0331'); }
+          '\u1e70' => { f('\x54'); f('\u032d'); }
+          '\u1e71' => { f('\x74'); f('\u032d'); }
+          '\u1e72' => { f('\x55'); f('\u0324'); }
+          '\u1e73' => { f('\x75'); f('\u0324'); }
+          '\u1e74' => { f('\x55'); f('\u0330'); }
+          '\u1e75' => { f('\x75'); f('\u0330'); }
+          '\u1e76' => { f('\x55'); f('\u032d'); }
+          '\u1e77' => { f('\x75'); f('\u032d'); }
+          '\u1e78' => { f('\x55'); f('\u0303'); f('\u0301'); }
+          '\u1e79' => { f('\x75'); f('\u0303'); f('\u0301'); }
+          '\u1e7a' => { f('\x55'); f('\u0304'); f('\u0308'); }
+          '\u1e7b' => { f('\x75'); f('\u0304'); f('\u0308'); }
+          '\u1e7c' => { f('\x56'); f('\u0303'); }
+          '\u1e7d' => { f('\x76'); f('\u0303'); }
+          '\u1e7e' => { f('\x56'); f('\u0323'); }
+          '\u1e7f' => { f('\x76'); f('\u0323'); }
+          '\u1e80' => { f('\x57'); f('\u0300'); }
+          '\u1e81' => { f('\x77'); f('\u0300'); }
+          '\u1e82' => { f('\x57'); f('\u0301'); }
+          '\u1e83' => { f('\x77'); f('\u0301'); }
+          '\u1e84' => { f('\x57'); f('\u0308'); }
+          '\u1e85' => { f('\x77'); f('\u0308'); }
+          '\u1e86' => { f('\x57'); f('\u0307'); }
+          '\u1e87' => { f('\x77'); f('\u0307'); }
+          '\u1e88' => { f('\x57'); f('\u0323'); }
+          '\u1e89' => { f('\x77'); f('\u0323'); }
+          '\u1e8a' => { f('\x58'); f('\u0307'); }
+          '\u1e8b' => { f('\x78'); f('\u0307'); }
+          '\u1e8c' => { f('\x58'); f('\u0308'); }
+          '\u1e8d' => { f('\x78'); f('\u0308'); }
+          '\u1e8e' => { f('\x59'); f('\u0307'); }
+          '\u1e8f' => { f('\x79'); f('\u0307'); }
+          '\u1e90' => { f('\x5a'); f('\u0302'); }
+          '\u1e91' => { f('\x7a'); f('\u0302'); }
+          '\u1e92' => { f('\x5a'); f('\u0323'); }
+          '\u1e93' => { f('\x7a'); f('\u0323'); }
+          '\u1e94' => { f('\x5a'); f('\u0331'); }
+          '\u1e95' => { f('\x7a'); f('\u0331'); }
+          '\u1e96' => { f('\x68'); f('\u0331'); }
+          '\u1e97' => { f('\x74'); f('\u0308'); }
+          '\u1e98' => { f('\x77'); f('\u030a'); }
+          '\u1e99' => { f('\x79'); f('\u030a'); }
+          '\u1e9b' => { f('\u017f'); f('\u0307'); }
+          '\u1ea0' => { f('\x41'); f('\u0323'); }
+          '\u1ea1' => { f('\x61'); f('\u0323'); }
+          '\u1ea2' => { f('\x41'); f('\u0309'); }
+          '\u1ea3' => { f('\x61'); f('\u0309'); }
+          '\u1ea4' => { f('\x41'); f('\u0302'); f('\u0301'); }
+          '\u1ea5' => { f('\x61'); f('\u0302'); f('\u0301'); }
+          '\u1ea6' => { f('\x41'); f('\u0302'); f('\u0300'); }
+          '\u1ea7' => { f('\x61'); f('\u0302'); f('\u0300'); }
+          '\u1ea8' => { f('\x41'); f('\u0302'); f('\u0309'); }
+          '\u1ea9' => { f('\x61'); f('\u0302'); f('\u0309'); }
+          '\u1eaa' => { f('\x41'); f('\u0302'); f('\u0303'); }
+          '\u1eab' => { f('\x61'); f('\u0302'); f('\u0303'); }
+          '\u1eac' => { f('\x41'); f('\u0323'); f('\u0302'); }
+          '\u1ead' => { f('\x61'); f('\u0323'); f('\u0302'); }
+          '\u1eae' => { f('\x41'); f('\u0306'); f('\u0301'); }
+          '\u1eaf' => { f('\x61'); f('\u0306'); f('\u0301'); }
+          '\u1eb0' => { f('\x41'); f('\u0306'); f('\u0300'); }
+          '\u1eb1' => { f('\x61'); f('\u0306'); f('\u0300'); }
+          '\u1eb2' => { f('\x41'); f('\u0306'); f('\u0309'); }
+          '\u1eb3' => { f('\x61'); f('\u0306'); f('\u0309'); }
+          '\u1eb4' => { f('\x41'); f('\u0306'); f('\u0303'); }
+          '\u1eb5' => { f('\x61'); f('\u0306'); f('\u0303'); }
+          '\u1eb6' => { f('\x41'); f('\u0323'); f('\u0306'); }
+          '\u1eb7' => { f('\x61'); f('\u0323'); f('\u0306'); }
+          '\u1eb8' => { f('\x45'); f('\u0323'); }
+          '\u1eb9' => { f('\x65'); f('\u0323'); }
+          '\u1eba' => { f('\x45'); f('\u0309'); }
+          '\u1ebb' => { f('\x65'); f('\u0309'); }
+          '\u1ebc' => { f('\x45'); f('\u0303'); }
+          '\u1ebd' => { f('\x65'); f('\u0303'); }
+          '\u1ebe' => { f('\x45'); f('\u0302'); f('\u0301'); }
+          '\u1ebf' => { f('\x65'); f('\u0302'); f('\u0301'); }
+          '\u1ec0' => { f('\x45'); f('\u0302'); f('\u0300'); }
+          '\u1ec1' => { f('\x65'); f('\u0302'); f('\u0300'); }
+          '\u1ec2' => { f('\x45'); f('\u0302'); f('\u0309'); }
+          '\u1ec3' => { f('\x65'); f('\u0302'); f('\u0309'); }
+          '\u1ec4' => { f('\x45'); f('\u0302'); f('\u0303'); }
+          '\u1ec5' => { f('\x65'); f('\u0302'); f('\u0303'); }
+          '\u1ec6' => { f('\x45'); f('\u0323'); f('\u0302'); }
+          '\u1ec7' => { f('\x65'); f('\u0323'); f('\u0302'); }
+          '\u1ec8' => { f('\x49'); f('\u0309'); }
+          '\u1ec9' => { f('\x69'); f('\u0309'); }
+          '\u1eca' => { f('\x49'); f('\u0323'); }
+          '\u1ecb' => { f('\x69'); f('\u0323'); }
+          '\u1ecc' => { f('\x4f'); f('\u0323'); }
+          '\u1ecd' => { f('\x6f'); f('\u0323'); }
+          '\u1ece' => { f('\x4f'); f('\u0309'); }
+          '\u1ecf' => { f('\x6f'); f('\u0309'); }
+          '\u1ed0' => { f('\x4f'); f('\u0302'); f('\u0301'); }
+          '\u1ed1' => { f('\x6f'); f('\u0302'); f('\u0301'); }
+          '\u1ed2' => { f('\x4f'); f('\u0302'); f('\u0300'); }
+          '\u1ed3' => { f('\x6f'); f('\u0302'); f('\u0300'); }
+          '\u1ed4' => { f('\x4f'); f('\u0302'); f('\u0309'); }
+          '\u1ed5' => { f('\x6f'); f('\u0302'); f('\u0309'); }
+          '\u1ed6' => { f('\x4f'); f('\u0302'); f('\u0303'); }
+          '\u1ed7' => { f('\x6f'); f('\u0302'); f('\u0303'); }
+          '\u1ed8' => { f('\x4f'); f('\u0323'); f('\u0302'); }
+          '\u1ed9' => { f('\x6f'); f('\u0323'); f('\u0302'); }
+          '\u1eda' => { f('\x4f'); f('\u031b'); f('\u0301'); }
+          '\u1edb' => { f('\x6f'); f('\u031b'); f('\u0301'); }
+          '\u1edc' => { f('\x4f'); f('\u031b'); f('\u0300'); }
+          '\u1edd' => { f('\x6f'); f('\u031b'); f('\u0300'); }
+          '\u1ede' => { f('\x4f'); f('\u031b'); f('\u0309'); }
+          '\u1edf' => { f('\x6f'); f('\u031b'); f('\u0309'); }
+          '\u1ee0' => { f('\x4f'); f('\u031b'); f('\u0303'); }
+          '\u1ee1' => { f('\x6f'); f('\u031b'); f('\u0303'); }
+          '\u1ee2' => { f('\x4f'); f('\u031b'); f('\u0323'); }
+          '\u1ee3' => { f('\x6f'); f('\u031b'); f('\u0323'); }
+          '\u1ee4' => { f('\x55'); f('\u0323'); }
+          '\u1ee5' => { f('\x75'); f('\u0323'); }
+          '\u1ee6' => { f('\x55'); f('\u0309'); }
+          '\u1ee7' => { f('\x75'); f('\u0309'); }
+          '\u1ee8' => { f('\x55'); f('\u031b'); f('\u0301'); }
+          '\u1ee9' => { f('\x75'); f('\u031b'); f('\u0301'); }
+          '\u1eea' => { f('\x55'); f('\u031b'); f('\u0300'); }
+          '\u1eeb' => { f('\x75'); f('\u031b'); f('\u0300'); }
+          '\u1eec' => { f('\x55'); f('\u031b'); f('\u0309'); }
+          '\u1eed' => { f('\x75'); f('\u031b'); f('\u0309'); }
+          '\u1eee' => { f('\x55'); f('\u031b'); f('\u0303'); }
+          '\u1eef' => { f('\x75'); f('\u031b'); f('\u0303'); }
+          '\u1ef0' => { f('\x55'); f('\u031b'); f('\u0323'); }
+          '\u1ef1' => { f('\x75'); f('\u031b'); f('\u0323'); }
+          '\u1ef2' => { f('\x59'); f('\u0300'); }
+          '\u1ef3' => { f('\x79'); f('\u0300'); }
+          '\u1ef4' => { f('\x59'); f('\u0323'); }
+          '\u1ef5' => { f('\x79'); f('\u0323'); }
+          '\u1ef6' => { f('\x59'); f('\u0309'); }
+          '\u1ef7' => { f('\x79'); f('\u0309'); }
+          '\u1ef8' => { f('\x59'); f('\u0303'); }
+          '\u1ef9' => { f('\x79'); f('\u0303'); }
+          '\u1f00' => { f('\u03b1'); f('\u0313'); }
+          '\u1f01' => { f('\u03b1'); f('\u0314'); }
+          '\u1f02' => { f('\u03b1'); f('\u0313'); f('\u0300'); }
+          '\u1f03' => { f('\u03b1'); f('\u0314'); f('\u0300'); }
+          '\u1f04' => { f('\u03b1'); f('\u0313'); f('\u0301'); }
+          '\u1f05' => { f('\u03b1'); f('\u0314'); f('\u0301'); }
+          '\u1f06' => { f('\u03b1'); f('\u0313'); f('\u0342'); }
+          '\u1f07' => { f('\u03b1'); f('\u0314'); f('\u0342'); }
+          '\u1f08' => { f('\u0391'); f('\u0313'); }
+          '\u1f09' => { f('\u0391'); f('\u0314'); }
+          '\u1f0a' => { f('\u0391'); f('\u0313'); f('\u0300'); }
+          '\u1f0b' => { f('\u0391'); f('\u0314'); f('\u0300'); }
+          '\u1f0c' => { f('\u0391'); f('\u0313'); f('\u0301'); }
+          '\u1f0d' => { f('\u0391'); f('\u0314'); f('\u0301'); }
+          '\u1f0e' => { f('\u0391'); f('\u0313'); f('\u0342'); }
+          '\u1f0f' => { f('\u0391'); f('\u0314'); f('\u0342'); }
+          '\u1f10' => { f('\u03b5'); f('\u0313'); }
+          '\u1f11' => { f('\u03b5'); f('\u0314'); }
+          '\u1f12' => { f('\u03b5'); f('\u0313'); f('\u0300'); }
+          '\u1f13' => { f('\u03b5'); f('\u0314'); f('\u0300'); }
+          '\u1f14' => { f('\u03b5'); f('\u0313'); f('\u0301'); }
+          '\u1f15' => { f('\u03b5'); f('\u0314'); f('\u0301'); }
+          '\u1f18' => { f('\u0395'); f('\u0313'); }
+          '\u1f19' => { f('\u0395'); f('\u0314'); }
+          '\u1f1a' => { f('\u0395'); f('\u0313'); f('\u0300'); }
+          '\u1f1b' => { f('\u0395'); f('\u0314'); f('\u0300'); }
+          '\u1f1c' => { f('\u0395'); f('\u0313'); f('\u0301'); }
+          '\u1f1d' => { f('\u0395'); f('\u0314'); f('\u0301'); }
+          '\u1f20' => { f('\u03b7'); f('\u0313'); }
+          '\u1f21' => { f('\u03b7'); f('\u0314'); }
+          '\u1f22' => { f('\u03b7'); f('\u0313'); f('\u0300'); }
+          '\u1f23' => { f('\u03b7'); f('\u0314'); f('\u0300'); }
+          '\u1f24' => { f('\u03b7'); f('\u0313'); f('\u0301'); }
+          '\u1f25' => { f('\u03b7'); f('\u0314'); f('\u0301'); }
+          '\u1f26' => { f('\u03b7'); f('\u0313'); f('\u0342'); }
+          '\u1f27' => { f('\u03b7'); f('\u0314'); f('\u0342'); }
+          '\u1f28' => { f('\u0397'); f('\u0313'); }
+          '\u1f29' => { f('\u0397'); f('\u0314'); }
+          '\u1f2a' => { f('\u0397'); f('\u0313'); f('\u0300'); }
+          '\u1f2b' => { f('\u0397'); f('\u0314'); f('\u0300'); }
+          '\u1f2c' => { f('\u0397'); f('\u0313'); f('\u0301'); }
+          '\u1f2d' => { f('\u0397'); f('\u0314'); f('\u0301'); }
+          '\u1f2e' => { f('\u0397'); f('\u0313'); f('\u0342'); }
+          '\u1f2f' => { f('\u0397'); f('\u0314'); f('\u0342'); }
+          '\u1f30' => { f('\u03b9'); f('\u0313'); }
+          '\u1f31' => { f('\u03b9'); f('\u0314'); }
+          '\u1f32' => { f('\u03b9'); f('\u0313'); f('\u0300'); }
+          '\u1f33' => { f('\u03b9'); f('\u0314'); f('\u0300'); }
+          '\u1f34' => { f('\u03b9'); f('\u0313'); f('\u0301'); }
+          '\u1f35' => { f('\u03b9'); f('\u0314'); f('\u0301'); }
+          '\u1f36' => { f('\u03b9'); f('\u0313'); f('\u0342'); }
+          '\u1f37' => { f('\u03b9'); f('\u0314'); f('\u0342'); }
+          '\u1f38' => { f('\u0399'); f('\u0313'); }
+          '\u1f39' => { f('\u0399'); f('\u0314'); }
+          '\u1f3a' => { f('\u0399'); f('\u0313'); f('\u0300'); }
+          '\u1f3b' => { f('\u0399'); f('\u0314'); f('\u0300'); }
+          '\u1f3c' => { f('\u0399'); f('\u0313'); f('\u0301'); }
+          '\u1f3d' => { f('\u0399'); f('\u0314'); f('\u0301'); }
+          '\u1f3e' => { f('\u0399'); f('\u0313'); f('\u0342'); }
+          '\u1f3f' => { f('\u0399'); f('\u0314'); f('\u0342'); }
+          '\u1f40' => { f('\u03bf'); f('\u0313'); }
+          '\u1f41' => { f('\u03bf'); f('\u0314'); }
+          '\u1f42' => { f('\u03bf'); f('\u0313'); f('\u0300'); }
+          '\u1f43' => { f('\u03bf'); f('\u0314'); f('\u0300'); }
+          '\u1f44' => { f('\u03bf'); f('\u0313'); f('\u0301'); }
+          '\u1f45' => { f('\u03bf'); f('\u0314'); f('\u0301'); }
+          '\u1f48' => { f('\u039f'); f('\u0313'); }
+          '\u1f49' => { f('\u039f'); f('\u0314'); }
+          '\u1f4a' => { f('\u039f'); f('\u0313'); f('\u0300'); }
+          '\u1f4b' => { f('\u039f'); f('\u0314'); f('\u0300'); }
+          '\u1f4c' => { f('\u039f'); f('\u0313'); f('\u0301'); }
+          '\u1f4d' => { f('\u039f'); f('\u0314'); f('\u0301'); }
+          '\u1f50' => { f('\u03c5'); f('\u0313'); }
+          '\u1f51' => { f('\u03c5'); f('\u0314'); }
+          '\u1f52' => { f('\u03c5'); f('\u0313'); f('\u0300'); }
+          '\u1f53' => { f('\u03c5'); f('\u0314'); f('\u0300'); }
+          '\u1f54' => { f('\u03c5'); f('\u0313'); f('\u0301'); }
+          '\u1f55' => { f('\u03c5'); f('\u0314'); f('\u0301'); }
+          '\u1f56' => { f('\u03c5'); f('\u0313'); f('\u0342'); }
+          '\u1f57' => { f('\u03c5'); f('\u0314'); f('\u0342'); }
+          '\u1f59' => { f('\u03a5'); f('\u0314'); }
+          '\u1f5b' => { f('\u03a5'); f('\u0314'); f('\u0300'); }
+          '\u1f5d' => { f('\u03a5'); f('\u0314'); f('\u0301'); }
+          '\u1f5f' => { f('\u03a5'); f('\u0314'); f('\u0342'); }
+          '\u1f60' => { f('\u03c9'); f('\u0313'); }
+          '\u1f61' => { f('\u03c9'); f('\u0314'); }
+          '\u1f62' => { f('\u03c9'); f('\u0313'); f('\u0300'); }
+          '\u1f63' => { f('\u03c9'); f('\u0314'); f('\u0300'); }
+          '\u1f64' => { f('\u03c9'); f('\u0313'); f('\u0301'); }
+          '\u1f65' => { f('\u03c9'); f('\u0314'); f('\u0301'); }
+          '\u1f66' => { f('\u03c9'); f('\u0313'); f('\u0342'); }
+          '\u1f67' => { f('\u03c9'); f('\u0314'); f('\u0342'); }
+          '\u1f68' => { f('\u03a9'); f('\u0313'); }
+          '\u1f69' => { f('\u03a9'); f('\u0314'); }
+          '\u1f6a' => { f('\u03a9'); f('\u0313'); f('\u0300'); }
+          '\u1f6b' => { f('\u03a9'); f('\u0314'); f('\u0300'); }
+          '\u1f6c' => { f('\u03a9'); f('\u0313'); f('\u0301'); }
+          '\u1f6d' => { f('\u03a9'); f('\u0314'); f('\u0301'); }
+          '\u1f6e' => { f('\u03a9'); f('\u0313'); f('\u0342'); }
+          '\u1f6f' => { f('\u03a9'); f('\u0314'); f('\u0342'); }
+          '\u1f70' => { f('\u03b1'); f('\u0300'); }
+          '\u1f71' => { f('\u03b1'); f('\u0301'); }
+          '\u1f72' => { f('\u03b5'); f('\u0300'); }
+          '\u1f73' => { f('\u03b5'); f('\u0301'); }
+          '\u1f74' => { f('\u03b7'); f('\u0300'); }
+          '\u1f75' => { f('\u03b7'); f('\u0301'); }
+          '\u1f76' => { f('\u03b9'); f('\u0300'); }
+          '\u1f77' => { f('\u03b9'); f('\u0301'); }
+          '\u1f78' => { f('\u03bf'); f('\u0300'); }
+          '\u1f79' => { f('\u03bf'); f('\u0301'); }
+          '\u1f7a' => { f('\u03c5'); f('\u0300'); }
+          '\u1f7b' => { f('\u03c5'); f('\u0301'); }
+          '\u1f7c' => { f('\u03c9'); f('\u0300'); }
+          '\u1f7d' => { f('\u03c9'); f('\u0301'); }
+          '\u1f80' => { f('\u03b1'); f('\u0313'); f('\u0345'); }
+          '\u1f81' => { f('\u03b1'); f('\u0314'); f('\u0345'); }
+          '\u1f82' => { f('\u03b1'); f('\u0313'); f('\u0300'); f('\u0345'); }
+          '\u1f83' => { f('\u03b1'); f('\u0314'); f('\u0300'); f('\u0345'); }
+          '\u1f84' => { f('\u03b1'); f('\u0313'); f('\u0301'); f('\u0345'); }
+          '\u1f85' => { f('\u03b1'); f('\u0314'); f('\u0301'); f('\u0345'); }
+          '\u1f86' => { f('\u03b1'); f('\u0313'); f('\u0342'); f('\u0345'); }
+          '\u1f87' => { f('\u03b1'); f('\u0314'); f('\u0342'); f('\u0345'); }
+          '\u1f88' => { f('\u0391'); f('\u0313'); f('\u0345'); }
+          '\u1f89' => { f('\u0391'); f('\u0314'); f('\u0345'); }
+          '\u1f8a' => { f('\u0391'); f('\u0313'); f('\u0300'); f('\u0345'); }
+          '\u1f8b' => { f('\u0391'); f('\u0314'); f('\u0300'); f('\u0345'); }
+          '\u1f8c' => { f('\u0391'); f('\u0313'); f('\u0301'); f('\u0345'); }
+          '\u1f8d' => { f('\u0391'); f('\u0314'); f('\u0301'); f('\u0345'); }
+          '\u1f8e' => { f('\u0391'); f('\u0313'); f('\u0342'); f('\u0345'); }
+          '\u1f8f' => { f('\u0391'); f('\u0314'); f('\u0342'); f('\u0345'); }
+          '\u1f90' => { f('\u03b7'); f('\u0313'); f('\u0345'); }
+          '\u1f91' => { f('\u03b7'); f('\u0314'); f('\u0345'); }
+          '\u1f92' => { f('\u03b7'); f('\u0313'); f('\u0300'); f('\u0345'); }
+          '\u1f93' => { f('\u03b7'); f('\u0314'); f('\u0300'); f('\u0345'); }
+          '\u1f94' => { f('\u03b7'); f('\u0313'); f('\u0301'); f('\u0345'); }
+          '\u1f95' => { f('\u03b7'); f('\u0314'); f('\u0301'); f('\u0345'); }
+          '\u1f96' => { f('\u03b7'); f('\u0313'); f('\u0342'); f('\u0345'); }
+          '\u1f97' => { f('\u03b7'); f('\u0314'); f('\u0342'); f('\u0345'); }
+          '\u1f98' => { f('\u0397'); f('\u0313'); f('\u0345'); }
+          '\u1f99' => { f('\u0397'); f('\u0314'); f('\u0345'); }
+          '\u1f9a' => { f('\u0397'); f('\u0313'); f('\u0300'); f('\u0345'); }
+          '\u1f9b' => { f('\u0397'); f('\u0314'); f('\u0300'); f('\u0345'); }
+          '\u1f9c' => { f('\u0397'); f('\u0313'); f('\u0301'); f('\u0345'); }
+          '\u1f9d' => { f('\u0397'); f('\u0314'); f('\u0301'); f('\u0345'); }
+          '\u1f9e' => { f('\u0397'); f('\u0313'); f('\u0342'); f('\u0345'); }
+          '\u1f9f' => { f('\u0397'); f('\u0314'); f('\u0342'); f('\u0345'); }
+          '\u1fa0' => { f('\u03c9'); f('\u0313'); f('\u0345'); }
+          '\u1fa1' => { f('\u03c9'); f('\u0314'); f('\u0345'); }
+          '\u1fa2' => { f('\u03c9'); f('\u0313'); f('\u0300'); f('\u0345'); }
+          '\u1fa3' => { f('\u03c9'); f('\u0314'); f('\u0300'); f('\u0345'); }
+          '\u1fa4' => { f('\u03c9'); f('\u0313'); f('\u0301'); f('\u0345'); }
+          '\u1fa5' => { f('\u03c9'); f('\u0314'); f('\u0301'); f('\u0345'); }
+          '\u1fa6' => { f('\u03c9'); f('\u0313'); f('\u0342'); f('\u0345'); }
+          '\u1fa7' => { f('\u03c9'); f('\u0314'); f('\u0342'); f('\u0345'); }
+          '\u1fa8' => { f('\u03a9'); f('\u0313'); f('\u0345'); }
+          '\u1fa9' => { f('\u03a9'); f('\u0314'); f('\u0345'); }
+          '\u1faa' => { f('\u03a9'); f('\u0313'); f('\u0300'); f('\u0345'); }
+          '\u1fab' => { f('\u03a9'); f('\u0314'); f('\u0300'); f('\u0345'); }
+          '\u1fac' => { f('\u03a9'); f('\u0313'); f('\u0301'); f('\u0345'); }
+          '\u1fad' => { f('\u03a9'); f('\u0314'); f('\u0301'); f('\u0345'); }
+          '\u1fae' => { f('\u03a9'); f('\u0313'); f('\u0342'); f('\u0345'); }
+          '\u1faf' => { f('\u03a9'); f('\u0314'); f('\u0342'); f('\u0345'); }
+          '\u1fb0' => { f('\u03b1'); f('\u0306'); }
+          '\u1fb1' => { f('\u03b1'); f('\u0304'); }
+          '\u1fb2' => { f('\u03b1'); f('\u0300'); f('\u0345'); }
+          '\u1fb3' => { f('\u03b1'); f('\u0345'); }
+          '\u1fb4' => { f('\u03b1'); f('\u0301'); f('\u0345'); }
+          '\u1fb6' => { f('\u03b1'); f('\u0342'); }
+          '\u1fb7' => { f('\u03b1'); f('\u0342'); f('\u0345'); }
+          '\u1fb8' => { f('\u0391'); f('\u0306'); }
+          '\u1fb9' => { f('\u0391'); f('\u0304'); }
+          '\u1fba' => { f('\u0391'); f('\u0300'); }
+          '\u1fbb' => { f('\u0391'); f('\u0301'); }
+          '\u1fbc' => { f('\u0391'); f('\u0345'); }
+          '\u1fbe' => { f('\u03b9'); }
+          '\u1fc1' => { f('\xa8'); f('\u0342'); }
+          '\u1fc2' => { f('\u03b7'); f('\u0300'); f('\u0345'); }
+          '\u1fc3' => { f('\u03b7'); f('\u0345'); }
+          '\u1fc4' => { f('\u03b7'); f('\u0301'); f('\u0345'); }
+          '\u1fc6' => { f('\u03b7'); f('\u0342'); }
+          '\u1fc7' => { f('\u03b7'); f('\u0342'); f('\u0345'); }
+          '\u1fc8' => { f('\u0395'); f('\u0300'); }
+          '\u1fc9' => { f('\u0395'); f('\u0301'); }
+          '\u1fca' => { f('\u0397'); f('\u0300'); }
+          '\u1fcb' => { f('\u0397'); f('\u0301'); }
+          '\u1fcc' => { f('\u0397'); f('\u0345'); }
+          '\u1fcd' => { f('\u1fbf'); f('\u0300'); }
+          '\u1fce' => { f('\u1fbf'); f('\u0301'); }
+          '\u1fcf' => { f('\u1fbf'); f('\u0342'); }
+          '\u1fd0' => { f('\u03b9'); f('\u0306'); }
+          '\u1fd1' => { f('\u03b9'); f('\u0304'); }
+          '\u1fd2' => { f('\u03b9'); f('\u0308'); f('\u0300'); }
+          '\u1fd3' => { f('\u03b9'); f('\u0308'); f('\u0301'); }
+          '\u1fd6' => { f('\u03b9'); f('\u0342'); }
+          '\u1fd7' => { f('\u03b9'); f('\u0308'); f('\u0342'); }
+          '\u1fd8' => { f('\u0399'); f('\u0306'); }
+          '\u1fd9' => { f('\u0399'); f('\u0304'); }
+          '\u1fda' => { f('\u0399'); f('\u0300'); }
+          '\u1fdb' => { f('\u0399'); f('\u0301'); }
+          '\u1fdd' => { f('\u1ffe'); f('\u0300'); }
+          '\u1fde' => { f('\u1ffe'); f('\u0301'); }
+          '\u1fdf' => { f('\u1ffe'); f('\u0342'); }
+          '\u1fe0' => { f('\u03c5'); f('\u0306'); }
+          '\u1fe1' => { f('\u03c5'); f('\u0304'); }
+          '\u1fe2' => { f('\u03c5'); f('\u0308'); f('\u0300'); }
+          '\u1fe3' => { f('\u03c5'); f('\u0308'); f('\u0301'); }
+          '\u1fe4' => { f('\u03c1'); f('\u0313'); }
+          '\u1fe5' => { f('\u03c1'); f('\u0314'); }
+          '\u1fe6' => { f('\u03c5'); f('\u0342'); }
+          '\u1fe7' => { f('\u03c5'); f('\u0308'); f('\u0342'); }
+          '\u1fe8' => { f('\u03a5'); f('\u0306'); }
+          '\u1fe9' => { f('\u03a5'); f('\u0304'); }
+          '\u1fea' => { f('\u03a5'); f('\u0300'); }
+          '\u1feb' => { f('\u03a5'); f('\u0301'); }
+          '\u1fec' => { f('\u03a1'); f('\u0314'); }
+          '\u1fed' => { f('\xa8'); f('\u0300'); }
+          '\u1fee' => { f('\xa8'); f('\u0301'); }
+          '\u1fef' => { f('\x60'); }
+          '\u1ff2' => { f('\u03c9'); f('\u0300'); f('\u0345'); }
+          '\u1ff3' => { f('\u03c9'); f('\u0345'); }
+          '\u1ff4' => { f('\u03c9'); f('\u0301'); f('\u0345'); }
+          '\u1ff6' => { f('\u03c9'); f('\u0342'); }
+          '\u1ff7' => { f('\u03c9'); f('\u0342'); f('\u0345'); }
+          '\u1ff8' => { f('\u039f'); f('\u0300'); }
+          '\u1ff9' => { f('\u039f'); f('\u0301'); }
+          '\u1ffa' => { f('\u03a9'); f('\u0300'); }
+          '\u1ffb' => { f('\u03a9'); f('\u0301'); }
+          '\u1ffc' => { f('\u03a9'); f('\u0345'); }
+          '\u1ffd' => { f('\xb4'); }
+          '\u2000' => { f('\u2002'); }
+          '\u2001' => { f('\u2003'); }
+          '\u2126' => { f('\u03a9'); }
+          '\u212a' => { f('\x4b'); }
+          '\u212b' => { f('\x41'); f('\u030a'); }
+          '\u219a' => { f('\u2190'); f('\u0338'); }
+          '\u219b' => { f('\u2192'); f('\u0338'); }
+          '\u21ae' => { f('\u2194'); f('\u0338'); }
+          '\u21cd' => { f('\u21d0'); f('\u0338'); }
+          '\u21ce' => { f('\u21d4'); f('\u0338'); }
+          '\u21cf' => { f('\u21d2'); f('\u0338'); }
+          '\u2204' => { f('\u2203'); f('\u0338'); }
+          '\u2209' => { f('\u2208'); f('\u0338'); }
+          '\u220c' => { f('\u220b'); f('\u0338'); }
+          '\u2224' => { f('\u2223'); f('\u0338'); }
+          '\u2226' => { f('\u2225'); f('\u0338'); }
+          '\u2241' => { f('\u223c'); f('\u0338'); }
+          '\u2244' => { f('\u2243'); f('\u0338'); }
+          '\u2247' => { f('\u2245'); f('\u0338'); }
+          '\u2249' => { f('\u2248'); f('\u0338'); }
+          '\u2260' => { f('\x3d'); f('\u0338'); }
+          '\u2262' => { f('\u2261'); f('\u0338'); }
+          '\u226d' => { f('\u224d'); f('\u0338'); }
+          '\u226e' => { f('\x3c'); f('\u0338'); }
+          '\u226f' => { f('\x3e'); f('\u0338'); }
+          '\u2270' => { f('\u2264'); f('\u0338'); }
+          '\u2271' => { f('\u2265'); f('\u0338'); }
+          '\u2274' => { f('\u2272'); f('\u0338'); }
+          '\u2275' => { f('\u2273'); f('\u0338'); }
+          '\u2278' => { f('\u2276'); f('\u0338'); }
+          '\u2279' => { f('\u2277'); f('\u0338'); }
+          '\u2280' => { f('\u227a'); f('\u0338'); }
+          '\u2281' => { f('\u227b'); f('\u0338'); }
+          '\u2284' => { f('\u2282'); f('\u0338'); }
+          '\u2285' => { f('\u2283'); f('\u0338'); }
+          '\u2288' => { f('\u2286'); f('\u0338'); }
+          '\u2289' => { f('\u2287'); f('\u0338'); }
+          '\u22ac' => { f('\u22a2'); f('\u0338'); }
+          '\u22ad' => { f('\u22a8'); f('\u0338'); }
+          '\u22ae' => { f('\u22a9'); f('\u0338'); }
+          '\u22af' => { f('\u22ab'); f('\u0338'); }
+          '\u22e0' => { f('\u227c'); f('\u0338'); }
+          '\u22e1' => { f('\u227d'); f('\u0338'); }
+          '\u22e2' => { f('\u2291'); f('\u0338'); }
+          '\u22e3' => { f('\u2292'); f('\u0338'); }
+          '\u22ea' => { f('\u22b2'); f('\u0338'); }
+          '\u22eb' => { f('\u22b3'); f('\u0338'); }
+          '\u22ec' => { f('\u22b4'); f('\u0338'); }
+          '\u22ed' => { f('\u22b5'); f('\u0338'); }
+          '\u2329' => { f('\u3008'); }
+          '\u232a' => { f('\u3009'); }
+          '\u2adc' => { f('\u2add'); f('\u0338'); }
+          '\u304c' => { f('\u304b'); f('\u3099'); }
+          '\u304e' => { f('\u304d'); f('\u3099'); }
+          '\u3050' => { f('\u304f'); f('\u3099'); }
+          '\u3052' => { f('\u3051'); f('\u3099'); }
+          '\u3054' => { f('\u3053'); f('\u3099'); }
+          '\u3056' => { f('\u3055'); f('\u3099'); }
+          '\u3058' => { f('\u3057'); f('\u3099'); }
+          '\u305a' => { f('\u3059'); f('\u3099'); }
+          '\u305c' => { f('\u305b'); f('\u3099'); }
+          '\u305e' => { f('\u305d'); f('\u3099'); }
+          '\u3060' => { f('\u305f'); f('\u3099'); }
+          '\u3062' => { f('\u3061'); f('\u3099'); }
+          '\u3065' => { f('\u3064'); f('\u3099'); }
+          '\u3067' => { f('\u3066'); f('\u3099'); }
+          '\u3069' => { f('\u3068'); f('\u3099'); }
+          '\u3070' => { f('\u306f'); f('\u3099'); }
+          '\u3071' => { f('\u306f'); f('\u309a'); }
+          '\u3073' => { f('\u3072'); f('\u3099'); }
+          '\u3074' => { f('\u3072'); f('\u309a'); }
+          '\u3076' => { f('\u3075'); f('\u3099'); }
+          '\u3077' => { f('\u3075'); f('\u309a'); }
+          '\u3079' => { f('\u3078'); f('\u3099'); }
+          '\u307a' => { f('\u3078'); f('\u309a'); }
+          '\u307c' => { f('\u307b'); f('\u3099'); }
+          '\u307d' => { f('\u307b'); f('\u309a'); }
+          '\u3094' => { f('\u3046'); f('\u3099'); }
+          '\u309e' => { f('\u309d'); f('\u3099'); }
+          '\u30ac' => { f('\u30ab'); f('\u3099'); }
+          '\u30ae' => { f('\u30ad'); f('\u3099'); }
+          '\u30b0' => { f('\u30af'); f('\u3099'); }
+          '\u30b2' => { f('\u30b1'); f('\u3099'); }
+          '\u30b4' => { f('\u30b3'); f('\u3099'); }
+          '\u30b6' => { f('\u30b5'); f('\u3099'); }
+          '\u30b8' => { f('\u30b7'); f('\u3099'); }
+          '\u30ba' => { f('\u30b9'); f('\u3099'); }
+          '\u30bc' => { f('\u30bb'); f('\u3099'); }
+          '\u30be' => { f('\u30bd'); f('\u3099'); }
+          '\u30c0' => { f('\u30bf'); f('\u3099'); }
+          '\u30c2' => { f('\u30c1'); f('\u3099'); }
+          '\u30c5' => { f('\u30c4'); f('\u3099'); }
+          '\u30c7' => { f('\u30c6'); f('\u3099'); }
+          '\u30c9' => { f('\u30c8'); f('\u3099'); }
+          '\u30d0' => { f('\u30cf'); f('\u3099'); }
+          '\u30d1' => { f('\u30cf'); f('\u309a'); }
+          '\u30d3' => { f('\u30d2'); f('\u3099'); }
+          '\u30d4' => { f('\u30d2'); f('\u309a'); }
+          '\u30d6' => { f('\u30d5'); f('\u3099'); }
+          '\u30d7' => { f('\u30d5'); f('\u309a'); }
+          '\u30d9' => { f('\u30d8'); f('\u3099'); }
+          '\u30da' => { f('\u30d8'); f('\u309a'); }
+          '\u30dc' => { f('\u30db'); f('\u3099'); }
+          '\u30dd' => { f('\u30db'); f('\u309a'); }
+          '\u30f4' => { f('\u30a6'); f('\u3099'); }
+          '\u30f7' => { f('\u30ef'); f('\u3099'); }
+          '\u30f8' => { f('\u30f0'); f('\u3099'); }
+          '\u30f9' => { f('\u30f1'); f('\u3099'); }
+          '\u30fa' => { f('\u30f2'); f('\u3099'); }
+          '\u30fe' => { f('\u30fd'); f('\u3099'); }
+          '\uf900' => { f('\u8c48'); }
+          '\uf901' => { f('\u66f4'); }
+          '\uf902' => { f('\u8eca'); }
+          '\uf903' => { f('\u8cc8'); }
+          '\uf904' => { f('\u6ed1'); }
+          '\uf905' => { f('\u4e32'); }
+          '\uf906' => { f('\u53e5'); }
+          '\uf907' => { f('\u9f9c'); }
+          '\uf908' => { f('\u9f9c'); }
+          '\uf909' => { f('\u5951'); }
+          '\uf90a' => { f('\u91d1'); }
+          '\uf90b' => { f('\u5587'); }
+          '\uf90c' => { f('\u5948'); }
+          '\uf90d' => { f('\u61f6'); }
+          '\uf90e' => { f('\u7669'); }
+          '\uf90f' => { f('\u7f85'); }
+          '\uf910' => { f('\u863f'); }
+          '\uf911' => { f('\u87ba'); }
+          '\uf912' => { f('\u88f8'); }
+          '\uf913' => { f('\u908f'); }
+          '\uf914' => { f('\u6a02'); }
+          '\uf915' => { f('\u6d1b'); }
+          '\uf916' => { f('\u70d9'); }
+          '\uf917' => { f('\u73de'); }
+          '\uf918' => { f('\u843d'); }
+          '\uf919' => { f('\u916a'); }
+          '\uf91a' => { f('\u99f1'); }
+          '\uf91b' => { f('\u4e82'); }
+          '\uf91c' => { f('\u5375'); }
+          '\uf91d' => { f('\u6b04'); }
+          '\uf91e' => { f('\u721b'); }
+          '\uf91f' => { f('\u862d'); }
+          '\uf920' => { f('\u9e1e'); }
+          '\uf921' => { f('\u5d50'); }
+          '\uf922' => { f('\u6feb'); }
+          '\uf923' => { f('\u85cd'); }
+          '\uf924' => { f('\u8964'); }
+          '\uf925' => { f('\u62c9'); }
+          '\uf926' => { f('\u81d8'); }
+          '\uf927' => { f('\u881f'); }
+          '\uf928' => { f('\u5eca'); }
+          '\uf929' => { f('\u6717'); }
+          '\uf92a' => { f('\u6d6a'); }
+          '\uf92b' => { f('\u72fc'); }
+          '\uf92c' => { f('\u90ce'); }
+          '\uf92d' => { f('\u4f86'); }
+          '\uf92e' => { f('\u51b7'); }
+          '\uf92f' => { f('\u52de'); }
+          '\uf930' => { f('\u64c4'); }
+          '\uf931' => { f('\u6ad3'); }
+          '\uf932' => { f('\u7210'); }
+          '\uf933' => { f('\u76e7'); }
+          '\uf934' => { f('\u8001'); }
+          '\uf935' => { f('\u8606'); }
+          '\uf936' => { f('\u865c'); }
+          '\uf937' => { f('\u8def'); }
+          '\uf938' => { f('\u9732'); }
+          '\uf939' => { f('\u9b6f'); }
+          '\uf93a' => { f('\u9dfa'); }
+          '\uf93b' => { f('\u788c'); }
+          '\uf93c' => { f('\u797f'); }
+          '\uf93d' => { f('\u7da0'); }
+          '\uf93e' => { f('\u83c9'); }
+          '\uf93f' => { f('\u9304'); }
+          '\uf940' => { f('\u9e7f'); }
+          '\uf941' => { f('\u8ad6'); }
+          '\uf942' => { f('\u58df'); }
+          '\uf943' => { f('\u5f04'); }
+          '\uf944' => { f('\u7c60'); }
+          '\uf945' => { f('\u807e'); }
+          '\uf946' => { f('\u7262'); }
+          '\uf947' => { f('\u78ca'); }
+          '\uf948' => { f('\u8cc2'); }
+          '\uf949' => { f('\u96f7'); }
+          '\uf94a' => { f('\u58d8'); }
+          '\uf94b' => { f('\u5c62'); }
+          '\uf94c' => { f('\u6a13'); }
+          '\uf94d' => { f('\u6dda'); }
+          '\uf94e' => { f('\u6f0f'); }
+          '\uf94f' => { f('\u7d2f'); }
+          '\uf950' => { f('\u7e37'); }
+          '\uf951' => { f('\u964b'); }
+          '\uf952' => { f('\u52d2'); }
+          '\uf953' => { f('\u808b'); }
+          '\uf954' => { f('\u51dc'); }
+          '\uf955' => { f('\u51cc'); }
+          '\uf956' => { f('\u7a1c'); }
+          '\uf957' => { f('\u7dbe'); }
+          '\uf958' => { f('\u83f1'); }
+          '\uf959' => { f('\u9675'); }
+          '\uf95a' => { f('\u8b80'); }
+          '\uf95b' => { f('\u62cf'); }
+          '\uf95c' => { f('\u6a02'); }
+          '\uf95d' => { f('\u8afe'); }
+          '\uf95e' => { f('\u4e39'); }
+          '\uf95f' => { f('\u5be7'); }
+          '\uf960' => { f('\u6012'); }
+          '\uf961' => { f('\u7387'); }
+          '\uf962' => { f('\u7570'); }
+          '\uf963' => { f('\u5317'); }
+          '\uf964' => { f('\u78fb'); }
+          '\uf965' => { f('\u4fbf'); }
+          '\uf966' => { f('\u5fa9'); }
+          '\uf967' => { f('\u4e0d'); }
+          '\uf968' => { f('\u6ccc'); }
+          '\uf969' => { f('\u6578'); }
+          '\uf96a' => { f('\u7d22'); }
+          '\uf96b' => { f('\u53c3'); }
+          '\uf96c' => { f('\u585e'); }
+          '\uf96d' => { f('\u7701'); }
+          '\uf96e' => { f('\u8449'); }
+          '\uf96f' => { f('\u8aaa'); }
+          '\uf970' => { f('\u6bba'); }
+          '\uf971' => { f('\u8fb0'); }
+          '\uf972' => { f('\u6c88'); }
+          '\uf973' => { f('\u62fe'); }
+          '\uf974' => { f('\u82e5'); }
+          '\uf975' => { f('\u63a0'); }
+          '\uf976' => { f('\u7565'); }
+          '\uf977' => { f('\u4eae'); }
+          '\uf978' => { f('\u5169'); }
+          '\uf979' => { f('\u51c9'); }
+          '\uf97a' => { f('\u6881'); }
+          '\uf97b' => { f('\u7ce7'); }
+          '\uf97c' => { f('\u826f'); }
+          '\uf97d' => { f('\u8ad2'); }
+          '\uf97e' => { f('\u91cf'); }
+          '\uf97f' => { f('\u52f5'); }
+          '\uf980' => { f('\u5442'); }
+          '\uf981' => { f('\u5973'); }
+          '\uf982' => { f('\u5eec'); }
+          '\uf983' => { f('\u65c5'); }
+          '\uf984' => { f('\u6ffe'); }
+          '\uf985' => { f('\u792a'); }
+          '\uf986' => { f('\u95ad'); }
+          '\uf987' => { f('\u9a6a'); }
+          '\uf988' => { f('\u9e97'); }
+          '\uf989' => { f('\u9ece'); }
+          '\uf98a' => { f('\u529b'); }
+          '\uf98b' => { f('\u66c6'); }
+          '\uf98c' => { f('\u6b77'); }
+          '\uf98d' => { f('\u8f62'); }
+          '\uf98e' => { f('\u5e74'); }
+          '\uf98f' => { f('\u6190'); }
+          '\uf990' => { f('\u6200'); }
+          '\uf991' => { f('\u649a'); }
+          '\uf992' => { f('\u6f23'); }
+          '\uf993' => { f('\u7149'); }
+          '\uf994' => { f('\u7489'); }
+          '\uf995' => { f('\u79ca'); }
+          '\uf996' => { f('\u7df4'); }
+          '\uf997' => { f('\u806f'); }
+          '\uf998' => { f('\u8f26'); }
+          '\uf999' => { f('\u84ee'); }
+          '\uf99a' => { f('\u9023'); }
+          '\uf99b' => { f('\u934a'); }
+          '\uf99c' => { f('\u5217'); }
+          '\uf99d' => { f('\u52a3'); }
+          '\uf99e' => { f('\u54bd'); }
+          '\uf99f' => { f('\u70c8'); }
+          '\uf9a0' => { f('\u88c2'); }
+          '\uf9a1' => { f('\u8aaa'); }
+          '\uf9a2' => { f('\u5ec9'); }
+          '\uf9a3' => { f('\u5ff5'); }
+          '\uf9a4' => { f('\u637b'); }
+          '\uf9a5' => { f('\u6bae'); }
+          '\uf9a6' => { f('\u7c3e'); }
+          '\uf9a7' => { f('\u7375'); }
+          '\uf9a8' => { f('\u4ee4'); }
+          '\uf9a9' => { f('\u56f9'); }
+          '\uf9aa' => { f('\u5be7'); }
+          '\uf9ab' => { f('\u5dba'); }
+          '\uf9ac' => { f('\u601c'); }
+          '\uf9ad' => { f('\u73b2'); }
+          '\uf9ae' => { f('\u7469'); }
+          '\uf9af' => { f('\u7f9a'); }
+          '\uf9b0' => { f('\u8046'); }
+          '\uf9b1' => { f('\u9234'); }
+          '\uf9b2' => { f('\u96f6'); }
+          '\uf9b3' => { f('\u9748'); }
+          '\uf9b4' => { f('\u9818'); }
+          '\uf9b5' => { f('\u4f8b'); }
+          '\uf9b6' => { f('\u79ae'); }
+          '\uf9b7' => { f('\u91b4'); }
+          '\uf9b8' => { f('\u96b8'); }
+          '\uf9b9' => { f('\u60e1'); }
+          '\uf9ba' => { f('\u4e86'); }
+          '\uf9bb' => { f('\u50da'); }
+          '\uf9bc' => { f('\u5bee'); }
+          '\uf9bd' => { f('\u5c3f'); }
+          '\uf9be' => { f('\u6599'); }
+          '\uf9bf' => { f('\u6a02'); }
+          '\uf9c0' => { f('\u71ce'); }
+          '\uf9c1' => { f('\u7642'); }
+          '\uf9c2' => { f('\u84fc'); }
+          '\uf9c3' => { f('\u907c'); }
+          '\uf9c4' => { f('\u9f8d'); }
+          '\uf9c5' => { f('\u6688'); }
+          '\uf9c6' => { f('\u962e'); }
+          '\uf9c7' => { f('\u5289'); }
+          '\uf9c8' => { f('\u677b'); }
+          '\uf9c9' => { f('\u67f3'); }
+          '\uf9ca' => { f('\u6d41'); }
+          '\uf9cb' => { f('\u6e9c'); }
+          '\uf9cc' => { f('\u7409'); }
+          '\uf9cd' => { f('\u7559'); }
+          '\uf9ce' => { f('\u786b'); }
+          '\uf9cf' => { f('\u7d10'); }
+          '\uf9d0' => { f('\u985e'); }
+          '\uf9d1' => { f('\u516d'); }
+          '\uf9d2' => { f('\u622e'); }
+          '\uf9d3' => { f('\u9678'); }
+          '\uf9d4' => { f('\u502b'); }
+          '\uf9d5' => { f('\u5d19'); }
+          '\uf9d6' => { f('\u6dea'); }
+          '\uf9d7' => { f('\u8f2a'); }
+          '\uf9d8' => { f('\u5f8b'); }
+          '\uf9d9' => { f('\u6144'); }
+          '\uf9da' => { f('\u6817'); }
+          '\uf9db' => { f('\u7387'); }
+          '\uf9dc' => { f('\u9686'); }
+          '\uf9dd' => { f('\u5229'); }
+          '\uf9de' => { f('\u540f'); }
+          '\uf9df' => { f('\u5c65'); }
+          '\uf9e0' => { f('\u6613'); }
+          '\uf9e1' => { f('\u674e'); }
+          '\uf9e2' => { f('\u68a8'); }
+          '\uf9e3' => { f('\u6ce5'); }
+          '\uf9e4' => { f('\u7406'); }
+          '\uf9e5' => { f('\u75e2'); }
+          '\uf9e6' => { f('\u7f79'); }
+          '\uf9e7' => { f('\u88cf'); }
+          '\uf9e8' => { f('\u88e1'); }
+          '\uf9e9' => { f('\u91cc'); }
+          '\uf9ea' => { f('\u96e2'); }
+          '\uf9eb' => { f('\u533f'); }
+          '\uf9ec' => { f('\u6eba'); }
+          '\uf9ed' => { f('\u541d'); }
+          '\uf9ee' => { f('\u71d0'); }
+          '\uf9ef' => { f('\u7498'); }
+          '\uf9f0' => { f('\u85fa'); }
+          '\uf9f1' => { f('\u96a3'); }
+          '\uf9f2' => { f('\u9c57'); }
+          '\uf9f3' => { f('\u9e9f'); }
+          '\uf9f4' => { f('\u6797'); }
+          '\uf9f5' => { f('\u6dcb'); }
+          '\uf9f6' => { f('\u81e8'); }
+          '\uf9f7' => { f('\u7acb'); }
+          '\uf9f8' => { f('\u7b20'); }
+          '\uf9f9' => { f('\u7c92'); }
+          '\uf9fa' => { f('\u72c0'); }
+          '\uf9fb' => { f('\u7099'); }
+          '\uf9fc' => { f('\u8b58'); }
+          '\uf9fd' => { f('\u4ec0'); }
+          '\uf9fe' => { f('\u8336'); }
+          '\uf9ff' => { f('\u523a'); }
+          '\ufa00' => { f('\u5207'); }
+          '\ufa01' => { f('\u5ea6'); }
+          '\ufa02' => { f('\u62d3'); }
+          '\ufa03' => { f('\u7cd6'); }
+          '\ufa04' => { f('\u5b85'); }
+          '\ufa05' => { f('\u6d1e'); }
+          '\ufa06' => { f('\u66b4'); }
+          '\ufa07' => { f('\u8f3b'); }
+          '\ufa08' => { f('\u884c'); }
+          '\ufa09' => { f('\u964d'); }
+          '\ufa0a' => { f('\u898b'); }
+          '\ufa0b' => { f('\u5ed3'); }
+          '\ufa0c' => { f('\u5140'); }
+          '\ufa0d' => { f('\u55c0'); }
+          '\ufa10' => { f('\u585a'); }
+          '\ufa12' => { f('\u6674'); }
+          '\ufa15' => { f('\u51de'); }
+          '\ufa16' => { f('\u732a'); }
+          '\ufa17' => { f('\u76ca'); }
+          '\ufa18' => { f('\u793c'); }
+          '\ufa19' => { f('\u795e'); }
+          '\ufa1a' => { f('\u7965'); }
+          '\ufa1b' => { f('\u798f'); }
+          '\ufa1c' => { f('\u9756'); }
+          '\ufa1d' => { f('\u7cbe'); }
+          '\ufa1e' => { f('\u7fbd'); }
+          '\ufa20' => { f('\u8612'); }
+          '\ufa22' => { f('\u8af8'); }
+          '\ufa25' => { f('\u9038'); }
+          '\ufa26' => { f('\u90fd'); }
+          '\ufa2a' => { f('\u98ef'); }
+          '\ufa2b' => { f('\u98fc'); }
+          '\ufa2c' => { f('\u9928'); }
+          '\ufa2d' => { f('\u9db4'); }
+          '\ufa2e' => { f('\u90de'); }
+          '\ufa2f' => { f('\u96b7'); }
+          '\ufa30' => { f('\u4fae'); }
+          '\ufa31' => { f('\u50e7'); }
+          '\ufa32' => { f('\u514d'); }
+          '\ufa33' => { f('\u52c9'); }
+          '\ufa34' => { f('\u52e4'); }
+          '\ufa35' => { f('\u5351'); }
+          '\ufa36' => { f('\u559d'); }
+          '\ufa37' => { f('\u5606'); }
+          '\ufa38' => { f('\u5668'); }
+          '\ufa39' => { f('\u5840'); }
+          '\ufa3a' => { f('\u58a8'); }
+          '\ufa3b' => { f('\u5c64'); }
+          '\ufa3c' => { f('\u5c6e'); }
+          '\ufa3d' => { f('\u6094'); }
+          '\ufa3e' => { f('\u6168'); }
+          '\ufa3f' => { f('\u618e'); }
+          '\ufa40' => { f('\u61f2'); }
+          '\ufa41' => { f('\u654f'); }
+          '\ufa42' => { f('\u65e2'); }
+          '\ufa43' => { f('\u6691'); }
+          '\ufa44' => { f('\u6885'); }
+          '\ufa45' => { f('\u6d77'); }
+          '\ufa46' => { f('\u6e1a'); }
+          '\ufa47' => { f('\u6f22'); }
+          '\ufa48' => { f('\u716e'); }
+          '\ufa49' => { f('\u722b'); }
+          '\ufa4a' => { f('\u7422'); }
+          '\ufa4b' => { f('\u7891'); }
+          '\ufa4c' => { f('\u793e'); }
+          '\ufa4d' => { f('\u7949'); }
+          '\ufa4e' => { f('\u7948'); }
+          '\ufa4f' => { f('\u7950'); }
+          '\ufa50' => { f('\u7956'); }
+          '\ufa51' => { f('\u795d'); }
+          '\ufa52' => { f('\u798d'); }
+          '\ufa53' => { f('\u798e'); }
+          '\ufa54' => { f('\u7a40'); }
+          '\ufa55' => { f('\u7a81'); }
+          '\ufa56' => { f('\u7bc0'); }
+          '\ufa57' => { f('\u7df4'); }
+          '\ufa58' => { f('\u7e09'); }
+          '\ufa59' => { f('\u7e41'); }
+          '\ufa5a' => { f('\u7f72'); }
+          '\ufa5b' => { f('\u8005'); }
+          '\ufa5c' => { f('\u81ed'); }
+          '\ufa5d' => { f('\u8279'); }
+          '\ufa5e' => { f('\u8279'); }
+          '\ufa5f' => { f('\u8457'); }
+          '\ufa60' => { f('\u8910'); }
+          '\ufa61' => { f('\u8996'); }
+          '\ufa62' => { f('\u8b01'); }
+          '\ufa63' => { f('\u8b39'); }
+          '\ufa64' => { f('\u8cd3'); }
+          '\ufa65' => { f('\u8d08'); }
+          '\ufa66' => { f('\u8fb6'); }
+          '\ufa67' => { f('\u9038'); }
+          '\ufa68' => { f('\u96e3'); }
+          '\ufa69' => { f('\u97ff'); }
+          '\ufa6a' => { f('\u983b'); }
+          '\ufa6b' => { f('\u6075'); }
+          '\ufa6c' => { f('\U000242ee'); }
+          '\ufa6d' => { f('\u8218'); }
+          '\ufa70' => { f('\u4e26'); }
+          '\ufa71' => { f('\u51b5'); }
+          '\ufa72' => { f('\u5168'); }
+          '\ufa73' => { f('\u4f80'); }
+          '\ufa74' => { f('\u5145'); }
+          '\ufa75' => { f('\u5180'); }
+          '\ufa76' => { f('\u52c7'); }
+          '\ufa77' => { f('\u52fa'); }
+          '\ufa78' => { f('\u559d'); }
+          '\ufa79' => { f('\u5555'); }
+          '\ufa7a' => { f('\u5599'); }
+          '\ufa7b' => { f('\u55e2'); }
+          '\ufa7c' => { f('\u585a'); }
+          '\ufa7d' => { f('\u58b3'); }
+          '\ufa7e' => { f('\u5944'); }
+          '\ufa7f' => { f('\u5954'); }
+          '\ufa80' => { f('\u5a62'); }
+          '\ufa81' => { f('\u5b28'); }
+          '\ufa82' => { f('\u5ed2'); }
+          '\ufa83' => { f('\u5ed9'); }
+          '\ufa84' => { f('\u5f69'); }
+          '\ufa85' => { f('\u5fad'); }
+          '\ufa86' => { f('\u60d8'); }
+          '\ufa87' => { f('\u614e'); }
+          '\ufa88' => { f('\u6108'); }
+          '\ufa89' => { f('\u618e'); }
+          '\ufa8a' => { f('\u6160'); }
+          '\ufa8b' => { f('\u61f2'); }
+          '\ufa8c' => { f('\u6234'); }
+          '\ufa8d' => { f('\u63c4'); }
+          '\ufa8e' => { f('\u641c'); }
+          '\ufa8f' => { f('\u6452'); }
+          '\ufa90' => { f('\u6556'); }
+          '\ufa91' => { f('\u6674'); }
+          '\ufa92' => { f('\u6717'); }
+          '\ufa93' => { f('\u671b'); }
+          '\ufa94' => { f('\u6756'); }
+          '\ufa95' => { f('\u6b79'); }
+          '\ufa96' => { f('\u6bba'); }
+          '\ufa97' => { f('\u6d41'); }
+          '\ufa98' => { f('\u6edb'); }
+          '\ufa99' => { f('\u6ecb'); }
+          '\ufa9a' => { f('\u6f22'); }
+          '\ufa9b' => { f('\u701e'); }
+          '\ufa9c' => { f('\u716e'); }
+          '\ufa9d' => { f('\u77a7'); }
+          '\ufa9e' => { f('\u7235'); }
+          '\ufa9f' => { f('\u72af'); }
+          '\ufaa0' => { f('\u732a'); }
+          '\ufaa1' => { f('\u7471'); }
+          '\ufaa2' => { f('\u7506'); }
+          '\ufaa3' => { f('\u753b'); }
+          '\ufaa4' => { f('\u761d'); }
+          '\ufaa5' => { f('\u761f'); }
+          '\ufaa6' => { f('\u76ca'); }
+          '\ufaa7' => { f('\u76db'); }
+          '\ufaa8' => { f('\u76f4'); }
+          '\ufaa9' => { f('\u774a'); }
+          '\ufaaa' => { f('\u7740'); }
+          '\ufaab' => { f('\u78cc'); }
+          '\ufaac' => { f('\u7ab1'); }
+          '\ufaad' => { f('\u7bc0'); }
+          '\ufaae' => { f('\u7c7b'); }
+          '\ufaaf' => { f('\u7d5b'); }
+          '\ufab0' => { f('\u7df4'); }
+          '\ufab1' => { f('\u7f3e'); }
+          '\ufab2' => { f('\u8005'); }
+          '\ufab3' => { f('\u8352'); }
+          '\ufab4' => { f('\u83ef'); }
+          '\ufab5' => { f('\u8779'); }
+          '\ufab6' => { f('\u8941'); }
+          '\ufab7' => { f('\u8986'); }
+          '\ufab8' => { f('\u8996'); }
+          '\ufab9' => { f('\u8abf'); }
+          '\ufaba' => { f('\u8af8'); }
+          '\ufabb' => { f('\u8acb'); }
+          '\ufabc' => { f('\u8b01'); }
+          '\ufabd' => { f('\u8afe'); }
+          '\ufabe' => { f('\u8aed'); }
+          '\ufabf' => { f('\u8b39'); }
+          '\ufac0' => { f('\u8b8a'); }
+          '\ufac1' => { f('\u8d08'); }
+          '\ufac2' => { f('\u8f38'); }
+          '\ufac3' => { f('\u9072'); }
+          '\ufac4' => { f('\u9199'); }
+          '\ufac5' => { f('\u9276'); }
+          '\ufac6' => { f('\u967c'); }
+          '\ufac7' => { f('\u96e3'); }
+          '\ufac8' => { f('\u9756'); }
+          '\ufac9' => { f('\u97db'); }
+          '\ufaca' => { f('\u97ff'); }
+          '\ufacb' => { f('\u980b'); }
+          '\ufacc' => { f('\u983b'); }
+          '\ufacd' => { f('\u9b12'); }
+          '\uface' => { f('\u9f9c'); }
+          '\ufacf' => { f('\U0002284a'); }
+          '\ufad0' => { f('\U00022844'); }
+          '\ufad1' => { f('\U000233d5'); }
+          '\ufad2' => { f('\u3b9d'); }
+          '\ufad3' => { f('\u4018'); }
+          '\ufad4' => { f('\u4039'); }
+          '\ufad5' => { f('\U00025249'); }
+          '\ufad6' => { f('\U00025cd0'); }
+          '\ufad7' => { f('\U00027ed3'); }
+          '\ufad8' => { f('\u9f43'); }
+          '\ufad9' => { f('\u9f8e'); }
+          '\ufb1d' => { f('\u05d9'); f('\u05b4'); }
+          '\ufb1f' => { f('\u05f2'); f('\u05b7'); }
+          '\ufb2a' => { f('\u05e9'); f('\u05c1'); }
+          '\ufb2b' => { f('\u05e9'); f('\u05c2'); }
+          '\ufb2c' => { f('\u05e9'); f('\u05bc'); f('\u05c1'); }
+          '\ufb2d' => { f('\u05e9'); f('\u05bc'); f('\u05c2'); }
+          '\ufb2e' => { f('\u05d0'); f('\u05b7'); }
+          '\ufb2f' => { f('\u05d0'); f('\u05b8'); }
+          '\ufb30' => { f('\u05d0'); f('\u05bc'); }
+          '\ufb31' => { f('\u05d1'); f('\u05bc'); }
+          '\ufb32' => { f('\u05d2'); f('\u05bc'); }
+          '\ufb33' => { f('\u05d3'); f('\u05bc'); }
+          '\ufb34' => { f('\u05d4'); f('\u05bc'); }
+          '\ufb35' => { f('\u05d5'); f('\u05bc'); }
+          '\ufb36' => { f('\u05d6'); f('\u05bc'); }
+          '\ufb38' => { f('\u05d8'); f('\u05bc'); }
+          '\ufb39' => { f('\u05d9'); f('\u05bc'); }
+          '\ufb3a' => { f('\u05da'); f('\u05bc'); }
+          '\ufb3b' => { f('\u05db'); f('\u05bc'); }
+          '\ufb3c' => { f('\u05dc'); f('\u05bc'); }
+          '\ufb3e' => { f('\u05de'); f('\u05bc'); }
+          '\ufb40' => { f('\u05e0'); f('\u05bc'); }
+          '\ufb41' => { f('\u05e1'); f('\u05bc'); }
+          '\ufb43' => { f('\u05e3'); f('\u05bc'); }
+          '\ufb44' => { f('\u05e4'); f('\u05bc'); }
+          '\ufb46' => { f('\u05e6'); f('\u05bc'); }
+          '\ufb47' => { f('\u05e7'); f('\u05bc'); }
+          '\ufb48' => { f('\u05e8'); f('\u05bc'); }
+          '\ufb49' => { f('\u05e9'); f('\u05bc'); }
+          '\ufb4a' => { f('\u05ea'); f('\u05bc'); }
+          '\ufb4b' => { f('\u05d5'); f('\u05b9'); }
+          '\ufb4c' => { f('\u05d1'); f('\u05bf'); }
+          '\ufb4d' => { f('\u05db'); f('\u05bf'); }
+          '\ufb4e' => { f('\u05e4'); f('\u05bf'); }
+          '\U0001109a' => { f('\U00011099'); f('\U000110ba'); }
+          '\U0001109c' => { f('\U0001109b'); f('\U000110ba'); }
+          '\U000110ab' => { f('\U000110a5'); f('\U000110ba'); }
+          '\U0001112e' => { f('\U00011131'); f('\U00011127'); }
+          '\U0001112f' => { f('\U00011132'); f('\U00011127'); }
+          '\U0001134b' => { f('\U00011347'); f('\U0001133e'); }
+          '\U0001134c' => { f('\U00011347'); f('\U00011357'); }
+          '\U000114bb' => { f('\U000114b9'); f('\U000114ba'); }
+          '\U000114bc' => { f('\U000114b9'); f('\U000114b0'); }
+          '\U000114be' => { f('\U000114b9'); f('\U000114bd'); }
+          '\U000115ba' => { f('\U000115b8'); f('\U000115af'); }
+          '\U000115bb' => { f('\U000115b9'); f('\U000115af'); }
+          '\U00011938' => { f('\U00011935'); f('\U00011930'); }
+          '\U0001d15e' => { f('\U0001d157'); f('\U0001d165'); }
+          '\U0001d15f' => { f('\U0001d158'); f('\U0001d165'); }
+          '\U0001d160' => {
+            f('\U0001d158'); f('\U0001d165'); f('\U0001d16e');
+          }
+          '\U0001d161' => {
+            f('\U0001d158'); f('\U0001d165'); f('\U0001d16f');
+          }
+          '\U0001d162' => {
+            f('\U0001d158'); f('\U0001d165'); f('\U0001d170');
+          }
+          '\U0001d163' => {
+            f('\U0001d158'); f('\U0001d165'); f('\U0001d171');
+          }
+          '\U0001d164' => {
+            f('\U0001d158'); f('\U0001d165'); f('\U0001d172');
+          }
+          '\U0001d1bb' => { f('\U0001d1b9'); f('\U0001d165'); }
+          '\U0001d1bc' => { f('\U0001d1ba'); f('\U0001d165'); }
+          '\U0001d1bd' => {
+            f('\U0001d1b9'); f('\U0001d165'); f('\U0001d16e');
+          }
+          '\U0001d1be' => {
+            f('\U0001d1ba'); f('\U0001d165'); f('\U0001d16e');
+          }
+          '\U0001d1bf' => {
+            f('\U0001d1b9'); f('\U0001d165'); f('\U0001d16f');
+          }
+          '\U0001d1c0' => {
+            f('\U0001d1ba'); f('\U0001d165'); f('\U0001d16f');
+          }
+          '\U0002f800' => { f('\u4e3d'); }
+          '\U0002f801' => { f('\u4e38'); }
+          '\U0002f802' => { f('\u4e41'); }
+          '\U0002f803' => { f('\U00020122'); }
+          '\U0002f804' => { f('\u4f60'); }
+          '\U0002f805' => { f('\u4fae'); }
+          '\U0002f806' => { f('\u4fbb'); }
+          '\U0002f807' => { f('\u5002'); }
+          '\U0002f808' => { f('\u507a'); }
+          '\U0002f809' => { f('\u5099'); }
+          '\U0002f80a' => { f('\u50e7'); }
+          '\U0002f80b' => { f('\u50cf'); }
+          '\U0002f80c' => { f('\u349e'); }
+          '\U0002f80d' => { f('\U0002063a'); }
+          '\U0002f80e' => { f('\u514d'); }
+          '\U0002f80f' => { f('\u5154'); }
+          '\U0002f810' => { f('\u5164'); }
+          '\U0002f811' => { f('\u5177'); }
+          '\U0002f812' => { f('\U0002051c'); }
+          '\U0002f813' => { f('\u34b9'); }
+          '\U0002f814' => { f('\u5167'); }
+          '\U0002f815' => { f('\u518d'); }
+          '\U0002f816' => { f('\U0002054b'); }
+          '\U0002f817' => { f('\u5197'); }
+          '\U0002f818' => { f('\u51a4'); }
+          '\U0002f819' => { f('\u4ecc'); }
+          '\U0002f81a' => { f('\u51ac'); }
+          '\U0002f81b' => { f('\u51b5'); }
+          '\U0002f81c' => { f('\U000291df'); }
+          '\U0002f81d' => { f('\u51f5'); }
+          '\U0002f81e' => { f('\u5203'); }
+          '\U0002f81f' => { f('\u34df'); }
+          '\U0002f820' => { f('\u523b'); }
+          '\U0002f821' => { f('\u5246'); }
+          '\U0002f822' => { f('\u5272'); }
+          '\U0002f823' => { f('\u5277'); }
+          '\U0002f824' => { f('\u3515'); }
+          '\U0002f825' => { f('\u52c7'); }
+          '\U0002f826' => { f('\u52c9'); }
+          '\U0002f827' => { f('\u52e4'); }
+          '\U0002f828' => { f('\u52fa'); }
+          '\U0002f829' => { f('\u5305'); }
+          '\U0002f82a' => { f('\u5306'); }
+          '\U0002f82b' => { f('\u5317'); }
+          '\U0002f82c' => { f('\u5349'); }
+          '\U0002f82d' => { f('\u5351'); }
+          '\U0002f82e' => { f('\u535a'); }
+          '\U0002f82f' => { f('\u5373'); }
+          '\U0002f830' => { f('\u537d'); }
+          '\U0002f831' => { f('\u537f'); }
+          '\U0002f832' => { f('\u537f'); }
+          '\U0002f833' => { f('\u537f'); }
+          '\U0002f834' => { f('\U00020a2c'); }
+          '\U0002f835' => { f('\u7070'); }
+          '\U0002f836' => { f('\u53ca'); }
+          '\U0002f837' => { f('\u53df'); }
+          '\U0002f838' => { f('\U00020b63'); }
+          '\U0002f839' => { f('\u53eb'); }
+          '\U0002f83a' => { f('\u53f1'); }
+          '\U0002f83b' => { f('\u5406'); }
+          '\U0002f83c' => { f('\u549e'); }
+          '\U0002f83d' => { f('\u5438'); }
+          '\U0002f83e' => { f('\u5448'); }
+          '\U0002f83f' => { f('\u5468'); }
+          '\U0002f840' => { f('\u54a2'); }
+          '\U0002f841' => { f('\u54f6'); }
+          '\U0002f842' => { f('\u5510'); }
+          '\U0002f843' => { f('\u5553'); }
+          '\U0002f844' => { f('\u5563'); }
+          '\U0002f845' => { f('\u5584'); }
+          '\U0002f846' => { f('\u5584'); }
+          '\U0002f847' => { f('\u5599'); }
+          '\U0002f848' => { f('\u55ab'); }
+          '\U0002f849' => { f('\u55b3'); }
+          '\U0002f84a' => { f('\u55c2'); }
+          '\U0002f84b' => { f('\u5716'); }
+          '\U0002f84c' => { f('\u5606'); }
+          '\U0002f84d' => { f('\u5717'); }
+          '\U0002f84e' => { f('\u5651'); }
+          '\U0002f84f' => { f('\u5674'); }
+          '\U0002f850' => { f('\u5207'); }
+          '\U0002f851' => { f('\u58ee'); }
+          '\U0002f852' => { f('\u57ce'); }
+          '\U0002f853' => { f('\u57f4'); }
+          '\U0002f854' => { f('\u580d'); }
+          '\U0002f855' => { f('\u578b'); }
+          '\U0002f856' => { f('\u5832'); }
+          '\U0002f857' => { f('\u5831'); }
+          '\U0002f858' => { f('\u58ac'); }
+          '\U0002f859' => { f('\U000214e4'); }
+          '\U0002f85a' => { f('\u58f2'); }
+          '\U0002f85b' => { f('\u58f7'); }
+          '\U0002f85c' => { f('\u5906'); }
+          '\U0002f85d' => { f('\u591a'); }
+          '\U0002f85e' => { f('\u5922'); }
+          '\U0002f85f' => { f('\u5962'); }
+          '\U0002f860' => { f('\U000216a8'); }
+          '\U0002f861' => { f('\U000216ea'); }
+          '\U0002f862' => { f('\u59ec'); }
+          '\U0002f863' => { f('\u5a1b'); }
+          '\U0002f864' => { f('\u5a27'); }
+          '\U0002f865' => { f('\u59d8'); }
+          '\U0002f866' => { f('\u5a66'); }
+          '\U0002f867' => { f('\u36ee'); }
+          '\U0002f868' => { f('\u36fc'); }
+          '\U0002f869' => { f('\u5b08'); }
+          '\U0002f86a' => { f('\u5b3e'); }
+          '\U0002f86b' => { f('\u5b3e'); }
+          '\U0002f86c' => { f('\U000219c8'); }
+          '\U0002f86d' => { f('\u5bc3'); }
+          '\U0002f86e' => { f('\u5bd8'); }
+          '\U0002f86f' => { f('\u5be7'); }
+          '\U0002f870' => { f('\u5bf3'); }
+          '\U0002f871' => { f('\U00021b18'); }
+          '\U0002f872' => { f('\u5bff'); }
+          '\U0002f873' => { f('\u5c06'); }
+          '\U0002f874' => { f('\u5f53'); }
+          '\U0002f875' => { f('\u5c22'); }
+          '\U0002f876' => { f('\u3781'); }
+          '\U0002f877' => { f('\u5c60'); }
+          '\U0002f878' => { f('\u5c6e'); }
+          '\U0002f879' => { f('\u5cc0'); }
+          '\U0002f87a' => { f('\u5c8d'); }
+          '\U0002f87b' => { f('\U00021de4'); }
+          '\U0002f87c' => { f('\u5d43'); }
+          '\U0002f87d' => { f('\U00021de6'); }
+          '\U0002f87e' => { f('\u5d6e'); }
+          '\U0002f87f' => { f('\u5d6b'); }
+          '\U0002f880' => { f('\u5d7c'); }
+          '\U0002f881' => { f('\u5de1'); }
+          '\U0002f882' => { f('\u5de2'); }
+          '\U0002f883' => { f('\u382f'); }
+          '\U0002f884' => { f('\u5dfd'); }
+          '\U0002f885' => { f('\u5e28'); }
+          '\U0002f886' => { f('\u5e3d'); }
+          '\U0002f887' => { f('\u5e69'); }
+          '\U0002f888' => { f('\u3862'); }
+          '\U0002f889' => { f('\U00022183'); }
+          '\U0002f88a' => { f('\u387c'); }
+          '\U0002f88b' => { f('\u5eb0'); }
+          '\U0002f88c' => { f('\u5eb3'); }
+          '\U0002f88d' => { f('\u5eb6'); }
+          '\U0002f88e' => { f('\u5eca'); }
+          '\U0002f88f' => { f('\U0002a392'); }
+          '\U0002f890' => { f('\u5efe'); }
+          '\U0002f891' => { f('\U00022331'); }
+          '\U0002f892' => { f('\U00022331'); }
+          '\U0002f893' => { f('\u8201'); }
+          '\U0002f894' => { f('\u5f22'); }
+          '\U0002f895' => { f('\u5f22'); }
+          '\U0002f896' => { f('\u38c7'); }
+          '\U0002f897' => { f('\U000232b8'); }
+          '\U0002f898' => { f('\U000261da'); }
+          '\U0002f899' => { f('\u5f62'); }
+          '\U0002f89a' => { f('\u5f6b'); }
+          '\U0002f89b' => { f('\u38e3'); }
+          '\U0002f89c' => { f('\u5f9a'); }
+          '\U0002f89d' => { f('\u5fcd'); }
+          '\U0002f89e' => { f('\u5fd7'); }
+          '\U0002f89f' => { f('\u5ff9'); }
+          '\U0002f8a0' => { f('\u6081'); }
+          '\U0002f8a1' => { f('\u393a'); }
+          '\U0002f8a2' => { f('\u391c'); }
+          '\U0002f8a3' => { f('\u6094'); }
+          '\U0002f8a4' => { f('\U000226d4'); }
+          '\U0002f8a5' => { f('\u60c7'); }
+          '\U0002f8a6' => { f('\u6148'); }
+          '\U0002f8a7' => { f('\u614c'); }
+          '\U0002f8a8' => { f('\u614e'); }
+          '\U0002f8a9' => { f('\u614c'); }
+          '\U0002f8aa' => { f('\u617a'); }
+          '\U0002f8ab' => { f('\u618e'); }
+          '\U0002f8ac' => { f('\u61b2'); }
+          '\U0002f8ad' => { f('\u61a4'); }
+          '\U0002f8ae' => { f('\u61af'); }
+          '\U0002f8af' => { f('\u61de'); }
+          '\U0002f8b0' => { f('\u61f2'); }
+          '\U0002f8b1' => { f('\u61f6'); }
+          '\U0002f8b2' => { f('\u6210'); }
+          '\U0002f8b3' => { f('\u621b'); }
+          '\U0002f8b4' => { f('\u625d'); }
+          '\U0002f8b5' => { f('\u62b1'); }
+          '\U0002f8b6' => { f('\u62d4'); }
+          '\U0002f8b7' => { f('\u6350'); }
+          '\U0002f8b8' => { f('\U00022b0c'); }
+          '\U0002f8b9' => { f('\u633d'); }
+          '\U0002f8ba' => { f('\u62fc'); }
+          '\U0002f8bb' => { f('\u6368'); }
+          '\U0002f8bc' => { f('\u6383'); }
+          '\U0002f8bd' => { f('\u63e4'); }
+          '\U0002f8be' => { f('\U00022bf1'); }
+          '\U0002f8bf' => { f('\u6422'); }
+          '\U0002f8c0' => { f('\u63c5'); }
+          '\U0002f8c1' => { f('\u63a9'); }
+          '\U0002f8c2' => { f('\u3a2e'); }
+          '\U0002f8c3' => { f('\u6469'); }
+          '\U0002f8c4' => { f('\u647e'); }
+          '\U0002f8c5' => { f('\u649d'); }
+          '\U0002f8c6' => { f('\u6477'); }
+          '\U0002f8c7' => { f('\u3a6c'); }
+          '\U0002f8c8' => { f('\u654f'); }
+          '\U0002f8c9' => { f('\u656c'); }
+          '\U0002f8ca' => { f('\U0002300a'); }
+          '\U0002f8cb' => { f('\u65e3'); }
+          '\U0002f8cc' => { f('\u66f8'); }
+          '\U0002f8cd' => { f('\u6649'); }
+          '\U0002f8ce' => { f('\u3b19'); }
+          '\U0002f8cf' => { f('\u6691'); }
+          '\U0002f8d0' => { f('\u3b08'); }
+          '\U0002f8d1' => { f('\u3ae4'); }
+          '\U0002f8d2' => { f('\u5192'); }
+          '\U0002f8d3' => { f('\u5195'); }
+          '\U0002f8d4' => { f('\u6700'); }
+          '\U0002f8d5' => { f('\u669c'); }
+          '\U0002f8d6' => { f('\u80ad'); }
+          '\U0002f8d7' => { f('\u43d9'); }
+          '\U0002f8d8' => { f('\u6717'); }
+          '\U0002f8d9' => { f('\u671b'); }
+          '\U0002f8da' => { f('\u6721'); }
+          '\U0002f8db' => { f('\u675e'); }
+          '\U0002f8dc' => { f('\u6753'); }
+          '\U0002f8dd' => { f('\U000233c3'); }
+          '\U0002f8de' => { f('\u3b49'); }
+          '\U0002f8df' => { f('\u67fa'); }
+          '\U0002f8e0' => { f('\u6785'); }
+          '\U0002f8e1' => { f('\u6852'); }
+          '\U0002f8e2' => { f('\u6885'); }
+          '\U0002f8e3' => { f('\U0002346d'); }
+          '\U0002f8e4' => { f('\u688e'); }
+          '\U0002f8e5' => { f('\u681f'); }
+          '\U0002f8e6' => { f('\u6914'); }
+          '\U0002f8e7' => { f('\u3b9d'); }
+          '\U0002f8e8' => { f('\u6942'); }
+          '\U0002f8e9' => { f('\u69a3'); }
+          '\U0002f8ea' => { f('\u69ea'); }
+          '\U0002f8eb' => { f('\u6aa8'); }
+          '\U0002f8ec' => { f('\U000236a3'); }
+          '\U0002f8ed' => { f('\u6adb'); }
+          '\U0002f8ee' => { f('\u3c18'); }
+          '\U0002f8ef' => { f('\u6b21'); }
+          '\U0002f8f0' => { f('\U000238a7'); }
+          '\U0002f8f1' => { f('\u6b54'); }
+          '\U0002f8f2' => { f('\u3c4e'); }
+          '\U0002f8f3' => { f('\u6b72'); }
+          '\U0002f8f4' => { f('\u6b9f'); }
+          '\U0002f8f5' => { f('\u6bba'); }
+          '\U0002f8f6' => { f('\u6bbb'); }
+          '\U0002f8f7' => { f('\U00023a8d'); }
+          '\U0002f8f8' => { f('\U00021d0b'); }
+          '\U0002f8f9' => { f('\U00023afa'); }
+          '\U0002f8fa' => { f('\u6c4e'); }
+          '\U0002f8fb' => { f('\U00023cbc'); }
+          '\U0002f8fc' => { f('\u6cbf'); }
+          '\U0002f8fd' => { f('\u6ccd'); }
+          '\U0002f8fe' => { f('\u6c67'); }
+          '\U0002f8ff' => { f('\u6d16'); }
+          '\U0002f900' => { f('\u6d3e'); }
+          '\U0002f901' => { f('\u6d77'); }
+          '\U0002f902' => { f('\u6d41'); }
+          '\U0002f903' => { f('\u6d69'); }
+          '\U0002f904' => { f('\u6d78'); }
+          '\U0002f905' => { f('\u6d85'); }
+          '\U0002f906' => { f('\U00023d1e'); }
+          '\U0002f907' => { f('\u6d34'); }
+          '\U0002f908' => { f('\u6e2f'); }
+          '\U0002f909' => { f('\u6e6e'); }
+          '\U0002f90a' => { f('\u3d33'); }
+          '\U0002f90b' => { f('\u6ecb'); }
+          '\U0002f90c' => { f('\u6ec7'); }
+          '\U0002f90d' => { f('\U00023ed1'); }
+          '\U0002f90e' => { f('\u6df9'); }
+          '\U0002f90f' => { f('\u6f6e'); }
+          '\U0002f910' => { f('\U00023f5e'); }
+          '\U0002f911' => { f('\U00023f8e'); }
+          '\U0002f912' => { f('\u6fc6'); }
+          '\U0002f913' => { f('\u7039'); }
+          '\U0002f914' => { f('\u701e'); }
+          '\U0002f915' => { f('\u701b'); }
+          '\U0002f916' => { f('\u3d96'); }
+          '\U0002f917' => { f('\u704a'); }
+          '\U0002f918' => { f('\u707d'); }
+          '\U0002f919' => { f('\u7077'); }
+          '\U0002f91a' => { f('\u70ad'); }
+          '\U0002f91b' => { f('\U00020525'); }
+          '\U0002f91c' => { f('\u7145'); }
+          '\U0002f91d' => { f('\U00024263'); }
+          '\U0002f91e' => { f('\u719c'); }
+          '\U0002f91f' => { f('\U000243ab'); }
+          '\U0002f920' => { f('\u7228'); }
+          '\U0002f921' => { f('\u7235'); }
+          '\U0002f922' => { f('\u7250'); }
+          '\U0002f923' => { f('\U00024608'); }
+          '\U0002f924' => { f('\u7280'); }
+          '\U0002f925' => { f('\u7295'); }
+          '\U0002f926' => { f('\U00024735'); }
+          '\U0002f927' => { f('\U00024814'); }
+          '\U0002f928' => { f('\u737a'); }
+          '\U0002f929' => { f('\u738b'); }
+          '\U0002f92a' => { f('\u3eac'); }
+          '\U0002f92b' => { f('\u73a5'); }
+          '\U0002f92c' => { f('\u3eb8'); }
+          '\U0002f92d' => { f('\u3eb8'); }
+          '\U0002f92e' => { f('\u7447'); }
+          '\U0002f92f' => { f('\u745c'); }
+          '\U0002f930' => { f('\u7471'); }
+          '\U0002f931' => { f('\u7485'); }
+          '\U0002f932' => { f('\u74ca'); }
+          '\U0002f933' => { f('\u3f1b'); }
+          '\U0002f934' => { f('\u7524'); }
+          '\U0002f935' => { f('\U00024c36'); }
+          '\U0002f936' => { f('\u753e'); }
+          '\U0002f937' => { f('\U00024c92'); }
+          '\U0002f938' => { f('\u7570'); }
+          '\U0002f939' => { f('\U0002219f'); }
+          '\U0002f93a' => { f('\u7610'); }
+          '\U0002f93b' => { f('\U00024fa1'); }
+          '\U0002f93c' => { f('\U00024fb8'); }
+          '\U0002f93d' => { f('\U00025044'); }
+          '\U0002f93e' => { f('\u3ffc'); }
+          '\U0002f93f' => { f('\u4008'); }
+          '\U0002f940' => { f('\u76f4'); }
+          '\U0002f941' => { f('\U000250f3'); }
+          '\U0002f942' => { f('\U000250f2'); }
+          '\U0002f943' => { f('\U00025119'); }
+          '\U0002f944' => { f('\U00025133'); }
+          '\U0002f945' => { f('\u771e'); }
+          '\U0002f946' => { f('\u771f'); }
+          '\U0002f947' => { f('\u771f'); }
+          '\U0002f948' => { f('\u774a'); }
+          '\U0002f949' => { f('\u4039'); }
+          '\U0002f94a' => { f('\u778b'); }
+          '\U0002f94b' => { f('\u4046'); }
+          '\U0002f94c' => { f('\u4096'); }
+          '\U0002f94d' => { f('\U0002541d'); }
+          '\U0002f94e' => { f('\u784e'); }
+          '\U0002f94f' => { f('\u788c'); }
+          '\U0002f950' => { f('\u78cc'); }
+          '\U0002f951' => { f('\u40e3'); }
+          '\U0002f952' => { f('\U00025626'); }
+          '\U0002f953' => { f('\u7956'); }
+          '\U0002f954' => { f('\U0002569a'); }
+          '\U0002f955' => { f('\U000256c5'); }
+          '\U0002f956' => { f('\u798f'); }
+          '\U0002f957' => { f('\u79eb'); }
+          '\U0002f958' => { f('\u412f'); }
+          '\U0002f959' => { f('\u7a40'); }
+          '\U0002f95a' => { f('\u7a4a'); }
+          '\U0002f95b' => { f('\u7a4f'); }
+          '\U0002f95c' => { f('\U0002597c'); }
+          '\U0002f95d' => { f('\U00025aa7'); }
+          '\U0002f95e' => { f('\U00025aa7'); }
+          '\U0002f95f' => { f('\u7aee'); }
+          '\U0002f960' => { f('\u4202'); }
+          '\U0002f961' => { f('\U00025bab'); }
+          '\U0002f962' => { f('\u7bc6'); }
+          '\U0002f963' => { f('\u7bc9'); }
+          '\U0002f964' => { f('\u4227'); }
+          '\U0002f965' => { f('\U00025c80'); }
+          '\U0002f966' => { f('\u7cd2'); }
+          '\U0002f967' => { f('\u42a0'); }
+          '\U0002f968' => { f('\u7ce8'); }
+          '\U0002f969' => { f('\u7ce3'); }
+          '\U0002f96a' => { f('\u7d00'); }
+          '\U0002f96b' => { f('\U00025f86'); }
+          '\U0002f96c' => { f('\u7d63'); }
+          '\U0002f96d' => { f('\u4301'); }
+          '\U0002f96e' => { f('\u7dc7'); }
+          '\U0002f96f' => { f('\u7e02'); }
+          '\U0002f970' => { f('\u7e45'); }
+          '\U0002f971' => { f('\u4334'); }
+          '\U0002f972' => { f('\U00026228'); }
+          '\U0002f973' => { f('\U00026247'); }
+          '\U0002f974' => { f('\u4359'); }
+          '\U0002f975' => { f('\U000262d9'); }
+          '\U0002f976' => { f('\u7f7a'); }
+          '\U0002f977' => { f('\U0002633e'); }
+          '\U0002f978' => { f('\u7f95'); }
+          '\U0002f979' => { f('\u7ffa'); }
+          '\U0002f97a' => { f('\u8005'); }
+          '\U0002f97b' => { f('\U000264da'); }
+          '\U0002f97c' => { f('\U00026523'); }
+          '\U0002f97d' => { f('\u8060'); }
+          '\U0002f97e' => { f('\U000265a8'); }
+          '\U0002f97f' => { f('\u8070'); }
+          '\U0002f980' => { f('\U0002335f'); }
+          '\U0002f981' => { f('\u43d5'); }
+          '\U0002f982' => { f('\u80b2'); }
+          '\U0002f983' => { f('\u8103'); }
+          '\U0002f984' => { f('\u440b'); }
+          '\U0002f985' => { f('\u813e'); }
+          '\U0002f986' => { f('\u5ab5'); }
+          '\U0002f987' => { f('\U000267a7'); }
+          '\U0002f988' => { f('\U000267b5'); }
+          '\U0002f989' => { f('\U00023393'); }
+          '\U0002f98a' => { f('\U0002339c'); }
+          '\U0002f98b' => { f('\u8201'); }
+          '\U0002f98c' => { f('\u8204'); }
+          '\U0002f98d' => { f('\u8f9e'); }
+          '\U0002f98e' => { f('\u446b'); }
+          '\U0002f98f' => { f('\u8291'); }
+          '\U0002f990' => { f('\u828b'); }
+          '\U0002f991' => { f('\u829d'); }
+          '\U0002f992' => { f('\u52b3'); }
+          '\U0002f993' => { f('\u82b1'); }
+          '\U0002f994' => { f('\u82b3'); }
+          '\U0002f995' => { f('\u82bd'); }
+          '\U0002f996' => { f('\u82e6'); }
+          '\U0002f997' => { f('\U00026b3c'); }
+          '\U0002f998' => { f('\u82e5'); }
+          '\U0002f999' => { f('\u831d'); }
+          '\U0002f99a' => { f('\u8363'); }
+          '\U0002f99b' => { f('\u83ad'); }
+          '\U0002f99c' => { f('\u8323'); }
+          '\U0002f99d' => { f('\u83bd'); }
+          '\U0002f99e' => { f('\u83e7'); }
+          '\U0002f99f' => { f('\u8457'); }
+          '\U0002f9a0' => { f('\u8353'); }
+          '\U0002f9a1' => { f('\u83ca'); }
+          '\U0002f9a2' => { f('\u83cc'); }
+          '\U0002f9a3' => { f('\u83dc'); }
+          '\U0002f9a4' => { f('\U00026c36'); }
+          '\U0002f9a5' => { f('\U00026d6b'); }
+          '\U0002f9a6' => { f('\U00026cd5'); }
+          '\U0002f9a7' => { f('\u452b'); }
+          '\U0002f9a8' => { f('\u84f1'); }
+          '\U0002f9a9' => { f('\u84f3'); }
+          '\U0002f9aa' => { f('\u8516'); }
+          '\U0002f9ab' => { f('\U000273ca'); }
+          '\U0002f9ac' => { f('\u8564'); }
+          '\U0002f9ad' => { f('\U00026f2c'); }
+          '\U0002f9ae' => { f('\u455d'); }
+          '\U0002f9af' => { f('\u4561'); }
+          '\U0002f9b0' => { f('\U00026fb1'); }
+          '\U0002f9b1' => { f('\U000270d2'); }
+          '\U0002f9b2' => { f('\u456b'); }
+          '\U0002f9b3' => { f('\u8650'); }
+          '\U0002f9b4' => { f('\u865c'); }
+          '\U0002f9b5' => { f('\u8667'); }
+          '\U0002f9b6' => { f('\u8669'); }
+          '\U0002f9b7' => { f('\u86a9'); }
+          '\U0002f9b8' => { f('\u8688'); }
+          '\U0002f9b9' => { f('\u870e'); }
+          '\U0002f9ba' => { f('\u86e2'); }
+          '\U0002f9bb' => { f('\u8779'); }
+          '\U0002f9bc' => { f('\u8728'); }
+          '\U0002f9bd' => { f('\u876b'); }
+          '\U0002f9be' => { f('\u8786'); }
+          '\U0002f9bf' => { f('\u45d7'); }
+          '\U0002f9c0' => { f('\u87e1'); }
+          '\U0002f9c1' => { f('\u8801'); }
+          '\U0002f9c2' => { f('\u45f9'); }
+          '\U0002f9c3' => { f('\u8860'); }
+          '\U0002f9c4' => { f('\u8863'); }
+          '\U0002f9c5' => { f('\U00027667'); }
+          '\U0002f9c6' => { f('\u88d7'); }
+          '\U0002f9c7' => { f('\u88de'); }
+          '\U0002f9c8' => { f('\u4635'); }
+          '\U0002f9c9' => { f('\u88fa'); }
+          '\U0002f9ca' => { f('\u34bb'); }
+          '\U0002f9cb' => { f('\U000278ae'); }
+          '\U0002f9cc' => { f('\U00027966'); }
+          '\U0002f9cd' => { f('\u46be'); }
+          '\U0002f9ce' => { f('\u46c7'); }
+          '\U0002f9cf' => { f('\u8aa0'); }
+          '\U0002f9d0' => { f('\u8aed'); }
+          '\U0002f9d1' => { f('\u8b8a'); }
+          '\U0002f9d2' => { f('\u8c55'); }
+          '\U0002f9d3' => { f('\U00027ca8'); }
+          '\U0002f9d4' => { f('\u8cab'); }
+          '\U0002f9d5' => { f('\u8cc1'); }
+          '\U0002f9d6' => { f('\u8d1b'); }
+          '\U0002f9d7' => { f('\u8d77'); }
+          '\U0002f9d8' => { f('\U00027f2f'); }
+          '\U0002f9d9' => { f('\U00020804'); }
+          '\U0002f9da' => { f('\u8dcb'); }
+          '\U0002f9db' => { f('\u8dbc'); }
+          '\U0002f9dc' => { f('\u8df0'); }
+          '\U0002f9dd' => { f('\U000208de'); }
+          '\U0002f9de' => { f('\u8ed4'); }
+          '\U0002f9df' => { f('\u8f38'); }
+          '\U0002f9e0' => { f('\U000285d2'); }
+          '\U0002f9e1' => { f('\U000285ed'); }
+          '\U0002f9e2' => { f('\u9094'); }
+          '\U0002f9e3' => { f('\u90f1'); }
+          '\U0002f9e4' => { f('\u9111'); }
+          '\U0002f9e5' => { f('\U0002872e'); }
+          '\U0002f9e6' => { f('\u911b'); }
+          '\U0002f9e7' => { f('\u9238'); }
+          '\U0002f9e8' => { f('\u92d7'); }
+          '\U0002f9e9' => { f('\u92d8'); }
+          '\U0002f9ea' => { f('\u927c'); }
+          '\U0002f9eb' => { f('\u93f9'); }
+          '\U0002f9ec' => { f('\u9415'); }
+          '\U0002f9ed' => { f('\U00028bfa'); }
+          '\U0002f9ee' => { f('\u958b'); }
+          '\U0002f9ef' => { f('\u4995'); }
+          '\U0002f9f0' => { f('\u95b7'); }
+          '\U0002f9f1' => { f('\U00028d77'); }
+          '\U0002f9f2' => { f('\u49e6'); }
+          '\U0002f9f3' => { f('\u96c3'); }
+          '\U0002f9f4' => { f('\u5db2'); }
+          '\U0002f9f5' => { f('\u9723'); }
+          '\U0002f9f6' => { f('\U00029145'); }
+          '\U0002f9f7' => { f('\U0002921a'); }
+          '\U0002f9f8' => { f('\u4a6e'); }
+          '\U0002f9f9' => { f('\u4a76'); }
+          '\U0002f9fa' => { f('\u97e0'); }
+          '\U0002f9fb' => { f('\U0002940a'); }
+          '\U0002f9fc' => { f('\u4ab2'); }
+          '\U0002f9fd' => { f('\U00029496'); }
+          '\U0002f9fe' => { f('\u980b'); }
+          '\U0002f9ff' => { f('\u980b'); }
+          '\U0002fa00' => { f('\u9829'); }
+          '\U0002fa01' => { f('\U000295b6'); }
+          '\U0002fa02' => { f('\u98e2'); }
+          '\U0002fa03' => { f('\u4b33'); }
+          '\U0002fa04' => { f('\u9929'); }
+          '\U0002fa05' => { f('\u99a7'); }
+          '\U0002fa06' => { f('\u99c2'); }
+          '\U0002fa07' => { f('\u99fe'); }
+          '\U0002fa08' => { f('\u4bce'); }
+          '\U0002fa09' => { f('\U00029b30'); }
+          '\U0002fa0a' => { f('\u9b12'); }
+          '\U0002fa0b' => { f('\u9c40'); }
+          '\U0002fa0c' => { f('\u9cfd'); }
+          '\U0002fa0d' => { f('\u4cce'); }
+          '\U0002fa0e' => { f('\u4ced'); }
+          '\U0002fa0f' => { f('\u9d67'); }
+          '\U0002fa10' => { f('\U0002a0ce'); }
+          '\U0002fa11' => { f('\u4cf8'); }
+          '\U0002fa12' => { f('\U0002a105'); }
+          '\U0002fa13' => { f('\U0002a20e'); }
+          '\U0002fa14' => { f('\U0002a291'); }
+          '\U0002fa15' => { f('\u9ebb'); }
+          '\U0002fa16' => { f('\u4d56'); }
+          '\U0002fa17' => { f('\u9ef9'); }
+          '\U0002fa18' => { f('\u9efe'); }
+          '\U0002fa19' => { f('\u9f05'); }
+          '\U0002fa1a' => { f('\u9f0f'); }
+          '\U0002fa1b' => { f('\u9f16'); }
+          '\U0002fa1c' => { f('\u9f3b'); }
+          '\U0002fa1d' => { f('\U0002a600'); }
+          _ => return false
+        }
+        true
+    }
+
+    pure fn compatibility(c: char, f: fn(char)) -> bool {
+        match c {
+          '\xa0' => { f('\x20'); }
+          '\xa8' => { f('\x20'); f('\u0308'); }
+          '\xaa' => { f('\x61'); }
+          '\xaf' => { f('\x20'); f('\u0304'); }
+          '\xb2' => { f('\x32'); }
+          '\xb3' => { f('\x33'); }
+          '\xb4' => { f('\x20'); f('\u0301'); }
+          '\xb5' => { f('\u03bc'); }
+          '\xb8' => { f('\x20'); f('\u0327'); }
+          '\xb9' => { f('\x31'); }
+          '\xba' => { f('\x6f'); }
+          '\xbc' => { f('\x31'); f('\u2044'); f('\x34'); }
+          '\xbd' => { f('\x31'); f('\u2044'); f('\x32'); }
+          '\xbe' => { f('\x33'); f('\u2044'); f('\x34'); }
+          '\u0132' => { f('\x49'); f('\x4a'); }
+          '\u0133' => { f('\x69'); f('\x6a'); }
+          '\u013f' => { f('\x4c'); f('\xb7'); }
+          '\u0140' => { f('\x6c'); f('\xb7'); }
+          '\u0149' => { f('\u02bc'); f('\x6e'); }
+          '\u017f' => { f('\x73'); }
+          '\u01c4' => { f('\x44'); f('\x5a'); f('\u030c'); }
+          '\u01c5' => { f('\x44'); f('\x7a'); f('\u030c'); }
+          '\u01c6' => { f('\x64'); f('\x7a'); f('\u030c'); }
+          '\u01c7' => { f('\x4c'); f('\x4a'); }
+          '\u01c8' => { f('\x4c'); f('\x6a'); }
+          '\u01c9' => { f('\x6c'); f('\x6a'); }
+          '\u01ca' => { f('\x4e'); f('\x4a'); }
+          '\u01cb' => { f('\x4e'); f('\x6a'); }
+          '\u01cc' => { f('\x6e'); f('\x6a'); }
+          '\u01f1' => { f('\x44'); f('\x5a'); }
+          '\u01f2' => { f('\x44'); f('\x7a'); }
+          '\u01f3' => { f('\x64'); f('\x7a'); }
+          '\u02b0' => { f('\x68'); }
+          '\u02b1' => { f('\u0266'); }
+          '\u02b2' => { f('\x6a'); }
+          '\u02b3' => { f('\x72'); }
+          '\u02b4' => { f('\u0279'); }
+          '\u02b5' => { f('\u027b'); }
+          '\u02b6' => { f('\u0281'); }
+          '\u02b7' => { f('\x77'); }
+          '\u02b8' => { f('\x79'); }
+          '\u02d8' => { f('\x20'); f('\u0306'); }
+          '\u02d9' => { f('\x20'); f('\u0307'); }
+          '\u02da' => { f('\x20'); f('\u030a'); }
+          '\u02db' => { f('\x20'); f('\u0328'); }
+          '\u02dc' => { f('\x20'); f('\u0303'); }
+          '\u02dd' => { f('\x20'); f('\u030b'); }
+          '\u02e0' => { f('\u0263'); }
+          '\u02e1' => { f('\x6c'); }
+          '\u02e2' => { f('\x73'); }
+          '\u02e3' => { f('\x78'); }
+          '\u02e4' => { f('\u0295'); }
+          '\u037a' => { f('\x20'); f('\u0345'); }
+          '\u0384' => { f('\x20'); f('\u0301'); }
+          '\u0385' => { f('\x20'); f('\u0308'); f('\u0301'); }
+          '\u03d0' => { f('\u03b2'); }
+          '\u03d1' => { f('\u03b8'); }
+          '\u03d2' => { f('\u03a5'); }
+          '\u03d3' => { f('\u03a5'); f('\u0301'); }
+          '\u03d4' => { f('\u03a5'); f('\u0308'); }
+          '\u03d5' => { f('\u03c6'); }
+          '\u03d6' => { f('\u03c0'); }
+          '\u03f0' => { f('\u03ba'); }
+          '\u03f1' => { f('\u03c1'); }
+          '\u03f2' => { f('\u03c2'); }
+          '\u03f4' => { f('\u0398'); }
+          '\u03f5' => { f('\u03b5'); }
+          '\u03f9' => { f('\u03a3'); }
+          '\u0587' => { f('\u0565'); f('\u0582'); }
+          '\u0675' => { f('\u0627'); f('\u0674'); }
+          '\u0676' => { f('\u0648'); f('\u0674'); }
+          '\u0677' => { f('\u06c7'); f('\u0674'); }
+          '\u0678' => { f('\u064a'); f('\u0674'); }
+          '\u0e33' => { f('\u0e4d'); f('\u0e32'); }
+          '\u0eb3' => { f('\u0ecd'); f('\u0eb2'); }
+          '\u0edc' => { f('\u0eab'); f('\u0e99'); }
+          '\u0edd' => { f('\u0eab'); f('\u0ea1'); }
+          '\u0f0c' => { f('\u0f0b'); }
+          '\u0f77' => { f('\u0fb2'); f('\u0f71'); f('\u0f80'); }
+          '\u0f79' => { f('\u0fb3'); f('\u0f71'); f('\u0f80'); }
+          '\u10fc' => { f('\u10dc'); }
+          '\u1d2c' => { f('\x41'); }
+          '\u1d2d' => { f('\xc6'); }
+          '\u1d2e' => { f('\x42'); }
+          '\u1d30' => { f('\x44'); }
+          '\u1d31' => { f('\x45'); }
+          '\u1d32' => { f('\u018e'); }
+          '\u1d33' => { f('\x47'); }
+          '\u1d34' => { f('\x48'); }
+          '\u1d35' => { f('\x49'); }
+          '\u1d36' => { f('\x4a'); }
+          '\u1d37' => { f('\x4b'); }
+          '\u1d38' => { f('\x4c'); }
+          '\u1d39' => { f('\x4d'); }
+          '\u1d3a' => { f('\x4e'); }
+          '\u1d3c' => { f('\x4f'); }
+          '\u1d3d' => { f('\u0222'); }
+          '\u1d3e' => { f('\x50'); }
+          '\u1d3f' => { f('\x52'); }
+          '\u1d40' => { f('\x54'); }
+          '\u1d41' => { f('\x55'); }
+          '\u1d42' => { f('\x57'); }
+          '\u1d43' => { f('\x61'); }
+          '\u1d44' => { f('\u0250'); }
+          '\u1d45' => { f('\u0251'); }
+          '\u1d46' => { f('\u1d02'); }
+          '\u1d47' => { f('\x62'); }
+          '\u1d48' => { f('\x64'); }
+          '\u1d49' => { f('\x65'); }
+          '\u1d4a' => { f('\u0259'); }
+          '\u1d4b' => { f('\u025b'); }
+          '\u1d4c' => { f('\u025c'); }
+          '\u1d4d' => { f('\x67'); }
+          '\u1d4f' => { f('\x6b'); }
+          '\u1d50' => { f('\x6d'); }
+          '\u1d51' => { f('\u014b'); }
+          '\u1d52' => { f('\x6f'); }
+          '\u1d53' => { f('\u0254'); }
+          '\u1d54' => { f('\u1d16'); }
+          '\u1d55' => { f('\u1d17'); }
+          '\u1d56' => { f('\x70'); }
+          '\u1d57' => { f('\x74'); }
+          '\u1d58' => { f('\x75'); }
+          '\u1d59' => { f('\u1d1d'); }
+          '\u1d5a' => { f('\u026f'); }
+          '\u1d5b' => { f('\x76'); }
+          '\u1d5c' => { f('\u1d25'); }
+          '\u1d5d' => { f('\u03b2'); }
+          '\u1d5e' => { f('\u03b3'); }
+          '\u1d5f' => { f('\u03b4'); }
+          '\u1d60' => { f('\u03c6'); }
+          '\u1d61' => { f('\u03c7'); }
+          '\u1d62' => { f('\x69'); }
+          '\u1d63' => { f('\x72'); }
+          '\u1d64' => { f('\x75'); }
+          '\u1d65' => { f('\x76'); }
+          '\u1d66' => { f('\u03b2'); }
+          '\u1d67' => { f('\u03b3'); }
+          '\u1d68' => { f('\u03c1'); }
+          '\u1d69' => { f('\u03c6'); }
+          '\u1d6a' => { f('\u03c7'); }
+          '\u1d78' => { f('\u043d'); }
+          '\u1d9b' => { f('\u0252'); }
+          '\u1d9c' => { f('\x63'); }
+          '\u1d9d' => { f('\u0255'); }
+          '\u1d9e' => { f('\xf0'); }
+          '\u1d9f' => { f('\u025c'); }
+          '\u1da0' => { f('\x66'); }
+          '\u1da1' => { f('\u025f'); }
+          '\u1da2' => { f('\u0261'); }
+          '\u1da3' => { f('\u0265'); }
+          '\u1da4' => { f('\u0268'); }
+          '\u1da5' => { f('\u0269'); }
+          '\u1da6' => { f('\u026a'); }
+          '\u1da7' => { f('\u1d7b'); }
+          '\u1da8' => { f('\u029d'); }
+          '\u1da9' => { f('\u026d'); }
+          '\u1daa' => { f('\u1d85'); }
+          '\u1dab' => { f('\u029f'); }
+          '\u1dac' => { f('\u0271'); }
+          '\u1dad' => { f('\u0270'); }
+          '\u1dae' => { f('\u0272'); }
+          '\u1daf' => { f('\u0273'); }
+          '\u1db0' => { f('\u0274'); }
+          '\u1db1' => { f('\u0275'); }
+          '\u1db2' => { f('\u0278'); }
+          '\u1db3' => { f('\u0282'); }
+          '\u1db4' => { f('\u0283'); }
+          '\u1db5' => { f('\u01ab'); }
+          '\u1db6' => { f('\u0289'); }
+          '\u1db7' => { f('\u028a'); }
+          '\u1db8' => { f('\u1d1c'); }
+          '\u1db9' => { f('\u028b'); }
+          '\u1dba' => { f('\u028c'); }
+          '\u1dbb' => { f('\x7a'); }
+          '\u1dbc' => { f('\u0290'); }
+          '\u1dbd' => { f('\u0291'); }
+          '\u1dbe' => { f('\u0292'); }
+          '\u1dbf' => { f('\u03b8'); }
+          '\u1e9a' => { f('\x61'); f('\u02be'); }
+          '\u1e9b' => { f('\x73'); f('\u0307'); }
+          '\u1fbd' => { f('\x20'); f('\u0313'); }
+          '\u1fbf' => { f('\x20'); f('\u0313'); }
+          '\u1fc0' => { f('\x20'); f('\u0342'); }
+          '\u1fc1' => { f('\x20'); f('\u0308'); f('\u0342'); }
+          '\u1fcd' => { f('\x20'); f('\u0313'); f('\u0300'); }
+          '\u1fce' => { f('\x20'); f('\u0313'); f('\u0301'); }
+          '\u1fcf' => { f('\x20'); f('\u0313'); f('\u0342'); }
+          '\u1fdd' => { f('\x20'); f('\u0314'); f('\u0300'); }
+          '\u1fde' => { f('\x20'); f('\u0314'); f('\u0301'); }
+          '\u1fdf' => { f('\x20'); f('\u0314'); f('\u0342'); }
+          '\u1fed' => { f('\x20'); f('\u0308'); f('\u0300'); }
+          '\u1fee' => { f('\x20'); f('\u0308'); f('\u0301'); }
+          '\u1ffd' => { f('\x20'); f('\u0301'); }
+          '\u1ffe' => { f('\x20'); f('\u0314'); }
+          '\u2000' => { f('\x20'); }
+          '\u2001' => { f('\x20'); }
+          '\u2002' => { f('\x20'); }
+          '\u2003' => { f('\x20'); }
+          '\u2004' => { f('\x20'); }
+          '\u2005' => { f('\x20'); }
+          '\u2006' => { f('\x20'); }
+          '\u2007' => { f('\x20'); }
+          '\u2008' => { f('\x20'); }
+          '\u2009' => { f('\x20'); }
+          '\u200a' => { f('\x20'); }
+          '\u2011' => { f('\u2010'); }
+          '\u2017' => { f('\x20'); f('\u0333'); }
+          '\u2024' => { f('\x2e'); }
+          '\u2025' => { f('\x2e'); f('\x2e'); }
+          '\u2026' => { f('\x2e'); f('\x2e'); f('\x2e'); }
+          '\u202f' => { f('\x20'); }
+          '\u2033' => { f('\u2032'); f('\u2032'); }
+          '\u2034' => { f('\u2032'); f('\u2032'); f('\u2032'); }
+          '\u2036' => { f('\u2035'); f('\u2035'); }
+          '\u2037' => { f('\u2035'); f('\u2035'); f('\u2035'); }
+          '\u203c' => { f('\x21'); f('\x21'); }
+          '\u203e' => { f('\x20'); f('\u0305'); }
+          '\u2047' => { f('\x3f'); f('\x3f'); }
+          '\u2048' => { f('\x3f'); f('\x21'); }
+          '\u2049' => { f('\x21'); f('\x3f'); }
+          '\u2057' => { f('\u2032'); f('\u2032'); f('\u2032'); f('\u2032'); }
+          '\u205f' => { f('\x20'); }
+          '\u2070' => { f('\x30'); }
+          '\u2071' => { f('\x69'); }
+          '\u2074' => { f('\x34'); }
+          '\u2075' => { f('\x35'); }
+          '\u2076' => { f('\x36'); }
+          '\u2077' => { f('\x37'); }
+          '\u2078' => { f('\x38'); }
+          '\u2079' => { f('\x39'); }
+          '\u207a' => { f('\x2b'); }
+          '\u207b' => { f('\u2212'); }
+          '\u207c' => { f('\x3d'); }
+          '\u207d' => { f('\x28'); }
+          '\u207e' => { f('\x29'); }
+          '\u207f' => { f('\x6e'); }
+          '\u2080' => { f('\x30'); }
+          '\u2081' => { f('\x31'); }
+          '\u2082' => { f('\x32'); }
+          '\u2083' => { f('\x33'); }
+          '\u2084' => { f('\x34'); }
+          '\u2085' => { f('\x35'); }
+          '\u2086' => { f('\x36'); }
+          '\u2087' => { f('\x37'); }
+          '\u2088' => { f('\x38'); }
+          '\u2089' => { f('\x39'); }
+          '\u208a' => { f('\x2b'); }
+          '\u208b' => { f('\u2212'); }
+          '\u208c' => { f('\x3d'); }
+          '\u208d' => { f('\x28'); }
+          '\u208e' => { f('\x29'); }
+          '\u2090' => { f('\x61'); }
+          '\u2091' => { f('\x65'); }
+          '\u2092' => { f('\x6f'); }
+          '\u2093' => { f('\x78'); }
+          '\u2094' => { f('\u0259'); }
+          '\u2095' => { f('\x68'); }
+          '\u2096' => { f('\x6b'); }
+          '\u2097' => { f('\x6c'); }
+          '\u2098' => { f('\x6d'); }
+          '\u2099' => { f('\x6e'); }
+          '\u209a' => { f('\x70'); }
+          '\u209b' => { f('\x73'); }
+          '\u209c' => { f('\x74'); }
+          '\u20a8' => { f('\x52'); f('\x73'); }
+          '\u2100' => { f('\x61'); f('\x2f'); f('\x63'); }
+          '\u2101' => { f('\x61'); f('\x2f'); f('\x73'); }
+          '\u2102' => { f('\x43'); }
+          '\u2103' => { f('\xb0'); f('\x43'); }
+          '\u2105' => { f('\x63'); f('\x2f'); f('\x6f'); }
+          '\u2106' => { f('\x63'); f('\x2f'); f('\x75'); }
+          '\u2107' => { f('\u0190'); }
+          '\u2109' => { f('\xb0'); f('\x46'); }
+          '\u210a' => { f('\x67'); }
+          '\u210b' => { f('\x48'); }
+          '\u210c' => { f('\x48'); }
+          '\u210d' => { f('\x48'); }
+          '\u210e' => { f('\x68'); }
+          '\u210f' => { f('\u0127'); }
+          '\u2110' => { f('\x49'); }
+          '\u2111' => { f('\x49'); }
+          '\u2112' => { f('\x4c'); }
+          '\u2113' => { f('\x6c'); }
+          '\u2115' => { f('\x4e'); }
+          '\u2116' => { f('\x4e'); f('\x6f'); }
+          '\u2119' => { f('\x50'); }
+          '\u211a' => { f('\x51'); }
+          '\u211b' => { f('\x52'); }
+          '\u211c' => { f('\x52'); }
+          '\u211d' => { f('\x52'); }
+          '\u2120' => { f('\x53'); f('\x4d'); }
+          '\u2121' => { f('\x54'); f('\x45'); f('\x4c'); }
+          '\u2122' => { f('\x54'); f('\x4d'); }
+          '\u2124' => { f('\x5a'); }
+          '\u2128' => { f('\x5a'); }
+          '\u212c' => { f('\x42'); }
+          '\u212d' => { f('\x43'); }
+          '\u212f' => { f('\x65'); }
+          '\u2130' => { f('\x45'); }
+          '\u2131' => { f('\x46'); }
+          '\u2133' => { f('\x4d'); }
+          '\u2134' => { f('\x6f'); }
+          '\u2135' => { f('\u05d0'); }
+          '\u2136' => { f('\u05d1'); }
+          '\u2137' => { f('\u05d2'); }
+          '\u2138' => { f('\u05d3'); }
+          '\u2139' => { f('\x69'); }
+          '\u213b' => { f('\x46'); f('\x41'); f('\x58'); }
+          '\u213c' => { f('\u03c0'); }
+          '\u213d' => { f('\u03b3'); }
+          '\u213e' => { f('\u0393'); }
+          '\u213f' => { f('\u03a0'); }
+          '\u2140' => { f('\u2211'); }
+          '\u2145' => { f('\x44'); }
+          '\u2146' => { f('\x64'); }
+          '\u2147' => { f('\x65'); }
+          '\u2148' => { f('\x69'); }
+          '\u2149' => { f('\x6a'); }
+          '\u2150' => { f('\x31'); f('\u2044'); f('\x37'); }
+          '\u2151' => { f('\x31'); f('\u2044'); f('\x39'); }
+          '\u2152' => { f('\x31'); f('\u2044'); f('\x31'); f('\x30'); }
+          '\u2153' => { f('\x31'); f('\u2044'); f('\x33'); }
+          '\u2154' => { f('\x32'); f('\u2044'); f('\x33'); }
+          '\u2155' => { f('\x31'); f('\u2044'); f('\x35'); }
+          '\u2156' => { f('\x32'); f('\u2044'); f('\x35'); }
+          '\u2157' => { f('\x33'); f('\u2044'); f('\x35'); }
+          '\u2158' => { f('\x34'); f('\u2044'); f('\x35'); }
+          '\u2159' => { f('\x31'); f('\u2044'); f('\x36'); }
+          '\u215a' => { f('\x35'); f('\u2044'); f('\x36'); }
+          '\u215b' => { f('\x31'); f('\u2044'); f('\x38'); }
+          '\u215c' => { f('\x33'); f('\u2044'); f('\x38'); }
+          '\u215d' => { f('\x35'); f('\u2044'); f('\x38'); }
+          '\u215e' => { f('\x37'); f('\u2044'); f('\x38'); }
+          '\u215f' => { f('\x31'); f('\u2044'); }
+          '\u2160' => { f('\x49'); }
+          '\u2161' => { f('\x49'); f('\x49'); }
+          '\u2162' => { f('\x49'); f('\x49'); f('\x49'); }
+          '\u2163' => { f('\x49'); f('\x56'); }
+          '\u2164' => { f('\x56'); }
+          '\u2165' => { f('\x56'); f('\x49'); }
+          '\u2166' => { f('\x56'); f('\x49'); f('\x49'); }
+          '\u2167' => { f('\x56'); f('\x49'); f('\x49'); f('\x49'); }
+          '\u2168' => { f('\x49'); f('\x58'); }
+          '\u2169' => { f('\x58'); }
+          '\u216a' => { f('\x58'); f('\x49'); }
+          '\u216b' => { f('\x58'); f('\x49'); f('\x49'); }
+          '\u216c' => { f('\x4c'); }
+          '\u216d' => { f('\x43'); }
+          '\u216e' => { f('\x44'); }
+          '\u216f' => { f('\x4d'); }
+          '\u2170' => { f('\x69'); }
+          '\u2171' => { f('\x69'); f('\x69'); }
+          '\u2172' => { f('\x69'); f('\x69'); f('\x69'); }
+          '\u2173' => { f('\x69'); f('\x76'); }
+          '\u2174' => { f('\x76'); }
+          '\u2175' => { f('\x76'); f('\x69'); }
+          '\u2176' => { f('\x76'); f('\x69'); f('\x69'); }
+          '\u2177' => { f('\x76'); f('\x69'); f('\x69'); f('\x69'); }
+          '\u2178' => { f('\x69'); f('\x78'); }
+          '\u2179' => { f('\x78'); }
+          '\u217a' => { f('\x78'); f('\x69'); }
+          '\u217b' => { f('\x78'); f('\x69'); f('\x69'); }
+          '\u217c' => { f('\x6c'); }
+          '\u217d' => { f('\x63'); }
+          '\u217e' => { f('\x64'); }
+          '\u217f' => { f('\x6d'); }
+          '\u2189' => { f('\x30'); f('\u2044'); f('\x33'); }
+          '\u222c' => { f('\u222b'); f('\u222b'); }
+          '\u222d' => { f('\u222b'); f('\u222b'); f('\u222b'); }
+          '\u222f' => { f('\u222e'); f('\u222e'); }
+          '\u2230' => { f('\u222e'); f('\u222e'); f('\u222e'); }
+          '\u2460' => { f('\x31'); }
+          '\u2461' => { f('\x32'); }
+          '\u2462' => { f('\x33'); }
+          '\u2463' => { f('\x34'); }
+          '\u2464' => { f('\x35'); }
+          '\u2465' => { f('\x36'); }
+          '\u2466' => { f('\x37'); }
+          '\u2467' => { f('\x38'); }
+          '\u2468' => { f('\x39'); }
+          '\u2469' => { f('\x31'); f('\x30'); }
+          '\u246a' => { f('\x31'); f('\x31'); }
+          '\u246b' => { f('\x31'); f('\x32'); }
+          '\u246c' => { f('\x31'); f('\x33'); }
+          '\u246d' => { f('\x31'); f('\x34'); }
+          '\u246e' => { f('\x31'); f('\x35'); }
+          '\u246f' => { f('\x31'); f('\x36'); }
+          '\u2470' => { f('\x31'); f('\x37'); }
+          '\u2471' => { f('\x31'); f('\x38'); }
+          '\u2472' => { f('\x31'); f('\x39'); }
+          '\u2473' => { f('\x32'); f('\x30'); }
+          '\u2474' => { f('\x28'); f('\x31'); f('\x29'); }
+          '\u2475' => { f('\x28'); f('\x32'); f('\x29'); }
+          '\u2476' => { f('\x28'); f('\x33'); f('\x29'); }
+          '\u2477' => { f('\x28'); f('\x34'); f('\x29'); }
+          '\u2478' => { f('\x28'); f('\x35'); f('\x29'); }
+          '\u2479' => { f('\x28'); f('\x36'); f('\x29'); }
+          '\u247a' => { f('\x28'); f('\x37'); f('\x29'); }
+          '\u247b' => { f('\x28'); f('\x38'); f('\x29'); }
+          '\u247c' => { f('\x28'); f('\x39'); f('\x29'); }
+          '\u247d' => { f('\x28'); f('\x31'); f('\x30'); f('\x29'); }
+          '\u247e' => { f('\x28'); f('\x31'); f('\x31'); f('\x29'); }
+          '\u247f' => { f('\x28'); f('\x31'); f('\x32'); f('\x29'); }
+          '\u2480' => { f('\x28'); f('\x31'); f('\x33'); f('\x29'); }
+          '\u2481' => { f('\x28'); f('\x31'); f('\x34'); f('\x29'); }
+          '\u2482' => { f('\x28'); f('\x31'); f('\x35'); f('\x29'); }
+          '\u2483' => { f('\x28'); f('\x31'); f('\x36'); f('\x29'); }
+          '\u2484' => { f('\x28'); f('\x31'); f('\x37'); f('\x29'); }
+          '\u2485' => { f('\x28'); f('\x31'); f('\x38'); f('\x29'); }
+          '\u2486' => { f('\x28'); f('\x31'); f('\x39'); f('\x29'); }
+          '\u2487' => { f('\x28'); f('\x32'); f('\x30'); f('\x29'); }
+          '\u2488' => { f('\x31'); f('\x2e'); }
+          '\u2489' => { f('\x32'); f('\x2e'); }
+          '\u248a' => { f('\x33'); f('\x2e'); }
+          '\u248b' => { f('\x34'); f('\x2e'); }
+          '\u248c' => { f('\x35'); f('\x2e'); }
+          '\u248d' => { f('\x36'); f('\x2e'); }
+          '\u248e' => { f('\x37'); f('\x2e'); }
+          '\u248f' => { f('\x38'); f('\x2e'); }
+          '\u2490' => { f('\x39'); f('\x2e'); }
+          '\u2491' => { f('\x31'); f('\x30'); f('\x2e'); }
+          '\u2492' => { f('\x31'); f('\x31'); f('\x2e'); }
+          '\u2493' => { f('\x31'); f('\x32'); f('\x2e'); }
+          '\u2494' => { f('\x31'); f('\x33'); f('\x2e'); }
+          '\u2495' => { f('\x31'); f('\x34'); f('\x2e'); }
+          '\u2496' => { f('\x31'); f('\x35'); f('\x2e'); }
+          '\u2497' => { f('\x31'); f('\x36'); f('\x2e'); }
+          '\u2498' => { f('\x31'); f('\x37'); f('\x2e'); }
+          '\u2499' => { f('\x31'); f('\x38'); f('\x2e'); }
+          '\u249a' => { f('\x31'); f('\x39'); f('\x2e'); }
+          '\u249b' => { f('\x32'); f('\x30'); f('\x2e'); }
+          '\u249c' => { f('\x28'); f('\x61'); f('\x29'); }
+          '\u249d' => { f('\x28'); f('\x62'); f('\x29'); }
+          '\u249e' => { f('\x28'); f('\x63'); f('\x29'); }
+          '\u249f' => { f('\x28'); f('\x64'); f('\x29'); }
+          '\u24a0' => { f('\x28'); f('\x65'); f('\x29'); }
+          '\u24a1' => { f('\x28'); f('\x66'); f('\x29'); }
+          '\u24a2' => { f('\x28'); f('\x67'); f('\x29'); }
+          '\u24a3' => { f('\x28'); f('\x68'); f('\x29'); }
+          '\u24a4' => { f('\x28'); f('\x69'); f('\x29'); }
+          '\u24a5' => { f('\x28'); f('\x6a'); f('\x29'); }
+          '\u24a6' => { f('\x28'); f('\x6b'); f('\x29'); }
+          '\u24a7' => { f('\x28'); f('\x6c'); f('\x29'); }
+          '\u24a8' => { f('\x28'); f('\x6d'); f('\x29'); }
+          '\u24a9' => { f('\x28'); f('\x6e'); f('\x29'); }
+          '\u24aa' => { f('\x28'); f('\x6f'); f('\x29'); }
+          '\u24ab' => { f('\x28'); f('\x70'); f('\x29'); }
+          '\u24ac' => { f('\x28'); f('\x71'); f('\x29'); }
+          '\u24ad' => { f('\x28'); f('\x72'); f('\x29'); }
+          '\u24ae' => { f('\x28'); f('\x73'); f('\x29'); }
+          '\u24af' => { f('\x28'); f('\x74'); f('\x29'); }
+          '\u24b0' => { f('\x28'); f('\x75'); f('\x29'); }
+          '\u24b1' => { f('\x28'); f('\x76'); f('\x29'); }
+          '\u24b2' => { f('\x28'); f('\x77'); f('\x29'); }
+          '\u24b3' => { f('\x28'); f('\x78'); f('\x29'); }
+          '\u24b4' => { f('\x28'); f('\x79'); f('\x29'); }
+          '\u24b5' => { f('\x28'); f('\x7a'); f('\x29'); }
+          '\u24b6' => { f('\x41'); }
+          '\u24b7' => { f('\x42'); }
+          '\u24b8' => { f('\x43'); }
+          '\u24b9' => { f('\x44'); }
+          '\u24ba' => { f('\x45'); }
+          '\u24bb' => { f('\x46'); }
+          '\u24bc' => { f('\x47'); }
+          '\u24bd' => { f('\x48'); }
+          '\u24be' => { f('\x49'); }
+          '\u24bf' => { f('\x4a'); }
+          '\u24c0' => { f('\x4b'); }
+          '\u24c1' => { f('\x4c'); }
+          '\u24c2' => { f('\x4d'); }
+          '\u24c3' => { f('\x4e'); }
+          '\u24c4' => { f('\x4f'); }
+          '\u24c5' => { f('\x50'); }
+          '\u24c6' => { f('\x51'); }
+          '\u24c7' => { f('\x52'); }
+          '\u24c8' => { f('\x53'); }
+          '\u24c9' => { f('\x54'); }
+          '\u24ca' => { f('\x55'); }
+          '\u24cb' => { f('\x56'); }
+          '\u24cc' => { f('\x57'); }
+          '\u24cd' => { f('\x58'); }
+          '\u24ce' => { f('\x59'); }
+          '\u24cf' => { f('\x5a'); }
+          '\u24d0' => { f('\x61'); }
+          '\u24d1' => { f('\x62'); }
+          '\u24d2' => { f('\x63'); }
+          '\u24d3' => { f('\x64'); }
+          '\u24d4' => { f('\x65'); }
+          '\u24d5' => { f('\x66'); }
+          '\u24d6' => { f('\x67'); }
+          '\u24d7' => { f('\x68'); }
+          '\u24d8' => { f('\x69'); }
+          '\u24d9' => { f('\x6a'); }
+          '\u24da' => { f('\x6b'); }
+          '\u24db' => { f('\x6c'); }
+          '\u24dc' => { f('\x6d'); }
+          '\u24dd' => { f('\x6e'); }
+          '\u24de' => { f('\x6f'); }
+          '\u24df' => { f('\x70'); }
+          '\u24e0' => { f('\x71'); }
+          '\u24e1' => { f('\x72'); }
+          '\u24e2' => { f('\x73'); }
+          '\u24e3' => { f('\x74'); }
+          '\u24e4' => { f('\x75'); }
+          '\u24e5' => { f('\x76'); }
+          '\u24e6' => { f('\x77'); }
+          '\u24e7' => { f('\x78'); }
+          '\u24e8' => { f('\x79'); }
+          '\u24e9' => { f('\x7a'); }
+          '\u24ea' => { f('\x30'); }
+          '\u2a0c' => { f('\u222b'); f('\u222b'); f('\u222b'); f('\u222b'); }
+          '\u2a74' => { f('\x3a'); f('\x3a'); f('\x3d'); }
+          '\u2a75' => { f('\x3d'); f('\x3d'); }
+          '\u2a76' => { f('\x3d'); f('\x3d'); f('\x3d'); }
+          '\u2c7c' => { f('\x6a'); }
+          '\u2c7d' => { f('\x56'); }
+          '\u2d6f' => { f('\u2d61'); }
+          '\u2e9f' => { f('\u6bcd'); }
+          '\u2ef3' => { f('\u9f9f'); }
+          '\u2f00' => { f('\u4e00'); }
+          '\u2f01' => { f('\u4e28'); }
+          '\u2f02' => { f('\u4e36'); }
+          '\u2f03' => { f('\u4e3f'); }
+          '\u2f04' => { f('\u4e59'); }
+          '\u2f05' => { f('\u4e85'); }
+          '\u2f06' => { f('\u4e8c'); }
+          '\u2f07' => { f('\u4ea0'); }
+          '\u2f08' => { f('\u4eba'); }
+          '\u2f09' => { f('\u513f'); }
+          '\u2f0a' => { f('\u5165'); }
+          '\u2f0b' => { f('\u516b'); }
+          '\u2f0c' => { f('\u5182'); }
+          '\u2f0d' => { f('\u5196'); }
+          '\u2f0e' => { f('\u51ab'); }
+          '\u2f0f' => { f('\u51e0'); }
+          '\u2f10' => { f('\u51f5'); }
+          '\u2f11' => { f('\u5200'); }
+          '\u2f12' => { f('\u529b'); }
+          '\u2f13' => { f('\u52f9'); }
+          '\u2f14' => { f('\u5315'); }
+          '\u2f15' => { f('\u531a'); }
+          '\u2f16' => { f('\u5338'); }
+          '\u2f17' => { f('\u5341'); }
+          '\u2f18' => { f('\u535c'); }
+          '\u2f19' => { f('\u5369'); }
+          '\u2f1a' => { f('\u5382'); }
+          '\u2f1b' => { f('\u53b6'); }
+          '\u2f1c' => { f('\u53c8'); }
+          '\u2f1d' => { f('\u53e3'); }
+          '\u2f1e' => { f('\u56d7'); }
+          '\u2f1f' => { f('\u571f'); }
+          '\u2f20' => { f('\u58eb'); }
+          '\u2f21' => { f('\u5902'); }
+          '\u2f22' => { f('\u590a'); }
+          '\u2f23' => { f('\u5915'); }
+          '\u2f24' => { f('\u5927'); }
+          '\u2f25' => { f('\u5973'); }
+          '\u2f26' => { f('\u5b50'); }
+          '\u2f27' => { f('\u5b80'); }
+          '\u2f28' => { f('\u5bf8'); }
+          '\u2f29' => { f('\u5c0f'); }
+          '\u2f2a' => { f('\u5c22'); }
+          '\u2f2b' => { f('\u5c38'); }
+          '\u2f2c' => { f('\u5c6e'); }
+          '\u2f2d' => { f('\u5c71'); }
+          '\u2f2e' => { f('\u5ddb'); }
+          '\u2f2f' => { f('\u5de5'); }
+          '\u2f30' => { f('\u5df1'); }
+          '\u2f31' => { f('\u5dfe'); }
+          '\u2f32' => { f('\u5e72'); }
+          '\u2f33' => { f('\u5e7a'); }
+          '\u2f34' => { f('\u5e7f'); }
+          '\u2f35' => { f('\u5ef4'); }
+          '\u2f36' => { f('\u5efe'); }
+          '\u2f37' => { f('\u5f0b'); }
+          '\u2f38' => { f('\u5f13'); }
+          '\u2f39' => { f('\u5f50'); }
+          '\u2f3a' => { f('\u5f61'); }
+          '\u2f3b' => { f('\u5f73'); }
+          '\u2f3c' => { f('\u5fc3'); }
+          '\u2f3d' => { f('\u6208'); }
+          '\u2f3e' => { f('\u6236'); }
+          '\u2f3f' => { f('\u624b'); }
+          '\u2f40' => { f('\u652f'); }
+          '\u2f41' => { f('\u6534'); }
+          '\u2f42' => { f('\u6587'); }
+          '\u2f43' => { f('\u6597'); }
+          '\u2f44' => { f('\u65a4'); }
+          '\u2f45' => { f('\u65b9'); }
+          '\u2f46' => { f('\u65e0'); }
+          '\u2f47' => { f('\u65e5'); }
+          '\u2f48' => { f('\u66f0'); }
+          '\u2f49' => { f('\u6708'); }
+          '\u2f4a' => { f('\u6728'); }
+          '\u2f4b' => { f('\u6b20'); }
+          '\u2f4c' => { f('\u6b62'); }
+          '\u2f4d' => { f('\u6b79'); }
+          '\u2f4e' => { f('\u6bb3'); }
+          '\u2f4f' => { f('\u6bcb'); }
+          '\u2f50' => { f('\u6bd4'); }
+          '\u2f51' => { f('\u6bdb'); }
+          '\u2f52' => { f('\u6c0f'); }
+          '\u2f53' => { f('\u6c14'); }
+          '\u2f54' => { f('\u6c34'); }
+          '\u2f55' => { f('\u706b'); }
+          '\u2f56' => { f('\u722a'); }
+          '\u2f57' => { f('\u7236'); }
+          '\u2f58' => { f('\u723b'); }
+          '\u2f59' => { f('\u723f'); }
+          '\u2f5a' => { f('\u7247'); }
+          '\u2f5b' => { f('\u7259'); }
+          '\u2f5c' => { f('\u725b'); }
+          '\u2f5d' => { f('\u72ac'); }
+          '\u2f5e' => { f('\u7384'); }
+          '\u2f5f' => { f('\u7389'); }
+          '\u2f60' => { f('\u74dc'); }
+          '\u2f61' => { f('\u74e6'); }
+          '\u2f62' => { f('\u7518'); }
+          '\u2f63' => { f('\u751f'); }
+          '\u2f64' => { f('\u7528'); }
+          '\u2f65' => { f('\u7530'); }
+          '\u2f66' => { f('\u758b'); }
+          '\u2f67' => { f('\u7592'); }
+          '\u2f68' => { f('\u7676'); }
+          '\u2f69' => { f('\u767d'); }
+          '\u2f6a' => { f('\u76ae'); }
+          '\u2f6b' => { f('\u76bf'); }
+          '\u2f6c' => { f('\u76ee'); }
+          '\u2f6d' => { f('\u77db'); }
+          '\u2f6e' => { f('\u77e2'); }
+          '\u2f6f' => { f('\u77f3'); }
+          '\u2f70' => { f('\u793a'); }
+          '\u2f71' => { f('\u79b8'); }
+          '\u2f72' => { f('\u79be'); }
+          '\u2f73' => { f('\u7a74'); }
+          '\u2f74' => { f('\u7acb'); }
+          '\u2f75' => { f('\u7af9'); }
+          '\u2f76' => { f('\u7c73'); }
+          '\u2f77' => { f('\u7cf8'); }
+          '\u2f78' => { f('\u7f36'); }
+          '\u2f79' => { f('\u7f51'); }
+          '\u2f7a' => { f('\u7f8a'); }
+          '\u2f7b' => { f('\u7fbd'); }
+          '\u2f7c' => { f('\u8001'); }
+          '\u2f7d' => { f('\u800c'); }
+          '\u2f7e' => { f('\u8012'); }
+          '\u2f7f' => { f('\u8033'); }
+          '\u2f80' => { f('\u807f'); }
+          '\u2f81' => { f('\u8089'); }
+          '\u2f82' => { f('\u81e3'); }
+          '\u2f83' => { f('\u81ea'); }
+          '\u2f84' => { f('\u81f3'); }
+          '\u2f85' => { f('\u81fc'); }
+          '\u2f86' => { f('\u820c'); }
+          '\u2f87' => { f('\u821b'); }
+          '\u2f88' => { f('\u821f'); }
+          '\u2f89' => { f('\u826e'); }
+          '\u2f8a' => { f('\u8272'); }
+          '\u2f8b' => { f('\u8278'); }
+          '\u2f8c' => { f('\u864d'); }
+          '\u2f8d' => { f('\u866b'); }
+          '\u2f8e' => { f('\u8840'); }
+          '\u2f8f' => { f('\u884c'); }
+          '\u2f90' => { f('\u8863'); }
+          '\u2f91' => { f('\u897e'); }
+          '\u2f92' => { f('\u898b'); }
+          '\u2f93' => { f('\u89d2'); }
+          '\u2f94' => { f('\u8a00'); }
+          '\u2f95' => { f('\u8c37'); }
+          '\u2f96' => { f('\u8c46'); }
+          '\u2f97' => { f('\u8c55'); }
+          '\u2f98' => { f('\u8c78'); }
+          '\u2f99' => { f('\u8c9d'); }
+          '\u2f9a' => { f('\u8d64'); }
+          '\u2f9b' => { f('\u8d70'); }
+          '\u2f9c' => { f('\u8db3'); }
+          '\u2f9d' => { f('\u8eab'); }
+          '\u2f9e' => { f('\u8eca'); }
+          '\u2f9f' => { f('\u8f9b'); }
+          '\u2fa0' => { f('\u8fb0'); }
+          '\u2fa1' => { f('\u8fb5'); }
+          '\u2fa2' => { f('\u9091'); }
+          '\u2fa3' => { f('\u9149'); }
+          '\u2fa4' => { f('\u91c6'); }
+          '\u2fa5' => { f('\u91cc'); }
+          '\u2fa6' => { f('\u91d1'); }
+          '\u2fa7' => { f('\u9577'); }
+          '\u2fa8' => { f('\u9580'); }
+          '\u2fa9' => { f('\u961c'); }
+          '\u2faa' => { f('\u96b6'); }
+          '\u2fab' => { f('\u96b9'); }
+          '\u2fac' => { f('\u96e8'); }
+          '\u2fad' => { f('\u9751'); }
+          '\u2fae' => { f('\u975e'); }
+          '\u2faf' => { f('\u9762'); }
+          '\u2fb0' => { f('\u9769'); }
+          '\u2fb1' => { f('\u97cb'); }
+          '\u2fb2' => { f('\u97ed'); }
+          '\u2fb3' => { f('\u97f3'); }
+          '\u2fb4' => { f('\u9801'); }
+          '\u2fb5' => { f('\u98a8'); }
+          '\u2fb6' => { f('\u98db'); }
+          '\u2fb7' => { f('\u98df'); }
+          '\u2fb8' => { f('\u9996'); }
+          '\u2fb9' => { f('\u9999'); }
+          '\u2fba' => { f('\u99ac'); }
+          '\u2fbb' => { f('\u9aa8'); }
+          '\u2fbc' => { f('\u9ad8'); }
+          '\u2fbd' => { f('\u9adf'); }
+          '\u2fbe' => { f('\u9b25'); }
+          '\u2fbf' => { f('\u9b2f'); }
+          '\u2fc0' => { f('\u9b32'); }
+          '\u2fc1' => { f('\u9b3c'); }
+          '\u2fc2' => { f('\u9b5a'); }
+          '\u2fc3' => { f('\u9ce5'); }
+          '\u2fc4' => { f('\u9e75'); }
+          '\u2fc5' => { f('\u9e7f'); }
+          '\u2fc6' => { f('\u9ea5'); }
+          '\u2fc7' => { f('\u9ebb'); }
+          '\u2fc8' => { f('\u9ec3'); }
+          '\u2fc9' => { f('\u9ecd'); }
+          '\u2fca' => { f('\u9ed1'); }
+          '\u2fcb' => { f('\u9ef9'); }
+          '\u2fcc' => { f('\u9efd'); }
+          '\u2fcd' => { f('\u9f0e'); }
+          '\u2fce' => { f('\u9f13'); }
+          '\u2fcf' => { f('\u9f20'); }
+          '\u2fd0' => { f('\u9f3b'); }
+          '\u2fd1' => { f('\u9f4a'); }
+          '\u2fd2' => { f('\u9f52'); }
+          '\u2fd3' => { f('\u9f8d'); }
+          '\u2fd4' => { f('\u9f9c'); }
+          '\u2fd5' => { f('\u9fa0'); }
+          '\u3000' => { f('\x20'); }
+          '\u3036' => { f('\u3012'); }
+          '\u3038' => { f('\u5341'); }
+          '\u3039' => { f('\u5344'); }
+          '\u303a' => { f('\u5345'); }
+          '\u309b' => { f('\x20'); f('\u3099'); }
+          '\u309c' => { f('\x20'); f('\u309a'); }
+          '\u309f' => { f('\u3088'); f('\u308a'); }
+          '\u30ff' => { f('\u30b3'); f('\u30c8'); }
+          '\u3131' => { f('\u1100'); }
+          '\u3132' => { f('\u1101'); }
+          '\u3133' => { f('\u11aa'); }
+          '\u3134' => { f('\u1102'); }
+          '\u3135' => { f('\u11ac'); }
+          '\u3136' => { f('\u11ad'); }
+          '\u3137' => { f('\u1103'); }
+          '\u3138' => { f('\u1104'); }
+          '\u3139' => { f('\u1105'); }
+          '\u313a' => { f('\u11b0'); }
+          '\u313b' => { f('\u11b1'); }
+          '\u313c' => { f('\u11b2'); }
+          '\u313d' => { f('\u11b3'); }
+          '\u313e' => { f('\u11b4'); }
+          '\u313f' => { f('\u11b5'); }
+          '\u3140' => { f('\u111a'); }
+          '\u3141' => { f('\u1106'); }
+          '\u3142' => { f('\u1107'); }
+          '\u3143' => { f('\u1108'); }
+          '\u3144' => { f('\u1121'); }
+          '\u3145' => { f('\u1109'); }
+          '\u3146' => { f('\u110a'); }
+          '\u3147' => { f('\u110b'); }
+          '\u3148' => { f('\u110c'); }
+          '\u3149' => { f('\u110d'); }
+          '\u314a' => { f('\u110e'); }
+          '\u314b' => { f('\u110f'); }
+          '\u314c' => { f('\u1110'); }
+          '\u314d' => { f('\u1111'); }
+          '\u314e' => { f('\u1112'); }
+          '\u314f' => { f('\u1161'); }
+          '\u3150' => { f('\u1162'); }
+          '\u3151' => { f('\u1163'); }
+          '\u3152' => { f('\u1164'); }
+          '\u3153' => { f('\u1165'); }
+          '\u3154' => { f('\u1166'); }
+          '\u3155' => { f('\u1167'); }
+          '\u3156' => { f('\u1168'); }
+          '\u3157' => { f('\u1169'); }
+          '\u3158' => { f('\u116a'); }
+          '\u3159' => { f('\u116b'); }
+          '\u315a' => { f('\u116c'); }
+          '\u315b' => { f('\u116d'); }
+          '\u315c' => { f('\u116e'); }
+          '\u315d' => { f('\u116f'); }
+          '\u315e' => { f('\u1170'); }
+          '\u315f' => { f('\u1171'); }
+          '\u3160' => { f('\u1172'); }
+          '\u3161' => { f('\u1173'); }
+          '\u3162' => { f('\u1174'); }
+          '\u3163' => { f('\u1175'); }
+          '\u3164' => { f('\u1160'); }
+          '\u3165' => { f('\u1114'); }
+          '\u3166' => { f('\u1115'); }
+          '\u3167' => { f('\u11c7'); }
+          '\u3168' => { f('\u11c8'); }
+          '\u3169' => { f('\u11cc'); }
+          '\u316a' => { f('\u11ce'); }
+          '\u316b' => { f('\u11d3'); }
+          '\u316c' => { f('\u11d7'); }
+          '\u316d' => { f('\u11d9'); }
+          '\u316e' => { f('\u111c'); }
+          '\u316f' => { f('\u11dd'); }
+          '\u3170' => { f('\u11df'); }
+          '\u3171' => { f('\u111d'); }
+          '\u3172' => { f('\u111e'); }
+          '\u3173' => { f('\u1120'); }
+          '\u3174' => { f('\u1122'); }
+          '\u3175' => { f('\u1123'); }
+          '\u3176' => { f('\u1127'); }
+          '\u3177' => { f('\u1129'); }
+          '\u3178' => { f('\u112b'); }
+          '\u3179' => { f('\u112c'); }
+          '\u317a' => { f('\u112d'); }
+          '\u317b' => { f('\u112e'); }
+          '\u317c' => { f('\u112f'); }
+          '\u317d' => { f('\u1132'); }
+          '\u317e' => { f('\u1136'); }
+          '\u317f' => { f('\u1140'); }
+          '\u3180' => { f('\u1147'); }
+          '\u3181' => { f('\u114c'); }
+          '\u3182' => { f('\u11f1'); }
+          '\u3183' => { f('\u11f2'); }
+          '\u3184' => { f('\u1157'); }
+          '\u3185' => { f('\u1158'); }
+          '\u3186' => { f('\u1159'); }
+          '\u3187' => { f('\u1184'); }
+          '\u3188' => { f('\u1185'); }
+          '\u3189' => { f('\u1188'); }
+          '\u318a' => { f('\u1191'); }
+          '\u318b' => { f('\u1192'); }
+          '\u318c' => { f('\u1194'); }
+          '\u318d' => { f('\u119e'); }
+          '\u318e' => { f('\u11a1'); }
+          '\u3192' => { f('\u4e00'); }
+          '\u3193' => { f('\u4e8c'); }
+          '\u3194' => { f('\u4e09'); }
+          '\u3195' => { f('\u56db'); }
+          '\u3196' => { f('\u4e0a'); }
+          '\u3197' => { f('\u4e2d'); }
+          '\u3198' => { f('\u4e0b'); }
+          '\u3199' => { f('\u7532'); }
+          '\u319a' => { f('\u4e59'); }
+          '\u319b' => { f('\u4e19'); }
+          '\u319c' => { f('\u4e01'); }
+          '\u319d' => { f('\u5929'); }
+          '\u319e' => { f('\u5730'); }
+          '\u319f' => { f('\u4eba'); }
+          '\u3200' => { f('\x28'); f('\u1100'); f('\x29'); }
+          '\u3201' => { f('\x28'); f('\u1102'); f('\x29'); }
+          '\u3202' => { f('\x28'); f('\u1103'); f('\x29'); }
+          '\u3203' => { f('\x28'); f('\u1105'); f('\x29'); }
+          '\u3204' => { f('\x28'); f('\u1106'); f('\x29'); }
+          '\u3205' => { f('\x28'); f('\u1107'); f('\x29'); }
+          '\u3206' => { f('\x28'); f('\u1109'); f('\x29'); }
+          '\u3207' => { f('\x28'); f('\u110b'); f('\x29'); }
+          '\u3208' => { f('\x28'); f('\u110c'); f('\x29'); }
+          '\u3209' => { f('\x28'); f('\u110e'); f('\x29'); }
+          '\u320a' => { f('\x28'); f('\u110f'); f('\x29'); }
+          '\u320b' => { f('\x28'); f('\u1110'); f('\x29'); }
+          '\u320c' => { f('\x28'); f('\u1111'); f('\x29'); }
+          '\u320d' => { f('\x28'); f('\u1112'); f('\x29'); }
+          '\u320e' => { f('\x28'); f('\u1100'); f('\u1161'); f('\x29'); }
+          '\u320f' => { f('\x28'); f('\u1102'); f('\u1161'); f('\x29'); }
+          '\u3210' => { f('\x28'); f('\u1103'); f('\u1161'); f('\x29'); }
+          '\u3211' => { f('\x28'); f('\u1105'); f('\u1161'); f('\x29'); }
+          '\u3212' => { f('\x28'); f('\u1106'); f('\u1161'); f('\x29'); }
+          '\u3213' => { f('\x28'); f('\u1107'); f('\u1161'); f('\x29'); }
+          '\u3214' => { f('\x28'); f('\u1109'); f('\u1161'); f('\x29'); }
+          '\u3215' => { f('\x28'); f('\u110b'); f('\u1161'); f('\x29'); }
+          '\u3216' => { f('\x28'); f('\u110c'); f('\u1161'); f('\x29'); }
+          '\u3217' => { f('\x28'); f('\u110e'); f('\u1161'); f('\x29'); }
+          '\u3218' => { f('\x28'); f('\u110f'); f('\u1161'); f('\x29'); }
+          '\u3219' => { f('\x28'); f('\u1110'); f('\u1161'); f('\x29'); }
+          '\u321a' => { f('\x28'); f('\u1111'); f('\u1161'); f('\x29'); }
+          '\u321b' => { f('\x28'); f('\u1112'); f('\u1161'); f('\x29'); }
+          '\u321c' => { f('\x28'); f('\u110c'); f('\u116e'); f('\x29'); }
+          '\u321d' => {
+            f('\x28'); f('\u110b'); f('\u1169'); f('\u110c'); f('\u1165');
+            f('\u11ab'); f('\x29');
+          }
+          '\u321e' => {
+            f('\x28'); f('\u110b'); f('\u1169'); f('\u1112'); f('\u116e');
+            f('\x29');
+          }
+          '\u3220' => { f('\x28'); f('\u4e00'); f('\x29'); }
+          '\u3221' => { f('\x28'); f('\u4e8c'); f('\x29'); }
+          '\u3222' => { f('\x28'); f('\u4e09'); f('\x29'); }
+          '\u3223' => { f('\x28'); f('\u56db'); f('\x29'); }
+          '\u3224' => { f('\x28'); f('\u4e94'); f('\x29'); }
+          '\u3225' => { f('\x28'); f('\u516d'); f('\x29'); }
+          '\u3226' => { f('\x28'); f('\u4e03'); f('\x29'); }
+          '\u3227' => { f('\x28'); f('\u516b'); f('\x29'); }
+          '\u3228' => { f('\x28'); f('\u4e5d'); f('\x29'); }
+          '\u3229' => { f('\x28'); f('\u5341'); f('\x29'); }
+          '\u322a' => { f('\x28'); f('\u6708'); f('\x29'); }
+          '\u322b' => { f('\x28'); f('\u706b'); f('\x29'); }
+          '\u322c' => { f('\x28'); f('\u6c34'); f('\x29'); }
+          '\u322d' => { f('\x28'); f('\u6728'); f('\x29'); }
+          '\u322e' => { f('\x28'); f('\u91d1'); f('\x29'); }
+          '\u322f' => { f('\x28'); f('\u571f'); f('\x29'); }
+          '\u3230' => { f('\x28'); f('\u65e5'); f('\x29'); }
+          '\u3231' => { f('\x28'); f('\u682a'); f('\x29'); }
+          '\u3232' => { f('\x28'); f('\u6709'); f('\x29'); }
+          '\u3233' => { f('\x28'); f('\u793e'); f('\x29'); }
+          '\u3234' => { f('\x28'); f('\u540d'); f('\x29'); }
+          '\u3235' => { f('\x28'); f('\u7279'); f('\x29'); }
+          '\u3236' => { f('\x28'); f('\u8ca1'); f('\x29'); }
+          '\u3237' => { f('\x28'); f('\u795d'); f('\x29'); }
+          '\u3238' => { f('\x28'); f('\u52b4'); f('\x29'); }
+          '\u3239' => { f('\x28'); f('\u4ee3'); f('\x29'); }
+          '\u323a' => { f('\x28'); f('\u547c'); f('\x29'); }
+          '\u323b' => { f('\x28'); f('\u5b66'); f('\x29'); }
+          '\u323c' => { f('\x28'); f('\u76e3'); f('\x29'); }
+          '\u323d' => { f('\x28'); f('\u4f01'); f('\x29'); }
+          '\u323e' => { f('\x28'); f('\u8cc7'); f('\x29'); }
+          '\u323f' => { f('\x28'); f('\u5354'); f('\x29'); }
+          '\u3240' => { f('\x28'); f('\u796d'); f('\x29'); }
+          '\u3241' => { f('\x28'); f('\u4f11'); f('\x29'); }
+          '\u3242' => { f('\x28'); f('\u81ea'); f('\x29'); }
+          '\u3243' => { f('\x28'); f('\u81f3'); f('\x29'); }
+          '\u3244' => { f('\u554f'); }
+          '\u3245' => { f('\u5e7c'); }
+          '\u3246' => { f('\u6587'); }
+          '\u3247' => { f('\u7b8f'); }
+          '\u3250' => { f('\x50'); f('\x54'); f('\x45'); }
+          '\u3251' => { f('\x32'); f('\x31'); }
+          '\u3252' => { f('\x32'); f('\x32'); }
+          '\u3253' => { f('\x32'); f('\x33'); }
+          '\u3254' => { f('\x32'); f('\x34'); }
+          '\u3255' => { f('\x32'); f('\x35'); }
+          '\u3256' => { f('\x32'); f('\x36'); }
+          '\u3257' => { f('\x32'); f('\x37'); }
+          '\u3258' => { f('\x32'); f('\x38'); }
+          '\u3259' => { f('\x32'); f('\x39'); }
+          '\u325a' => { f('\x33'); f('\x30'); }
+          '\u325b' => { f('\x33'); f('\x31'); }
+          '\u325c' => { f('\x33'); f('\x32'); }
+          '\u325d' => { f('\x33'); f('\x33'); }
+          '\u325e' => { f('\x33'); f('\x34'); }
+          '\u325f' => { f('\x33'); f('\x35'); }
+          '\u3260' => { f('\u1100'); }
+          '\u3261' => { f('\u1102'); }
+          '\u3262' => { f('\u1103'); }
+          '\u3263' => { f('\u1105'); }
+          '\u3264' => { f('\u1106'); }
+          '\u3265' => { f('\u1107'); }
+          '\u3266' => { f('\u1109'); }
+          '\u3267' => { f('\u110b'); }
+          '\u3268' => { f('\u110c'); }
+          '\u3269' => { f('\u110e'); }
+          '\u326a' => { f('\u110f'); }
+          '\u326b' => { f('\u1110'); }
+          '\u326c' => { f('\u1111'); }
+          '\u326d' => { f('\u1112'); }
+          '\u326e' => { f('\u1100'); f('\u1161'); }
+          '\u326f' => { f('\u1102'); f('\u1161'); }
+          '\u3270' => { f('\u1103'); f('\u1161'); }
+          '\u3271' => { f('\u1105'); f('\u1161'); }
+          '\u3272' => { f('\u1106'); f('\u1161'); }
+          '\u3273' => { f('\u1107'); f('\u1161'); }
+          '\u3274' => { f('\u1109'); f('\u1161'); }
+          '\u3275' => { f('\u110b'); f('\u1161'); }
+          '\u3276' => { f('\u110c'); f('\u1161'); }
+          '\u3277' => { f('\u110e'); f('\u1161'); }
+          '\u3278' => { f('\u110f'); f('\u1161'); }
+          '\u3279' => { f('\u1110'); f('\u1161'); }
+          '\u327a' => { f('\u1111'); f('\u1161'); }
+          '\u327b' => { f('\u1112'); f('\u1161'); }
+          '\u327c' => {
+            f('\u110e'); f('\u1161'); f('\u11b7'); f('\u1100'); f('\u1169');
+          }
+          '\u327d' => { f('\u110c'); f('\u116e'); f('\u110b'); f('\u1174'); }
+          '\u327e' => { f('\u110b'); f('\u116e'); }
+          '\u3280' => { f('\u4e00'); }
+          '\u3281' => { f('\u4e8c'); }
+          '\u3282' => { f('\u4e09'); }
+          '\u3283' => { f('\u56db'); }
+          '\u3284' => { f('\u4e94'); }
+          '\u3285' => { f('\u516d'); }
+          '\u3286' => { f('\u4e03'); }
+          '\u3287' => { f('\u516b'); }
+          '\u3288' => { f('\u4e5d'); }
+          '\u3289' => { f('\u5341'); }
+          '\u328a' => { f('\u6708'); }
+          '\u328b' => { f('\u706b'); }
+          '\u328c' => { f('\u6c34'); }
+          '\u328d' => { f('\u6728'); }
+          '\u328e' => { f('\u91d1'); }
+          '\u328f' => { f('\u571f'); }
+          '\u3290' => { f('\u65e5'); }
+          '\u3291' => { f('\u682a'); }
+          '\u3292' => { f('\u6709'); }
+          '\u3293' => { f('\u793e'); }
+          '\u3294' => { f('\u540d'); }
+          '\u3295' => { f('\u7279'); }
+          '\u3296' => { f('\u8ca1'); }
+          '\u3297' => { f('\u795d'); }
+          '\u3298' => { f('\u52b4'); }
+          '\u3299' => { f('\u79d8'); }
+          '\u329a' => { f('\u7537'); }
+          '\u329b' => { f('\u5973'); }
+          '\u329c' => { f('\u9069'); }
+          '\u329d' => { f('\u512a'); }
+          '\u329e' => { f('\u5370'); }
+          '\u329f' => { f('\u6ce8'); }
+          '\u32a0' => { f('\u9805'); }
+          '\u32a1' => { f('\u4f11'); }
+          '\u32a2' => { f('\u5199'); }
+          '\u32a3' => { f('\u6b63'); }
+          '\u32a4' => { f('\u4e0a'); }
+          '\u32a5' => { f('\u4e2d'); }
+          '\u32a6' => { f('\u4e0b'); }
+          '\u32a7' => { f('\u5de6'); }
+          '\u32a8' => { f('\u53f3'); }
+          '\u32a9' => { f('\u533b'); }
+          '\u32aa' => { f('\u5b97'); }
+          '\u32ab' => { f('\u5b66'); }
+          '\u32ac' => { f('\u76e3'); }
+          '\u32ad' => { f('\u4f01'); }
+          '\u32ae' => { f('\u8cc7'); }
+          '\u32af' => { f('\u5354'); }
+          '\u32b0' => { f('\u591c'); }
+          '\u32b1' => { f('\x33'); f('\x36'); }
+          '\u32b2' => { f('\x33'); f('\x37'); }
+          '\u32b3' => { f('\x33'); f('\x38'); }
+          '\u32b4' => { f('\x33'); f('\x39'); }
+          '\u32b5' => { f('\x34'); f('\x30'); }
+          '\u32b6' => { f('\x34'); f('\x31'); }
+          '\u32b7' => { f('\x34'); f('\x32'); }
+          '\u32b8' => { f('\x34'); f('\x33'); }
+          '\u32b9' => { f('\x34'); f('\x34'); }
+          '\u32ba' => { f('\x34'); f('\x35'); }
+          '\u32bb' => { f('\x34'); f('\x36'); }
+          '\u32bc' => { f('\x34'); f('\x37'); }
+          '\u32bd' => { f('\x34'); f('\x38'); }
+          '\u32be' => { f('\x34'); f('\x39'); }
+          '\u32bf' => { f('\x35'); f('\x30'); }
+          '\u32c0' => { f('\x31'); f('\u6708'); }
+          '\u32c1' => { f('\x32'); f('\u6708'); }
+          '\u32c2' => { f('\x33'); f('\u6708'); }
+          '\u32c3' => { f('\x34'); f('\u6708'); }
+          '\u32c4' => { f('\x35'); f('\u6708'); }
+          '\u32c5' => { f('\x36'); f('\u6708'); }
+          '\u32c6' => { f('\x37'); f('\u6708'); }
+          '\u32c7' => { f('\x38'); f('\u6708'); }
+          '\u32c8' => { f('\x39'); f('\u6708'); }
+          '\u32c9' => { f('\x31'); f('\x30'); f('\u6708'); }
+          '\u32ca' => { f('\x31'); f('\x31'); f('\u6708'); }
+          '\u32cb' => { f('\x31'); f('\x32'); f('\u6708'); }
+          '\u32cc' => { f('\x48'); f('\x67'); }
+          '\u32cd' => { f('\x65'); f('\x72'); f('\x67'); }
+          '\u32ce' => { f('\x65'); f('\x56'); }
+          '\u32cf' => { f('\x4c'); f('\x54'); f('\x44'); }
+          '\u32d0' => { f('\u30a2'); }
+          '\u32d1' => { f('\u30a4'); }
+          '\u32d2' => { f('\u30a6'); }
+          '\u32d3' => { f('\u30a8'); }
+          '\u32d4' => { f('\u30aa'); }
+          '\u32d5' => { f('\u30ab'); }
+          '\u32d6' => { f('\u30ad'); }
+          '\u32d7' => { f('\u30af'); }
+          '\u32d8' => { f('\u30b1'); }
+          '\u32d9' => { f('\u30b3'); }
+          '\u32da' => { f('\u30b5'); }
+          '\u32db' => { f('\u30b7'); }
+          '\u32dc' => { f('\u30b9'); }
+          '\u32dd' => { f('\u30bb'); }
+          '\u32de' => { f('\u30bd'); }
+          '\u32df' => { f('\u30bf'); }
+          '\u32e0' => { f('\u30c1'); }
+          '\u32e1' => { f('\u30c4'); }
+          '\u32e2' => { f('\u30c6'); }
+          '\u32e3' => { f('\u30c8'); }
+          '\u32e4' => { f('\u30ca'); }
+          '\u32e5' => { f('\u30cb'); }
+          '\u32e6' => { f('\u30cc'); }
+          '\u32e7' => { f('\u30cd'); }
+          '\u32e8' => { f('\u30ce'); }
+          '\u32e9' => { f('\u30cf'); }
+          '\u32ea' => { f('\u30d2'); }
+          '\u32eb' => { f('\u30d5'); }
+          '\u32ec' => { f('\u30d8'); }
+          '\u32ed' => { f('\u30db'); }
+          '\u32ee' => { f('\u30de'); }
+          '\u32ef' => { f('\u30df'); }
+          '\u32f0' => { f('\u30e0'); }
+          '\u32f1' => { f('\u30e1'); }
+          '\u32f2' => { f('\u30e2'); }
+          '\u32f3' => { f('\u30e4'); }
+          '\u32f4' => { f('\u30e6'); }
+          '\u32f5' => { f('\u30e8'); }
+          '\u32f6' => { f('\u30e9'); }
+          '\u32f7' => { f('\u30ea'); }
+          '\u32f8' => { f('\u30eb'); }
+          '\u32f9' => { f('\u30ec'); }
+          '\u32fa' => { f('\u30ed'); }
+          '\u32fb' => { f('\u30ef'); }
+          '\u32fc' => { f('\u30f0'); }
+          '\u32fd' => { f('\u30f1'); }
+          '\u32fe' => { f('\u30f2'); }
+          '\u32ff' => { f('\u4ee4'); f('\u548c'); }
+          '\u3300' => {
+            f('\u30a2'); f('\u30cf'); f('\u309a'); f('\u30fc'); f('\u30c8');
+          }
+          '\u3301' => { f('\u30a2'); f('\u30eb'); f('\u30d5'); f('\u30a1'); }
+          '\u3302' => {
+            f('\u30a2'); f('\u30f3'); f('\u30d8'); f('\u309a'); f('\u30a2');
+          }
+          '\u3303' => { f('\u30a2'); f('\u30fc'); f('\u30eb'); }
+          '\u3304' => {
+            f('\u30a4'); f('\u30cb'); f('\u30f3'); f('\u30af'); f('\u3099');
+          }
+          '\u3305' => { f('\u30a4'); f('\u30f3'); f('\u30c1'); }
+          '\u3306' => { f('\u30a6'); f('\u30a9'); f('\u30f3'); }
+          '\u3307' => {
+            f('\u30a8'); f('\u30b9'); f('\u30af'); f('\u30fc'); f('\u30c8');
+            f('\u3099');
+          }
+          '\u3308' => { f('\u30a8'); f('\u30fc'); f('\u30ab'); f('\u30fc'); }
+          '\u3309' => { f('\u30aa'); f('\u30f3'); f('\u30b9'); }
+          '\u330a' => { f('\u30aa'); f('\u30fc'); f('\u30e0'); }
+          '\u330b' => { f('\u30ab'); f('\u30a4'); f('\u30ea'); }
+          '\u330c' => { f('\u30ab'); f('\u30e9'); f('\u30c3'); f('\u30c8'); }
+          '\u330d' => { f('\u30ab'); f('\u30ed'); f('\u30ea'); f('\u30fc'); }
+          '\u330e' => { f('\u30ab'); f('\u3099'); f('\u30ed'); f('\u30f3'); }
+          '\u330f' => { f('\u30ab'); f('\u3099'); f('\u30f3'); f('\u30de'); }
+          '\u3310' => { f('\u30ad'); f('\u3099'); f('\u30ab'); f('\u3099'); }
+          '\u3311' => { f('\u30ad'); f('\u3099'); f('\u30cb'); f('\u30fc'); }
+          '\u3312' => { f('\u30ad'); f('\u30e5'); f('\u30ea'); f('\u30fc'); }
+          '\u3313' => {
+            f('\u30ad'); f('\u3099'); f('\u30eb'); f('\u30bf'); f('\u3099');
+            f('\u30fc');
+          }
+          '\u3314' => { f('\u30ad'); f('\u30ed'); }
+          '\u3315' => {
+            f('\u30ad'); f('\u30ed'); f('\u30af'); f('\u3099'); f('\u30e9');
+            f('\u30e0');
+          }
+          '\u3316' => {
+            f('\u30ad'); f('\u30ed'); f('\u30e1'); f('\u30fc'); f('\u30c8');
+            f('\u30eb');
+          }
+          '\u3317' => {
+            f('\u30ad'); f('\u30ed'); f('\u30ef'); f('\u30c3'); f('\u30c8');
+          }
+          '\u3318' => { f('\u30af'); f('\u3099'); f('\u30e9'); f('\u30e0'); }
+          '\u3319' => {
+            f('\u30af'); f('\u3099'); f('\u30e9'); f('\u30e0'); f('\u30c8');
+            f('\u30f3');
+          }
+          '\u331a' => {
+            f('\u30af'); f('\u30eb'); f('\u30bb'); f('\u3099'); f('\u30a4');
+            f('\u30ed');
+          }
+          '\u331b' => { f('\u30af'); f('\u30ed'); f('\u30fc'); f('\u30cd'); }
+          '\u331c' => { f('\u30b1'); f('\u30fc'); f('\u30b9'); }
+          '\u331d' => { f('\u30b3'); f('\u30eb'); f('\u30ca'); }
+          '\u331e' => { f('\u30b3'); f('\u30fc'); f('\u30db'); f('\u309a'); }
+          '\u331f' => { f('\u30b5'); f('\u30a4'); f('\u30af'); f('\u30eb'); }
+          '\u3320' => {
+            f('\u30b5'); f('\u30f3'); f('\u30c1'); f('\u30fc'); f('\u30e0');
+          }
+          '\u3321' => {
+            f('\u30b7'); f('\u30ea'); f('\u30f3'); f('\u30af'); f('\u3099');
+          }
+          '\u3322' => { f('\u30bb'); f('\u30f3'); f('\u30c1'); }
+          '\u3323' => { f('\u30bb'); f('\u30f3'); f('\u30c8'); }
+          '\u3324' => { f('\u30bf'); f('\u3099'); f('\u30fc'); f('\u30b9'); }
+          '\u3325' => { f('\u30c6'); f('\u3099'); f('\u30b7'); }
+          '\u3326' => { f('\u30c8'); f('\u3099'); f('\u30eb'); }
+          '\u3327' => { f('\u30c8'); f('\u30f3'); }
+          '\u3328' => { f('\u30ca'); f('\u30ce'); }
+          '\u3329' => { f('\u30ce'); f('\u30c3'); f('\u30c8'); }
+          '\u332a' => { f('\u30cf'); f('\u30a4'); f('\u30c4'); }
+          '\u332b' => {
+            f('\u30cf'); f('\u309a'); f('\u30fc'); f('\u30bb'); f('\u30f3');
+            f('\u30c8');
+          }
+          '\u332c' => { f('\u30cf'); f('\u309a'); f('\u30fc'); f('\u30c4'); }
+          '\u332d' => {
+            f('\u30cf'); f('\u3099'); f('\u30fc'); f('\u30ec'); f('\u30eb');
+          }
+          '\u332e' => {
+            f('\u30d2'); f('\u309a'); f('\u30a2'); f('\u30b9'); f('\u30c8');
+            f('\u30eb');
+          }
+          '\u332f' => { f('\u30d2'); f('\u309a'); f('\u30af'); f('\u30eb'); }
+          '\u3330' => { f('\u30d2'); f('\u309a'); f('\u30b3'); }
+          '\u3331' => { f('\u30d2'); f('\u3099'); f('\u30eb'); }
+          '\u3332' => {
+            f('\u30d5'); f('\u30a1'); f('\u30e9'); f('\u30c3'); f('\u30c8');
+            f('\u3099');
+          }
+          '\u3333' => { f('\u30d5'); f('\u30a3'); f('\u30fc'); f('\u30c8'); }
+          '\u3334' => {
+            f('\u30d5'); f('\u3099'); f('\u30c3'); f('\u30b7'); f('\u30a7');
+            f('\u30eb');
+          }
+          '\u3335' => { f('\u30d5'); f('\u30e9'); f('\u30f3'); }
+          '\u3336' => {
+            f('\u30d8'); f('\u30af'); f('\u30bf'); f('\u30fc'); f('\u30eb');
+          }
+          '\u3337' => { f('\u30d8'); f('\u309a'); f('\u30bd'); }
+          '\u3338' => { f('\u30d8'); f('\u309a'); f('\u30cb'); f('\u30d2'); }
+          '\u3339' => { f('\u30d8'); f('\u30eb'); f('\u30c4'); }
+          '\u333a' => { f('\u30d8'); f('\u309a'); f('\u30f3'); f('\u30b9'); }
+          '\u333b' => {
+            f('\u30d8'); f('\u309a'); f('\u30fc'); f('\u30b7'); f('\u3099');
+          }
+          '\u333c' => { f('\u30d8'); f('\u3099'); f('\u30fc'); f('\u30bf'); }
+          '\u333d' => {
+            f('\u30db'); f('\u309a'); f('\u30a4'); f('\u30f3'); f('\u30c8');
+          }
+          '\u333e' => { f('\u30db'); f('\u3099'); f('\u30eb'); f('\u30c8'); }
+          '\u333f' => { f('\u30db'); f('\u30f3'); }
+          '\u3340' => {
+            f('\u30db'); f('\u309a'); f('\u30f3'); f('\u30c8'); f('\u3099');
+          }
+          '\u3341' => { f('\u30db'); f('\u30fc'); f('\u30eb'); }
+          '\u3342' => { f('\u30db'); f('\u30fc'); f('\u30f3'); }
+          '\u3343' => { f('\u30de'); f('\u30a4'); f('\u30af'); f('\u30ed'); }
+          '\u3344' => { f('\u30de'); f('\u30a4'); f('\u30eb'); }
+          '\u3345' => { f('\u30de'); f('\u30c3'); f('\u30cf'); }
+          '\u3346' => { f('\u30de'); f('\u30eb'); f('\u30af'); }
+          '\u3347' => {
+            f('\u30de'); f('\u30f3'); f('\u30b7'); f('\u30e7'); f('\u30f3');
+          }
+          '\u3348' => { f('\u30df'); f('\u30af'); f('\u30ed'); f('\u30f3'); }
+          '\u3349' => { f('\u30df'); f('\u30ea'); }
+          '\u334a' => {
+            f('\u30df'); f('\u30ea'); f('\u30cf'); f('\u3099'); f('\u30fc');
+            f('\u30eb');
+          }
+          '\u334b' => { f('\u30e1'); f('\u30ab'); f('\u3099'); }
+          '\u334c' => {
+            f('\u30e1'); f('\u30ab'); f('\u3099'); f('\u30c8'); f('\u30f3');
+          }
+          '\u334d' => { f('\u30e1'); f('\u30fc'); f('\u30c8'); f('\u30eb'); }
+          '\u334e' => { f('\u30e4'); f('\u30fc'); f('\u30c8'); f('\u3099'); }
+          '\u334f' => { f('\u30e4'); f('\u30fc'); f('\u30eb'); }
+          '\u3350' => { f('\u30e6'); f('\u30a2'); f('\u30f3'); }
+          '\u3351' => { f('\u30ea'); f('\u30c3'); f('\u30c8'); f('\u30eb'); }
+          '\u3352' => { f('\u30ea'); f('\u30e9'); }
+          '\u3353' => { f('\u30eb'); f('\u30d2'); f('\u309a'); f('\u30fc'); }
+          '\u3354' => {
+            f('\u30eb'); f('\u30fc'); f('\u30d5'); f('\u3099'); f('\u30eb');
+          }
+          '\u3355' => { f('\u30ec'); f('\u30e0'); }
+          '\u3356' => {
+            f('\u30ec'); f('\u30f3'); f('\u30c8'); f('\u30b1'); f('\u3099');
+            f('\u30f3');
+          }
+          '\u3357' => { f('\u30ef'); f('\u30c3'); f('\u30c8'); }
+          '\u3358' => { f('\x30'); f('\u70b9'); }
+          '\u3359' => { f('\x31'); f('\u70b9'); }
+          '\u335a' => { f('\x32'); f('\u70b9'); }
+          '\u335b' => { f('\x33'); f('\u70b9'); }
+          '\u335c' => { f('\x34'); f('\u70b9'); }
+          '\u335d' => { f('\x35'); f('\u70b9'); }
+          '\u335e' => { f('\x36'); f('\u70b9'); }
+          '\u335f' => { f('\x37'); f('\u70b9'); }
+          '\u3360' => { f('\x38'); f('\u70b9'); }
+          '\u3361' => { f('\x39'); f('\u70b9'); }
+          '\u3362' => { f('\x31'); f('\x30'); f('\u70b9'); }
+          '\u3363' => { f('\x31'); f('\x31'); f('\u70b9'); }
+          '\u3364' => { f('\x31'); f('\x32'); f('\u70b9'); }
+          '\u3365' => { f('\x31'); f('\x33'); f('\u70b9'); }
+          '\u3366' => { f('\x31'); f('\x34'); f('\u70b9'); }
+          '\u3367' => { f('\x31'); f('\x35'); f('\u70b9'); }
+          '\u3368' => { f('\x31'); f('\x36'); f('\u70b9'); }
+          '\u3369' => { f('\x31'); f('\x37'); f('\u70b9'); }
+          '\u336a' => { f('\x31'); f('\x38'); f('\u70b9'); }
+          '\u336b' => { f('\x31'); f('\x39'); f('\u70b9'); }
+          '\u336c' => { f('\x32'); f('\x30'); f('\u70b9'); }
+          '\u336d' => { f('\x32'); f('\x31'); f('\u70b9'); }
+          '\u336e' => { f('\x32'); f('\x32'); f('\u70b9'); }
+          '\u336f' => { f('\x32'); f('\x33'); f('\u70b9'); }
+          '\u3370' => { f('\x32'); f('\x34'); f('\u70b9'); }
+          '\u3371' => { f('\x68'); f('\x50'); f('\x61'); }
+          '\u3372' => { f('\x64'); f('\x61'); }
+          '\u3373' => { f('\x41'); f('\x55'); }
+          '\u3374' => { f('\x62'); f('\x61'); f('\x72'); }
+          '\u3375' => { f('\x6f'); f('\x56'); }
+          '\u3376' => { f('\x70'); f('\x63'); }
+          '\u3377' => { f('\x64'); f('\x6d'); }
+          '\u3378' => { f('\x64'); f('\x6d'); f('\x32'); }
+          '\u3379' => { f('\x64'); f('\x6d'); f('\x33'); }
+          '\u337a' => { f('\x49'); f('\x55'); }
+          '\u337b' => { f('\u5e73'); f('\u6210'); }
+          '\u337c' => { f('\u662d'); f('\u548c'); }
+          '\u337d' => { f('\u5927'); f('\u6b63'); }
+          '\u337e' => { f('\u660e'); f('\u6cbb'); }
+          '\u337f' => { f('\u682a'); f('\u5f0f'); f('\u4f1a'); f('\u793e'); }
+          '\u3380' => { f('\x70'); f('\x41'); }
+          '\u3381' => { f('\x6e'); f('\x41'); }
+          '\u3382' => { f('\u03bc'); f('\x41'); }
+          '\u3383' => { f('\x6d'); f('\x41'); }
+          '\u3384' => { f('\x6b'); f('\x41'); }
+          '\u3385' => { f('\x4b'); f('\x42'); }
+          '\u3386' => { f('\x4d'); f('\x42'); }
+          '\u3387' => { f('\x47'); f('\x42'); }
+          '\u3388' => { f('\x63'); f('\x61'); f('\x6c'); }
+          '\u3389' => { f('\x6b'); f('\x63'); f('\x61'); f('\x6c'); }
+          '\u338a' => { f('\x70'); f('\x46'); }
+          '\u338b' => { f('\x6e'); f('\x46'); }
+          '\u338c' => { f('\u03bc'); f('\x46'); }
+          '\u338d' => { f('\u03bc'); f('\x67'); }
+          '\u338e' => { f('\x6d'); f('\x67'); }
+          '\u338f' => { f('\x6b'); f('\x67'); }
+          '\u3390' => { f('\x48'); f('\x7a'); }
+          '\u3391' => { f('\x6b'); f('\x48'); f('\x7a'); }
+          '\u3392' => { f('\x4d'); f('\x48'); f('\x7a'); }
+          '\u3393' => { f('\x47'); f('\x48'); f('\x7a'); }
+          '\u3394' => { f('\x54'); f('\x48'); f('\x7a'); }
+          '\u3395' => { f('\u03bc'); f('\x6c'); }
+          '\u3396' => { f('\x6d'); f('\x6c'); }
+          '\u3397' => { f('\x64'); f('\x6c'); }
+          '\u3398' => { f('\x6b'); f('\x6c'); }
+          '\u3399' => { f('\x66'); f('\x6d'); }
+          '\u339a' => { f('\x6e'); f('\x6d'); }
+          '\u339b' => { f('\u03bc'); f('\x6d'); }
+          '\u339c' => { f('\x6d'); f('\x6d'); }
+          '\u339d' => { f('\x63'); f('\x6d'); }
+          '\u339e' => { f('\x6b'); f('\x6d'); }
+          '\u339f' => { f('\x6d'); f('\x6d'); f('\x32'); }
+          '\u33a0' => { f('\x63'); f('\x6d'); f('\x32'); }
+          '\u33a1' => { f('\x6d'); f('\x32'); }
+          '\u33a2' => { f('\x6b'); f('\x6d'); f('\x32'); }
+          '\u33a3' => { f('\x6d'); f('\x6d'); f('\x33'); }
+          '\u33a4' => { f('\x63'); f('\x6d'); f('\x33'); }
+          '\u33a5' => { f('\x6d'); f('\x33'); }
+          '\u33a6' => { f('\x6b'); f('\x6d'); f('\x33'); }
+          '\u33a7' => { f('\x6d'); f('\u2215'); f('\x73'); }
+          '\u33a8' => { f('\x6d'); f('\u2215'); f('\x73'); f('\x32'); }
+          '\u33a9' => { f('\x50'); f('\x61'); }
+          '\u33aa' => { f('\x6b'); f('\x50'); f('\x61'); }
+          '\u33ab' => { f('\x4d'); f('\x50'); f('\x61'); }
+          '\u33ac' => { f('\x47'); f('\x50'); f('\x61'); }
+          '\u33ad' => { f('\x72'); f('\x61'); f('\x64'); }
+          '\u33ae' => {
+            f('\x72'); f('\x61'); f('\x64'); f('\u2215'); f('\x73');
+          }
+          '\u33af' => {
+            f('\x72'); f('\x61'); f('\x64'); f('\u2215'); f('\x73');
+            f('\x32');
+          }
+          '\u33b0' => { f('\x70'); f('\x73'); }
+          '\u33b1' => { f('\x6e'); f('\x73'); }
+          '\u33b2' => { f('\u03bc'); f('\x73'); }
+          '\u33b3' => { f('\x6d'); f('\x73'); }
+          '\u33b4' => { f('\x70'); f('\x56'); }
+          '\u33b5' => { f('\x6e'); f('\x56'); }
+          '\u33b6' => { f('\u03bc'); f('\x56'); }
+          '\u33b7' => { f('\x6d'); f('\x56'); }
+          '\u33b8' => { f('\x6b'); f('\x56'); }
+          '\u33b9' => { f('\x4d'); f('\x56'); }
+          '\u33ba' => { f('\x70'); f('\x57'); }
+          '\u33bb' => { f('\x6e'); f('\x57'); }
+          '\u33bc' => { f('\u03bc'); f('\x57'); }
+          '\u33bd' => { f('\x6d'); f('\x57'); }
+          '\u33be' => { f('\x6b'); f('\x57'); }
+          '\u33bf' => { f('\x4d'); f('\x57'); }
+          '\u33c0' => { f('\x6b'); f('\u03a9'); }
+          '\u33c1' => { f('\x4d'); f('\u03a9'); }
+          '\u33c2' => { f('\x61'); f('\x2e'); f('\x6d'); f('\x2e'); }
+          '\u33c3' => { f('\x42'); f('\x71'); }
+          '\u33c4' => { f('\x63'); f('\x63'); }
+          '\u33c5' => { f('\x63'); f('\x64'); }
+          '\u33c6' => { f('\x43'); f('\u2215'); f('\x6b'); f('\x67'); }
+          '\u33c7' => { f('\x43'); f('\x6f'); f('\x2e'); }
+          '\u33c8' => { f('\x64'); f('\x42'); }
+          '\u33c9' => { f('\x47'); f('\x79'); }
+          '\u33ca' => { f('\x68'); f('\x61'); }
+          '\u33cb' => { f('\x48'); f('\x50'); }
+          '\u33cc' => { f('\x69'); f('\x6e'); }
+          '\u33cd' => { f('\x4b'); f('\x4b'); }
+          '\u33ce' => { f('\x4b'); f('\x4d'); }
+          '\u33cf' => { f('\x6b'); f('\x74'); }
+          '\u33d0' => { f('\x6c'); f('\x6d'); }
+          '\u33d1' => { f('\x6c'); f('\x6e'); }
+          '\u33d2' => { f('\x6c'); f('\x6f'); f('\x67'); }
+          '\u33d3' => { f('\x6c'); f('\x78'); }
+          '\u33d4' => { f('\x6d'); f('\x62'); }
+          '\u33d5' => { f('\x6d'); f('\x69'); f('\x6c'); }
+          '\u33d6' => { f('\x6d'); f('\x6f'); f('\x6c'); }
+          '\u33d7' => { f('\x50'); f('\x48'); }
+          '\u33d8' => { f('\x70'); f('\x2e'); f('\x6d'); f('\x2e'); }
+          '\u33d9' => { f('\x50'); f('\x50'); f('\x4d'); }
+          '\u33da' => { f('\x50'); f('\x52'); }
+          '\u33db' => { f('\x73'); f('\x72'); }
+          '\u33dc' => { f('\x53'); f('\x76'); }
+          '\u33dd' => { f('\x57'); f('\x62'); }
+          '\u33de' => { f('\x56'); f('\u2215'); f('\x6d'); }
+          '\u33df' => { f('\x41'); f('\u2215'); f('\x6d'); }
+          '\u33e0' => { f('\x31'); f('\u65e5'); }
+          '\u33e1' => { f('\x32'); f('\u65e5'); }
+          '\u33e2' => { f('\x33'); f('\u65e5'); }
+          '\u33e3' => { f('\x34'); f('\u65e5'); }
+          '\u33e4' => { f('\x35'); f('\u65e5'); }
+          '\u33e5' => { f('\x36'); f('\u65e5'); }
+          '\u33e6' => { f('\x37'); f('\u65e5'); }
+          '\u33e7' => { f('\x38'); f('\u65e5'); }
+          '\u33e8' => { f('\x39'); f('\u65e5'); }
+          '\u33e9' => { f('\x31'); f('\x30'); f('\u65e5'); }
+          '\u33ea' => { f('\x31'); f('\x31'); f('\u65e5'); }
+          '\u33eb' => { f('\x31'); f('\x32'); f('\u65e5'); }
+          '\u33ec' => { f('\x31'); f('\x33'); f('\u65e5'); }
+          '\u33ed' => { f('\x31'); f('\x34'); f('\u65e5'); }
+          '\u33ee' => { f('\x31'); f('\x35'); f('\u65e5'); }
+          '\u33ef' => { f('\x31'); f('\x36'); f('\u65e5'); }
+          '\u33f0' => { f('\x31'); f('\x37'); f('\u65e5'); }
+          '\u33f1' => { f('\x31'); f('\x38'); f('\u65e5'); }
+          '\u33f2' => { f('\x31'); f('\x39'); f('\u65e5'); }
+          '\u33f3' => { f('\x32'); f('\x30'); f('\u65e5'); }
+          '\u33f4' => { f('\x32'); f('\x31'); f('\u65e5'); }
+          '\u33f5' => { f('\x32'); f('\x32'); f('\u65e5'); }
+          '\u33f6' => { f('\x32'); f('\x33'); f('\u65e5'); }
+          '\u33f7' => { f('\x32'); f('\x34'); f('\u65e5'); }
+          '\u33f8' => { f('\x32'); f('\x35'); f('\u65e5'); }
+          '\u33f9' => { f('\x32'); f('\x36'); f('\u65e5'); }
+          '\u33fa' => { f('\x32'); f('\x37'); f('\u65e5'); }
+          '\u33fb' => { f('\x32'); f('\x38'); f('\u65e5'); }
+          '\u33fc' => { f('\x32'); f('\x39'); f('\u65e5'); }
+          '\u33fd' => { f('\x33'); f('\x30'); f('\u65e5'); }
+          '\u33fe' => { f('\x33'); f('\x31'); f('\u65e5'); }
+          '\u33ff' => { f('\x67'); f('\x61'); f('\x6c'); }
+          '\ua69c' => { f('\u044a'); }
+          '\ua69d' => { f('\u044c'); }
+          '\ua770' => { f('\ua76f'); }
+          '\ua7f2' => { f('\x43'); }
+          '\ua7f3' => { f('\x46'); }
+          '\ua7f4' => { f('\x51'); }
+          '\ua7f8' => { f('\u0126'); }
+          '\ua7f9' => { f('\u0153'); }
+          '\uab5c' => { f('\ua727'); }
+          '\uab5d' => { f('\uab37'); }
+          '\uab5e' => { f('\u026b'); }
+          '\uab5f' => { f('\uab52'); }
+          '\uab69' => { f('\u028d'); }
+          '\ufb00' => { f('\x66'); f('\x66'); }
+          '\ufb01' => { f('\x66'); f('\x69'); }
+          '\ufb02' => { f('\x66'); f('\x6c'); }
+          '\ufb03' => { f('\x66'); f('\x66'); f('\x69'); }
+          '\ufb04' => { f('\x66'); f('\x66'); f('\x6c'); }
+          '\ufb05' => { f('\x73'); f('\x74'); }
+          '\ufb06' => { f('\x73'); f('\x74'); }
+          '\ufb13' => { f('\u0574'); f('\u0576'); }
+          '\ufb14' => { f('\u0574'); f('\u0565'); }
+          '\ufb15' => { f('\u0574'); f('\u056b'); }
+          '\ufb16' => { f('\u057e'); f('\u0576'); }
+          '\ufb17' => { f('\u0574'); f('\u056d'); }
+          '\ufb20' => { f('\u05e2'); }
+          '\ufb21' => { f('\u05d0'); }
+          '\ufb22' => { f('\u05d3'); }
+          '\ufb23' => { f('\u05d4'); }
+          '\ufb24' => { f('\u05db'); }
+          '\ufb25' => { f('\u05dc'); }
+          '\ufb26' => { f('\u05dd'); }
+          '\ufb27' => { f('\u05e8'); }
+          '\ufb28' => { f('\u05ea'); }
+          '\ufb29' => { f('\x2b'); }
+          '\ufb4f' => { f('\u05d0'); f('\u05dc'); }
+          '\ufb50' => { f('\u0671'); }
+          '\ufb51' => { f('\u0671'); }
+          '\ufb52' => { f('\u067b'); }
+          '\ufb53' => { f('\u067b'); }
+          '\ufb54' => { f('\u067b'); }
+          '\ufb55' => { f('\u067b'); }
+          '\ufb56' => { f('\u067e'); }
+          '\ufb57' => { f('\u067e'); }
+          '\ufb58' => { f('\u067e'); }
+          '\ufb59' => { f('\u067e'); }
+          '\ufb5a' => { f('\u0680'); }
+          '\ufb5b' => { f('\u0680'); }
+          '\ufb5c' => { f('\u0680'); }
+          '\ufb5d' => { f('\u0680'); }
+          '\ufb5e' => { f('\u067a'); }
+          '\ufb5f' => { f('\u067a'); }
+          '\ufb60' => { f('\u067a'); }
+          '\ufb61' => { f('\u067a'); }
+          '\ufb62' => { f('\u067f'); }
+          '\ufb63' => { f('\u067f'); }
+          '\ufb64' => { f('\u067f'); }
+          '\ufb65' => { f('\u067f'); }
+          '\ufb66' => { f('\u0679'); }
+          '\ufb67' => { f('\u0679'); }
+          '\ufb68' => { f('\u0679'); }
+          '\ufb69' => { f('\u0679'); }
+          '\ufb6a' => { f('\u06a4'); }
+          '\ufb6b' => { f('\u06a4'); }
+          '\ufb6c' => { f('\u06a4'); }
+          '\ufb6d' => { f('\u06a4'); }
+          '\ufb6e' => { f('\u06a6'); }
+          '\ufb6f' => { f('\u06a6'); }
+          '\ufb70' => { f('\u06a6'); }
+          '\ufb71' => { f('\u06a6'); }
+          '\ufb72' => { f('\u0684'); }
+          '\ufb73' => { f('\u0684'); }
+          '\ufb74' => { f('\u0684'); }
+          '\ufb75' => { f('\u0684'); }
+          '\ufb76' => { f('\u0683'); }
+          '\ufb77' => { f('\u0683'); }
+          '\ufb78' => { f('\u0683'); }
+          '\ufb79' => { f('\u0683'); }
+          '\ufb7a' => { f('\u0686'); }
+          '\ufb7b' => { f('\u0686'); }
+          '\ufb7c' => { f('\u0686'); }
+          '\ufb7d' => { f('\u0686'); }
+          '\ufb7e' => { f('\u0687'); }
+          '\ufb7f' => { f('\u0687'); }
+          '\ufb80' => { f('\u0687'); }
+          '\ufb81' => { f('\u0687'); }
+          '\ufb82' => { f('\u068d'); }
+          '\ufb83' => { f('\u068d'); }
+          '\ufb84' => { f('\u068c'); }
+          '\ufb85' => { f('\u068c'); }
+          '\ufb86' => { f('\u068e'); }
+          '\ufb87' => { f('\u068e'); }
+          '\ufb88' => { f('\u0688'); }
+          '\ufb89' => { f('\u0688'); }
+          '\ufb8a' => { f('\u0698'); }
+          '\ufb8b' => { f('\u0698'); }
+          '\ufb8c' => { f('\u0691'); }
+          '\ufb8d' => { f('\u0691'); }
+          '\ufb8e' => { f('\u06a9'); }
+          '\ufb8f' => { f('\u06a9'); }
+          '\ufb90' => { f('\u06a9'); }
+          '\ufb91' => { f('\u06a9'); }
+          '\ufb92' => { f('\u06af'); }
+          '\ufb93' => { f('\u06af'); }
+          '\ufb94' => { f('\u06af'); }
+          '\ufb95' => { f('\u06af'); }
+          '\ufb96' => { f('\u06b3'); }
+          '\ufb97' => { f('\u06b3'); }
+          '\ufb98' => { f('\u06b3'); }
+          '\ufb99' => { f('\u06b3'); }
+          '\ufb9a' => { f('\u06b1'); }
+          '\ufb9b' => { f('\u06b1'); }
+          '\ufb9c' => { f('\u06b1'); }
+          '\ufb9d' => { f('\u06b1'); }
+          '\ufb9e' => { f('\u06ba'); }
+          '\ufb9f' => { f('\u06ba'); }
+          '\ufba0' => { f('\u06bb'); }
+          '\ufba1' => { f('\u06bb'); }
+          '\ufba2' => { f('\u06bb'); }
+          '\ufba3' => { f('\u06bb'); }
+          '\ufba4' => { f('\u06d5'); f('\u0654'); }
+          '\ufba5' => { f('\u06d5'); f('\u0654'); }
+          '\ufba6' => { f('\u06c1'); }
+          '\ufba7' => { f('\u06c1'); }
+          '\ufba8' => { f('\u06c1'); }
+          '\ufba9' => { f('\u06c1'); }
+          '\ufbaa' => { f('\u06be'); }
+          '\ufbab' => { f('\u06be'); }
+          '\ufbac' => { f('\u06be'); }
+          '\ufbad' => { f('\u06be'); }
+          '\ufbae' => { f('\u06d2'); }
+          '\ufbaf' => { f('\u06d2'); }
+          '\ufbb0' => { f('\u06d2'); f('\u0654'); }
+          '\ufbb1' => { f('\u06d2'); f('\u0654'); }
+          '\ufbd3' => { f('\u06ad'); }
+          '\ufbd4' => { f('\u06ad'); }
+          '\ufbd5' => { f('\u06ad'); }
+          '\ufbd6' => { f('\u06ad'); }
+          '\ufbd7' => { f('\u06c7'); }
+          '\ufbd8' => { f('\u06c7'); }
+          '\ufbd9' => { f('\u06c6'); }
+          '\ufbda' => { f('\u06c6'); }
+          '\ufbdb' => { f('\u06c8'); }
+          '\ufbdc' => { f('\u06c8'); }
+          '\ufbdd' => { f('\u06c7'); f('\u0674'); }
+          '\ufbde' => { f('\u06cb'); }
+          '\ufbdf' => { f('\u06cb'); }
+          '\ufbe0' => { f('\u06c5'); }
+          '\ufbe1' => { f('\u06c5'); }
+          '\ufbe2' => { f('\u06c9'); }
+          '\ufbe3' => { f('\u06c9'); }
+          '\ufbe4' => { f('\u06d0'); }
+          '\ufbe5' => { f('\u06d0'); }
+          '\ufbe6' => { f('\u06d0'); }
+          '\ufbe7' => { f('\u06d0'); }
+          '\ufbe8' => { f('\u0649'); }
+          '\ufbe9' => { f('\u0649'); }
+          '\ufbea' => { f('\u064a'); f('\u0654'); f('\u0627'); }
+          '\ufbeb' => { f('\u064a'); f('\u0654'); f('\u0627'); }
+          '\ufbec' => { f('\u064a'); f('\u0654'); f('\u06d5'); }
+          '\ufbed' => { f('\u064a'); f('\u0654'); f('\u06d5'); }
+          '\ufbee' => { f('\u064a'); f('\u0654'); f('\u0648'); }
+          '\ufbef' => { f('\u064a'); f('\u0654'); f('\u0648'); }
+          '\ufbf0' => { f('\u064a'); f('\u0654'); f('\u06c7'); }
+          '\ufbf1' => { f('\u064a'); f('\u0654'); f('\u06c7'); }
+          '\ufbf2' => { f('\u064a'); f('\u0654'); f('\u06c6'); }
+          '\ufbf3' => { f('\u064a'); f('\u0654'); f('\u06c6'); }
+          '\ufbf4' => { f('\u064a'); f('\u0654'); f('\u06c8'); }
+          '\ufbf5' => { f('\u064a'); f('\u0654'); f('\u06c8'); }
+          '\ufbf6' => { f('\u064a'); f('\u0654'); f('\u06d0'); }
+          '\ufbf7' => { f('\u064a'); f('\u0654'); f('\u06d0'); }
+          '\ufbf8' => { f('\u064a'); f('\u0654'); f('\u06d0'); }
+          '\ufbf9' => { f('\u064a'); f('\u0654'); f('\u0649'); }
+          '\ufbfa' => { f('\u064a'); f('\u0654'); f('\u0649'); }
+          '\ufbfb' => { f('\u064a'); f('\u0654'); f('\u0649'); }
+          '\ufbfc' => { f('\u06cc'); }
+          '\ufbfd' => { f('\u06cc'); }
+          '\ufbfe' => { f('\u06cc'); }
+          '\ufbff' => { f('\u06cc'); }
+          '\ufc00' => { f('\u064a'); f('\u0654'); f('\u062c'); }
+          '\ufc01' => { f('\u064a'); f('\u0654'); f('\u062d'); }
+          '\ufc02' => { f('\u064a'); f('\u0654'); f('\u0645'); }
+          '\ufc03' => { f('\u064a'); f('\u0654'); f('\u0649'); }
+          '\ufc04' => { f('\u064a'); f('\u0654'); f('\u064a'); }
+          '\ufc05' => { f('\u0628'); f('\u062c'); }
+          '\ufc06' => { f('\u0628'); f('\u062d'); }
+          '\ufc07' => { f('\u0628'); f('\u062e'); }
+          '\ufc08' => { f('\u0628'); f('\u0645'); }
+          '\ufc09' => { f('\u0628'); f('\u0649'); }
+          '\ufc0a' => { f('\u0628'); f('\u064a'); }
+          '\ufc0b' => { f('\u062a'); f('\u062c'); }
+          '\ufc0c' => { f('\u062a'); f('\u062d'); }
+          '\ufc0d' => { f('\u062a'); f('\u062e'); }
+          '\ufc0e' => { f('\u062a'); f('\u0645'); }
+          '\ufc0f' => { f('\u062a'); f('\u0649'); }
+          '\ufc10' => { f('\u062a'); f('\u064a'); }
+          '\ufc11' => { f('\u062b'); f('\u062c'); }
+          '\ufc12' => { f('\u062b'); f('\u0645'); }
+          '\ufc13' => { f('\u062b'); f('\u0649'); }
+          '\ufc14' => { f('\u062b'); f('\u064a'); }
+          '\ufc15' => { f('\u062c'); f('\u062d'); }
+          '\ufc16' => { f('\u062c'); f('\u0645'); }
+          '\ufc17' => { f('\u062d'); f('\u062c'); }
+          '\ufc18' => { f('\u062d'); f('\u0645'); }
+          '\ufc19' => { f('\u062e'); f('\u062c'); }
+          '\ufc1a' => { f('\u062e'); f('\u062d'); }
+          '\ufc1b' => { f('\u062e'); f('\u0645'); }
+          '\ufc1c' => { f('\u0633'); f('\u062c'); }
+          '\ufc1d' => { f('\u0633'); f('\u062d'); }
+          '\ufc1e' => { f('\u0633'); f('\u062e'); }
+          '\ufc1f' => { f('\u0633'); f('\u0645'); }
+          '\ufc20' => { f('\u0635'); f('\u062d'); }
+          '\ufc21' => { f('\u0635'); f('\u0645'); }
+          '\ufc22' => { f('\u0636'); f('\u062c'); }
+          '\ufc23' => { f('\u0636'); f('\u062d'); }
+          '\ufc24' => { f('\u0636'); f('\u062e'); }
+          '\ufc25' => { f('\u0636'); f('\u0645'); }
+          '\ufc26' => { f('\u0637'); f('\u062d'); }
+          '\ufc27' => { f('\u0637'); f('\u0645'); }
+          '\ufc28' => { f('\u0638'); f('\u0645'); }
+          '\ufc29' => { f('\u0639'); f('\u062c'); }
+          '\ufc2a' => { f('\u0639'); f('\u0645'); }
+          '\ufc2b' => { f('\u063a'); f('\u062c'); }
+          '\ufc2c' => { f('\u063a'); f('\u0645'); }
+          '\ufc2d' => { f('\u0641'); f('\u062c'); }
+          '\ufc2e' => { f('\u0641'); f('\u062d'); }
+          '\ufc2f' => { f('\u0641'); f('\u062e'); }
+          '\ufc30' => { f('\u0641'); f('\u0645'); }
+          '\ufc31' => { f('\u0641'); f('\u0649'); }
+          '\ufc32' => { f('\u0641'); f('\u064a'); }
+          '\ufc33' => { f('\u0642'); f('\u062d'); }
+          '\ufc34' => { f('\u0642'); f('\u0645'); }
+          '\ufc35' => { f('\u0642'); f('\u0649'); }
+          '\ufc36' => { f('\u0642'); f('\u064a'); }
+          '\ufc37' => { f('\u0643'); f('\u0627'); }
+          '\ufc38' => { f('\u0643'); f('\u062c'); }
+          '\ufc39' => { f('\u0643'); f('\u062d'); }
+          '\ufc3a' => { f('\u0643'); f('\u062e'); }
+          '\ufc3b' => { f('\u0643'); f('\u0644'); }
+          '\ufc3c' => { f('\u0643'); f('\u0645'); }
+          '\ufc3d' => { f('\u0643'); f('\u0649'); }
+          '\ufc3e' => { f('\u0643'); f('\u064a'); }
+          '\ufc3f' => { f('\u0644'); f('\u062c'); }
+          '\ufc40' => { f('\u0644'); f('\u062d'); }
+          '\ufc41' => { f('\u0644'); f('\u062e'); }
+          '\ufc42' => { f('\u0644'); f('\u0645'); }
+          '\ufc43' => { f('\u0644'); f('\u0649'); }
+          '\ufc44' => { f('\u0644'); f('\u064a'); }
+          '\ufc45' => { f('\u0645'); f('\u062c'); }
+          '\ufc46' => { f('\u0645'); f('\u062d'); }
+          '\ufc47' => { f('\u0645'); f('\u062e'); }
+          '\ufc48' => { f('\u0645'); f('\u0645'); }
+          '\ufc49' => { f('\u0645'); f('\u0649'); }
+          '\ufc4a' => { f('\u0645'); f('\u064a'); }
+          '\ufc4b' => { f('\u0646'); f('\u062c'); }
+          '\ufc4c' => { f('\u0646'); f('\u062d'); }
+          '\ufc4d' => { f('\u0646'); f('\u062e'); }
+          '\ufc4e' => { f('\u0646'); f('\u0645'); }
+          '\ufc4f' => { f('\u0646'); f('\u0649'); }
+          '\ufc50' => { f('\u0646'); f('\u064a'); }
+          '\ufc51' => { f('\u0647'); f('\u062c'); }
+          '\ufc52' => { f('\u0647'); f('\u0645'); }
+          '\ufc53' => { f('\u0647'); f('\u0649'); }
+          '\ufc54' => { f('\u0647'); f('\u064a'); }
+          '\ufc55' => { f('\u064a'); f('\u062c'); }
+          '\ufc56' => { f('\u064a'); f('\u062d'); }
+          '\ufc57' => { f('\u064a'); f('\u062e'); }
+          '\ufc58' => { f('\u064a'); f('\u0645'); }
+          '\ufc59' => { f('\u064a'); f('\u0649'); }
+          '\ufc5a' => { f('\u064a'); f('\u064a'); }
+          '\ufc5b' => { f('\u0630'); f('\u0670'); }
+          '\ufc5c' => { f('\u0631'); f('\u0670'); }
+          '\ufc5d' => { f('\u0649'); f('\u0670'); }
+          '\ufc5e' => { f('\x20'); f('\u064c'); f('\u0651'); }
+          '\ufc5f' => { f('\x20'); f('\u064d'); f('\u0651'); }
+          '\ufc60' => { f('\x20'); f('\u064e'); f('\u0651'); }
+          '\ufc61' => { f('\x20'); f('\u064f'); f('\u0651'); }
+          '\ufc62' => { f('\x20'); f('\u0650'); f('\u0651'); }
+          '\ufc63' => { f('\x20'); f('\u0651'); f('\u0670'); }
+          '\ufc64' => { f('\u064a'); f('\u0654'); f('\u0631'); }
+          '\ufc65' => { f('\u064a'); f('\u0654'); f('\u0632'); }
+          '\ufc66' => { f('\u064a'); f('\u0654'); f('\u0645'); }
+          '\ufc67' => { f('\u064a'); f('\u0654'); f('\u0646'); }
+          '\ufc68' => { f('\u064a'); f('\u0654'); f('\u0649'); }
+          '\ufc69' => { f('\u064a'); f('\u0654'); f('\u064a'); }
+          '\ufc6a' => { f('\u0628'); f('\u0631'); }
+          '\ufc6b' => { f('\u0628'); f('\u0632'); }
+          '\ufc6c' => { f('\u0628'); f('\u0645'); }
+          '\ufc6d' => { f('\u0628'); f('\u0646'); }
+          '\ufc6e' => { f('\u0628'); f('\u0649'); }
+          '\ufc6f' => { f('\u0628'); f('\u064a'); }
+          '\ufc70' => { f('\u062a'); f('\u0631'); }
+          '\ufc71' => { f('\u062a'); f('\u0632'); }
+          '\ufc72' => { f('\u062a'); f('\u0645'); }
+          '\ufc73' => { f('\u062a'); f('\u0646'); }
+          '\ufc74' => { f('\u062a'); f('\u0649'); }
+          '\ufc75' => { f('\u062a'); f('\u064a'); }
+          '\ufc76' => { f('\u062b'); f('\u0631'); }
+          '\ufc77' => { f('\u062b'); f('\u0632'); }
+          '\ufc78' => { f('\u062b'); f('\u0645'); }
+          '\ufc79' => { f('\u062b'); f('\u0646'); }
+          '\ufc7a' => { f('\u062b'); f('\u0649'); }
+          '\ufc7b' => { f('\u062b'); f('\u064a'); }
+          '\ufc7c' => { f('\u0641'); f('\u0649'); }
+          '\ufc7d' => { f('\u0641'); f('\u064a'); }
+          '\ufc7e' => { f('\u0642'); f('\u0649'); }
+          '\ufc7f' => { f('\u0642'); f('\u064a'); }
+          '\ufc80' => { f('\u0643'); f('\u0627'); }
+          '\ufc81' => { f('\u0643'); f('\u0644'); }
+          '\ufc82' => { f('\u0643'); f('\u0645'); }
+          '\ufc83' => { f('\u0643'); f('\u0649'); }
+          '\ufc84' => { f('\u0643'); f('\u064a'); }
+          '\ufc85' => { f('\u0644'); f('\u0645'); }
+          '\ufc86' => { f('\u0644'); f('\u0649'); }
+          '\ufc87' => { f('\u0644'); f('\u064a'); }
+          '\ufc88' => { f('\u0645'); f('\u0627'); }
+          '\ufc89' => { f('\u0645'); f('\u0645'); }
+          '\ufc8a' => { f('\u0646'); f('\u0631'); }
+          '\ufc8b' => { f('\u0646'); f('\u0632'); }
+          '\ufc8c' => { f('\u0646'); f('\u0645'); }
+          '\ufc8d' => { f('\u0646'); f('\u0646'); }
+          '\ufc8e' => { f('\u0646'); f('\u0649'); }
+          '\ufc8f' => { f('\u0646'); f('\u064a'); }
+          '\ufc90' => { f('\u0649'); f('\u0670'); }
+          '\ufc91' => { f('\u064a'); f('\u0631'); }
+          '\ufc92' => { f('\u064a'); f('\u0632'); }
+          '\ufc93' => { f('\u064a'); f('\u0645'); }
+          '\ufc94' => { f('\u064a'); f('\u0646'); }
+          '\ufc95' => { f('\u064a'); f('\u0649'); }
+          '\ufc96' => { f('\u064a'); f('\u064a'); }
+          '\ufc97' => { f('\u064a'); f('\u0654'); f('\u062c'); }
+          '\ufc98' => { f('\u064a'); f('\u0654'); f('\u062d'); }
+          '\ufc99' => { f('\u064a'); f('\u0654'); f('\u062e'); }
+          '\ufc9a' => { f('\u064a'); f('\u0654'); f('\u0645'); }
+          '\ufc9b' => { f('\u064a'); f('\u0654'); f('\u0647'); }
+          '\ufc9c' => { f('\u0628'); f('\u062c'); }
+          '\ufc9d' => { f('\u0628'); f('\u062d'); }
+          '\ufc9e' => { f('\u0628'); f('\u062e'); }
+          '\ufc9f' => { f('\u0628'); f('\u0645'); }
+          '\ufca0' => { f('\u0628'); f('\u0647'); }
+          '\ufca1' => { f('\u062a'); f('\u062c'); }
+          '\ufca2' => { f('\u062a'); f('\u062d'); }
+          '\ufca3' => { f('\u062a'); f('\u062e'); }
+          '\ufca4' => { f('\u062a'); f('\u0645'); }
+          '\ufca5' => { f('\u062a'); f('\u0647'); }
+          '\ufca6' => { f('\u062b'); f('\u0645'); }
+          '\ufca7' => { f('\u062c'); f('\u062d'); }
+          '\ufca8' => { f('\u062c'); f('\u0645'); }
+          '\ufca9' => { f('\u062d'); f('\u062c'); }
+          '\ufcaa' => { f('\u062d'); f('\u0645'); }
+          '\ufcab' => { f('\u062e'); f('\u062c'); }
+          '\ufcac' => { f('\u062e'); f('\u0645'); }
+          '\ufcad' => { f('\u0633'); f('\u062c'); }
+          '\ufcae' => { f('\u0633'); f('\u062d'); }
+          '\ufcaf' => { f('\u0633'); f('\u062e'); }
+          '\ufcb0' => { f('\u0633'); f('\u0645'); }
+          '\ufcb1' => { f('\u0635'); f('\u062d'); }
+          '\ufcb2' => { f('\u0635'); f('\u062e'); }
+          '\ufcb3' => { f('\u0635'); f('\u0645'); }
+          '\ufcb4' => { f('\u0636'); f('\u062c'); }
+          '\ufcb5' => { f('\u0636'); f('\u062d'); }
+          '\ufcb6' => { f('\u0636'); f('\u062e'); }
+          '\ufcb7' => { f('\u0636'); f('\u0645'); }
+          '\ufcb8' => { f('\u0637'); f('\u062d'); }
+          '\ufcb9' => { f('\u0638'); f('\u0645'); }
+          '\ufcba' => { f('\u0639'); f('\u062c'); }
+          '\ufcbb' => { f('\u0639'); f('\u0645'); }
+          '\ufcbc' => { f('\u063a'); f('\u062c'); }
+          '\ufcbd' => { f('\u063a'); f('\u0645'); }
+          '\ufcbe' => { f('\u0641'); f('\u062c'); }
+          '\ufcbf' => { f('\u0641'); f('\u062d'); }
+          '\ufcc0' => { f('\u0641'); f('\u062e'); }
+          '\ufcc1' => { f('\u0641'); f('\u0645'); }
+          '\ufcc2' => { f('\u0642'); f('\u062d'); }
+          '\ufcc3' => { f('\u0642'); f('\u0645'); }
+          '\ufcc4' => { f('\u0643'); f('\u062c'); }
+          '\ufcc5' => { f('\u0643'); f('\u062d'); }
+          '\ufcc6' => { f('\u0643'); f('\u062e'); }
+          '\ufcc7' => { f('\u0643'); f('\u0644'); }
+          '\ufcc8' => { f('\u0643'); f('\u0645'); }
+          '\ufcc9' => { f('\u0644'); f('\u062c'); }
+          '\ufcca' => { f('\u0644'); f('\u062d'); }
+          '\ufccb' => { f('\u0644'); f('\u062e'); }
+          '\ufccc' => { f('\u0644'); f('\u0645'); }
+          '\ufccd' => { f('\u0644'); f('\u0647'); }
+          '\ufcce' => { f('\u0645'); f('\u062c'); }
+          '\ufccf' => { f('\u0645'); f('\u062d'); }
+          '\ufcd0' => { f('\u0645'); f('\u062e'); }
+          '\ufcd1' => { f('\u0645'); f('\u0645'); }
+          '\ufcd2' => { f('\u0646'); f('\u062c'); }
+          '\ufcd3' => { f('\u0646'); f('\u062d'); }
+          '\ufcd4' => { f('\u0646'); f('\u062e'); }
+          '\ufcd5' => { f('\u0646'); f('\u0645'); }
+          '\ufcd6' => { f('\u0646'); f('\u0647'); }
+          '\ufcd7' => { f('\u0647'); f('\u062c'); }
+          '\ufcd8' => { f('\u0647'); f('\u0645'); }
+          '\ufcd9' => { f('\u0647'); f('\u0670'); }
+          '\ufcda' => { f('\u064a'); f('\u062c'); }
+          '\ufcdb' => { f('\u064a'); f('\u062d'); }
+          '\ufcdc' => { f('\u064a'); f('\u062e'); }
+          '\ufcdd' => { f('\u064a'); f('\u0645'); }
+          '\ufcde' => { f('\u064a'); f('\u0647'); }
+          '\ufcdf' => { f('\u064a'); f('\u0654'); f('\u0645'); }
+          '\ufce0' => { f('\u064a'); f('\u0654'); f('\u0647'); }
+          '\ufce1' => { f('\u0628'); f('\u0645'); }
+          '\ufce2' => { f('\u0628'); f('\u0647'); }
+          '\ufce3' => { f('\u062a'); f('\u0645'); }
+          '\ufce4' => { f('\u062a'); f('\u0647'); }
+          '\ufce5' => { f('\u062b'); f('\u0645'); }
+          '\ufce6' => { f('\u062b'); f('\u0647'); }
+          '\ufce7' => { f('\u0633'); f('\u0645'); }
+          '\ufce8' => { f('\u0633'); f('\u0647'); }
+          '\ufce9' => { f('\u0634'); f('\u0645'); }
+          '\ufcea' => { f('\u0634'); f('\u0647'); }
+          '\ufceb' => { f('\u0643'); f('\u0644'); }
+          '\ufcec' => { f('\u0643'); f('\u0645'); }
+          '\ufced' => { f('\u0644'); f('\u0645'); }
+          '\ufcee' => { f('\u0646'); f('\u0645'); }
+          '\ufcef' => { f('\u0646'); f('\u0647'); }
+          '\ufcf0' => { f('\u064a'); f('\u0645'); }
+          '\ufcf1' => { f('\u064a'); f('\u0647'); }
+          '\ufcf2' => { f('\u0640'); f('\u064e'); f('\u0651'); }
+          '\ufcf3' => { f('\u0640'); f('\u064f'); f('\u0651'); }
+          '\ufcf4' => { f('\u0640'); f('\u0650'); f('\u0651'); }
+          '\ufcf5' => { f('\u0637'); f('\u0649'); }
+          '\ufcf6' => { f('\u0637'); f('\u064a'); }
+          '\ufcf7' => { f('\u0639'); f('\u0649'); }
+          '\ufcf8' => { f('\u0639'); f('\u064a'); }
+          '\ufcf9' => { f('\u063a'); f('\u0649'); }
+          '\ufcfa' => { f('\u063a'); f('\u064a'); }
+          '\ufcfb' => { f('\u0633'); f('\u0649'); }
+          '\ufcfc' => { f('\u0633'); f('\u064a'); }
+          '\ufcfd' => { f('\u0634'); f('\u0649'); }
+          '\ufcfe' => { f('\u0634'); f('\u064a'); }
+          '\ufcff' => { f('\u062d'); f('\u0649'); }
+          '\ufd00' => { f('\u062d'); f('\u064a'); }
+          '\ufd01' => { f('\u062c'); f('\u0649'); }
+          '\ufd02' => { f('\u062c'); f('\u064a'); }
+          '\ufd03' => { f('\u062e'); f('\u0649'); }
+          '\ufd04' => { f('\u062e'); f('\u064a'); }
+          '\ufd05' => { f('\u0635'); f('\u0649'); }
+          '\ufd06' => { f('\u0635'); f('\u064a'); }
+          '\ufd07' => { f('\u0636'); f('\u0649'); }
+          '\ufd08' => { f('\u0636'); f('\u064a'); }
+          '\ufd09' => { f('\u0634'); f('\u062c'); }
+          '\ufd0a' => { f('\u0634'); f('\u062d'); }
+          '\ufd0b' => { f('\u0634'); f('\u062e'); }
+          '\ufd0c' => { f('\u0634'); f('\u0645'); }
+          '\ufd0d' => { f('\u0634'); f('\u0631'); }
+          '\ufd0e' => { f('\u0633'); f('\u0631'); }
+          '\ufd0f' => { f('\u0635'); f('\u0631'); }
+          '\ufd10' => { f('\u0636'); f('\u0631'); }
+          '\ufd11' => { f('\u0637'); f('\u0649'); }
+          '\ufd12' => { f('\u0637'); f('\u064a'); }
+          '\ufd13' => { f('\u0639'); f('\u0649'); }
+          '\ufd14' => { f('\u0639'); f('\u064a'); }
+          '\ufd15' => { f('\u063a'); f('\u0649'); }
+          '\ufd16' => { f('\u063a'); f('\u064a'); }
+          '\ufd17' => { f('\u0633'); f('\u0649'); }
+          '\ufd18' => { f('\u0633'); f('\u064a'); }
+          '\ufd19' => { f('\u0634'); f('\u0649'); }
+          '\ufd1a' => { f('\u0634'); f('\u064a'); }
+          '\ufd1b' => { f('\u062d'); f('\u0649'); }
+          '\ufd1c' => { f('\u062d'); f('\u064a'); }
+          '\ufd1d' => { f('\u062c'); f('\u0649'); }
+          '\ufd1e' => { f('\u062c'); f('\u064a'); }
+          '\ufd1f' => { f('\u062e'); f('\u0649'); }
+          '\ufd20' => { f('\u062e'); f('\u064a'); }
+          '\ufd21' => { f('\u0635'); f('\u0649'); }
+          '\ufd22' => { f('\u0635'); f('\u064a'); }
+          '\ufd23' => { f('\u0636'); f('\u0649'); }
+          '\ufd24' => { f('\u0636'); f('\u064a'); }
+          '\ufd25' => { f('\u0634'); f('\u062c'); }
+          '\ufd26' => { f('\u0634'); f('\u062d'); }
+          '\ufd27' => { f('\u0634'); f('\u062e'); }
+          '\ufd28' => { f('\u0634'); f('\u0645'); }
+          '\ufd29' => { f('\u0634'); f('\u0631'); }
+          '\ufd2a' => { f('\u0633'); f('\u0631'); }
+          '\ufd2b' => { f('\u0635'); f('\u0631'); }
+          '\ufd2c' => { f('\u0636'); f('\u0631'); }
+          '\ufd2d' => { f('\u0634'); f('\u062c'); }
+          '\ufd2e' => { f('\u0634'); f('\u062d'); }
+          '\ufd2f' => { f('\u0634'); f('\u062e'); }
+          '\ufd30' => { f('\u0634'); f('\u0645'); }
+          '\ufd31' => { f('\u0633'); f('\u0647'); }
+          '\ufd32' => { f('\u0634'); f('\u0647'); }
+          '\ufd33' => { f('\u0637'); f('\u0645'); }
+          '\ufd34' => { f('\u0633'); f('\u062c'); }
+          '\ufd35' => { f('\u0633'); f('\u062d'); }
+          '\ufd36' => { f('\u0633'); f('\u062e'); }
+          '\ufd37' => { f('\u0634'); f('\u062c'); }
+          '\ufd38' => { f('\u0634'); f('\u062d'); }
+          '\ufd39' => { f('\u0634'); f('\u062e'); }
+          '\ufd3a' => { f('\u0637'); f('\u0645'); }
+          '\ufd3b' => { f('\u0638'); f('\u0645'); }
+          '\ufd3c' => { f('\u0627'); f('\u064b'); }
+          '\ufd3d' => { f('\u0627'); f('\u064b'); }
+          '\ufd50' => { f('\u062a'); f('\u062c'); f('\u0645'); }
+          '\ufd51' => { f('\u062a'); f('\u062d'); f('\u062c'); }
+          '\ufd52' => { f('\u062a'); f('\u062d'); f('\u062c'); }
+          '\ufd53' => { f('\u062a'); f('\u062d'); f('\u0645'); }
+          '\ufd54' => { f('\u062a'); f('\u062e'); f('\u0645'); }
+          '\ufd55' => { f('\u062a'); f('\u0645'); f('\u062c'); }
+          '\ufd56' => { f('\u062a'); f('\u0645'); f('\u062d'); }
+          '\ufd57' => { f('\u062a'); f('\u0645'); f('\u062e'); }
+          '\ufd58' => { f('\u062c'); f('\u0645'); f('\u062d'); }
+          '\ufd59' => { f('\u062c'); f('\u0645'); f('\u062d'); }
+          '\ufd5a' => { f('\u062d'); f('\u0645'); f('\u064a'); }
+          '\ufd5b' => { f('\u062d'); f('\u0645'); f('\u0649'); }
+          '\ufd5c' => { f('\u0633'); f('\u062d'); f('\u062c'); }
+          '\ufd5d' => { f('\u0633'); f('\u062c'); f('\u062d'); }
+          '\ufd5e' => { f('\u0633'); f('\u062c'); f('\u0649'); }
+          '\ufd5f' => { f('\u0633'); f('\u0645'); f('\u062d'); }
+          '\ufd60' => { f('\u0633'); f('\u0645'); f('\u062d'); }
+          '\ufd61' => { f('\u0633'); f('\u0645'); f('\u062c'); }
+          '\ufd62' => { f('\u0633'); f('\u0645'); f('\u0645'); }
+          '\ufd63' => { f('\u0633'); f('\u0645'); f('\u0645'); }
+          '\ufd64' => { f('\u0635'); f('\u062d'); f('\u062d'); }
+          '\ufd65' => { f('\u0635'); f('\u062d'); f('\u062d'); }
+          '\ufd66' => { f('\u0635'); f('\u0645'); f('\u0645'); }
+          '\ufd67' => { f('\u0634'); f('\u062d'); f('\u0645'); }
+          '\ufd68' => { f('\u0634'); f('\u062d'); f('\u0645'); }
+          '\ufd69' => { f('\u0634'); f('\u062c'); f('\u064a'); }
+          '\ufd6a' => { f('\u0634'); f('\u0645'); f('\u062e'); }
+          '\ufd6b' => { f('\u0634'); f('\u0645'); f('\u062e'); }
+          '\ufd6c' => { f('\u0634'); f('\u0645'); f('\u0645'); }
+          '\ufd6d' => { f('\u0634'); f('\u0645'); f('\u0645'); }
+          '\ufd6e' => { f('\u0636'); f('\u062d'); f('\u0649'); }
+          '\ufd6f' => { f('\u0636'); f('\u062e'); f('\u0645'); }
+          '\ufd70' => { f('\u0636'); f('\u062e'); f('\u0645'); }
+          '\ufd71' => { f('\u0637'); f('\u0645'); f('\u062d'); }
+          '\ufd72' => { f('\u0637'); f('\u0645'); f('\u062d'); }
+          '\ufd73' => { f('\u0637'); f('\u0645'); f('\u0645'); }
+          '\ufd74' => { f('\u0637'); f('\u0645'); f('\u064a'); }
+          '\ufd75' => { f('\u0639'); f('\u062c'); f('\u0645'); }
+          '\ufd76' => { f('\u0639'); f('\u0645'); f('\u0645'); }
+          '\ufd77' => { f('\u0639'); f('\u0645'); f('\u0645'); }
+          '\ufd78' => { f('\u0639'); f('\u0645'); f('\u0649'); }
+          '\ufd79' => { f('\u063a'); f('\u0645'); f('\u0645'); }
+          '\ufd7a' => { f('\u063a'); f('\u0645'); f('\u064a'); }
+          '\ufd7b' => { f('\u063a'); f('\u0645'); f('\u0649'); }
+          '\ufd7c' => { f('\u0641'); f('\u062e'); f('\u0645'); }
+          '\ufd7d' => { f('\u0641'); f('\u062e'); f('\u0645'); }
+          '\ufd7e' => { f('\u0642'); f('\u0645'); f('\u062d'); }
+          '\ufd7f' => { f('\u0642'); f('\u0645'); f('\u0645'); }
+          '\ufd80' => { f('\u0644'); f('\u062d'); f('\u0645'); }
+          '\ufd81' => { f('\u0644'); f('\u062d'); f('\u064a'); }
+          '\ufd82' => { f('\u0644'); f('\u062d'); f('\u0649'); }
+          '\ufd83' => { f('\u0644'); f('\u062c'); f('\u062c'); }
+          '\ufd84' => { f('\u0644'); f('\u062c'); f('\u062c'); }
+          '\ufd85' => { f('\u0644'); f('\u062e'); f('\u0645'); }
+          '\ufd86' => { f('\u0644'); f('\u062e'); f('\u0645'); }
+          '\ufd87' => { f('\u0644'); f('\u0645'); f('\u062d'); }
+          '\ufd88' => { f('\u0644'); f('\u0645'); f('\u062d'); }
+          '\ufd89' => { f('\u0645'); f('\u062d'); f('\u062c'); }
+          '\ufd8a' => { f('\u0645'); f('\u062d'); f('\u0645'); }
+          '\ufd8b' => { f('\u0645'); f('\u062d'); f('\u064a'); }
+          '\ufd8c' => { f('\u0645'); f('\u062c'); f('\u062d'); }
+          '\ufd8d' => { f('\u0645'); f('\u062c'); f('\u0645'); }
+          '\ufd8e' => { f('\u0645'); f('\u062e'); f('\u062c'); }
+          '\ufd8f' => { f('\u0645'); f('\u062e'); f('\u0645'); }
+          '\ufd92' => { f('\u0645'); f('\u062c'); f('\u062e'); }
+          '\ufd93' => { f('\u0647'); f('\u0645'); f('\u062c'); }
+          '\ufd94' => { f('\u0647'); f('\u0645'); f('\u0645'); }
+          '\ufd95' => { f('\u0646'); f('\u062d'); f('\u0645'); }
+          '\ufd96' => { f('\u0646'); f('\u062d'); f('\u0649'); }
+          '\ufd97' => { f('\u0646'); f('\u062c'); f('\u0645'); }
+          '\ufd98' => { f('\u0646'); f('\u062c'); f('\u0645'); }
+          '\ufd99' => { f('\u0646'); f('\u062c'); f('\u0649'); }
+          '\ufd9a' => { f('\u0646'); f('\u0645'); f('\u064a'); }
+          '\ufd9b' => { f('\u0646'); f('\u0645'); f('\u0649'); }
+          '\ufd9c' => { f('\u064a'); f('\u0645'); f('\u0645'); }
+          '\ufd9d' => { f('\u064a'); f('\u0645'); f('\u0645'); }
+          '\ufd9e' => { f('\u0628'); f('\u062e'); f('\u064a'); }
+          '\ufd9f' => { f('\u062a'); f('\u062c'); f('\u064a'); }
+          '\ufda0' => { f('\u062a'); f('\u062c'); f('\u0649'); }
+          '\ufda1' => { f('\u062a'); f('\u062e'); f('\u064a'); }
+          '\ufda2' => { f('\u062a'); f('\u062e'); f('\u0649'); }
+          '\ufda3' => { f('\u062a'); f('\u0645'); f('\u064a'); }
+          '\ufda4' => { f('\u062a'); f('\u0645'); f('\u0649'); }
+          '\ufda5' => { f('\u062c'); f('\u0645'); f('\u064a'); }
+          '\ufda6' => { f('\u062c'); f('\u062d'); f('\u0649'); }
+          '\ufda7' => { f('\u062c'); f('\u0645'); f('\u0649'); }
+          '\ufda8' => { f('\u0633'); f('\u062e'); f('\u0649'); }
+          '\ufda9' => { f('\u0635'); f('\u062d'); f('\u064a'); }
+          '\ufdaa' => { f('\u0634'); f('\u062d'); f('\u064a'); }
+          '\ufdab' => { f('\u0636'); f('\u062d'); f('\u064a'); }
+          '\ufdac' => { f('\u0644'); f('\u062c'); f('\u064a'); }
+          '\ufdad' => { f('\u0644'); f('\u0645'); f('\u064a'); }
+          '\ufdae' => { f('\u064a'); f('\u062d'); f('\u064a'); }
+          '\ufdaf' => { f('\u064a'); f('\u062c'); f('\u064a'); }
+          '\ufdb0' => { f('\u064a'); f('\u0645'); f('\u064a'); }
+          '\ufdb1' => { f('\u0645'); f('\u0645'); f('\u064a'); }
+          '\ufdb2' => { f('\u0642'); f('\u0645'); f('\u064a'); }
+          '\ufdb3' => { f('\u0646'); f('\u062d'); f('\u064a'); }
+          '\ufdb4' => { f('\u0642'); f('\u0645'); f('\u062d'); }
+          '\ufdb5' => { f('\u0644'); f('\u062d'); f('\u0645'); }
+          '\ufdb6' => { f('\u0639'); f('\u0645'); f('\u064a'); }
+          '\ufdb7' => { f('\u0643'); f('\u0645'); f('\u064a'); }
+          '\ufdb8' => { f('\u0646'); f('\u062c'); f('\u062d'); }
+          '\ufdb9' => { f('\u0645'); f('\u062e'); f('\u064a'); }
+          '\ufdba' => { f('\u0644'); f('\u062c'); f('\u0645'); }
+          '\ufdbb' => { f('\u0643'); f('\u0645'); f('\u0645'); }
+          '\ufdbc' => { f('\u0644'); f('\u062c'); f('\u0645'); }
+          '\ufdbd' => { f('\u0646'); f('\u062c'); f('\u062d'); }
+          '\ufdbe' => { f('\u062c'); f('\u062d'); f('\u064a'); }
+          '\ufdbf' => { f('\u062d'); f('\u062c'); f('\u064a'); }
+          '\ufdc0' => { f('\u0645'); f('\u062c'); f('\u064a'); }
+          '\ufdc1' => { f('\u0641'); f('\u0645'); f('\u064a'); }
+          '\ufdc2' => { f('\u0628'); f('\u062d'); f('\u064a'); }
+          '\ufdc3' => { f('\u0643'); f('\u0645'); f('\u0645'); }
+          '\ufdc4' => { f('\u0639'); f('\u062c'); f('\u0645'); }
+          '\ufdc5' => { f('\u0635'); f('\u0645'); f('\u0645'); }
+          '\ufdc6' => { f('\u0633'); f('\u062e'); f('\u064a'); }
+          '\ufdc7' => { f('\u0646'); f('\u062c'); f('\u064a'); }
+          '\ufdf0' => { f('\u0635'); f('\u0644'); f('\u06d2'); }
+          '\ufdf1' => { f('\u0642'); f('\u0644'); f('\u06d2'); }
+          '\ufdf2' => { f('\u0627'); f('\u0644'); f('\u0644'); f('\u0647'); }
+          '\ufdf3' => { f('\u0627'); f('\u0643'); f('\u0628'); f('\u0631'); }
+          '\ufdf4' => { f('\u0645'); f('\u062d'); f('\u0645'); f('\u062f'); }
+          '\ufdf5' => { f('\u0635'); f('\u0644'); f('\u0639'); f('\u0645'); }
+          '\ufdf6' => { f('\u0631'); f('\u0633'); f('\u0648'); f('\u0644'); }
+          '\ufdf7' => { f('\u0639'); f('\u0644'); f('\u064a'); f('\u0647'); }
+          '\ufdf8' => { f('\u0648'); f('\u0633'); f('\u0644'); f('\u0645'); }
+          '\ufdf9' => { f('\u0635'); f('\u0644'); f('\u0649'); }
+          '\ufdfa' => {
+            f('\u0635'); f('\u0644'); f('\u0649'); f('\x20'); f('\u0627');
+            f('\u0644'); f('\u0644'); f('\u0647'); f('\x20'); f('\u0639');
+            f('\u0644'); f('\u064a'); f('\u0647'); f('\x20'); f('\u0648');
+            f('\u0633'); f('\u0644'); f('\u0645');
+          }
+          '\ufdfb' => {
+            f('\u062c'); f('\u0644'); f('\x20'); f('\u062c'); f('\u0644');
+            f('\u0627'); f('\u0644'); f('\u0647');
+          }
+          '\ufdfc' => { f('\u0631'); f('\u06cc'); f('\u0627'); f('\u0644'); }
+          '\ufe10' => { f('\x2c'); }
+          '\ufe11' => { f('\u3001'); }
+          '\ufe12' => { f('\u3002'); }
+          '\ufe13' => { f('\x3a'); }
+          '\ufe14' => { f('\x3b'); }
+          '\ufe15' => { f('\x21'); }
+          '\ufe16' => { f('\x3f'); }
+          '\ufe17' => { f('\u3016'); }
+          '\ufe18' => { f('\u3017'); }
+          '\ufe19' => { f('\x2e'); f('\x2e'); f('\x2e'); }
+          '\ufe30' => { f('\x2e'); f('\x2e'); }
+          '\ufe31' => { f('\u2014'); }
+          '\ufe32' => { f('\u2013'); }
+          '\ufe33' => { f('\x5f'); }
+          '\ufe34' => { f('\x5f'); }
+          '\ufe35' => { f('\x28'); }
+          '\ufe36' => { f('\x29'); }
+          '\ufe37' => { f('\x7b'); }
+          '\ufe38' => { f('\x7d'); }
+          '\ufe39' => { f('\u3014'); }
+          '\ufe3a' => { f('\u3015'); }
+          '\ufe3b' => { f('\u3010'); }
+          '\ufe3c' => { f('\u3011'); }
+          '\ufe3d' => { f('\u300a'); }
+          '\ufe3e' => { f('\u300b'); }
+          '\ufe3f' => { f('\u3008'); }
+          '\ufe40' => { f('\u3009'); }
+          '\ufe41' => { f('\u300c'); }
+          '\ufe42' => { f('\u300d'); }
+          '\ufe43' => { f('\u300e'); }
+          '\ufe44' => { f('\u300f'); }
+          '\ufe47' => { f('\x5b'); }
+          '\ufe48' => { f('\x5d'); }
+          '\ufe49' => { f('\x20'); f('\u0305'); }
+          '\ufe4a' => { f('\x20'); f('\u0305'); }
+          '\ufe4b' => { f('\x20'); f('\u0305'); }
+          '\ufe4c' => { f('\x20'); f('\u0305'); }
+          '\ufe4d' => { f('\x5f'); }
+          '\ufe4e' => { f('\x5f'); }
+          '\ufe4f' => { f('\x5f'); }
+          '\ufe50' => { f('\x2c'); }
+          '\ufe51' => { f('\u3001'); }
+          '\ufe52' => { f('\x2e'); }
+          '\ufe54' => { f('\x3b'); }
+          '\ufe55' => { f('\x3a'); }
+          '\ufe56' => { f('\x3f'); }
+          '\ufe57' => { f('\x21'); }
+          '\ufe58' => { f('\u2014'); }
+          '\ufe59' => { f('\x28'); }
+          '\ufe5a' => { f('\x29'); }
+          '\ufe5b' => { f('\x7b'); }
+          '\ufe5c' => { f('\x7d'); }
+          '\ufe5d' => { f('\u3014'); }
+          '\ufe5e' => { f('\u3015'); }
+          '\ufe5f' => { f('\x23'); }
+          '\ufe60' => { f('\x26'); }
+          '\ufe61' => { f('\x2a'); }
+          '\ufe62' => { f('\x2b'); }
+          '\ufe63' => { f('\x2d'); }
+          '\ufe64' => { f('\x3c'); }
+          '\ufe65' => { f('\x3e'); }
+          '\ufe66' => { f('\x3d'); }
+          '\ufe68' => { f('\x5c'); }
+          '\ufe69' => { f('\x24'); }
+          '\ufe6a' => { f('\x25'); }
+          '\ufe6b' => { f('\x40'); }
+          '\ufe70' => { f('\x20'); f('\u064b'); }
+          '\ufe71' => { f('\u0640'); f('\u064b'); }
+          '\ufe72' => { f('\x20'); f('\u064c'); }
+          '\ufe74' => { f('\x20'); f('\u064d'); }
+          '\ufe76' => { f('\x20'); f('\u064e'); }
+          '\ufe77' => { f('\u0640'); f('\u064e'); }
+          '\ufe78' => { f('\x20'); f('\u064f'); }
+          '\ufe79' => { f('\u0640'); f('\u064f'); }
+          '\ufe7a' => { f('\x20'); f('\u0650'); }
+          '\ufe7b' => { f('\u0640'); f('\u0650'); }
+          '\ufe7c' => { f('\x20'); f('\u0651'); }
+          '\ufe7d' => { f('\u0640'); f('\u0651'); }
+          '\ufe7e' => { f('\x20'); f('\u0652'); }
+          '\ufe7f' => { f('\u0640'); f('\u0652'); }
+          '\ufe80' => { f('\u0621'); }
+          '\ufe81' => { f('\u0627'); f('\u0653'); }
+          '\ufe82' => { f('\u0627'); f('\u0653'); }
+          '\ufe83' => { f('\u0627'); f('\u0654'); }
+          '\ufe84' => { f('\u0627'); f('\u0654'); }
+          '\ufe85' => { f('\u0648'); f('\u0654'); }
+          '\ufe86' => { f('\u0648'); f('\u0654'); }
+          '\ufe87' => { f('\u0627'); f('\u0655'); }
+          '\ufe88' => { f('\u0627'); f('\u0655'); }
+          '\ufe89' => { f('\u064a'); f('\u0654'); }
+          '\ufe8a' => { f('\u064a'); f('\u0654'); }
+          '\ufe8b' => { f('\u064a'); f('\u0654'); }
+          '\ufe8c' => { f('\u064a'); f('\u0654'); }
+          '\ufe8d' => { f('\u0627'); }
+          '\ufe8e' => { f('\u0627'); }
+          '\ufe8f' => { f('\u0628'); }
+          '\ufe90' => { f('\u0628'); }
+          '\ufe91' => { f('\u0628'); }
+          '\ufe92' => { f('\u0628'); }
+          '\ufe93' => { f('\u0629'); }
+          '\ufe94' => { f('\u0629'); }
+          '\ufe95' => { f('\u062a'); }
+          '\ufe96' => { f('\u062a'); }
+          '\ufe97' => { f('\u062a'); }
+          '\ufe98' => { f('\u062a'); }
+          '\ufe99' => { f('\u062b'); }
+          '\ufe9a' => { f('\u062b'); }
+          '\ufe9b' => { f('\u062b'); }
+          '\ufe9c' => { f('\u062b'); }
+          '\ufe9d' => { f('\u062c'); }
+          '\ufe9e' => { f('\u062c'); }
+          '\ufe9f' => { f('\u062c'); }
+          '\ufea0' => { f('\u062c'); }
+          '\ufea1' => { f('\u062d'); }
+          '\ufea2' => { f('\u062d'); }
+          '\ufea3' => { f('\u062d'); }
+          '\ufea4' => { f('\u062d'); }
+          '\ufea5' => { f('\u062e'); }
+          '\ufea6' => { f('\u062e'); }
+          '\ufea7' => { f('\u062e'); }
+          '\ufea8' => { f('\u062e'); }
+          '\ufea9' => { f('\u062f'); }
+          '\ufeaa' => { f('\u062f'); }
+          '\ufeab' => { f('\u0630'); }
+          '\ufeac' => { f('\u0630'); }
+          '\ufead' => { f('\u0631'); }
+          '\ufeae' => { f('\u0631'); }
+          '\ufeaf' => { f('\u0632'); }
+          '\ufeb0' => { f('\u0632'); }
+          '\ufeb1' => { f('\u0633'); }
+          '\ufeb2' => { f('\u0633'); }
+          '\ufeb3' => { f('\u0633'); }
+          '\ufeb4' => { f('\u0633'); }
+          '\ufeb5' => { f('\u0634'); }
+          '\ufeb6' => { f('\u0634'); }
+          '\ufeb7' => { f('\u0634'); }
+          '\ufeb8' => { f('\u0634'); }
+          '\ufeb9' => { f('\u0635'); }
+          '\ufeba' => { f('\u0635'); }
+          '\ufebb' => { f('\u0635'); }
+          '\ufebc' => { f('\u0635'); }
+          '\ufebd' => { f('\u0636'); }
+          '\ufebe' => { f('\u0636'); }
+          '\ufebf' => { f('\u0636'); }
+          '\ufec0' => { f('\u0636'); }
+          '\ufec1' => { f('\u0637'); }
+          '\ufec2' => { f('\u0637'); }
+          '\ufec3' => { f('\u0637'); }
+          '\ufec4' => { f('\u0637'); }
+          '\ufec5' => { f('\u0638'); }
+          '\ufec6' => { f('\u0638'); }
+          '\ufec7' => { f('\u0638'); }
+          '\ufec8' => { f('\u0638'); }
+          '\ufec9' => { f('\u0639'); }
+          '\ufeca' => { f('\u0639'); }
+          '\ufecb' => { f('\u0639'); }
+          '\ufecc' => { f('\u0639'); }
+          '\ufecd' => { f('\u063a'); }
+          '\ufece' => { f('\u063a'); }
+          '\ufecf' => { f('\u063a'); }
+          '\ufed0' => { f('\u063a'); }
+          '\ufed1' => { f('\u0641'); }
+          '\ufed2' => { f('\u0641'); }
+          '\ufed3' => { f('\u0641'); }
+          '\ufed4' => { f('\u0641'); }
+          '\ufed5' => { f('\u0642'); }
+          '\ufed6' => { f('\u0642'); }
+          '\ufed7' => { f('\u0642'); }
+          '\ufed8' => { f('\u0642'); }
+          '\ufed9' => { f('\u0643'); }
+          '\ufeda' => { f('\u0643'); }
+          '\ufedb' => { f('\u0643'); }
+          '\ufedc' => { f('\u0643'); }
+          '\ufedd' => { f('\u0644'); }
+          '\ufede' => { f('\u0644'); }
+          '\ufedf' => { f('\u0644'); }
+          '\ufee0' => { f('\u0644'); }
+          '\ufee1' => { f('\u0645'); }
+          '\ufee2' => { f('\u0645'); }
+          '\ufee3' => { f('\u0645'); }
+          '\ufee4' => { f('\u0645'); }
+          '\ufee5' => { f('\u0646'); }
+          '\ufee6' => { f('\u0646'); }
+          '\ufee7' => { f('\u0646'); }
+          '\ufee8' => { f('\u0646'); }
+          '\ufee9' => { f('\u0647'); }
+          '\ufeea' => { f('\u0647'); }
+          '\ufeeb' => { f('\u0647'); }
+          '\ufeec' => { f('\u0647'); }
+          '\ufeed' => { f('\u0648'); }
+          '\ufeee' => { f('\u0648'); }
+          '\ufeef' => { f('\u0649'); }
+          '\ufef0' => { f('\u0649'); }
+          '\ufef1' => { f('\u064a'); }
+          '\ufef2' => { f('\u064a'); }
+          '\ufef3' => { f('\u064a'); }
+          '\ufef4' => { f('\u064a'); }
+          '\ufef5' => { f('\u0644'); f('\u0627'); f('\u0653'); }
+          '\ufef6' => { f('\u0644'); f('\u0627'); f('\u0653'); }
+          '\ufef7' => { f('\u0644'); f('\u0627'); f('\u0654'); }
+          '\ufef8' => { f('\u0644'); f('\u0627'); f('\u0654'); }
+          '\ufef9' => { f('\u0644'); f('\u0627'); f('\u0655'); }
+          '\ufefa' => { f('\u0644'); f('\u0627'); f('\u0655'); }
+          '\ufefb' => { f('\u0644'); f('\u0627'); }
+          '\ufefc' => { f('\u0644'); f('\u0627'); }
+          '\uff01' => { f('\x21'); }
+          '\uff02' => { f('\x22'); }
+          '\uff03' => { f('\x23'); }
+          '\uff04' => { f('\x24'); }
+          '\uff05' => { f('\x25'); }
+          '\uff06' => { f('\x26'); }
+          '\uff07' => { f('\x27'); }
+          '\uff08' => { f('\x28'); }
+          '\uff09' => { f('\x29'); }
+          '\uff0a' => { f('\x2a'); }
+          '\uff0b' => { f('\x2b'); }
+          '\uff0c' => { f('\x2c'); }
+          '\uff0d' => { f('\x2d'); }
+          '\uff0e' => { f('\x2e'); }
+          '\uff0f' => { f('\x2f'); }
+          '\uff10' => { f('\x30'); }
+          '\uff11' => { f('\x31'); }
+          '\uff12' => { f('\x32'); }
+          '\uff13' => { f('\x33'); }
+          '\uff14' => { f('\x34'); }
+          '\uff15' => { f('\x35'); }
+          '\uff16' => { f('\x36'); }
+          '\uff17' => { f('\x37'); }
+          '\uff18' => { f('\x38'); }
+          '\uff19' => { f('\x39'); }
+          '\uff1a' => { f('\x3a'); }
+          '\uff1b' => { f('\x3b'); }
+          '\uff1c' => { f('\x3c'); }
+          '\uff1d' => { f('\x3d'); }
+          '\uff1e' => { f('\x3e'); }
+          '\uff1f' => { f('\x3f'); }
+          '\uff20' => { f('\x40'); }
+          '\uff21' => { f('\x41'); }
+          '\uff22' => { f('\x42'); }
+          '\uff23' => { f('\x43'); }
+          '\uff24' => { f('\x44'); }
+          '\uff25' => { f('\x45'); }
+          '\uff26' => { f('\x46'); }
+          '\uff27' => { f('\x47'); }
+          '\uff28' => { f('\x48'); }
+          '\uff29' => { f('\x49'); }
+          '\uff2a' => { f('\x4a'); }
+          '\uff2b' => { f('\x4b'); }
+          '\uff2c' => { f('\x4c'); }
+          '\uff2d' => { f('\x4d'); }
+          '\uff2e' => { f('\x4e'); }
+          '\uff2f' => { f('\x4f'); }
+          '\uff30' => { f('\x50'); }
+          '\uff31' => { f('\x51'); }
+          '\uff32' => { f('\x52'); }
+          '\uff33' => { f('\x53'); }
+          '\uff34' => { f('\x54'); }
+          '\uff35' => { f('\x55'); }
+          '\uff36' => { f('\x56'); }
+          '\uff37' => { f('\x57'); }
+          '\uff38' => { f('\x58'); }
+          '\uff39' => { f('\x59'); }
+          '\uff3a' => { f('\x5a'); }
+          '\uff3b' => { f('\x5b'); }
+          '\uff3c' => { f('\x5c'); }
+          '\uff3d' => { f('\x5d'); }
+          '\uff3e' => { f('\x5e'); }
+          '\uff3f' => { f('\x5f'); }
+          '\uff40' => { f('\x60'); }
+          '\uff41' => { f('\x61'); }
+          '\uff42' => { f('\x62'); }
+          '\uff43' => { f('\x63'); }
+          '\uff44' => { f('\x64'); }
+          '\uff45' => { f('\x65'); }
+          '\uff46' => { f('\x66'); }
+          '\uff47' => { f('\x67'); }
+          '\uff48' => { f('\x68'); }
+          '\uff49' => { f('\x69'); }
+          '\uff4a' => { f('\x6a'); }
+          '\uff4b' => { f('\x6b'); }
+          '\uff4c' => { f('\x6c'); }
+          '\uff4d' => { f('\x6d'); }
+          '\uff4e' => { f('\x6e'); }
+          '\uff4f' => { f('\x6f'); }
+          '\uff50' => { f('\x70'); }
+          '\uff51' => { f('\x71'); }
+          '\uff52' => { f('\x72'); }
+          '\uff53' => { f('\x73'); }
+          '\uff54' => { f('\x74'); }
+          '\uff55' => { f('\x75'); }
+          '\uff56' => { f('\x76'); }
+          '\uff57' => { f('\x77'); }
+          '\uff58' => { f('\x78'); }
+          '\uff59' => { f('\x79'); }
+          '\uff5a' => { f('\x7a'); }
+          '\uff5b' => { f('\x7b'); }
+          '\uff5c' => { f('\x7c'); }
+          '\uff5d' => { f('\x7d'); }
+          '\uff5e' => { f('\x7e'); }
+          '\uff5f' => { f('\u2985'); }
+          '\uff60' => { f('\u2986'); }
+          '\uff61' => { f('\u3002'); }
+          '\uff62' => { f('\u300c'); }
+          '\uff63' => { f('\u300d'); }
+          '\uff64' => { f('\u3001'); }
+          '\uff65' => { f('\u30fb'); }
+          '\uff66' => { f('\u30f2'); }
+          '\uff67' => { f('\u30a1'); }
+          '\uff68' => { f('\u30a3'); }
+          '\uff69' => { f('\u30a5'); }
+          '\uff6a' => { f('\u30a7'); }
+          '\uff6b' => { f('\u30a9'); }
+          '\uff6c' => { f('\u30e3'); }
+          '\uff6d' => { f('\u30e5'); }
+          '\uff6e' => { f('\u30e7'); }
+          '\uff6f' => { f('\u30c3'); }
+          '\uff70' => { f('\u30fc'); }
+          '\uff71' => { f('\u30a2'); }
+          '\uff72' => { f('\u30a4'); }
+          '\uff73' => { f('\u30a6'); }
+          '\uff74' => { f('\u30a8'); }
+          '\uff75' => { f('\u30aa'); }
+          '\uff76' => { f('\u30ab'); }
+          '\uff77' => { f('\u30ad'); }
+          '\uff78' => { f('\u30af'); }
+          '\uff79' => { f('\u30b1'); }
+          '\uff7a' => { f('\u30b3'); }
+          '\uff7b' => { f('\u30b5'); }
+          '\uff7c' => { f('\u30b7'); }
+          '\uff7d' => { f('\u30b9'); }
+          '\uff7e' => { f('\u30bb'); }
+          '\uff7f' => { f('\u30bd'); }
+          '\uff80' => { f('\u30bf'); }
+          '\uff81' => { f('\u30c1'); }
+          '\uff82' => { f('\u30c4'); }
+          '\uff83' => { f('\u30c6'); }
+          '\uff84' => { f('\u30c8'); }
+          '\uff85' => { f('\u30ca'); }
+          '\uff86' => { f('\u30cb'); }
+          '\uff87' => { f('\u30cc'); }
+          '\uff88' => { f('\u30cd'); }
+          '\uff89' => { f('\u30ce'); }
+          '\uff8a' => { f('\u30cf'); }
+          '\uff8b' => { f('\u30d2'); }
+          '\uff8c' => { f('\u30d5'); }
+          '\uff8d' => { f('\u30d8'); }
+          '\uff8e' => { f('\u30db'); }
+          '\uff8f' => { f('\u30de'); }
+          '\uff90' => { f('\u30df'); }
+          '\uff91' => { f('\u30e0'); }
+          '\uff92' => { f('\u30e1'); }
+          '\uff93' => { f('\u30e2'); }
+          '\uff94' => { f('\u30e4'); }
+          '\uff95' => { f('\u30e6'); }
+          '\uff96' => { f('\u30e8'); }
+          '\uff97' => { f('\u30e9'); }
+          '\uff98' => { f('\u30ea'); }
+          '\uff99' => { f('\u30eb'); }
+          '\uff9a' => { f('\u30ec'); }
+          '\uff9b' => { f('\u30ed'); }
+          '\uff9c' => { f('\u30ef'); }
+          '\uff9d' => { f('\u30f3'); }
+          '\uff9e' => { f('\u3099'); }
+          '\uff9f' => { f('\u309a'); }
+          '\uffa0' => { f('\u1160'); }
+          '\uffa1' => { f('\u1100'); }
+          '\uffa2' => { f('\u1101'); }
+          '\uffa3' => { f('\u11aa'); }
+          '\uffa4' => { f('\u1102'); }
+          '\uffa5' => { f('\u11ac'); }
+          '\uffa6' => { f('\u11ad'); }
+          '\uffa7' => { f('\u1103'); }
+          '\uffa8' => { f('\u1104'); }
+          '\uffa9' => { f('\u1105'); }
+          '\uffaa' => { f('\u11b0'); }
+          '\uffab' => { f('\u11b1'); }
+          '\uffac' => { f('\u11b2'); }
+          '\uffad' => { f('\u11b3'); }
+          '\uffae' => { f('\u11b4'); }
+          '\uffaf' => { f('\u11b5'); }
+          '\uffb0' => { f('\u111a'); }
+          '\uffb1' => { f('\u1106'); }
+          '\uffb2' => { f('\u1107'); }
+          '\uffb3' => { f('\u1108'); }
+          '\uffb4' => { f('\u1121'); }
+          '\uffb5' => { f('\u1109'); }
+          '\uffb6' => { f('\u110a'); }
+          '\uffb7' => { f('\u110b'); }
+          '\uffb8' => { f('\u110c'); }
+          '\uffb9' => { f('\u110d'); }
+          '\uffba' => { f('\u110e'); }
+          '\uffbb' => { f('\u110f'); }
+          '\uffbc' => { f('\u1110'); }
+          '\uffbd' => { f('\u1111'); }
+          '\uffbe' => { f('\u1112'); }
+          '\uffc2' => { f('\u1161'); }
+          '\uffc3' => { f('\u1162'); }
+          '\uffc4' => { f('\u1163'); }
+          '\uffc5' => { f('\u1164'); }
+          '\uffc6' => { f('\u1165'); }
+          '\uffc7' => { f('\u1166'); }
+          '\uffca' => { f('\u1167'); }
+          '\uffcb' => { f('\u1168'); }
+          '\uffcc' => { f('\u1169'); }
+          '\uffcd' => { f('\u116a'); }
+          '\uffce' => { f('\u116b'); }
+          '\uffcf' => { f('\u116c'); }
+          '\uffd2' => { f('\u116d'); }
+          '\uffd3' => { f('\u116e'); }
+          '\uffd4' => { f('\u116f'); }
+          '\uffd5' => { f('\u1170'); }
+          '\uffd6' => { f('\u1171'); }
+          '\uffd7' => { f('\u1172'); }
+          '\uffda' => { f('\u1173'); }
+          '\uffdb' => { f('\u1174'); }
+          '\uffdc' => { f('\u1175'); }
+          '\uffe0' => { f('\xa2'); }
+          '\uffe1' => { f('\xa3'); }
+          '\uffe2' => { f('\xac'); }
+          '\uffe3' => { f('\x20'); f('\u0304'); }
+          '\uffe4' => { f('\xa6'); }
+          '\uffe5' => { f('\xa5'); }
+          '\uffe6' => { f('\u20a9'); }
+          '\uffe8' => { f('\u2502'); }
+          '\uffe9' => { f('\u2190'); }
+          '\uffea' => { f('\u2191'); }
+          '\uffeb' => { f('\u2192'); }
+          '\uffec' => { f('\u2193'); }
+          '\uffed' => { f('\u25a0'); }
+          '\uffee' => { f('\u25cb'); }
+          '\U00010781' => { f('\u02d0'); }
+          '\U00010782' => { f('\u02d1'); }
+          '\U00010783' => { f('\xe6'); }
+          '\U00010784' => { f('\u0299'); }
+          '\U00010785' => { f('\u0253'); }
+          '\U00010787' => { f('\u02a3'); }
+          '\U00010788' => { f('\uab66'); }
+          '\U00010789' => { f('\u02a5'); }
+          '\U0001078a' => { f('\u02a4'); }
+          '\U0001078b' => { f('\u0256'); }
+          '\U0001078c' => { f('\u0257'); }
+          '\U0001078d' => { f('\u1d91'); }
+          '\U0001078e' => { f('\u0258'); }
+          '\U0001078f' => { f('\u025e'); }
+          '\U00010790' => { f('\u02a9'); }
+          '\U00010791' => { f('\u0264'); }
+          '\U00010792' => { f('\u0262'); }
+          '\U00010793' => { f('\u0260'); }
+          '\U00010794' => { f('\u029b'); }
+          '\U00010795' => { f('\u0127'); }
+          '\U00010796' => { f('\u029c'); }
+          '\U00010797' => { f('\u0267'); }
+          '\U00010798' => { f('\u0284'); }
+          '\U00010799' => { f('\u02aa'); }
+          '\U0001079a' => { f('\u02ab'); }
+          '\U0001079b' => { f('\u026c'); }
+          '\U0001079c' => { f('\U0001df04'); }
+          '\U0001079d' => { f('\ua78e'); }
+          '\U0001079e' => { f('\u026e'); }
+          '\U0001079f' => { f('\U0001df05'); }
+          '\U000107a0' => { f('\u028e'); }
+          '\U000107a1' => { f('\U0001df06'); }
+          '\U000107a2' => { f('\xf8'); }
+          '\U000107a3' => { f('\u0276'); }
+          '\U000107a4' => { f('\u0277'); }
+          '\U000107a5' => { f('\x71'); }
+          '\U000107a6' => { f('\u027a'); }
+          '\U000107a7' => { f('\U0001df08'); }
+          '\U000107a8' => { f('\u027d'); }
+          '\U000107a9' => { f('\u027e'); }
+          '\U000107aa' => { f('\u0280'); }
+          '\U000107ab' => { f('\u02a8'); }
+          '\U000107ac' => { f('\u02a6'); }
+          '\U000107ad' => { f('\uab67'); }
+          '\U000107ae' => { f('\u02a7'); }
+          '\U000107af' => { f('\u0288'); }
+          '\U000107b0' => { f('\u2c71'); }
+          '\U000107b2' => { f('\u028f'); }
+          '\U000107b3' => { f('\u02a1'); }
+          '\U000107b4' => { f('\u02a2'); }
+          '\U000107b5' => { f('\u0298'); }
+          '\U000107b6' => { f('\u01c0'); }
+          '\U000107b7' => { f('\u01c1'); }
+          '\U000107b8' => { f('\u01c2'); }
+          '\U000107b9' => { f('\U0001df0a'); }
+          '\U000107ba' => { f('\U0001df1e'); }
+          '\U0001d400' => { f('\x41'); }
+          '\U0001d401' => { f('\x42'); }
+          '\U0001d402' => { f('\x43'); }
+          '\U0001d403' => { f('\x44'); }
+          '\U0001d404' => { f('\x45'); }
+          '\U0001d405' => { f('\x46'); }
+          '\U0001d406' => { f('\x47'); }
+          '\U0001d407' => { f('\x48'); }
+          '\U0001d408' => { f('\x49'); }
+          '\U0001d409' => { f('\x4a'); }
+          '\U0001d40a' => { f('\x4b'); }
+          '\U0001d40b' => { f('\x4c'); }
+          '\U0001d40c' => { f('\x4d'); }
+          '\U0001d40d' => { f('\x4e'); }
+          '\U0001d40e' => { f('\x4f'); }
+          '\U0001d40f' => { f('\x50'); }
+          '\U0001d410' => { f('\x51'); }
+          '\U0001d411' => { f('\x52'); }
+          '\U0001d412' => { f('\x53'); }
+          '\U0001d413' => { f('\x54'); }
+          '\U0001d414' => { f('\x55'); }
+          '\U0001d415' => { f('\x56'); }
+          '\U0001d416' => { f('\x57'); }
+          '\U0001d417' => { f('\x58'); }
+          '\U0001d418' => { f('\x59'); }
+          '\U0001d419' => { f('\x5a'); }
+          '\U0001d41a' => { f('\x61'); }
+          '\U0001d41b' => { f('\x62'); }
+          '\U0001d41c' => { f('\x63'); }
+          '\U0001d41d' => { f('\x64'); }
+          '\U0001d41e' => { f('\x65'); }
+          '\U0001d41f' => { f('\x66'); }
+          '\U0001d420' => { f('\x67'); }
+          '\U0001d421' => { f('\x68'); }
+          '\U0001d422' => { f('\x69'); }
+          '\U0001d423' => { f('\x6a'); }
+          '\U0001d424' => { f('\x6b'); }
+          '\U0001d425' => { f('\x6c'); }
+          '\U0001d426' => { f('\x6d'); }
+          '\U0001d427' => { f('\x6e'); }
+          '\U0001d428' => { f('\x6f'); }
+          '\U0001d429' => { f('\x70'); }
+          '\U0001d42a' => { f('\x71'); }
+          '\U0001d42b' => { f('\x72'); }
+          '\U0001d42c' => { f('\x73'); }
+          '\U0001d42d' => { f('\x74'); }
+          '\U0001d42e' => { f('\x75'); }
+          '\U0001d42f' => { f('\x76'); }
+          '\U0001d430' => { f('\x77'); }
+          '\U0001d431' => { f('\x78'); }
+          '\U0001d432' => { f('\x79'); }
+          '\U0001d433' => { f('\x7a'); }
+          '\U0001d434' => { f('\x41'); }
+          '\U0001d435' => { f('\x42'); }
+          '\U0001d436' => { f('\x43'); }
+          '\U0001d437' => { f('\x44'); }
+          '\U0001d438' => { f('\x45'); }
+          '\U0001d439' => { f('\x46'); }
+          '\U0001d43a' => { f('\x47'); }
+          '\U0001d43b' => { f('\x48'); }
+          '\U0001d43c' => { f('\x49'); }
+          '\U0001d43d' => { f('\x4a'); }
+          '\U0001d43e' => { f('\x4b'); }
+          '\U0001d43f' => { f('\x4c'); }
+          '\U0001d440' => { f('\x4d'); }
+          '\U0001d441' => { f('\x4e'); }
+          '\U0001d442' => { f('\x4f'); }
+          '\U0001d443' => { f('\x50'); }
+          '\U0001d444' => { f('\x51'); }
+          '\U0001d445' => { f('\x52'); }
+          '\U0001d446' => { f('\x53'); }
+          '\U0001d447' => { f('\x54'); }
+          '\U0001d448' => { f('\x55'); }
+          '\U0001d449' => { f('\x56'); }
+          '\U0001d44a' => { f('\x57'); }
+          '\U0001d44b' => { f('\x58'); }
+          '\U0001d44c' => { f('\x59'); }
+          '\U0001d44d' => { f('\x5a'); }
+          '\U0001d44e' => { f('\x61'); }
+          '\U0001d44f' => { f('\x62'); }
+          '\U0001d450' => { f('\x63'); }
+          '\U0001d451' => { f('\x64'); }
+          '\U0001d452' => { f('\x65'); }
+          '\U0001d453' => { f('\x66'); }
+          '\U0001d454' => { f('\x67'); }
+          '\U0001d456' => { f('\x69'); }
+          '\U0001d457' => { f('\x6a'); }
+          '\U0001d458' => { f('\x6b'); }
+          '\U0001d459' => { f('\x6c'); }
+          '\U0001d45a' => { f('\x6d'); }
+          '\U0001d45b' => { f('\x6e'); }
+          '\U0001d45c' => { f('\x6f'); }
+          '\U0001d45d' => { f('\x70'); }
+          '\U0001d45e' => { f('\x71'); }
+          '\U0001d45f' => { f('\x72'); }
+          '\U0001d460' => { f('\x73'); }
+          '\U0001d461' => { f('\x74'); }
+          '\U0001d462' => { f('\x75'); }
+          '\U0001d463' => { f('\x76'); }
+          '\U0001d464' => { f('\x77'); }
+          '\U0001d465' => { f('\x78'); }
+          '\U0001d466' => { f('\x79'); }
+          '\U0001d467' => { f('\x7a'); }
+          '\U0001d468' => { f('\x41'); }
+          '\U0001d469' => { f('\x42'); }
+          '\U0001d46a' => { f('\x43'); }
+          '\U0001d46b' => { f('\x44'); }
+          '\U0001d46c' => { f('\x45'); }
+          '\U0001d46d' => { f('\x46'); }
+          '\U0001d46e' => { f('\x47'); }
+          '\U0001d46f' => { f('\x48'); }
+          '\U0001d470' => { f('\x49'); }
+          '\U0001d471' => { f('\x4a'); }
+          '\U0001d472' => { f('\x4b'); }
+          '\U0001d473' => { f('\x4c'); }
+          '\U0001d474' => { f('\x4d'); }
+          '\U0001d475' => { f('\x4e'); }
+          '\U0001d476' => { f('\x4f'); }
+          '\U0001d477' => { f('\x50'); }
+          '\U0001d478' => { f('\x51'); }
+          '\U0001d479' => { f('\x52'); }
+          '\U0001d47a' => { f('\x53'); }
+          '\U0001d47b' => { f('\x54'); }
+          '\U0001d47c' => { f('\x55'); }
+          '\U0001d47d' => { f('\x56'); }
+          '\U0001d47e' => { f('\x57'); }
+          '\U0001d47f' => { f('\x58'); }
+          '\U0001d480' => { f('\x59'); }
+          '\U0001d481' => { f('\x5a'); }
+          '\U0001d482' => { f('\x61'); }
+          '\U0001d483' => { f('\x62'); }
+          '\U0001d484' => { f('\x63'); }
+          '\U0001d485' => { f('\x64'); }
+          '\U0001d486' => { f('\x65'); }
+          '\U0001d487' => { f('\x66'); }
+          '\U0001d488' => { f('\x67'); }
+          '\U0001d489' => { f('\x68'); }
+          '\U0001d48a' => { f('\x69'); }
+          '\U0001d48b' => { f('\x6a'); }
+          '\U0001d48c' => { f('\x6b'); }
+          '\U0001d48d' => { f('\x6c'); }
+          '\U0001d48e' => { f('\x6d'); }
+          '\U0001d48f' => { f('\x6e'); }
+          '\U0001d490' => { f('\x6f'); }
+          '\U0001d491' => { f('\x70'); }
+          '\U0001d492' => { f('\x71'); }
+          '\U0001d493' => { f('\x72'); }
+          '\U0001d494' => { f('\x73'); }
+          '\U0001d495' => { f('\x74'); }
+          '\U0001d496' => { f('\x75'); }
+          '\U0001d497' => { f('\x76'); }
+          '\U0001d498' => { f('\x77'); }
+          '\U0001d499' => { f('\x78'); }
+          '\U0001d49a' => { f('\x79'); }
+          '\U0001d49b' => { f('\x7a'); }
+          '\U0001d49c' => { f('\x41'); }
+          '\U0001d49e' => { f('\x43'); }
+          '\U0001d49f' => { f('\x44'); }
+          '\U0001d4a2' => { f('\x47'); }
+          '\U0001d4a5' => { f('\x4a'); }
+          '\U0001d4a6' => { f('\x4b'); }
+          '\U0001d4a9' => { f('\x4e'); }
+          '\U0001d4aa' => { f('\x4f'); }
+          '\U0001d4ab' => { f('\x50'); }
+          '\U0001d4ac' => { f('\x51'); }
+          '\U0001d4ae' => { f('\x53'); }
+          '\U0001d4af' => { f('\x54'); }
+          '\U0001d4b0' => { f('\x55'); }
+          '\U0001d4b1' => { f('\x56'); }
+          '\U0001d4b2' => { f('\x57'); }
+          '\U0001d4b3' => { f('\x58'); }
+          '\U0001d4b4' => { f('\x59'); }
+          '\U0001d4b5' => { f('\x5a'); }
+          '\U0001d4b6' => { f('\x61'); }
+          '\U0001d4b7' => { f('\x62'); }
+          '\U0001d4b8' => { f('\x63'); }
+          '\U0001d4b9' => { f('\x64'); }
+          '\U0001d4bb' => { f('\x66'); }
+          '\U0001d4bd' => { f('\x68'); }
+          '\U0001d4be' => { f('\x69'); }
+          '\U0001d4bf' => { f('\x6a'); }
+          '\U0001d4c0' => { f('\x6b'); }
+          '\U0001d4c1' => { f('\x6c'); }
+          '\U0001d4c2' => { f('\x6d'); }
+          '\U0001d4c3' => { f('\x6e'); }
+          '\U0001d4c5' => { f('\x70'); }
+          '\U0001d4c6' => { f('\x71'); }
+          '\U0001d4c7' => { f('\x72'); }
+          '\U0001d4c8' => { f('\x73'); }
+          '\U0001d4c9' => { f('\x74'); }
+          '\U0001d4ca' => { f('\x75'); }
+          '\U0001d4cb' => { f('\x76'); }
+          '\U0001d4cc' => { f('\x77'); }
+          '\U0001d4cd' => { f('\x78'); }
+          '\U0001d4ce' => { f('\x79'); }
+          '\U0001d4cf' => { f('\x7a'); }
+          '\U0001d4d0' => { f('\x41'); }
+          '\U0001d4d1' => { f('\x42'); }
+          '\U0001d4d2' => { f('\x43'); }
+          '\U0001d4d3' => { f('\x44'); }
+          '\U0001d4d4' => { f('\x45'); }
+          '\U0001d4d5' => { f('\x46'); }
+          '\U0001d4d6' => { f('\x47'); }
+          '\U0001d4d7' => { f('\x48'); }
+          '\U0001d4d8' => { f('\x49'); }
+          '\U0001d4d9' => { f('\x4a'); }
+          '\U0001d4da' => { f('\x4b'); }
+          '\U0001d4db' => { f('\x4c'); }
+          '\U0001d4dc' => { f('\x4d'); }
+          '\U0001d4dd' => { f('\x4e'); }
+          '\U0001d4de' => { f('\x4f'); }
+          '\U0001d4df' => { f('\x50'); }
+          '\U0001d4e0' => { f('\x51'); }
+          '\U0001d4e1' => { f('\x52'); }
+          '\U0001d4e2' => { f('\x53'); }
+          '\U0001d4e3' => { f('\x54'); }
+          '\U0001d4e4' => { f('\x55'); }
+          '\U0001d4e5' => { f('\x56'); }
+          '\U0001d4e6' => { f('\x57'); }
+          '\U0001d4e7' => { f('\x58'); }
+          '\U0001d4e8' => { f('\x59'); }
+          '\U0001d4e9' => { f('\x5a'); }
+          '\U0001d4ea' => { f('\x61'); }
+          '\U0001d4eb' => { f('\x62'); }
+          '\U0001d4ec' => { f('\x63'); }
+          '\U0001d4ed' => { f('\x64'); }
+          '\U0001d4ee' => { f('\x65'); }
+          '\U0001d4ef' => { f('\x66'); }
+          '\U0001d4f0' => { f('\x67'); }
+          '\U0001d4f1' => { f('\x68'); }
+          '\U0001d4f2' => { f('\x69'); }
+          '\U0001d4f3' => { f('\x6a'); }
+          '\U0001d4f4' => { f('\x6b'); }
+          '\U0001d4f5' => { f('\x6c'); }
+          '\U0001d4f6' => { f('\x6d'); }
+          '\U0001d4f7' => { f('\x6e'); }
+          '\U0001d4f8' => { f('\x6f'); }
+          '\U0001d4f9' => { f('\x70'); }
+          '\U0001d4fa' => { f('\x71'); }
+          '\U0001d4fb' => { f('\x72'); }
+          '\U0001d4fc' => { f('\x73'); }
+          '\U0001d4fd' => { f('\x74'); }
+          '\U0001d4fe' => { f('\x75'); }
+          '\U0001d4ff' => { f('\x76'); }
+          '\U0001d500' => { f('\x77'); }
+          '\U0001d501' => { f('\x78'); }
+          '\U0001d502' => { f('\x79'); }
+          '\U0001d503' => { f('\x7a'); }
+          '\U0001d504' => { f('\x41'); }
+          '\U0001d505' => { f('\x42'); }
+          '\U0001d507' => { f('\x44'); }
+          '\U0001d508' => { f('\x45'); }
+          '\U0001d509' => { f('\x46'); }
+          '\U0001d50a' => { f('\x47'); }
+          '\U0001d50d' => { f('\x4a'); }
+          '\U0001d50e' => { f('\x4b'); }
+          '\U0001d50f' => { f('\x4c'); }
+          '\U0001d510' => { f('\x4d'); }
+          '\U0001d511' => { f('\x4e'); }
+          '\U0001d512' => { f('\x4f'); }
+          '\U0001d513' => { f('\x50'); }
+          '\U0001d514' => { f('\x51'); }
+          '\U0001d516' => { f('\x53'); }
+          '\U0001d517' => { f('\x54'); }
+          '\U0001d518' => { f('\x55'); }
+          '\U0001d519' => { f('\x56'); }
+          '\U0001d51a' => { f('\x57'); }
+          '\U0001d51b' => { f('\x58'); }
+          '\U0001d51c' => { f('\x59'); }
+          '\U0001d51e' => { f('\x61'); }
+          '\U0001d51f' => { f('\x62'); }
+          '\U0001d520' => { f('\x63'); }
+          '\U0001d521' => { f('\x64'); }
+          '\U0001d522' => { f('\x65'); }
+          '\U0001d523' => { f('\x66'); }
+          '\U0001d524' => { f('\x67'); }
+          '\U0001d525' => { f('\x68'); }
+          '\U0001d526' => { f('\x69'); }
+          '\U0001d527' => { f('\x6a'); }
+          '\U0001d528' => { f('\x6b'); }
+          '\U0001d529' => { f('\x6c'); }
+          '\U0001d52a' => { f('\x6d'); }
+          '\U0001d52b' => { f('\x6e'); }
+          '\U0001d52c' => { f('\x6f'); }
+          '\U0001d52d' => { f('\x70'); }
+          '\U0001d52e' => { f('\x71'); }
+          '\U0001d52f' => { f('\x72'); }
+          '\U0001d530' => { f('\x73'); }
+          '\U0001d531' => { f('\x74'); }
+          '\U0001d532' => { f('\x75'); }
+          '\U0001d533' => { f('\x76'); }
+          '\U0001d534' => { f('\x77'); }
+          '\U0001d535' => { f('\x78'); }
+          '\U0001d536' => { f('\x79'); }
+          '\U0001d537' => { f('\x7a'); }
+          '\U0001d538' => { f('\x41'); }
+          '\U0001d539' => { f('\x42'); }
+          '\U0001d53b' => { f('\x44'); }
+          '\U0001d53c' => { f('\x45'); }
+          '\U0001d53d' => { f('\x46'); }
+          '\U0001d53e' => { f('\x47'); }
+          '\U0001d540' => { f('\x49'); }
+          '\U0001d541' => { f('\x4a'); }
+          '\U0001d542' => { f('\x4b'); }
+          '\U0001d543' => { f('\x4c'); }
+          '\U0001d544' => { f('\x4d'); }
+          '\U0001d546' => { f('\x4f'); }
+          '\U0001d54a' => { f('\x53'); }
+          '\U0001d54b' => { f('\x54'); }
+          '\U0001d54c' => { f('\x55'); }
+          '\U0001d54d' => { f('\x56'); }
+          '\U0001d54e' => { f('\x57'); }
+          '\U0001d54f' => { f('\x58'); }
+          '\U0001d550' => { f('\x59'); }
+          '\U0001d552' => { f('\x61'); }
+          '\U0001d553' => { f('\x62'); }
+          '\U0001d554' => { f('\x63'); }
+          '\U0001d555' => { f('\x64'); }
+          '\U0001d556' => { f('\x65'); }
+          '\U0001d557' => { f('\x66'); }
+          '\U0001d558' => { f('\x67'); }
+          '\U0001d559' => { f('\x68'); }
+          '\U0001d55a' => { f('\x69'); }
+          '\U0001d55b' => { f('\x6a'); }
+          '\U0001d55c' => { f('\x6b'); }
+          '\U0001d55d' => { f('\x6c'); }
+          '\U0001d55e' => { f('\x6d'); }
+          '\U0001d55f' => { f('\x6e'); }
+          '\U0001d560' => { f('\x6f'); }
+          '\U0001d561' => { f('\x70'); }
+          '\U0001d562' => { f('\x71'); }
+          '\U0001d563' => { f('\x72'); }
+          '\U0001d564' => { f('\x73'); }
+          '\U0001d565' => { f('\x74'); }
+          '\U0001d566' => { f('\x75'); }
+          '\U0001d567' => { f('\x76'); }
+          '\U0001d568' => { f('\x77'); }
+          '\U0001d569' => { f('\x78'); }
+          '\U0001d56a' => { f('\x79'); }
+          '\U0001d56b' => { f('\x7a'); }
+          '\U0001d56c' => { f('\x41'); }
+          '\U0001d56d' => { f('\x42'); }
+          '\U0001d56e' => { f('\x43'); }
+          '\U0001d56f' => { f('\x44'); }
+          '\U0001d570' => { f('\x45'); }
+          '\U0001d571' => { f('\x46'); }
+          '\U0001d572' => { f('\x47'); }
+          '\U0001d573' => { f('\x48'); }
+          '\U0001d574' => { f('\x49'); }
+          '\U0001d575' => { f('\x4a'); }
+          '\U0001d576' => { f('\x4b'); }
+          '\U0001d577' => { f('\x4c'); }
+          '\U0001d578' => { f('\x4d'); }
+          '\U0001d579' => { f('\x4e'); }
+          '\U0001d57a' => { f('\x4f'); }
+          '\U0001d57b' => { f('\x50'); }
+          '\U0001d57c' => { f('\x51'); }
+          '\U0001d57d' => { f('\x52'); }
+          '\U0001d57e' => { f('\x53'); }
+          '\U0001d57f' => { f('\x54'); }
+          '\U0001d580' => { f('\x55'); }
+          '\U0001d581' => { f('\x56'); }
+          '\U0001d582' => { f('\x57'); }
+          '\U0001d583' => { f('\x58'); }
+          '\U0001d584' => { f('\x59'); }
+          '\U0001d585' => { f('\x5a'); }
+          '\U0001d586' => { f('\x61'); }
+          '\U0001d587' => { f('\x62'); }
+          '\U0001d588' => { f('\x63'); }
+          '\U0001d589' => { f('\x64'); }
+          '\U0001d58a' => { f('\x65'); }
+          '\U0001d58b' => { f('\x66'); }
+          '\U0001d58c' => { f('\x67'); }
+          '\U0001d58d' => { f('\x68'); }
+          '\U0001d58e' => { f('\x69'); }
+          '\U0001d58f' => { f('\x6a'); }
+          '\U0001d590' => { f('\x6b'); }
+          '\U0001d591' => { f('\x6c'); }
+          '\U0001d592' => { f('\x6d'); }
+          '\U0001d593' => { f('\x6e'); }
+          '\U0001d594' => { f('\x6f'); }
+          '\U0001d595' => { f('\x70'); }
+          '\U0001d596' => { f('\x71'); }
+          '\U0001d597' => { f('\x72'); }
+          '\U0001d598' => { f('\x73'); }
+          '\U0001d599' => { f('\x74'); }
+          '\U0001d59a' => { f('\x75'); }
+          '\U0001d59b' => { f('\x76'); }
+          '\U0001d59c' => { f('\x77'); }
+          '\U0001d59d' => { f('\x78'); }
+          '\U0001d59e' => { f('\x79'); }
+          '\U0001d59f' => { f('\x7a'); }
+          '\U0001d5a0' => { f('\x41'); }
+          '\U0001d5a1' => { f('\x42'); }
+          '\U0001d5a2' => { f('\x43'); }
+          '\U0001d5a3' => { f('\x44'); }
+          '\U0001d5a4' => { f('\x45'); }
+          '\U0001d5a5' => { f('\x46'); }
+          '\U0001d5a6' => { f('\x47'); }
+          '\U0001d5a7' => { f('\x48'); }
+          '\U0001d5a8' => { f('\x49'); }
+          '\U0001d5a9' => { f('\x4a'); }
+          '\U0001d5aa' => { f('\x4b'); }
+          '\U0001d5ab' => { f('\x4c'); }
+          '\U0001d5ac' => { f('\x4d'); }
+          '\U0001d5ad' => { f('\x4e'); }
+          '\U0001d5ae' => { f('\x4f'); }
+          '\U0001d5af' => { f('\x50'); }
+          '\U0001d5b0' => { f('\x51'); }
+          '\U0001d5b1' => { f('\x52'); }
+          '\U0001d5b2' => { f('\x53'); }
+          '\U0001d5b3' => { f('\x54'); }
+          '\U0001d5b4' => { f('\x55'); }
+          '\U0001d5b5' => { f('\x56'); }
+          '\U0001d5b6' => { f('\x57'); }
+          '\U0001d5b7' => { f('\x58'); }
+          '\U0001d5b8' => { f('\x59'); }
+          '\U0001d5b9' => { f('\x5a'); }
+          '\U0001d5ba' => { f('\x61'); }
+          '\U0001d5bb' => { f('\x62'); }
+          '\U0001d5bc' => { f('\x63'); }
+          '\U0001d5bd' => { f('\x64'); }
+          '\U0001d5be' => { f('\x65'); }
+          '\U0001d5bf' => { f('\x66'); }
+          '\U0001d5c0' => { f('\x67'); }
+          '\U0001d5c1' => { f('\x68'); }
+          '\U0001d5c2' => { f('\x69'); }
+          '\U0001d5c3' => { f('\x6a'); }
+          '\U0001d5c4' => { f('\x6b'); }
+          '\U0001d5c5' => { f('\x6c'); }
+          '\U0001d5c6' => { f('\x6d'); }
+          '\U0001d5c7' => { f('\x6e'); }
+          '\U0001d5c8' => { f('\x6f'); }
+          '\U0001d5c9' => { f('\x70'); }
+          '\U0001d5ca' => { f('\x71'); }
+          '\U0001d5cb' => { f('\x72'); }
+          '\U0001d5cc' => { f('\x73'); }
+          '\U0001d5cd' => { f('\x74'); }
+          '\U0001d5ce' => { f('\x75'); }
+          '\U0001d5cf' => { f('\x76'); }
+          '\U0001d5d0' => { f('\x77'); }
+          '\U0001d5d1' => { f('\x78'); }
+          '\U0001d5d2' => { f('\x79'); }
+          '\U0001d5d3' => { f('\x7a'); }
+          '\U0001d5d4' => { f('\x41'); }
+          '\U0001d5d5' => { f('\x42'); }
+          '\U0001d5d6' => { f('\x43'); }
+          '\U0001d5d7' => { f('\x44'); }
+          '\U0001d5d8' => { f('\x45'); }
+          '\U0001d5d9' => { f('\x46'); }
+          '\U0001d5da' => { f('\x47'); }
+          '\U0001d5db' => { f('\x48'); }
+          '\U0001d5dc' => { f('\x49'); }
+          '\U0001d5dd' => { f('\x4a'); }
+          '\U0001d5de' => { f('\x4b'); }
+          '\U0001d5df' => { f('\x4c'); }
+          '\U0001d5e0' => { f('\x4d'); }
+          '\U0001d5e1' => { f('\x4e'); }
+          '\U0001d5e2' => { f('\x4f'); }
+          '\U0001d5e3' => { f('\x50'); }
+          '\U0001d5e4' => { f('\x51'); }
+          '\U0001d5e5' => { f('\x52'); }
+          '\U0001d5e6' => { f('\x53'); }
+          '\U0001d5e7' => { f('\x54'); }
+          '\U0001d5e8' => { f('\x55'); }
+          '\U0001d5e9' => { f('\x56'); }
+          '\U0001d5ea' => { f('\x57'); }
+          '\U0001d5eb' => { f('\x58'); }
+          '\U0001d5ec' => { f('\x59'); }
+          '\U0001d5ed' => { f('\x5a'); }
+          '\U0001d5ee' => { f('\x61'); }
+          '\U0001d5ef' => { f('\x62'); }
+          '\U0001d5f0' => { f('\x63'); }
+          '\U0001d5f1' => { f('\x64'); }
+          '\U0001d5f2' => { f('\x65'); }
+          '\U0001d5f3' => { f('\x66'); }
+          '\U0001d5f4' => { f('\x67'); }
+          '\U0001d5f5' => { f('\x68'); }
+          '\U0001d5f6' => { f('\x69'); }
+          '\U0001d5f7' => { f('\x6a'); }
+          '\U0001d5f8' => { f('\x6b'); }
+          '\U0001d5f9' => { f('\x6c'); }
+          '\U0001d5fa' => { f('\x6d'); }
+          '\U0001d5fb' => { f('\x6e'); }
+          '\U0001d5fc' => { f('\x6f'); }
+          '\U0001d5fd' => { f('\x70'); }
+          '\U0001d5fe' => { f('\x71'); }
+          '\U0001d5ff' => { f('\x72'); }
+          '\U0001d600' => { f('\x73'); }
+          '\U0001d601' => { f('\x74'); }
+          '\U0001d602' => { f('\x75'); }
+          '\U0001d603' => { f('\x76'); }
+          '\U0001d604' => { f('\x77'); }
+          '\U0001d605' => { f('\x78'); }
+          '\U0001d606' => { f('\x79'); }
+          '\U0001d607' => { f('\x7a'); }
+          '\U0001d608' => { f('\x41'); }
+          '\U0001d609' => { f('\x42'); }
+          '\U0001d60a' => { f('\x43'); }
+          '\U0001d60b' => { f('\x44'); }
+          '\U0001d60c' => { f('\x45'); }
+          '\U0001d60d' => { f('\x46'); }
+          '\U0001d60e' => { f('\x47'); }
+          '\U0001d60f' => { f('\x48'); }
+          '\U0001d610' => { f('\x49'); }
+          '\U0001d611' => { f('\x4a'); }
+          '\U0001d612' => { f('\x4b'); }
+          '\U0001d613' => { f('\x4c'); }
+          '\U0001d614' => { f('\x4d'); }
+          '\U0001d615' => { f('\x4e'); }
+          '\U0001d616' => { f('\x4f'); }
+          '\U0001d617' => { f('\x50'); }
+          '\U0001d618' => { f('\x51'); }
+          '\U0001d619' => { f('\x52'); }
+          '\U0001d61a' => { f('\x53'); }
+          '\U0001d61b' => { f('\x54'); }
+          '\U0001d61c' => { f('\x55'); }
+          '\U0001d61d' => { f('\x56'); }
+          '\U0001d61e' => { f('\x57'); }
+          '\U0001d61f' => { f('\x58'); }
+          '\U0001d620' => { f('\x59'); }
+          '\U0001d621' => { f('\x5a'); }
+          '\U0001d622' => { f('\x61'); }
+          '\U0001d623' => { f('\x62'); }
+          '\U0001d624' => { f('\x63'); }
+          '\U0001d625' => { f('\x64'); }
+          '\U0001d626' => { f('\x65'); }
+          '\U0001d627' => { f('\x66'); }
+          '\U0001d628' => { f('\x67'); }
+          '\U0001d629' => { f('\x68'); }
+          '\U0001d62a' => { f('\x69'); }
+          '\U0001d62b' => { f('\x6a'); }
+          '\U0001d62c' => { f('\x6b'); }
+          '\U0001d62d' => { f('\x6c'); }
+          '\U0001d62e' => { f('\x6d'); }
+          '\U0001d62f' => { f('\x6e'); }
+          '\U0001d630' => { f('\x6f'); }
+          '\U0001d631' => { f('\x70'); }
+          '\U0001d632' => { f('\x71'); }
+          '\U0001d633' => { f('\x72'); }
+          '\U0001d634' => { f('\x73'); }
+          '\U0001d635' => { f('\x74'); }
+          '\U0001d636' => { f('\x75'); }
+          '\U0001d637' => { f('\x76'); }
+          '\U0001d638' => { f('\x77'); }
+          '\U0001d639' => { f('\x78'); }
+          '\U0001d63a' => { f('\x79'); }
+          '\U0001d63b' => { f('\x7a'); }
+          '\U0001d63c' => { f('\x41'); }
+          '\U0001d63d' => { f('\x42'); }
+          '\U0001d63e' => { f('\x43'); }
+          '\U0001d63f' => { f('\x44'); }
+          '\U0001d640' => { f('\x45'); }
+          '\U0001d641' => { f('\x46'); }
+          '\U0001d642' => { f('\x47'); }
+          '\U0001d643' => { f('\x48'); }
+          '\U0001d644' => { f('\x49'); }
+          '\U0001d645' => { f('\x4a'); }
+          '\U0001d646' => { f('\x4b'); }
+          '\U0001d647' => { f('\x4c'); }
+          '\U0001d648' => { f('\x4d'); }
+          '\U0001d649' => { f('\x4e'); }
+          '\U0001d64a' => { f('\x4f'); }
+          '\U0001d64b' => { f('\x50'); }
+          '\U0001d64c' => { f('\x51'); }
+          '\U0001d64d' => { f('\x52'); }
+          '\U0001d64e' => { f('\x53'); }
+          '\U0001d64f' => { f('\x54'); }
+          '\U0001d650' => { f('\x55'); }
+          '\U0001d651' => { f('\x56'); }
+          '\U0001d652' => { f('\x57'); }
+          '\U0001d653' => { f('\x58'); }
+          '\U0001d654' => { f('\x59'); }
+          '\U0001d655' => { f('\x5a'); }
+          '\U0001d656' => { f('\x61'); }
+          '\U0001d657' => { f('\x62'); }
+          '\U0001d658' => { f('\x63'); }
+          '\U0001d659' => { f('\x64'); }
+          '\U0001d65a' => { f('\x65'); }
+          '\U0001d65b' => { f('\x66'); }
+          '\U0001d65c' => { f('\x67'); }
+          '\U0001d65d' => { f('\x68'); }
+          '\U0001d65e' => { f('\x69'); }
+          '\U0001d65f' => { f('\x6a'); }
+          '\U0001d660' => { f('\x6b'); }
+          '\U0001d661' => { f('\x6c'); }
+          '\U0001d662' => { f('\x6d'); }
+          '\U0001d663' => { f('\x6e'); }
+          '\U0001d664' => { f('\x6f'); }
+          '\U0001d665' => { f('\x70'); }
+          '\U0001d666' => { f('\x71'); }
+          '\U0001d667' => { f('\x72'); }
+          '\U0001d668' => { f('\x73'); }
+          '\U0001d669' => { f('\x74'); }
+          '\U0001d66a' => { f('\x75'); }
+          '\U0001d66b' => { f('\x76'); }
+          '\U0001d66c' => { f('\x77'); }
+          '\U0001d66d' => { f('\x78'); }
+          '\U0001d66e' => { f('\x79'); }
+          '\U0001d66f' => { f('\x7a'); }
+          '\U0001d670' => { f('\x41'); }
+          '\U0001d671' => { f('\x42'); }
+          '\U0001d672' => { f('\x43'); }
+          '\U0001d673' => { f('\x44'); }
+          '\U0001d674' => { f('\x45'); }
+          '\U0001d675' => { f('\x46'); }
+          '\U0001d676' => { f('\x47'); }
+          '\U0001d677' => { f('\x48'); }
+          '\U0001d678' => { f('\x49'); }
+          '\U0001d679' => { f('\x4a'); }
+          '\U0001d67a' => { f('\x4b'); }
+          '\U0001d67b' => { f('\x4c'); }
+          '\U0001d67c' => { f('\x4d'); }
+          '\U0001d67d' => { f('\x4e'); }
+          '\U0001d67e' => { f('\x4f'); }
+          '\U0001d67f' => { f('\x50'); }
+          '\U0001d680' => { f('\x51'); }
+          '\U0001d681' => { f('\x52'); }
+          '\U0001d682' => { f('\x53'); }
+          '\U0001d683' => { f('\x54'); }
+          '\U0001d684' => { f('\x55'); }
+          '\U0001d685' => { f('\x56'); }
+          '\U0001d686' => { f('\x57'); }
+          '\U0001d687' => { f('\x58'); }
+          '\U0001d688' => { f('\x59'); }
+          '\U0001d689' => { f('\x5a'); }
+          '\U0001d68a' => { f('\x61'); }
+          '\U0001d68b' => { f('\x62'); }
+          '\U0001d68c' => { f('\x63'); }
+          '\U0001d68d' => { f('\x64'); }
+          '\U0001d68e' => { f('\x65'); }
+          '\U0001d68f' => { f('\x66'); }
+          '\U0001d690' => { f('\x67'); }
+          '\U0001d691' => { f('\x68'); }
+          '\U0001d692' => { f('\x69'); }
+          '\U0001d693' => { f('\x6a'); }
+          '\U0001d694' => { f('\x6b'); }
+          '\U0001d695' => { f('\x6c'); }
+          '\U0001d696' => { f('\x6d'); }
+          '\U0001d697' => { f('\x6e'); }
+          '\U0001d698' => { f('\x6f'); }
+          '\U0001d699' => { f('\x70'); }
+          '\U0001d69a' => { f('\x71'); }
+          '\U0001d69b' => { f('\x72'); }
+          '\U0001d69c' => { f('\x73'); }
+          '\U0001d69d' => { f('\x74'); }
+          '\U0001d69e' => { f('\x75'); }
+          '\U0001d69f' => { f('\x76'); }
+          '\U0001d6a0' => { f('\x77'); }
+          '\U0001d6a1' => { f('\x78'); }
+          '\U0001d6a2' => { f('\x79'); }
+          '\U0001d6a3' => { f('\x7a'); }
+          '\U0001d6a4' => { f('\u0131'); }
+          '\U0001d6a5' => { f('\u0237'); }
+          '\U0001d6a8' => { f('\u0391'); }
+          '\U0001d6a9' => { f('\u0392'); }
+          '\U0001d6aa' => { f('\u0393'); }
+          '\U0001d6ab' => { f('\u0394'); }
+          '\U0001d6ac' => { f('\u0395'); }
+          '\U0001d6ad' => { f('\u0396'); }
+          '\U0001d6ae' => { f('\u0397'); }
+          '\U0001d6af' => { f('\u0398'); }
+          '\U0001d6b0' => { f('\u0399'); }
+          '\U0001d6b1' => { f('\u039a'); }
+          '\U0001d6b2' => { f('\u039b'); }
+          '\U0001d6b3' => { f('\u039c'); }
+          '\U0001d6b4' => { f('\u039d'); }
+          '\U0001d6b5' => { f('\u039e'); }
+          '\U0001d6b6' => { f('\u039f'); }
+          '\U0001d6b7' => { f('\u03a0'); }
+          '\U0001d6b8' => { f('\u03a1'); }
+          '\U0001d6b9' => { f('\u0398'); }
+          '\U0001d6ba' => { f('\u03a3'); }
+          '\U0001d6bb' => { f('\u03a4'); }
+          '\U0001d6bc' => { f('\u03a5'); }
+          '\U0001d6bd' => { f('\u03a6'); }
+          '\U0001d6be' => { f('\u03a7'); }
+          '\U0001d6bf' => { f('\u03a8'); }
+          '\U0001d6c0' => { f('\u03a9'); }
+          '\U0001d6c1' => { f('\u2207'); }
+          '\U0001d6c2' => { f('\u03b1'); }
+          '\U0001d6c3' => { f('\u03b2'); }
+          '\U0001d6c4' => { f('\u03b3'); }
+          '\U0001d6c5' => { f('\u03b4'); }
+          '\U0001d6c6' => { f('\u03b5'); }
+          '\U0001d6c7' => { f('\u03b6'); }
+          '\U0001d6c8' => { f('\u03b7'); }
+          '\U0001d6c9' => { f('\u03b8'); }
+          '\U0001d6ca' => { f('\u03b9'); }
+          '\U0001d6cb' => { f('\u03ba'); }
+          '\U0001d6cc' => { f('\u03bb'); }
+          '\U0001d6cd' => { f('\u03bc'); }
+          '\U0001d6ce' => { f('\u03bd'); }
+          '\U0001d6cf' => { f('\u03be'); }
+          '\U0001d6d0' => { f('\u03bf'); }
+          '\U0001d6d1' => { f('\u03c0'); }
+          '\U0001d6d2' => { f('\u03c1'); }
+          '\U0001d6d3' => { f('\u03c2'); }
+          '\U0001d6d4' => { f('\u03c3'); }
+          '\U0001d6d5' => { f('\u03c4'); }
+          '\U0001d6d6' => { f('\u03c5'); }
+          '\U0001d6d7' => { f('\u03c6'); }
+          '\U0001d6d8' => { f('\u03c7'); }
+          '\U0001d6d9' => { f('\u03c8'); }
+          '\U0001d6da' => { f('\u03c9'); }
+          '\U0001d6db' => { f('\u2202'); }
+          '\U0001d6dc' => { f('\u03b5'); }
+          '\U0001d6dd' => { f('\u03b8'); }
+          '\U0001d6de' => { f('\u03ba'); }
+          '\U0001d6df' => { f('\u03c6'); }
+          '\U0001d6e0' => { f('\u03c1'); }
+          '\U0001d6e1' => { f('\u03c0'); }
+          '\U0001d6e2' => { f('\u0391'); }
+          '\U0001d6e3' => { f('\u0392'); }
+          '\U0001d6e4' => { f('\u0393'); }
+          '\U0001d6e5' => { f('\u0394'); }
+          '\U0001d6e6' => { f('\u0395'); }
+          '\U0001d6e7' => { f('\u0396'); }
+          '\U0001d6e8' => { f('\u0397'); }
+          '\U0001d6e9' => { f('\u0398'); }
+          '\U0001d6ea' => { f('\u0399'); }
+          '\U0001d6eb' => { f('\u039a'); }
+          '\U0001d6ec' => { f('\u039b'); }
+          '\U0001d6ed' => { f('\u039c'); }
+          '\U0001d6ee' => { f('\u039d'); }
+          '\U0001d6ef' => { f('\u039e'); }
+          '\U0001d6f0' => { f('\u039f'); }
+          '\U0001d6f1' => { f('\u03a0'); }
+          '\U0001d6f2' => { f('\u03a1'); }
+          '\U0001d6f3' => { f('\u0398'); }
+          '\U0001d6f4' => { f('\u03a3'); }
+          '\U0001d6f5' => { f('\u03a4'); }
+          '\U0001d6f6' => { f('\u03a5'); }
+          '\U0001d6f7' => { f('\u03a6'); }
+          '\U0001d6f8' => { f('\u03a7'); }
+          '\U0001d6f9' => { f('\u03a8'); }
+          '\U0001d6fa' => { f('\u03a9'); }
+          '\U0001d6fb' => { f('\u2207'); }
+          '\U0001d6fc' => { f('\u03b1'); }
+          '\U0001d6fd' => { f('\u03b2'); }
+          '\U0001d6fe' => { f('\u03b3'); }
+          '\U0001d6ff' => { f('\u03b4'); }
+          '\U0001d700' => { f('\u03b5'); }
+          '\U0001d701' => { f('\u03b6'); }
+          '\U0001d702' => { f('\u03b7'); }
+          '\U0001d703' => { f('\u03b8'); }
+          '\U0001d704' => { f('\u03b9'); }
+          '\U0001d705' => { f('\u03ba'); }
+          '\U0001d706' => { f('\u03bb'); }
+          '\U0001d707' => { f('\u03bc'); }
+          '\U0001d708' => { f('\u03bd'); }
+          '\U0001d709' => { f('\u03be'); }
+          '\U0001d70a' => { f('\u03bf'); }
+          '\U0001d70b' => { f('\u03c0'); }
+          '\U0001d70c' => { f('\u03c1'); }
+          '\U0001d70d' => { f('\u03c2'); }
+          '\U0001d70e' => { f('\u03c3'); }
+          '\U0001d70f' => { f('\u03c4'); }
+          '\U0001d710' => { f('\u03c5'); }
+          '\U0001d711' => { f('\u03c6'); }
+          '\U0001d712' => { f('\u03c7'); }
+          '\U0001d713' => { f('\u03c8'); }
+          '\U0001d714' => { f('\u03c9'); }
+          '\U0001d715' => { f('\u2202'); }
+          '\U0001d716' => { f('\u03b5'); }
+          '\U0001d717' => { f('\u03b8'); }
+          '\U0001d718' => { f('\u03ba'); }
+          '\U0001d719' => { f('\u03c6'); }
+          '\U0001d71a' => { f('\u03c1'); }
+          '\U0001d71b' => { f('\u03c0'); }
+          '\U0001d71c' => { f('\u0391'); }
+          '\U0001d71d' => { f('\u0392'); }
+          '\U0001d71e' => { f('\u0393'); }
+          '\U0001d71f' => { f('\u0394'); }
+          '\U0001d720' => { f('\u0395'); }
+          '\U0001d721' => { f('\u0396'); }
+          '\U0001d722' => { f('\u0397'); }
+          '\U0001d723' => { f('\u0398'); }
+          '\U0001d724' => { f('\u0399'); }
+          '\U0001d725' => { f('\u039a'); }
+          '\U0001d726' => { f('\u039b'); }
+          '\U0001d727' => { f('\u039c'); }
+          '\U0001d728' => { f('\u039d'); }
+          '\U0001d729' => { f('\u039e'); }
+          '\U0001d72a' => { f('\u039f'); }
+          '\U0001d72b' => { f('\u03a0'); }
+          '\U0001d72c' => { f('\u03a1'); }
+          '\U0001d72d' => { f('\u0398'); }
+          '\U0001d72e' => { f('\u03a3'); }
+          '\U0001d72f' => { f('\u03a4'); }
+          '\U0001d730' => { f('\u03a5'); }
+          '\U0001d731' => { f('\u03a6'); }
+          '\U0001d732' => { f('\u03a7'); }
+          '\U0001d733' => { f('\u03a8'); }
+          '\U0001d734' => { f('\u03a9'); }
+          '\U0001d735' => { f('\u2207'); }
+          '\U0001d736' => { f('\u03b1'); }
+          '\U0001d737' => { f('\u03b2'); }
+          '\U0001d738' => { f('\u03b3'); }
+          '\U0001d739' => { f('\u03b4'); }
+          '\U0001d73a' => { f('\u03b5'); }
+          '\U0001d73b' => { f('\u03b6'); }
+          '\U0001d73c' => { f('\u03b7'); }
+          '\U0001d73d' => { f('\u03b8'); }
+          '\U0001d73e' => { f('\u03b9'); }
+          '\U0001d73f' => { f('\u03ba'); }
+          '\U0001d740' => { f('\u03bb'); }
+          '\U0001d741' => { f('\u03bc'); }
+          '\U0001d742' => { f('\u03bd'); }
+          '\U0001d743' => { f('\u03be'); }
+          '\U0001d744' => { f('\u03bf'); }
+          '\U0001d745' => { f('\u03c0'); }
+          '\U0001d746' => { f('\u03c1'); }
+          '\U0001d747' => { f('\u03c2'); }
+          '\U0001d748' => { f('\u03c3'); }
+          '\U0001d749' => { f('\u03c4'); }
+          '\U0001d74a' => { f('\u03c5'); }
+          '\U0001d74b' => { f('\u03c6'); }
+          '\U0001d74c' => { f('\u03c7'); }
+          '\U0001d74d' => { f('\u03c8'); }
+          '\U0001d74e' => { f('\u03c9'); }
+          '\U0001d74f' => { f('\u2202'); }
+          '\U0001d750' => { f('\u03b5'); }
+          '\U0001d751' => { f('\u03b8'); }
+          '\U0001d752' => { f('\u03ba'); }
+          '\U0001d753' => { f('\u03c6'); }
+          '\U0001d754' => { f('\u03c1'); }
+          '\U0001d755' => { f('\u03c0'); }
+          '\U0001d756' => { f('\u0391'); }
+          '\U0001d757' => { f('\u0392'); }
+          '\U0001d758' => { f('\u0393'); }
+          '\U0001d759' => { f('\u0394'); }
+          '\U0001d75a' => { f('\u0395'); }
+          '\U0001d75b' => { f('\u0396'); }
+          '\U0001d75c' => { f('\u0397'); }
+          '\U0001d75d' => { f('\u0398'); }
+          '\U0001d75e' => { f('\u0399'); }
+          '\U0001d75f' => { f('\u039a'); }
+          '\U0001d760' => { f('\u039b'); }
+          '\U0001d761' => { f('\u039c'); }
+          '\U0001d762' => { f('\u039d'); }
+          '\U0001d763' => { f('\u039e'); }
+          '\U0001d764' => { f('\u039f'); }
+          '\U0001d765' => { f('\u03a0'); }
+          '\U0001d766' => { f('\u03a1'); }
+          '\U0001d767' => { f('\u0398'); }
+          '\U0001d768' => { f('\u03a3'); }
+          '\U0001d769' => { f('\u03a4'); }
+          '\U0001d76a' => { f('\u03a5'); }
+          '\U0001d76b' => { f('\u03a6'); }
+          '\U0001d76c' => { f('\u03a7'); }
+          '\U0001d76d' => { f('\u03a8'); }
+          '\U0001d76e' => { f('\u03a9'); }
+          '\U0001d76f' => { f('\u2207'); }
+          '\U0001d770' => { f('\u03b1'); }
+          '\U0001d771' => { f('\u03b2'); }
+          '\U0001d772' => { f('\u03b3'); }
+          '\U0001d773' => { f('\u03b4'); }
+          '\U0001d774' => { f('\u03b5'); }
+          '\U0001d775' => { f('\u03b6'); }
+          '\U0001d776' => { f('\u03b7'); }
+          '\U0001d777' => { f('\u03b8'); }
+          '\U0001d778' => { f('\u03b9'); }
+          '\U0001d779' => { f('\u03ba'); }
+          '\U0001d77a' => { f('\u03bb'); }
+          '\U0001d77b' => { f('\u03bc'); }
+          '\U0001d77c' => { f('\u03bd'); }
+          '\U0001d77d' => { f('\u03be'); }
+          '\U0001d77e' => { f('\u03bf'); }
+          '\U0001d77f' => { f('\u03c0'); }
+          '\U0001d780' => { f('\u03c1'); }
+          '\U0001d781' => { f('\u03c2'); }
+          '\U0001d782' => { f('\u03c3'); }
+          '\U0001d783' => { f('\u03c4'); }
+          '\U0001d784' => { f('\u03c5'); }
+          '\U0001d785' => { f('\u03c6'); }
+          '\U0001d786' => { f('\u03c7'); }
+          '\U0001d787' => { f('\u03c8'); }
+          '\U0001d788' => { f('\u03c9'); }
+          '\U0001d789' => { f('\u2202'); }
+          '\U0001d78a' => { f('\u03b5'); }
+          '\U0001d78b' => { f('\u03b8'); }
+          '\U0001d78c' => { f('\u03ba'); }
+          '\U0001d78d' => { f('\u03c6'); }
+          '\U0001d78e' => { f('\u03c1'); }
+          '\U0001d78f' => { f('\u03c0'); }
+          '\U0001d790' => { f('\u0391'); }
+          '\U0001d791' => { f('\u0392'); }
+          '\U0001d792' => { f('\u0393'); }
+          '\U0001d793' => { f('\u0394'); }
+          '\U0001d794' => { f('\u0395'); }
+          '\U0001d795' => { f('\u0396'); }
+          '\U0001d796' => { f('\u0397'); }
+          '\U0001d797' => { f('\u0398'); }
+          '\U0001d798' => { f('\u0399'); }
+          '\U0001d799' => { f('\u039a'); }
+          '\U0001d79a' => { f('\u039b'); }
+          '\U0001d79b' => { f('\u039c'); }
+          '\U0001d79c' => { f('\u039d'); }
+          '\U0001d79d' => { f('\u039e'); }
+          '\U0001d79e' => { f('\u039f'); }
+          '\U0001d79f' => { f('\u03a0'); }
+          '\U0001d7a0' => { f('\u03a1'); }
+          '\U0001d7a1' => { f('\u0398'); }
+          '\U0001d7a2' => { f('\u03a3'); }
+          '\U0001d7a3' => { f('\u03a4'); }
+          '\U0001d7a4' => { f('\u03a5'); }
+          '\U0001d7a5' => { f('\u03a6'); }
+          '\U0001d7a6' => { f('\u03a7'); }
+          '\U0001d7a7' => { f('\u03a8'); }
+          '\U0001d7a8' => { f('\u03a9'); }
+          '\U0001d7a9' => { f('\u2207'); }
+          '\U0001d7aa' => { f('\u03b1'); }
+          '\U0001d7ab' => { f('\u03b2'); }
+          '\U0001d7ac' => { f('\u03b3'); }
+          '\U0001d7ad' => { f('\u03b4'); }
+          '\U0001d7ae' => { f('\u03b5'); }
+          '\U0001d7af' => { f('\u03b6'); }
+          '\U0001d7b0' => { f('\u03b7'); }
+          '\U0001d7b1' => { f('\u03b8'); }
+          '\U0001d7b2' => { f('\u03b9'); }
+          '\U0001d7b3' => { f('\u03ba'); }
+          '\U0001d7b4' => { f('\u03bb'); }
+          '\U0001d7b5' => { f('\u03bc'); }
+          '\U0001d7b6' => { f('\u03bd'); }
+          '\U0001d7b7' => { f('\u03be'); }
+          '\U0001d7b8' => { f('\u03bf'); }
+          '\U0001d7b9' => { f('\u03c0'); }
+          '\U0001d7ba' => { f('\u03c1'); }
+          '\U0001d7bb' => { f('\u03c2'); }
+          '\U0001d7bc' => { f('\u03c3'); }
+          '\U0001d7bd' => { f('\u03c4'); }
+          '\U0001d7be' => { f('\u03c5'); }
+          '\U0001d7bf' => { f('\u03c6'); }
+          '\U0001d7c0' => { f('\u03c7'); }
+          '\U0001d7c1' => { f('\u03c8'); }
+          '\U0001d7c2' => { f('\u03c9'); }
+          '\U0001d7c3' => { f('\u2202'); }
+          '\U0001d7c4' => { f('\u03b5'); }
+          '\U0001d7c5' => { f('\u03b8'); }
+          '\U0001d7c6' => { f('\u03ba'); }
+          '\U0001d7c7' => { f('\u03c6'); }
+          '\U0001d7c8' => { f('\u03c1'); }
+          '\U0001d7c9' => { f('\u03c0'); }
+          '\U0001d7ca' => { f('\u03dc'); }
+          '\U0001d7cb' => { f('\u03dd'); }
+          '\U0001d7ce' => { f('\x30'); }
+          '\U0001d7cf' => { f('\x31'); }
+          '\U0001d7d0' => { f('\x32'); }
+          '\U0001d7d1' => { f('\x33'); }
+          '\U0001d7d2' => { f('\x34'); }
+          '\U0001d7d3' => { f('\x35'); }
+          '\U0001d7d4' => { f('\x36'); }
+          '\U0001d7d5' => { f('\x37'); }
+          '\U0001d7d6' => { f('\x38'); }
+          '\U0001d7d7' => { f('\x39'); }
+          '\U0001d7d8' => { f('\x30'); }
+          '\U0001d7d9' => { f('\x31'); }
+          '\U0001d7da' => { f('\x32'); }
+          '\U0001d7db' => { f('\x33'); }
+          '\U0001d7dc' => { f('\x34'); }
+          '\U0001d7dd' => { f('\x35'); }
+          '\U0001d7de' => { f('\x36'); }
+          '\U0001d7df' => { f('\x37'); }
+          '\U0001d7e0' => { f('\x38'); }
+          '\U0001d7e1' => { f('\x39'); }
+          '\U0001d7e2' => { f('\x30'); }
+          '\U0001d7e3' => { f('\x31'); }
+          '\U0001d7e4' => { f('\x32'); }
+          '\U0001d7e5' => { f('\x33'); }
+          '\U0001d7e6' => { f('\x34'); }
+          '\U0001d7e7' => { f('\x35'); }
+          '\U0001d7e8' => { f('\x36'); }
+          '\U0001d7e9' => { f('\x37'); }
+          '\U0001d7ea' => { f('\x38'); }
+          '\U0001d7eb' => { f('\x39'); }
+          '\U0001d7ec' => { f('\x30'); }
+          '\U0001d7ed' => { f('\x31'); }
+          '\U0001d7ee' => { f('\x32'); }
+          '\U0001d7ef' => { f('\x33'); }
+          '\U0001d7f0' => { f('\x34'); }
+          '\U0001d7f1' => { f('\x35'); }
+          '\U0001d7f2' => { f('\x36'); }
+          '\U0001d7f3' => { f('\x37'); }
+          '\U0001d7f4' => { f('\x38'); }
+          '\U0001d7f5' => { f('\x39'); }
+          '\U0001d7f6' => { f('\x30'); }
+          '\U0001d7f7' => { f('\x31'); }
+          '\U0001d7f8' => { f('\x32'); }
+          '\U0001d7f9' => { f('\x33'); }
+          '\U0001d7fa' => { f('\x34'); }
+          '\U0001d7fb' => { f('\x35'); }
+          '\U0001d7fc' => { f('\x36'); }
+          '\U0001d7fd' => { f('\x37'); }
+          '\U0001d7fe' => { f('\x38'); }
+          '\U0001d7ff' => { f('\x39'); }
+          '\U0001ee00' => { f('\u0627'); }
+          '\U0001ee01' => { f('\u0628'); }
+          '\U0001ee02' => { f('\u062c'); }
+          '\U0001ee03' => { f('\u062f'); }
+          '\U0001ee05' => { f('\u0648'); }
+          '\U0001ee06' => { f('\u0632'); }
+          '\U0001ee07' => { f('\u062d'); }
+          '\U0001ee08' => { f('\u0637'); }
+          '\U0001ee09' => { f('\u064a'); }
+          '\U0001ee0a' => { f('\u0643'); }
+          '\U0001ee0b' => { f('\u0644'); }
+          '\U0001ee0c' => { f('\u0645'); }
+          '\U0001ee0d' => { f('\u0646'); }
+          '\U0001ee0e' => { f('\u0633'); }
+          '\U0001ee0f' => { f('\u0639'); }
+          '\U0001ee10' => { f('\u0641'); }
+          '\U0001ee11' => { f('\u0635'); }
+          '\U0001ee12' => { f('\u0642'); }
+          '\U0001ee13' => { f('\u0631'); }
+          '\U0001ee14' => { f('\u0634'); }
+          '\U0001ee15' => { f('\u062a'); }
+          '\U0001ee16' => { f('\u062b'); }
+          '\U0001ee17' => { f('\u062e'); }
+          '\U0001ee18' => { f('\u0630'); }
+          '\U0001ee19' => { f('\u0636'); }
+          '\U0001ee1a' => { f('\u0638'); }
+          '\U0001ee1b' => { f('\u063a'); }
+          '\U0001ee1c' => { f('\u066e'); }
+          '\U0001ee1d' => { f('\u06ba'); }
+          '\U0001ee1e' => { f('\u06a1'); }
+          '\U0001ee1f' => { f('\u066f'); }
+          '\U0001ee21' => { f('\u0628'); }
+          '\U0001ee22' => { f('\u062c'); }
+          '\U0001ee24' => { f('\u0647'); }
+          '\U0001ee27' => { f('\u062d'); }
+          '\U0001ee29' => { f('\u064a'); }
+          '\U0001ee2a' => { f('\u0643'); }
+          '\U0001ee2b' => { f('\u0644'); }
+          '\U0001ee2c' => { f('\u0645'); }
+          '\U0001ee2d' => { f('\u0646'); }
+          '\U0001ee2e' => { f('\u0633'); }
+          '\U0001ee2f' => { f('\u0639'); }
+          '\U0001ee30' => { f('\u0641'); }
+          '\U0001ee31' => { f('\u0635'); }
+          '\U0001ee32' => { f('\u0642'); }
+          '\U0001ee34' => { f('\u0634'); }
+          '\U0001ee35' => { f('\u062a'); }
+          '\U0001ee36' => { f('\u062b'); }
+          '\U0001ee37' => { f('\u062e'); }
+          '\U0001ee39' => { f('\u0636'); }
+          '\U0001ee3b' => { f('\u063a'); }
+          '\U0001ee42' => { f('\u062c'); }
+          '\U0001ee47' => { f('\u062d'); }
+          '\U0001ee49' => { f('\u064a'); }
+          '\U0001ee4b' => { f('\u0644'); }
+          '\U0001ee4d' => { f('\u0646'); }
+          '\U0001ee4e' => { f('\u0633'); }
+          '\U0001ee4f' => { f('\u0639'); }
+          '\U0001ee51' => { f('\u0635'); }
+          '\U0001ee52' => { f('\u0642'); }
+          '\U0001ee54' => { f('\u0634'); }
+          '\U0001ee57' => { f('\u062e'); }
+          '\U0001ee59' => { f('\u0636'); }
+          '\U0001ee5b' => { f('\u063a'); }
+          '\U0001ee5d' => { f('\u06ba'); }
+          '\U0001ee5f' => { f('\u066f'); }
+          '\U0001ee61' => { f('\u0628'); }
+          '\U0001ee62' => { f('\u062c'); }
+          '\U0001ee64' => { f('\u0647'); }
+          '\U0001ee67' => { f('\u062d'); }
+          '\U0001ee68' => { f('\u0637'); }
+          '\U0001ee69' => { f('\u064a'); }
+          '\U0001ee6a' => { f('\u0643'); }
+          '\U0001ee6c' => { f('\u0645'); }
+          '\U0001ee6d' => { f('\u0646'); }
+          '\U0001ee6e' => { f('\u0633'); }
+          '\U0001ee6f' => { f('\u0639'); }
+          '\U0001ee70' => { f('\u0641'); }
+          '\U0001ee71' => { f('\u0635'); }
+          '\U0001ee72' => { f('\u0642'); }
+          '\U0001ee74' => { f('\u0634'); }
+          '\U0001ee75' => { f('\u062a'); }
+          '\U0001ee76' => { f('\u062b'); }
+          '\U0001ee77' => { f('\u062e'); }
+          '\U0001ee79' => { f('\u0636'); }
+          '\U0001ee7a' => { f('\u0638'); }
+          '\U0001ee7b' => { f('\u063a'); }
+          '\U0001ee7c' => { f('\u066e'); }
+          '\U0001ee7e' => { f('\u06a1'); }
+          '\U0001ee80' => { f('\u0627'); }
+          '\U0001ee81' => { f('\u0628'); }
+          '\U0001ee82' => { f('\u062c'); }
+          '\U0001ee83' => { f('\u062f'); }
+          '\U0001ee84' => { f('\u0647'); }
+          '\U0001ee85' => { f('\u0648'); }
+          '\U0001ee86' => { f('\u0632'); }
+          '\U0001ee87' => { f('\u062d'); }
+          '\U0001ee88' => { f('\u0637'); }
+          '\U0001ee89' => { f('\u064a'); }
+          '\U0001ee8b' => { f('\u0644'); }
+          '\U0001ee8c' => { f('\u0645'); }
+          '\U0001ee8d' => { f('\u0646'); }
+          '\U0001ee8e' => { f('\u0633'); }
+          '\U0001ee8f' => { f('\u0639'); }
+          '\U0001ee90' => { f('\u0641'); }
+          '\U0001ee91' => { f('\u0635'); }
+          '\U0001ee92' => { f('\u0642'); }
+          '\U0001ee93' => { f('\u0631'); }
+          '\U0001ee94' => { f('\u0634'); }
+          '\U0001ee95' => { f('\u062a'); }
+          '\U0001ee96' => { f('\u062b'); }
+          '\U0001ee97' => { f('\u062e'); }
+          '\U0001ee98' => { f('\u0630'); }
+          '\U0001ee99' => { f('\u0636'); }
+          '\U0001ee9a' => { f('\u0638'); }
+          '\U0001ee9b' => { f('\u063a'); }
+          '\U0001eea1' => { f('\u0628'); }
+          '\U0001eea2' => { f('\u062c'); }
+          '\U0001eea3' => { f('\u062f'); }
+          '\U0001eea5' => { f('\u0648'); }
+          '\U0001eea6' => { f('\u0632'); }
+          '\U0001eea7' => { f('\u062d'); }
+          '\U0001eea8' => { f('\u0637'); }
+          '\U0001eea9' => { f('\u064a'); }
+          '\U0001eeab' => { f('\u0644'); }
+          '\U0001eeac' => { f('\u0645'); }
+          '\U0001eead' => { f('\u0646'); }
+          '\U0001eeae' => { f('\u0633'); }
+          '\U0001eeaf' => { f('\u0639'); }
+          '\U0001eeb0' => { f('\u0641'); }
+          '\U0001eeb1' => { f('\u0635'); }
+          '\U0001eeb2' => { f('\u0642'); }
+          '\U0001eeb3' => { f('\u0631'); }
+          '\U0001eeb4' => { f('\u0634'); }
+          '\U0001eeb5' => { f('\u062a'); }
+          '\U0001eeb6' => { f('\u062b'); }
+          '\U0001eeb7' => { f('\u062e'); }
+          '\U0001eeb8' => { f('\u0630'); }
+          '\U0001eeb9' => { f('\u0636'); }
+          '\U0001eeba' => { f('\u0638'); }
+          '\U0001eebb' => { f('\u063a'); }
+          '\U0001f100' => { f('\x30'); f('\x2e'); }
+          '\U0001f101' => { f('\x30'); f('\x2c'); }
+          '\U0001f102' => { f('\x31'); f('\x2c'); }
+          '\U0001f103' => { f('\x32'); f('\x2c'); }
+          '\U0001f104' => { f('\x33'); f('\x2c'); }
+          '\U0001f105' => { f('\x34'); f('\x2c'); }
+          '\U0001f106' => { f('\x35'); f('\x2c'); }
+          '\U0001f107' => { f('\x36'); f('\x2c'); }
+          '\U0001f108' => { f('\x37'); f('\x2c'); }
+          '\U0001f109' => { f('\x38'); f('\x2c'); }
+          '\U0001f10a' => { f('\x39'); f('\x2c'); }
+          '\U0001f110' => { f('\x28'); f('\x41'); f('\x29'); }
+          '\U0001f111' => { f('\x28'); f('\x42'); f('\x29'); }
+          '\U0001f112' => { f('\x28'); f('\x43'); f('\x29'); }
+          '\U0001f113' => { f('\x28'); f('\x44'); f('\x29'); }
+          '\U0001f114' => { f('\x28'); f('\x45'); f('\x29'); }
+          '\U0001f115' => { f('\x28'); f('\x46'); f('\x29'); }
+          '\U0001f116' => { f('\x28'); f('\x47'); f('\x29'); }
+          '\U0001f117' => { f('\x28'); f('\x48'); f('\x29'); }
+          '\U0001f118' => { f('\x28'); f('\x49'); f('\x29'); }
+          '\U0001f119' => { f('\x28'); f('\x4a'); f('\x29'); }
+          '\U0001f11a' => { f('\x28'); f('\x4b'); f('\x29'); }
+          '\U0001f11b' => { f('\x28'); f('\x4c'); f('\x29'); }
+          '\U0001f11c' => { f('\x28'); f('\x4d'); f('\x29'); }
+          '\U0001f11d' => { f('\x28'); f('\x4e'); f('\x29'); }
+          '\U0001f11e' => { f('\x28'); f('\x4f'); f('\x29'); }
+          '\U0001f11f' => { f('\x28'); f('\x50'); f('\x29'); }
+          '\U0001f120' => { f('\x28'); f('\x51'); f('\x29'); }
+          '\U0001f121' => { f('\x28'); f('\x52'); f('\x29'); }
+          '\U0001f122' => { f('\x28'); f('\x53'); f('\x29'); }
+          '\U0001f123' => { f('\x28'); f('\x54'); f('\x29'); }
+          '\U0001f124' => { f('\x28'); f('\x55'); f('\x29'); }
+          '\U0001f125' => { f('\x28'); f('\x56'); f('\x29'); }
+          '\U0001f126' => { f('\x28'); f('\x57'); f('\x29'); }
+          '\U0001f127' => { f('\x28'); f('\x58'); f('\x29'); }
+          '\U0001f128' => { f('\x28'); f('\x59'); f('\x29'); }
+          '\U0001f129' => { f('\x28'); f('\x5a'); f('\x29'); }
+          '\U0001f12a' => { f('\u3014'); f('\x53'); f('\u3015'); }
+          '\U0001f12b' => { f('\x43'); }
+          '\U0001f12c' => { f('\x52'); }
+          '\U0001f12d' => { f('\x43'); f('\x44'); }
+          '\U0001f12e' => { f('\x57'); f('\x5a'); }
+          '\U0001f130' => { f('\x41'); }
+          '\U0001f131' => { f('\x42'); }
+          '\U0001f132' => { f('\x43'); }
+          '\U0001f133' => { f('\x44'); }
+          '\U0001f134' => { f('\x45'); }
+          '\U0001f135' => { f('\x46'); }
+          '\U0001f136' => { f('\x47'); }
+          '\U0001f137' => { f('\x48'); }
+          '\U0001f138' => { f('\x49'); }
+          '\U0001f139' => { f('\x4a'); }
+          '\U0001f13a' => { f('\x4b'); }
+          '\U0001f13b' => { f('\x4c'); }
+          '\U0001f13c' => { f('\x4d'); }
+          '\U0001f13d' => { f('\x4e'); }
+          '\U0001f13e' => { f('\x4f'); }
+          '\U0001f13f' => { f('\x50'); }
+          '\U0001f140' => { f('\x51'); }
+          '\U0001f141' => { f('\x52'); }
+          '\U0001f142' => { f('\x53'); }
+          '\U0001f143' => { f('\x54'); }
+          '\U0001f144' => { f('\x55'); }
+          '\U0001f145' => { f('\x56'); }
+          '\U0001f146' => { f('\x57'); }
+          '\U0001f147' => { f('\x58'); }
+          '\U0001f148' => { f('\x59'); }
+          '\U0001f149' => { f('\x5a'); }
+          '\U0001f14a' => { f('\x48'); f('\x56'); }
+          '\U0001f14b' => { f('\x4d'); f('\x56'); }
+          '\U0001f14c' => { f('\x53'); f('\x44'); }
+          '\U0001f14d' => { f('\x53'); f('\x53'); }
+          '\U0001f14e' => { f('\x50'); f('\x50'); f('\x56'); }
+          '\U0001f14f' => { f('\x57'); f('\x43'); }
+          '\U0001f16a' => { f('\x4d'); f('\x43'); }
+          '\U0001f16b' => { f('\x4d'); f('\x44'); }
+          '\U0001f16c' => { f('\x4d'); f('\x52'); }
+          '\U0001f190' => { f('\x44'); f('\x4a'); }
+          '\U0001f200' => { f('\u307b'); f('\u304b'); }
+          '\U0001f201' => { f('\u30b3'); f('\u30b3'); }
+          '\U0001f202' => { f('\u30b5'); }
+          '\U0001f210' => { f('\u624b'); }
+          '\U0001f211' => { f('\u5b57'); }
+          '\U0001f212' => { f('\u53cc'); }
+          '\U0001f213' => { f('\u30c6'); f('\u3099'); }
+          '\U0001f214' => { f('\u4e8c'); }
+          '\U0001f215' => { f('\u591a'); }
+          '\U0001f216' => { f('\u89e3'); }
+          '\U0001f217' => { f('\u5929'); }
+          '\U0001f218' => { f('\u4ea4'); }
+          '\U0001f219' => { f('\u6620'); }
+          '\U0001f21a' => { f('\u7121'); }
+          '\U0001f21b' => { f('\u6599'); }
+          '\U0001f21c' => { f('\u524d'); }
+          '\U0001f21d' => { f('\u5f8c'); }
+          '\U0001f21e' => { f('\u518d'); }
+          '\U0001f21f' => { f('\u65b0'); }
+          '\U0001f220' => { f('\u521d'); }
+          '\U0001f221' => { f('\u7d42'); }
+          '\U0001f222' => { f('\u751f'); }
+          '\U0001f223' => { f('\u8ca9'); }
+          '\U0001f224' => { f('\u58f0'); }
+          '\U0001f225' => { f('\u5439'); }
+          '\U0001f226' => { f('\u6f14'); }
+          '\U0001f227' => { f('\u6295'); }
+          '\U0001f228' => { f('\u6355'); }
+          '\U0001f229' => { f('\u4e00'); }
+          '\U0001f22a' => { f('\u4e09'); }
+          '\U0001f22b' => { f('\u904a'); }
+          '\U0001f22c' => { f('\u5de6'); }
+          '\U0001f22d' => { f('\u4e2d'); }
+          '\U0001f22e' => { f('\u53f3'); }
+          '\U0001f22f' => { f('\u6307'); }
+          '\U0001f230' => { f('\u8d70'); }
+          '\U0001f231' => { f('\u6253'); }
+          '\U0001f232' => { f('\u7981'); }
+          '\U0001f233' => { f('\u7a7a'); }
+          '\U0001f234' => { f('\u5408'); }
+          '\U0001f235' => { f('\u6e80'); }
+          '\U0001f236' => { f('\u6709'); }
+          '\U0001f237' => { f('\u6708'); }
+          '\U0001f238' => { f('\u7533'); }
+          '\U0001f239' => { f('\u5272'); }
+          '\U0001f23a' => { f('\u55b6'); }
+          '\U0001f23b' => { f('\u914d'); }
+          '\U0001f240' => { f('\u3014'); f('\u672c'); f('\u3015'); }
+          '\U0001f241' => { f('\u3014'); f('\u4e09'); f('\u3015'); }
+          '\U0001f242' => { f('\u3014'); f('\u4e8c'); f('\u3015'); }
+          '\U0001f243' => { f('\u3014'); f('\u5b89'); f('\u3015'); }
+          '\U0001f244' => { f('\u3014'); f('\u70b9'); f('\u3015'); }
+          '\U0001f245' => { f('\u3014'); f('\u6253'); f('\u3015'); }
+          '\U0001f246' => { f('\u3014'); f('\u76d7'); f('\u3015'); }
+          '\U0001f247' => { f('\u3014'); f('\u52dd'); f('\u3015'); }
+          '\U0001f248' => { f('\u3014'); f('\u6557'); f('\u3015'); }
+          '\U0001f250' => { f('\u5f97'); }
+          '\U0001f251' => { f('\u53ef'); }
+          '\U0001fbf0' => { f('\x30'); }
+          '\U0001fbf1' => { f('\x31'); }
+          '\U0001fbf2' => { f('\x32'); }
+          '\U0001fbf3' => { f('\x33'); }
+          '\U0001fbf4' => { f('\x34'); }
+          '\U0001fbf5' => { f('\x35'); }
+          '\U0001fbf6' => { f('\x36'); }
+          '\U0001fbf7' => { f('\x37'); }
+          '\U0001fbf8' => { f('\x38'); }
+          '\U0001fbf9' => { f('\x39'); }
+          _ => return false
+        }
+        true
+    }
+
+}
+
+pub mod grapheme {
+    /// Grapheme_Cluster_Break property values, as used by
+    /// the extended grapheme cluster rules of UAX #29
+    #[deriving_eq]
+    pub enum GraphemeCat {
+        CR,
+        LF,
+        Control,
+        Extend,
+        ZWJ,
+        RegionalIndicator,
+        Prepend,
+        SpacingMark,
+        HangulL,
+        HangulV,
+        HangulT,
+        HangulLV,
+        HangulLVT,
+        ExtendedPictographic,
+        Any,
+    }
+
+    /// The grapheme cluster break category of a char
+    pub pure fn grapheme_category(c: char) -> GraphemeCat {
+        match c {
+          '\x00' .. '\x09' => Control,
+          '\x0a' => LF,
+          '\x0b' .. '\x0c' => Control,
+          '\x0d' => CR,
+          '\x0e' .. '\x1f' => Control,
+          '\x7f' .. '\x9f' => Control,
+          '\xa9' => ExtendedPictographic,
+          '\xad' => Control,
+          '\xae' => ExtendedPictographic,
+          '\u0300' .. '\u036f' => Extend,
+          '\u0483' .. '\u0489' => Extend,
+          '\u0591' .. '\u05bd' => Extend,
+          '\u05bf' => Extend,
+          '\u05c1' .. '\u05c2' => Extend,
+          '\u05c4' .. '\u05c5' => Extend,
+          '\u05c7' => Extend,
+          '\u0600' .. '\u0605' => Prepend,
+          '\u0610' .. '\u061a' => Extend,
+          '\u061c' => Control,
+          '\u064b' .. '\u065f' => Extend,
+          '\u0670' => Extend,
+          '\u06d6' .. '\u06dc' => Extend,
+          '\u06dd' => Prepend,
+          '\u06df' .. '\u06e4' => Extend,
+          '\u06e7' .. '\u06e8' => Extend,
+          '\u06ea' .. '\u06ed' => Extend,
+          '\u070f' => Prepend,
+          '\u0711' => Extend,
+          '\u0730' .. '\u074a' => Extend,
+          '\u07a6' .. '\u07b0' => Extend,
+          '\u07eb' .. '\u07f3' => Extend,
+          '\u07fd' => Extend,
+          '\u0816' .. '\u0819' => Extend,
+          '\u081b' .. '\u0823' => Extend,
+          '\u0825' .. '\u0827' => Extend,
+          '\u0829' .. '\u082d' => Extend,
+          '\u0859' .. '\u085b' => Extend,
+          '\u0890' .. '\u0891' => Prepend,
+          '\u0898' .. '\u089f' => Extend,
+          '\u08ca' .. '\u08e1' => Extend,
+          '\u08e2' => Prepend,
+          '\u08e3' .. '\u0902' => Extend,
+          '\u0903' => SpacingMark,
+          '\u093a' => Extend,
+          '\u093b' => SpacingMark,
+          '\u093c' => Extend,
+          '\u093e' .. '\u0940' => SpacingMark,
+          '\u0941' .. '\u0948' => Extend,
+          '\u0949' .. '\u094c' => SpacingMark,
+          '\u094d' => Extend,
+          '\u094e' .. '\u094f' => SpacingMark,
+          '\u0951' .. '\u0957' => Extend,
+          '\u0962' .. '\u0963' => Extend,
+          '\u0981' => Extend,
+          '\u0982' .. '\u0983' => SpacingMark,
+          '\u09bc' => Extend,
+          '\u09be' => Extend,
+          '\u09bf' .. '\u09c0' => SpacingMark,
+          '\u09c1' .. '\u09c4' => Extend,
+          '\u09c7' .. '\u09c8' => SpacingMark,
+          '\u09cb' .. '\u09cc' => SpacingMark,
+          '\u09cd' => Extend,
+          '\u09d7' => Extend,
+          '\u09e2' .. '\u09e3' => Extend,
+          '\u09fe' => Extend,
+          '\u0a01' .. '\u0a02' => Extend,
+          '\u0a03' => SpacingMark,
+          '\u0a3c' => Extend,
+          '\u0a3e' .. '\u0a40' => SpacingMark,
+          '\u0a41' .. '\u0a42' => Extend,
+          '\u0a47' .. '\u0a48' => Extend,
+          '\u0a4b' .. '\u0a4d' => Extend,
+          '\u0a51' => Extend,
+          '\u0a70' .. '\u0a71' => Extend,
+          '\u0a75' => Extend,
+          '\u0a81' .. '\u0a82' => Extend,
+          '\u0a83' => SpacingMark,
+          '\u0abc' => Extend,
+          '\u0abe' .. '\u0ac0' => SpacingMark,
+          '\u0ac1' .. '\u0ac5' => Extend,
+          '\u0ac7' .. '\u0ac8' => Extend,
+          '\u0ac9' => SpacingMark,
+          '\u0acb' .. '\u0acc' => SpacingMark,
+          '\u0acd' => Extend,
+          '\u0ae2' .. '\u0ae3' => Extend,
+          '\u0afa' .. '\u0aff' => Extend,
+          '\u0b01' => Extend,
+          '\u0b02' .. '\u0b03' => SpacingMark,
+          '\u0b3c' => Extend,
+          '\u0b3e' .. '\u0b3f' => Extend,
+          '\u0b40' => SpacingMark,
+          '\u0b41' .. '\u0b44' => Extend,
+          '\u0b47' .. '\u0b48' => SpacingMark,
+          '\u0b4b' .. '\u0b4c' => SpacingMark,
+          '\u0b4d' => Extend,
+          '\u0b55' .. '\u0b57' => Extend,
+          '\u0b62' .. '\u0b63' => Extend,
+          '\u0b82' => Extend,
+          '\u0bbe' => Extend,
+          '\u0bbf' => SpacingMark,
+          '\u0bc0' => Extend,
+          '\u0bc1' .. '\u0bc2' => SpacingMark,
+          '\u0bc6' .. '\u0bc8' => SpacingMark,
+          '\u0bca' .. '\u0bcc' => SpacingMark,
+          '\u0bcd' => Extend,
+          '\u0bd7' => Extend,
+          '\u0c00' => Extend,
+          '\u0c01' .. '\u0c03' => SpacingMark,
+          '\u0c04' => Extend,
+          '\u0c3c' => Extend,
+          '\u0c3e' .. '\u0c40' => Extend,
+          '\u0c41' .. '\u0c44' => SpacingMark,
+          '\u0c46' .. '\u0c48' => Extend,
+          '\u0c4a' .. '\u0c4d' => Extend,
+          '\u0c55' .. '\u0c56' => Extend,
+          '\u0c62' .. '\u0c63' => Extend,
+          '\u0c81' => Extend,
+          '\u0c82' .. '\u0c83' => SpacingMark,
+          '\u0cbc' => Extend,
+          '\u0cbe' => SpacingMark,
+          '\u0cbf' => Extend,
+          '\u0cc0' .. '\u0cc1' => SpacingMark,
+          '\u0cc2' => Extend,
+          '\u0cc3' .. '\u0cc4' => SpacingMark,
+          '\u0cc6' => Extend,
+          '\u0cc7' .. '\u0cc8' => SpacingMark,
+          '\u0cca' .. '\u0ccb' => SpacingMark,
+          '\u0ccc' .. '\u0ccd' => Extend,
+          '\u0cd5' .. '\u0cd6' => Extend,
+          '\u0ce2' .. '\u0ce3' => Extend,
+          '\u0d00' .. '\u0d01' => Extend,
+          '\u0d02' .. '\u0d03' => SpacingMark,
+          '\u0d3b' .. '\u0d3c' => Extend,
+          '\u0d3e' => Extend,
+          '\u0d3f' .. '\u0d40' => SpacingMark,
+          '\u0d41' .. '\u0d44' => Extend,
+          '\u0d46' .. '\u0d48' => SpacingMark,
+          '\u0d4a' .. '\u0d4c' => SpacingMark,
+          '\u0d4d' => Extend,
+          '\u0d4e' => Prepend,
+          '\u0d57' => Extend,
+          '\u0d62' .. '\u0d63' => Extend,
+          '\u0d81' => Extend,
+          '\u0d82' .. '\u0d83' => SpacingMark,
+          '\u0dca' => Extend,
+          '\u0dcf' => Extend,
+          '\u0dd0' .. '\u0dd1' => SpacingMark,
+          '\u0dd2' .. '\u0dd4' => Extend,
+          '\u0dd6' => Extend,
+          '\u0dd8' .. '\u0dde' => SpacingMark,
+          '\u0ddf' => Extend,
+          '\u0df2' .. '\u0df3' => SpacingMark,
+          '\u0e31' => Extend,
+          '\u0e33' => SpacingMark,
+          '\u0e34' .. '\u0e3a' => Extend,
+          '\u0e47' .. '\u0e4e' => Extend,
+          '\u0eb1' => Extend,
+          '\u0eb3' => SpacingMark,
+          '\u0eb4' .. '\u0ebc' => Extend,
+          '\u0ec8' .. '\u0ecd' => Extend,
+          '\u0f18' .. '\u0f19' => Extend,
+          '\u0f35' => Extend,
+          '\u0f37' => Extend,
+          '\u0f39' => Extend,
+          '\u0f3e' .. '\u0f3f' => SpacingMark,
+          '\u0f71' .. '\u0f7e' => Extend,
+          '\u0f7f' => SpacingMark,
+          '\u0f80' .. '\u0f84' => Extend,
+          '\u0f86' .. '\u0f87' => Extend,
+          '\u0f8d' .. '\u0f97' => Extend,
+          '\u0f99' .. '\u0fbc' => Extend,
+          '\u0fc6' => Extend,
+          '\u102d' .. '\u1030' => Extend,
+          '\u1031' => SpacingMark,
+          '\u1032' .. '\u1037' => Extend,
+          '\u1039' .. '\u103a' => Extend,
+          '\u103b' .. '\u103c' => SpacingMark,
+          '\u103d' .. '\u103e' => Extend,
+          '\u1056' .. '\u1057' => SpacingMark,
+          '\u1058' .. '\u1059' => Extend,
+          '\u105e' .. '\u1060' => Extend,
+          '\u1071' .. '\u1074' => Extend,
+          '\u1082' => Extend,
+          '\u1084' => SpacingMark,
+          '\u1085' .. '\u1086' => Extend,
+          '\u108d' => Extend,
+          '\u109d' => Extend,
+          '\u1100' .. '\u115f' => HangulL,
+          '\u1160' .. '\u11a7' => HangulV,
+          '\u11a8' .. '\u11ff' => HangulT,
+          '\u135d' .. '\u135f' => Extend,
+          '\u1712' .. '\u1714' => Extend,
+          '\u1715' => SpacingMark,
+          '\u1732' .. '\u1733' => Extend,
+          '\u1734' => SpacingMark,
+          '\u1752' .. '\u1753' => Extend,
+          '\u1772' .. '\u1773' => Extend,
+          '\u17b4' .. '\u17b5' => Extend,
+          '\u17b6' => SpacingMark,
+          '\u17b7' .. '\u17bd' => Extend,
+          '\u17be' .. '\u17c5' => SpacingMark,
+          '\u17c6' => Extend,
+          '\u17c7' .. '\u17c8' => SpacingMark,
+          '\u17c9' .. '\u17d3' => Extend,
+          '\u17dd' => Extend,
+          '\u180b' .. '\u180d' => Extend,
+          '\u180e' => Control,
+          '\u180f' => Extend,
+          '\u1885' .. '\u1886' => Extend,
+          '\u18a9' => Extend,
+          '\u1920' .. '\u1922' => Extend,
+          '\u1923' .. '\u1926' => SpacingMark,
+          '\u1927' .. '\u1928' => Extend,
+          '\u1929' .. '\u192b' => SpacingMark,
+          '\u1930' .. '\u1931' => SpacingMark,
+          '\u1932' => Extend,
+          '\u1933' .. '\u1938' => SpacingMark,
+          '\u1939' .. '\u193b' => Extend,
+          '\u1a17' .. '\u1a18' => Extend,
+          '\u1a19' .. '\u1a1a' => SpacingMark,
+          '\u1a1b' => Extend,
+          '\u1a55' => SpacingMark,
+          '\u1a56' => Extend,
+          '\u1a57' => SpacingMark,
+          '\u1a58' .. '\u1a5e' => Extend,
+          '\u1a60' => Extend,
+          '\u1a62' => Extend,
+          '\u1a65' .. '\u1a6c' => Extend,
+          '\u1a6d' .. '\u1a72' => SpacingMark,
+          '\u1a73' .. '\u1a7c' => Extend,
+          '\u1a7f' => Extend,
+          '\u1ab0' .. '\u1ace' => Extend,
+          '\u1b00' .. '\u1b03' => Extend,
+          '\u1b04' => SpacingMark,
+          '\u1b34' .. '\u1b3a' => Extend,
+          '\u1b3b' => SpacingMark,
+          '\u1b3c' => Extend,
+          '\u1b3d' .. '\u1b41' => SpacingMark,
+          '\u1b42' => Extend,
+          '\u1b43' .. '\u1b44' => SpacingMark,
+          '\u1b6b' .. '\u1b73' => Extend,
+          '\u1b80' .. '\u1b81' => Extend,
+          '\u1b82' => SpacingMark,
+          '\u1ba1' => SpacingMark,
+          '\u1ba2' .. '\u1ba5' => Extend,
+          '\u1ba6' .. '\u1ba7' => SpacingMark,
+          '\u1ba8' .. '\u1ba9' => Extend,
+          '\u1baa' => SpacingMark,
+          '\u1bab' .. '\u1bad' => Extend,
+          '\u1be6' => Extend,
+          '\u1be7' => SpacingMark,
+          '\u1be8' .. '\u1be9' => Extend,
+          '\u1bea' .. '\u1bec' => SpacingMark,
+          '\u1bed' => Extend,
+          '\u1bee' => SpacingMark,
+          '\u1bef' .. '\u1bf1' => Extend,
+          '\u1bf2' .. '\u1bf3' => SpacingMark,
+          '\u1c24' .. '\u1c2b' => SpacingMark,
+          '\u1c2c' .. '\u1c33' => Extend,
+          '\u1c34' .. '\u1c35' => SpacingMark,
+          '\u1c36' .. '\u1c37' => Extend,
+          '\u1cd0' .. '\u1cd2' => Extend,
+          '\u1cd4' .. '\u1ce0' => Extend,
+          '\u1ce1' => SpacingMark,
+          '\u1ce2' .. '\u1ce8' => Extend,
+          '\u1ced' => Extend,
+          '\u1cf4' => Extend,
+          '\u1cf7' => SpacingMark,
+          '\u1cf8' .. '\u1cf9' => Extend,
+          '\u1dc0' .. '\u1dff' => Extend,
+          '\u200b' => Control,
+          '\u200c' => Extend,
+          '\u200d' => ZWJ,
+          '\u200e' .. '\u200f' => Control,
+          '\u2028' .. '\u202e' => Control,
+          '\u203c' => ExtendedPictographic,
+          '\u2049' => ExtendedPictographic,
+          '\u2060' .. '\u206f' => Control,
+          '\u20d0' .. '\u20f0' => Extend,
+          '\u2122' => ExtendedPictographic,
+          '\u2139' => ExtendedPictographic,
+          '\u2194' .. '\u2199' => ExtendedPictographic,
+          '\u21a9' .. '\u21aa' => ExtendedPictographic,
+          '\u231a' .. '\u231b' => ExtendedPictographic,
+          '\u2328' => ExtendedPictographic,
+          '\u2388' => ExtendedPictographic,
+          '\u23cf' => ExtendedPictographic,
+          '\u23e9' .. '\u23f3' => ExtendedPictographic,
+          '\u23f8' .. '\u23fa' => ExtendedPictographic,
+          '\u24c2' => ExtendedPictographic,
+          '\u25aa' .. '\u25ab' => ExtendedPictographic,
+          '\u25b6' => ExtendedPictographic,
+          '\u25c0' => ExtendedPictographic,
+          '\u25fb' .. '\u25fe' => ExtendedPictographic,
+          '\u2600' .. '\u2605' => ExtendedPictographic,
+          '\u2607' .. '\u2612' => ExtendedPictographic,
+          '\u2614' .. '\u2685' => ExtendedPictographic,
+          '\u2690' .. '\u2705' => ExtendedPictographic,
+          '\u2708' .. '\u2712' => ExtendedPictographic,
+          '\u2714' => ExtendedPictographic,
+          '\u2716' => ExtendedPictographic,
+          '\u271d' => ExtendedPictographic,
+          '\u2721' => ExtendedPictographic,
+          '\u2728' => ExtendedPictographic,
+          '\u2733' .. '\u2734' => ExtendedPictographic,
+          '\u2744' => ExtendedPictographic,
+          '\u2747' => ExtendedPictographic,
+          '\u274c' => ExtendedPictographic,
+          '\u274e' => ExtendedPictographic,
+          '\u2753' .. '\u2755' => ExtendedPictographic,
+          '\u2757' => ExtendedPictographic,
+          '\u2763' .. '\u2767' => ExtendedPictographic,
+          '\u2795' .. '\u2797' => ExtendedPictographic,
+          '\u27a1' => ExtendedPictographic,
+          '\u27b0' => ExtendedPictographic,
+          '\u27bf' => ExtendedPictographic,
+          '\u2934' .. '\u2935' => ExtendedPictographic,
+          '\u2b05' .. '\u2b07' => ExtendedPictographic,
+          '\u2b1b' .. '\u2b1c' => ExtendedPictographic,
+          '\u2b50' => ExtendedPictographic,
+          '\u2b55' => ExtendedPictographic,
+          '\u2cef' .. '\u2cf1' => Extend,
+          '\u2d7f' => Extend,
+          '\u2de0' .. '\u2dff' => Extend,
+          '\u302a' .. '\u302f' => Extend,
+          '\u3030' => ExtendedPictographic,
+          '\u303d' => ExtendedPictographic,
+          '\u3099' .. '\u309a' => Extend,
+          '\u3297' => ExtendedPictographic,
+          '\u3299' => ExtendedPictographic,
+          '\ua66f' .. '\ua672' => Extend,
+          '\ua674' .. '\ua67d' => Extend,
+          '\ua69e' .. '\ua69f' => Extend,
+          '\ua6f0' .. '\ua6f1' => Extend,
+          '\ua802' => Extend,
+          '\ua806' => Extend,
+          '\ua80b' => Extend,
+          '\ua823' .. '\ua824' => SpacingMark,
+          '\ua825' .. '\ua826' => Extend,
+          '\ua827' => SpacingMark,
+          '\ua82c' => Extend,
+          '\ua880' .. '\ua881' => SpacingMark,
+          '\ua8b4' .. '\ua8c3' => SpacingMark,
+          '\ua8c4' .. '\ua8c5' => Extend,
+          '\ua8e0' .. '\ua8f1' => Extend,
+          '\ua8ff' => Extend,
+          '\ua926' .. '\ua92d' => Extend,
+          '\ua947' .. '\ua951' => Extend,
+          '\ua952' .. '\ua953' => SpacingMark,
+          '\ua960' .. '\ua97c' => HangulL,
+          '\ua980' .. '\ua982' => Extend,
+          '\ua983' => SpacingMark,
+          '\ua9b3' => Extend,
+          '\ua9b4' .. '\ua9b5' => SpacingMark,
+          '\ua9b6' .. '\ua9b9' => Extend,
+          '\ua9ba' .. '\ua9bb' => SpacingMark,
+          '\ua9bc' .. '\ua9bd' => Extend,
+          '\ua9be' .. '\ua9c0' => SpacingMark,
+          '\ua9e5' => Extend,
+          '\uaa29' .. '\uaa2e' => Extend,
+          '\uaa2f' .. '\uaa30' => SpacingMark,
+          '\uaa31' .. '\uaa32' => Extend,
+          '\uaa33' .. '\uaa34' => SpacingMark,
+          '\uaa35' .. '\uaa36' => Extend,
+          '\uaa43' => Extend,
+          '\uaa4c' => Extend,
+          '\uaa4d' => SpacingMark,
+          '\uaa7c' => Extend,
+          '\uaab0' => Extend,
+          '\uaab2' .. '\uaab4' => Extend,
+          '\uaab7' .. '\uaab8' => Extend,
+          '\uaabe' .. '\uaabf' => Extend,
+          '\uaac1' => Extend,
+          '\uaaeb' => SpacingMark,
+          '\uaaec' .. '\uaaed' => Extend,
+          '\uaaee' .. '\uaaef' => SpacingMark,
+          '\uaaf5' => SpacingMark,
+          '\uaaf6' => Extend,
+          '\uabe3' .. '\uabe4' => SpacingMark,
+          '\uabe5' => Extend,
+          '\uabe6' .. '\uabe7' => SpacingMark,
+          '\uabe8' => Extend,
+          '\uabe9' .. '\uabea' => SpacingMark,
+          '\uabec' => SpacingMark,
+          '\uabed' => Extend,
+          '\ud7b0' .. '\ud7c6' => HangulV,
+          '\ud7cb' .. '\ud7fb' => HangulT,
+          '\ufb1e' => Extend,
+          '\ufe00' .. '\ufe0f' => Extend,
+          '\ufe20' .. '\ufe2f' => Extend,
+          '\ufeff' => Control,
+          '\uff9e' .. '\uff9f' => Extend,
+          '\ufff0' .. '\ufffb' => Control,
+          '\U000101fd' => Extend,
+          '\U000102e0' => Extend,
+          '\U00010376' .. '\U0001037a' => Extend,
+          '\U00010a01' .. '\U00010a03' => Extend,
+          '\U00010a05' .. '\U00010a06' => Extend,
+          '\U00010a0c' .. '\U00010a0f' => Extend,
+          '\U00010a38' .. '\U00010a3a' => Extend,
+          '\U00010a3f' => Extend,
+          '\U00010ae5' .. '\U00010ae6' => Extend,
+          '\U00010d24' .. '\U00010d27' => Extend,
+          '\U00010eab' .. '\U00010eac' => Extend,
+          '\U00010f46' .. '\U00010f50' => Extend,
+          '\U00010f82' .. '\U00010f85' => Extend,
+          '\U00011000' => SpacingMark,
+          '\U00011001' => Extend,
+          '\U00011002' => SpacingMark,
+          '\U00011038' .. '\U00011046' => Extend,
+          '\U00011070' => Extend,
+          '\U00011073' .. '\U00011074' => Extend,
+          '\U0001107f' .. '\U00011081' => Extend,
+          '\U00011082' => SpacingMark,
+          '\U000110b0' .. '\U000110b2' => SpacingMark,
+          '\U000110b3' .. '\U000110b6' => Extend,
+          '\U000110b7' .. '\U000110b8' => SpacingMark,
+          '\U000110b9' .. '\U000110ba' => Extend,
+          '\U000110bd' => Prepend,
+          '\U000110c2' => Extend,
+          '\U000110cd' => Prepend,
+          '\U00011100' .. '\U00011102' => Extend,
+          '\U00011127' .. '\U0001112b' => Extend,
+          '\U0001112c' => SpacingMark,
+          '\U0001112d' .. '\U00011134' => Extend,
+          '\U00011145' .. '\U00011146' => SpacingMark,
+          '\U00011173' => Extend,
+          '\U00011180' .. '\U00011181' => Extend,
+          '\U00011182' => SpacingMark,
+          '\U000111b3' .. '\U000111b5' => SpacingMark,
+          '\U000111b6' .. '\U000111be' => Extend,
+          '\U000111bf' .. '\U000111c0' => SpacingMark,
+          '\U000111c2' .. '\U000111c3' => Prepend,
+          '\U000111c9' .. '\U000111cc' => Extend,
+          '\U000111ce' => SpacingMark,
+          '\U000111cf' => Extend,
+          '\U0001122c' .. '\U0001122e' => SpacingMark,
+          '\U0001122f' .. '\U00011231' => Extend,
+          '\U00011232' .. '\U00011233' => SpacingMark,
+          '\U00011234' => Extend,
+          '\U00011235' => SpacingMark,
+          '\U00011236' .. '\U00011237' => Extend,
+          '\U0001123e' => Extend,
+          '\U000112df' => Extend,
+          '\U000112e0' .. '\U000112e2' => SpacingMark,
+          '\U000112e3' .. '\U000112ea' => Extend,
+          '\U00011300' .. '\U00011301' => Extend,
+          '\U00011302' .. '\U00011303' => SpacingMark,
+          '\U0001133b' .. '\U0001133c' => Extend,
+          '\U0001133e' => Extend,
+          '\U0001133f' => SpacingMark,
+          '\U00011340' => Extend,
+          '\U00011341' .. '\U00011344' => SpacingMark,
+          '\U00011347' .. '\U00011348' => SpacingMark,
+          '\U0001134b' .. '\U0001134d' => SpacingMark,
+          '\U00011357' => Extend,
+          '\U00011362' .. '\U00011363' => SpacingMark,
+          '\U00011366' .. '\U0001136c' => Extend,
+          '\U00011370' .. '\U00011374' => Extend,
+          '\U00011435' .. '\U00011437' => SpacingMark,
+          '\U00011438' .. '\U0001143f' => Extend,
+          '\U00011440' .. '\U00011441' => SpacingMark,
+          '\U00011442' .. '\U00011444' => Extend,
+          '\U00011445' => SpacingMark,
+          '\U00011446' => Extend,
+          '\U0001145e' => Extend,
+          '\U000114b0' => Extend,
+          '\U000114b1' .. '\U000114b2' => SpacingMark,
+          '\U000114b3' .. '\U000114b8' => Extend,
+          '\U000114b9' => SpacingMark,
+          '\U000114ba' => Extend,
+          '\U000114bb' .. '\U000114bc' => SpacingMark,
+          '\U000114bd' => Extend,
+          '\U000114be' => SpacingMark,
+          '\U000114bf' .. '\U000114c0' => Extend,
+          '\U000114c1' => SpacingMark,
+          '\U000114c2' .. '\U000114c3' => Extend,
+          '\U000115af' => Extend,
+          '\U000115b0' .. '\U000115b1' => SpacingMark,
+          '\U000115b2' .. '\U000115b5' => Extend,
+          '\U000115b8' .. '\U000115bb' => SpacingMark,
+          '\U000115bc' .. '\U000115bd' => Extend,
+          '\U000115be' => SpacingMark,
+          '\U000115bf' .. '\U000115c0' => Extend,
+          '\U000115dc' .. '\U000115dd' => Extend,
+          '\U00011630' .. '\U00011632' => SpacingMark,
+          '\U00011633' .. '\U0001163a' => Extend,
+          '\U0001163b' .. '\U0001163c' => SpacingMark,
+          '\U0001163d' => Extend,
+          '\U0001163e' => SpacingMark,
+          '\U0001163f' .. '\U00011640' => Extend,
+          '\U000116ab' => Extend,
+          '\U000116ac' => SpacingMark,
+          '\U000116ad' => Extend,
+          '\U000116ae' .. '\U000116af' => SpacingMark,
+          '\U000116b0' .. '\U000116b5' => Extend,
+          '\U000116b6' => SpacingMark,
+          '\U000116b7' => Extend,
+          '\U0001171d' .. '\U0001171f' => Extend,
+          '\U00011722' .. '\U00011725' => Extend,
+          '\U00011726' => SpacingMark,
+          '\U00011727' .. '\U0001172b' => Extend,
+          '\U0001182c' .. '\U0001182e' => SpacingMark,
+          '\U0001182f' .. '\U00011837' => Extend,
+          '\U00011838' => SpacingMark,
+          '\U00011839' .. '\U0001183a' => Extend,
+          '\U00011930' => Extend,
+          '\U00011931' .. '\U00011935' => SpacingMark,
+          '\U00011937' .. '\U00011938' => SpacingMark,
+          '\U0001193b' .. '\U0001193c' => Extend,
+          '\U0001193d' => SpacingMark,
+          '\U0001193e' => Extend,
+          '\U0001193f' => Prepend,
+          '\U00011940' => SpacingMark,
+          '\U00011941' => Prepend,
+          '\U00011942' => SpacingMark,
+          '\U00011943' => Extend,
+          '\U000119d1' .. '\U000119d3' => SpacingMark,
+          '\U000119d4' .. '\U000119d7' => Extend,
+          '\U000119da' .. '\U000119db' => Extend,
+          '\U000119dc' .. '\U000119df' => SpacingMark,
+          '\U000119e0' => Extend,
+          '\U000119e4' => SpacingMark,
+          '\U00011a01' .. '\U00011a0a' => Extend,
+          '\U00011a33' .. '\U00011a38' => Extend,
+          '\U00011a39' => SpacingMark,
+          '\U00011a3a' => Prepend,
+          '\U00011a3b' .. '\U00011a3e' => Extend,
+          '\U00011a47' => Extend,
+          '\U00011a51' .. '\U00011a56' => Extend,
+          '\U00011a57' .. '\U00011a58' => SpacingMark,
+          '\U00011a59' .. '\U00011a5b' => Extend,
+          '\U00011a84' .. '\U00011a89' => Prepend,
+          '\U00011a8a' .. '\U00011a96' => Extend,
+          '\U00011a97' => SpacingMark,
+          '\U00011a98' .. '\U00011a99' => Extend,
+          '\U00011c2f' => SpacingMark,
+          '\U00011c30' .. '\U00011c36' => Extend,
+          '\U00011c38' .. '\U00011c3d' => Extend,
+          '\U00011c3e' => SpacingMark,
+          '\U00011c3f' => Extend,
+          '\U00011c92' .. '\U00011ca7' => Extend,
+          '\U00011ca9' => SpacingMark,
+          '\U00011caa' .. '\U00011cb0' => Extend,
+          '\U00011cb1' => SpacingMark,
+          '\U00011cb2' .. '\U00011cb3' => Extend,
+          '\U00011cb4' => SpacingMark,
+          '\U00011cb5' .. '\U00011cb6' => Extend,
+          '\U00011d31' .. '\U00011d36' => Extend,
+          '\U00011d3a' => Extend,
+          '\U00011d3c' .. '\U00011d3d' => Extend,
+          '\U00011d3f' .. '\U00011d45' => Extend,
+          '\U00011d46' => Prepend,
+          '\U00011d47' => Extend,
+          '\U00011d8a' .. '\U00011d8e' => SpacingMark,
+          '\U00011d90' .. '\U00011d91' => Extend,
+          '\U00011d93' .. '\U00011d94' => SpacingMark,
+          '\U00011d95' => Extend,
+          '\U00011d96' => SpacingMark,
+          '\U00011d97' => Extend,
+          '\U00011ef3' .. '\U00011ef4' => Extend,
+          '\U00011ef5' .. '\U00011ef6' => SpacingMark,
+          '\U00013430' .. '\U00013438' => Control,
+          '\U00016af0' .. '\U00016af4' => Extend,
+          '\U00016b30' .. '\U00016b36' => Extend,
+          '\U00016f4f' => Extend,
+          '\U00016f51' .. '\U00016f87' => SpacingMark,
+          '\U00016f8f' .. '\U00016f92' => Extend,
+          '\U00016fe4' => Extend,
+          '\U00016ff0' .. '\U00016ff1' => SpacingMark,
+          '\U0001bc9d' .. '\U0001bc9e' => Extend,
+          '\U0001bca0' .. '\U0001bca3' => Control,
+          '\U0001cf00' .. '\U0001cf2d' => Extend,
+          '\U0001cf30' .. '\U0001cf46' => Extend,
+          '\U0001d165' => Extend,
+          '\U0001d166' => SpacingMark,
+          '\U0001d167' .. '\U0001d169' => Extend,
+          '\U0001d16d' => SpacingMark,
+          '\U0001d16e' .. '\U0001d172' => Extend,
+          '\U0001d173' .. '\U0001d17a' => Control,
+          '\U0001d17b' .. '\U0001d182' => Extend,
+          '\U0001d185' .. '\U0001d18b' => Extend,
+          '\U0001d1aa' .. '\U0001d1ad' => Extend,
+          '\U0001d242' .. '\U0001d244' => Extend,
+          '\U0001da00' .. '\U0001da36' => Extend,
+          '\U0001da3b' .. '\U0001da6c' => Extend,
+          '\U0001da75' => Extend,
+          '\U0001da84' => Extend,
+          '\U0001da9b' .. '\U0001da9f' => Extend,
+          '\U0001daa1' .. '\U0001daaf' => Extend,
+          '\U0001e000' .. '\U0001e006' => Extend,
+          '\U0001e008' .. '\U0001e018' => Extend,
+          '\U0001e01b' .. '\U0001e021' => Extend,
+          '\U0001e023' .. '\U0001e024' => Extend,
+          '\U0001e026' .. '\U0001e02a' => Extend,
+          '\U0001e130' .. '\U0001e136' => Extend,
+          '\U0001e2ae' => Extend,
+          '\U0001e2ec' .. '\U0001e2ef' => Extend,
+          '\U0001e8d0' .. '\U0001e8d6' => Extend,
+          '\U0001e944' .. '\U0001e94a' => Extend,
+          '\U0001f000' .. '\U0001f0ff' => ExtendedPictographic,
+          '\U0001f10d' .. '\U0001f10f' => ExtendedPictographic,
+          '\U0001f12f' => ExtendedPictographic,
+          '\U0001f16c' .. '\U0001f171' => ExtendedPictographic,
+          '\U0001f17e' .. '\U0001f17f' => ExtendedPictographic,
+          '\U0001f18e' => ExtendedPictographic,
+          '\U0001f191' .. '\U0001f19a' => ExtendedPictographic,
+          '\U0001f1ad' .. '\U0001f1e5' => ExtendedPictographic,
+          '\U0001f1e6' .. '\U0001f1ff' => RegionalIndicator,
+          '\U0001f201' .. '\U0001f20f' => ExtendedPictographic,
+          '\U0001f21a' => ExtendedPictographic,
+          '\U0001f22f' => ExtendedPictographic,
+          '\U0001f232' .. '\U0001f23a' => ExtendedPictographic,
+          '\U0001f23c' .. '\U0001f23f' => ExtendedPictographic,
+          '\U0001f249' .. '\U0001f3fa' => ExtendedPictographic,
+          '\U0001f3fb' .. '\U0001f3ff' => Extend,
+          '\U0001f400' .. '\U0001f53d' => ExtendedPictographic,
+          '\U0001f546' .. '\U0001f64f' => ExtendedPictographic,
+          '\U0001f680' .. '\U0001f6ff' => ExtendedPictographic,
+          '\U0001f774' .. '\U0001f77f' => ExtendedPictographic,
+          '\U0001f7d5' .. '\U0001f7ff' => ExtendedPictographic,
+          '\U0001f80c' .. '\U0001f80f' => ExtendedPictographic,
+          '\U0001f848' .. '\U0001f84f' => ExtendedPictographic,
+          '\U0001f85a' .. '\U0001f85f' => ExtendedPictographic,
+          '\U0001f888' .. '\U0001f88f' => ExtendedPictographic,
+          '\U0001f8ae' .. '\U0001f8ff' => ExtendedPictographic,
+          '\U0001f90c' .. '\U0001f93a' => ExtendedPictographic,
+          '\U0001f93c' .. '\U0001f945' => ExtendedPictographic,
+          '\U0001f947' .. '\U0001faff' => ExtendedPictographic,
+          '\U0001fc00' .. '\U0001fffd' => ExtendedPictographic,
+          '\U000e0000' .. '\U000e001f' => Control,
+          '\U000e0020' .. '\U000e007f' => Extend,
+          '\U000e0080' .. '\U000e00ff' => Control,
+          '\U000e0100' .. '\U000e01ef' => Extend,
+          '\U000e01f0' .. '\U000e0fff' => Control,
+          '\uac00' .. '\ud7a3' => {
+            if ((c as u32) - 0xac00) % 28 == 0 {
+                HangulLV
+            } else {
+                HangulLVT
+            }
+          }
+          _ => Any
+        }
+    }
+}
@@ -8,17 +8,12 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use back::target_strs;
+use back::target_spec;
 use driver::session;
-use session::sess_os_to_meta_os;
-use metadata::loader::meta_section_name;
 
-fn get_target_strs(target_os: session::os) -> target_strs::t {
+fn get_target_spec(target_os: session::os) -> target_spec::t {
+    let (dll_prefix, dll_suffix) = target_spec::default_dll_naming(target_os);
     return {
-        module_asm: ~"",
-
-        meta_sect_name: meta_section_name(sess_os_to_meta_os(target_os)),
-
         data_layout: match target_os {
           session::os_macos => {
             ~"e-p:32:32:32" +
@@ -61,7 +56,7 @@ fn get_target_strs(target_os: session::os) -> target_strs::t {
           }
         },
 
-        target_triple: match target_os {
+        llvm_target: match target_os {
           session::os_macos => ~"arm-apple-darwin",
           session::os_win32 => ~"arm-pc-mingw32",
           session::os_linux => ~"arm-unknown-linux",
@@ -69,7 +64,21 @@ fn get_target_strs(target_os: session::os) -> target_strs::t {
           session::os_freebsd => ~"arm-unknown-freebsd"
         },
 
-        cc_args: ~[~"-marm"]
+        target_word_size: 32u,
+
+        os: target_os,
+
+        arch: session::arch_arm,
+
+        linker: target_spec::default_linker(target_os),
+
+        linker_args: ~[~"-marm"],
+
+        ar: target_spec::default_ar(target_os),
+
+        dll_prefix: dll_prefix,
+
+        dll_suffix: dll_suffix
     };
 }
 
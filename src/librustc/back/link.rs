@@ -10,7 +10,7 @@
 
 use core::prelude::*;
 
-use back::rpath;
+use back::{lto, rpath};
 use driver::session;
 use lib::llvm::llvm;
 use lib::llvm::{ModuleRef, mk_pass_manager, mk_target_data, True, False};
@@ -30,7 +30,6 @@ use core::cmp;
 use core::hash;
 use core::io::{Writer, WriterUtil};
 use core::libc::{c_int, c_uint, c_char};
-use core::os::consts::{macos, freebsd, linux, android, win32};
 use core::os;
 use core::ptr;
 use core::run;
@@ -712,17 +711,129 @@ fn mangle_internal_name_by_seq(ccx: @crate_ctxt, +flav: ~str) -> ~str {
 }
 
 
-fn output_dll_filename(os: session::os, lm: link_meta) -> ~str {
+fn output_dll_filename(sess: Session, lm: link_meta) -> ~str {
     let libname = fmt!("%s-%s-%s", lm.name, lm.extras_hash, lm.vers);
-    let (dll_prefix, dll_suffix) = match os {
-        session::os_win32 => (win32::DLL_PREFIX, win32::DLL_SUFFIX),
-        session::os_macos => (macos::DLL_PREFIX, macos::DLL_SUFFIX),
-        session::os_linux => (linux::DLL_PREFIX, linux::DLL_SUFFIX),
-        session::os_android => (android::DLL_PREFIX, android::DLL_SUFFIX),
-        session::os_freebsd => (freebsd::DLL_PREFIX, freebsd::DLL_SUFFIX),
-    };
-    return str::from_slice(dll_prefix) + libname +
-           str::from_slice(dll_suffix);
+    let strs = &sess.targ_cfg.target_strs;
+    return strs.dll_prefix + libname + strs.dll_suffix;
+}
+
+fn output_rlib_filename(lm: link_meta) -> ~str {
+    // The naming the loader looks for when resolving crates statically
+    fmt!("lib%s-%s-%s.rlib", lm.name, lm.extras_hash, lm.vers)
+}
+
+/// The file a library crate ends up in
+fn library_filename(sess: Session, out_filename: &Path,
+                    lm: link_meta) -> Path {
+    if sess.opts.static {
+        out_filename.dir_path().push(output_rlib_filename(lm))
+    } else {
+        out_filename.dir_path().push(output_dll_filename(sess, lm))
+    }
+}
+
+/**
+ * Moves the object file of a crate built with `--static --lib` to where
+ * crates resolved statically are looked for. The object file is used as
+ * it is; `--staticlib` archives these, and static binaries link them in.
+ */
+fn link_rlib(sess: Session, obj_filename: &Path, out_filename: &Path,
+             lm: link_meta) {
+    let output = library_filename(sess, out_filename, lm);
+    debug!("moving %s to %s", obj_filename.to_str(), output.to_str());
+    if os::path_exists(&output) { os::remove_file(&output); }
+    match os::rename(obj_filename, &output) {
+      Ok(()) => (),
+      Err(e) => {
+        sess.fatal(fmt!("failed to write static library `%s`: %s",
+                        output.to_str(), e));
+      }
+    }
+}
+
+// Converts a library file-stem into a cc -l argument
+fn unlib(config: @session::config, +stem: ~str) -> ~str {
+    if stem.starts_with("lib") &&
+        config.os != session::os_win32 {
+        stem.slice(3, stem.len())
+    } else {
+        stem
+    }
+}
+
+/**
+ * The arguments, other than those naming Rust crates, that are needed to
+ * link Rust code into an executable or shared library: the native
+ * libraries it uses and the runtime with its own dependencies.
+ */
+fn native_link_args(sess: Session) -> ~[~str] {
+    // The default library location, we need this to find the runtime.
+    // The location of crates will be determined as needed.
+    let mut args =
+        ~[~"-L" + sess.filesearch.get_target_lib_path().to_str()];
+
+    let cstore = sess.cstore;
+    let ula = cstore::get_used_link_args(cstore);
+    for ula.each |arg| { args.push(/*bad*/copy *arg); }
+
+    // # Extern library linking
+
+    // User-supplied library search paths (-L on the cammand line) These are
+    // the same paths used to find Rust crates, so some of them may have been
+    // added already by the previous crate linking code. This only allows them
+    // to be found at compile time so it is still entirely up to outside
+    // forces to make sure that library can be found at runtime.
+
+    let addl_paths = /*bad*/copy sess.opts.addl_lib_search_paths;
+    for addl_paths.each |path| { args.push(~"-L" + path.to_str()); }
+
+    // The names of the extern libraries
+    let used_libs = cstore::get_used_libraries(cstore);
+    for used_libs.each |l| { args.push(~"-l" + *l); }
+
+    // Always want the runtime linked in
+    args.push(~"-lrustrt");
+
+    // On linux librt and libdl are an indirect dependencies via rustrt,
+    // and binutils 2.22+ won't add them automatically
+    if sess.targ_cfg.os == session::os_linux {
+        args.push_all(~[~"-lrt", ~"-ldl"]);
+
+        // LLVM implements the `frem` instruction as a call to `fmod`,
+        // which lives in libm. Similar to above, on some linuxes we
+        // have to be explicit about linking to it. See #2510
+        args.push(~"-lm");
+    }
+    else if sess.targ_cfg.os == session::os_android {
+        args.push_all(~[~"-ldl", ~"-llog",  ~"-lsupc++",
+                        ~"-lgnustl_shared"]);
+        args.push(~"-lm");
+    }
+
+    if sess.targ_cfg.os == session::os_freebsd {
+        args.push_all(~[~"-pthread", ~"-lrt",
+                        ~"-L/usr/local/lib", ~"-lexecinfo",
+                        ~"-L/usr/local/lib/gcc46",
+                        ~"-L/usr/local/lib/gcc44", ~"-lstdc++",
+                        ~"-Wl,-z,origin",
+                        ~"-Wl,-rpath,/usr/local/lib/gcc46",
+                        ~"-Wl,-rpath,/usr/local/lib/gcc44"]);
+    }
+
+    // OS X 10.6 introduced 'compact unwind info', which is produced by the
+    // linker from the dwarf unwind info. Unfortunately, it does not seem to
+    // understand how to unwind our __morestack frame, so we have to turn it
+    // off. This has impacted some other projects like GHC.
+    if sess.targ_cfg.os == session::os_macos {
+        args.push(~"-Wl,-no_compact_unwind");
+    }
+
+    // Stack growth requires statically linking a __morestack function
+    if sess.targ_cfg.os != session::os_android {
+        args.push(~"-lmorestack");
+    }
+
+    return args;
 }
 
 // If the user wants an exe generated we need to invoke
@@ -731,48 +842,24 @@ fn link_binary(sess: Session,
                obj_filename: &Path,
                out_filename: &Path,
                lm: link_meta) {
-    // Converts a library file-stem into a cc -l argument
-    fn unlib(config: @session::config, +stem: ~str) -> ~str {
-        if stem.starts_with("lib") &&
-            config.os != session::os_win32 {
-            stem.slice(3, stem.len())
-        } else {
-            stem
-        }
-    }
-
     let output = if sess.building_library {
-        let long_libname = output_dll_filename(sess.targ_cfg.os, lm);
+        let long_libname = output_dll_filename(sess, lm);
         debug!("link_meta.name:  %s", lm.name);
         debug!("long_libname: %s", long_libname);
         debug!("out_filename: %s", out_filename.to_str());
         debug!("dirname(out_filename): %s", out_filename.dir_path().to_str());
 
-        out_filename.dir_path().push(long_libname)
+        library_filename(sess, out_filename, lm)
     } else {
         /*bad*/copy *out_filename
     };
 
     log(debug, ~"output: " + output.to_str());
 
-    // The default library location, we need this to find the runtime.
-    // The location of crates will be determined as needed.
-    let stage: ~str = ~"-L" + sess.filesearch.get_target_lib_path().to_str();
-
-    // In the future, FreeBSD will use clang as default compiler.
-    // It would be flexible to use cc (system's default C compiler)
-    // instead of hard-coded gcc.
-    // For win32, there is no cc command,
-    // so we add a condition to make it use gcc.
-    let cc_prog: ~str =
-        if sess.targ_cfg.os == session::os_android {
-            ~"arm-linux-androideabi-g++"
-        } else if sess.targ_cfg.os == session::os_win32 { ~"gcc" }
-        else { ~"cc" };
+    let cc_prog = /*bad*/copy sess.targ_cfg.target_strs.linker;
     // The invocations of cc share some flags across platforms
 
-    let mut cc_args =
-        vec::append(~[stage], sess.targ_cfg.target_strs.cc_args);
+    let mut cc_args = /*bad*/copy sess.targ_cfg.target_strs.cc_args;
     cc_args.push(~"-o");
     cc_args.push(output.to_str());
     cc_args.push(obj_filename.to_str());
@@ -787,8 +874,12 @@ fn link_binary(sess: Session,
 
     // # Crate linking
 
+    let use_lto = sess.lto() && !sess.building_library;
+
     let cstore = sess.cstore;
     for cstore::get_used_crate_files(cstore).each |cratepath| {
+        // LTO has already linked the crate's code into the object file
+        if use_lto && lto::has_bitcode(cratepath) { loop; }
         if cratepath.filetype() == Some(~".rlib") {
             cc_args.push(cratepath.to_str());
             loop;
@@ -799,23 +890,7 @@ fn link_binary(sess: Session,
         cc_args.push(~"-l" + libarg);
     }
 
-    let ula = cstore::get_used_link_args(cstore);
-    for ula.each |arg| { cc_args.push(/*bad*/copy *arg); }
-
-    // # Extern library linking
-
-    // User-supplied library search paths (-L on the cammand line) These are
-    // the same paths used to find Rust crates, so some of them may have been
-    // added already by the previous crate linking code. This only allows them
-    // to be found at compile time so it is still entirely up to outside
-    // forces to make sure that library can be found at runtime.
-
-    let addl_paths = /*bad*/copy sess.opts.addl_lib_search_paths;
-    for addl_paths.each |path| { cc_args.push(~"-L" + path.to_str()); }
-
-    // The names of the extern libraries
-    let used_libs = cstore::get_used_libraries(cstore);
-    for used_libs.each |l| { cc_args.push(~"-l" + *l); }
+    cc_args.push_all(native_link_args(sess));
 
     if sess.building_library {
         cc_args.push(lib_cmd);
@@ -828,48 +903,6 @@ fn link_binary(sess: Session,
         }
     }
 
-    // Always want the runtime linked in
-    cc_args.push(~"-lrustrt");
-
-    // On linux librt and libdl are an indirect dependencies via rustrt,
-    // and binutils 2.22+ won't add them automatically
-    if sess.targ_cfg.os == session::os_linux {
-        cc_args.push_all(~[~"-lrt", ~"-ldl"]);
-
-        // LLVM implements the `frem` instruction as a call to `fmod`,
-        // which lives in libm. Similar to above, on some linuxes we
-        // have to be explicit about linking to it. See #2510
-        cc_args.push(~"-lm");
-    }
-    else if sess.targ_cfg.os == session::os_android {
-        cc_args.push_all(~[~"-ldl", ~"-llog",  ~"-lsupc++",
-                           ~"-lgnustl_shared"]);
-        cc_args.push(~"-lm");
-    }
-
-    if sess.targ_cfg.os == session::os_freebsd {
-        cc_args.push_all(~[~"-pthread", ~"-lrt",
-                                ~"-L/usr/local/lib", ~"-lexecinfo",
-                                ~"-L/usr/local/lib/gcc46",
-                                ~"-L/usr/local/lib/gcc44", ~"-lstdc++",
-                                ~"-Wl,-z,origin",
-                                ~"-Wl,-rpath,/usr/local/lib/gcc46",
-                                ~"-Wl,-rpath,/usr/local/lib/gcc44"]);
-    }
-
-    // OS X 10.6 introduced 'compact unwind info', which is produced by the
-    // linker from the dwarf unwind info. Unfortunately, it does not seem to
-    // understand how to unwind our __morestack frame, so we have to turn it
-    // off. This has impacted some other projects like GHC.
-    if sess.targ_cfg.os == session::os_macos {
-        cc_args.push(~"-Wl,-no_compact_unwind");
-    }
-
-    // Stack growth requires statically linking a __morestack function
-    if sess.targ_cfg.os != session::os_android {
-    cc_args.push(~"-lmorestack");
-    }
-
     // FIXME (#2397): At some point we want to rpath our guesses as to where
     // extern libraries might live, based on the addl_lib_search_paths
     cc_args.push_all(rpath::get_rpath_flags(sess, &output));
@@ -899,6 +932,65 @@ fn link_binary(sess: Session,
         }
     }
 }
+
+/**
+ * Bundles the crate's object file and the objects of the Rust crates it
+ * uses into a static archive that C programs can link against. Crates are
+ * always resolved statically for `--staticlib`, so each of them must have
+ * been built with `--static --lib`. The native libraries needed to link
+ * the archive can't be recorded in it, so they are printed as a note
+ * instead.
+ *
+ * C code can only call into the archive from a Rust task, so the program
+ * starts the runtime with `rust_start_from_c`, passing it the archive's
+ * `_rust_crate_map_toplevel`, and makes its calls from the function that
+ * the runtime then runs on the main task.
+ */
+fn link_staticlib(sess: Session,
+                  obj_filename: &Path,
+                  out_filename: &Path) {
+    let mut members = ~[obj_filename.to_str()];
+    for cstore::get_used_crate_files(sess.cstore).each |cratepath| {
+        if cratepath.filetype() != Some(~".rlib") {
+            sess.err(fmt!("crate `%s` is not a static library and can't \
+                           be bundled into `%s`", cratepath.to_str(),
+                          out_filename.filename().get()));
+            loop;
+        }
+        members.push(cratepath.to_str());
+    }
+    sess.abort_if_errors();
+
+    // `ar` adds to an existing archive rather than replacing it
+    if os::path_exists(out_filename) {
+        os::remove_file(out_filename);
+    }
+
+    let ar_prog = copy sess.targ_cfg.target_strs.ar;
+    let ar_args = ~[~"crs", out_filename.to_str()] + members;
+    debug!("%s args: %s", ar_prog, str::connect(ar_args, ~" "));
+    let prog = run::program_output(ar_prog, ar_args);
+    if 0 != prog.status {
+        sess.err(fmt!("creating archive with `%s` failed with code %d",
+                      ar_prog, prog.status));
+        sess.note(fmt!("%s arguments: %s",
+                       ar_prog, str::connect(ar_args, ~" ")));
+        sess.note(prog.err + prog.out);
+        sess.abort_if_errors();
+    }
+
+    sess.note(fmt!("link against the following native libraries when \
+                    linking `%s`: %s", out_filename.filename().get(),
+                   str::connect(native_link_args(sess), ~" ")));
+
+    // Remove the temporary object file if we aren't saving temps
+    if !sess.opts.save_temps {
+        if ! os::remove_file(obj_filename) {
+            sess.warn(fmt!("failed to delete object file `%s`",
+                           obj_filename.to_str()));
+        }
+    }
+}
 //
 // Local Variables:
 // mode: rust
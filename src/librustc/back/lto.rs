@@ -0,0 +1,116 @@
+// Copyright 2013 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Link-time optimization across crates, enabled with `-Z lto`.
+//
+// Every library has its unoptimized bitcode written next to it. When an
+// executable is built with `-Z lto`, the bitcode of every crate it uses is
+// linked into its own module before any optimization, everything but
+// `main` is made internal, and the LTO passes get to inline and drop code
+// across what used to be crate boundaries. Those crates are then left off
+// the link line. Crates without bitcode, built by a rustc that didn't
+// write it, are linked as usual with a warning.
+
+use core::prelude::*;
+
+use back::link::llvm_err;
+use driver::session::Session;
+use lib::llvm::llvm;
+use lib::llvm::{ModuleRef, mk_pass_manager, mk_target_data};
+use lib;
+use metadata::cstore;
+
+use core::os;
+use core::str;
+
+/// Where the bitcode of the library at `lib` is kept
+pub fn bitcode_path(lib: &Path) -> Path {
+    lib.with_filetype("bc")
+}
+
+/// Whether bitcode was written for the crate at `lib`
+pub fn has_bitcode(lib: &Path) -> bool {
+    os::path_exists(&bitcode_path(lib))
+}
+
+/// Writes the bitcode of a library next to it, for use by `run`
+pub fn write_bitcode(sess: Session, llmod: ModuleRef, lib: &Path) {
+    let path = bitcode_path(lib);
+    let status = str::as_c_str(path.to_str(), |buf| unsafe {
+        llvm::LLVMWriteBitcodeToFile(llmod, buf)
+    });
+    if status != 0 {
+        sess.fatal(fmt!("failed to write bitcode to `%s`", path.to_str()));
+    }
+}
+
+/**
+ * Links the bitcode of every crate that has been loaded and has bitcode
+ * into `llmod`, internalizes everything but `main` and runs the LTO passes
+ * over the result.
+ */
+pub fn run(sess: Session, llmod: ModuleRef) {
+    for cstore::get_used_crate_files(sess.cstore).each |cratepath| {
+        if !has_bitcode(cratepath) {
+            sess.warn(fmt!("no bitcode for `%s`, so it is linked without \
+                            link-time optimization", cratepath.to_str()));
+            loop;
+        }
+        let path = bitcode_path(cratepath);
+        debug!("linking in bitcode from %s", path.to_str());
+
+        unsafe {
+            let mb = str::as_c_str(path.to_str(), |buf| {
+                llvm::LLVMRustCreateMemoryBufferWithContentsOfFile(buf)
+            });
+            if mb as int == 0 {
+                llvm_err(sess, fmt!("failed to read `%s`", path.to_str()));
+            }
+            let crate_mod = llvm::LLVMRustParseBitcode(mb);
+            llvm::LLVMDisposeMemoryBuffer(mb);
+            if crate_mod as int == 0 {
+                llvm_err(sess, fmt!("failed to parse `%s`", path.to_str()));
+            }
+
+            // Every crate defines its own ABI version marker
+            let abi_version = str::as_c_str(~"rust_abi_version", |buf| {
+                llvm::LLVMGetNamedGlobal(crate_mod, buf)
+            });
+            if abi_version as int != 0 {
+                lib::llvm::SetLinkage(abi_version,
+                                      lib::llvm::InternalLinkage);
+            }
+
+            let linked = llvm::LLVMRustLinkModules(llmod, crate_mod);
+            llvm::LLVMDisposeModule(crate_mod);
+            if !linked {
+                llvm_err(sess, fmt!("failed to link in `%s`",
+                                    path.to_str()));
+            }
+        }
+    }
+
+    unsafe {
+        let pm = mk_pass_manager();
+        let td = mk_target_data(
+            /*bad*/copy sess.targ_cfg.target_strs.data_layout);
+        llvm::LLVMAddTargetData(td.lltd, pm.llpm);
+        if !sess.no_verify() { llvm::LLVMAddVerifierPass(pm.llpm); }
+
+        // With internalization on, everything except `main` becomes
+        // internal, so the passes are free to inline or remove it
+        let pmb = llvm::LLVMPassManagerBuilderCreate();
+        llvm::LLVMPassManagerBuilderPopulateLTOPassManager(
+            pmb, pm.llpm, true, true);
+        llvm::LLVMPassManagerBuilderDispose(pmb);
+
+        llvm::LLVMRunPassManager(pm.llpm, llmod);
+    }
+}
@@ -15,7 +15,6 @@ use metadata::cstore;
 use metadata::filesearch;
 
 use core::os;
-use core::uint;
 use core::util;
 use core::vec;
 use std::map::HashMap;
@@ -53,14 +52,16 @@ fn get_rpath_flags(sess: session::Session, out_filename: &Path) -> ~[~str] {
     // where rustrt is and we know every rust program needs it
     let libs = vec::append_one(libs, get_sysroot_absolute_rt_lib(sess));
 
-    let target_triple = /*bad*/copy sess.opts.target_triple;
+    let target_triple = /*bad*/copy sess.targ_cfg.target_triple;
     let rpaths = get_rpaths(os, &sysroot, output, libs, target_triple);
     rpaths_to_flags(rpaths)
 }
 
 fn get_sysroot_absolute_rt_lib(sess: session::Session) -> Path {
-    let r = filesearch::relative_target_lib_path(sess.opts.target_triple);
-    sess.filesearch.sysroot().push_rel(&r).push(os::dll_filename("rustrt"))
+    let strs = &sess.targ_cfg.target_strs;
+    let r = filesearch::relative_target_lib_path(sess.targ_cfg.target_triple);
+    let rustrt = strs.dll_prefix + ~"rustrt" + strs.dll_suffix;
+    sess.filesearch.sysroot().push_rel(&r).push(rustrt)
 }
 
 fn rpaths_to_flags(rpaths: &[Path]) -> ~[~str] {
@@ -144,33 +145,15 @@ fn get_rpath_relative_to_output(os: session::os,
 fn get_relative_to(abs1: &Path, abs2: &Path) -> Path {
     assert abs1.is_absolute;
     assert abs2.is_absolute;
-    let abs1 = abs1.normalize();
-    let abs2 = abs2.normalize();
     debug!("finding relative path from %s to %s",
            abs1.to_str(), abs2.to_str());
-    let split1 = /*bad*/copy abs1.components;
-    let split2 = /*bad*/copy abs2.components;
-    let len1 = vec::len(split1);
-    let len2 = vec::len(split2);
-    assert len1 > 0;
-    assert len2 > 0;
-
-    let max_common_path = uint::min(len1, len2) - 1;
-    let mut start_idx = 0;
-    while start_idx < max_common_path
-        && split1[start_idx] == split2[start_idx] {
-        start_idx += 1;
-    }
-
-    let mut path = ~[];
-    for uint::range(start_idx, len1 - 1) |_i| { path.push(~".."); };
-
-    path.push_all(vec::view(split2, start_idx, len2 - 1));
-
-    if !path.is_empty() {
-        return Path("").push_many(path);
-    } else {
-        return Path(".");
+    let dir1 = abs1.normalize().dir_path();
+    let dir2 = abs2.normalize().dir_path();
+    match dir2.relative_to(&dir1) {
+        Some(ref path) if path.components.is_empty() => Path("."),
+        Some(move path) => move path,
+        None => fail fmt!("no relative path from %s to %s",
+                          abs1.to_str(), abs2.to_str())
     }
 }
 
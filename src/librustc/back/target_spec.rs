@@ -0,0 +1,356 @@
+// Copyright 2013 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Descriptions of the targets rustc can generate code for.
+//
+// The built-in targets are picked by triple and described by `back::x86`,
+// `back::x86_64` and `back::arm`. Any other target can be described in a
+// JSON file passed to `--target` in place of a triple, for example:
+//
+//     {
+//         "llvm-target": "arm-none-linux-gnueabi",
+//         "data-layout": "e-p:32:32:32-i64:64:64-f64:64:64-n32",
+//         "target-word-size": 32,
+//         "os": "linux",
+//         "arch": "arm",
+//         "linker": "arm-none-linux-gnueabi-gcc",
+//         "linker-args": ["-mcpu=cortex-a8"],
+//         "ar": "arm-none-linux-gnueabi-ar",
+//         "dll-prefix": "lib",
+//         "dll-suffix": ".so"
+//     }
+//
+// `os` and `arch` take the values of the `target_os` and `target_arch`
+// cfgs. `linker`, `linker-args`, `ar`, `dll-prefix` and `dll-suffix` may
+// be left out, in which case those of the built-in targets for the same OS
+// are used. Libraries for the target are installed under `llvm-target`.
+
+use core::prelude::*;
+
+use back::{arm, target_strs, x86, x86_64};
+use driver::session;
+use metadata::loader::meta_section_name;
+use session::sess_os_to_meta_os;
+
+use core::io;
+use core::os::consts::{macos, freebsd, linux, android, win32};
+use core::str;
+use std::json;
+
+pub type t = {
+    llvm_target: ~str,
+    data_layout: ~str,
+    target_word_size: uint,
+    os: session::os,
+    arch: session::arch,
+    linker: ~str,
+    linker_args: ~[~str],
+    ar: ~str,
+    dll_prefix: ~str,
+    dll_suffix: ~str
+};
+
+/// The built-in target for `triple`
+pub fn builtin(triple: &str) -> Result<t, ~str> {
+    let os = match get_os(triple) {
+      Some(os) => os,
+      None => return Err(~"unknown operating system")
+    };
+    match get_arch(triple) {
+      Some(session::arch_x86) => Ok(x86::get_target_spec(os)),
+      Some(session::arch_x86_64) => Ok(x86_64::get_target_spec(os)),
+      Some(session::arch_arm) => Ok(arm::get_target_spec(os)),
+      None => Err(~"unknown architecture: " + str::from_slice(triple))
+    }
+}
+
+/// Reads a target specification from the JSON file at `path`
+pub fn load(path: &Path) -> Result<t, ~str> {
+    let spec = match io::read_whole_file_str(path) {
+      Ok(move s) => s,
+      Err(move e) => return Err(e)
+    };
+    match json::from_str(spec) {
+      Ok(ref j) => {
+        from_json(j).map_err(|e| fmt!("%s: %s", path.to_str(), *e))
+      }
+      Err(e) => Err(fmt!("%s:%s", path.to_str(), e.to_str()))
+    }
+}
+
+pub fn from_json(j: &json::Json) -> Result<t, ~str> {
+    let obj: &json::Object = match *j {
+      json::Object(ref obj) => &**obj,
+      _ => return Err(~"target specification must be a JSON object")
+    };
+
+    let llvm_target = match required_str(obj, "llvm-target") {
+      Ok(move s) => s,
+      Err(move e) => return Err(e)
+    };
+    let data_layout = match required_str(obj, "data-layout") {
+      Ok(move s) => s,
+      Err(move e) => return Err(e)
+    };
+    let target_word_size = match obj.find_copy(&~"target-word-size") {
+      Some(json::Number(n)) if n == 32f => 32u,
+      Some(json::Number(n)) if n == 64f => 64u,
+      Some(_) => return Err(~"`target-word-size` must be 32 or 64"),
+      None => return Err(~"missing `target-word-size`")
+    };
+    let os = match required_str(obj, "os") {
+      Ok(move s) => match os_from_str(&s) {
+        Some(os) => os,
+        None => return Err(fmt!("unknown operating system `%s`", s))
+      },
+      Err(move e) => return Err(e)
+    };
+    let arch = match required_str(obj, "arch") {
+      Ok(move s) => match arch_from_str(&s) {
+        Some(arch) => arch,
+        None => return Err(fmt!("unknown architecture `%s`", s))
+      },
+      Err(move e) => return Err(e)
+    };
+
+    let linker = match optional_str(obj, "linker") {
+      Ok(move s) => s.get_or_default(default_linker(os)),
+      Err(move e) => return Err(e)
+    };
+    let linker_args = match obj.find_copy(&~"linker-args") {
+      Some(json::List(move args)) => {
+        let mut strs = ~[];
+        for args.each |arg| {
+            match *arg {
+              json::String(ref s) => strs.push(copy *s),
+              _ => return Err(~"`linker-args` must be a list of strings")
+            }
+        }
+        move strs
+      }
+      Some(_) => return Err(~"`linker-args` must be a list of strings"),
+      None => ~[]
+    };
+    let ar = match optional_str(obj, "ar") {
+      Ok(move s) => s.get_or_default(default_ar(os)),
+      Err(move e) => return Err(e)
+    };
+    let (default_prefix, default_suffix) = default_dll_naming(os);
+    let dll_prefix = match optional_str(obj, "dll-prefix") {
+      Ok(move s) => s.get_or_default(default_prefix),
+      Err(move e) => return Err(e)
+    };
+    let dll_suffix = match optional_str(obj, "dll-suffix") {
+      Ok(move s) => s.get_or_default(default_suffix),
+      Err(move e) => return Err(e)
+    };
+
+    Ok({llvm_target: llvm_target,
+        data_layout: data_layout,
+        target_word_size: target_word_size,
+        os: os,
+        arch: arch,
+        linker: linker,
+        linker_args: linker_args,
+        ar: ar,
+        dll_prefix: dll_prefix,
+        dll_suffix: dll_suffix})
+}
+
+fn required_str(obj: &json::Object, key: &str) -> Result<~str, ~str> {
+    match optional_str(obj, key) {
+      Ok(Some(move s)) => Ok(s),
+      Ok(None) => Err(fmt!("missing `%s`", key)),
+      Err(move e) => Err(e)
+    }
+}
+
+fn optional_str(obj: &json::Object, key: &str) -> Result<Option<~str>, ~str> {
+    match obj.find_copy(&str::from_slice(key)) {
+      Some(json::String(move s)) => Ok(Some(s)),
+      Some(_) => Err(fmt!("`%s` must be a string", key)),
+      None => Ok(None)
+    }
+}
+
+/// The strings the rest of the compiler takes from the target
+pub fn to_target_strs(spec: &t) -> target_strs::t {
+    {module_asm: ~"",
+     meta_sect_name: meta_section_name(sess_os_to_meta_os(spec.os)),
+     data_layout: copy spec.data_layout,
+     target_triple: copy spec.llvm_target,
+     cc_args: copy spec.linker_args,
+     linker: copy spec.linker,
+     ar: copy spec.ar,
+     dll_prefix: copy spec.dll_prefix,
+     dll_suffix: copy spec.dll_suffix}
+}
+
+/// The C compiler used to link for `os` when the target doesn't name one
+pub fn default_linker(os: session::os) -> ~str {
+    // In the future, FreeBSD will use clang as default compiler.
+    // It would be flexible to use cc (system's default C compiler)
+    // instead of hard-coded gcc.
+    // For win32, there is no cc command,
+    // so we add a condition to make it use gcc.
+    match os {
+      session::os_android => ~"arm-linux-androideabi-g++",
+      session::os_win32 => ~"gcc",
+      _ => ~"cc"
+    }
+}
+
+/// The archiver used for static libraries on `os` when the target doesn't
+/// name one
+pub fn default_ar(os: session::os) -> ~str {
+    match os {
+      session::os_android => ~"arm-linux-androideabi-ar",
+      _ => ~"ar"
+    }
+}
+
+/// The prefix and suffix of dynamic library names on `os`
+pub fn default_dll_naming(os: session::os) -> (~str, ~str) {
+    let (dll_prefix, dll_suffix) = match os {
+      session::os_win32 => (win32::DLL_PREFIX, win32::DLL_SUFFIX),
+      session::os_macos => (macos::DLL_PREFIX, macos::DLL_SUFFIX),
+      session::os_linux => (linux::DLL_PREFIX, linux::DLL_SUFFIX),
+      session::os_android => (android::DLL_PREFIX, android::DLL_SUFFIX),
+      session::os_freebsd => (freebsd::DLL_PREFIX, freebsd::DLL_SUFFIX),
+    };
+    (str::from_slice(dll_prefix), str::from_slice(dll_suffix))
+}
+
+fn get_os(triple: &str) -> Option<session::os> {
+    if str::contains(triple, ~"win32") ||
+               str::contains(triple, ~"mingw32") {
+            Some(session::os_win32)
+        } else if str::contains(triple, ~"darwin") {
+            Some(session::os_macos)
+        } else if str::contains(triple, ~"linux") {
+            Some(session::os_linux)
+        } else if str::contains(triple, ~"android") {
+            Some(session::os_android)
+        } else if str::contains(triple, ~"freebsd") {
+            Some(session::os_freebsd)
+        } else { None }
+}
+
+fn get_arch(triple: &str) -> Option<session::arch> {
+    if str::contains(triple, ~"i386") ||
+        str::contains(triple, ~"i486") ||
+               str::contains(triple, ~"i586") ||
+               str::contains(triple, ~"i686") ||
+               str::contains(triple, ~"i786") {
+            Some(session::arch_x86)
+        } else if str::contains(triple, ~"x86_64") {
+            Some(session::arch_x86_64)
+        } else if str::contains(triple, ~"arm") ||
+                      str::contains(triple, ~"xscale") {
+            Some(session::arch_arm)
+        } else { None }
+}
+
+fn os_from_str(s: &~str) -> Option<session::os> {
+    match *s {
+      ~"win32" => Some(session::os_win32),
+      ~"macos" => Some(session::os_macos),
+      ~"linux" => Some(session::os_linux),
+      ~"android" => Some(session::os_android),
+      ~"freebsd" => Some(session::os_freebsd),
+      _ => None
+    }
+}
+
+fn arch_from_str(s: &~str) -> Option<session::arch> {
+    match *s {
+      ~"x86" => Some(session::arch_x86),
+      ~"x86_64" => Some(session::arch_x86_64),
+      ~"arm" => Some(session::arch_arm),
+      _ => None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::prelude::*;
+
+    use back::target_spec::*;
+    use driver::session;
+
+    use std::json;
+
+    fn parse(s: &str) -> Result<t, ~str> {
+        from_json(&json::from_str(s).get())
+    }
+
+    #[test]
+    fn test_minimal_spec() {
+        let spec = parse(~"{\"llvm-target\": \"arm-none-linux-gnueabi\", \
+                            \"data-layout\": \"e-p:32:32:32\", \
+                            \"target-word-size\": 32, \
+                            \"os\": \"linux\", \"arch\": \"arm\"}").get();
+        assert spec.llvm_target == ~"arm-none-linux-gnueabi";
+        assert spec.target_word_size == 32u;
+        assert spec.os == session::os_linux;
+        assert spec.arch == session::arch_arm;
+        assert spec.linker == ~"cc";
+        assert spec.linker_args.is_empty();
+        assert spec.ar == ~"ar";
+        assert spec.dll_prefix == ~"lib";
+        assert spec.dll_suffix == ~".so";
+    }
+
+    #[test]
+    fn test_full_spec() {
+        let spec = parse(~"{\"llvm-target\": \"arm-none-eabi\", \
+                            \"data-layout\": \"e-p:32:32:32\", \
+                            \"target-word-size\": 32, \
+                            \"os\": \"linux\", \"arch\": \"arm\", \
+                            \"linker\": \"arm-none-eabi-gcc\", \
+                            \"linker-args\": [\"-mthumb\"], \
+                            \"ar\": \"arm-none-eabi-ar\", \
+                            \"dll-prefix\": \"\", \
+                            \"dll-suffix\": \".elf\"}").get();
+        assert spec.linker == ~"arm-none-eabi-gcc";
+        assert spec.linker_args == ~[~"-mthumb"];
+        assert spec.ar == ~"arm-none-eabi-ar";
+        assert spec.dll_prefix == ~"";
+        assert spec.dll_suffix == ~".elf";
+    }
+
+    #[test]
+    fn test_bad_specs() {
+        assert parse(~"[]").is_err();
+        assert parse(~"{\"llvm-target\": \"arm-none-eabi\"}").get_err() ==
+            ~"missing `data-layout`";
+        assert parse(~"{\"llvm-target\": \"arm-none-eabi\", \
+                        \"data-layout\": \"e-p:32:32:32\", \
+                        \"target-word-size\": 16, \
+                        \"os\": \"linux\", \"arch\": \"arm\"}").get_err() ==
+            ~"`target-word-size` must be 32 or 64";
+        assert parse(~"{\"llvm-target\": \"arm-none-eabi\", \
+                        \"data-layout\": \"e-p:32:32:32\", \
+                        \"target-word-size\": 32, \
+                        \"os\": \"plan9\", \"arch\": \"arm\"}").get_err() ==
+            ~"unknown operating system `plan9`";
+    }
+
+    #[test]
+    fn test_builtin() {
+        let spec = builtin(~"x86_64-unknown-linux-gnu").get();
+        assert spec.llvm_target == ~"x86_64-unknown-linux-gnu";
+        assert spec.target_word_size == 64u;
+        assert spec.arch == session::arch_x86_64;
+        assert spec.linker_args == ~[~"-m64"];
+        assert builtin(~"x86_64-unknown-plan9").is_err();
+        assert builtin(~"mips-unknown-linux-gnu").is_err();
+    }
+}
@@ -14,5 +14,9 @@ type t = {
     meta_sect_name: ~str,
     data_layout: ~str,
     target_triple: ~str,
-    cc_args: ~[~str]
+    cc_args: ~[~str],
+    linker: ~str,
+    ar: ~str,
+    dll_prefix: ~str,
+    dll_suffix: ~str
 };
@@ -9,17 +9,12 @@
 // except according to those terms.
 
 
-use back::target_strs;
+use back::target_spec;
 use driver::session;
-use metadata::loader::meta_section_name;
-use session::sess_os_to_meta_os;
 
-fn get_target_strs(target_os: session::os) -> target_strs::t {
+fn get_target_spec(target_os: session::os) -> target_spec::t {
+    let (dll_prefix, dll_suffix) = target_spec::default_dll_naming(target_os);
     return {
-        module_asm: ~"",
-
-        meta_sect_name: meta_section_name(sess_os_to_meta_os(target_os)),
-
         data_layout: match target_os {
           session::os_macos => {
             ~"e-p:64:64:64-i1:8:8-i8:8:8-i16:16:16-i32:32:32-i64:64:64-"+
@@ -52,7 +47,7 @@ fn get_target_strs(target_os: session::os) -> target_strs::t {
           }
         },
 
-        target_triple: match target_os {
+        llvm_target: match target_os {
           session::os_macos => ~"x86_64-apple-darwin",
           session::os_win32 => ~"x86_64-pc-mingw32",
           session::os_linux => ~"x86_64-unknown-linux-gnu",
@@ -60,7 +55,21 @@ fn get_target_strs(target_os: session::os) -> target_strs::t {
           session::os_freebsd => ~"x86_64-unknown-freebsd",
         },
 
-        cc_args: ~[~"-m64"]
+        target_word_size: 64u,
+
+        os: target_os,
+
+        arch: session::arch_x86_64,
+
+        linker: target_spec::default_linker(target_os),
+
+        linker_args: ~[~"-m64"],
+
+        ar: target_spec::default_ar(target_os),
+
+        dll_prefix: dll_prefix,
+
+        dll_suffix: dll_suffix
     };
 }
 
@@ -11,7 +11,7 @@
 use core::prelude::*;
 
 use back::link;
-use back::{arm, x86, x86_64};
+use back::{lto, target_spec};
 use front;
 use lib::llvm::llvm;
 use metadata::{creader, cstore, filesearch};
@@ -189,6 +189,36 @@ impl compile_upto : cmp::Eq {
     pure fn ne(&self, other: &compile_upto) -> bool { !(*self).eq(other) }
 }
 
+// Gives the expander the macros exported by the crates used, which are
+// found as read_crates will find them later on.
+fn macro_loader(sess: Session) -> syntax::ext::expand::MacroLoader {
+    macro_loader_(sess, false)
+}
+
+// As macro_loader, but crates that can't be found are taken to export no
+// macros, for tools that expand a crate without linking it.
+fn lenient_macro_loader(sess: Session) -> syntax::ext::expand::MacroLoader {
+    macro_loader_(sess, true)
+}
+
+fn macro_loader_(sess: Session, skip_missing: bool)
+    -> syntax::ext::expand::MacroLoader {
+    |vi| {
+        let strs = &sess.targ_cfg.target_strs;
+        creader::load_exported_macros(
+            sess.diagnostic(),
+            sess.cstore,
+            sess.filesearch,
+            session::sess_os_to_meta_os(sess.targ_cfg.os),
+            {prefix: /*bad*/copy strs.dll_prefix,
+             suffix: /*bad*/copy strs.dll_suffix},
+            sess.opts.static || sess.opts.staticlib,
+            skip_missing,
+            sess.parse_sess.interner,
+            vi)
+    }
+}
+
 fn compile_upto(sess: Session, cfg: ast::crate_cfg,
                 input: input, upto: compile_upto,
                 outputs: Option<output_filenames>)
@@ -209,7 +239,7 @@ fn compile_upto(sess: Session, cfg: ast::crate_cfg,
 
     crate = time(time_passes, ~"expansion", ||
         syntax::ext::expand::expand_crate(sess.parse_sess, copy cfg,
-                                          crate));
+                                          crate, macro_loader(sess)));
 
     if upto == cu_expand { return {crate: crate, tcx: None}; }
 
@@ -225,13 +255,24 @@ fn compile_upto(sess: Session, cfg: ast::crate_cfg,
     let ast_map = time(time_passes, ~"ast indexing", ||
             syntax::ast_map::map_crate(sess.diagnostic(), *crate));
 
+    let strs = &sess.targ_cfg.target_strs;
     time(time_passes, ~"external crate/lib resolution", ||
         creader::read_crates(sess.diagnostic(), *crate, sess.cstore,
                              sess.filesearch,
                              session::sess_os_to_meta_os(sess.targ_cfg.os),
-                             sess.opts.static,
+                             {prefix: /*bad*/copy strs.dll_prefix,
+                              suffix: /*bad*/copy strs.dll_suffix},
+                             sess.opts.static || sess.opts.staticlib,
                              sess.parse_sess.interner));
 
+    match outputs {
+      Some(outputs) if sess.opts.dep_info.is_some() => {
+        time(time_passes, ~"writing dependency info", ||
+             write_dep_info(sess, outputs));
+      }
+      _ => ()
+    }
+
     let lang_items = time(time_passes, ~"language item collection", ||
          middle::lang_items::collect_language_items(crate, sess));
 
@@ -301,6 +342,21 @@ fn compile_upto(sess: Session, cfg: ast::crate_cfg,
         time(time_passes, ~"lint checking", ||
              lint::check_crate(ty_cx, crate));
 
+        time(time_passes, ~"dead code checking", ||
+             middle::dead::check_crate(ty_cx, method_map, exp_map2, crate));
+
+        match sess.opts.c_header {
+          Some(ref file) => {
+            let path = match *file {
+              Some(ref path) => copy *path,
+              None => outputs.obj_filename.with_filetype(~"h")
+            };
+            time(time_passes, ~"writing C header", ||
+                 middle::c_header::write_header(ty_cx, crate, &path));
+          }
+          None => ()
+        }
+
         if upto == cu_no_trans { return {crate: crate, tcx: Some(ty_cx)}; }
 
         let maps = {mutbl_map: mutbl_map,
@@ -318,25 +374,90 @@ fn compile_upto(sess: Session, cfg: ast::crate_cfg,
     };
 
 
+    if sess.opts.output_type == link::output_type_exe {
+        if sess.building_library && !sess.opts.staticlib {
+            // Kept for executables built with -Z lto
+            let lib = link::library_filename(sess, &outputs.out_filename,
+                                             link_meta);
+            time(time_passes, ~"writing bitcode", ||
+                 lto::write_bitcode(sess, llmod, &lib));
+        } else if sess.lto() && !sess.building_library {
+            time(time_passes, ~"LTO passes", ||
+                 lto::run(sess, llmod));
+        }
+    }
+
     time(time_passes, ~"LLVM passes", ||
         link::write::run_passes(sess, llmod,
                                 &outputs.obj_filename));
 
     let stop_after_codegen =
         sess.opts.output_type != link::output_type_exe ||
-        (sess.opts.static && sess.building_library)    ||
         sess.opts.jit;
 
     if stop_after_codegen { return {crate: crate, tcx: None}; }
 
-    time(time_passes, ~"linking", ||
-         link::link_binary(sess,
-                           &outputs.obj_filename,
-                           &outputs.out_filename, link_meta));
+    time(time_passes, ~"linking", || {
+        if sess.opts.staticlib {
+            link::link_staticlib(sess, &outputs.obj_filename,
+                                 &outputs.out_filename);
+        } else if sess.opts.static && sess.building_library {
+            link::link_rlib(sess, &outputs.obj_filename,
+                            &outputs.out_filename, link_meta);
+        } else {
+            link::link_binary(sess, &outputs.obj_filename,
+                              &outputs.out_filename, link_meta);
+        }
+    });
 
     return {crate: crate, tcx: None};
 }
 
+/**
+ * Writes a make rule that lists, as prerequisites of the output, every
+ * source file the parser has read, every file read by `include_str!` or
+ * `include_bin!` and every library that has been loaded.
+ */
+fn write_dep_info(sess: Session, outputs: output_filenames) {
+    let stop_after_codegen =
+        sess.opts.output_type != link::output_type_exe;
+    let target = if stop_after_codegen {
+        &outputs.obj_filename
+    } else {
+        &outputs.out_filename
+    };
+    let dep_path = match sess.opts.dep_info {
+      Some(Some(ref path)) => copy *path,
+      _ => target.with_filetype(~"d")
+    };
+
+    let mut deps = ~[];
+    for sess.codemap.files.each |fm| {
+        // Source that isn't from a file has a name like `<anon>`
+        if !fm.name.starts_with(~"<") && !deps.contains(&fm.name) {
+            deps.push(copy fm.name);
+        }
+    }
+    let included = vec::append(sess.parse_sess.included_files.get(),
+                               cstore::get_used_crate_files(sess.cstore));
+    for included.each |path| {
+        let name = path.to_str();
+        if !deps.contains(&name) { deps.push(move name); }
+    }
+
+    // Make separates prerequisites with spaces
+    let escape = |s: &str| str::replace(s, ~" ", ~"\\ ");
+    let rule = fmt!("%s: %s\n", escape(target.to_str()),
+                    str::connect(deps.map(|d| escape(*d)), ~" "));
+    match io::file_writer(&dep_path, ~[io::Create, io::Truncate]) {
+      Ok(writer) => writer.write_str(rule),
+      Err(ref e) => {
+        sess.fatal(fmt!("failed to write dependency info to %s: %s",
+                        dep_path.to_str(), *e));
+      }
+    }
+}
+
 fn compile_input(sess: Session, +cfg: ast::crate_cfg, input: input,
                  outdir: &Option<Path>, output: &Option<Path>) {
 
@@ -424,60 +545,36 @@ fn pretty_print_input(sess: Session, +cfg: ast::crate_cfg, input: input,
     }
 }
 
-fn get_os(triple: ~str) -> Option<session::os> {
-    if str::contains(triple, ~"win32") ||
-               str::contains(triple, ~"mingw32") {
-            Some(session::os_win32)
-        } else if str::contains(triple, ~"darwin") {
-            Some(session::os_macos)
-        } else if str::contains(triple, ~"linux") {
-            Some(session::os_linux)
-        } else if str::contains(triple, ~"android") {
-            Some(session::os_android)
-        } else if str::contains(triple, ~"freebsd") {
-            Some(session::os_freebsd)
-        } else { None }
-}
-
-fn get_arch(triple: ~str) -> Option<session::arch> {
-    if str::contains(triple, ~"i386") ||
-        str::contains(triple, ~"i486") ||
-               str::contains(triple, ~"i586") ||
-               str::contains(triple, ~"i686") ||
-               str::contains(triple, ~"i786") {
-            Some(session::arch_x86)
-        } else if str::contains(triple, ~"x86_64") {
-            Some(session::arch_x86_64)
-        } else if str::contains(triple, ~"arm") ||
-                      str::contains(triple, ~"xscale") {
-            Some(session::arch_arm)
-        } else { None }
-}
-
 fn build_target_config(sopts: @session::options,
                        demitter: diagnostic::emitter) -> @session::config {
-    let os = match get_os(sopts.target_triple) {
-      Some(os) => os,
-      None => early_error(demitter, ~"unknown operating system")
+    // A target is either one of the built-in triples or a JSON file
+    // describing it
+    let is_json = str::ends_with(sopts.target_triple, ".json");
+    let spec = if is_json {
+        target_spec::load(&Path(sopts.target_triple))
+    } else {
+        target_spec::builtin(sopts.target_triple)
     };
-    let arch = match get_arch(sopts.target_triple) {
-      Some(arch) => arch,
-      None => early_error(demitter,
-                          ~"unknown architecture: " + sopts.target_triple)
+    let spec = match move spec {
+      Ok(move spec) => spec,
+      Err(move e) => early_error(demitter, e)
     };
-    let (int_type, uint_type, float_type) = match arch {
-      session::arch_x86 => (ast::ty_i32, ast::ty_u32, ast::ty_f64),
-      session::arch_x86_64 => (ast::ty_i64, ast::ty_u64, ast::ty_f64),
-      session::arch_arm => (ast::ty_i32, ast::ty_u32, ast::ty_f64)
+    // The libraries for a JSON target are found under the triple it names,
+    // not the path of the file
+    let target_triple = if is_json {
+        copy spec.llvm_target
+    } else {
+        copy sopts.target_triple
     };
-    let target_strs = match arch {
-      session::arch_x86 => x86::get_target_strs(os),
-      session::arch_x86_64 => x86_64::get_target_strs(os),
-      session::arch_arm => arm::get_target_strs(os)
+    let (int_type, uint_type) = match spec.target_word_size {
+      32u => (ast::ty_i32, ast::ty_u32),
+      _ => (ast::ty_i64, ast::ty_u64)
     };
     let target_cfg: @session::config =
-        @{os: os, arch: arch, target_strs: target_strs, int_type: int_type,
-          uint_type: uint_type, float_type: float_type};
+        @{os: spec.os, arch: spec.arch,
+          target_triple: target_triple,
+          target_strs: target_spec::to_target_strs(&spec),
+          int_type: int_type, uint_type: uint_type, float_type: ast::ty_f64};
     return target_cfg;
 }
 
@@ -501,7 +598,11 @@ fn host_triple() -> ~str {
 fn build_session_options(+binary: ~str,
                          matches: &getopts::Matches,
                          demitter: diagnostic::emitter) -> @session::options {
-    let crate_type = if opt_present(matches, ~"lib") {
+    let staticlib = opt_present(matches, ~"staticlib");
+    if staticlib && opt_present(matches, ~"bin") {
+        early_error(demitter, ~"--staticlib and --bin both provided");
+    }
+    let crate_type = if opt_present(matches, ~"lib") || staticlib {
         session::lib_crate
     } else if opt_present(matches, ~"bin") {
         session::bin_crate
@@ -511,6 +612,13 @@ fn build_session_options(+binary: ~str,
     let static = opt_present(matches, ~"static");
     let gc = opt_present(matches, ~"gc");
 
+    match getopts::opt_maybe_str(matches, ~"error-format") {
+      Some(ref f) if *f != ~"human" && *f != ~"json" => {
+        early_error(demitter, fmt!("unknown error format: %s", *f));
+      }
+      _ => ()
+    }
+
     let parse_only = opt_present(matches, ~"parse-only");
     let no_trans = opt_present(matches, ~"no-trans");
 
@@ -614,9 +722,29 @@ fn build_session_options(+binary: ~str,
         .map(|s| Path(*s));
     let cfg = parse_cfgspecs(getopts::opt_strs(matches, ~"cfg"));
     let test = opt_present(matches, ~"test");
+    // --dep-info-file names the file and implies --dep-info; an optional
+    // argument to --dep-info itself would swallow the input file
+    let dep_info_file = getopts::opt_maybe_str(matches, ~"dep-info-file")
+        .map(|f| Path(*f));
+    let dep_info = if opt_present(matches, ~"dep-info") ||
+                      dep_info_file.is_some() {
+        Some(dep_info_file)
+    } else {
+        None
+    };
+    // Likewise for --c-header-file and --c-header
+    let c_header_file = getopts::opt_maybe_str(matches, ~"c-header-file")
+        .map(|f| Path(*f));
+    let c_header = if opt_present(matches, ~"c-header") ||
+                      c_header_file.is_some() {
+        Some(c_header_file)
+    } else {
+        None
+    };
     let sopts: @session::options =
         @{crate_type: crate_type,
           static: static,
+          staticlib: staticlib,
           gc: gc,
           optimize: opt_level,
           debuginfo: debuginfo,
@@ -633,6 +761,8 @@ fn build_session_options(+binary: ~str,
           test: test,
           parse_only: parse_only,
           no_trans: no_trans,
+          dep_info: dep_info,
+          c_header: c_header,
           debugging_opts: debugging_opts};
     return sopts;
 }
@@ -658,7 +788,7 @@ fn build_session_(sopts: @session::options,
     let cstore = cstore::mk_cstore(p_s.interner);
     let filesearch = filesearch::mk_filesearch(
         sopts.maybe_sysroot,
-        sopts.target_triple,
+        target_cfg.target_triple,
         /*bad*/copy sopts.addl_lib_search_paths);
     let lint_settings = lint::mk_lint_settings();
     Session_(@{targ_cfg: target_cfg,
@@ -695,10 +825,28 @@ fn optgroups() -> ~[getopts::groups::OptGroup] {
  ~[
   optflag(~"",  ~"bin", ~"Compile an executable crate (default)"),
   optflag(~"c", ~"",    ~"Compile and assemble, but do not link"),
+  optflag(~"", ~"c-header",
+                        ~"Write a C header declaring the #[no_mangle]
+                          extern functions and constants of the crate,
+                          next to the output with a .h extension"),
+  optopt(~"", ~"c-header-file",
+                        ~"Write the C header to FILE; implies
+                          --c-header", ~"FILE"),
   optmulti(~"", ~"cfg", ~"Configure the compilation
                           environment", ~"SPEC"),
+  optflag(~"", ~"dep-info",
+                        ~"Write a make-style dependency file listing the
+                          source files and libraries the output depends
+                          on, next to the output with a .d extension"),
+  optopt(~"", ~"dep-info-file",
+                        ~"Write the dependency file to FILE; implies
+                          --dep-info", ~"FILE"),
   optflag(~"",  ~"emit-llvm",
                         ~"Produce an LLVM bitcode file"),
+  optopt(~"",  ~"error-format",
+                        ~"How errors and other diagnostics are printed;
+                          valid formats are: human (the default) or
+                          json (one JSON object per line)", ~"FORMAT"),
   optflag(~"g", ~"",    ~"Produce debug info (experimental)"),
   optflag(~"",  ~"gc",  ~"Garbage collect shared data (experimental)"),
   optflag(~"h", ~"help",~"Display this message"),
@@ -733,6 +881,10 @@ fn optgroups() -> ~[getopts::groups::OptGroup] {
   optflag(~"", ~"static",
                         ~"Use or produce static libraries or binaries
                          (experimental)"),
+  optflag(~"", ~"staticlib",
+                        ~"Compile a static library for linking into C
+                          programs, bundling the crate and the Rust crates
+                          it uses, which must be built with --static"),
   optopt(~"", ~"sysroot",
                         ~"Override the system root", ~"PATH"),
   optflag(~"", ~"test", ~"Build a test harness"),
@@ -740,7 +892,8 @@ fn optgroups() -> ~[getopts::groups::OptGroup] {
                         ~"Target triple cpu-manufacturer-kernel[-os]
                           to compile for (see
          http://sources.redhat.com/autobook/autobook/autobook_17.html
-                          for detail)", ~"TRIPLE"),
+                          for detail), or a .json file describing
+                          the target", ~"TRIPLE"),
   optmulti(~"W", ~"warn",
                         ~"Set lint warnings", ~"OPT"),
   optmulti(~"A", ~"allow",
@@ -765,9 +918,7 @@ fn build_output_filenames(input: input,
     let obj_path;
     let out_path;
     let sopts = sess.opts;
-    let stop_after_codegen =
-        sopts.output_type != link::output_type_exe ||
-            sopts.static && sess.building_library;
+    let stop_after_codegen = sopts.output_type != link::output_type_exe;
 
 
     let obj_suffix =
@@ -798,8 +949,12 @@ fn build_output_filenames(input: input,
           str_input(_) => ~"rust_out"
         };
 
-        if sess.building_library {
-            out_path = dirpath.push(os::dll_filename(stem));
+        if sopts.staticlib {
+            out_path = dirpath.push(fmt!("lib%s.a", stem));
+            obj_path = dirpath.push(stem).with_filetype(obj_suffix);
+        } else if sess.building_library {
+            let strs = &sess.targ_cfg.target_strs;
+            out_path = dirpath.push(strs.dll_prefix + stem + strs.dll_suffix);
             obj_path = dirpath.push(stem).with_filetype(obj_suffix);
         } else {
             out_path = dirpath.push(stem);
@@ -850,10 +1005,15 @@ mod test {
 
     use driver::driver::{build_configuration, build_session};
     use driver::driver::{build_session_options, optgroups, str_input};
+    use driver::driver::{build_output_filenames, compile_upto, cu_parse};
+    use driver::driver::{file_input, write_dep_info};
 
+    use core::io;
+    use core::os;
     use core::vec;
     use std::getopts::groups::getopts;
     use std::getopts;
+    use std::tempfile;
     use syntax::attr;
     use syntax::diagnostic;
 
@@ -892,6 +1052,89 @@ mod test {
         let test_items = attr::find_meta_items_by_name(cfg, ~"test");
         assert (vec::len(test_items) == 1u);
     }
+
+    // --dep-info takes no argument, so it must leave the input file alone
+    #[test]
+    fn test_dep_info_leaves_input_file() {
+        let matches =
+            &match getopts(~[~"--dep-info", ~"foo.rs"], optgroups()) {
+              Ok(copy m) => m,
+              Err(copy f) => fail ~"test_dep_info_leaves_input_file: " +
+                             getopts::fail_str(f)
+            };
+        assert matches.free == ~[~"foo.rs"];
+        let sessopts = build_session_options(
+            ~"rustc", matches, diagnostic::emit);
+        assert sessopts.dep_info == Some(None);
+    }
+
+    #[test]
+    fn test_dep_info_file_implies_dep_info() {
+        let matches =
+            &match getopts(~[~"--dep-info-file", ~"foo.d", ~"foo.rs"],
+                           optgroups()) {
+              Ok(copy m) => m,
+              Err(copy f) => {
+                fail ~"test_dep_info_file_implies_dep_info: " +
+                    getopts::fail_str(f);
+              }
+            };
+        assert matches.free == ~[~"foo.rs"];
+        let sessopts = build_session_options(
+            ~"rustc", matches, diagnostic::emit);
+        assert sessopts.dep_info == Some(Some(Path("foo.d")));
+    }
+
+    #[test]
+    fn test_c_header_leaves_input_file() {
+        let matches =
+            &match getopts(~[~"--c-header", ~"foo.rs"], optgroups()) {
+              Ok(copy m) => m,
+              Err(copy f) => fail ~"test_c_header_leaves_input_file: " +
+                             getopts::fail_str(f)
+            };
+        assert matches.free == ~[~"foo.rs"];
+        let sessopts = build_session_options(
+            ~"rustc", matches, diagnostic::emit);
+        assert sessopts.c_header == Some(None);
+    }
+
+    // The dependency file names the output as the target of a rule whose
+    // prerequisites are the sources that were read
+    #[test]
+    fn test_write_dep_info() {
+        let dir = tempfile::mkdtemp(&os::tmpdir(), "dep-info").unwrap();
+        let src = dir.push("foo.rs");
+        let dep = dir.push("foo.d");
+        match io::file_writer(&src, ~[io::Create, io::Truncate]) {
+          Ok(writer) => writer.write_str(~"fn main() { }\n"),
+          Err(copy e) => fail ~"test_write_dep_info: " + e
+        }
+
+        let matches =
+            &match getopts(~[~"--dep-info-file", dep.to_str(),
+                             src.to_str()], optgroups()) {
+              Ok(copy m) => m,
+              Err(copy f) => fail ~"test_write_dep_info: " +
+                             getopts::fail_str(f)
+            };
+        let sessopts = build_session_options(
+            ~"rustc", matches, diagnostic::emit);
+        let sess = build_session(sessopts, diagnostic::emit);
+        let input = file_input(copy src);
+        let cfg = build_configuration(sess, ~"whatever", input);
+        compile_upto(sess, cfg, input, cu_parse, None);
+        let outputs = build_output_filenames(input, &None, &None, sess);
+        write_dep_info(sess, outputs);
+
+        let rule = io::read_whole_file_str(&dep).get();
+        assert rule == fmt!("%s: %s\n", outputs.out_filename.to_str(),
+                            src.to_str());
+
+        os::remove_file(&src);
+        os::remove_file(&dep);
+        os::remove_dir(&dir);
+    }
 }
 
 // Local Variables:
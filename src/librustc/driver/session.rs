@@ -52,6 +52,8 @@ enum crate_type { bin_crate, lib_crate, unknown_crate, }
 type config =
     {os: os,
      arch: arch,
+     // The triple the target's libraries are installed under
+     target_triple: ~str,
      target_strs: target_strs::t,
      int_type: int_ty,
      uint_type: uint_ty,
@@ -75,6 +77,7 @@ const count_type_sizes: uint = 1 << 14;
 const meta_stats: uint = 1 << 15;
 const no_opt: uint = 1 << 16;
 const no_monomorphic_collapse: uint = 1 << 17;
+const lto: uint = 1 << 18;
 
 fn debugging_opts_map() -> ~[(~str, ~str, uint)] {
     ~[(~"verbose", ~"in general, enable more debug printouts", verbose),
@@ -102,6 +105,7 @@ fn debugging_opts_map() -> ~[(~str, ~str, uint)] {
      (~"no-opt", ~"do not optimize, even if -O is passed", no_opt),
      (~"no-monomorphic-collapse", ~"do not collapse template instantiations",
       no_monomorphic_collapse),
+     (~"lto", ~"optimize executables across crates", lto),
     ]
 }
 
@@ -124,6 +128,8 @@ type options =
     // with additional crate configurations during the compile process
     {crate_type: crate_type,
      static: bool,
+     // Bundle the crate and its Rust dependencies into a static archive
+     staticlib: bool,
      gc: bool,
      optimize: OptLevel,
      debuginfo: bool,
@@ -144,6 +150,11 @@ type options =
      test: bool,
      parse_only: bool,
      no_trans: bool,
+     // Whether to write a dependency file, and where if not next to the
+     // output
+     dep_info: Option<Option<Path>>,
+     // Whether to write a C header, and where if not next to the output
+     c_header: Option<Option<Path>>,
      debugging_opts: uint,
     };
 
@@ -258,6 +269,7 @@ impl Session {
     fn no_monomorphic_collapse() -> bool {
         self.debugging_opt(no_monomorphic_collapse)
     }
+    fn lto() -> bool { self.debugging_opt(lto) }
 
     fn str_of(id: ast::ident) -> ~str {
         /*bad*/copy *self.parse_sess.interner.get(id)
@@ -275,6 +287,7 @@ fn basic_options() -> @options {
     @{
         crate_type: session::lib_crate,
         static: false,
+        staticlib: false,
         gc: false,
         optimize: No,
         debuginfo: false,
@@ -291,6 +304,8 @@ fn basic_options() -> @options {
         test: false,
         parse_only: false,
         no_trans: false,
+        dep_info: None,
+        c_header: None,
         debugging_opts: 0u
     }
 }
@@ -363,7 +378,8 @@ mod test {
         @ast_util::respan(ast_util::dummy_sp(), ast::crate_ {
             module: ast::_mod { view_items: ~[], items: ~[] },
             attrs: attrs,
-            config: ~[]
+            config: ~[],
+            exported_macros: ~[]
         })
     }
 
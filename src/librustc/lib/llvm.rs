@@ -1147,6 +1147,10 @@ extern mod llvm {
     unsafe fn LLVMPassManagerBuilderPopulateFunctionPassManager
         (PMB: PassManagerBuilderRef, PM: PassManagerRef);
 
+    unsafe fn LLVMPassManagerBuilderPopulateLTOPassManager
+        (PMB: PassManagerBuilderRef, PM: PassManagerRef,
+         Internalize: bool, RunInliner: bool);
+
     /** Destroys a memory buffer. */
     unsafe fn LLVMDisposeMemoryBuffer(MemBuf: MemoryBufferRef);
 
@@ -1209,6 +1213,10 @@ extern mod llvm {
     /** Parses the bitcode in the given memory buffer. */
     unsafe fn LLVMRustParseBitcode(MemBuf: MemoryBufferRef) -> ModuleRef;
 
+    /** Links the source module into the destination module, leaving the
+        source module unusable. Returns false on failure. */
+    unsafe fn LLVMRustLinkModules(Dest: ModuleRef, Src: ModuleRef) -> bool;
+
     /** Parses LLVM asm in the given file */
     unsafe fn LLVMRustParseAssemblyFile(Filename: *c_char) -> ModuleRef;
 
@@ -152,5 +152,13 @@ const tag_lang_items_item: uint = 0x73;
 const tag_lang_items_item_id: uint = 0x74;
 const tag_lang_items_item_node_id: uint = 0x75;
 
+// The macros a crate exports with #[macro_export]. Hierarchy:
+//
+// tag_exported_macros
+// - tag_macro_def: the source of a `macro_rules!` item
+
+const tag_exported_macros: uint = 0x76;
+const tag_macro_def: uint = 0x77;
+
 type link_meta = {name: @str, vers: @str, extras_hash: @str};
 
@@ -33,6 +33,7 @@ use syntax::{ast, ast_util};
 use std::map::HashMap;
 
 export read_crates;
+export load_exported_macros;
 
 // Traverses an AST, reading all the information about use'd crates and extern
 // libraries necessary for later resolving, typechecking, linking, etc.
@@ -41,12 +42,14 @@ fn read_crates(diag: span_handler,
                cstore: cstore::CStore,
                filesearch: FileSearch,
                os: loader::os,
+               dll_naming: {prefix: ~str, suffix: ~str},
                static: bool,
                intr: @ident_interner) {
     let e = @{diag: diag,
               filesearch: filesearch,
               cstore: cstore,
               os: os,
+              dll_naming: dll_naming,
               static: static,
               crate_cache: DVec(),
               mut next_crate_num: 1,
@@ -61,6 +64,50 @@ fn read_crates(diag: span_handler,
     warn_if_multiple_versions(e, diag, e.crate_cache.get());
 }
 
+// Finds the crate an `extern mod` refers to and returns the source of the
+// macros it exports. This runs during expansion, before read_crates, so the
+// crate is kept in the cstore for read_crates to use. A crate that can't be
+// found is an error unless `skip_missing` is set, in which case it is taken
+// to export no macros.
+fn load_exported_macros(diag: span_handler,
+                        cstore: cstore::CStore,
+                        filesearch: FileSearch,
+                        os: loader::os,
+                        dll_naming: {prefix: ~str, suffix: ~str},
+                        static: bool,
+                        skip_missing: bool,
+                        intr: @ident_interner,
+                        i: @ast::view_item) -> ~[~str] {
+    match /*bad*/copy i.node {
+      ast::view_item_use(ident, meta_items, id) => {
+        let load_ctxt: loader::ctxt = {
+            diag: diag,
+            filesearch: filesearch,
+            span: i.span,
+            ident: ident,
+            metas: metas_with_ident(/*bad*/copy *intr.get(ident), meta_items),
+            hash: ~"",
+            os: os,
+            dll_naming: dll_naming,
+            static: static,
+            intr: intr
+        };
+        let cinfo = if skip_missing {
+            match loader::find_library_crate(load_ctxt) {
+              Some(move cinfo) => cinfo,
+              None => return ~[]
+            }
+        } else {
+            loader::load_library_crate(load_ctxt)
+        };
+        let macros = decoder::get_exported_macros(cinfo.data);
+        cstore::add_loaded_crate(cstore, id, cinfo);
+        macros
+      }
+      _ => ~[]
+    }
+}
+
 type cache_entry = {
     cnum: int,
     span: span,
@@ -118,6 +165,7 @@ type env = @{diag: span_handler,
              filesearch: FileSearch,
              cstore: cstore::CStore,
              os: loader::os,
+             dll_naming: {prefix: ~str, suffix: ~str},
              static: bool,
              crate_cache: DVec<cache_entry>,
              mut next_crate_num: ast::crate_num,
@@ -127,7 +175,10 @@ fn visit_view_item(e: env, i: @ast::view_item) {
     match /*bad*/copy i.node {
       ast::view_item_use(ident, meta_items, id) => {
         debug!("resolving use stmt. ident: %?, meta: %?", ident, meta_items);
-        let cnum = resolve_crate(e, ident, meta_items, ~"", i.span);
+        // The crate may have been loaded already to find its macros
+        let preloaded = cstore::find_loaded_crate(e.cstore, id);
+        let cnum = resolve_crate(e, ident, meta_items, ~"", i.span,
+                                 preloaded);
         cstore::add_use_stmt_cnum(e.cstore, id, cnum);
       }
       _ => ()
@@ -217,7 +268,9 @@ fn existing_match(e: env, metas: ~[@ast::meta_item], hash: ~str) ->
 }
 
 fn resolve_crate(e: env, ident: ast::ident, +metas: ~[@ast::meta_item],
-                 +hash: ~str, span: span) -> ast::crate_num {
+                 +hash: ~str, span: span,
+                 +preloaded: Option<{ident: ~str, data: @~[u8]}>)
+    -> ast::crate_num {
     let metas = metas_with_ident(/*bad*/copy *e.intr.get(ident), metas);
 
     match existing_match(e, metas, hash) {
@@ -230,10 +283,14 @@ fn resolve_crate(e: env, ident: ast::ident, +metas: ~[@ast::meta_item],
             metas: metas,
             hash: hash,
             os: e.os,
+            dll_naming: /*bad*/copy e.dll_naming,
             static: e.static,
             intr: e.intr
         };
-        let cinfo = loader::load_library_crate(load_ctxt);
+        let cinfo = match move preloaded {
+          Some(move cinfo) => cinfo,
+          None => loader::load_library_crate(load_ctxt)
+        };
 
         let cfilename = Path(cinfo.ident);
         let cdata = cinfo.data;
@@ -297,7 +354,8 @@ fn resolve_crate_deps(e: env, cdata: @~[u8]) -> cstore::cnum_map {
             // span.
             let fake_span = ast_util::dummy_sp();
             let local_cnum = resolve_crate(e, cname, cmetas,
-                                           /*bad*/copy dep.hash, fake_span);
+                                           /*bad*/copy dep.hash, fake_span,
+                                           None);
             cnum_map.insert(extrn_cnum, local_cnum);
           }
         }
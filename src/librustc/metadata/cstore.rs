@@ -45,6 +45,8 @@ export add_used_link_args;
 export get_used_link_args;
 export add_use_stmt_cnum;
 export find_use_stmt_cnum;
+export add_loaded_crate;
+export find_loaded_crate;
 export get_dep_hashes;
 
 
@@ -69,6 +71,7 @@ enum CStore { private(cstore_private), }
 type cstore_private =
     @{metas: map::HashMap<ast::crate_num, crate_metadata>,
       use_crate_map: use_crate_map,
+      loaded_crate_map: loaded_crate_map,
       mut used_crate_files: ~[Path],
       mut used_libraries: ~[~str],
       mut used_link_args: ~[~str],
@@ -77,6 +80,11 @@ type cstore_private =
 // Map from node_id's of local use statements to crate numbers
 type use_crate_map = map::HashMap<ast::node_id, ast::crate_num>;
 
+// Map from node_id's of local use statements to the crates that were
+// loaded for them before they were resolved to crate numbers
+type loaded_crate_map =
+    map::HashMap<ast::node_id, {ident: ~str, data: @~[u8]}>;
+
 // Internal method to retrieve the data from the cstore
 pure fn p(cstore: CStore) -> cstore_private {
     match cstore { private(p) => p }
@@ -87,6 +95,7 @@ fn mk_cstore(intr: @ident_interner) -> CStore {
     let crate_map = map::HashMap();
     return private(@{metas: meta_cache,
                      use_crate_map: crate_map,
+                     loaded_crate_map: map::HashMap(),
                      mut used_crate_files: ~[],
                      mut used_libraries: ~[],
                      mut used_link_args: ~[],
@@ -161,6 +170,16 @@ fn find_use_stmt_cnum(cstore: CStore,
     p(cstore).use_crate_map.find(use_id)
 }
 
+fn add_loaded_crate(cstore: CStore, use_id: ast::node_id,
+                    +cinfo: {ident: ~str, data: @~[u8]}) {
+    p(cstore).loaded_crate_map.insert(use_id, cinfo);
+}
+
+fn find_loaded_crate(cstore: CStore, use_id: ast::node_id)
+    -> Option<{ident: ~str, data: @~[u8]}> {
+    p(cstore).loaded_crate_map.find(use_id)
+}
+
 // returns hashes of crates directly used by this crate. Hashes are
 // sorted by crate name.
 fn get_dep_hashes(cstore: CStore) -> ~[~str] {
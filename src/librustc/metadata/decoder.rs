@@ -76,6 +76,7 @@ export dl_def;
 export dl_impl;
 export dl_field;
 export each_lang_item;
+export get_exported_macros;
 export each_path;
 export get_item_path;
 export maybe_find_item; // sketchy
@@ -1087,6 +1088,20 @@ fn list_crate_deps(intr: @ident_interner, data: @~[u8], out: io::Writer) {
     out.write_str(~"\n");
 }
 
+fn get_exported_macros(data: @~[u8]) -> ~[~str] {
+    let cratedoc = reader::Doc(data);
+    // Crates built before macros were exported have no such tag
+    let macros = match reader::maybe_get_doc(cratedoc, tag_exported_macros) {
+      Some(macros) => macros,
+      None => return ~[]
+    };
+    let mut result = ~[];
+    for reader::tagged_docs(macros, tag_macro_def) |macro_doc| {
+        result.push(str::from_bytes(reader::doc_data(macro_doc)));
+    }
+    return result;
+}
+
 fn get_crate_hash(data: @~[u8]) -> ~str {
     let cratedoc = reader::Doc(data);
     let hashdoc = reader::get_doc(cratedoc, tag_crate_hash);
@@ -88,6 +88,7 @@ type stats = {
     mut attr_bytes: uint,
     mut dep_bytes: uint,
     mut lang_item_bytes: uint,
+    mut macro_def_bytes: uint,
     mut item_bytes: uint,
     mut index_bytes: uint,
     mut zero_bytes: uint,
@@ -1133,6 +1134,22 @@ fn encode_lang_items(ecx: @encode_ctxt, ebml_w: writer::Encoder) {
     ebml_w.end_tag();   // tag_lang_items
 }
 
+// Macros are stored as the source of their `macro_rules!` item, which the
+// crates using them parse again.
+fn encode_exported_macros(ecx: @encode_ctxt, ebml_w: writer::Encoder,
+                          crate: &crate) {
+    ebml_w.start_tag(tag_exported_macros);
+
+    for crate.node.exported_macros.each |item| {
+        let source = ecx.tcx.sess.codemap.span_to_snippet(item.span);
+        ebml_w.start_tag(tag_macro_def);
+        ebml_w.writer.write(str::to_bytes(source));
+        ebml_w.end_tag();   // tag_macro_def
+    }
+
+    ebml_w.end_tag();   // tag_exported_macros
+}
+
 fn encode_crate_dep(ecx: @encode_ctxt, ebml_w: writer::Encoder,
                     dep: decoder::crate_dep) {
     ebml_w.start_tag(tag_crate_dep);
@@ -1168,6 +1185,7 @@ fn encode_metadata(parms: encode_parms, crate: &crate) -> ~[u8] {
          mut attr_bytes: 0,
          mut dep_bytes: 0,
          mut lang_item_bytes: 0,
+         mut macro_def_bytes: 0,
          mut item_bytes: 0,
          mut index_bytes: 0,
          mut zero_bytes: 0,
@@ -1205,6 +1223,11 @@ fn encode_metadata(parms: encode_parms, crate: &crate) -> ~[u8] {
     encode_lang_items(ecx, ebml_w);
     ecx.stats.lang_item_bytes = wr.pos - i;
 
+    // Encode the macros this crate exports.
+    i = wr.pos;
+    encode_exported_macros(ecx, ebml_w, crate);
+    ecx.stats.macro_def_bytes = wr.pos - i;
+
     // Encode and index the items.
     ebml_w.start_tag(tag_items);
     i = wr.pos;
@@ -1235,6 +1258,7 @@ fn encode_metadata(parms: encode_parms, crate: &crate) -> ~[u8] {
         io::println(fmt!(" attribute bytes: %u", ecx.stats.attr_bytes));
         io::println(fmt!("       dep bytes: %u", ecx.stats.dep_bytes));
         io::println(fmt!(" lang item bytes: %u", ecx.stats.lang_item_bytes));
+        io::println(fmt!(" macro def bytes: %u", ecx.stats.macro_def_bytes));
         io::println(fmt!("      item bytes: %u", ecx.stats.item_bytes));
         io::println(fmt!("     index bytes: %u", ecx.stats.index_bytes));
         io::println(fmt!("      zero bytes: %u", ecx.stats.zero_bytes));
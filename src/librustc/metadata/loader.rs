@@ -28,7 +28,6 @@ use core::cast;
 use core::flate;
 use core::io::WriterUtil;
 use core::io;
-use core::os::consts::{macos, freebsd, linux, android, win32};
 use core::option;
 use core::ptr;
 use core::str;
@@ -39,6 +38,7 @@ export os;
 export os_macos, os_win32, os_linux, os_freebsd, os_android;
 export ctxt;
 export load_library_crate;
+export find_library_crate;
 export list_file_metadata;
 export note_linkage_attrs;
 export crate_name_from_metas;
@@ -61,6 +61,7 @@ type ctxt = {
     metas: ~[@ast::meta_item],
     hash: ~str,
     os: os,
+    dll_naming: {prefix: ~str, suffix: ~str},
     static: bool,
     intr: @ident_interner
 };
@@ -83,17 +84,7 @@ fn find_library_crate(cx: ctxt) -> Option<{ident: ~str, data: @~[u8]}> {
 
 fn libname(cx: ctxt) -> {prefix: ~str, suffix: ~str} {
     if cx.static { return {prefix: ~"lib", suffix: ~".rlib"}; }
-    let (dll_prefix, dll_suffix) = match cx.os {
-        os_win32 => (win32::DLL_PREFIX, win32::DLL_SUFFIX),
-        os_macos => (macos::DLL_PREFIX, macos::DLL_SUFFIX),
-        os_linux => (linux::DLL_PREFIX, linux::DLL_SUFFIX),
-        os_android => (android::DLL_PREFIX, android::DLL_SUFFIX),
-        os_freebsd => (freebsd::DLL_PREFIX, freebsd::DLL_SUFFIX),
-    };
-    return {
-        prefix: str::from_slice(dll_prefix),
-        suffix: str::from_slice(dll_suffix)
-    }
+    return /*bad*/copy cx.dll_naming;
 }
 
 fn find_library_crate_aux(cx: ctxt,
@@ -347,6 +347,7 @@ impl check_loan_ctxt {
               }
             }
         }
+        self.bccx.add_to_used_mut_nodes(cmt);
 
         // if this is a pure function, only loan-able state can be
         // assigned, because it is uniquely tied to this function and
@@ -75,6 +75,7 @@ impl LoanContext {
                         return Err({cmt:cmt,
                                     code:err_mutbl(req_mutbl)});
                     }
+                    self.bccx.add_to_used_mut_nodes(cmt);
                 }
                 m_const | m_imm => {
                     // However, mutable data can be loaned out as
@@ -545,6 +545,21 @@ impl borrowck_ctxt {
         }
     }
 
+    /// Records that the local variable `cmt` is rooted in, if any, needs
+    /// to be mutable.
+    fn add_to_used_mut_nodes(cmt: cmt) {
+        match cmt.cat {
+          cat_local(id) => {
+            self.tcx.used_mut_nodes.insert(id, ());
+          }
+          cat_stack_upvar(base) | cat_comp(base, _) | cat_discr(base, _) |
+          cat_deref(base, _, uniq_ptr) => {
+            self.add_to_used_mut_nodes(base);
+          }
+          _ => ()
+        }
+    }
+
     fn bckerr_to_str(err: bckerr) -> ~str {
         match err.code {
             err_mutbl(req) => {
@@ -0,0 +1,295 @@
+// Copyright 2013 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Writes a C header declaring the `#[no_mangle]` extern functions and
+// constants of a crate, together with the structs and C-like enums they
+// use. As with the `ctypes` lint, `int` and `uint` are pointer-sized, so
+// they become `intptr_t` and `uintptr_t`. A type that has no C equivalent
+// is an error.
+
+use core::prelude::*;
+
+use middle::const_eval;
+use middle::ty;
+use util::ppaux::ty_to_str;
+
+use core::char;
+use core::dvec::DVec;
+use core::float;
+use core::i64;
+use core::io::WriterUtil;
+use core::io;
+use core::str;
+use core::u64;
+use core::vec;
+use std::map::HashMap;
+use syntax::ast::*;
+use syntax::ast_map;
+use syntax::attr;
+use syntax::codemap::span;
+use syntax::parse::token::special_idents;
+use syntax::visit;
+
+struct ctx {
+    tcx: ty::ctxt,
+    // Structs and enums that have been given a name so far
+    named: HashMap<def_id, ()>,
+    // `typedef`s for every struct, so that they can refer to each other
+    typedefs: DVec<~str>,
+    // Struct and enum definitions, each after the types it uses
+    definitions: DVec<~str>,
+    // `#define`s for constants and function prototypes
+    items: DVec<~str>,
+}
+
+impl ctx {
+    fn unrepresentable(sp: span, t: ty::t) {
+        self.tcx.sess.span_err(sp, fmt!("type `%s` can't be represented in \
+                                         C", ty_to_str(self.tcx, t)));
+    }
+
+    fn str_of(id: ident) -> ~str { self.tcx.sess.str_of(id) }
+
+    fn item_name(did: def_id) -> ~str {
+        match self.tcx.items.get(did.node) {
+          ast_map::node_item(item, _) => self.str_of(item.ident),
+          _ => self.tcx.sess.bug(~"struct or enum is not an item")
+        }
+    }
+}
+
+pub fn write_header(tcx: ty::ctxt, crate: @crate, path: &Path) {
+    let cx = @ctx {
+        tcx: tcx,
+        named: HashMap(),
+        typedefs: DVec(),
+        definitions: DVec(),
+        items: DVec()
+    };
+    let v = visit::mk_simple_visitor(@visit::SimpleVisitor {
+        visit_item: |item| check_item(cx, item),
+        .. *visit::default_simple_visitor()
+    });
+    visit::visit_crate(*crate, (), v);
+    tcx.sess.abort_if_errors();
+
+    let stem = match path.filestem() {
+      Some(stem) => stem,
+      None => ~"rust"
+    };
+    let guard = str::to_upper(str::map(stem, |c| {
+        if char::is_alphanumeric(c) { c } else { '_' }
+    })) + ~"_H";
+
+    let mut lines = ~[
+        ~"/* Generated by rustc. Changes will be overwritten. */",
+        ~"",
+        ~"#ifndef " + guard,
+        ~"#define " + guard,
+        ~"",
+        ~"#include <stdbool.h>",
+        ~"#include <stdint.h>",
+        ~"",
+        ~"#ifdef __cplusplus",
+        ~"extern \"C\" {",
+        ~"#endif",
+        ~""
+    ];
+    for [cx.typedefs.get(), cx.definitions.get(), cx.items.get()].each
+        |section| {
+        if !section.is_empty() {
+            lines.push_all(*section);
+            lines.push(~"");
+        }
+    }
+    lines.push_all(~[
+        ~"#ifdef __cplusplus",
+        ~"}",
+        ~"#endif",
+        ~"",
+        ~"#endif"
+    ]);
+
+    match io::file_writer(path, ~[io::Create, io::Truncate]) {
+      Ok(writer) => {
+        for lines.each |line| { writer.write_line(*line); }
+      }
+      Err(ref e) => {
+        tcx.sess.fatal(fmt!("failed to write C header to %s: %s",
+                            path.to_str(), *e));
+      }
+    }
+}
+
+fn check_item(cx: @ctx, item: @item) {
+    if !attr::attrs_contains_name(item.attrs, ~"no_mangle") { return; }
+    let name = cx.str_of(item.ident);
+    match item.node {
+      item_fn(ref decl, extern_fn, ref tps, _) => {
+        if !tps.is_empty() {
+            cx.tcx.sess.span_err(item.span, ~"generic functions can't be \
+                                              declared in a C header");
+            return;
+        }
+        let fty = ty::node_id_to_type(cx.tcx, item.id);
+        let mut params = ~[];
+        for vec::each2(ty::ty_fn_args(fty), decl.inputs) |arg, input| {
+            let name = match input.pat.node {
+              pat_ident(_, path, None) => cx.str_of(vec::last(path.idents)),
+              _ => ~""
+            };
+            // C would pass an array by pointer
+            if is_fixed_vec(arg.ty) {
+                cx.unrepresentable(input.ty.span, arg.ty);
+            }
+            params.push(declare(cx, arg.ty, name, input.ty.span));
+        }
+        if params.is_empty() { params.push(~"void"); }
+
+        let ret = ty::ty_fn_ret(fty);
+        if is_fixed_vec(ret) { cx.unrepresentable(decl.output.span, ret); }
+        let proto = fmt!("%s(%s)", name, str::connect(params, ~", "));
+        cx.items.push(declare(cx, ret, proto, decl.output.span) + ~";");
+      }
+      item_const(const_ty, expr) => {
+        let t = ty::node_id_to_type(cx.tcx, item.id);
+        let value = match const_eval::eval_const_expr_partial(cx.tcx, expr) {
+          Ok(const_eval::const_int(i)) => i64::str(i) + ~"LL",
+          Ok(const_eval::const_uint(u)) => u64::str(u) + ~"ULL",
+          Ok(const_eval::const_float(f)) => float::to_str(f as float, 17u),
+          Ok(const_eval::const_bool(b)) => if b { ~"true" } else { ~"false" },
+          Ok(const_eval::const_str(_)) => {
+            cx.unrepresentable(const_ty.span, t);
+            return;
+          }
+          Err(ref e) => {
+            cx.tcx.sess.span_err(expr.span, copy *e);
+            return;
+          }
+        };
+        cx.items.push(fmt!("#define %s ((%s)%s)", name,
+                           declare(cx, t, ~"", const_ty.span), value));
+      }
+      _ => ()
+    }
+}
+
+fn is_fixed_vec(t: ty::t) -> bool {
+    match ty::get(t).sty {
+      ty::ty_evec(_, ty::vstore_fixed(_)) => true,
+      _ => false
+    }
+}
+
+/**
+ * The C declaration of `name` with type `t`, or just the C type if `name`
+ * is empty. Any struct or enum `t` uses is defined along the way.
+ */
+fn declare(cx: @ctx, t: ty::t, name: ~str, sp: span) -> ~str {
+    let base = |c_ty: &str| {
+        if name.is_empty() { str::from_slice(c_ty) }
+        else { fmt!("%s %s", c_ty, name) }
+    };
+    match ty::get(t).sty {
+      ty::ty_nil | ty::ty_bot => base("void"),
+      ty::ty_bool => base("bool"),
+      ty::ty_int(it) => base(match it {
+        ty_i => "intptr_t",
+        ty_char => "uint32_t",
+        ty_i8 => "int8_t",
+        ty_i16 => "int16_t",
+        ty_i32 => "int32_t",
+        ty_i64 => "int64_t"
+      }),
+      ty::ty_uint(ut) => base(match ut {
+        ty_u => "uintptr_t",
+        ty_u8 => "uint8_t",
+        ty_u16 => "uint16_t",
+        ty_u32 => "uint32_t",
+        ty_u64 => "uint64_t"
+      }),
+      ty::ty_float(ty_f32) => base("float"),
+      ty::ty_float(_) => base("double"),
+      ty::ty_ptr(mt) => {
+        let name = if is_fixed_vec(mt.ty) { fmt!("(*%s)", name) }
+                   else { ~"*" + name };
+        let decl = declare(cx, mt.ty, name, sp);
+        match ty::get(mt.ty).sty {
+          ty::ty_ptr(_) | ty::ty_evec(*) => decl,
+          _ if mt.mutbl == m_imm => ~"const " + decl,
+          _ => decl
+        }
+      }
+      ty::ty_evec(mt, ty::vstore_fixed(n)) => {
+        declare(cx, mt.ty, fmt!("%s[%u]", name, n), sp)
+      }
+      ty::ty_struct(did, ref substs) if did.crate == local_crate &&
+                                        substs.tps.is_empty() &&
+                                        !ty::has_dtor(cx.tcx, did) => {
+        define_struct(cx, did, sp);
+        base(cx.item_name(did))
+      }
+      ty::ty_enum(did, _) if ty::enum_variants(cx.tcx, did).len() == 0u => {
+        // Like `libc::c_void`, only useful behind a pointer
+        base("void")
+      }
+      ty::ty_enum(did, ref substs) if did.crate == local_crate &&
+                                      substs.tps.is_empty() &&
+                                      ty::type_is_c_like_enum(cx.tcx, t) => {
+        define_enum(cx, did);
+        base(cx.item_name(did))
+      }
+      _ => {
+        cx.unrepresentable(sp, t);
+        base("void")
+      }
+    }
+}
+
+fn define_struct(cx: @ctx, did: def_id, sp: span) {
+    if cx.named.contains_key(did) { return; }
+    cx.named.insert(did, ());
+    let name = cx.item_name(did);
+    cx.typedefs.push(fmt!("typedef struct %s %s;", name, name));
+
+    let fields = ty::lookup_struct_fields(cx.tcx, did);
+    if fields.is_empty() {
+        cx.tcx.sess.span_err(sp, fmt!("struct `%s` has no fields and \
+                                       can't be represented in C", name));
+        return;
+    }
+    let substs = {self_r: None, self_ty: None, tps: ~[]};
+    let mut lines = ~[fmt!("struct %s {", name)];
+    for vec::eachi(fields) |i, field| {
+        let field_name = if field.ident == special_idents::unnamed_field {
+            fmt!("_%u", i)
+        } else {
+            cx.str_of(field.ident)
+        };
+        let t = ty::lookup_field_type(cx.tcx, did, field.id, &substs);
+        lines.push(fmt!("    %s;", declare(cx, t, field_name, sp)));
+    }
+    lines.push(~"};");
+    cx.definitions.push(str::connect(lines, ~"\n"));
+}
+
+// C-like enums are represented as an `int`, and their variants as
+// constants prefixed by the name of the enum
+fn define_enum(cx: @ctx, did: def_id) {
+    if cx.named.contains_key(did) { return; }
+    cx.named.insert(did, ());
+    let name = cx.item_name(did);
+    let mut lines = ~[fmt!("typedef intptr_t %s;", name)];
+    for vec::each(*ty::enum_variants(cx.tcx, did)) |variant| {
+        lines.push(fmt!("#define %s_%s ((%s)%d)", name,
+                        cx.str_of(variant.name), name, variant.disr_val));
+    }
+    cx.definitions.push(str::connect(lines, ~"\n"));
+}
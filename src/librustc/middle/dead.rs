@@ -0,0 +1,277 @@
+// Copyright 2013 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// A pass that reports private functions, constants, struct fields and
+// enum variants that can never be used, under the `dead_code` lint.
+//
+// As in trans::reachable, everything that can be used from outside the
+// crate is live: `main`, public and exported items, lang items, tests
+// and the methods of trait implementations. Starting from those, the
+// paths, field accesses and method calls in the body of each live item
+// make whatever they refer to live as well. A field is only live if it
+// is read, and a variant only if it is constructed; matching on them
+// does not count.
+
+use core::prelude::*;
+
+use middle::lint::dead_code;
+use middle::resolve;
+use middle::ty;
+use middle::typeck;
+
+use core::dvec::DVec;
+use core::str;
+use std::map::HashMap;
+use syntax::ast::*;
+use syntax::ast_map;
+use syntax::ast_util::{def_id_of_def, has_legacy_export_attr};
+use syntax::attr;
+use syntax::codemap::span;
+use syntax::visit;
+
+struct ctx {
+    tcx: ty::ctxt,
+    method_map: typeck::method_map,
+    live: HashMap<node_id, ()>,
+    worklist: DVec<node_id>,
+}
+
+impl ctx {
+    fn mark(id: node_id) {
+        if !self.live.contains_key(id) {
+            self.live.insert(id, ());
+            self.worklist.push(id);
+        }
+    }
+
+    fn mark_def_id(did: def_id) {
+        if did.crate == local_crate { self.mark(did.node); }
+    }
+
+    fn mark_def(def: def) {
+        match def {
+          def_variant(enum_id, variant_id) => {
+            self.mark_def_id(enum_id);
+            self.mark_def_id(variant_id);
+          }
+          def_fn(*) | def_static_method(*) | def_const(*) | def_ty(*) |
+          def_struct(*) => {
+            self.mark_def_id(def_id_of_def(def));
+          }
+          _ => ()
+        }
+    }
+
+    fn mark_path(id: node_id) {
+        match self.tcx.def_map.find(id) {
+          Some(def) => self.mark_def(def),
+          None => ()
+        }
+    }
+
+    fn mark_method(id: node_id) {
+        match self.method_map.find(id) {
+          Some(typeck::method_map_entry {
+              origin: typeck::method_static(did),
+              _
+          }) => {
+            self.mark_def_id(did);
+          }
+          _ => ()
+        }
+    }
+
+    fn mark_field(t: ty::t, ident: ident) {
+        match ty::get(ty::type_autoderef(self.tcx, t)).sty {
+          ty::ty_struct(did, _) if did.crate == local_crate => {
+            for ty::lookup_struct_fields(self.tcx, did).each |field| {
+                if field.ident == ident { self.mark_def_id(field.id); }
+            }
+          }
+          _ => ()
+        }
+    }
+
+    fn is_live(id: node_id) -> bool { self.live.contains_key(id) }
+}
+
+pub fn check_crate(tcx: ty::ctxt, method_map: typeck::method_map,
+                   exp_map2: resolve::ExportMap2, crate: @crate) {
+    let cx = @ctx {
+        tcx: tcx,
+        method_map: method_map,
+        live: HashMap(),
+        worklist: DVec()
+    };
+    mark_roots(cx, exp_map2, crate);
+
+    let v = mk_marking_visitor();
+    while cx.worklist.len() > 0u {
+        let id = cx.worklist.pop();
+        match tcx.items.find(id) {
+          Some(ast_map::node_item(item, _)) => {
+            match item.node {
+              // Methods are made live one by one.
+              item_impl(*) => (),
+              _ => visit::visit_item(item, cx, v)
+            }
+          }
+          Some(ast_map::node_method(method, _, _)) => {
+            visit::visit_method_helper(method, cx, v);
+          }
+          Some(ast_map::node_struct_ctor(_, item, _)) => cx.mark(item.id),
+          _ => ()
+        }
+    }
+
+    visit::visit_crate(*crate, cx, visit::mk_vt(@visit::Visitor {
+        visit_item: check_item,
+        .. *visit::default_visitor()
+    }));
+}
+
+fn mark_roots(cx: @ctx, exp_map2: resolve::ExportMap2, crate: @crate) {
+    for exp_map2.each_value |exports| {
+        for exports.each |export| { cx.mark_def_id(export.def_id); }
+    }
+
+    mark_legacy_mod(cx, exp_map2, crate_node_id, crate.node.attrs,
+                    &crate.node.module);
+    let main = cx.tcx.sess.ident_of(~"main");
+    for crate.node.module.items.each |item| {
+        if item.ident == main {
+            cx.mark(item.id);
+        }
+    }
+
+    visit::visit_crate(*crate, cx, visit::mk_vt(@visit::Visitor {
+        visit_item: |item, cx: @ctx, v| {
+            if item.vis == public ||
+               attr::attrs_contains_name(item.attrs, ~"main") ||
+               attr::attrs_contains_name(item.attrs, ~"lang") ||
+               attr::attrs_contains_name(item.attrs, ~"test") {
+                cx.mark(item.id);
+            }
+            match item.node {
+              item_mod(ref m) => {
+                mark_legacy_mod(cx, exp_map2, item.id, item.attrs, m);
+              }
+              item_impl(_, Some(_), _, ref methods) => {
+                for methods.each |m| { cx.mark(m.id); }
+              }
+              item_impl(_, None, _, ref methods) => {
+                for methods.each |m| {
+                    if m.vis == public ||
+                       (m.vis == inherited && item.vis == public) {
+                        cx.mark(m.id);
+                    }
+                }
+              }
+              _ => ()
+            }
+            visit::visit_item(item, cx, v);
+        },
+        .. *visit::default_visitor()
+    }));
+}
+
+// A legacy module without an export list exports everything.
+fn mark_legacy_mod(cx: @ctx, exp_map2: resolve::ExportMap2, id: node_id,
+                   attrs: &[attribute], m: &_mod) {
+    if has_legacy_export_attr(attrs) && !exp_map2.contains_key(id) {
+        for m.items.each |item| { cx.mark(item.id); }
+    }
+}
+
+// Visits the body of a single live item, leaving nested items to be
+// visited if and when they are found to be live.
+fn mk_marking_visitor() -> visit::vt<@ctx> {
+    visit::mk_vt(@visit::Visitor {
+        visit_item: |_item, _cx, _v| (),
+        visit_expr: |e, cx: @ctx, v| {
+            match e.node {
+              expr_path(_) | expr_struct(*) => cx.mark_path(e.id),
+              expr_field(base, ident, _) => {
+                cx.mark_field(ty::expr_ty(cx.tcx, base), ident);
+              }
+              _ => ()
+            }
+            // Method calls and overloaded operators.
+            cx.mark_method(e.id);
+            visit::visit_expr(e, cx, v);
+        },
+        visit_pat: |p, cx: @ctx, v| {
+            match p.node {
+              pat_struct(_, ref fields, _) => {
+                let t = ty::pat_ty(cx.tcx, p);
+                for fields.each |field| { cx.mark_field(t, field.ident); }
+              }
+              _ => ()
+            }
+            match cx.tcx.def_map.find(p.id) {
+              Some(def_const(did)) => cx.mark_def_id(did),
+              _ => ()
+            }
+            visit::visit_pat(p, cx, v);
+        },
+        visit_ty: |t, cx: @ctx, v| {
+            match t.node {
+              ty_path(_, id) => cx.mark_path(id),
+              _ => ()
+            }
+            visit::visit_ty(t, cx, v);
+        },
+        .. *visit::default_visitor()
+    })
+}
+
+fn check_item(item: @item, &&cx: @ctx, v: visit::vt<@ctx>) {
+    let warn = |id: node_id, sp: span, what: &str, name: ident| {
+        let name = cx.tcx.sess.str_of(name);
+        if !cx.is_live(id) && !str::starts_with(name, ~"_") {
+            cx.tcx.sess.span_lint(dead_code, id, item.id, sp,
+                                  fmt!("%s: `%s`", what, name));
+        }
+    };
+    match item.node {
+      item_fn(*) => {
+        warn(item.id, item.span, "function is never used", item.ident);
+      }
+      item_const(*) => {
+        warn(item.id, item.span, "constant is never used", item.ident);
+      }
+      item_struct(struct_def, _) if cx.is_live(item.id) => {
+        for struct_def.fields.each |field| {
+            match field.node.kind {
+              named_field(ident, _, vis) => {
+                if vis == private ||
+                   (vis == inherited && item.vis != public) {
+                    warn(field.node.id, field.span, "field is never read",
+                         ident);
+                }
+              }
+              unnamed_field => ()
+            }
+        }
+      }
+      item_enum(ref enum_def, _) if cx.is_live(item.id) => {
+        for enum_def.variants.each |variant| {
+            let vis = variant.node.vis;
+            if vis == private ||
+               (vis == inherited && item.vis != public) {
+                warn(variant.node.id, variant.span,
+                     "variant is never constructed", variant.node.name);
+            }
+        }
+      }
+      _ => ()
+    }
+    visit::visit_item(item, cx, v);
+}
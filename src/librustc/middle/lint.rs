@@ -45,6 +45,8 @@ use syntax::{ast, ast_util, visit};
 export lint, ctypes, unused_imports, while_true, path_statement, old_vecs;
 export unrecognized_lint, non_implicitly_copyable_typarams;
 export vecs_implicitly_copyable, implicit_copies, legacy_modes;
+export unused_variable, dead_assignment, dead_code;
+export unused_mut, unnecessary_allocation;
 export level, allow, warn, deny, forbid;
 export lint_dict, get_lint_dict, level_to_str;
 export get_lint_level, get_lint_settings_level;
@@ -95,9 +97,11 @@ enum lint {
 
     legacy_modes,
 
-    // FIXME(#3266)--make liveness warnings lintable
-    // unused_variable,
-    // dead_assignment
+    unused_variable,
+    dead_assignment,
+    dead_code,
+    unused_mut,
+    unnecessary_allocation,
 }
 
 impl lint : cmp::Eq {
@@ -235,7 +239,6 @@ fn get_lint_dict() -> lint_dict {
            desc: "warn about deprecated uses of `self`",
            default: allow}),
 
-        /* FIXME(#3266)--make liveness warnings lintable
         (~"unused_variable",
          @{lint: unused_variable,
            desc: "detect variables which are not used in any way",
@@ -245,7 +248,22 @@ fn get_lint_dict() -> lint_dict {
          @{lint: dead_assignment,
            desc: "detect assignments that will never be read",
            default: warn}),
-        */
+
+        (~"dead_code",
+         @{lint: dead_code,
+           desc: "detect private items, fields and variants that are \
+                  never used",
+           default: allow}),
+
+        (~"unused_mut",
+         @{lint: unused_mut,
+           desc: "detect mut variables which don't need to be mutable",
+           default: allow}),
+
+        (~"unnecessary_allocation",
+         @{lint: unnecessary_allocation,
+           desc: "detect ~ and @ allocations that are only borrowed",
+           default: allow}),
     ];
     map::hash_from_vec(v)
 }
@@ -450,6 +468,8 @@ fn check_item(i: @ast::item, cx: ty::ctxt) {
     check_item_type_limits(cx, i);
     check_item_default_methods(cx, i);
     check_item_deprecated_self(cx, i);
+    check_item_unused_mut(cx, i);
+    check_item_unnecessary_allocation(cx, i);
 }
 
 // Take a visitor, and modify it so that it will not proceed past subitems.
@@ -817,6 +837,75 @@ fn check_item_path_statement(cx: ty::ctxt, it: @ast::item) {
     visit::visit_item(it, (), visit);
 }
 
+fn check_item_unused_mut(cx: ty::ctxt, it: @ast::item) {
+    let visit = item_stopping_visitor(
+        visit::mk_simple_visitor(@visit::SimpleVisitor {
+            visit_local: |l: @ast::local| {
+                if l.node.is_mutbl {
+                    let mut bindings = 0u;
+                    let mut used = false;
+                    do pat_bindings(cx.def_map, l.node.pat) |_bm, id, _sp,
+                                                             _path| {
+                        bindings += 1u;
+                        used = used || cx.used_mut_nodes.contains_key(id);
+                    }
+                    if bindings > 0u && !used {
+                        let msg = if bindings == 1u {
+                            ~"variable does not need to be mutable"
+                        } else {
+                            ~"variables do not need to be mutable"
+                        };
+                        cx.sess.span_lint(unused_mut, l.node.id, it.id,
+                                          l.span, msg);
+                    }
+                }
+            },
+            .. *visit::default_simple_visitor()
+        }));
+    visit::visit_item(it, (), visit);
+}
+
+fn check_item_unnecessary_allocation(cx: ty::ctxt, it: @ast::item) {
+    // A fresh box whose adjustment derefs it again, or borrows the
+    // vector or string inside it, is only ever used through a borrowed
+    // pointer and is dropped straight afterwards.
+    fn is_only_borrowed(adj: @ty::AutoAdjustment) -> bool {
+        match adj.autoref {
+          Some(ty::AutoRef { kind: ty::AutoBorrowVec, _ }) |
+          Some(ty::AutoRef { kind: ty::AutoBorrowVecRef, _ }) => true,
+          Some(_) => adj.autoderefs > 0u,
+          None => false
+        }
+    }
+
+    let visit = item_stopping_visitor(
+        visit::mk_simple_visitor(@visit::SimpleVisitor {
+            visit_expr: |e: @ast::expr| {
+                let is_alloc = match e.node {
+                  ast::expr_vstore(_, ast::expr_vstore_uniq) |
+                  ast::expr_vstore(_, ast::expr_vstore_box) |
+                  ast::expr_vstore(_, ast::expr_vstore_mut_box) |
+                  ast::expr_unary(ast::box(_), _) |
+                  ast::expr_unary(ast::uniq(_), _) => true,
+                  _ => false
+                };
+                if is_alloc {
+                    match cx.adjustments.find(e.id) {
+                      Some(adj) if is_only_borrowed(adj) => {
+                        cx.sess.span_lint(
+                            unnecessary_allocation, e.id, it.id, e.span,
+                            ~"unnecessary allocation, the value is only \
+                              borrowed");
+                      }
+                      _ => ()
+                    }
+                }
+            },
+            .. *visit::default_simple_visitor()
+        }));
+    visit::visit_item(it, (), visit);
+}
+
 fn check_item_non_camel_case_types(cx: ty::ctxt, it: @ast::item) {
     fn is_camel_case(cx: ty::ctxt, ident: ast::ident) -> bool {
         let ident = cx.sess.str_of(ident);
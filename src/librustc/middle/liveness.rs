@@ -107,6 +107,8 @@ use core::prelude::*;
 
 use middle::capture::{cap_move, cap_drop, cap_copy, cap_ref};
 use middle::capture;
+use middle::lint::{dead_assignment, unused_variable};
+use middle::lint;
 use middle::pat_util;
 use middle::ty::MoveValue;
 use middle::ty;
@@ -208,6 +210,7 @@ fn check_crate(tcx: ty::ctxt,
                method_map: typeck::method_map,
                crate: @crate) -> last_use_map {
     let visitor = visit::mk_vt(@visit::Visitor {
+        visit_item: visit_item,
         visit_fn: visit_fn,
         visit_local: visit_local,
         visit_expr: visit_expr,
@@ -216,7 +219,8 @@ fn check_crate(tcx: ty::ctxt,
     });
 
     let last_use_map = HashMap();
-    let initial_maps = @IrMaps(tcx, method_map, last_use_map);
+    let initial_maps = @IrMaps(tcx, method_map, last_use_map,
+                               crate_node_id);
     visit::visit_crate(*crate, initial_maps, visitor);
     tcx.sess.abort_if_errors();
     return last_use_map;
@@ -298,6 +302,8 @@ struct IrMaps {
     tcx: ty::ctxt,
     method_map: typeck::method_map,
     last_use_map: last_use_map,
+    // The item whose lint settings apply
+    cur_item: node_id,
 
     mut num_live_nodes: uint,
     mut num_vars: uint,
@@ -309,11 +315,12 @@ struct IrMaps {
 }
 
 fn IrMaps(tcx: ty::ctxt, method_map: typeck::method_map,
-          last_use_map: last_use_map) -> IrMaps {
+          last_use_map: last_use_map, cur_item: node_id) -> IrMaps {
     IrMaps {
         tcx: tcx,
         method_map: method_map,
         last_use_map: last_use_map,
+        cur_item: cur_item,
         num_live_nodes: 0,
         num_vars: 0,
         live_node_map: HashMap(),
@@ -424,6 +431,12 @@ impl IrMaps {
     }
 }
 
+fn visit_item(item: @item, &&self: @IrMaps, v: vt<@IrMaps>) {
+    let item_maps = @IrMaps(self.tcx, self.method_map, self.last_use_map,
+                            item.id);
+    visit::visit_item(item, item_maps, v);
+}
+
 fn visit_fn(fk: visit::fn_kind, decl: fn_decl, body: blk,
             sp: span, id: node_id, &&self: @IrMaps, v: vt<@IrMaps>) {
     debug!("visit_fn: id=%d", id);
@@ -431,7 +444,7 @@ fn visit_fn(fk: visit::fn_kind, decl: fn_decl, body: blk,
 
     // swap in a new set of IR maps for this function body:
     let fn_maps = @IrMaps(self.tcx, self.method_map,
-                          self.last_use_map);
+                          self.last_use_map, self.cur_item);
 
     debug!("creating fn_maps: %x", ptr::addr_of(&(*fn_maps)) as uint);
 
@@ -1912,14 +1925,12 @@ impl @Liveness {
                 };
 
                 if is_assigned {
-                    // FIXME(#3266)--make liveness warnings lintable
-                    self.tcx.sess.span_warn(
-                        sp, fmt!("variable `%s` is assigned to, \
-                                  but never used", *name));
+                    self.span_lint(unused_variable, sp,
+                                   fmt!("variable `%s` is assigned to, \
+                                         but never used", *name));
                 } else {
-                    // FIXME(#3266)--make liveness warnings lintable
-                    self.tcx.sess.span_warn(
-                        sp, fmt!("unused variable: `%s`", *name));
+                    self.span_lint(unused_variable, sp,
+                                   fmt!("unused variable: `%s`", *name));
                 }
             }
             return true;
@@ -1930,11 +1941,16 @@ impl @Liveness {
     fn warn_about_dead_assign(sp: span, ln: LiveNode, var: Variable) {
         if self.live_on_exit(ln, var).is_none() {
             for self.should_warn(var).each |name| {
-                // FIXME(#3266)--make liveness warnings lintable
-                self.tcx.sess.span_warn(
-                    sp,
-                    fmt!("value assigned to `%s` is never read", *name));
+                self.span_lint(dead_assignment, sp,
+                               fmt!("value assigned to `%s` is never read",
+                                    *name));
             }
         }
     }
+
+    fn span_lint(lint: lint::lint, sp: span, +msg: ~str) {
+        // Lint settings are only kept per item.
+        let item = self.ir.cur_item;
+        self.tcx.sess.span_lint(lint, item, item, sp, msg);
+    }
  }
@@ -2695,6 +2695,105 @@ fn declare_intrinsics(llmod: ModuleRef) -> HashMap<~str, ValueRef> {
     let bswap64 = decl_cdecl_fn(llmod, ~"llvm.bswap.i64",
                                 T_fn(~[T_i64()], T_i64()));
 
+    // The arithmetic-with-overflow intrinsics return the wrapped result
+    // and whether it overflowed.
+    let sadd8_with_overflow =
+        decl_cdecl_fn(llmod, ~"llvm.sadd.with.overflow.i8",
+                      T_fn(~[T_i8(), T_i8()],
+                           T_struct(~[T_i8(), T_i1()])));
+    let sadd16_with_overflow =
+        decl_cdecl_fn(llmod, ~"llvm.sadd.with.overflow.i16",
+                      T_fn(~[T_i16(), T_i16()],
+                           T_struct(~[T_i16(), T_i1()])));
+    let sadd32_with_overflow =
+        decl_cdecl_fn(llmod, ~"llvm.sadd.with.overflow.i32",
+                      T_fn(~[T_i32(), T_i32()],
+                           T_struct(~[T_i32(), T_i1()])));
+    let sadd64_with_overflow =
+        decl_cdecl_fn(llmod, ~"llvm.sadd.with.overflow.i64",
+                      T_fn(~[T_i64(), T_i64()],
+                           T_struct(~[T_i64(), T_i1()])));
+    let ssub8_with_overflow =
+        decl_cdecl_fn(llmod, ~"llvm.ssub.with.overflow.i8",
+                      T_fn(~[T_i8(), T_i8()],
+                           T_struct(~[T_i8(), T_i1()])));
+    let ssub16_with_overflow =
+        decl_cdecl_fn(llmod, ~"llvm.ssub.with.overflow.i16",
+                      T_fn(~[T_i16(), T_i16()],
+                           T_struct(~[T_i16(), T_i1()])));
+    let ssub32_with_overflow =
+        decl_cdecl_fn(llmod, ~"llvm.ssub.with.overflow.i32",
+                      T_fn(~[T_i32(), T_i32()],
+                           T_struct(~[T_i32(), T_i1()])));
+    let ssub64_with_overflow =
+        decl_cdecl_fn(llmod, ~"llvm.ssub.with.overflow.i64",
+                      T_fn(~[T_i64(), T_i64()],
+                           T_struct(~[T_i64(), T_i1()])));
+    let smul8_with_overflow =
+        decl_cdecl_fn(llmod, ~"llvm.smul.with.overflow.i8",
+                      T_fn(~[T_i8(), T_i8()],
+                           T_struct(~[T_i8(), T_i1()])));
+    let smul16_with_overflow =
+        decl_cdecl_fn(llmod, ~"llvm.smul.with.overflow.i16",
+                      T_fn(~[T_i16(), T_i16()],
+                           T_struct(~[T_i16(), T_i1()])));
+    let smul32_with_overflow =
+        decl_cdecl_fn(llmod, ~"llvm.smul.with.overflow.i32",
+                      T_fn(~[T_i32(), T_i32()],
+                           T_struct(~[T_i32(), T_i1()])));
+    let smul64_with_overflow =
+        decl_cdecl_fn(llmod, ~"llvm.smul.with.overflow.i64",
+                      T_fn(~[T_i64(), T_i64()],
+                           T_struct(~[T_i64(), T_i1()])));
+    let uadd8_with_overflow =
+        decl_cdecl_fn(llmod, ~"llvm.uadd.with.overflow.i8",
+                      T_fn(~[T_i8(), T_i8()],
+                           T_struct(~[T_i8(), T_i1()])));
+    let uadd16_with_overflow =
+        decl_cdecl_fn(llmod, ~"llvm.uadd.with.overflow.i16",
+                      T_fn(~[T_i16(), T_i16()],
+                           T_struct(~[T_i16(), T_i1()])));
+    let uadd32_with_overflow =
+        decl_cdecl_fn(llmod, ~"llvm.uadd.with.overflow.i32",
+                      T_fn(~[T_i32(), T_i32()],
+                           T_struct(~[T_i32(), T_i1()])));
+    let uadd64_with_overflow =
+        decl_cdecl_fn(llmod, ~"llvm.uadd.with.overflow.i64",
+                      T_fn(~[T_i64(), T_i64()],
+                           T_struct(~[T_i64(), T_i1()])));
+    let usub8_with_overflow =
+        decl_cdecl_fn(llmod, ~"llvm.usub.with.overflow.i8",
+                      T_fn(~[T_i8(), T_i8()],
+                           T_struct(~[T_i8(), T_i1()])));
+    let usub16_with_overflow =
+        decl_cdecl_fn(llmod, ~"llvm.usub.with.overflow.i16",
+                      T_fn(~[T_i16(), T_i16()],
+                           T_struct(~[T_i16(), T_i1()])));
+    let usub32_with_overflow =
+        decl_cdecl_fn(llmod, ~"llvm.usub.with.overflow.i32",
+                      T_fn(~[T_i32(), T_i32()],
+                           T_struct(~[T_i32(), T_i1()])));
+    let usub64_with_overflow =
+        decl_cdecl_fn(llmod, ~"llvm.usub.with.overflow.i64",
+                      T_fn(~[T_i64(), T_i64()],
+                           T_struct(~[T_i64(), T_i1()])));
+    let umul8_with_overflow =
+        decl_cdecl_fn(llmod, ~"llvm.umul.with.overflow.i8",
+                      T_fn(~[T_i8(), T_i8()],
+                           T_struct(~[T_i8(), T_i1()])));
+    let umul16_with_overflow =
+        decl_cdecl_fn(llmod, ~"llvm.umul.with.overflow.i16",
+                      T_fn(~[T_i16(), T_i16()],
+                           T_struct(~[T_i16(), T_i1()])));
+    let umul32_with_overflow =
+        decl_cdecl_fn(llmod, ~"llvm.umul.with.overflow.i32",
+                      T_fn(~[T_i32(), T_i32()],
+                           T_struct(~[T_i32(), T_i1()])));
+    let umul64_with_overflow =
+        decl_cdecl_fn(llmod, ~"llvm.umul.with.overflow.i64",
+                      T_fn(~[T_i64(), T_i64()],
+                           T_struct(~[T_i64(), T_i1()])));
+
     let intrinsics = HashMap();
     intrinsics.insert(~"llvm.gcroot", gcroot);
     intrinsics.insert(~"llvm.gcread", gcread);
@@ -2749,6 +2848,30 @@ fn declare_intrinsics(llmod: ModuleRef) -> HashMap<~str, ValueRef> {
     intrinsics.insert(~"llvm.bswap.i16", bswap16);
     intrinsics.insert(~"llvm.bswap.i32", bswap32);
     intrinsics.insert(~"llvm.bswap.i64", bswap64);
+    intrinsics.insert(~"llvm.sadd.with.overflow.i8", sadd8_with_overflow);
+    intrinsics.insert(~"llvm.sadd.with.overflow.i16", sadd16_with_overflow);
+    intrinsics.insert(~"llvm.sadd.with.overflow.i32", sadd32_with_overflow);
+    intrinsics.insert(~"llvm.sadd.with.overflow.i64", sadd64_with_overflow);
+    intrinsics.insert(~"llvm.ssub.with.overflow.i8", ssub8_with_overflow);
+    intrinsics.insert(~"llvm.ssub.with.overflow.i16", ssub16_with_overflow);
+    intrinsics.insert(~"llvm.ssub.with.overflow.i32", ssub32_with_overflow);
+    intrinsics.insert(~"llvm.ssub.with.overflow.i64", ssub64_with_overflow);
+    intrinsics.insert(~"llvm.smul.with.overflow.i8", smul8_with_overflow);
+    intrinsics.insert(~"llvm.smul.with.overflow.i16", smul16_with_overflow);
+    intrinsics.insert(~"llvm.smul.with.overflow.i32", smul32_with_overflow);
+    intrinsics.insert(~"llvm.smul.with.overflow.i64", smul64_with_overflow);
+    intrinsics.insert(~"llvm.uadd.with.overflow.i8", uadd8_with_overflow);
+    intrinsics.insert(~"llvm.uadd.with.overflow.i16", uadd16_with_overflow);
+    intrinsics.insert(~"llvm.uadd.with.overflow.i32", uadd32_with_overflow);
+    intrinsics.insert(~"llvm.uadd.with.overflow.i64", uadd64_with_overflow);
+    intrinsics.insert(~"llvm.usub.with.overflow.i8", usub8_with_overflow);
+    intrinsics.insert(~"llvm.usub.with.overflow.i16", usub16_with_overflow);
+    intrinsics.insert(~"llvm.usub.with.overflow.i32", usub32_with_overflow);
+    intrinsics.insert(~"llvm.usub.with.overflow.i64", usub64_with_overflow);
+    intrinsics.insert(~"llvm.umul.with.overflow.i8", umul8_with_overflow);
+    intrinsics.insert(~"llvm.umul.with.overflow.i16", umul16_with_overflow);
+    intrinsics.insert(~"llvm.umul.with.overflow.i32", umul32_with_overflow);
+    intrinsics.insert(~"llvm.umul.with.overflow.i64", umul64_with_overflow);
 
     return intrinsics;
 }
@@ -2825,7 +2948,9 @@ fn decl_crate_map(sess: session::Session, mapmeta: link_meta,
     let mut n_subcrates = 1;
     let cstore = sess.cstore;
     while cstore::have_crate_data(cstore, n_subcrates) { n_subcrates += 1; }
-    let mapname = if sess.building_library {
+    // A static library is the root of its crate graph, like an executable,
+    // and the C program that links it names its map to start the runtime
+    let mapname = if sess.building_library && !sess.opts.staticlib {
         mapmeta.name.to_owned() + ~"_" + mapmeta.vers.to_owned() + ~"_"
             + mapmeta.extras_hash.to_owned()
     } else { ~"toplevel" };
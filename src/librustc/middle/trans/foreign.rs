@@ -809,6 +809,150 @@ fn trans_intrinsic(ccx: @crate_ctxt, decl: ValueRef, item: @ast::foreign_item,
             let cttz = ccx.intrinsics.get(~"llvm.bswap.i64");
             Store(bcx, Call(bcx, cttz, ~[x]), fcx.llretptr)
         }
+        ~"i8_add_with_overflow" => {
+            let x = get_param(decl, first_real_arg);
+            let y = get_param(decl, first_real_arg + 1u);
+            let f = ccx.intrinsics.get(~"llvm.sadd.with.overflow.i8");
+            Store(bcx, Call(bcx, f, ~[x, y]), fcx.llretptr)
+        }
+        ~"i16_add_with_overflow" => {
+            let x = get_param(decl, first_real_arg);
+            let y = get_param(decl, first_real_arg + 1u);
+            let f = ccx.intrinsics.get(~"llvm.sadd.with.overflow.i16");
+            Store(bcx, Call(bcx, f, ~[x, y]), fcx.llretptr)
+        }
+        ~"i32_add_with_overflow" => {
+            let x = get_param(decl, first_real_arg);
+            let y = get_param(decl, first_real_arg + 1u);
+            let f = ccx.intrinsics.get(~"llvm.sadd.with.overflow.i32");
+            Store(bcx, Call(bcx, f, ~[x, y]), fcx.llretptr)
+        }
+        ~"i64_add_with_overflow" => {
+            let x = get_param(decl, first_real_arg);
+            let y = get_param(decl, first_real_arg + 1u);
+            let f = ccx.intrinsics.get(~"llvm.sadd.with.overflow.i64");
+            Store(bcx, Call(bcx, f, ~[x, y]), fcx.llretptr)
+        }
+        ~"i8_sub_with_overflow" => {
+            let x = get_param(decl, first_real_arg);
+            let y = get_param(decl, first_real_arg + 1u);
+            let f = ccx.intrinsics.get(~"llvm.ssub.with.overflow.i8");
+            Store(bcx, Call(bcx, f, ~[x, y]), fcx.llretptr)
+        }
+        ~"i16_sub_with_overflow" => {
+            let x = get_param(decl, first_real_arg);
+            let y = get_param(decl, first_real_arg + 1u);
+            let f = ccx.intrinsics.get(~"llvm.ssub.with.overflow.i16");
+            Store(bcx, Call(bcx, f, ~[x, y]), fcx.llretptr)
+        }
+        ~"i32_sub_with_overflow" => {
+            let x = get_param(decl, first_real_arg);
+            let y = get_param(decl, first_real_arg + 1u);
+            let f = ccx.intrinsics.get(~"llvm.ssub.with.overflow.i32");
+            Store(bcx, Call(bcx, f, ~[x, y]), fcx.llretptr)
+        }
+        ~"i64_sub_with_overflow" => {
+            let x = get_param(decl, first_real_arg);
+            let y = get_param(decl, first_real_arg + 1u);
+            let f = ccx.intrinsics.get(~"llvm.ssub.with.overflow.i64");
+            Store(bcx, Call(bcx, f, ~[x, y]), fcx.llretptr)
+        }
+        ~"i8_mul_with_overflow" => {
+            let x = get_param(decl, first_real_arg);
+            let y = get_param(decl, first_real_arg + 1u);
+            let f = ccx.intrinsics.get(~"llvm.smul.with.overflow.i8");
+            Store(bcx, Call(bcx, f, ~[x, y]), fcx.llretptr)
+        }
+        ~"i16_mul_with_overflow" => {
+            let x = get_param(decl, first_real_arg);
+            let y = get_param(decl, first_real_arg + 1u);
+            let f = ccx.intrinsics.get(~"llvm.smul.with.overflow.i16");
+            Store(bcx, Call(bcx, f, ~[x, y]), fcx.llretptr)
+        }
+        ~"i32_mul_with_overflow" => {
+            let x = get_param(decl, first_real_arg);
+            let y = get_param(decl, first_real_arg + 1u);
+            let f = ccx.intrinsics.get(~"llvm.smul.with.overflow.i32");
+            Store(bcx, Call(bcx, f, ~[x, y]), fcx.llretptr)
+        }
+        ~"i64_mul_with_overflow" => {
+            let x = get_param(decl, first_real_arg);
+            let y = get_param(decl, first_real_arg + 1u);
+            let f = ccx.intrinsics.get(~"llvm.smul.with.overflow.i64");
+            Store(bcx, Call(bcx, f, ~[x, y]), fcx.llretptr)
+        }
+        ~"u8_add_with_overflow" => {
+            let x = get_param(decl, first_real_arg);
+            let y = get_param(decl, first_real_arg + 1u);
+            let f = ccx.intrinsics.get(~"llvm.uadd.with.overflow.i8");
+            Store(bcx, Call(bcx, f, ~[x, y]), fcx.llretptr)
+        }
+        ~"u16_add_with_overflow" => {
+            let x = get_param(decl, first_real_arg);
+            let y = get_param(decl, first_real_arg + 1u);
+            let f = ccx.intrinsics.get(~"llvm.uadd.with.overflow.i16");
+            Store(bcx, Call(bcx, f, ~[x, y]), fcx.llretptr)
+        }
+        ~"u32_add_with_overflow" => {
+            let x = get_param(decl, first_real_arg);
+            let y = get_param(decl, first_real_arg + 1u);
+            let f = ccx.intrinsics.get(~"llvm.uadd.with.overflow.i32");
+            Store(bcx, Call(bcx, f, ~[x, y]), fcx.llretptr)
+        }
+        ~"u64_add_with_overflow" => {
+            let x = get_param(decl, first_real_arg);
+            let y = get_param(decl, first_real_arg + 1u);
+            let f = ccx.intrinsics.get(~"llvm.uadd.with.overflow.i64");
+            Store(bcx, Call(bcx, f, ~[x, y]), fcx.llretptr)
+        }
+        ~"u8_sub_with_overflow" => {
+            let x = get_param(decl, first_real_arg);
+            let y = get_param(decl, first_real_arg + 1u);
+            let f = ccx.intrinsics.get(~"llvm.usub.with.overflow.i8");
+            Store(bcx, Call(bcx, f, ~[x, y]), fcx.llretptr)
+        }
+        ~"u16_sub_with_overflow" => {
+            let x = get_param(decl, first_real_arg);
+            let y = get_param(decl, first_real_arg + 1u);
+            let f = ccx.intrinsics.get(~"llvm.usub.with.overflow.i16");
+            Store(bcx, Call(bcx, f, ~[x, y]), fcx.llretptr)
+        }
+        ~"u32_sub_with_overflow" => {
+            let x = get_param(decl, first_real_arg);
+            let y = get_param(decl, first_real_arg + 1u);
+            let f = ccx.intrinsics.get(~"llvm.usub.with.overflow.i32");
+            Store(bcx, Call(bcx, f, ~[x, y]), fcx.llretptr)
+        }
+        ~"u64_sub_with_overflow" => {
+            let x = get_param(decl, first_real_arg);
+            let y = get_param(decl, first_real_arg + 1u);
+            let f = ccx.intrinsics.get(~"llvm.usub.with.overflow.i64");
+            Store(bcx, Call(bcx, f, ~[x, y]), fcx.llretptr)
+        }
+        ~"u8_mul_with_overflow" => {
+            let x = get_param(decl, first_real_arg);
+            let y = get_param(decl, first_real_arg + 1u);
+            let f = ccx.intrinsics.get(~"llvm.umul.with.overflow.i8");
+            Store(bcx, Call(bcx, f, ~[x, y]), fcx.llretptr)
+        }
+        ~"u16_mul_with_overflow" => {
+            let x = get_param(decl, first_real_arg);
+            let y = get_param(decl, first_real_arg + 1u);
+            let f = ccx.intrinsics.get(~"llvm.umul.with.overflow.i16");
+            Store(bcx, Call(bcx, f, ~[x, y]), fcx.llretptr)
+        }
+        ~"u32_mul_with_overflow" => {
+            let x = get_param(decl, first_real_arg);
+            let y = get_param(decl, first_real_arg + 1u);
+            let f = ccx.intrinsics.get(~"llvm.umul.with.overflow.i32");
+            Store(bcx, Call(bcx, f, ~[x, y]), fcx.llretptr)
+        }
+        ~"u64_mul_with_overflow" => {
+            let x = get_param(decl, first_real_arg);
+            let y = get_param(decl, first_real_arg + 1u);
+            let f = ccx.intrinsics.get(~"llvm.umul.with.overflow.i64");
+            Store(bcx, Call(bcx, f, ~[x, y]), fcx.llretptr)
+        }
         _ => {
             // Could we make this an enum rather than a string? does it get
             // checked earlier?
@@ -471,6 +471,11 @@ type ctxt =
 
       // Records the value mode (read, copy, or move) for every value.
       value_modes: HashMap<ast::node_id, ValueMode>,
+
+      // The ids of the `mut` local variables that are assigned to or
+      // borrowed mutably, as found by borrowck. Used by the `unused_mut`
+      // lint.
+      used_mut_nodes: HashMap<ast::node_id, ()>,
       };
 
 enum tbox_flag {
@@ -1025,7 +1030,8 @@ fn mk_ctxt(s: session::Session,
       supertraits: HashMap(),
       destructor_for_type: HashMap(),
       destructors: HashMap(),
-      value_modes: HashMap()}
+      value_modes: HashMap(),
+      used_mut_nodes: HashMap()}
 }
 
 
@@ -3335,6 +3335,54 @@ fn check_intrinsic_type(ccx: @crate_ctxt, it: @ast::foreign_item) {
          (0u, ~[arg(ast::by_copy, ty::mk_i64(tcx))],
          ty::mk_i64(tcx))
      }
+     ~"i8_add_with_overflow" | ~"i8_sub_with_overflow" |
+     ~"i8_mul_with_overflow" => {
+        let t = ty::mk_i8(tcx);
+        (0u, ~[arg(ast::by_copy, t), arg(ast::by_copy, t)],
+         ty::mk_tup(tcx, ~[t, ty::mk_bool(tcx)]))
+     }
+     ~"i16_add_with_overflow" | ~"i16_sub_with_overflow" |
+     ~"i16_mul_with_overflow" => {
+        let t = ty::mk_i16(tcx);
+        (0u, ~[arg(ast::by_copy, t), arg(ast::by_copy, t)],
+         ty::mk_tup(tcx, ~[t, ty::mk_bool(tcx)]))
+     }
+     ~"i32_add_with_overflow" | ~"i32_sub_with_overflow" |
+     ~"i32_mul_with_overflow" => {
+        let t = ty::mk_i32(tcx);
+        (0u, ~[arg(ast::by_copy, t), arg(ast::by_copy, t)],
+         ty::mk_tup(tcx, ~[t, ty::mk_bool(tcx)]))
+     }
+     ~"i64_add_with_overflow" | ~"i64_sub_with_overflow" |
+     ~"i64_mul_with_overflow" => {
+        let t = ty::mk_i64(tcx);
+        (0u, ~[arg(ast::by_copy, t), arg(ast::by_copy, t)],
+         ty::mk_tup(tcx, ~[t, ty::mk_bool(tcx)]))
+     }
+     ~"u8_add_with_overflow" | ~"u8_sub_with_overflow" |
+     ~"u8_mul_with_overflow" => {
+        let t = ty::mk_u8(tcx);
+        (0u, ~[arg(ast::by_copy, t), arg(ast::by_copy, t)],
+         ty::mk_tup(tcx, ~[t, ty::mk_bool(tcx)]))
+     }
+     ~"u16_add_with_overflow" | ~"u16_sub_with_overflow" |
+     ~"u16_mul_with_overflow" => {
+        let t = ty::mk_u16(tcx);
+        (0u, ~[arg(ast::by_copy, t), arg(ast::by_copy, t)],
+         ty::mk_tup(tcx, ~[t, ty::mk_bool(tcx)]))
+     }
+     ~"u32_add_with_overflow" | ~"u32_sub_with_overflow" |
+     ~"u32_mul_with_overflow" => {
+        let t = ty::mk_u32(tcx);
+        (0u, ~[arg(ast::by_copy, t), arg(ast::by_copy, t)],
+         ty::mk_tup(tcx, ~[t, ty::mk_bool(tcx)]))
+     }
+     ~"u64_add_with_overflow" | ~"u64_sub_with_overflow" |
+     ~"u64_mul_with_overflow" => {
+        let t = ty::mk_u64(tcx);
+        (0u, ~[arg(ast::by_copy, t), arg(ast::by_copy, t)],
+         ty::mk_tup(tcx, ~[t, ty::mk_bool(tcx)]))
+     }
      ref other => {
         tcx.sess.span_err(it.span, ~"unrecognized intrinsic function: `" +
                           (*other) + ~"`");
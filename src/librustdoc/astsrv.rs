@@ -122,8 +122,11 @@ fn build_ctxt(sess: Session,
     use rustc::front::config;
 
     let ast = config::strip_unconfigured_items(ast);
+    // Crates that aren't built yet can still be documented
+    let macro_loader = driver::lenient_macro_loader(sess);
     let ast = syntax::ext::expand::expand_crate(sess.parse_sess,
-                                                sess.opts.cfg, ast);
+                                                sess.opts.cfg, ast,
+                                                macro_loader);
     let ast = front::test::modify_for_testing(sess, ast);
     let ast_map = ast_map::map_crate(sess.diagnostic(), *ast);
 
@@ -10,6 +10,7 @@
 
 #[forbid(deprecated_mode)];
 
+use core::container::{Container, Mutable, Set};
 use core::ops;
 use core::prelude::*;
 use core::uint;
@@ -28,7 +29,7 @@ fn SmallBitv(bits: u32) -> SmallBitv {
 /// a mask that has a 1 for each defined bit in a small_bitv, assuming n bits
 #[inline(always)]
 fn small_mask(nbits: uint) -> u32 {
-    (1 << nbits) - 1
+    if nbits >= 32 { !0 } else { (1 << nbits) - 1 }
 }
 
 impl SmallBitv {
@@ -499,6 +500,7 @@ impl Bitv {
         true
     }
 
+    /// Visit the index of each bit that is set to 1, in increasing order
     fn ones(f: fn(uint) -> bool) {
         for uint::range(0, self.nbits) |i| {
             if self.get(i) {
@@ -507,6 +509,69 @@ impl Bitv {
         }
     }
 
+    /// Returns the number of bits set to 1
+    fn count_ones() -> uint {
+        match self.rep {
+          Small(ref b) => {
+            count_bits((b.bits & small_mask(self.nbits)) as uint)
+          }
+          Big(ref b) => {
+            let mut n = 0;
+            for uint::range(0, b.storage.len()) |i| {
+                n += count_bits(b.storage[i] & big_mask(self.nbits, i));
+            }
+            n
+          }
+        }
+    }
+
+    /**
+     * Returns the number of bits set to 1 at indices strictly less than `i`
+     *
+     * `i` must be less than or equal to the length of the bitvector.
+     */
+    fn rank(i: uint) -> uint {
+        assert (i <= self.nbits);
+        match self.rep {
+          Small(ref b) => count_bits((b.bits & small_mask(i)) as uint),
+          Big(ref b) => {
+            let w = i / uint_bits;
+            let rmd = i % uint_bits;
+            let mut n = 0;
+            for uint::range(0, w) |j| { n += count_bits(b.storage[j]); }
+            if rmd != 0 {
+                n += count_bits(b.storage[w] & ((1 << rmd) - 1));
+            }
+            n
+          }
+        }
+    }
+
+    /**
+     * Returns the index of the `n`th bit set to 1, counting from zero
+     *
+     * Returns `None` if fewer than `n + 1` bits are set. This is the inverse
+     * of `rank`: if `select(n)` is `Some(i)` then `rank(i)` is `n`.
+     */
+    fn select(n: uint) -> Option<uint> {
+        match self.rep {
+          Small(ref b) => {
+            let w = (b.bits & small_mask(self.nbits)) as uint;
+            if n < count_bits(w) { Some(select_bit(w, n)) } else { None }
+          }
+          Big(ref b) => {
+            let mut n = n;
+            for uint::range(0, b.storage.len()) |i| {
+                let w = b.storage[i] & big_mask(self.nbits, i);
+                let c = count_bits(w);
+                if n < c { return Some(i * uint_bits + select_bit(w, n)); }
+                n -= c;
+            }
+            None
+          }
+        }
+    }
+
 }
 
 impl Bitv: Clone {
@@ -570,12 +635,154 @@ pure fn difference(w0: uint, w1: uint) -> uint { return w0 & !w1; }
 
 pure fn right(_w0: uint, w1: uint) -> uint { return w1; }
 
+/// Returns the number of bits set in a word
+pure fn count_bits(w: uint) -> uint {
+    let mut w = w;
+    let mut n = 0;
+    while w != 0 {
+        w &= w - 1;
+        n += 1;
+    }
+    n
+}
+
+/// Returns the position of the `n`th set bit of a word, which must have
+/// more than `n` bits set
+pure fn select_bit(w: uint, n: uint) -> uint {
+    let mut w = w;
+    for n.times { w &= w - 1; }
+    let mut i = 0;
+    while (w >> i) & 1 == 0 { i += 1; }
+    i
+}
+
 impl Bitv: ops::Index<uint,bool> {
     pure fn index(&self, i: uint) -> bool {
         self.get(i)
     }
 }
 
+/**
+ * A set of unsigned integers backed by a bitvector
+ *
+ * The underlying bitvector is grown as needed to hold the largest value
+ * inserted, so memory use is proportional to that value rather than to the
+ * number of elements.
+ */
+pub struct BitvSet {
+    priv size: uint,
+    priv bitv: Bitv
+}
+
+impl BitvSet: iter::BaseIter<uint> {
+    /// Visit all values in increasing order
+    pure fn each(&self, f: fn(&uint) -> bool) {
+        for uint::range(0, self.bitv.nbits) |i| {
+            if self.bitv.get(i) && !f(&i) { return }
+        }
+    }
+    pure fn size_hint(&self) -> Option<uint> { Some(self.size) }
+}
+
+impl BitvSet: Container {
+    /// Return the number of elements in the set
+    pure fn len(&self) -> uint { self.size }
+
+    /// Return true if the set contains no elements
+    pure fn is_empty(&self) -> bool { self.size == 0 }
+}
+
+impl BitvSet: Mutable {
+    /// Clear the set, removing all values.
+    fn clear(&mut self) {
+        self.bitv.clear();
+        self.size = 0;
+    }
+}
+
+impl BitvSet: Set<uint> {
+    /// Return true if the set contains a value
+    pure fn contains(&self, value: &uint) -> bool {
+        *value < self.bitv.nbits && self.bitv.get(*value)
+    }
+
+    /// Add a value to the set. Return true if the value was not already
+    /// present in the set.
+    fn insert(&mut self, value: uint) -> bool {
+        if self.contains(&value) { return false }
+        if value >= self.bitv.nbits {
+            self.grow(uint::max(value + 1, self.bitv.nbits * 2));
+        }
+        self.bitv.set(value, true);
+        self.size += 1;
+        true
+    }
+
+    /// Remove a value from the set. Return true if the value was
+    /// present in the set.
+    fn remove(&mut self, value: &uint) -> bool {
+        if !self.contains(value) { return false }
+        self.bitv.set(*value, false);
+        self.size -= 1;
+        true
+    }
+}
+
+impl BitvSet {
+    /// Create an empty BitvSet
+    static fn new() -> BitvSet {
+        BitvSet{size: 0, bitv: Bitv(0, false)}
+    }
+
+    /// Create a BitvSet containing the indices of the bits set in `bitv`
+    static fn from_bitv(bitv: Bitv) -> BitvSet {
+        let size = bitv.count_ones();
+        BitvSet{size: size, bitv: move bitv}
+    }
+
+    /// Consume the BitvSet and return the underlying bitvector
+    fn unwrap(self) -> Bitv { let BitvSet{bitv: bitv, _} = self; move bitv }
+
+    /// Return the number of values the set can hold without growing
+    pure fn capacity(&self) -> uint { self.bitv.nbits }
+
+    /// Add every value of `other` to the set
+    fn union_with(&mut self, other: &BitvSet) {
+        for other.each |i| { self.insert(*i); }
+    }
+
+    /// Remove every value that is not also in `other` from the set
+    fn intersect_with(&mut self, other: &BitvSet) {
+        let mut removed = 0;
+        for self.bitv.ones |i| {
+            if !other.contains(&i) {
+                self.bitv.set(i, false);
+                removed += 1;
+            }
+        }
+        self.size -= removed;
+    }
+
+    /// Remove every value of `other` from the set
+    fn difference_with(&mut self, other: &BitvSet) {
+        for other.each |i| { self.remove(i); }
+    }
+
+    /// Return the number of values in the set that are less than `value`
+    fn rank(&self, value: uint) -> uint {
+        self.bitv.rank(uint::min(value, self.bitv.nbits))
+    }
+
+    /// Return the `n`th smallest value in the set, counting from zero
+    fn select(&self, n: uint) -> Option<uint> { self.bitv.select(n) }
+
+    priv fn grow(&mut self, nbits: uint) {
+        let bitv = Bitv(nbits, false);
+        for self.bitv.ones |i| { bitv.set(i, true); }
+        self.bitv = move bitv;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[legacy_exports];
@@ -983,6 +1190,122 @@ mod tests {
       assert !b1[40];
       assert !b1[80];
     }
+
+    #[test]
+    fn test_small_count_ones() {
+        let b = Bitv(32, false);
+        assert b.count_ones() == 0;
+        b.set(0, true);
+        b.set(31, true);
+        assert b.count_ones() == 2;
+        b.set_all();
+        assert b.count_ones() == 32;
+
+        let b = Bitv(10, true);
+        b.invert();
+        assert b.count_ones() == 0;
+    }
+
+    #[test]
+    fn test_big_count_ones() {
+        let b = Bitv(100, true);
+        assert b.count_ones() == 100;
+        b.set(64, false);
+        assert b.count_ones() == 99;
+        b.clear();
+        assert b.count_ones() == 0;
+    }
+
+    #[test]
+    fn test_rank_select() {
+        for ~[10u, 70u].each |nbits| {
+            let b = Bitv(*nbits, false);
+            b.set(1, true);
+            b.set(4, true);
+            b.set(*nbits - 1, true);
+            assert b.rank(0) == 0;
+            assert b.rank(1) == 0;
+            assert b.rank(2) == 1;
+            assert b.rank(5) == 2;
+            assert b.rank(*nbits) == 3;
+            assert b.select(0) == Some(1);
+            assert b.select(1) == Some(4);
+            assert b.select(2) == Some(*nbits - 1);
+            assert b.select(3) == None;
+            for uint::range(0, 3) |n| {
+                assert b.rank(b.select(n).get()) == n;
+            }
+        }
+    }
+
+    #[test]
+    fn test_ones() {
+        let b = Bitv(100, false);
+        b.set(3, true);
+        b.set(33, true);
+        b.set(99, true);
+        let mut v = ~[];
+        for b.ones |i| { v.push(i) }
+        assert v == ~[3, 33, 99];
+    }
+
+    #[test]
+    fn test_bitv_set_basic() {
+        let mut s = BitvSet::new();
+        assert s.is_empty();
+        assert s.insert(3);
+        assert !s.insert(3);
+        assert s.insert(200);
+        assert s.insert(0);
+        assert s.len() == 3;
+        assert s.capacity() > 200;
+        assert s.contains(&200);
+        assert !s.contains(&1);
+        assert !s.contains(&1000);
+        assert s.remove(&3);
+        assert !s.remove(&3);
+        assert !s.remove(&1000);
+        assert s.len() == 2;
+
+        let mut v = ~[];
+        for s.each |i| { v.push(*i) }
+        assert v == ~[0, 200];
+
+        s.clear();
+        assert s.is_empty();
+        assert !s.contains(&200);
+    }
+
+    #[test]
+    fn test_bitv_set_ops() {
+        let mut a = BitvSet::new();
+        let mut b = BitvSet::new();
+        for ~[1u, 3, 5, 70].each |i| { a.insert(*i); }
+        for ~[3u, 4, 70, 140].each |i| { b.insert(*i); }
+
+        let mut u = BitvSet::from_bitv(a.unwrap());
+        u.union_with(&b);
+        assert u.len() == 6;
+        assert u.contains(&140);
+
+        u.intersect_with(&b);
+        assert u.len() == 4;
+        assert !u.contains(&1);
+
+        u.difference_with(&b);
+        assert u.is_empty();
+    }
+
+    #[test]
+    fn test_bitv_set_rank_select() {
+        let mut s = BitvSet::new();
+        for ~[2u, 40, 41].each |i| { s.insert(*i); }
+        assert s.rank(0) == 0;
+        assert s.rank(41) == 2;
+        assert s.rank(1000) == 3;
+        assert s.select(1) == Some(40);
+        assert s.select(3) == None;
+    }
 }
 
 //
@@ -8,150 +8,171 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-//! A deque. Untested as of yet. Likely buggy
+//! A double-ended queue implemented as a growable ring buffer
+
 #[forbid(deprecated_mode)];
 #[forbid(non_camel_case_types)];
 
-use core::cmp::Eq;
-use core::dvec::DVec;
-use core::dvec;
+use core::container::{Container, Mutable};
 use core::prelude::*;
 use core::uint;
 use core::vec;
 
-pub trait Deque<T> {
-    fn size() -> uint;
-    fn add_front(v: T);
-    fn add_back(v: T);
-    fn pop_front() -> T;
-    fn pop_back() -> T;
-    fn peek_front() -> T;
-    fn peek_back() -> T;
-    fn get(int) -> T;
+const initial_capacity: uint = 32u; // 2^5
+
+pub struct Deque<T> {
+    priv nelts: uint,
+    priv lo: uint,
+    priv elts: ~[Option<T>]
+}
+
+impl <T> Deque<T>: Container {
+    /// Return the number of elements in the deque
+    pure fn len(&self) -> uint { self.nelts }
+
+    /// Return true if the deque contains no elements
+    pure fn is_empty(&self) -> bool { self.nelts == 0 }
 }
 
-// FIXME (#2343) eventually, a proper datatype plus an exported impl would
-// be preferrable.
-pub fn create<T: Copy>() -> Deque<T> {
-    type Cell<T> = Option<T>;
-
-    let initial_capacity: uint = 32u; // 2^5
-     /**
-      * Grow is only called on full elts, so nelts is also len(elts), unlike
-      * elsewhere.
-      */
-    fn grow<T: Copy>(nelts: uint, lo: uint, elts: ~[Cell<T>])
-      -> ~[Cell<T>] {
-        let mut elts = move elts;
-        assert (nelts == vec::len(elts));
-        let mut rv = ~[];
-
-        let mut i = 0u;
-        let nalloc = uint::next_power_of_two(nelts + 1u);
-        while i < nalloc {
-            if i < nelts {
-                rv.push(elts[(lo + i) % nelts]);
-            } else { rv.push(None); }
-            i += 1u;
+impl <T> Deque<T>: Mutable {
+    /// Clear the deque, removing all values.
+    fn clear(&mut self) {
+        for uint::range(0, self.elts.len()) |i| { self.elts[i] = None }
+        self.nelts = 0;
+        self.lo = 0;
+    }
+}
+
+impl <T> Deque<T>: iter::BaseIter<T> {
+    /// Visit all values from front to back
+    pure fn each(&self, f: fn(&T) -> bool) {
+        for uint::range(0, self.nelts) |i| {
+            if !f(self.get(i)) { return }
         }
+    }
+    pure fn size_hint(&self) -> Option<uint> { Some(self.nelts) }
+}
+
+impl <T: Copy> Deque<T>: ops::Index<uint, T> {
+    pure fn index(&self, i: uint) -> T { *self.get(i) }
+}
 
-        move rv
+impl <T> Deque<T> {
+    /// Create an empty Deque
+    static pure fn new() -> Deque<T> {
+        Deque{nelts: 0, lo: 0,
+              elts: vec::from_fn(initial_capacity, |_| None)}
     }
-    fn get<T: Copy>(elts: &DVec<Cell<T>>, i: uint) -> T {
-        match (*elts).get_elt(i) { Some(move t) => t, _ => fail }
+
+    /// Return a reference to the front element - fails if empty
+    pure fn peek_front(&self) -> &self/T { self.get(0) }
+
+    /// Return a reference to the back element - fails if empty
+    pure fn peek_back(&self) -> &self/T {
+        if self.nelts == 0 { fail ~"peek_back: empty deque" }
+        self.get(self.nelts - 1)
     }
 
-    struct Repr<T> {
-        mut nelts: uint,
-        mut lo: uint,
-        mut hi: uint,
-        elts: DVec<Cell<T>>,
+    /// Return a reference to the element at index `i`, counting from the
+    /// front - fails if out of bounds
+    pure fn get(&self, i: uint) -> &self/T {
+        if i >= self.nelts { fail ~"deque index out of bounds" }
+        get(self.elts, (self.lo + i) % self.elts.len())
     }
 
-    impl <T: Copy> Repr<T>: Deque<T> {
-        fn size() -> uint { return self.nelts; }
-        fn add_front(t: T) {
-            let oldlo: uint = self.lo;
-            if self.lo == 0u {
-                self.lo = self.elts.len() - 1u;
-            } else { self.lo -= 1u; }
-            if self.lo == self.hi {
-                self.elts.swap(|v| grow(self.nelts, oldlo, move v));
-                self.lo = self.elts.len() - 1u;
-                self.hi = self.nelts;
-            }
-            self.elts.set_elt(self.lo, Some(t));
-            self.nelts += 1u;
-        }
-        fn add_back(t: T) {
-            if self.lo == self.hi && self.nelts != 0u {
-                self.elts.swap(|v| grow(self.nelts, self.lo, move v));
-                self.lo = 0u;
-                self.hi = self.nelts;
-            }
-            self.elts.set_elt(self.hi, Some(t));
-            self.hi = (self.hi + 1u) % self.elts.len();
-            self.nelts += 1u;
+    /// Visit all values from back to front
+    pure fn each_reverse(&self, f: fn(&T) -> bool) {
+        // range_rev yields `nelts` down to 1, excluding the lower bound
+        for uint::range_rev(self.nelts, 0) |i| {
+            if !f(self.get(i - 1)) { return }
         }
-        /**
-         * We actually release (turn to none()) the T we're popping so
-         * that we don't keep anyone's refcount up unexpectedly.
-         */
-        fn pop_front() -> T {
-            let t: T = get(&self.elts, self.lo);
-            self.elts.set_elt(self.lo, None);
-            self.lo = (self.lo + 1u) % self.elts.len();
-            self.nelts -= 1u;
-            return t;
-        }
-        fn pop_back() -> T {
-            if self.hi == 0u {
-                self.hi = self.elts.len() - 1u;
-            } else { self.hi -= 1u; }
-            let t: T = get(&self.elts, self.hi);
-            self.elts.set_elt(self.hi, None);
-            self.nelts -= 1u;
-            return t;
-        }
-        fn peek_front() -> T { return get(&self.elts, self.lo); }
-        fn peek_back() -> T { return get(&self.elts, self.hi - 1u); }
-        fn get(i: int) -> T {
-            let idx = (self.lo + (i as uint)) % self.elts.len();
-            return get(&self.elts, idx);
+    }
+
+    /// Prepend an element to the deque
+    fn add_front(&mut self, t: T) {
+        if self.nelts == self.elts.len() { self.grow() }
+        if self.lo == 0 {
+            self.lo = self.elts.len() - 1;
+        } else {
+            self.lo -= 1;
         }
+        self.elts[self.lo] = Some(move t);
+        self.nelts += 1;
+    }
+
+    /// Append an element to the deque
+    fn add_back(&mut self, t: T) {
+        if self.nelts == self.elts.len() { self.grow() }
+        let hi = (self.lo + self.nelts) % self.elts.len();
+        self.elts[hi] = Some(move t);
+        self.nelts += 1;
+    }
+
+    /// Remove and return the front element - fails if empty
+    fn pop_front(&mut self) -> T {
+        if self.nelts == 0 { fail ~"pop_front: empty deque" }
+        let t = self.elts[self.lo].swap_unwrap();
+        self.lo = (self.lo + 1) % self.elts.len();
+        self.nelts -= 1;
+        move t
     }
 
-    let repr: Repr<T> = Repr {
-        nelts: 0u,
-        lo: 0u,
-        hi: 0u,
-        elts: dvec::from_vec(vec::from_elem(initial_capacity, None)),
-    };
+    /// Remove and return the back element - fails if empty
+    fn pop_back(&mut self) -> T {
+        if self.nelts == 0 { fail ~"pop_back: empty deque" }
+        let hi = (self.lo + self.nelts - 1) % self.elts.len();
+        let t = self.elts[hi].swap_unwrap();
+        self.nelts -= 1;
+        move t
+    }
+
+    /// Remove and return the front element - None if empty
+    fn maybe_pop_front(&mut self) -> Option<T> {
+        if self.is_empty() { None } else { Some(self.pop_front()) }
+    }
+
+    /// Remove and return the back element - None if empty
+    fn maybe_pop_back(&mut self) -> Option<T> {
+        if self.is_empty() { None } else { Some(self.pop_back()) }
+    }
 
-    repr as Deque::<T>
+    /// Grow is only called on a full buffer. The elements are moved into
+    /// a buffer of twice the size, with the front element at index 0.
+    priv fn grow(&mut self) {
+        let len = self.elts.len();
+        let mut elts = vec::from_fn(len * 2, |_| None);
+        for uint::range(0, len) |i| {
+            elts[i] <-> self.elts[(self.lo + i) % len];
+        }
+        self.elts = move elts;
+        self.lo = 0;
+    }
+}
+
+pure fn get<T>(elts: &r/[Option<T>], i: uint) -> &r/T {
+    match elts[i] { Some(ref t) => t, None => fail }
 }
 
 #[cfg(test)]
 mod tests {
     use core::prelude::*;
 
-    use deque::*;
-    use deque;
+    use deque::Deque;
 
     #[test]
     fn test_simple() {
-        let d: deque::Deque<int> = deque::create::<int>();
-        assert (d.size() == 0u);
+        let mut d = Deque::new();
+        assert (d.len() == 0u);
         d.add_front(17);
         d.add_front(42);
         d.add_back(137);
-        assert (d.size() == 3u);
+        assert (d.len() == 3u);
         d.add_back(137);
-        assert (d.size() == 4u);
-        log(debug, d.peek_front());
-        assert (d.peek_front() == 42);
-        log(debug, d.peek_back());
-        assert (d.peek_back() == 137);
+        assert (d.len() == 4u);
+        log(debug, *d.peek_front());
+        assert (*d.peek_front() == 42);
+        log(debug, *d.peek_back());
+        assert (*d.peek_back() == 137);
         let mut i: int = d.pop_front();
         log(debug, i);
         assert (i == 42);
@@ -164,23 +185,23 @@ mod tests {
         i = d.pop_back();
         log(debug, i);
         assert (i == 17);
-        assert (d.size() == 0u);
+        assert (d.len() == 0u);
         d.add_back(3);
-        assert (d.size() == 1u);
+        assert (d.len() == 1u);
         d.add_front(2);
-        assert (d.size() == 2u);
+        assert (d.len() == 2u);
         d.add_back(4);
-        assert (d.size() == 3u);
+        assert (d.len() == 3u);
         d.add_front(1);
-        assert (d.size() == 4u);
-        log(debug, d.get(0));
-        log(debug, d.get(1));
-        log(debug, d.get(2));
-        log(debug, d.get(3));
-        assert (d.get(0) == 1);
-        assert (d.get(1) == 2);
-        assert (d.get(2) == 3);
-        assert (d.get(3) == 4);
+        assert (d.len() == 4u);
+        log(debug, *d.get(0));
+        log(debug, *d.get(1));
+        log(debug, *d.get(2));
+        log(debug, *d.get(3));
+        assert (*d.get(0) == 1);
+        assert (*d.get(1) == 2);
+        assert (*d.get(2) == 3);
+        assert (*d.get(3) == 4);
     }
 
     #[test]
@@ -190,63 +211,63 @@ mod tests {
         let c: @int = @64;
         let d: @int = @175;
 
-        let deq: deque::Deque<@int> = deque::create::<@int>();
-        assert (deq.size() == 0u);
+        let mut deq = Deque::new();
+        assert (deq.len() == 0u);
         deq.add_front(a);
         deq.add_front(b);
         deq.add_back(c);
-        assert (deq.size() == 3u);
+        assert (deq.len() == 3u);
         deq.add_back(d);
-        assert (deq.size() == 4u);
-        assert (deq.peek_front() == b);
-        assert (deq.peek_back() == d);
+        assert (deq.len() == 4u);
+        assert (*deq.peek_front() == b);
+        assert (*deq.peek_back() == d);
         assert (deq.pop_front() == b);
         assert (deq.pop_back() == d);
         assert (deq.pop_back() == c);
         assert (deq.pop_back() == a);
-        assert (deq.size() == 0u);
+        assert (deq.len() == 0u);
         deq.add_back(c);
-        assert (deq.size() == 1u);
+        assert (deq.len() == 1u);
         deq.add_front(b);
-        assert (deq.size() == 2u);
+        assert (deq.len() == 2u);
         deq.add_back(d);
-        assert (deq.size() == 3u);
+        assert (deq.len() == 3u);
         deq.add_front(a);
-        assert (deq.size() == 4u);
-        assert (deq.get(0) == a);
-        assert (deq.get(1) == b);
-        assert (deq.get(2) == c);
-        assert (deq.get(3) == d);
+        assert (deq.len() == 4u);
+        assert (*deq.get(0) == a);
+        assert (*deq.get(1) == b);
+        assert (*deq.get(2) == c);
+        assert (*deq.get(3) == d);
     }
 
     fn test_parameterized<T: Copy Eq Durable>(a: T, b: T, c: T, d: T) {
-        let deq: deque::Deque<T> = deque::create::<T>();
-        assert (deq.size() == 0u);
+        let mut deq = Deque::new();
+        assert (deq.len() == 0u);
         deq.add_front(a);
         deq.add_front(b);
         deq.add_back(c);
-        assert (deq.size() == 3u);
+        assert (deq.len() == 3u);
         deq.add_back(d);
-        assert (deq.size() == 4u);
-        assert deq.peek_front() == b;
-        assert deq.peek_back() == d;
+        assert (deq.len() == 4u);
+        assert *deq.peek_front() == b;
+        assert *deq.peek_back() == d;
         assert deq.pop_front() == b;
         assert deq.pop_back() == d;
         assert deq.pop_back() == c;
         assert deq.pop_back() == a;
-        assert (deq.size() == 0u);
+        assert (deq.len() == 0u);
         deq.add_back(c);
-        assert (deq.size() == 1u);
+        assert (deq.len() == 1u);
         deq.add_front(b);
-        assert (deq.size() == 2u);
+        assert (deq.len() == 2u);
         deq.add_back(d);
-        assert (deq.size() == 3u);
+        assert (deq.len() == 3u);
         deq.add_front(a);
-        assert (deq.size() == 4u);
-        assert deq.get(0) == a;
-        assert deq.get(1) == b;
-        assert deq.get(2) == c;
-        assert deq.get(3) == d;
+        assert (deq.len() == 4u);
+        assert *deq.get(0) == a;
+        assert *deq.get(1) == b;
+        assert *deq.get(2) == c;
+        assert *deq.get(3) == d;
     }
 
     enum Taggy { One(int), Two(int, int), Three(int, int, int), }
@@ -346,4 +367,106 @@ mod tests {
         let reccy4 = RecCy { x: 19, y: 252, t: Two(17, 42) };
         test_parameterized::<RecCy>(reccy1, reccy2, reccy3, reccy4);
     }
+
+    #[test]
+    fn test_owned() {
+        let mut deq = Deque::new();
+        deq.add_back(~1);
+        deq.add_front(~0);
+        deq.add_back(~2);
+        assert *deq.get(0) == ~0;
+        assert deq.pop_front() == ~0;
+        assert deq.pop_back() == ~2;
+        assert deq.pop_back() == ~1;
+        assert deq.is_empty();
+    }
+
+    #[test]
+    fn test_grow() {
+        let mut deq = Deque::new();
+        for uint::range(0, 100) |i| {
+            if i % 2 == 0 { deq.add_back(i) } else { deq.add_front(i) }
+        }
+        assert deq.len() == 100;
+        assert *deq.peek_front() == 99;
+        assert *deq.peek_back() == 98;
+        for uint::range(0, 50) |i| {
+            assert deq.pop_front() == 99 - 2 * i;
+        }
+        for uint::range(0, 50) |i| {
+            assert deq.pop_back() == 98 - 2 * i;
+        }
+        assert deq.is_empty();
+        assert deq.maybe_pop_front().is_none();
+        assert deq.maybe_pop_back().is_none();
+    }
+
+    #[test]
+    fn test_wraparound() {
+        let mut deq = Deque::new();
+        for uint::range(0, 1000) |i| {
+            deq.add_back(i);
+            if i >= 10 { assert deq.pop_front() == i - 10 }
+        }
+        assert deq.len() == 10;
+        assert deq[0] == 990;
+        assert deq[9] == 999;
+    }
+
+    #[test]
+    fn test_each() {
+        let mut deq = Deque::new();
+        for uint::range(0, 5) |i| { deq.add_back(i) }
+        deq.add_front(42);
+
+        let mut v = ~[];
+        for deq.each |x| { v.push(*x) }
+        assert v == ~[42, 0, 1, 2, 3, 4];
+
+        let mut v = ~[];
+        for deq.each_reverse |x| { v.push(*x) }
+        assert v == ~[4, 3, 2, 1, 0, 42];
+
+        let mut v = ~[];
+        for deq.each |x| { v.push(*x); if *x == 1 { break } }
+        assert v == ~[42, 0, 1];
+    }
+
+    #[test]
+    fn test_each_wrapped() {
+        let mut deq = Deque::new();
+        for uint::range(0, 40) |i| {
+            deq.add_back(i);
+            if i >= 4 { deq.pop_front(); }
+        }
+        deq.add_front(99);
+
+        let mut v = ~[];
+        for deq.each |x| { v.push(*x) }
+        assert v == ~[99, 36, 37, 38, 39];
+
+        let mut v = ~[];
+        for deq.each_reverse |x| { v.push(*x) }
+        assert v == ~[39, 38, 37, 36, 99];
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut deq = Deque::new();
+        deq.add_back(1);
+        deq.add_front(0);
+        deq.clear();
+        assert deq.is_empty();
+        deq.add_back(2);
+        assert *deq.peek_front() == 2;
+        assert *deq.peek_back() == 2;
+    }
+
+    #[test]
+    #[should_fail]
+    #[ignore(cfg(windows))]
+    fn test_pop_empty() {
+        let mut deq: Deque<int> = Deque::new();
+        deq.pop_front();
+    }
 }
@@ -11,6 +11,7 @@
 //! Types/fns concerning Internet Protocol (IP), versions 4 & 6
 #[forbid(deprecated_mode)];
 
+use core::from_str::FromStr;
 use core::libc;
 use core::oldcomm;
 use core::prelude::*;
@@ -151,6 +152,588 @@ pub fn get_addr(node: &str, iotask: iotask)
     }
 }
 
+/// An IPv4 address held as a 32-bit integer in host byte order
+#[deriving_eq]
+pub struct Ipv4Addr {
+    priv bits: u32
+}
+
+/// An IPv6 address held as two 64-bit halves in host byte order
+#[deriving_eq]
+pub struct Ipv6Addr {
+    priv hi: u64,
+    priv lo: u64
+}
+
+/// An IPv4 network in CIDR notation, such as `10.0.0.0/8`
+#[deriving_eq]
+pub struct Ipv4Net {
+    priv addr: Ipv4Addr,
+    priv prefix: uint
+}
+
+/// An IPv6 network in CIDR notation, such as `2001:db8::/32`
+#[deriving_eq]
+pub struct Ipv6Net {
+    priv addr: Ipv6Addr,
+    priv prefix: uint
+}
+
+pub impl Ipv4Addr {
+    /// Create an address from its four octets, most significant first
+    static pure fn new(a: u8, b: u8, c: u8, d: u8) -> Ipv4Addr {
+        Ipv4Addr {
+            bits: ((a as u32) << 24) | ((b as u32) << 16) |
+                  ((c as u32) << 8) | (d as u32)
+        }
+    }
+
+    /// Create an address from a 32-bit integer in host byte order
+    static pure fn from_u32(bits: u32) -> Ipv4Addr { Ipv4Addr { bits: bits } }
+
+    /**
+     * Extract the address from an `IpAddr`, discarding its port
+     *
+     * Returns `None` if `ip` is not an IPv4 address.
+     */
+    static fn from_ip_addr(ip: &IpAddr) -> Option<Ipv4Addr> {
+        match *ip {
+          Ipv4(_) => match parse_ipv4_addr(format_addr(ip)) {
+            Ok(addr) => Some(addr),
+            Err(_) => None
+          },
+          Ipv6(_) => None
+        }
+    }
+
+    /// Return the address as a 32-bit integer in host byte order
+    pure fn to_u32(&self) -> u32 { self.bits }
+
+    /// Return the four octets of the address, most significant first
+    pure fn octets(&self) -> ~[u8] {
+        ~[(self.bits >> 24) as u8, (self.bits >> 16) as u8,
+          (self.bits >> 8) as u8, self.bits as u8]
+    }
+
+    /// Returns true for `0.0.0.0`
+    pure fn is_unspecified(&self) -> bool { self.bits == 0 }
+
+    /// Returns true for addresses in `127.0.0.0/8`
+    pure fn is_loopback(&self) -> bool { self.bits >> 24 == 127 }
+
+    /// Returns true for the RFC 1918 ranges `10.0.0.0/8`, `172.16.0.0/12`
+    /// and `192.168.0.0/16`
+    pure fn is_private(&self) -> bool {
+        self.bits >> 24 == 10 ||
+            self.bits >> 20 == 0xac1 ||
+            self.bits >> 16 == 0xc0a8
+    }
+
+    /// Returns true for addresses in `169.254.0.0/16`
+    pure fn is_link_local(&self) -> bool { self.bits >> 16 == 0xa9fe }
+
+    /// Returns true for addresses in `224.0.0.0/4`
+    pure fn is_multicast(&self) -> bool { self.bits >> 28 == 0xe }
+
+    /// Returns true for `255.255.255.255`
+    pure fn is_broadcast(&self) -> bool { self.bits == 0xffffffff }
+
+    /// Return the IPv4-mapped IPv6 address `::ffff:a.b.c.d`
+    pure fn to_ipv6_mapped(&self) -> Ipv6Addr {
+        Ipv6Addr { hi: 0, lo: 0xffff00000000u64 | (self.bits as u64) }
+    }
+
+    /// Convert to an `IpAddr` with the given port
+    fn to_ip_addr(&self, port: uint) -> IpAddr {
+        unsafe { Ipv4(uv_ip4_addr(self.to_str(), port as int)) }
+    }
+}
+
+pub impl Ipv6Addr {
+    /// Create an address from its eight 16-bit segments, most significant
+    /// first
+    static pure fn new(a: u16, b: u16, c: u16, d: u16,
+                       e: u16, f: u16, g: u16, h: u16) -> Ipv6Addr {
+        Ipv6Addr {
+            hi: ((a as u64) << 48) | ((b as u64) << 32) |
+                ((c as u64) << 16) | (d as u64),
+            lo: ((e as u64) << 48) | ((f as u64) << 32) |
+                ((g as u64) << 16) | (h as u64)
+        }
+    }
+
+    /**
+     * Extract the address from an `IpAddr`, discarding its port
+     *
+     * Returns `None` if `ip` is not an IPv6 address.
+     */
+    static fn from_ip_addr(ip: &IpAddr) -> Option<Ipv6Addr> {
+        match *ip {
+          Ipv4(_) => None,
+          Ipv6(_) => match parse_ipv6_addr(format_addr(ip)) {
+            Ok(addr) => Some(addr),
+            Err(_) => None
+          }
+        }
+    }
+
+    /// Return the eight 16-bit segments of the address, most significant
+    /// first
+    pure fn segments(&self) -> ~[u16] {
+        ~[(self.hi >> 48) as u16, (self.hi >> 32) as u16,
+          (self.hi >> 16) as u16, self.hi as u16,
+          (self.lo >> 48) as u16, (self.lo >> 32) as u16,
+          (self.lo >> 16) as u16, self.lo as u16]
+    }
+
+    /// Return the sixteen octets of the address, most significant first
+    pure fn octets(&self) -> ~[u8] {
+        do vec::from_fn(16) |i| {
+            let half = if i < 8 { self.hi } else { self.lo };
+            (half >> (56 - 8 * (i % 8))) as u8
+        }
+    }
+
+    /// Returns true for `::`
+    pure fn is_unspecified(&self) -> bool { self.hi == 0 && self.lo == 0 }
+
+    /// Returns true for `::1`
+    pure fn is_loopback(&self) -> bool { self.hi == 0 && self.lo == 1 }
+
+    /// Returns true for addresses in `fe80::/10`
+    pure fn is_link_local(&self) -> bool { self.hi >> 54 == 0x3fa }
+
+    /// Returns true for the unique local addresses in `fc00::/7`
+    pure fn is_unique_local(&self) -> bool { self.hi >> 57 == 0x7e }
+
+    /// Returns true for addresses in `ff00::/8`
+    pure fn is_multicast(&self) -> bool { self.hi >> 56 == 0xff }
+
+    /**
+     * Return the embedded IPv4 address if this is an IPv4-mapped address
+     * (`::ffff:a.b.c.d`), or `None` otherwise
+     */
+    pure fn to_ipv4_mapped(&self) -> Option<Ipv4Addr> {
+        if self.hi == 0 && self.lo >> 32 == 0xffff {
+            Some(Ipv4Addr { bits: self.lo as u32 })
+        } else {
+            None
+        }
+    }
+
+    /// Convert to an `IpAddr` with the given port
+    fn to_ip_addr(&self, port: uint) -> IpAddr {
+        unsafe { Ipv6(uv_ip6_addr(self.to_str(), port as int)) }
+    }
+}
+
+impl Ipv4Addr: Ord {
+    pure fn lt(&self, other: &Ipv4Addr) -> bool { self.bits < other.bits }
+    pure fn le(&self, other: &Ipv4Addr) -> bool { self.bits <= other.bits }
+    pure fn ge(&self, other: &Ipv4Addr) -> bool { self.bits >= other.bits }
+    pure fn gt(&self, other: &Ipv4Addr) -> bool { self.bits > other.bits }
+}
+
+impl Ipv6Addr: Ord {
+    pure fn lt(&self, other: &Ipv6Addr) -> bool {
+        self.hi < other.hi || (self.hi == other.hi && self.lo < other.lo)
+    }
+    pure fn le(&self, other: &Ipv6Addr) -> bool { !(*other).lt(self) }
+    pure fn ge(&self, other: &Ipv6Addr) -> bool { !(*self).lt(other) }
+    pure fn gt(&self, other: &Ipv6Addr) -> bool { (*other).lt(self) }
+}
+
+impl Ipv4Addr: ToStr {
+    pure fn to_str() -> ~str {
+        fmt!("%u.%u.%u.%u", (self.bits >> 24) as uint,
+             (self.bits >> 16) as uint & 0xff,
+             (self.bits >> 8) as uint & 0xff,
+             self.bits as uint & 0xff)
+    }
+}
+
+/**
+ * Formats the address as recommended by RFC 5952: lower-case hex digits
+ * without leading zeros, with the longest run of two or more zero segments
+ * replaced by `::`. IPv4-mapped addresses keep their dotted-quad suffix.
+ */
+impl Ipv6Addr: ToStr {
+    pure fn to_str() -> ~str {
+        match self.to_ipv4_mapped() {
+          Some(ref v4) => return ~"::ffff:" + v4.to_str(),
+          None => ()
+        }
+
+        let segs = self.segments();
+        let mut best_start = 0;
+        let mut best_len = 0;
+        let mut i = 0;
+        while i < 8 {
+            if segs[i] == 0 {
+                let mut j = i;
+                while j < 8 && segs[j] == 0 { j += 1; }
+                if j - i > best_len {
+                    best_start = i;
+                    best_len = j - i;
+                }
+                i = j;
+            } else {
+                i += 1;
+            }
+        }
+
+        if best_len < 2 {
+            ipv6_segments_to_str(segs)
+        } else {
+            ipv6_segments_to_str(vec::view(segs, 0, best_start)) + ~"::" +
+                ipv6_segments_to_str(vec::view(segs, best_start + best_len,
+                                               8))
+        }
+    }
+}
+
+pure fn ipv6_segments_to_str(segs: &[u16]) -> ~str {
+    str::connect(vec::map(segs, |s| uint::to_str(*s as uint, 16u)), ~":")
+}
+
+impl Ipv4Addr: FromStr {
+    static pure fn from_str(s: &str) -> Option<Ipv4Addr> {
+        unsafe { // purity workaround
+            match parse_ipv4_addr(s) {
+              Ok(move x) => Some(x),
+              Err(_) => None
+            }
+        }
+    }
+}
+
+impl Ipv6Addr: FromStr {
+    static pure fn from_str(s: &str) -> Option<Ipv6Addr> {
+        unsafe { // purity workaround
+            match parse_ipv6_addr(s) {
+              Ok(move x) => Some(x),
+              Err(_) => None
+            }
+        }
+    }
+}
+
+pure fn ipv4_mask(prefix: uint) -> u32 {
+    if prefix == 0 { 0 } else { !0u32 << (32 - prefix) }
+}
+
+pure fn ipv6_mask(prefix: uint) -> (u64, u64) {
+    let hi = if prefix == 0 {
+        0
+    } else if prefix >= 64 {
+        !0u64
+    } else {
+        !0u64 << (64 - prefix)
+    };
+    let lo = if prefix <= 64 { 0 } else { !0u64 << (128 - prefix) };
+    (hi, lo)
+}
+
+pub impl Ipv4Net {
+    /**
+     * Create a network from an address and a prefix length
+     *
+     * Host bits set in `addr` are cleared.
+     *
+     * # Failure
+     *
+     * Fails if `prefix` is greater than 32
+     */
+    static pure fn new(addr: Ipv4Addr, prefix: uint) -> Ipv4Net {
+        if prefix > 32 { fail ~"IPv4 prefix length out of range" }
+        Ipv4Net {
+            addr: Ipv4Addr { bits: addr.bits & ipv4_mask(prefix) },
+            prefix: prefix
+        }
+    }
+
+    /// Return the first address of the network
+    pure fn network(&self) -> Ipv4Addr { self.addr }
+
+    /// Return the number of leading bits fixed by the network
+    pure fn prefix_len(&self) -> uint { self.prefix }
+
+    /// Return the netmask, such as `255.255.255.0` for a /24 network
+    pure fn netmask(&self) -> Ipv4Addr {
+        Ipv4Addr { bits: ipv4_mask(self.prefix) }
+    }
+
+    /// Return the last address of the network
+    pure fn broadcast(&self) -> Ipv4Addr {
+        Ipv4Addr { bits: self.addr.bits | !ipv4_mask(self.prefix) }
+    }
+
+    /// Returns true if `addr` belongs to the network
+    pure fn contains(&self, addr: &Ipv4Addr) -> bool {
+        addr.bits & ipv4_mask(self.prefix) == self.addr.bits
+    }
+
+    /// Returns true if every address of `other` belongs to the network
+    pure fn contains_net(&self, other: &Ipv4Net) -> bool {
+        other.prefix >= self.prefix && self.contains(&other.addr)
+    }
+
+    /// Visit every address of the network in increasing order
+    pure fn each(&self, f: fn(&Ipv4Addr) -> bool) {
+        let last = self.broadcast().bits;
+        let mut bits = self.addr.bits;
+        loop {
+            if !f(&Ipv4Addr { bits: bits }) || bits == last { break; }
+            bits += 1;
+        }
+    }
+}
+
+pub impl Ipv6Net {
+    /**
+     * Create a network from an address and a prefix length
+     *
+     * Host bits set in `addr` are cleared.
+     *
+     * # Failure
+     *
+     * Fails if `prefix` is greater than 128
+     */
+    static pure fn new(addr: Ipv6Addr, prefix: uint) -> Ipv6Net {
+        if prefix > 128 { fail ~"IPv6 prefix length out of range" }
+        let (hi, lo) = ipv6_mask(prefix);
+        Ipv6Net {
+            addr: Ipv6Addr { hi: addr.hi & hi, lo: addr.lo & lo },
+            prefix: prefix
+        }
+    }
+
+    /// Return the first address of the network
+    pure fn network(&self) -> Ipv6Addr { self.addr }
+
+    /// Return the number of leading bits fixed by the network
+    pure fn prefix_len(&self) -> uint { self.prefix }
+
+    /// Return the netmask, such as `ffff:ffff::` for a /32 network
+    pure fn netmask(&self) -> Ipv6Addr {
+        let (hi, lo) = ipv6_mask(self.prefix);
+        Ipv6Addr { hi: hi, lo: lo }
+    }
+
+    /// Return the last address of the network
+    pure fn last(&self) -> Ipv6Addr {
+        let (hi, lo) = ipv6_mask(self.prefix);
+        Ipv6Addr { hi: self.addr.hi | !hi, lo: self.addr.lo | !lo }
+    }
+
+    /// Returns true if `addr` belongs to the network
+    pure fn contains(&self, addr: &Ipv6Addr) -> bool {
+        let (hi, lo) = ipv6_mask(self.prefix);
+        addr.hi & hi == self.addr.hi && addr.lo & lo == self.addr.lo
+    }
+
+    /// Returns true if every address of `other` belongs to the network
+    pure fn contains_net(&self, other: &Ipv6Net) -> bool {
+        other.prefix >= self.prefix && self.contains(&other.addr)
+    }
+
+    /// Visit every address of the network in increasing order
+    pure fn each(&self, f: fn(&Ipv6Addr) -> bool) {
+        let last = self.last();
+        let mut addr = self.addr;
+        loop {
+            if !f(&addr) || addr == last { break; }
+            addr.lo += 1;
+            if addr.lo == 0 { addr.hi += 1; }
+        }
+    }
+}
+
+impl Ipv4Net: ToStr {
+    pure fn to_str() -> ~str {
+        fmt!("%s/%u", self.addr.to_str(), self.prefix)
+    }
+}
+
+impl Ipv6Net: ToStr {
+    pure fn to_str() -> ~str {
+        fmt!("%s/%u", self.addr.to_str(), self.prefix)
+    }
+}
+
+impl Ipv4Net: FromStr {
+    static pure fn from_str(s: &str) -> Option<Ipv4Net> {
+        unsafe { // purity workaround
+            match parse_ipv4_net(s) {
+              Ok(move x) => Some(x),
+              Err(_) => None
+            }
+        }
+    }
+}
+
+impl Ipv6Net: FromStr {
+    static pure fn from_str(s: &str) -> Option<Ipv6Net> {
+        unsafe { // purity workaround
+            match parse_ipv6_net(s) {
+              Ok(move x) => Some(x),
+              Err(_) => None
+            }
+        }
+    }
+}
+
+/**
+ * Parse an IPv4 address in dotted-quad notation, such as `192.168.0.1`
+ *
+ * Unlike `v4::parse_addr`, this does not go through libuv and returns an
+ * `Ipv4Addr` rather than a socket address.
+ */
+pub fn parse_ipv4_addr(s: &str) -> result::Result<Ipv4Addr, ParseAddrErr> {
+    match parse_ipv4_bits(s) {
+      Some(bits) => Ok(Ipv4Addr { bits: bits }),
+      None => Err(ParseAddrErr {
+        err_msg: fmt!("invalid IPv4 address '%s'", s)
+      })
+    }
+}
+
+/**
+ * Parse an IPv6 address as described in RFC 4291 section 2.2
+ *
+ * Accepts `::` to stand for one or more zero segments, and a trailing
+ * dotted-quad IPv4 address such as `::ffff:10.0.0.1`.
+ */
+pub fn parse_ipv6_addr(s: &str) -> result::Result<Ipv6Addr, ParseAddrErr> {
+    match parse_ipv6_segments(s) {
+      Some(segs) => {
+        Ok(Ipv6Addr::new(segs[0], segs[1], segs[2], segs[3],
+                         segs[4], segs[5], segs[6], segs[7]))
+      }
+      None => Err(ParseAddrErr {
+        err_msg: fmt!("invalid IPv6 address '%s'", s)
+      })
+    }
+}
+
+/**
+ * Parse an IPv4 network such as `10.0.0.0/8`
+ *
+ * Host bits set in the address are cleared, so `10.1.2.3/8` parses to the
+ * same network as `10.0.0.0/8`.
+ */
+pub fn parse_ipv4_net(s: &str) -> result::Result<Ipv4Net, ParseAddrErr> {
+    match split_prefix(s, 32) {
+      Some((ref addr, prefix)) => match parse_ipv4_bits(*addr) {
+        Some(bits) => return Ok(Ipv4Net::new(Ipv4Addr { bits: bits },
+                                             prefix)),
+        None => ()
+      },
+      None => ()
+    }
+    Err(ParseAddrErr { err_msg: fmt!("invalid IPv4 network '%s'", s) })
+}
+
+/**
+ * Parse an IPv6 network such as `2001:db8::/32`
+ *
+ * Host bits set in the address are cleared.
+ */
+pub fn parse_ipv6_net(s: &str) -> result::Result<Ipv6Net, ParseAddrErr> {
+    match split_prefix(s, 128) {
+      Some((ref addr, prefix)) => match parse_ipv6_addr(*addr) {
+        Ok(addr) => return Ok(Ipv6Net::new(addr, prefix)),
+        Err(_) => ()
+      },
+      None => ()
+    }
+    Err(ParseAddrErr { err_msg: fmt!("invalid IPv6 network '%s'", s) })
+}
+
+// Splits `addr/prefix`, checking that the prefix is at most `max`
+fn split_prefix(s: &str, max: uint) -> Option<(~str, uint)> {
+    match str::rfind_char(s, '/') {
+      Some(i) => {
+        let prefix = str::view(s, i + 1, str::len(s));
+        if str::is_empty(prefix) || str::len(prefix) > 3 { return None }
+        match uint::from_str(prefix) {
+          Some(n) if n <= max => Some((str::slice(s, 0, i), n)),
+          _ => None
+        }
+      }
+      None => None
+    }
+}
+
+fn parse_ipv4_bits(s: &str) -> Option<u32> {
+    let parts = str::split_char(s, '.');
+    if parts.len() != 4 { return None }
+    let mut bits = 0u32;
+    for parts.each |part| {
+        let len = str::len(*part);
+        if len == 0 || len > 3 { return None }
+        match uint::from_str(*part) {
+          Some(n) if n <= 255 => bits = (bits << 8) | (n as u32),
+          _ => return None
+        }
+    }
+    Some(bits)
+}
+
+// Returns all eight segments of an IPv6 address, expanding `::`
+fn parse_ipv6_segments(s: &str) -> Option<~[u16]> {
+    match str::find_str(s, "::") {
+      None => match parse_ipv6_groups(s, true) {
+        Some(move segs) => {
+            if segs.len() == 8 { Some(move segs) } else { None }
+        }
+        None => None
+      },
+      Some(i) => {
+        let tail = str::view(s, i + 2, str::len(s));
+        if str::contains(tail, "::") { return None }
+        let head = match parse_ipv6_groups(str::view(s, 0, i), false) {
+          Some(move segs) => segs,
+          None => return None
+        };
+        let tail = match parse_ipv6_groups(tail, true) {
+          Some(move segs) => segs,
+          None => return None
+        };
+        let n = head.len() + tail.len();
+        if n > 7 { return None }
+        Some(head + vec::from_elem(8 - n, 0u16) + tail)
+      }
+    }
+}
+
+// Parses colon-separated hex groups, optionally ending in an IPv4 address
+fn parse_ipv6_groups(s: &str, allow_ipv4: bool) -> Option<~[u16]> {
+    if str::is_empty(s) { return Some(~[]) }
+    let parts = str::split_char(s, ':');
+    let mut segs = ~[];
+    for vec::eachi(parts) |i, part| {
+        if allow_ipv4 && i == parts.len() - 1 &&
+                str::contains_char(*part, '.') {
+            match parse_ipv4_bits(*part) {
+              Some(bits) => {
+                segs.push((bits >> 16) as u16);
+                segs.push(bits as u16);
+              }
+              None => return None
+            }
+        } else {
+            let len = str::len(*part);
+            if len == 0 || len > 4 { return None }
+            match uint::parse_bytes(str::to_bytes(*part), 16u) {
+              Some(n) => segs.push(n as u16),
+              None => return None
+            }
+        }
+    }
+    Some(segs)
+}
+
 pub mod v4 {
     use net::ip::{IpAddr, Ipv4, Ipv6, ParseAddrErr};
     use uv::ll;
@@ -251,9 +834,8 @@ pub mod v4 {
     }
 }
 pub mod v6 {
-    use net::ip::{IpAddr, Ipv6, ParseAddrErr};
+    use net::ip::{IpAddr, Ipv6, ParseAddrErr, parse_ipv6_addr};
     use uv_ip6_addr = uv::ll::ip6_addr;
-    use uv_ip6_name = uv::ll::ip6_name;
 
     use core::prelude::*;
     use core::result;
@@ -281,20 +863,13 @@ pub mod v6 {
         }
     }
     pub fn try_parse_addr(ip: &str) -> result::Result<IpAddr,ParseAddrErr> {
-        unsafe {
-            // need to figure out how to establish a parse failure..
-            let new_addr = uv_ip6_addr(str::from_slice(ip), 22);
-            let reparsed_name = uv_ip6_name(&new_addr);
-            log(debug, fmt!("v6::try_parse_addr ip: '%s' reparsed '%s'",
-                            ip, reparsed_name));
-            // '::' appears to be uv_ip6_name() returns for bogus
-            // parses..
-            if  ip != &"::" && reparsed_name == ~"::" {
-                Err(ParseAddrErr { err_msg:fmt!("failed to parse '%s'", ip) })
-            }
-            else {
-                Ok(Ipv6(new_addr))
-            }
+        // libuv can't report a parse failure, so validate the address
+        // with the pure Rust parser first
+        match parse_ipv6_addr(ip) {
+          result::Ok(_) => unsafe {
+            Ok(Ipv6(uv_ip6_addr(str::from_slice(ip), 22)))
+          },
+          result::Err(move err) => Err(err)
         }
     }
 }
@@ -449,4 +1024,143 @@ mod test {
         let ga_result = get_addr(localhost_name, iotask);
         assert result::is_err(&ga_result);
     }
+
+    #[test]
+    fn test_ipv4_addr_parse_and_format() {
+        let addr = result::unwrap(parse_ipv4_addr("192.168.0.1"));
+        assert addr == Ipv4Addr::new(192, 168, 0, 1);
+        assert addr.octets() == ~[192, 168, 0, 1];
+        assert addr.to_u32() == 0xc0a80001;
+        assert addr.to_str() == ~"192.168.0.1";
+        assert addr.is_private();
+        assert !addr.is_loopback();
+        assert result::unwrap(parse_ipv4_addr("255.255.255.255"))
+            .is_broadcast();
+
+        for ~[~"", ~"1.2.3", ~"1.2.3.4.5", ~"256.0.0.1", ~"1..2.3",
+             ~"1.2.3.-4", ~"0001.2.3.4", ~"a.b.c.d"].each |s| {
+            assert result::is_err(&parse_ipv4_addr(*s));
+        }
+    }
+
+    #[test]
+    fn test_ipv6_addr_parse() {
+        let addr =
+            result::unwrap(parse_ipv6_addr("2001:DB8::8:800:200C:417A"));
+        assert addr.segments() ==
+            ~[0x2001, 0xdb8, 0, 0, 0x8, 0x800, 0x200c, 0x417a];
+        assert addr.octets()[0] == 0x20 && addr.octets()[15] == 0x7a;
+
+        assert result::unwrap(parse_ipv6_addr("::")).is_unspecified();
+        assert result::unwrap(parse_ipv6_addr("::1")).is_loopback();
+        assert result::unwrap(parse_ipv6_addr("1::")) ==
+            Ipv6Addr::new(1, 0, 0, 0, 0, 0, 0, 0);
+        assert result::unwrap(parse_ipv6_addr("1:2:3:4:5:6:7:8")) ==
+            Ipv6Addr::new(1, 2, 3, 4, 5, 6, 7, 8);
+        assert result::unwrap(parse_ipv6_addr("::ffff:10.0.0.1")) ==
+            Ipv4Addr::new(10, 0, 0, 1).to_ipv6_mapped();
+        assert result::unwrap(parse_ipv6_addr("64:ff9b::192.0.2.33")) ==
+            Ipv6Addr::new(0x64, 0xff9b, 0, 0, 0, 0, 0xc000, 0x221);
+
+        for ~[~"", ~":", ~":::", ~"1:::2", ~"1::2::3", ~":1::", ~"::1:",
+             ~"1:2:3:4:5:6:7", ~"1:2:3:4:5:6:7:8:9", ~"1:2:3:4:5:6:7::8",
+             ~"12345::", ~"g::", ~"1.2.3.4::", ~"::1.2.3"].each |s| {
+            assert result::is_err(&parse_ipv6_addr(*s));
+        }
+    }
+
+    #[test]
+    fn test_ipv6_addr_format() {
+        fn roundtrip(s: &str) -> ~str {
+            result::unwrap(parse_ipv6_addr(s)).to_str()
+        }
+        assert roundtrip("::") == ~"::";
+        assert roundtrip("::1") == ~"::1";
+        assert roundtrip("2001:0db8:0000:0000:0000:0000:0002:0001") ==
+            ~"2001:db8::2:1";
+        // a single zero segment is not compressed
+        assert roundtrip("2001:db8:0:1:1:1:1:1") == ~"2001:db8:0:1:1:1:1:1";
+        // the longest run is compressed, the first one on a tie
+        assert roundtrip("2001:0:0:1:0:0:0:1") == ~"2001:0:0:1::1";
+        assert roundtrip("2001:db8:0:0:1:0:0:1") == ~"2001:db8::1:0:0:1";
+        assert roundtrip("::ffff:192.0.2.1") == ~"::ffff:192.0.2.1";
+        assert roundtrip("FE80::1") == ~"fe80::1";
+        assert result::unwrap(parse_ipv6_addr("fe80::1")).is_link_local();
+        assert result::unwrap(parse_ipv6_addr("ff02::1")).is_multicast();
+    }
+
+    #[test]
+    fn test_ip_addr_conversion() {
+        let v4 = Ipv4Addr::new(127, 0, 0, 1);
+        let ip = v4.to_ip_addr(8080);
+        assert get_port(&ip) == 8080;
+        assert Ipv4Addr::from_ip_addr(&ip) == Some(v4);
+        assert Ipv6Addr::from_ip_addr(&ip).is_none();
+
+        let v6 = result::unwrap(parse_ipv6_addr("::1"));
+        let ip = v6.to_ip_addr(80);
+        assert Ipv6Addr::from_ip_addr(&ip) == Some(v6);
+        assert Ipv4Addr::from_ip_addr(&ip).is_none();
+    }
+
+    #[test]
+    fn test_ipv4_net() {
+        let net = result::unwrap(parse_ipv4_net("10.1.2.3/8"));
+        assert net.network() == Ipv4Addr::new(10, 0, 0, 0);
+        assert net.prefix_len() == 8;
+        assert net.netmask() == Ipv4Addr::new(255, 0, 0, 0);
+        assert net.broadcast() == Ipv4Addr::new(10, 255, 255, 255);
+        assert net.to_str() == ~"10.0.0.0/8";
+        assert net.contains(&Ipv4Addr::new(10, 200, 0, 1));
+        assert !net.contains(&Ipv4Addr::new(11, 0, 0, 0));
+        assert net.contains_net(&Ipv4Net::new(Ipv4Addr::new(10, 1, 0, 0),
+                                              16));
+        assert !net.contains_net(&Ipv4Net::new(Ipv4Addr::new(0, 0, 0, 0),
+                                               0));
+
+        let mut addrs = ~[];
+        let net = Ipv4Net::new(Ipv4Addr::new(192, 168, 1, 5), 30);
+        for net.each |a| { addrs.push(a.to_str()) }
+        assert addrs == ~[~"192.168.1.4", ~"192.168.1.5",
+                          ~"192.168.1.6", ~"192.168.1.7"];
+
+        // iteration stops at the top of the address space
+        let mut n = 0;
+        let net = Ipv4Net::new(Ipv4Addr::new(255, 255, 255, 255), 31);
+        for net.each |_| { n += 1 }
+        assert n == 2;
+
+        for ~[~"10.0.0.0", ~"10.0.0.0/33", ~"10.0.0.0/", ~"10.0.0/8",
+             ~"10.0.0.0/x"].each |s| {
+            assert result::is_err(&parse_ipv4_net(*s));
+        }
+    }
+
+    #[test]
+    fn test_ipv6_net() {
+        let net = result::unwrap(parse_ipv6_net("2001:db8:1::1/32"));
+        assert net.to_str() == ~"2001:db8::/32";
+        assert net.netmask().to_str() == ~"ffff:ffff::";
+        assert net.contains(&result::unwrap(parse_ipv6_addr("2001:db8::")));
+        assert !net.contains(&result::unwrap(parse_ipv6_addr("2001:db9::")));
+        assert net.last().to_str() ==
+            ~"2001:db8:ffff:ffff:ffff:ffff:ffff:ffff";
+
+        let host = result::unwrap(parse_ipv6_net("::1/128"));
+        assert host.contains(&result::unwrap(parse_ipv6_addr("::1")));
+        assert !host.contains(&result::unwrap(parse_ipv6_addr("::2")));
+
+        let mut addrs = ~[];
+        let net = result::unwrap(parse_ipv6_net("::ffff:ffff:ffff:fffe/126"));
+        for net.each |a| { addrs.push(a.to_str()) }
+        assert addrs == ~[~"::ffff:ffff:ffff:fffc", ~"::ffff:ffff:ffff:fffd",
+                          ~"::ffff:ffff:ffff:fffe", ~"::ffff:ffff:ffff:ffff"];
+
+        let net = result::unwrap(parse_ipv6_net("::/64"));
+        let mut n = 0;
+        for net.each |_| { n += 1; if n == 3 { break } }
+        assert n == 3;
+
+        assert result::is_err(&parse_ipv6_net("::/129"));
+    }
 }
@@ -18,6 +18,7 @@ use core::io::{Reader, ReaderUtil};
 use core::io;
 use core::prelude::*;
 use core::hashmap::linear::LinearMap;
+use core::result;
 use core::str;
 use core::to_bytes::IterBytes;
 use core::to_bytes;
@@ -27,6 +28,8 @@ use core::uint;
 use core::util;
 use core::vec;
 
+use net_ip;
+
 #[deriving_eq]
 struct Url {
     scheme: ~str,
@@ -66,6 +69,90 @@ pub impl Url {
             fragment: fragment,
         }
     }
+
+    /**
+     * Resolve a URI reference against this URL
+     *
+     * This implements the strict algorithm of RFC 3986 section 5.2, so
+     * that for example `../img/a.png` joined to `http://a/b/c/d` gives
+     * `http://a/b/img/a.png`. A reference with its own scheme is returned
+     * as is, apart from the removal of dot segments from its path.
+     */
+    fn join(&self, reference: &str) -> Result<Url, ~str> {
+        let Reference {
+            scheme: scheme,
+            authority: authority,
+            path: path,
+            query: query,
+            fragment: fragment
+        } = match parse_reference(reference) {
+            Ok(move r) => r,
+            Err(move e) => return Err(e)
+        };
+
+        let has_scheme = scheme.is_some();
+        let scheme = match scheme {
+            Some(move scheme) => scheme,
+            None => copy self.scheme
+        };
+
+        let (user, host, port, path, query) = match authority {
+          Some((move user, move host, move port)) => {
+            (user, host, port, remove_dot_segments(path),
+             query.get_or_default(~[]))
+          }
+          None if has_scheme => {
+            (None, ~"", None, remove_dot_segments(path),
+             query.get_or_default(~[]))
+          }
+          None => {
+            let (path, query) = if path.is_empty() {
+                (copy self.path, query.get_or_default(copy self.query))
+            } else if str::starts_with(path, "/") {
+                (remove_dot_segments(path), query.get_or_default(~[]))
+            } else {
+                (remove_dot_segments(merge_paths(self, path)),
+                 query.get_or_default(~[]))
+            };
+            (copy self.user, copy self.host, copy self.port, path, query)
+          }
+        };
+
+        Ok(Url::new(scheme, user, host, port, path, query, fragment))
+    }
+
+    /**
+     * Return the URL in the normal form described by RFC 3986 section 6
+     *
+     * The scheme and host are lower-cased, IPv6 hosts are written in their
+     * canonical form, a default port for the scheme is dropped, dot
+     * segments are removed from the path, and an empty path is replaced by
+     * `/` when there is a host.
+     */
+    fn normalize(&self) -> Url {
+        let scheme = str::to_lower(self.scheme);
+        let host = normalize_host(self.host);
+        let port = match self.port {
+            Some(ref port) => match uint::from_str(*port) {
+                Some(n) if Some(n) == default_port(scheme) => None,
+                Some(n) => Some(uint::str(n)),
+                None => None
+            },
+            None => None
+        };
+        let path = if self.path.is_empty() && !host.is_empty() {
+            ~"/"
+        } else {
+            remove_dot_segments(self.path)
+        };
+        Url::new(scheme, copy self.user, host, port, path,
+                 copy self.query, copy self.fragment)
+    }
+
+    /// Returns true if both URLs have the same normal form
+    fn equiv(&self, other: &Url) -> bool {
+        self.normalize() == other.normalize()
+    }
 }
 
 pub impl UserInfo {
@@ -420,6 +507,7 @@ pure fn get_authority(rawurl: &str) ->
         PassHostPort, // could be in user or port
         Ip6Port, // either in ipv6 host or port
         Ip6Host, // are in an ipv6 host
+        Ip6Literal, // just past a bracketed ipv6 host
         InHost, // are in a host - may be ipv6, but don't know yet
         InPort // are in port
     }
@@ -434,9 +522,48 @@ pure fn get_authority(rawurl: &str) ->
 
     let mut colon_count = 0;
     let mut pos = 0, begin = 2, end = len;
+    let mut literal_end = 0;
 
     for str::each_chari(rawurl) |i,c| {
         if i < 2 { loop; } // ignore the leading //
+        if i < literal_end { loop; } // skip over a bracketed host
+
+        if c == '[' {
+            // an IP literal must make up the whole host
+            let at_host = match st {
+              Start | InHost => i == begin,
+              _ => false
+            };
+            if !at_host {
+                return Err(~"Invalid '[' in authority.");
+            }
+            match str::find_char_from(rawurl, ']', i) {
+              Some(close) => {
+                let addr = str::view(rawurl, i + 1, close);
+                // FIXME(#3722): unsafe only because the ipv6 parser pushes
+                // onto vectors
+                let valid = unsafe {
+                    result::is_ok(&net_ip::parse_ipv6_addr(addr))
+                };
+                if !valid {
+                    return Err(~"Invalid IPv6 address in authority.");
+                }
+                host = str::from_slice(addr);
+                literal_end = close + 1;
+                st = Ip6Literal;
+              }
+              None => return Err(~"Unterminated '[' in authority.")
+            }
+            loop;
+        }
+
+        match st {
+          Ip6Literal => match c {
+            ':' | '?' | '#' | '/' => (),
+            _ => return Err(~"Invalid character after IPv6 address.")
+          },
+          _ => ()
+        }
 
         // deal with input class first
         match c {
@@ -496,6 +623,10 @@ pure fn get_authority(rawurl: &str) ->
                     st = InPort;
                 }
               }
+              Ip6Literal => {
+                pos = i;
+                st = InPort;
+              }
               _ => {
                 return Err(~"Invalid ':' in authority.");
               }
@@ -531,24 +662,12 @@ pure fn get_authority(rawurl: &str) ->
           }
           _ => ()
         }
-        end = i;
     }
 
-    let end = end; // make end immutable so it can be captured
-
-    let host_is_end_plus_one: &pure fn() -> bool = || {
-        end+1 == len
-            && !['?', '#', '/'].contains(&(rawurl[end] as char))
-    };
-
     // finish up
     match st {
       Start => {
-        if host_is_end_plus_one() {
-            host = str::slice(rawurl, begin, end+1);
-        } else {
-            host = str::slice(rawurl, begin, end);
-        }
+        host = str::slice(rawurl, begin, end);
       }
       PassHostPort | Ip6Port => {
         if in != Digit {
@@ -560,6 +679,7 @@ pure fn get_authority(rawurl: &str) ->
       Ip6Host | InHost => {
         host = str::slice(rawurl, begin, end);
       }
+      Ip6Literal => (),
       InPort => {
         if in != Digit {
             return Err(~"Non-digit characters in port.");
@@ -568,8 +688,7 @@ pure fn get_authority(rawurl: &str) ->
       }
     }
 
-    let rest = if host_is_end_plus_one() { ~"" }
-    else { str::slice(rawurl, end, len) };
+    let rest = str::slice(rawurl, end, len);
     return Ok((userinfo, host, port, rest));
 }
 
@@ -667,6 +786,141 @@ pub pure fn from_str(rawurl: &str) -> Result<Url, ~str> {
     Ok(Url::new(scheme, userinfo, host, port, path, query, fragment))
 }
 
+// A parsed URI reference, which unlike a `Url` may be relative and
+// distinguishes an empty authority or query from a missing one
+struct Reference {
+    scheme: Option<~str>,
+    authority: Option<(Option<UserInfo>, ~str, Option<~str>)>,
+    path: ~str,
+    query: Option<Query>,
+    fragment: Option<~str>
+}
+
+fn parse_reference(rawref: &str) -> Result<Reference, ~str> {
+    // scheme
+    let (scheme, rest) = match get_scheme(rawref) {
+        Ok((move scheme, move rest)) => (Some(scheme), rest),
+        Err(_) => (None, str::from_slice(rawref)),
+    };
+
+    // authority
+    let has_authority = str::starts_with(rest, "//");
+    let (userinfo, host, port, rest) = match get_authority(rest) {
+        Ok(val) => val,
+        Err(e) => return Err(e),
+    };
+
+    // path
+    let (path, rest) = match get_path(rest, has_authority) {
+        Ok(val) => val,
+        Err(e) => return Err(e),
+    };
+
+    // query and fragment
+    let has_query = str::starts_with(rest, "?");
+    let (query, fragment) = match get_query_fragment(rest) {
+        Ok(val) => val,
+        Err(e) => return Err(e),
+    };
+
+    Ok(Reference {
+        scheme: scheme,
+        authority: if has_authority {
+            Some((userinfo, host, port))
+        } else {
+            None
+        },
+        path: path,
+        query: if has_query { Some(query) } else { None },
+        fragment: fragment
+    })
+}
+
+// Merges a relative path onto the path of `base` (RFC 3986 section 5.2.3)
+pure fn merge_paths(base: &Url, path: &str) -> ~str {
+    if !base.host.is_empty() && base.path.is_empty() {
+        ~"/" + path
+    } else {
+        match str::rfind_char(base.path, '/') {
+            Some(i) => str::slice(base.path, 0, i + 1) + path,
+            None => str::from_slice(path),
+        }
+    }
+}
+
+/**
+ * Remove the `.` and `..` segments from a path
+ *
+ * This is the algorithm of RFC 3986 section 5.2.4. A `..` segment that
+ * would climb above the root is dropped, so `/a/../../b` becomes `/b`.
+ */
+pub pure fn remove_dot_segments(path: &str) -> ~str {
+    let mut input = str::from_slice(path);
+    let mut output = ~"";
+
+    while !input.is_empty() {
+        if str::starts_with(input, "../") {
+            input = str::slice(input, 3, input.len());
+        } else if str::starts_with(input, "./") {
+            input = str::slice(input, 2, input.len());
+        } else if str::starts_with(input, "/./") {
+            input = str::slice(input, 2, input.len());
+        } else if input == ~"/." {
+            input = ~"/";
+        } else if str::starts_with(input, "/../") {
+            input = str::slice(input, 3, input.len());
+            output = pop_segment(output);
+        } else if input == ~"/.." {
+            input = ~"/";
+            output = pop_segment(output);
+        } else if input == ~"." || input == ~".." {
+            input = ~"";
+        } else {
+            // move the first segment, with its leading '/', to the output
+            let start = if str::starts_with(input, "/") { 1 } else { 0 };
+            let end = match str::find_char_from(input, '/', start) {
+                Some(i) => i,
+                None => input.len(),
+            };
+            output = output + str::view(input, 0, end);
+            input = str::slice(input, end, input.len());
+        }
+    }
+
+    output
+}
+
+// Removes the last segment and its preceding '/', if any
+pure fn pop_segment(path: &str) -> ~str {
+    match str::rfind_char(path, '/') {
+        Some(i) => str::slice(path, 0, i),
+        None => ~"",
+    }
+}
+
+pure fn normalize_host(host: &str) -> ~str {
+    if str::contains_char(host, ':') {
+        // FIXME(#3722): unsafe only because the ipv6 parser pushes onto
+        // vectors
+        unsafe {
+            match net_ip::parse_ipv6_addr(host) {
+                Ok(addr) => return addr.to_str(),
+                Err(_) => ()
+            }
+        }
+    }
+    str::to_lower(host)
+}
+
+pure fn default_port(scheme: &str) -> Option<uint> {
+    match str::from_slice(scheme) {
+        ~"http" => Some(80),
+        ~"https" => Some(443),
+        ~"ftp" => Some(21),
+        _ => None
+    }
+}
+
 impl Url: FromStr {
     static pure fn from_str(s: &str) -> Option<Url> {
         match from_str(s) {
@@ -700,7 +954,15 @@ pub pure fn to_str(url: &Url) -> ~str {
     let authority = if url.host.is_empty() {
         ~""
     } else {
-        fmt!("//%s%s", user, url.host)
+        let port = match url.port {
+            Some(ref port) => fmt!(":%s", *port),
+            None => ~"",
+        };
+        if str::contains_char(url.host, ':') {
+            fmt!("//%s[%s]%s", user, url.host, port)
+        } else {
+            fmt!("//%s%s%s", user, url.host, port)
+        }
     };
 
     let query = if url.query.is_empty() {
@@ -1085,4 +1347,147 @@ mod tests {
         assert form.get_ref(&~"foo bar") == &~[~"abc", ~"12 = 34"];
         */
     }
+    #[test]
+    fn test_port_parse_and_format() {
+        let url = ~"http://rust-lang.org:8080";
+        let u = from_str(url).unwrap();
+        assert u.port == Some(~"8080");
+        assert u.to_str() == url;
+
+        let url = ~"http://user@rust-lang.org:8080/doc?s=v";
+        assert from_str(url).unwrap().to_str() == url;
+    }
+
+    #[test]
+    fn test_ipv6_literal() {
+        let (_, h, p, r) = get_authority("//[2001:db8::7]:8000/c").unwrap();
+        assert h == ~"2001:db8::7";
+        assert p == Some(~"8000");
+        assert r == ~"/c";
+
+        let (u, h, p, r) = get_authority("//us@[::1]").unwrap();
+        assert u == Some(UserInfo::new(~"us", None));
+        assert h == ~"::1";
+        assert p.is_none();
+        assert r == ~"";
+
+        let url = ~"ldap://[2001:db8::7]/c=GB";
+        assert from_str(url).unwrap().to_str() == url;
+
+        assert get_authority("//[::1").is_err();
+        assert get_authority("//[::g]").is_err();
+        assert get_authority("//[::1]x").is_err();
+        assert get_authority("//host[::1]").is_err();
+    }
+
+    #[test]
+    fn test_remove_dot_segments() {
+        assert remove_dot_segments("/a/b/c/./../../g") == ~"/a/g";
+        assert remove_dot_segments("mid/content=5/../6") == ~"mid/6";
+        assert remove_dot_segments("/../a") == ~"/a";
+        assert remove_dot_segments("/a/b/..") == ~"/a/";
+        assert remove_dot_segments("..") == ~"";
+        assert remove_dot_segments("") == ~"";
+    }
+
+    // Compares the parts of the URLs rather than their strings, which
+    // to_str writes in its own encoding.
+    fn check_join(base: &Url, reference: &str, expected: &str) {
+        let joined = base.join(reference).unwrap();
+        if joined != from_str(expected).unwrap() {
+            fail fmt!("joining '%s': expected '%s', got '%s'",
+                      reference, expected, joined.to_str());
+        }
+    }
+
+    #[test]
+    fn test_join_normal_examples() {
+        // RFC 3986 section 5.4.1
+        let base = from_str("http://a/b/c/d;p?q").unwrap();
+        check_join(&base, "g:h", "g:h");
+        check_join(&base, "g", "http://a/b/c/g");
+        check_join(&base, "./g", "http://a/b/c/g");
+        check_join(&base, "g/", "http://a/b/c/g/");
+        check_join(&base, "/g", "http://a/g");
+        check_join(&base, "//g", "http://g");
+        check_join(&base, "?y", "http://a/b/c/d;p?y");
+        check_join(&base, "g?y", "http://a/b/c/g?y");
+        check_join(&base, "#s", "http://a/b/c/d;p?q#s");
+        check_join(&base, "g#s", "http://a/b/c/g#s");
+        check_join(&base, "g?y#s", "http://a/b/c/g?y#s");
+        check_join(&base, ";x", "http://a/b/c/;x");
+        check_join(&base, "g;x", "http://a/b/c/g;x");
+        check_join(&base, "g;x?y#s", "http://a/b/c/g;x?y#s");
+        check_join(&base, "", "http://a/b/c/d;p?q");
+        check_join(&base, ".", "http://a/b/c/");
+        check_join(&base, "./", "http://a/b/c/");
+        check_join(&base, "..", "http://a/b/");
+        check_join(&base, "../", "http://a/b/");
+        check_join(&base, "../g", "http://a/b/g");
+        check_join(&base, "../..", "http://a/");
+        check_join(&base, "../../", "http://a/");
+        check_join(&base, "../../g", "http://a/g");
+    }
+
+    #[test]
+    fn test_join_abnormal_examples() {
+        // RFC 3986 section 5.4.2
+        let base = from_str("http://a/b/c/d;p?q").unwrap();
+        check_join(&base, "../../../g", "http://a/g");
+        check_join(&base, "../../../../g", "http://a/g");
+        check_join(&base, "/./g", "http://a/g");
+        check_join(&base, "/../g", "http://a/g");
+        check_join(&base, "g.", "http://a/b/c/g.");
+        check_join(&base, ".g", "http://a/b/c/.g");
+        check_join(&base, "g..", "http://a/b/c/g..");
+        check_join(&base, "..g", "http://a/b/c/..g");
+        check_join(&base, "./../g", "http://a/b/g");
+        check_join(&base, "./g/.", "http://a/b/c/g/");
+        check_join(&base, "g/./h", "http://a/b/c/g/h");
+        check_join(&base, "g/../h", "http://a/b/c/h");
+        check_join(&base, "g;x=1/./y", "http://a/b/c/g;x=1/y");
+        check_join(&base, "g;x=1/../y", "http://a/b/c/y");
+        check_join(&base, "g?y/./x", "http://a/b/c/g?y/./x");
+        check_join(&base, "g?y/../x", "http://a/b/c/g?y/../x");
+        check_join(&base, "g#s/./x", "http://a/b/c/g#s/./x");
+        check_join(&base, "g#s/../x", "http://a/b/c/g#s/../x");
+        check_join(&base, "http:g", "http:g");
+    }
+
+    #[test]
+    fn test_join_keeps_authority() {
+        let base = from_str("http://u:p@example.com:8080/a/b").unwrap();
+        check_join(&base, "../img/a.png",
+                   "http://u:p@example.com:8080/img/a.png");
+        check_join(&base, "//other.org/x", "http://other.org/x");
+        check_join(&base, "https://example.com", "https://example.com");
+
+        let base = from_str("http://example.com").unwrap();
+        check_join(&base, "a", "http://example.com/a");
+
+        assert base.join("//user:pass@rust-lang:something").is_err();
+    }
+
+    #[test]
+    fn test_normalize() {
+        let u = from_str("HTTP://www.Example.COM:80/a/./b/../c").unwrap();
+        assert u.normalize().to_str() == ~"http://www.example.com/a/c";
+
+        let u = from_str("https://example.com:8443").unwrap();
+        assert u.normalize().to_str() == ~"https://example.com:8443/";
+
+        let u = from_str("http://[2001:DB8:0:0::1]:80/").unwrap();
+        assert u.normalize().to_str() == ~"http://[2001:db8::1]/";
+    }
+
+    #[test]
+    fn test_equiv() {
+        let a = from_str("http://example.com/").unwrap();
+        let b = from_str("HTTP://EXAMPLE.com:80").unwrap();
+        let c = from_str("http://example.com:81/").unwrap();
+        assert a.equiv(&b);
+        assert b.equiv(&a);
+        assert !a.equiv(&c);
+        assert a != b;
+    }
 }
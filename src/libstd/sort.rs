@@ -911,6 +911,7 @@ mod test_tim_sort {
     use sort::tim_sort;
 
     use core::rand;
+    use core::rand::RngUtil;
     use core::vec;
 
     struct CVal {
@@ -1008,6 +1009,7 @@ mod big_tests {
     use sort::*;
 
     use core::rand;
+    use core::rand::RngUtil;
     use core::task;
     use core::uint;
     use core::vec;
@@ -0,0 +1,386 @@
+// Copyright 2013 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+ * Supervisors that restart failed tasks.
+ *
+ * A `Supervisor` owns a list of child specifications. When run, it starts
+ * a task for each child, watches for them to exit, and restarts them
+ * according to its `Strategy` and each child's `Restart` policy. If
+ * children have to be restarted too often, the supervisor stops them all
+ * and fails itself, so that a supervisor running as the child of another
+ * supervisor passes the problem up the tree.
+ *
+ * # Example
+ *
+ * ~~~
+ * let mut sup = Supervisor::new(OneForOne, 5, 10000);
+ * sup.add_child(ChildSpec::new(~"listener", Permanent, || {
+ *     let body: ~fn() = || listen();
+ *     move body
+ * }));
+ * sup.run();
+ * ~~~
+ */
+
+#[forbid(deprecated_mode)];
+
+use core::either::{Left, Right};
+use core::option;
+use core::pipes::{Chan, GenericChan, GenericPort, Port, Select2, SharedChan};
+use core::pipes;
+use core::prelude::*;
+use core::task::{Failure, Success, TaskResult};
+use core::task;
+use core::uint;
+use core::vec;
+use time;
+
+/// How a supervisor reacts to a child that has to be restarted
+#[deriving_eq]
+pub enum Strategy {
+    /// Restart just that child
+    OneForOne,
+    /**
+     * Stop all the other running children, then restart that child and
+     * every other child that was running and is not `Temporary`
+     */
+    OneForAll,
+}
+
+/// When a child is restarted
+#[deriving_eq]
+pub enum Restart {
+    /// Whenever it exits
+    Permanent,
+    /// Only when it fails
+    Transient,
+    /// Never
+    Temporary,
+}
+
+/// Describes a child of a supervisor
+pub struct ChildSpec {
+    name: ~str,
+    restart: Restart,
+    priv start: ~fn() -> ~fn(),
+}
+
+pub impl ChildSpec {
+    /**
+     * A child called `name` that is restarted according to `restart`.
+     * Each time the child is started, `start` is called in the supervisor
+     * task to produce the function the child task runs.
+     */
+    static fn new(name: ~str, restart: Restart,
+                  start: ~fn() -> ~fn()) -> ChildSpec {
+        ChildSpec { name: move name, restart: restart, start: move start }
+    }
+
+    /// Whether the child should be restarted after exiting with `result`
+    pure fn should_restart(&self, result: TaskResult) -> bool {
+        match (self.restart, result) {
+          (Permanent, _) | (Transient, Failure) => true,
+          (Transient, Success) | (Temporary, _) => false
+        }
+    }
+}
+
+/// A child exit, tagged with the child's index and the generation it was
+/// started in
+type Exit = (uint, uint, TaskResult);
+
+/// A child that is currently running
+struct Running {
+    generation: uint,
+    stop: Chan<()>,
+}
+
+pub struct Supervisor {
+    priv strategy: Strategy,
+    priv max_restarts: uint,
+    priv period_ms: uint,
+    priv children: ~[ChildSpec],
+}
+
+pub impl Supervisor {
+    /**
+     * A supervisor with no children yet that gives up if it has to make
+     * more than `max_restarts` restarts within `period_ms` milliseconds.
+     */
+    static fn new(strategy: Strategy, max_restarts: uint,
+                  period_ms: uint) -> Supervisor {
+        Supervisor {
+            strategy: strategy,
+            max_restarts: max_restarts,
+            period_ms: period_ms,
+            children: ~[]
+        }
+    }
+
+    /// Add a child, which is started when the supervisor is run.
+    fn add_child(&mut self, spec: ChildSpec) {
+        self.children.push(move spec);
+    }
+
+    /**
+     * Start every child and supervise them until none is left running.
+     * This blocks the calling task, so a supervisor usually gets a task
+     * of its own, which may in turn be the child of another supervisor.
+     *
+     * Children are stopped by failing the task that watches them, so a
+     * child only notices that it has been stopped when it next yields.
+     *
+     * # Failure
+     *
+     * Fails, after stopping every child, if the restart limit is exceeded.
+     */
+    fn run(&self) {
+        let (exits, exit_chan) = pipes::stream();
+        let exit_chan = pipes::SharedChan(move exit_chan);
+        let mut next_generation = 0u;
+        let mut restarts: ~[u64] = ~[];
+
+        let mut running = do vec::from_fn(self.children.len()) |i| {
+            next_generation += 1u;
+            Some(self.start_child(i, next_generation, &exit_chan))
+        };
+
+        while running.any(|c| c.is_some()) {
+            let (index, generation, result) = exits.recv();
+            match running[index] {
+              Some(ref child) if child.generation == generation => (),
+              // A child we have already stopped.
+              _ => loop
+            }
+            running[index] = None;
+
+            let spec = &self.children[index];
+            debug!("supervised task %s exited with %?", spec.name, result);
+            if !spec.should_restart(result) { loop; }
+
+            let now = time::precise_time_ns();
+            let period = self.period_ms as u64 * 1000000u64;
+            restarts = restarts.filtered(|t| now - *t < period);
+            restarts.push(now);
+            if restarts.len() > self.max_restarts {
+                for uint::range(0u, running.len()) |i| {
+                    stop_child(&mut running, i);
+                }
+                fail fmt!("supervisor giving up: %s restarted too often",
+                          spec.name);
+            }
+
+            let mut to_start = ~[index];
+            if self.strategy == OneForAll {
+                for uint::range(0u, running.len()) |i| {
+                    if stop_child(&mut running, i) &&
+                       self.children[i].restart != Temporary {
+                        to_start.push(i);
+                    }
+                }
+            }
+            for to_start.each |i| {
+                next_generation += 1u;
+                running[*i] = Some(self.start_child(*i, next_generation,
+                                                    &exit_chan));
+            }
+        }
+    }
+
+    /**
+     * Start child `index` in a task of its own, watched by a keeper task
+     * that reports its exit on `exits`. Both are spawned supervised, so
+     * that they are killed if the supervisor fails and the child is
+     * killed if its keeper is stopped.
+     */
+    priv fn start_child(&self, index: uint, generation: uint,
+                        exits: &SharedChan<Exit>) -> Running {
+        let body = (self.children[index].start)();
+        let exits = exits.clone();
+        let (stop_port, stop_chan) = pipes::stream();
+        do task::task().supervised().spawn
+            |move body, move exits, move stop_port| {
+            let mut result = None;
+            do task::task().supervised().future_result(|+r| {
+                result = Some(move r);
+            }).spawn(move body);
+            let ports = (option::unwrap(move result), move stop_port);
+            match ports.select() {
+              Left(result) => exits.send((index, generation, result)),
+              Right(()) => fail ~"stopped by supervisor"
+            }
+        }
+        Running { generation: generation, stop: move stop_chan }
+    }
+}
+
+/// Stop child `i` if it is running, returning whether it was.
+fn stop_child(running: &mut ~[Option<Running>], i: uint) -> bool {
+    let mut child = None;
+    child <-> running[i];
+    match move child {
+      Some(move child) => {
+        child.stop.send(());
+        true
+      }
+      None => false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::prelude::*;
+
+    use cell::Cell;
+    use supervisor::*;
+
+    use core::option;
+    use core::pipes::{GenericChan, GenericPort, Port, SharedChan};
+    use core::pipes;
+    use core::task;
+
+    type Log = SharedChan<(~str, uint)>;
+
+    /**
+     * A child that reports each attempt to `log`, numbered from 1, and
+     * then hands the attempt number to `body`.
+     */
+    fn child(name: ~str, restart: Restart, log: &Log,
+             body: extern fn(uint)) -> ChildSpec {
+        let attempts = Cell(0u);
+        let log = log.clone();
+        let tag = copy name;
+        ChildSpec::new(move name, restart, |move attempts, move log,
+                                             move tag| {
+            let n = attempts.take() + 1u;
+            attempts.put_back(n);
+            let log = log.clone();
+            let tag = copy tag;
+            let f: ~fn() = |move log, move tag| {
+                log.send((copy tag, n));
+                body(n);
+            };
+            move f
+        })
+    }
+
+    fn log() -> (Port<(~str, uint)>, Log) {
+        let (port, chan) = pipes::stream();
+        (move port, pipes::SharedChan(move chan))
+    }
+
+    fn drain(port: &Port<(~str, uint)>) -> ~[(~str, uint)] {
+        let mut entries = ~[];
+        while port.peek() {
+            entries.push(port.recv());
+        }
+        move entries
+    }
+
+    fn fail_twice(n: uint) {
+        if n <= 2u { fail ~"deliberate failure"; }
+    }
+
+    fn always_fail(_n: uint) { fail ~"deliberate failure"; }
+
+    fn succeed(_n: uint) { }
+
+    fn spin_until_third(n: uint) {
+        if n < 3u {
+            loop { task::yield(); }
+        }
+    }
+
+    #[test]
+    #[ignore(cfg(windows))]
+    fn test_one_for_one() {
+        let (port, chan) = log();
+        let mut sup = Supervisor::new(OneForOne, 5u, 60000u);
+        sup.add_child(child(~"flaky", Transient, &chan, fail_twice));
+        sup.add_child(child(~"once", Temporary, &chan, always_fail));
+        sup.add_child(child(~"done", Transient, &chan, succeed));
+        sup.run();
+
+        let entries = drain(&port);
+        assert entries.len() == 5u;
+        assert entries.contains(&(~"flaky", 3u));
+        assert !entries.contains(&(~"flaky", 4u));
+        assert entries.contains(&(~"once", 1u));
+        assert !entries.contains(&(~"once", 2u));
+        assert !entries.contains(&(~"done", 2u));
+    }
+
+    #[test]
+    #[ignore(cfg(windows))]
+    fn test_one_for_all() {
+        let (port, chan) = log();
+        let mut sup = Supervisor::new(OneForAll, 5u, 60000u);
+        sup.add_child(child(~"spinner", Transient, &chan, spin_until_third));
+        sup.add_child(child(~"flaky", Transient, &chan, fail_twice));
+        sup.run();
+
+        // Each failure of the flaky child stops and restarts the spinner,
+        // which only exits by itself the third time it is started.
+        let entries = drain(&port);
+        assert entries.contains(&(~"flaky", 3u));
+        assert entries.contains(&(~"spinner", 3u));
+        assert !entries.contains(&(~"spinner", 4u));
+    }
+
+    #[test]
+    #[ignore(cfg(windows))]
+    fn test_restart_limit() {
+        let (port, chan) = log();
+        let mut sup = Supervisor::new(OneForOne, 3u, 60000u);
+        sup.add_child(child(~"doomed", Permanent, &chan, always_fail));
+
+        let mut result = None;
+        do task::task().future_result(|+r| {
+            result = Some(move r);
+        }).spawn_with(move sup) |sup| {
+            sup.run();
+        }
+        assert option::unwrap(move result).recv() == task::Failure;
+
+        // The first start and three restarts.
+        let entries = drain(&port);
+        assert entries.len() == 4u;
+        assert entries.contains(&(~"doomed", 4u));
+    }
+
+    #[test]
+    #[ignore(cfg(windows))]
+    fn test_nested() {
+        let (port, chan) = log();
+        // The inner supervisor gives up the first time it is run, and its
+        // worker succeeds once the outer supervisor has restarted it.
+        let runs = Cell(0u);
+        let inner_log = chan.clone();
+        let mut sup = Supervisor::new(OneForOne, 1u, 60000u);
+        sup.add_child(ChildSpec::new(~"inner", Transient,
+                                     |move runs, move inner_log| {
+            let n = runs.take() + 1u;
+            runs.put_back(n);
+            let log = inner_log.clone();
+            let f: ~fn() = |move log| {
+                let body = if n == 1u { always_fail } else { succeed };
+                let mut inner = Supervisor::new(OneForOne, 1u, 60000u);
+                inner.add_child(child(~"worker", Transient, &log, body));
+                inner.run();
+            };
+            move f
+        }));
+        sup.run();
+
+        assert drain(&port) == ~[(~"worker", 1u), (~"worker", 2u),
+                                 (~"worker", 1u)];
+    }
+}
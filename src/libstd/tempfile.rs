@@ -15,6 +15,7 @@
 use core::os;
 use core::prelude::*;
 use core::rand;
+use core::rand::RngUtil;
 use core::str;
 
 pub fn mkdtemp(tmpdir: &Path, suffix: &str) -> Option<Path> {
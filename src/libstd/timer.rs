@@ -19,6 +19,8 @@ use uv::iotask::IoTask;
 use core::either;
 use core::libc;
 use core::oldcomm;
+use core::pipes::{GenericPort, GenericSmartChan, Selectable};
+use core::pipes;
 use core::prelude::*;
 use core::ptr;
 use core;
@@ -138,6 +140,70 @@ pub fn recv_timeout<T: Copy Owned>(iotask: IoTask,
     )
 }
 
+/**
+ * Receive from whichever of `ports` is ready first, waiting for up to
+ * `msecs` milliseconds
+ *
+ * The timeout runs on a libuv timer which is stopped and closed before this
+ * call returns, so nothing is left waiting on `iotask` afterwards.
+ *
+ * # Returns
+ *
+ * `None` if no port was ready in time. Otherwise the index of the port that
+ * was ready and what it received, which is `None` if that port has closed.
+ * A closed port cannot be selected on again, so the caller must remove it
+ * from `ports` before the next call.
+ */
+pub fn select_recv_timeout<T: Owned, P: Selectable GenericPort<T>>(
+    iotask: IoTask, msecs: uint, ports: &[P]) -> Option<(uint, Option<T>)> {
+    unsafe {
+        let (timeout_po, timeout_ch) = pipes::stream::<()>();
+        let closed_po = oldcomm::Port::<()>();
+        let timer = SelectTimer {
+            handle: uv::ll::timer_t(),
+            mut closing: false,
+            timeout_ch: move timeout_ch,
+            closed_ch: oldcomm::Chan(&closed_po)
+        };
+        let timer_ptr = ptr::addr_of(&timer);
+        let handle_ptr = ptr::addr_of(&timer.handle);
+        do iotask::interact(iotask) |loop_ptr| {
+            unsafe {
+                let init_result = uv::ll::timer_init(loop_ptr, handle_ptr);
+                if (init_result == 0i32) {
+                    let start_result = uv::ll::timer_start(
+                        handle_ptr, select_timer_cb, msecs, 0u);
+                    if (start_result == 0i32) {
+                        uv::ll::set_data_for_uv_handle(
+                            handle_ptr,
+                            timer_ptr as *libc::c_void);
+                    } else {
+                        let error_msg = uv::ll::get_last_err_info(loop_ptr);
+                        fail ~"timer::select_recv_timeout() start failed: " +
+                            error_msg;
+                    }
+                } else {
+                    let error_msg = uv::ll::get_last_err_info(loop_ptr);
+                    fail ~"timer::select_recv_timeout() init failed: " +
+                        error_msg;
+                }
+            }
+        };
+        let result = pipes::select_recv_until(ports, &timeout_po);
+        // cancel the timer if it hasn't fired yet
+        do iotask::interact(iotask) |_loop_ptr| {
+            unsafe {
+                if !(*timer_ptr).closing {
+                    close_select_timer(handle_ptr);
+                }
+            }
+        };
+        // uv_close for this timer has been processed, `timer` may go away
+        oldcomm::recv(closed_po);
+        move result
+    }
+}
+
 // INTERNAL API
 extern fn delayed_send_cb(handle: *uv::ll::uv_timer_t,
                                 status: libc::c_int) {
@@ -167,6 +233,50 @@ extern fn delayed_send_close_cb(handle: *uv::ll::uv_timer_t) {
     }
 }
 
+struct SelectTimer {
+    handle: uv::ll::uv_timer_t,
+    // set once uv_close has been requested, which must happen exactly once
+    mut closing: bool,
+    timeout_ch: pipes::Chan<()>,
+    closed_ch: oldcomm::Chan<()>
+}
+
+extern fn select_timer_cb(handle: *uv::ll::uv_timer_t,
+                          status: libc::c_int) {
+    unsafe {
+        log(debug,
+            fmt!("select_timer_cb handle %? status %?", handle, status));
+        let timer =
+            uv::ll::get_data_for_uv_handle(handle) as *SelectTimer;
+        // The select may already have finished and dropped the port.
+        (*timer).timeout_ch.try_send(());
+        close_select_timer(handle);
+    }
+}
+
+// Must be called on the iotask, like any other libuv call
+unsafe fn close_select_timer(handle: *uv::ll::uv_timer_t) {
+    let timer = uv::ll::get_data_for_uv_handle(handle) as *SelectTimer;
+    (*timer).closing = true;
+    let stop_result = uv::ll::timer_stop(handle);
+    if (stop_result == 0i32) {
+        uv::ll::close(handle, select_timer_close_cb);
+    } else {
+        let loop_ptr = uv::ll::get_loop_for_uv_handle(handle);
+        let error_msg = uv::ll::get_last_err_info(loop_ptr);
+        fail ~"timer::select_recv_timeout() stop failed: " + error_msg;
+    }
+}
+
+extern fn select_timer_close_cb(handle: *uv::ll::uv_timer_t) {
+    unsafe {
+        log(debug, fmt!("select_timer_close_cb handle %?", handle));
+        let timer =
+            uv::ll::get_data_for_uv_handle(handle) as *SelectTimer;
+        oldcomm::send((*timer).closed_ch, ());
+    }
+}
+
 #[cfg(test)]
 mod test {
     #[legacy_exports];
@@ -178,7 +288,9 @@ mod test {
 
     use core::iter;
     use core::oldcomm;
+    use core::pipes::{GenericChan, stream};
     use core::rand;
+    use core::rand::RngUtil;
     use core::task;
 
     #[test]
@@ -290,4 +402,32 @@ mod test {
 
         assert successes > times / 2;
     }
+
+    #[test]
+    fn test_gl_timer_select_recv_timeout() {
+        let hl_loop = uv::global_loop::get();
+        let (p1, _c1) = stream::<int>();
+        let (p2, c2) = stream::<int>();
+        let ports = [move p1, move p2];
+
+        assert select_recv_timeout(hl_loop, 1u, ports).is_none();
+
+        // the timer is cancelled on return, so this doesn't leave a
+        // 10 second sleep behind on the global loop
+        c2.send(3);
+        assert select_recv_timeout(hl_loop, 10000u, ports) ==
+            Some((1u, Some(3)));
+    }
+
+    #[test]
+    fn test_gl_timer_select_recv_timeout_closed() {
+        let hl_loop = uv::global_loop::get();
+        let (p1, c1) = stream::<int>();
+        let (p2, _c2) = stream::<int>();
+        { let _c1 = move c1; }
+        let ports = [move p1, move p2];
+
+        assert select_recv_timeout(hl_loop, 10000u, ports) ==
+            Some((0u, None));
+    }
 }
@@ -296,6 +296,9 @@ struct crate_ {
     module: _mod,
     attrs: ~[attribute],
     config: crate_cfg,
+    // The `macro_rules!` definitions marked #[macro_export], which other
+    // crates can invoke; filled in by expansion
+    exported_macros: ~[@item],
 }
 
 type meta_item = spanned<meta_item_>;
@@ -14,6 +14,7 @@ use codemap::span;
 use codemap;
 
 use core::cmp;
+use core::hashmap::linear::LinearMap;
 use core::io::WriterUtil;
 use core::io;
 use core::option;
@@ -21,9 +22,10 @@ use core::str;
 use core::vec;
 use core::dvec::DVec;
 
+use std::json;
 use std::term;
 
-export emitter, collect, emit;
+export emitter, collect, emit, json_emitter;
 export level, fatal, error, warning, note;
 export span_handler, handler, mk_span_handler, mk_handler;
 export codemap_span_handler, codemap_handler;
@@ -229,6 +231,121 @@ fn emit(cmsp: Option<(@codemap::CodeMap, span)>, msg: &str, lvl: level) {
     }
 }
 
+/**
+ * An emitter that writes each diagnostic to `out` as a JSON object on a
+ * line of its own, for editors and other tools. An object has the form
+ *
+ *     {"level": "error", "message": "...", "spans": [...],
+ *      "children": [...]}
+ *
+ * Each span gives the file name, the byte range within that file, the
+ * 1-based line and column range, whether it is the diagnostic's primary
+ * span and an optional label. Secondary spans trace the macro expansions
+ * the primary span came from. Notes that directly follow an error or
+ * warning are attached to it as `children`, so a diagnostic is only
+ * written when the next one arrives or the emitter is dropped.
+ */
+fn json_emitter(out: io::Writer) -> emitter {
+    let pending = @json_pending { out: out, mut diag: None,
+                                  mut children: ~[] };
+    let f = fn@(cmsp: Option<(@codemap::CodeMap, span)>,
+                msg: &str, lvl: level) {
+        pending.push(json_diagnostic(cmsp, msg, lvl), lvl);
+    };
+    f
+}
+
+struct json_pending {
+    out: io::Writer,
+    mut diag: Option<~json::Object>,
+    mut children: ~[json::Json],
+    drop { self.flush(); }
+}
+
+impl json_pending {
+    fn push(diag: ~json::Object, lvl: level) {
+        if lvl == note && self.diag.is_some() {
+            let mut diag = move diag;
+            diag.insert(~"children", json::List(~[]));
+            self.children.push(json::Object(move diag));
+            return;
+        }
+        self.flush();
+        self.diag = Some(move diag);
+        // Nothing can follow a fatal error.
+        if lvl == fatal { self.flush(); }
+    }
+
+    fn flush() {
+        let mut diag = None;
+        diag <-> self.diag;
+        let mut children = ~[];
+        children <-> self.children;
+        match move diag {
+          Some(move diag) => {
+            let mut diag = move diag;
+            diag.insert(~"children", json::List(move children));
+            json::to_writer(self.out, &json::Object(move diag));
+            self.out.write_str("\n");
+          }
+          None => ()
+        }
+    }
+}
+
+fn json_diagnostic(cmsp: Option<(@codemap::CodeMap, span)>, msg: &str,
+                   lvl: level) -> ~json::Object {
+    let mut spans = ~[];
+    match cmsp {
+      Some((cm, sp)) if cm.files.len() > 0u => {
+        let sp = cm.adjust_span(sp);
+        spans.push(json_span(cm, sp, true, None));
+        json_macro_backtrace(cm, sp, &mut spans);
+      }
+      _ => ()
+    }
+    let mut diag = ~LinearMap();
+    diag.insert(~"level", json::String(diagnosticstr(lvl)));
+    diag.insert(~"message", json::String(msg.to_str()));
+    diag.insert(~"spans", json::List(move spans));
+    move diag
+}
+
+fn json_span(cm: @codemap::CodeMap, sp: span, primary: bool,
+             label: Option<~str>) -> json::Json {
+    let lo = cm.lookup_char_pos(sp.lo);
+    let hi = cm.lookup_char_pos(sp.hi);
+    let start = lo.file.start_pos;
+    let num = |n: uint| json::Number(n as float);
+    let mut obj = ~LinearMap();
+    obj.insert(~"file_name", json::String(copy lo.file.name));
+    obj.insert(~"byte_start", num((sp.lo - start).to_uint()));
+    obj.insert(~"byte_end", num((sp.hi - start).to_uint()));
+    obj.insert(~"line_start", num(lo.line));
+    obj.insert(~"line_end", num(hi.line));
+    obj.insert(~"column_start", num(lo.col.to_uint() + 1u));
+    obj.insert(~"column_end", num(hi.col.to_uint() + 1u));
+    obj.insert(~"is_primary", json::Boolean(primary));
+    obj.insert(~"label", match move label {
+      Some(move l) => json::String(move l),
+      None => json::Null
+    });
+    json::Object(move obj)
+}
+
+fn json_macro_backtrace(cm: @codemap::CodeMap, sp: span,
+                        spans: &mut ~[json::Json]) {
+    do option::iter(&sp.expn_info) |ei| {
+        do option::iter(&ei.callie.span) |span| {
+            let label = fmt!("in expansion of %s!", ei.callie.name);
+            spans.push(json_span(cm, *span, false, Some(move label)));
+        }
+        spans.push(json_span(cm, ei.call_site, false,
+                             Some(~"expansion site")));
+        json_macro_backtrace(cm, ei.call_site, spans);
+    }
+}
+
 fn highlight_lines(cm: @codemap::CodeMap, sp: span,
                    lines: @codemap::FileLines) {
 
@@ -303,3 +420,50 @@ fn expect<T: Copy>(diag: span_handler,
        None => diag.handler().bug(msg())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use core::io;
+    use core::str;
+    use std::json;
+
+    fn field(obj: &json::Json, name: &str) -> json::Json {
+        match *obj {
+          json::Object(ref o) => copy *o.get(&name.to_str()),
+          _ => fail ~"not an object"
+        }
+    }
+
+    #[test]
+    fn test_json_emitter() {
+        let out = do io::with_str_writer |wr| {
+            let emit = json_emitter(wr);
+            emit(None, "first", error);
+            emit(None, "attached", note);
+            emit(None, "also attached", note);
+            emit(None, "second", warning);
+        };
+        let lines = str::lines(out);
+        assert lines.len() == 2u;
+
+        let first = json::from_str(lines[0]).get();
+        assert field(&first, "level") == json::String(~"error");
+        assert field(&first, "message") == json::String(~"first");
+        assert field(&first, "spans") == json::List(~[]);
+        match field(&first, "children") {
+          json::List(ref notes) => {
+            assert notes.len() == 2u;
+            assert field(&notes[1], "level") == json::String(~"note");
+            assert field(&notes[1], "message") ==
+                json::String(~"also attached");
+          }
+          _ => fail
+        }
+
+        let second = json::from_str(lines[1]).get();
+        assert field(&second, "level") == json::String(~"warning");
+        assert field(&second, "children") == json::List(~[]);
+    }
+}
@@ -108,9 +108,24 @@ fn syntax_expander_table() -> HashMap<~str, SyntaxExtension> {
     syntax_expanders.insert(~"deriving_eq",
                             ItemDecorator(
                                 ext::deriving::expand_deriving_eq));
+    syntax_expanders.insert(~"deriving_ord",
+                            ItemDecorator(
+                                ext::deriving::expand_deriving_ord));
+    syntax_expanders.insert(~"deriving_clone",
+                            ItemDecorator(
+                                ext::deriving::expand_deriving_clone));
+    syntax_expanders.insert(~"deriving_to_str",
+                            ItemDecorator(
+                                ext::deriving::expand_deriving_to_str));
     syntax_expanders.insert(~"deriving_iter_bytes",
                             ItemDecorator(
                                 ext::deriving::expand_deriving_iter_bytes));
+    syntax_expanders.insert(~"deriving_encodable",
+                            ItemDecorator(
+                                ext::deriving::expand_deriving_encodable));
+    syntax_expanders.insert(~"deriving_decodable",
+                            ItemDecorator(
+                                ext::deriving::expand_deriving_decodable));
 
     // Quasi-quoting expanders
     syntax_expanders.insert(
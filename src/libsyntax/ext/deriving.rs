@@ -8,19 +8,28 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-/// The compiler code necessary to implement the #[deriving_eq] and
-/// #[deriving_iter_bytes] extensions.
+/// The compiler code necessary to implement the #[deriving_*] extensions,
+/// which implement a trait for a struct or enum: #[deriving_eq],
+/// #[deriving_ord], #[deriving_clone], #[deriving_to_str],
+/// #[deriving_iter_bytes], #[deriving_encodable] and #[deriving_decodable].
+///
+/// Each of them only builds its method bodies out of the fields of the
+/// struct or of one variant of the enum. Getting at those fields, by
+/// matching on `self` and on the other value of a comparison, building a
+/// value out of new fields and creating the impl are shared.
 
 use core::prelude::*;
 
 use ast;
-use ast::{TraitTyParamBound, Ty, and, bind_by_ref, binop, deref, enum_def};
-use ast::{enum_variant_kind, expr, expr_match, ident, item, item_};
-use ast::{item_enum, item_impl, item_struct, m_imm, meta_item, method};
-use ast::{named_field, or, pat, pat_ident, pat_wild, public, pure_fn};
-use ast::{re_anon, spanned, stmt, struct_def, struct_variant_kind};
-use ast::{sty_by_ref, sty_region, tuple_variant_kind, ty_nil, ty_param};
-use ast::{ty_param_bound, ty_path, ty_rptr, unnamed_field, variant};
+use ast::{TraitTyParamBound, Ty, add, and, bind_by_ref, binop, deref};
+use ast::{enum_variant_kind, expr, expr_fail, expr_match, ident, impure_fn};
+use ast::{item, item_, item_enum, item_impl, item_struct, lt, gt, m_imm};
+use ast::{meta_item, method, named_field, not, or, pat, pat_enum};
+use ast::{pat_ident, pat_lit, pat_struct, pat_wild, public, pure_fn};
+use ast::{purity, re_anon, self_ty_, spanned, struct_variant_kind};
+use ast::{sty_by_ref, sty_region, sty_static, tuple_variant_kind, ty_infer};
+use ast::{ty_nil, ty_param, ty_path, ty_rptr, ty_uniq, unnamed_field};
+use ast::{stmt, variant};
 use ext::base::ext_ctxt;
 use ext::build;
 use codemap::span;
@@ -28,6 +37,7 @@ use parse::token::special_idents::clownshoes_extensions;
 
 use core::dvec;
 use core::uint;
+use core::vec;
 
 enum Junction {
     Conjunction,
@@ -43,29 +53,86 @@ impl Junction {
     }
 }
 
-type ExpandDerivingStructDefFn = &fn(ext_ctxt,
-                                     span,
-                                     x: &struct_def,
-                                     ident,
-                                     +y: ~[ty_param])
-                                  -> @item;
-type ExpandDerivingEnumDefFn = &fn(ext_ctxt,
-                                   span,
-                                   x: &enum_def,
-                                   ident,
-                                   +y: ~[ty_param])
-                                -> @item;
+/// The struct or enum a trait is derived for
+struct TypeDef {
+    ident: ident,
+    ty_params: ~[ty_param],
+    is_enum: bool,
+    /// The struct, or each variant of the enum
+    shapes: ~[Shape],
+}
+
+/// The fields of a struct or of one enum variant
+struct Shape {
+    /// The name of the struct or variant, which constructs it
+    name: ident,
+    /// The position of the variant in the enum, or 0 for a struct
+    index: uint,
+    /// The explicit discriminant of the variant, if any
+    disr_expr: Option<@expr>,
+    /// Whether the fields are named or, as in a tuple-like variant,
+    /// positional
+    named: bool,
+    fields: ~[Option<ident>],
+}
+
+/// Where a method body finds a field
+enum FieldRef {
+    /// The named field of a struct, through the given variable
+    FieldOf(ident, ident),
+    /// The variable a field of an enum variant was bound to by reference
+    Binding(ident),
+}
+
+/// A field of the value, or values, a method body is built for
+struct Field {
+    name: Option<ident>,
+    self_ref: FieldRef,
+    /// The same field of `__other`, for methods comparing two values
+    other_ref: Option<FieldRef>,
+}
+
+/// A type parameter of a derived trait, like the `__S` of `Encodable<__S>`,
+/// which the impl takes as well, bounded by another trait
+struct TraitParam {
+    ident: ident,
+    bound: ~[ident],
+}
+
+type ExpandDerivingFn = &fn(ext_ctxt, span, &TypeDef) -> @item;
+type SubstructureFn = &fn(&Shape, &[Field]) -> @expr;
+type MismatchFn = &fn(uint, @expr) -> @expr;
 
 pub fn expand_deriving_eq(cx: ext_ctxt,
                           span: span,
                           _mitem: meta_item,
                           in_items: ~[@item])
                        -> ~[@item] {
-    expand_deriving(cx,
-                    span,
-                    in_items,
-                    expand_deriving_eq_struct_def,
-                    expand_deriving_eq_enum_def)
+    expand_deriving(cx, span, in_items, expand_deriving_eq_def)
+}
+
+pub fn expand_deriving_ord(cx: ext_ctxt,
+                           span: span,
+                           _mitem: meta_item,
+                           in_items: ~[@item])
+                        -> ~[@item] {
+    expand_deriving(cx, span, in_items, expand_deriving_ord_def)
+}
+
+pub fn expand_deriving_clone(cx: ext_ctxt,
+                             span: span,
+                             _mitem: meta_item,
+                             in_items: ~[@item])
+                          -> ~[@item] {
+    expand_deriving(cx, span, in_items, expand_deriving_clone_def)
+}
+
+pub fn expand_deriving_to_str(cx: ext_ctxt,
+                              span: span,
+                              _mitem: meta_item,
+                              in_items: ~[@item])
+                           -> ~[@item] {
+    expand_deriving(cx, span, in_items, expand_deriving_to_str_def)
 }
 
 pub fn expand_deriving_iter_bytes(cx: ext_ctxt,
@@ -73,43 +140,117 @@ pub fn expand_deriving_iter_bytes(cx: ext_ctxt,
                                   _mitem: meta_item,
                                   in_items: ~[@item])
                                -> ~[@item] {
-    expand_deriving(cx,
-                    span,
-                    in_items,
-                    expand_deriving_iter_bytes_struct_def,
-                    expand_deriving_iter_bytes_enum_def)
+    expand_deriving(cx, span, in_items, expand_deriving_iter_bytes_def)
+}
+
+pub fn expand_deriving_encodable(cx: ext_ctxt,
+                                 span: span,
+                                 _mitem: meta_item,
+                                 in_items: ~[@item])
+                              -> ~[@item] {
+    expand_deriving(cx, span, in_items, expand_deriving_encodable_def)
+}
+
+pub fn expand_deriving_decodable(cx: ext_ctxt,
+                                 span: span,
+                                 _mitem: meta_item,
+                                 in_items: ~[@item])
+                              -> ~[@item] {
+    expand_deriving(cx, span, in_items, expand_deriving_decodable_def)
 }
 
 fn expand_deriving(cx: ext_ctxt,
                    span: span,
                    in_items: ~[@item],
-                   expand_deriving_struct_def: ExpandDerivingStructDefFn,
-                   expand_deriving_enum_def: ExpandDerivingEnumDefFn)
+                   expand_deriving_def: ExpandDerivingFn)
                 -> ~[@item] {
     let result = dvec::DVec();
     for in_items.each |item| {
         result.push(copy *item);
-        match item.node {
-            item_struct(struct_def, copy ty_params) => {
-                result.push(expand_deriving_struct_def(cx,
-                                                       span,
-                                                       struct_def,
-                                                       item.ident,
-                                                       move ty_params));
-            }
-            item_enum(ref enum_definition, copy ty_params) => {
-                result.push(expand_deriving_enum_def(cx,
-                                                     span,
-                                                     enum_definition,
-                                                     item.ident,
-                                                     move ty_params));
+        match create_type_def(cx, span, *item) {
+            Some(ref type_def) => {
+                result.push(expand_deriving_def(cx, span, type_def));
             }
-            _ => ()
+            None => ()
         }
     }
     dvec::unwrap(move result)
 }
 
+fn create_type_def(cx: ext_ctxt, span: span, item: @item) -> Option<TypeDef> {
+    match item.node {
+        item_struct(struct_def, copy ty_params) => {
+            let fields = do struct_def.fields.map |struct_field| {
+                match struct_field.node.kind {
+                    named_field(ident, _, _) => Some(ident),
+                    unnamed_field => {
+                        cx.span_unimpl(span,
+                                       ~"unnamed fields with `deriving`");
+                    }
+                }
+            };
+            let shape = Shape {
+                name: item.ident,
+                index: 0,
+                disr_expr: None,
+                named: true,
+                fields: move fields
+            };
+            Some(TypeDef {
+                ident: item.ident,
+                ty_params: move ty_params,
+                is_enum: false,
+                shapes: ~[ move shape ]
+            })
+        }
+        item_enum(ref enum_definition, copy ty_params) => {
+            let shapes = do enum_definition.variants.mapi |i, variant| {
+                create_variant_shape(cx, span, i, variant)
+            };
+            Some(TypeDef {
+                ident: item.ident,
+                ty_params: move ty_params,
+                is_enum: true,
+                shapes: move shapes
+            })
+        }
+        _ => None
+    }
+}
+
+fn create_variant_shape(cx: ext_ctxt,
+                        span: span,
+                        index: uint,
+                        variant: &variant)
+                     -> Shape {
+    let (named, fields) = match variant.node.kind {
+        tuple_variant_kind(ref variant_args) => {
+            (false, variant_args.map(|_arg| None))
+        }
+        struct_variant_kind(struct_def) => {
+            let fields = do struct_def.fields.map |struct_field| {
+                match struct_field.node.kind {
+                    named_field(ident, _, _) => Some(ident),
+                    unnamed_field => {
+                        cx.span_bug(span, ~"unexpected unnamed field");
+                    }
+                }
+            };
+            (true, move fields)
+        }
+        enum_variant_kind(*) => {
+            cx.span_unimpl(span, ~"enum variants for `deriving`");
+        }
+    };
+    Shape {
+        name: variant.node.name,
+        index: index,
+        disr_expr: variant.node.disr_expr,
+        named: named,
+        fields: move fields
+    }
+}
+
 fn create_impl_item(cx: ext_ctxt, span: span, +item: item_) -> @item {
     @ast::item {
         ident: clownshoes_extensions,
@@ -121,59 +262,30 @@ fn create_impl_item(cx: ext_ctxt, span: span, +item: item_) -> @item {
     }
 }
 
-/// Creates a method from the given expression, the signature of which
-/// conforms to the `eq` or `ne` method.
-fn create_eq_method(cx: ext_ctxt,
-                    span: span,
-                    method_ident: ident,
-                    type_ident: ident,
-                    ty_params: &[ty_param],
-                    body: @expr)
-                 -> @method {
-    // Create the type of the `other` parameter.
-    let arg_path_type = create_self_type_with_params(cx,
-                                                     span,
-                                                     type_ident,
-                                                     ty_params);
-    let arg_region = @ast::region { id: cx.next_id(), node: re_anon };
-    let arg_type = ty_rptr(
-        arg_region,
-        ast::mt { ty: arg_path_type, mutbl: m_imm }
-    );
-    let arg_type = @ast::Ty {
-        id: cx.next_id(),
-        node: arg_type,
-        span: span,
-    };
-
-    // Create the `other` parameter.
-    let other_ident = cx.ident_of(~"__other");
-    let arg = build::mk_arg(cx, span, other_ident, arg_type);
-
-    // Create the type of the return value.
-    let bool_ident = cx.ident_of(~"bool");
-    let output_type = build::mk_raw_path(span, ~[ bool_ident ]);
-    let output_type = ty_path(output_type, cx.next_id());
-    let output_type = @ast::Ty {
-        id: cx.next_id(),
-        node: output_type,
-        span: span,
-    };
-
+/// Creates a method of a derived impl, with `body` as its body.
+fn create_method(cx: ext_ctxt,
+                 span: span,
+                 method_ident: ident,
+                 self_ty: self_ty_,
+                 purity: purity,
+                 +inputs: ~[ast::arg],
+                 output_type: @Ty,
+                 body: @expr)
+              -> @method {
     // Create the function declaration.
-    let fn_decl = build::mk_fn_decl(~[ move arg ], output_type);
+    let fn_decl = build::mk_fn_decl(move inputs, output_type);
 
     // Create the body block.
     let body_block = build::mk_simple_block(cx, span, body);
 
     // Create the method.
-    let self_ty = spanned { node: sty_region(m_imm), span: span };
+    let self_ty = spanned { node: self_ty, span: span };
     @ast::method {
         ident: method_ident,
         attrs: ~[],
         tps: ~[],
         self_ty: self_ty,
-        purity: pure_fn,
+        purity: purity,
         decl: move fn_decl,
         body: move body_block,
         id: cx.next_id(),
@@ -183,6 +295,37 @@ fn create_eq_method(cx: ext_ctxt,
     }
 }
 
+/// Creates a method, like `eq` or `lt`, that compares `self` with an
+/// `__other` of the same type.
+fn create_comparison_method(cx: ext_ctxt,
+                            span: span,
+                            type_def: &TypeDef,
+                            method_ident: ident,
+                            body: @expr)
+                         -> @method {
+    // Create the `other` parameter.
+    let other_type = create_self_type_with_params(cx,
+                                                  span,
+                                                  type_def.ident,
+                                                  type_def.ty_params);
+    let other_type = create_ref_type(cx, span, other_type);
+    let other_ident = cx.ident_of(~"__other");
+    let other_arg = build::mk_arg(cx, span, other_ident, other_type);
+
+    // Create the type of the return value.
+    let bool_ident = cx.ident_of(~"bool");
+    let output_type = build::mk_simple_ty_path(cx, span, bool_ident);
+
+    create_method(cx,
+                  span,
+                  method_ident,
+                  sty_region(m_imm),
+                  pure_fn,
+                  ~[ move other_arg ],
+                  output_type,
+                  body)
+}
+
 fn create_self_type_with_params(cx: ext_ctxt,
                                 span: span,
                                 type_ident: ident,
@@ -206,19 +349,60 @@ fn create_self_type_with_params(cx: ext_ctxt,
     @ast::Ty { id: cx.next_id(), node: self_type, span: span }
 }
 
+fn create_ref_type(cx: ext_ctxt, span: span, ty: @Ty) -> @Ty {
+    let region = @ast::region { id: cx.next_id(), node: re_anon };
+    let ref_type = ty_rptr(region, ast::mt { ty: ty, mutbl: m_imm });
+    @ast::Ty { id: cx.next_id(), node: ref_type, span: span }
+}
+
+/// Creates the path of a derived trait, with its type parameter if it has
+/// one.
+fn create_trait_path(cx: ext_ctxt,
+                     span: span,
+                     trait_path: &[ident],
+                     trait_param: &Option<TraitParam>)
+                  -> @ast::path {
+    let types = match *trait_param {
+        Some(ref param) => {
+            ~[ build::mk_simple_ty_path(cx, span, param.ident) ]
+        }
+        None => ~[]
+    };
+    @ast::path {
+        span: span,
+        global: true,
+        idents: trait_path.map(|x| *x),
+        rp: None,
+        types: move types
+    }
+}
+
 fn create_derived_impl(cx: ext_ctxt,
                        span: span,
-                       type_ident: ident,
-                       +ty_params: ~[ty_param],
+                       type_def: &TypeDef,
                        methods: &[@method],
-                       trait_path: &[ident])
+                       trait_path: &[ident],
+                       +trait_param: Option<TraitParam>)
                     -> @item {
-    // Create the type parameters.
+    // Create the type parameters: the one of the trait, if any, and then
+    // those of the type, each bounded by the trait.
     let impl_ty_params = dvec::DVec();
-    for ty_params.each |ty_param| {
-        let bound = build::mk_ty_path_global(cx,
-                                             span,
-                                             trait_path.map(|x| *x));
+    match trait_param {
+        Some(ref param) => {
+            let bound = build::mk_ty_path_global(cx, span, copy param.bound);
+            let bounds = @~[ TraitTyParamBound(bound) ];
+            let impl_ty_param = build::mk_ty_param(cx, param.ident, bounds);
+            impl_ty_params.push(move impl_ty_param);
+        }
+        None => ()
+    }
+    for type_def.ty_params.each |ty_param| {
+        let bound = create_trait_path(cx, span, trait_path, &trait_param);
+        let bound = @ast::Ty {
+            id: cx.next_id(),
+            node: ty_path(bound, cx.next_id()),
+            span: span
+        };
         let bounds = @~[ TraitTyParamBound(bound) ];
         let impl_ty_param = build::mk_ty_param(cx, ty_param.ident, bounds);
         impl_ty_params.push(move impl_ty_param);
@@ -226,14 +410,7 @@ fn create_derived_impl(cx: ext_ctxt,
     let impl_ty_params = dvec::unwrap(move impl_ty_params);
 
     // Create the reference to the trait.
-    let trait_path = ast::path {
-        span: span,
-        global: true,
-        idents: trait_path.map(|x| *x),
-        rp: None,
-        types: ~[]
-    };
-    let trait_path = @move trait_path;
+    let trait_path = create_trait_path(cx, span, trait_path, &trait_param);
     let trait_ref = ast::trait_ref {
         path: trait_path,
         ref_id: cx.next_id()
@@ -243,8 +420,8 @@ fn create_derived_impl(cx: ext_ctxt,
     // Create the type of `self`.
     let self_type = create_self_type_with_params(cx,
                                                  span,
-                                                 type_ident,
-                                                 ty_params);
+                                                 type_def.ident,
+                                                 type_def.ty_params);
 
     // Create the impl item.
     let impl_item = item_impl(move impl_ty_params,
@@ -254,84 +431,8 @@ fn create_derived_impl(cx: ext_ctxt,
     return create_impl_item(cx, span, move impl_item);
 }
 
-fn create_derived_eq_impl(cx: ext_ctxt,
-                          span: span,
-                          type_ident: ident,
-                          +ty_params: ~[ty_param],
-                          eq_method: @method,
-                          ne_method: @method)
-                       -> @item {
-    let methods = [ eq_method, ne_method ];
-    let trait_path = [
-        cx.ident_of(~"core"),
-        cx.ident_of(~"cmp"),
-        cx.ident_of(~"Eq")
-    ];
-    create_derived_impl(cx, span, type_ident, ty_params, methods, trait_path)
-}
-
-fn create_derived_iter_bytes_impl(cx: ext_ctxt,
-                                  span: span,
-                                  type_ident: ident,
-                                  +ty_params: ~[ty_param],
-                                  method: @method)
-                               -> @item {
-    let methods = [ method ];
-    let trait_path = [
-        cx.ident_of(~"core"),
-        cx.ident_of(~"to_bytes"),
-        cx.ident_of(~"IterBytes")
-    ];
-    create_derived_impl(cx, span, type_ident, ty_params, methods, trait_path)
-}
-
-// Creates a method from the given set of statements conforming to the
-// signature of the `iter_bytes` method.
-fn create_iter_bytes_method(cx: ext_ctxt,
-                            span: span,
-                            +statements: ~[@stmt])
-                         -> @method {
-    // Create the `lsb0` parameter.
-    let bool_ident = cx.ident_of(~"bool");
-    let lsb0_arg_type = build::mk_simple_ty_path(cx, span, bool_ident);
-    let lsb0_ident = cx.ident_of(~"__lsb0");
-    let lsb0_arg = build::mk_arg(cx, span, lsb0_ident, lsb0_arg_type);
-
-    // Create the `f` parameter.
-    let core_ident = cx.ident_of(~"core");
-    let to_bytes_ident = cx.ident_of(~"to_bytes");
-    let cb_ident = cx.ident_of(~"Cb");
-    let core_to_bytes_cb_ident = ~[ core_ident, to_bytes_ident, cb_ident ];
-    let f_arg_type = build::mk_ty_path(cx, span, core_to_bytes_cb_ident);
-    let f_ident = cx.ident_of(~"__f");
-    let f_arg = build::mk_arg(cx, span, f_ident, f_arg_type);
-
-    // Create the type of the return value.
-    let output_type = @ast::Ty { id: cx.next_id(), node: ty_nil, span: span };
-
-    // Create the function declaration.
-    let inputs = ~[ move lsb0_arg, move f_arg ];
-    let fn_decl = build::mk_fn_decl(move inputs, output_type);
-
-    // Create the body block.
-    let body_block = build::mk_block_(cx, span, move statements);
-
-    // Create the method.
-    let self_ty = spanned { node: sty_region(m_imm), span: span };
-    let method_ident = cx.ident_of(~"iter_bytes");
-    @ast::method {
-        ident: method_ident,
-        attrs: ~[],
-        tps: ~[],
-        self_ty: self_ty,
-        purity: pure_fn,
-        decl: move fn_decl,
-        body: move body_block,
-        id: cx.next_id(),
-        span: span,
-        self_id: cx.next_id(),
-        vis: public
-    }
+fn create_binding_ident(cx: ext_ctxt, prefix: &str, i: uint) -> ident {
+    cx.ident_of(str::from_slice(prefix) + i.to_str())
 }
 
 fn create_subpatterns(cx: ext_ctxt,
@@ -340,10 +441,9 @@ fn create_subpatterns(cx: ext_ctxt,
                       n: uint)
                    -> ~[@pat] {
     let subpats = dvec::DVec();
-    for uint::range(0, n) |_i| {
+    for uint::range(0, n) |i| {
         // Create the subidentifier.
-        let index = subpats.len().to_str();
-        let ident = cx.ident_of(prefix + index);
+        let ident = create_binding_ident(cx, prefix, i);
 
         // Create the subpattern.
         let subpath = build::mk_raw_path(span, ~[ ident ]);
@@ -354,78 +454,252 @@ fn create_subpatterns(cx: ext_ctxt,
     return dvec::unwrap(move subpats);
 }
 
+/// Creates a pattern matching the enum variant `shape` that binds its
+/// fields by reference to `prefix0`, `prefix1`, and so on.
 fn create_enum_variant_pattern(cx: ext_ctxt,
                                span: span,
-                               variant: &variant,
+                               shape: &Shape,
                                prefix: ~str)
                             -> @pat {
-    let variant_ident = variant.node.name;
-    match variant.node.kind {
-        tuple_variant_kind(ref variant_args) => {
-            if variant_args.len() == 0 {
-                return build::mk_pat_ident(cx, span, variant_ident);
-            }
+    if shape.fields.len() == 0 {
+        return build::mk_pat_ident(cx, span, shape.name);
+    }
 
-            let matching_path = build::mk_raw_path(span, ~[ variant_ident ]);
-            let subpats = create_subpatterns(cx,
-                                             span,
-                                             prefix,
-                                             variant_args.len());
+    let matching_path = build::mk_raw_path(span, ~[ shape.name ]);
+    let subpats = create_subpatterns(cx, span, prefix, shape.fields.len());
+    if !shape.named {
+        return build::mk_pat_enum(cx, span, matching_path, move subpats);
+    }
 
-            return build::mk_pat_enum(cx, span, matching_path, move subpats);
-        }
-        struct_variant_kind(struct_def) => {
-            let matching_path = build::mk_raw_path(span, ~[ variant_ident ]);
-            let subpats = create_subpatterns(cx,
-                                             span,
-                                             prefix,
-                                             struct_def.fields.len());
-
-            let field_pats = do struct_def.fields.mapi |i, struct_field| {
-                let ident = match struct_field.node.kind {
-                    named_field(ident, _, _) => ident,
-                    unnamed_field => {
-                        cx.span_bug(span, ~"unexpected unnamed field");
-                    }
-                };
-                ast::field_pat { ident: ident, pat: subpats[i] }
-            };
+    let field_pats = do shape.fields.mapi |i, name| {
+        ast::field_pat { ident: name.get(), pat: subpats[i] }
+    };
+    build::mk_pat_struct(cx, span, matching_path, field_pats)
+}
 
-            build::mk_pat_struct(cx, span, matching_path, field_pats)
-        }
-        enum_variant_kind(*) => {
-            cx.span_unimpl(span, ~"enum variants for `deriving`");
-        }
+/// Creates a pattern matching the enum variant `shape` whatever its fields.
+fn create_enum_variant_wildcard(cx: ext_ctxt,
+                                span: span,
+                                shape: &Shape)
+                             -> @pat {
+    if shape.fields.len() == 0 {
+        return build::mk_pat_ident(cx, span, shape.name);
+    }
+
+    let matching_path = build::mk_raw_path(span, ~[ shape.name ]);
+    if shape.named {
+        build::mk_pat(cx, span, pat_struct(matching_path, ~[], true))
+    } else {
+        build::mk_pat(cx, span, pat_enum(matching_path, None))
     }
 }
 
-fn call_substructure_eq_method(cx: ext_ctxt,
-                               span: span,
-                               self_field: @expr,
-                               other_field_ref: @expr,
-                               method_ident: ident,
-                               junction: Junction,
-                               chain_expr: &mut Option<@expr>) {
-    // Call the substructure method.
-    let self_method = build::mk_access_(cx, span, self_field, method_ident);
-    let self_call = build::mk_call_(cx,
-                                    span,
-                                    self_method,
-                                    ~[ other_field_ref ]);
-
-    // Connect to the outer expression if necessary.
-    *chain_expr = match *chain_expr {
-        None => Some(self_call),
-        Some(copy old_outer_expr) => {
-            let binop = junction.to_binop();
-            let chain_expr = build::mk_binary(cx,
-                                              span,
-                                              binop,
-                                              old_outer_expr,
-                                              self_call);
-            Some(chain_expr)
+fn create_arm(cx: ext_ctxt, span: span, pat: @pat, body: @expr) -> ast::arm {
+    ast::arm {
+        pats: ~[ pat ],
+        guard: None,
+        body: build::mk_simple_block(cx, span, body)
+    }
+}
+
+/// Creates `*ident`, or just `ident` if it is not a reference.
+fn create_value_expr(cx: ext_ctxt,
+                     span: span,
+                     ident: ident,
+                     is_ref: bool)
+                  -> @expr {
+    let path = build::mk_path(cx, span, ~[ ident ]);
+    if is_ref { build::mk_unary(cx, span, deref, path) } else { path }
+}
+
+fn create_field_expr(cx: ext_ctxt, span: span, field_ref: FieldRef) -> @expr {
+    match field_ref {
+        FieldOf(base, name) => build::mk_access(cx, span, ~[ base ], name),
+        Binding(ident) => create_value_expr(cx, span, ident, true)
+    }
+}
+
+fn call_method(cx: ext_ctxt,
+               span: span,
+               receiver: @expr,
+               method_name: ~str,
+               +args: ~[@expr])
+            -> @expr {
+    let method_ident = cx.ident_of(method_name);
+    let method = build::mk_access_(cx, span, receiver, method_ident);
+    build::mk_call_(cx, span, method, move args)
+}
+
+/// Creates the closure `|params| body`.
+fn create_lambda(cx: ext_ctxt,
+                 span: span,
+                 params: &[ident],
+                 body: @expr)
+              -> @expr {
+    let inputs = do params.map |param| {
+        let param_type = @ast::Ty {
+            id: cx.next_id(),
+            node: ty_infer,
+            span: span
+        };
+        build::mk_arg(cx, span, *param, param_type)
+    };
+    let output_type = @ast::Ty {
+        id: cx.next_id(),
+        node: ty_infer,
+        span: span
+    };
+    let fn_decl = build::mk_fn_decl(move inputs, output_type);
+    let body_block = build::mk_simple_block(cx, span, body);
+    build::mk_expr(cx, span, ast::expr_fn_block(move fn_decl,
+                                                move body_block,
+                                                @~[]))
+}
+
+/// Creates an expression from the fields of `self` (the value itself if
+/// `self_is_ref` is false, a reference to it otherwise): for a struct,
+/// `f` applied to its fields, and for an enum, a match with an arm for
+/// each variant, in which `f` is applied to the fields of that variant.
+fn walk_self(cx: ext_ctxt,
+             span: span,
+             type_def: &TypeDef,
+             self_is_ref: bool,
+             f: SubstructureFn)
+          -> @expr {
+    let self_ident = cx.ident_of(~"self");
+
+    if !type_def.is_enum {
+        let shape = &type_def.shapes[0];
+        let fields = do shape.fields.map |name| {
+            Field {
+                name: *name,
+                self_ref: FieldOf(self_ident, name.get()),
+                other_ref: None
+            }
+        };
+        return f(shape, fields);
+    }
+
+    let arms = do type_def.shapes.map |shape| {
+        let fields = do shape.fields.mapi |i, name| {
+            Field {
+                name: *name,
+                self_ref: Binding(create_binding_ident(cx, "__self", i)),
+                other_ref: None
+            }
+        };
+        let pat = create_enum_variant_pattern(cx, span, shape, ~"__self");
+        create_arm(cx, span, pat, f(shape, fields))
+    };
+    let self_expr = create_value_expr(cx, span, self_ident, self_is_ref);
+    build::mk_expr(cx, span, expr_match(self_expr, move arms))
+}
+
+/// Like `walk_self`, but for comparing `self` with `__other`, a reference
+/// to another value of the same type. For an enum, `f` is applied where
+/// both are the same variant, and `mismatch` gives the result where they
+/// are not from the index of the variant of `self` and an expression
+/// computing the index of the variant of `__other`.
+fn walk_self_and_other(cx: ext_ctxt,
+                       span: span,
+                       type_def: &TypeDef,
+                       f: SubstructureFn,
+                       mismatch: MismatchFn)
+                    -> @expr {
+    let self_ident = cx.ident_of(~"self");
+    let other_ident = cx.ident_of(~"__other");
+
+    if !type_def.is_enum {
+        let shape = &type_def.shapes[0];
+        let fields = do shape.fields.map |name| {
+            Field {
+                name: *name,
+                self_ref: FieldOf(self_ident, name.get()),
+                other_ref: Some(FieldOf(other_ident, name.get()))
+            }
+        };
+        return f(shape, fields);
+    }
+
+    let self_arms = do type_def.shapes.map |shape| {
+        let fields = do shape.fields.mapi |i, name| {
+            Field {
+                name: *name,
+                self_ref: Binding(create_binding_ident(cx, "__self", i)),
+                other_ref: Some(Binding(create_binding_ident(cx,
+                                                             "__other",
+                                                             i)))
+            }
+        };
+
+        // Create the arm for `__other` being the same variant.
+        let other_arms = dvec::DVec();
+        let other_pat = create_enum_variant_pattern(cx,
+                                                    span,
+                                                    shape,
+                                                    ~"__other");
+        other_arms.push(create_arm(cx, span, other_pat, f(shape, fields)));
+
+        // Maybe generate a non-matching case. If there is only one
+        // variant then there will always be a match.
+        if type_def.shapes.len() > 1 {
+            let wild_pat = build::mk_pat(cx, span, pat_wild);
+            let other_index = create_variant_index_expr(cx,
+                                                        span,
+                                                        type_def,
+                                                        other_ident);
+            let mismatch_expr = mismatch(shape.index, other_index);
+            other_arms.push(create_arm(cx, span, wild_pat, mismatch_expr));
         }
+
+        let other_expr = create_value_expr(cx, span, other_ident, true);
+        let other_arms = dvec::unwrap(move other_arms);
+        let other_match = expr_match(other_expr, move other_arms);
+        let other_match = build::mk_expr(cx, span, move other_match);
+
+        let self_pat = create_enum_variant_pattern(cx,
+                                                   span,
+                                                   shape,
+                                                   ~"__self");
+        create_arm(cx, span, self_pat, other_match)
     };
+
+    let self_expr = create_value_expr(cx, span, self_ident, true);
+    build::mk_expr(cx, span, expr_match(self_expr, move self_arms))
+}
+
+/// Creates a match giving the index of the variant `*ident` is.
+fn create_variant_index_expr(cx: ext_ctxt,
+                             span: span,
+                             type_def: &TypeDef,
+                             ident: ident)
+                          -> @expr {
+    let arms = do type_def.shapes.map |shape| {
+        let pat = create_enum_variant_wildcard(cx, span, shape);
+        create_arm(cx, span, pat, build::mk_uint(cx, span, shape.index))
+    };
+    let value_expr = create_value_expr(cx, span, ident, true);
+    build::mk_expr(cx, span, expr_match(value_expr, move arms))
+}
+
+/// Creates an expression constructing the struct or enum variant `shape`
+/// out of `values`, one for each of its fields.
+fn create_constructor(cx: ext_ctxt,
+                      span: span,
+                      shape: &Shape,
+                      +values: ~[@expr])
+                   -> @expr {
+    if values.len() == 0 {
+        build::mk_path(cx, span, ~[ shape.name ])
+    } else if shape.named {
+        let fields = do vec::map2(shape.fields, values) |name, value| {
+            let field = { ident: name.get(), ex: *value };
+            field
+        };
+        build::mk_struct_e(cx, span, ~[ shape.name ], move fields)
+    } else {
+        build::mk_call(cx, span, ~[ shape.name ], move values)
+    }
 }
 
 fn finish_eq_chain_expr(cx: ext_ctxt,
@@ -444,429 +718,591 @@ fn finish_eq_chain_expr(cx: ext_ctxt,
     }
 }
 
-fn call_substructure_iter_bytes_method(cx: ext_ctxt,
-                                       span: span,
-                                       self_field: @expr)
-                                    -> @stmt {
-    // Gather up the parameters we want to chain along.
-    let lsb0_ident = cx.ident_of(~"__lsb0");
-    let f_ident = cx.ident_of(~"__f");
-    let lsb0_expr = build::mk_path(cx, span, ~[ lsb0_ident ]);
-    let f_expr = build::mk_path(cx, span, ~[ f_ident ]);
-
-    // Call the substructure method.
-    let iter_bytes_ident = cx.ident_of(~"iter_bytes");
-    let self_method = build::mk_access_(cx,
-                                        span,
-                                        self_field,
-                                        iter_bytes_ident);
-    let self_call = build::mk_call_(cx,
-                                    span,
-                                    self_method,
-                                    ~[ lsb0_expr, f_expr ]);
+fn expand_deriving_eq_def(cx: ext_ctxt,
+                          span: span,
+                          type_def: &TypeDef)
+                       -> @item {
+    // Create the methods.
+    let eq_method = create_eq_method(cx, span, type_def, ~"eq", Conjunction);
+    let ne_method = create_eq_method(cx, span, type_def, ~"ne", Disjunction);
 
-    // Create a statement out of this expression.
-    build::mk_stmt(cx, span, self_call)
+    // Create the implementation.
+    let trait_path = [
+        cx.ident_of(~"core"),
+        cx.ident_of(~"cmp"),
+        cx.ident_of(~"Eq")
+    ];
+    create_derived_impl(cx,
+                        span,
+                        type_def,
+                        [ eq_method, ne_method ],
+                        trait_path,
+                        None)
 }
 
-fn variant_arg_count(cx: ext_ctxt, span: span, variant: &variant) -> uint {
-    match variant.node.kind {
-        tuple_variant_kind(args) => args.len(),
-        struct_variant_kind(struct_def) => struct_def.fields.len(),
-        enum_variant_kind(*) => {
-            cx.span_bug(span, ~"variant_arg_count: enum variants deprecated")
+/// Creates `eq` or `ne`, which call themselves on each pair of fields
+/// and join the results with `junction`.
+fn create_eq_method(cx: ext_ctxt,
+                    span: span,
+                    type_def: &TypeDef,
+                    method_name: ~str,
+                    junction: Junction)
+                 -> @method {
+    let body = walk_self_and_other(cx, span, type_def, |_shape, fields| {
+        let mut chain_expr = None;
+        for fields.each |field| {
+            let self_field = create_field_expr(cx, span, field.self_ref);
+            let other_field = create_field_expr(cx,
+                                                span,
+                                                field.other_ref.get());
+            let other_field_ref = build::mk_addr_of(cx, span, other_field);
+            let call = call_method(cx,
+                                   span,
+                                   self_field,
+                                   copy method_name,
+                                   ~[ other_field_ref ]);
+
+            // Connect to the outer expression if necessary.
+            chain_expr = match chain_expr {
+                None => Some(call),
+                Some(old_outer_expr) => {
+                    let binop = junction.to_binop();
+                    Some(build::mk_binary(cx,
+                                          span,
+                                          binop,
+                                          old_outer_expr,
+                                          call))
+                }
+            };
         }
-    }
+        finish_eq_chain_expr(cx, span, chain_expr, junction)
+    }, |_self_index, _other_index| {
+        match junction {
+            Conjunction => build::mk_bool(cx, span, false),
+            Disjunction => build::mk_bool(cx, span, true),
+        }
+    });
+
+    let method_ident = cx.ident_of(method_name);
+    create_comparison_method(cx, span, type_def, method_ident, body)
 }
 
-fn expand_deriving_eq_struct_def(cx: ext_ctxt,
-                                 span: span,
-                                 struct_def: &struct_def,
-                                 type_ident: ident,
-                                 +ty_params: ~[ty_param])
-                              -> @item {
+fn expand_deriving_ord_def(cx: ext_ctxt,
+                           span: span,
+                           type_def: &TypeDef)
+                        -> @item {
     // Create the methods.
-    let eq_ident = cx.ident_of(~"eq");
-    let ne_ident = cx.ident_of(~"ne");
-    let eq_method = expand_deriving_eq_struct_method(cx,
-                                                     span,
-                                                     struct_def,
-                                                     eq_ident,
-                                                     type_ident,
-                                                     ty_params,
-                                                     Conjunction);
-    let ne_method = expand_deriving_eq_struct_method(cx,
-                                                     span,
-                                                     struct_def,
-                                                     ne_ident,
-                                                     type_ident,
-                                                     ty_params,
-                                                     Disjunction);
+    let lt_method = create_ord_method(cx, span, type_def, ~"lt", true, false);
+    let le_method = create_ord_method(cx, span, type_def, ~"le", true, true);
+    let ge_method = create_ord_method(cx, span, type_def, ~"ge", false, true);
+    let gt_method = create_ord_method(cx,
+                                      span,
+                                      type_def,
+                                      ~"gt",
+                                      false,
+                                      false);
 
     // Create the implementation.
-    return create_derived_eq_impl(cx,
-                                  span,
-                                  type_ident,
-                                  move ty_params,
-                                  eq_method,
-                                  ne_method);
+    let trait_path = [
+        cx.ident_of(~"core"),
+        cx.ident_of(~"cmp"),
+        cx.ident_of(~"Ord")
+    ];
+    create_derived_impl(cx,
+                        span,
+                        type_def,
+                        [ lt_method, le_method, ge_method, gt_method ],
+                        trait_path,
+                        None)
 }
 
-fn expand_deriving_eq_enum_def(cx: ext_ctxt,
-                               span: span,
-                               enum_definition: &enum_def,
-                               type_ident: ident,
-                               +ty_params: ~[ty_param])
-                            -> @item {
-    // Create the methods.
-    let eq_ident = cx.ident_of(~"eq");
-    let ne_ident = cx.ident_of(~"ne");
-    let eq_method = expand_deriving_eq_enum_method(cx,
-                                                   span,
-                                                   enum_definition,
-                                                   eq_ident,
-                                                   type_ident,
-                                                   ty_params,
-                                                   Conjunction);
-    let ne_method = expand_deriving_eq_enum_method(cx,
-                                                   span,
-                                                   enum_definition,
-                                                   ne_ident,
-                                                   type_ident,
-                                                   ty_params,
-                                                   Disjunction);
+/// Creates `lt`, `le`, `ge` or `gt`, which order values lexicographically
+/// by their fields, and the variants of an enum in the order they are
+/// declared. `less` says which way the method orders, and `or_equal`
+/// whether it holds for equal values.
+fn create_ord_method(cx: ext_ctxt,
+                     span: span,
+                     type_def: &TypeDef,
+                     method_name: ~str,
+                     less: bool,
+                     or_equal: bool)
+                  -> @method {
+    let (strict_name, strict_binop) = if less {
+        (~"lt", lt)
+    } else {
+        (~"gt", gt)
+    };
 
-    // Create the implementation.
-    return create_derived_eq_impl(cx,
-                                  span,
-                                  type_ident,
-                                  move ty_params,
-                                  eq_method,
-                                  ne_method);
-}
-
-fn expand_deriving_iter_bytes_struct_def(cx: ext_ctxt,
-                                         span: span,
-                                         struct_def: &struct_def,
-                                         type_ident: ident,
-                                         +ty_params: ~[ty_param])
-                                      -> @item {
-    // Create the method.
-    let method = expand_deriving_iter_bytes_struct_method(cx,
-                                                          span,
-                                                          struct_def);
+    let body = walk_self_and_other(cx, span, type_def, |_shape, fields| {
+        // Working back from the last field, each field decides unless
+        // it is equal in both values:
+        //
+        //     self.a < other.a || (!(other.a < self.a) && <rest>)
+        let mut expr = build::mk_bool(cx, span, or_equal);
+        for vec::rev_each(fields) |field| {
+            let self_field = create_field_expr(cx, span, field.self_ref);
+            let other_field = create_field_expr(cx,
+                                                span,
+                                                field.other_ref.get());
+            let other_field_ref = build::mk_addr_of(cx, span, other_field);
+            let self_first = call_method(cx,
+                                         span,
+                                         self_field,
+                                         copy strict_name,
+                                         ~[ other_field_ref ]);
+
+            let self_field = create_field_expr(cx, span, field.self_ref);
+            let self_field_ref = build::mk_addr_of(cx, span, self_field);
+            let other_field = create_field_expr(cx,
+                                                span,
+                                                field.other_ref.get());
+            let other_first = call_method(cx,
+                                          span,
+                                          other_field,
+                                          copy strict_name,
+                                          ~[ self_field_ref ]);
+
+            let not_other_first = build::mk_unary(cx, span, not, other_first);
+            let rest = build::mk_binary(cx, span, and, not_other_first, expr);
+            expr = build::mk_binary(cx, span, or, self_first, rest);
+        }
+        expr
+    }, |self_index, other_index| {
+        let self_index = build::mk_uint(cx, span, self_index);
+        build::mk_binary(cx, span, strict_binop, self_index, other_index)
+    });
+
+    let method_ident = cx.ident_of(method_name);
+    create_comparison_method(cx, span, type_def, method_ident, body)
+}
+
+fn expand_deriving_clone_def(cx: ext_ctxt,
+                             span: span,
+                             type_def: &TypeDef)
+                          -> @item {
+    // Create the method, which clones each field into a new value.
+    let body = walk_self(cx, span, type_def, true, |shape, fields| {
+        let values = do fields.map |field| {
+            let self_field = create_field_expr(cx, span, field.self_ref);
+            call_method(cx, span, self_field, ~"clone", ~[])
+        };
+        create_constructor(cx, span, shape, move values)
+    });
+    let output_type = create_self_type_with_params(cx,
+                                                   span,
+                                                   type_def.ident,
+                                                   type_def.ty_params);
+    let method = create_method(cx,
+                               span,
+                               cx.ident_of(~"clone"),
+                               sty_region(m_imm),
+                               impure_fn,
+                               ~[],
+                               output_type,
+                               body);
 
     // Create the implementation.
-    return create_derived_iter_bytes_impl(cx,
-                                          span,
-                                          type_ident,
-                                          move ty_params,
-                                          method);
+    let trait_path = [
+        cx.ident_of(~"core"),
+        cx.ident_of(~"clone"),
+        cx.ident_of(~"Clone")
+    ];
+    create_derived_impl(cx, span, type_def, [ method ], trait_path, None)
 }
 
-fn expand_deriving_iter_bytes_enum_def(cx: ext_ctxt,
-                                       span: span,
-                                       enum_definition: &enum_def,
-                                       type_ident: ident,
-                                       +ty_params: ~[ty_param])
-                                    -> @item {
+fn expand_deriving_to_str_def(cx: ext_ctxt,
+                              span: span,
+                              type_def: &TypeDef)
+                           -> @item {
     // Create the method.
-    let method = expand_deriving_iter_bytes_enum_method(cx,
-                                                        span,
-                                                        enum_definition);
+    let body = walk_self(cx, span, type_def, false, |shape, fields| {
+        create_to_str_expr(cx, span, shape, fields)
+    });
+    let str_type = build::mk_simple_ty_path(cx, span, cx.ident_of(~"str"));
+    let output_type = @ast::Ty {
+        id: cx.next_id(),
+        node: ty_uniq(ast::mt { ty: str_type, mutbl: m_imm }),
+        span: span
+    };
+    let method = create_method(cx,
+                               span,
+                               cx.ident_of(~"to_str"),
+                               sty_by_ref,
+                               pure_fn,
+                               ~[],
+                               output_type,
+                               body);
 
     // Create the implementation.
-    return create_derived_iter_bytes_impl(cx,
-                                          span,
-                                          type_ident,
-                                          move ty_params,
-                                          method);
-}
-
-fn expand_deriving_eq_struct_method(cx: ext_ctxt,
-                                    span: span,
-                                    struct_def: &struct_def,
-                                    method_ident: ident,
-                                    type_ident: ident,
-                                    ty_params: &[ty_param],
-                                    junction: Junction)
-                                 -> @method {
-    let self_ident = cx.ident_of(~"self");
-    let other_ident = cx.ident_of(~"__other");
+    let trait_path = [
+        cx.ident_of(~"core"),
+        cx.ident_of(~"to_str"),
+        cx.ident_of(~"ToStr")
+    ];
+    create_derived_impl(cx, span, type_def, [ method ], trait_path, None)
+}
 
-    // Create the body of the method.
-    let mut outer_expr = None;
-    for struct_def.fields.each |struct_field| {
-        match struct_field.node.kind {
-            named_field(ident, _, _) => {
-                // Create the accessor for the other field.
-                let other_field = build::mk_access(cx,
-                                                   span,
-                                                   ~[ other_ident ],
-                                                   ident);
-                let other_field_ref = build::mk_addr_of(cx,
-                                                        span,
-                                                        other_field);
+/// Creates an expression building a string that looks like the expression
+/// constructing the struct or variant, like `Point { x: 1, y: 2 }` or
+/// `Some(3)`, out of the `to_str` of each field.
+fn create_to_str_expr(cx: ext_ctxt,
+                      span: span,
+                      shape: &Shape,
+                      fields: &[Field])
+                   -> @expr {
+    let (open, close) = if shape.named {
+        (~" { ", ~" }")
+    } else {
+        (~"(", ~")")
+    };
 
-                // Create the accessor for this field.
-                let self_field = build::mk_access(cx,
-                                                  span,
-                                                  ~[ self_ident ],
-                                                  ident);
-
-                // Call the substructure method.
-                call_substructure_eq_method(cx,
-                                            span,
-                                            self_field,
-                                            other_field_ref,
-                                            method_ident,
-                                            junction,
-                                            &mut outer_expr);
-            }
-            unnamed_field => {
-                cx.span_unimpl(span, ~"unnamed fields with `deriving_eq`");
-            }
-        }
+    let mut expr = None;
+    let mut text = cx.str_of(shape.name);
+    if fields.len() > 0 { text += open; }
+    for fields.eachi |i, field| {
+        if i > 0 { text += ~", "; }
+        if shape.named { text += cx.str_of(field.name.get()) + ~": "; }
+        let text_expr = build::mk_uniq_str(cx, span, copy text);
+        expr = Some(append_str_expr(cx, span, expr, text_expr));
+        text = ~"";
+
+        let self_field = create_field_expr(cx, span, field.self_ref);
+        let field_str = call_method(cx, span, self_field, ~"to_str", ~[]);
+        expr = Some(append_str_expr(cx, span, expr, field_str));
     }
+    if fields.len() > 0 { text += close; }
 
-    // Create the method itself.
-    let body = finish_eq_chain_expr(cx, span, outer_expr, junction);
-    return create_eq_method(cx,
-                            span,
-                            method_ident,
-                            type_ident,
-                            ty_params,
-                            body);
+    if text.is_empty() {
+        return expr.get();
+    }
+    let text_expr = build::mk_uniq_str(cx, span, move text);
+    append_str_expr(cx, span, expr, text_expr)
 }
 
-fn expand_deriving_iter_bytes_struct_method(cx: ext_ctxt,
-                                            span: span,
-                                            struct_def: &struct_def)
-                                         -> @method {
-    let self_ident = cx.ident_of(~"self");
+fn append_str_expr(cx: ext_ctxt,
+                   span: span,
+                   expr: Option<@expr>,
+                   rhs: @expr)
+                -> @expr {
+    match expr {
+        None => rhs,
+        Some(lhs) => build::mk_binary(cx, span, add, lhs, rhs)
+    }
+}
 
+fn expand_deriving_iter_bytes_def(cx: ext_ctxt,
+                                  span: span,
+                                  type_def: &TypeDef)
+                               -> @item {
     // Create the body of the method.
-    let statements = dvec::DVec();
-    for struct_def.fields.each |struct_field| {
-        match struct_field.node.kind {
-            named_field(ident, _, _) => {
-                // Create the accessor for this field.
-                let self_field = build::mk_access(cx,
-                                                  span,
-                                                  ~[ self_ident ],
-                                                  ident);
-
-                // Call the substructure method.
-                let stmt = call_substructure_iter_bytes_method(cx,
-                                                               span,
-                                                               self_field);
-                statements.push(stmt);
-            }
-            unnamed_field => {
-                cx.span_unimpl(span,
-                               ~"unnamed fields with `deriving_iter_bytes`");
-            }
+    let body = walk_self(cx, span, type_def, true, |shape, fields| {
+        let stmts = dvec::DVec();
+
+        // Feed the discriminant of an enum variant to the byte iteration
+        // function first.
+        if type_def.is_enum {
+            let discriminant = match shape.disr_expr {
+                Some(copy disr_expr) => disr_expr,
+                None => build::mk_uint(cx, span, shape.index)
+            };
+            stmts.push(call_substructure_iter_bytes_method(cx,
+                                                           span,
+                                                           discriminant));
         }
-    }
 
-    // Create the method itself.
-    let statements = dvec::unwrap(move statements);
-    return create_iter_bytes_method(cx, span, move statements);
-}
+        // Feed each field to the byte iteration function as well.
+        for fields.each |field| {
+            let self_field = create_field_expr(cx, span, field.self_ref);
+            stmts.push(call_substructure_iter_bytes_method(cx,
+                                                           span,
+                                                           self_field));
+        }
 
-fn expand_deriving_eq_enum_method(cx: ext_ctxt,
-                                  span: span,
-                                  enum_definition: &enum_def,
-                                  method_ident: ident,
-                                  type_ident: ident,
-                                  ty_params: &[ty_param],
-                                  junction: Junction)
-                               -> @method {
-    let self_ident = cx.ident_of(~"self");
-    let other_ident = cx.ident_of(~"__other");
+        let stmts = dvec::unwrap(move stmts);
+        build::mk_block(cx, span, ~[], move stmts, None)
+    });
 
-    let is_eq;
-    match junction {
-        Conjunction => is_eq = true,
-        Disjunction => is_eq = false,
-    }
+    // Create the `lsb0` parameter.
+    let bool_ident = cx.ident_of(~"bool");
+    let lsb0_arg_type = build::mk_simple_ty_path(cx, span, bool_ident);
+    let lsb0_ident = cx.ident_of(~"__lsb0");
+    let lsb0_arg = build::mk_arg(cx, span, lsb0_ident, lsb0_arg_type);
 
-    // Create the arms of the self match in the method body.
-    let self_arms = dvec::DVec();
-    for enum_definition.variants.each |self_variant| {
-        let other_arms = dvec::DVec();
+    // Create the `f` parameter.
+    let core_ident = cx.ident_of(~"core");
+    let to_bytes_ident = cx.ident_of(~"to_bytes");
+    let cb_ident = cx.ident_of(~"Cb");
+    let core_to_bytes_cb_ident = ~[ core_ident, to_bytes_ident, cb_ident ];
+    let f_arg_type = build::mk_ty_path(cx, span, core_to_bytes_cb_ident);
+    let f_ident = cx.ident_of(~"__f");
+    let f_arg = build::mk_arg(cx, span, f_ident, f_arg_type);
 
-        // Create the matching pattern.
-        let matching_pat = create_enum_variant_pattern(cx,
-                                                       span,
-                                                       self_variant,
-                                                       ~"__other");
-
-        // Create the matching pattern body.
-        let mut matching_body_expr = None;
-        for uint::range(0, variant_arg_count(cx, span, self_variant)) |i| {
-            // Create the expression for the other field.
-            let other_field_ident = cx.ident_of(~"__other" + i.to_str());
-            let other_field = build::mk_path(cx,
-                                             span,
-                                             ~[ other_field_ident ]);
-
-            // Create the expression for this field.
-            let self_field_ident = cx.ident_of(~"__self" + i.to_str());
-            let self_field = build::mk_path(cx, span, ~[ self_field_ident ]);
-
-            // Call the substructure method.
-            call_substructure_eq_method(cx,
-                                        span,
-                                        self_field,
-                                        other_field,
-                                        method_ident,
-                                        junction,
-                                        &mut matching_body_expr);
-        }
+    // Create the type of the return value.
+    let output_type = @ast::Ty { id: cx.next_id(), node: ty_nil, span: span };
 
-        let matching_body_expr = finish_eq_chain_expr(cx,
-                                                      span,
-                                                      matching_body_expr,
-                                                      junction);
-        let matching_body_block = build::mk_simple_block(cx,
-                                                         span,
-                                                         matching_body_expr);
-
-        // Create the matching arm.
-        let matching_arm = ast::arm {
-            pats: ~[ matching_pat ],
-            guard: None,
-            body: move matching_body_block
-        };
-        other_arms.push(move matching_arm);
+    // Create the method.
+    let method = create_method(cx,
+                               span,
+                               cx.ident_of(~"iter_bytes"),
+                               sty_region(m_imm),
+                               pure_fn,
+                               ~[ move lsb0_arg, move f_arg ],
+                               output_type,
+                               body);
 
-        // Maybe generate a non-matching case. If there is only one
-        // variant then there will always be a match.
-        if enum_definition.variants.len() > 1 {
-            // Create the nonmatching pattern.
-            let nonmatching_pat = @ast::pat {
-                id: cx.next_id(),
-                node: pat_wild,
-                span: span
-            };
+    // Create the implementation.
+    let trait_path = [
+        cx.ident_of(~"core"),
+        cx.ident_of(~"to_bytes"),
+        cx.ident_of(~"IterBytes")
+    ];
+    create_derived_impl(cx, span, type_def, [ method ], trait_path, None)
+}
 
-            // Create the nonmatching pattern body.
-            let nonmatching_expr = build::mk_bool(cx, span, !is_eq);
-            let nonmatching_body_block =
-                build::mk_simple_block(cx,
-                                       span,
-                                       nonmatching_expr);
+fn call_substructure_iter_bytes_method(cx: ext_ctxt,
+                                       span: span,
+                                       self_field: @expr)
+                                    -> @stmt {
+    // Gather up the parameters we want to chain along.
+    let lsb0_ident = cx.ident_of(~"__lsb0");
+    let f_ident = cx.ident_of(~"__f");
+    let lsb0_expr = build::mk_path(cx, span, ~[ lsb0_ident ]);
+    let f_expr = build::mk_path(cx, span, ~[ f_ident ]);
 
-            // Create the nonmatching arm.
-            let nonmatching_arm = ast::arm {
-                pats: ~[ nonmatching_pat ],
-                guard: None,
-                body: nonmatching_body_block,
-            };
-            other_arms.push(nonmatching_arm);
-        }
+    // Call the substructure method.
+    let self_call = call_method(cx,
+                                span,
+                                self_field,
+                                ~"iter_bytes",
+                                ~[ lsb0_expr, f_expr ]);
 
-        // Create the self pattern.
-        let self_pat = create_enum_variant_pattern(cx,
-                                                   span,
-                                                   self_variant,
-                                                   ~"__self");
+    // Create a statement out of this expression.
+    build::mk_stmt(cx, span, self_call)
+}
 
-        // Create the self pattern body.
-        let other_expr = build::mk_path(cx, span, ~[ other_ident ]);
-        let other_expr = build::mk_unary(cx, span, deref, other_expr);
-        let other_arms = dvec::unwrap(move other_arms);
-        let other_match_expr = expr_match(other_expr, move other_arms);
-        let other_match_expr = build::mk_expr(cx,
-                                              span,
-                                              move other_match_expr);
-        let other_match_body_block = build::mk_simple_block(cx,
-                                                            span,
-                                                            other_match_expr);
-
-        // Create the self arm.
-        let self_arm = ast::arm {
-            pats: ~[ self_pat ],
-            guard: None,
-            body: other_match_body_block,
+fn expand_deriving_encodable_def(cx: ext_ctxt,
+                                 span: span,
+                                 type_def: &TypeDef)
+                              -> @item {
+    let s_ident = cx.ident_of(~"__s");
+
+    // Create the body of the method, which emits each field, in a struct
+    // or in an enum variant.
+    let body = walk_self(cx, span, type_def, true, |shape, fields| {
+        let stmts = do fields.mapi |i, field| {
+            let self_field = create_field_expr(cx, span, field.self_ref);
+            let s_expr = build::mk_path(cx, span, ~[ s_ident ]);
+            let encode = call_method(cx,
+                                     span,
+                                     self_field,
+                                     ~"encode",
+                                     ~[ s_expr ]);
+            let encode = create_lambda(cx, span, [], encode);
+
+            let index = build::mk_uint(cx, span, i);
+            let s_expr = build::mk_path(cx, span, ~[ s_ident ]);
+            let emit = if type_def.is_enum {
+                call_method(cx,
+                            span,
+                            s_expr,
+                            ~"emit_enum_variant_arg",
+                            ~[ index, encode ])
+            } else {
+                let name = cx.str_of(field.name.get());
+                let name = build::mk_base_str(cx, span, move name);
+                call_method(cx,
+                            span,
+                            s_expr,
+                            ~"emit_field",
+                            ~[ name, index, encode ])
+            };
+            build::mk_stmt(cx, span, emit)
         };
-        self_arms.push(move self_arm);
-    }
 
-    // Create the method body.
-    let self_expr = build::mk_path(cx, span, ~[ self_ident ]);
-    let self_expr = build::mk_unary(cx, span, deref, self_expr);
-    let self_arms = dvec::unwrap(move self_arms);
-    let self_match_expr = expr_match(self_expr, move self_arms);
-    let self_match_expr = build::mk_expr(cx, span, move self_match_expr);
+        let len = build::mk_uint(cx, span, stmts.len());
+        let emit_fields = build::mk_block(cx, span, ~[], move stmts, None);
+        let emit_fields = create_lambda(cx, span, [], emit_fields);
+        let name = build::mk_base_str(cx, span, cx.str_of(shape.name));
+        let s_expr = build::mk_path(cx, span, ~[ s_ident ]);
+        if type_def.is_enum {
+            let index = build::mk_uint(cx, span, shape.index);
+            call_method(cx,
+                        span,
+                        s_expr,
+                        ~"emit_enum_variant",
+                        ~[ name, index, len, emit_fields ])
+        } else {
+            call_method(cx,
+                        span,
+                        s_expr,
+                        ~"emit_struct",
+                        ~[ name, len, emit_fields ])
+        }
+    });
+    let body = if type_def.is_enum {
+        let name = build::mk_base_str(cx, span, cx.str_of(type_def.ident));
+        let s_expr = build::mk_path(cx, span, ~[ s_ident ]);
+        let body = create_lambda(cx, span, [], body);
+        call_method(cx, span, s_expr, ~"emit_enum", ~[ name, body ])
+    } else {
+        body
+    };
+
+    // Create the `__s` parameter.
+    let s_type = build::mk_simple_ty_path(cx, span, cx.ident_of(~"__S"));
+    let s_type = create_ref_type(cx, span, s_type);
+    let s_arg = build::mk_arg(cx, span, s_ident, s_type);
 
     // Create the method.
-    return create_eq_method(cx,
-                            span,
-                            method_ident,
-                            type_ident,
-                            ty_params,
-                            self_match_expr);
-}
-
-fn expand_deriving_iter_bytes_enum_method(cx: ext_ctxt,
-                                          span: span,
-                                          enum_definition: &enum_def)
-                                       -> @method {
-    // Create the arms of the match in the method body.
-    let arms = do enum_definition.variants.mapi |i, variant| {
-        // Create the matching pattern.
-        let pat = create_enum_variant_pattern(cx, span, variant, ~"__self");
-
-        // Determine the discriminant. We will feed this value to the byte
-        // iteration function.
-        let discriminant;
-        match variant.node.disr_expr {
-            Some(copy disr_expr) => discriminant = disr_expr,
-            None => discriminant = build::mk_uint(cx, span, i),
-        }
+    let output_type = @ast::Ty { id: cx.next_id(), node: ty_nil, span: span };
+    let method = create_method(cx,
+                               span,
+                               cx.ident_of(~"encode"),
+                               sty_region(m_imm),
+                               impure_fn,
+                               ~[ move s_arg ],
+                               output_type,
+                               body);
 
-        // Feed the discriminant to the byte iteration function.
-        let stmts = dvec::DVec();
-        let discrim_stmt = call_substructure_iter_bytes_method(cx,
-                                                               span,
-                                                               discriminant);
-        stmts.push(discrim_stmt);
-
-        // Feed each argument in this variant to the byte iteration function
-        // as well.
-        for uint::range(0, variant_arg_count(cx, span, variant)) |j| {
-            // Create the expression for this field.
-            let field_ident = cx.ident_of(~"__self" + j.to_str());
-            let field = build::mk_path(cx, span, ~[ field_ident ]);
-
-            // Call the substructure method.
-            let stmt = call_substructure_iter_bytes_method(cx, span, field);
-            stmts.push(stmt);
-        }
+    // Create the implementation.
+    let trait_path = [
+        cx.ident_of(~"std"),
+        cx.ident_of(~"serialize"),
+        cx.ident_of(~"Encodable")
+    ];
+    let trait_param = TraitParam {
+        ident: cx.ident_of(~"__S"),
+        bound: ~[
+            cx.ident_of(~"std"),
+            cx.ident_of(~"serialize"),
+            cx.ident_of(~"Encoder")
+        ]
+    };
+    create_derived_impl(cx,
+                        span,
+                        type_def,
+                        [ method ],
+                        trait_path,
+                        Some(move trait_param))
+}
 
-        // Create the pattern body.
-        let stmts = dvec::unwrap(move stmts);
-        let match_body_block = build::mk_block_(cx, span, move stmts);
+fn expand_deriving_decodable_def(cx: ext_ctxt,
+                                 span: span,
+                                 type_def: &TypeDef)
+                              -> @item {
+    let d_ident = cx.ident_of(~"__d");
+
+    // Create the value of each struct or enum variant, built from the
+    // decoded fields.
+    let values = do type_def.shapes.map |shape| {
+        let fields = do shape.fields.mapi |i, name| {
+            let d_expr = build::mk_path(cx, span, ~[ d_ident ]);
+            let decode_path = ~[
+                cx.ident_of(~"std"),
+                cx.ident_of(~"serialize"),
+                cx.ident_of(~"Decodable"),
+                cx.ident_of(~"decode")
+            ];
+            let decode = build::mk_call_global(cx,
+                                               span,
+                                               decode_path,
+                                               ~[ d_expr ]);
+            let decode = create_lambda(cx, span, [], decode);
+
+            let index = build::mk_uint(cx, span, i);
+            let d_expr = build::mk_path(cx, span, ~[ d_ident ]);
+            if type_def.is_enum {
+                call_method(cx,
+                            span,
+                            d_expr,
+                            ~"read_enum_variant_arg",
+                            ~[ index, decode ])
+            } else {
+                let name = cx.str_of(name.get());
+                let name = build::mk_base_str(cx, span, move name);
+                call_method(cx,
+                            span,
+                            d_expr,
+                            ~"read_field",
+                            ~[ name, index, decode ])
+            }
+        };
+        create_constructor(cx, span, shape, move fields)
+    };
 
-        // Create the arm.
-        ast::arm {
-            pats: ~[ pat ],
-            guard: None,
-            body: match_body_block,
-        }
+    // Create the body of the method.
+    let name = build::mk_base_str(cx, span, cx.str_of(type_def.ident));
+    let d_expr = build::mk_path(cx, span, ~[ d_ident ]);
+    let body = if type_def.is_enum {
+        // Pick the variant by its index.
+        let i_ident = cx.ident_of(~"__i");
+        let mut arms = do type_def.shapes.map |shape| {
+            let index = build::mk_uint(cx, span, shape.index);
+            let pat = build::mk_pat(cx, span, pat_lit(index));
+            create_arm(cx, span, pat, values[shape.index])
+        };
+        let wild_pat = build::mk_pat(cx, span, pat_wild);
+        let fail_expr = build::mk_expr(cx, span, expr_fail(None));
+        arms.push(create_arm(cx, span, wild_pat, fail_expr));
+        let i_expr = build::mk_path(cx, span, ~[ i_ident ]);
+        let match_expr = build::mk_expr(cx, span, expr_match(i_expr, arms));
+        let read_variant = create_lambda(cx, span, [ i_ident ], match_expr);
+
+        let d_expr_inner = build::mk_path(cx, span, ~[ d_ident ]);
+        let read_variant = call_method(cx,
+                                       span,
+                                       d_expr_inner,
+                                       ~"read_enum_variant",
+                                       ~[ read_variant ]);
+        let read_variant = create_lambda(cx, span, [], read_variant);
+        call_method(cx, span, d_expr, ~"read_enum", ~[ name, read_variant ])
+    } else {
+        let len = build::mk_uint(cx, span, type_def.shapes[0].fields.len());
+        let read_fields = create_lambda(cx, span, [], values[0]);
+        call_method(cx,
+                    span,
+                    d_expr,
+                    ~"read_struct",
+                    ~[ name, len, read_fields ])
     };
 
-    // Create the method body.
-    let self_ident = cx.ident_of(~"self");
-    let self_expr = build::mk_path(cx, span, ~[ self_ident ]);
-    let self_expr = build::mk_unary(cx, span, deref, self_expr);
-    let self_match_expr = expr_match(self_expr, arms);
-    let self_match_expr = build::mk_expr(cx, span, self_match_expr);
-    let self_match_stmt = build::mk_stmt(cx, span, self_match_expr);
+    // Create the `__d` parameter.
+    let d_type = build::mk_simple_ty_path(cx, span, cx.ident_of(~"__D"));
+    let d_type = create_ref_type(cx, span, d_type);
+    let d_arg = build::mk_arg(cx, span, d_ident, d_type);
 
     // Create the method.
-    create_iter_bytes_method(cx, span, ~[ self_match_stmt ])
-}
+    let output_type = create_self_type_with_params(cx,
+                                                   span,
+                                                   type_def.ident,
+                                                   type_def.ty_params);
+    let method = create_method(cx,
+                               span,
+                               cx.ident_of(~"decode"),
+                               sty_static,
+                               impure_fn,
+                               ~[ move d_arg ],
+                               output_type,
+                               body);
 
+    // Create the implementation.
+    let trait_path = [
+        cx.ident_of(~"std"),
+        cx.ident_of(~"serialize"),
+        cx.ident_of(~"Decodable")
+    ];
+    let trait_param = TraitParam {
+        ident: cx.ident_of(~"__D"),
+        bound: ~[
+            cx.ident_of(~"std"),
+            cx.ident_of(~"serialize"),
+            cx.ident_of(~"Decoder")
+        ]
+    };
+    create_derived_impl(cx,
+                        span,
+                        type_def,
+                        [ method ],
+                        trait_path,
+                        Some(move trait_param))
+}
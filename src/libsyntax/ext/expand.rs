@@ -13,15 +13,22 @@ use core::prelude::*;
 use ast::{crate, expr_, expr_mac, mac_invoc_tt};
 use ast::{tt_delim, tt_tok, item_mac, stmt_, stmt_mac, stmt_expr, stmt_semi};
 use ast;
+use attr;
 use codemap::{span, ExpandedFrom};
 use ext::base::*;
 use fold::*;
 use parse::{parser, parse_expr_from_source_str, new_parser_from_tts};
+use parse::{parse_item_from_source_str};
 
+use core::dvec::DVec;
 use core::option;
 use core::vec;
 use std::map::HashMap;
 
+// Finds the crate an `extern mod` refers to and returns the source of the
+// macros it exports, each a `macro_rules!` item.
+type MacroLoader = fn@(@ast::view_item) -> ~[~str];
+
 fn expand_expr(exts: HashMap<~str, SyntaxExtension>, cx: ext_ctxt,
                e: expr_, s: span, fld: ast_fold,
                orig: fn@(expr_, span, ast_fold) -> (expr_, span))
@@ -126,6 +133,7 @@ fn expand_mod_items(exts: HashMap<~str, SyntaxExtension>, cx: ext_ctxt,
 
 // When we enter a module, record it, for the sake of `module!`
 fn expand_item(exts: HashMap<~str, SyntaxExtension>,
+               exported_macros: @DVec<@ast::item>,
                cx: ext_ctxt, &&it: @ast::item, fld: ast_fold,
                orig: fn@(&&v: @ast::item, ast_fold) -> Option<@ast::item>)
     -> Option<@ast::item>
@@ -135,7 +143,7 @@ fn expand_item(exts: HashMap<~str, SyntaxExtension>,
       _ => false
     };
     let maybe_it = match it.node {
-      ast::item_mac(*) => expand_item_mac(exts, cx, it, fld),
+      ast::item_mac(*) => expand_item_mac(exts, exported_macros, cx, it, fld),
       _ => Some(it)
     };
 
@@ -151,8 +159,11 @@ fn expand_item(exts: HashMap<~str, SyntaxExtension>,
 }
 
 // Support for item-position macro invocations, exactly the same
-// logic as for expression-position macro invocations.
+// logic as for expression-position macro invocations. Definitions marked
+// #[macro_export] are also recorded in `exported_macros`, so that they end
+// up in the crate metadata.
 fn expand_item_mac(exts: HashMap<~str, SyntaxExtension>,
+                   exported_macros: @DVec<@ast::item>,
                    cx: ext_ctxt, &&it: @ast::item,
                    fld: ast_fold) -> Option<@ast::item> {
 
@@ -163,6 +174,16 @@ fn expand_item_mac(exts: HashMap<~str, SyntaxExtension>,
         _ => cx.span_bug(it.span, ~"invalid item macro invocation")
     };
 
+    let mut exported = false;
+    for it.attrs.each |attr| {
+        if attr::get_attr_name(*attr) == ~"macro_export" {
+            exported = true;
+        } else {
+            cx.span_fatal(attr.span,
+                          ~"attrs on macros are not yet supported");
+        }
+    }
+
     let extname = cx.parse_sess().interner.get(pth.idents[0]);
     let expanded = match exts.find(*extname) {
         None => cx.span_fatal(pth.span,
@@ -195,6 +216,14 @@ fn expand_item_mac(exts: HashMap<~str, SyntaxExtension>,
             it.span, fmt!("%s! is not legal in item position", *extname))
     };
 
+    if exported {
+        match expanded {
+            MRDef(_) => (),
+            _ => cx.span_fatal(it.span,
+                               ~"only macro definitions can be exported")
+        }
+    }
+
     let maybe_it = match expanded {
         MRItem(it) => fld.fold_item(it),
         MRExpr(_) => cx.span_fatal(pth.span,
@@ -204,6 +233,7 @@ fn expand_item_mac(exts: HashMap<~str, SyntaxExtension>,
             option::chain(item_maker(), |i| {fld.fold_item(i)}),
         MRDef(ref mdef) => {
             exts.insert((*mdef).name, (*mdef).ext);
+            if exported { exported_macros.push(it); }
             None
         }
     };
@@ -328,14 +358,17 @@ fn core_macros() -> ~str {
 }
 
 fn expand_crate(parse_sess: parse::parse_sess,
-                cfg: ast::crate_cfg, c: @crate) -> @crate {
+                cfg: ast::crate_cfg, c: @crate,
+                macro_loader: MacroLoader) -> @crate {
     let exts = syntax_expander_table();
+    let exported_macros = @DVec();
     let afp = default_ast_fold();
     let cx: ext_ctxt = mk_ctxt(parse_sess, cfg);
     let f_pre = @AstFoldFns {
         fold_expr: |a,b,c| expand_expr(exts, cx, a, b, c, afp.fold_expr),
         fold_mod: |a,b| expand_mod_items(exts, cx, a, b, afp.fold_mod),
-        fold_item: |a,b| expand_item(exts, cx, a, b, afp.fold_item),
+        fold_item: |a,b| expand_item(exts, exported_macros, cx, a, b,
+                                     afp.fold_item),
         fold_stmt: |a,b,c| expand_stmt(exts, cx, a, b, c, afp.fold_stmt),
         new_span: |a| new_span(cx, a),
         .. *afp};
@@ -349,8 +382,38 @@ fn expand_crate(parse_sess: parse::parse_sess,
     // as it registers all the core macros as expanders.
     f.fold_expr(cm);
 
+    // Likewise for the macros exported by the crates this one uses. Only
+    // the `extern mod`s at the top of the crate are considered, as macros
+    // aren't scoped to modules.
+    for c.node.module.view_items.each |vi| {
+        match vi.node {
+          ast::view_item_use(ident, _, _) => {
+            let crate_name = parse_sess.interner.get(ident);
+            let name = fmt!("<%s macros>", *crate_name);
+            for macro_loader(*vi).each |source| {
+                let item = parse_item_from_source_str(copy name,
+                                                      @copy *source,
+                                                      copy cfg,
+                                                      ~[],
+                                                      parse_sess);
+                match item {
+                  Some(item) => { f.fold_item(item); }
+                  None => cx.span_fatal(vi.span,
+                                        fmt!("invalid macro exported by `%s`",
+                                             *crate_name))
+                }
+            }
+          }
+          _ => ()
+        }
+    }
+
     let res = @f.fold_crate(*c);
-    return res;
+    return @ast::spanned {
+        node: ast::crate_ { exported_macros: exported_macros.get(),
+                            .. /*bad*/copy res.node },
+        .. /*bad*/copy *res
+    };
 }
 // Local Variables:
 // mode: rust
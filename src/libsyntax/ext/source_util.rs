@@ -83,7 +83,9 @@ fn expand_include(cx: ext_ctxt, sp: span, tts: ~[ast::token_tree])
 fn expand_include_str(cx: ext_ctxt, sp: span, tts: ~[ast::token_tree])
     -> base::MacResult {
     let file = get_single_str_from_tts(cx, sp, tts, "include_str!");
-    let res = io::read_whole_file_str(&res_rel_file(cx, sp, &Path(file)));
+    let path = res_rel_file(cx, sp, &Path(file));
+    cx.parse_sess().included_files.push(copy path);
+    let res = io::read_whole_file_str(&path);
     match res {
       result::Ok(_) => { /* Continue. */ }
       result::Err(ref e) => {
@@ -97,7 +99,9 @@ fn expand_include_str(cx: ext_ctxt, sp: span, tts: ~[ast::token_tree])
 fn expand_include_bin(cx: ext_ctxt, sp: span, tts: ~[ast::token_tree])
     -> base::MacResult {
     let file = get_single_str_from_tts(cx, sp, tts, "include_bin!");
-    match io::read_whole_file(&res_rel_file(cx, sp, &Path(file))) {
+    let path = res_rel_file(cx, sp, &Path(file));
+    cx.parse_sess().included_files.push(copy path);
+    match io::read_whole_file(&path) {
       result::Ok(src) => {
         let u8_exprs = vec::map(src, |char| {
             mk_u8(cx, sp, *char)
@@ -174,6 +174,7 @@ fn noop_fold_crate(c: crate_, fld: ast_fold) -> crate_ {
         module: fld.fold_mod(c.module),
         attrs: c.attrs.map(|x| fold_attribute(*x)),
         config: c.config.map(|x| fold_meta_item(*x)),
+        exported_macros: copy c.exported_macros,
     }
 }
 
@@ -21,6 +21,7 @@ use parse::parser::Parser;
 use parse::token::{ident_interner, mk_ident_interner};
 use util::interner;
 
+use core::dvec::DVec;
 use core::io;
 use core::option::{None, Option, Some};
 use core::path::Path;
@@ -60,6 +61,9 @@ pub type parse_sess = @{
     mut next_id: node_id,
     span_diagnostic: span_handler,
     interner: @ident_interner,
+    // Files read by `include_str!` and `include_bin!`, which unlike the
+    // files that are parsed are not in the codemap
+    included_files: DVec<Path>,
 };
 
 pub fn new_parse_sess(demitter: Option<emitter>) -> parse_sess {
@@ -68,6 +72,7 @@ pub fn new_parse_sess(demitter: Option<emitter>) -> parse_sess {
              mut next_id: 1,
              span_diagnostic: mk_span_handler(mk_handler(demitter), cm),
              interner: mk_ident_interner(),
+             included_files: DVec(),
             };
 }
 
@@ -77,6 +82,7 @@ pub fn new_parse_sess_special_handler(sh: span_handler, cm: @codemap::CodeMap)
              mut next_id: 1,
              span_diagnostic: sh,
              interner: mk_ident_interner(),
+             included_files: DVec(),
              };
 }
 
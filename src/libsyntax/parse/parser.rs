@@ -3730,11 +3730,8 @@ impl Parser {
                 && (is_plain_ident(self.look_ahead(2))
                     || self.look_ahead(2) == token::LPAREN
                     || self.look_ahead(2) == token::LBRACE) {
-            if attrs.len() > 0 {
-                self.fatal(~"attrs on macros are not yet supported");
-            }
-
-            // item macro.
+            // item macro. Its attributes are checked by the expander, which
+            // only accepts #[macro_export] on `macro_rules!` definitions.
             let pth = self.parse_path_without_tps();
             self.expect(token::NOT);
 
@@ -3995,7 +3992,8 @@ impl Parser {
         @spanned(lo, self.span.lo,
                  ast::crate_ { module: m,
                                attrs: crate_attrs.inner,
-                               config: self.cfg })
+                               config: self.cfg,
+                               exported_macros: ~[] })
     }
 
     fn parse_str() -> @~str {
@@ -18,17 +18,15 @@
 #include "rust_util.h"
 #include "rust_scheduler.h"
 #include "rust_gc_metadata.h"
+#include "rust_task.h"
 
 void* global_crate_map = NULL;
 
-/**
-   The runtime entrypoint. The (C ABI) main function generated by rustc calls
-   `rust_start`, providing the address of the Rust ABI main function, the
-   platform argument vector, and a `crate_map` the provides some logging
-   metadata.
-*/
-extern "C" CDECL int
-rust_start(uintptr_t main_fn, int argc, char **argv, void* crate_map) {
+// Initializes the kernel, runs `main_fn` as the main task and waits for
+// all schedulers to exit
+static int
+run_main_task(spawn_fn main_fn, void *main_arg,
+              int argc, char **argv, void* crate_map) {
 
     // Load runtime configuration options from the environment.
     // FIXME #1497: Should provide a way to get these from the command
@@ -50,7 +48,7 @@ rust_start(uintptr_t main_fn, int argc, char **argv, void* crate_map) {
     rust_task *root_task = sched->create_task(NULL, "main");
 
     // Schedule the main Rust task
-    root_task->start((spawn_fn)main_fn, NULL, NULL);
+    root_task->start(main_fn, NULL, main_arg);
 
     // At this point the task lifecycle is responsible for it
     // and our pointer may not be valid
@@ -65,6 +63,54 @@ rust_start(uintptr_t main_fn, int argc, char **argv, void* crate_map) {
     return ret;
 }
 
+/**
+   The runtime entrypoint. The (C ABI) main function generated by rustc calls
+   `rust_start`, providing the address of the Rust ABI main function, the
+   platform argument vector, and a `crate_map` the provides some logging
+   metadata.
+*/
+extern "C" CDECL int
+rust_start(uintptr_t main_fn, int argc, char **argv, void* crate_map) {
+    return run_main_task((spawn_fn)main_fn, NULL, argc, argv, crate_map);
+}
+
+typedef int (*CDECL c_main_fn)(void *);
+
+struct c_main_args {
+    c_main_fn main_fn;
+    void *arg;
+    int ret;
+};
+
+static void CDECL
+c_main_on_c_stack(c_main_args *args) {
+    args->ret = args->main_fn(args->arg);
+}
+
+// The main task of a C program starts out on the Rust stack like any
+// other task and switches to the C stack to run the C code
+static void CDECL
+c_main_task(void *, rust_opaque_box *, void *args) {
+    rust_task *task = rust_get_current_task();
+    task->call_on_c_stack(args, (void*)c_main_on_c_stack);
+}
+
+/**
+   The runtime entrypoint for C programs that link in a library built with
+   `--staticlib`. Functions exported by Rust code expect to be called from
+   a Rust task, so a C program must make those calls from within
+   `main_fn`, which runs on the C stack of the main task. `crate_map` is
+   the library's `_rust_crate_map_toplevel`. Returns the value returned by
+   `main_fn`, or the runtime's exit status if a task failed.
+*/
+extern "C" CDECL int
+rust_start_from_c(c_main_fn main_fn, void *arg,
+                  int argc, char **argv, void* crate_map) {
+    c_main_args args = { main_fn, arg, 0 };
+    int ret = run_main_task(c_main_task, &args, argc, argv, crate_map);
+    return ret != 0 ? ret : args.ret;
+}
+
 //
 // Local Variables:
 // mode: C++
@@ -19,6 +19,9 @@
 #include "rust_abi.h"
 #include "rust_port.h"
 
+#define MINIZ_HEADER_FILE_ONLY
+#include "miniz.cpp"
+
 #include <time.h>
 
 #ifdef __APPLE__
@@ -1023,6 +1026,31 @@ rust_raw_thread_join_delete(raw_thread *thread) {
     delete thread;
 }
 
+// The miniz compressor and decompressor state is too large and too
+// platform-dependent to lay out on the Rust side, so we allocate it here.
+// Both are freed with free().
+extern "C" CDECL tdefl_compressor *
+rust_tdefl_new(int flags) {
+    tdefl_compressor *d = (tdefl_compressor *)malloc(sizeof(*d));
+    if (d == NULL) {
+        return NULL;
+    }
+    if (tdefl_init(d, NULL, NULL, flags) != TDEFL_STATUS_OKAY) {
+        free(d);
+        return NULL;
+    }
+    return d;
+}
+
+extern "C" CDECL tinfl_decompressor *
+rust_tinfl_new() {
+    tinfl_decompressor *r = (tinfl_decompressor *)malloc(sizeof(*r));
+    if (r == NULL) {
+        return NULL;
+    }
+    tinfl_init(r);
+    return r;
+}
 
 //
 // Local Variables:
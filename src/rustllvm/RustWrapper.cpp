@@ -465,6 +465,16 @@ extern "C" LLVMModuleRef LLVMRustParseBitcode(LLVMMemoryBufferRef MemBuf) {
          ? NULL : M;
 }
 
+extern "C" bool LLVMRustLinkModules(LLVMModuleRef Dest, LLVMModuleRef Src) {
+  static std::string Err;
+  if (Linker::LinkModules(unwrap(Dest), unwrap(Src), Linker::DestroySource,
+                          &Err)) {
+    LLVMRustError = Err.c_str();
+    return false;
+  }
+  return true;
+}
+
 extern "C" LLVMValueRef LLVMRustConstSmallInt(LLVMTypeRef IntTy, unsigned N,
                                               LLVMBool SignExtend) {
   return LLVMConstInt(IntTy, (unsigned long long)N, SignExtend);
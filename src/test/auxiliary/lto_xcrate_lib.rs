@@ -0,0 +1,34 @@
+// Copyright 2013 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[link(name = "lto_xcrate_lib", vers = "0.0")];
+#[crate_type = "lib"];
+
+pub struct Counter { mut count: uint }
+
+pub fn Counter() -> Counter { Counter { count: 0u } }
+
+pub impl Counter {
+    fn bump(&self, n: uint) -> uint {
+        self.count += n;
+        self.count
+    }
+}
+
+// Not #[inline], so without LTO the call stays a call into this crate
+pub fn sum(xs: &[int]) -> int {
+    let mut total = 0;
+    for xs.each |x| { total += *x; }
+    total
+}
+
+pub fn describe(n: int) -> ~str {
+    fmt!("n = %d", n)
+}
@@ -0,0 +1,40 @@
+// Copyright 2013 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[link(name = "macro_export_crate",
+       vers = "0.1")];
+
+#[crate_type = "lib"];
+
+#[macro_export]
+macro_rules! unwrap_or(
+    ($e:expr, $default:expr) => (
+        match $e {
+            Some(v) => v,
+            None => $default
+        }
+    )
+)
+
+#[macro_export]
+macro_rules! twice(
+    ($e:expr) => (
+        ::macro_export_crate::double($e)
+    )
+)
+
+// Only usable within this crate.
+macro_rules! not_exported(
+    () => (0)
+)
+
+pub fn double(x: int) -> int {
+    not_exported!() + x * 2
+}
@@ -0,0 +1,38 @@
+// Copyright 2013 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Links against the archive built from run-pass/staticlib-c-main.rs,
+// using the header generated for it.
+
+#include <stddef.h>
+#include "staticlib-c-main.h"
+
+// Provided by the runtime and by rustc for the archive, respectively
+int rust_start_from_c(int (*main_fn)(void *), void *arg,
+                      int argc, char **argv, void *crate_map);
+extern char _rust_crate_map_toplevel;
+
+// Calls into the archive; run on the main task by the runtime
+static int run(void *arg) {
+    int32_t xs[] = { 1, 2, 3, 4 };
+    Point p = { 3, -5 };
+    (void)arg;
+    if (rust_add(2, 3) != 5) return 1;
+    if (rust_sum(xs, 4) != 10) return 1;
+    if (rust_manhattan(&p) != -2) return 1;
+    if (rust_digits(-1234) != 5) return 1;
+    if (RUST_ANSWER != 42) return 1;
+    return 0;
+}
+
+int main(int argc, char **argv) {
+    return rust_start_from_c(run, NULL, argc, argv,
+                             &_rust_crate_map_toplevel);
+}
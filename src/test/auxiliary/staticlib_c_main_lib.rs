@@ -0,0 +1,40 @@
+// Copyright 2013 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// A crate built with --static --lib for run-pass/staticlib-c-main.rs to
+// bundle into its archive.
+
+#[link(name = "staticlib_c_main_lib", vers = "0.1")];
+#[crate_type = "lib"];
+#[no_core];
+
+pub fn sum(xs: *i32, len: uint) -> i32 {
+    let mut total = 0i32;
+    let mut i = 0u;
+    while i < len {
+        total += unsafe { *((xs as uint + i * 4u) as *i32) };
+        i += 1u;
+    }
+    total
+}
+
+pub fn manhattan(x: i32, y: i32) -> i32 { x + y }
+
+// The number of characters `n` takes when printed in decimal
+pub fn digits(n: i32) -> uint {
+    let mut count = if n < 0 { 1u } else { 0u };
+    let mut rest = n;
+    loop {
+        count += 1u;
+        rest /= 10;
+        if rest == 0 { break; }
+    }
+    count
+}
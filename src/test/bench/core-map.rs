@@ -13,6 +13,7 @@ use std::map;
 use std::treemap::TreeMap;
 use core::hashmap::linear::*;
 use core::io::WriterUtil;
+use core::rand::RngUtil;
 
 struct Results {
     sequential_ints: float,
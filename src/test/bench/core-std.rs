@@ -17,6 +17,7 @@ use std::map;
 use std::map::{Map, HashMap};
 
 use io::{Reader, ReaderUtil};
+use rand::RngUtil;
 
 macro_rules! bench (
     ($id:ident) => (maybe_run_test(argv, stringify!($id).to_owned(), $id))
@@ -22,12 +22,12 @@ use std::time;
 use std::map;
 use std::map::Map;
 use std::map::HashMap;
-use std::deque;
 use std::deque::Deque;
 use std::par;
 use core::io::WriterUtil;
 use core::oldcomm::*;
 use core::int::abs;
+use core::rand::RngUtil;
 
 type node_id = i64;
 type graph = ~[~[node_id]];
@@ -124,12 +124,12 @@ fn bfs(graph: graph, key: node_id) -> bfs_result {
     let marks : ~[mut node_id] 
         = vec::cast_to_mut(vec::from_elem(vec::len(graph), -1i64));
 
-    let Q = deque::create();
+    let mut Q = Deque::new();
 
     Q.add_back(key);
     marks[key] = key;
 
-    while Q.size() > 0 {
+    while !Q.is_empty() {
         let t = Q.pop_front();
 
         do graph[t].each() |k| {
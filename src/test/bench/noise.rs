@@ -1,5 +1,7 @@
 // Perlin noise benchmark from https://gist.github.com/1170424
 
+use core::rand::RngUtil;
+
 struct Vec2 {
     x: f32,
     y: f32,
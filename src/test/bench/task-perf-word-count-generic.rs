@@ -23,6 +23,7 @@
 extern mod std;
 
 use core::option;
+use core::rand::RngUtil;
 use std::map;
 use std::map::HashMap;
 use core::hash::Hash;
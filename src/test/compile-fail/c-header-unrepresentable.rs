@@ -0,0 +1,32 @@
+// Copyright 2013 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags:--c-header
+
+struct Counted { n: int, drop { } }
+
+enum Shape { Circle(float), Square(float) }
+
+#[no_mangle]
+pub extern fn takes_str(_s: ~str) { } //~ ERROR can't be represented in C
+
+#[no_mangle]
+pub extern fn takes_shape(_s: *Shape) { } //~ ERROR can't be represented in C
+
+#[no_mangle]
+pub extern fn takes_counted(_c: Counted) { } //~ ERROR can't be represented
+
+#[no_mangle]
+pub extern fn takes_array(_a: [u8 * 4]) { } //~ ERROR can't be represented
+
+#[no_mangle]
+pub const NAME: &static/str = "name"; //~ ERROR can't be represented in C
+
+fn main() { }
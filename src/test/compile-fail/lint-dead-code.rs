@@ -0,0 +1,55 @@
+// Copyright 2013 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[deny(dead_code)];
+
+const used_const: int = 1;
+const unused_const: int = 2; //~ ERROR constant is never used: `unused_const`
+pub const public_const: int = 3;
+
+struct Point {
+    x: int,
+    y: int, //~ ERROR field is never read: `y`
+    priv _z: int
+}
+
+enum Shape {
+    Circle(int),
+    Square(int), //~ ERROR variant is never constructed: `Square`
+}
+
+fn area(s: Shape) -> int {
+    match s {
+      Circle(r) => r * r,
+      Square(w) => w * w
+    }
+}
+
+fn unused() { //~ ERROR function is never used: `unused`
+    only_used_by_unused();
+}
+
+fn only_used_by_unused() { //~ ERROR function is never used
+}
+
+fn _unused() { }
+
+#[allow(dead_code)]
+fn allowed() { }
+
+pub fn public() -> int { helper() }
+
+fn helper() -> int { used_const }
+
+fn main() {
+    let p = Point { x: 1, y: 2, _z: 3 };
+    area(Circle(p.x));
+    public();
+}
@@ -0,0 +1,29 @@
+// Copyright 2013 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[deny(unnecessary_allocation)];
+
+fn takes_str(_s: &str) { }
+fn takes_slice(_v: &[int]) { }
+fn takes_ref(_x: &int) { }
+fn takes_box_ref(_v: &~[int]) { }
+
+fn main() {
+    takes_str(~"foo"); //~ ERROR unnecessary allocation
+    takes_slice(~[1, 2]); //~ ERROR unnecessary allocation
+    takes_slice(@[1, 2]); //~ ERROR unnecessary allocation
+    takes_ref(~3); //~ ERROR unnecessary allocation
+    takes_ref(@3); //~ ERROR unnecessary allocation
+
+    // Borrowing the box itself needs the box.
+    takes_box_ref(&~[1, 2]);
+    let v = ~[1, 2];
+    takes_slice(v);
+}
@@ -0,0 +1,37 @@
+// Copyright 2013 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[deny(unused_mut)];
+
+struct Point {
+    x: int,
+    y: int
+}
+
+fn main() {
+    let mut a = 3; //~ ERROR variable does not need to be mutable
+    let mut b = 3;
+    b = 4;
+    let mut c = ~[1];
+    c.push(2);
+    let mut d = 0;
+    for 3.times { d += 1; }
+    let mut e = Point { x: 1, y: 2 };
+    e.x = 3;
+    let mut f = ~[1, 2]; //~ ERROR variable does not need to be mutable
+    let g = &f;
+    log(debug, (a, b, c, d, e, g));
+}
+
+#[allow(unused_mut)]
+fn allowed() {
+    let mut a = 3;
+    log(debug, a);
+}
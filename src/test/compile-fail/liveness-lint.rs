@@ -0,0 +1,38 @@
+// Copyright 2013 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[deny(unused_variable)];
+#[deny(dead_assignment)];
+
+fn f1(x: int) { //~ ERROR unused variable: `x`
+}
+
+#[allow(unused_variable)]
+fn f2(x: int) {
+}
+
+fn f3() {
+    let mut x = 3; //~ ERROR variable `x` is assigned to, but never used
+    x = 4; //~ ERROR value assigned to `x` is never read
+}
+
+#[allow(dead_assignment)]
+fn f4() {
+    let mut x = 3;
+    log(debug, x);
+    x = 4;
+}
+
+fn main() {
+    f1(1);
+    f2(2);
+    f3();
+    f4();
+}
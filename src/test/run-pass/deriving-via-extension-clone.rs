@@ -0,0 +1,51 @@
+// Copyright 2013 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[deriving_clone]
+#[deriving_eq]
+struct Point {
+    x: int,
+    y: int,
+}
+
+#[deriving_clone]
+#[deriving_eq]
+enum Shape {
+    Circle(Point, uint),
+    Polygon { points: ~[Point], closed: bool },
+    Label(~str, (int, int)),
+    Empty
+}
+
+#[deriving_clone]
+#[deriving_eq]
+struct Named {
+    name: ~str,
+    shape: Shape,
+}
+
+fn main() {
+    let p = Point { x: 1, y: 2 };
+    assert p.clone() == p;
+
+    let c = Circle(p, 3);
+    assert c.clone() == c;
+
+    let q = Polygon { points: ~[ p, Point { x: 3, y: 4 } ], closed: true };
+    assert q.clone() == q;
+
+    let l = Label(~"origin", (0, 0));
+    assert l.clone() == l;
+
+    let n = Named { name: ~"unit", shape: Circle(Point { x: 0, y: 0 }, 1) };
+    assert n.clone() == n;
+
+    assert Empty.clone() == Empty;
+}
@@ -0,0 +1,65 @@
+// xfail-fast
+
+// Copyright 2013 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+extern mod std;
+
+use EBReader = std::ebml::reader;
+use EBWriter = std::ebml::writer;
+use std::serialize::{Encodable, Decodable};
+
+#[deriving_eq]
+#[deriving_encodable]
+#[deriving_decodable]
+struct Point {
+    x: int,
+    y: int,
+}
+
+#[deriving_eq]
+#[deriving_encodable]
+#[deriving_decodable]
+enum Shape {
+    Circle(Point, uint),
+    Square { corner: Point, side: uint },
+    Empty
+}
+
+#[deriving_eq]
+#[deriving_encodable]
+#[deriving_decodable]
+struct Pair<T> {
+    first: T,
+    second: T,
+}
+
+fn test_ebml<A:
+    Eq
+    Encodable<EBWriter::Encoder>
+    Decodable<EBReader::Decoder>
+>(a1: &A) {
+    let bytes = do io::with_bytes_writer |wr| {
+        let ebml_w = &EBWriter::Encoder(wr);
+        a1.encode(ebml_w)
+    };
+    let d = EBReader::Doc(@move bytes);
+    let a2: A = Decodable::decode(&EBReader::Decoder(d));
+    assert *a1 == a2;
+}
+
+fn main() {
+    let p = Point { x: 1, y: -2 };
+    test_ebml(&p);
+    test_ebml(&Circle(p, 3));
+    test_ebml(&Square { corner: p, side: 4 });
+    test_ebml(&Empty);
+    test_ebml(&Pair { first: p, second: Point { x: 3, y: 4 } });
+}
@@ -0,0 +1,44 @@
+// Copyright 2013 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[deriving_eq]
+#[deriving_ord]
+struct Version {
+    major: uint,
+    minor: uint,
+}
+
+#[deriving_eq]
+#[deriving_ord]
+enum Release {
+    Alpha(uint),
+    Beta(uint),
+    Final
+}
+
+fn main() {
+    let a = Version { major: 1, minor: 2 };
+    let b = Version { major: 1, minor: 3 };
+    let c = Version { major: 2, minor: 0 };
+    assert a < b;
+    assert b < c;
+    assert a <= a;
+    assert !(a < a);
+    assert c > a;
+    assert c >= c;
+    assert !(b > c);
+
+    assert Alpha(2) < Alpha(3);
+    assert Alpha(5) < Beta(1);
+    assert Beta(1) < Final;
+    assert Final >= Final;
+    assert !(Final > Final);
+    assert Final > Alpha(7);
+}
@@ -11,10 +11,9 @@
 // except according to those terms.
 
 extern mod std;
-use std::deque;
 use std::deque::Deque;
 
 fn main() {
-    let Q = deque::create();
+    let mut Q = Deque::new();
     Q.add_back(10);
 }
@@ -0,0 +1,32 @@
+// Copyright 2013 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// xfail-fast - check-fast doesn't understand aux-build
+// aux-build:lto_xcrate_lib.rs
+// compile-flags:-Z lto
+// no-aux-dylibs
+
+// The library's code is linked into the executable from its bitcode, so
+// its shared library is left off the link line. compiletest removes that
+// library before running the test, which then fails to start if it was
+// linked against it anyway.
+
+extern mod lto_xcrate_lib;
+
+use lto_xcrate_lib::Counter;
+
+fn main() {
+    assert lto_xcrate_lib::sum(~[1, 2, 3, 4]) == 10;
+    assert lto_xcrate_lib::describe(7) == ~"n = 7";
+
+    let c = Counter();
+    c.bump(2u);
+    assert c.bump(3u) == 5u;
+}
@@ -0,0 +1,47 @@
+// Copyright 2013 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// xfail-fast - check-fast doesn't understand c-main
+// aux-build:staticlib_c_main_lib.rs
+// c-main:staticlib-c-main.c
+// compile-flags:--c-header
+
+// Compiled with --staticlib and called from a C main, through the header
+// written by --c-header. The archive bundles staticlib_c_main_lib, built
+// with --static, so the C main links against nothing but the archive and
+// the runtime, which it starts before calling into the archive.
+
+#[crate_type = "lib"];
+#[no_core];
+
+extern mod staticlib_c_main_lib;
+
+#[no_mangle]
+pub extern fn rust_add(a: i32, b: i32) -> i32 { a + b }
+
+#[no_mangle]
+pub extern fn rust_sum(xs: *i32, len: uint) -> i32 {
+    staticlib_c_main_lib::sum(xs, len)
+}
+
+pub struct Point { x: i32, y: i32 }
+
+#[no_mangle]
+pub extern fn rust_manhattan(p: *Point) -> i32 {
+    unsafe { staticlib_c_main_lib::manhattan((*p).x, (*p).y) }
+}
+
+#[no_mangle]
+pub extern fn rust_digits(n: i32) -> uint {
+    staticlib_c_main_lib::digits(n)
+}
+
+#[no_mangle]
+pub const RUST_ANSWER: i32 = 42;